@@ -1,6 +1,6 @@
 mod record_cli_display;
 
-use apllodb_server::{ApllodbCommandSuccess, ApllodbServer, ApllodbSessionResult, Session};
+use apllodb_server::{ApllodbScriptSuccess, ApllodbServer, ApllodbSessionResult, Session};
 use record_cli_display::RecordCliDisplay;
 
 #[derive(Debug, new)]
@@ -9,38 +9,26 @@ pub(crate) struct CmdProcessor<'main> {
 }
 
 impl<'main> CmdProcessor<'main> {
+    /// Runs semicolon-separated SQLs in `cmd` and prints records of each SELECT.
     pub(crate) async fn process(
         &self,
         session: Session,
         cmd: &str,
     ) -> ApllodbSessionResult<Session> {
-        let success = self.server.command(session, cmd.to_string()).await?;
-        match success {
-            ApllodbCommandSuccess::QueryResponse { session, records } => {
-                let mut cnt = 0;
+        let ApllodbScriptSuccess { session, records } =
+            self.server.command_script(session, cmd.to_string()).await?;
 
-                for r in records {
-                    cnt += 1;
-                    println!("{}", r.cli_display());
-                }
+        for records in records {
+            let mut cnt = 0;
 
-                println!("\n{} records in total\n", cnt);
-
-                Ok(Session::from(session))
-            }
-
-            ApllodbCommandSuccess::ModificationResponse { session }
-            | ApllodbCommandSuccess::DdlResponse { session }
-            | ApllodbCommandSuccess::BeginTransactionResponse { session } => {
-                Ok(Session::from(session))
+            for r in records {
+                cnt += 1;
+                println!("{}", r.cli_display());
             }
 
-            ApllodbCommandSuccess::CreateDatabaseResponse { session } => Ok(session),
-
-            ApllodbCommandSuccess::UseDatabaseResponse { session }
-            | ApllodbCommandSuccess::TransactionEndResponse { session } => {
-                Ok(Session::from(session))
-            }
+            println!("\n{} records in total\n", cnt);
         }
+
+        Ok(session)
     }
 }
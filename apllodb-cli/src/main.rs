@@ -9,6 +9,7 @@ mod cmd_processor;
 mod shell;
 
 use apllodb_server::{ApllodbServer, Session};
+use clap::{App, Arg};
 use cmd_processor::CmdProcessor;
 use rustyline::error::ReadlineError;
 use shell::ReadLine;
@@ -17,10 +18,36 @@ use shell::ReadLine;
 async fn main() {
     env_logger::init();
 
+    let matches = App::new("apllodb-cli")
+        .arg(
+            Arg::with_name("file")
+                .short("f")
+                .long("file")
+                .value_name("FILE")
+                .help("Runs SQL script in FILE and exits")
+                .takes_value(true),
+        )
+        .get_matches();
+
     let server = ApllodbServer::default();
-    let mut rl = ReadLine::default();
     let cmd_processor = CmdProcessor::new(&server);
 
+    if let Some(path) = matches.value_of("file") {
+        let script = match std::fs::read_to_string(path) {
+            Ok(script) => script,
+            Err(e) => {
+                log::error!("failed to read {}: {:?}", path, e);
+                std::process::exit(1);
+            }
+        };
+        if let Err(e) = cmd_processor.process(Session::default(), &script).await {
+            log::error!("{:?}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let mut rl = ReadLine::default();
     let mut session = Session::default();
 
     loop {
@@ -4,7 +4,7 @@ use apllodb_immutable_schema_engine_domain::{
     row::pk::apparent_pk::ApparentPrimaryKey,
     version::id::VersionId,
     version::repository::VersionRepository,
-    vtable::{id::VTableId, repository::VTableRepository},
+    vtable::{id::VTableId, repository::VTableRepository, VTable},
 };
 use apllodb_shared_components::{ApllodbResult, DatabaseName, SqlValue};
 use apllodb_storage_engine_interface::{ColumnName, Row, TableName};
@@ -52,26 +52,12 @@ impl<'usecase, Types: ImmutableSchemaAbstractTypes> TxUseCase<Types>
         let vtable = vtable_repo.read(&vtable_id).await?;
 
         for row in input.rows {
-            // Construct ApparentPrimaryKey
-            let apk = ApparentPrimaryKey::from_table_pk_def(&vtable, &input.columns, &row)?;
-
-            let non_pk_col_vals: HashMap<ColumnName, SqlValue> = input
-                .columns
-                .iter()
-                .cloned()
-                .zip(row.into_values())
-                .filter_map(|(column_name, sql_value)| {
-                    if apk.column_names().iter().any(|pk_cn| pk_cn == &column_name) {
-                        None
-                    } else {
-                        Some((column_name, sql_value))
-                    }
-                })
-                .collect();
+            let (apk, non_pk_col_vals) = Self::split_pk(&vtable, input.columns, row)?;
 
             // Determine version to insert
             let active_versions = vtable_repo.active_versions(&vtable).await?;
-            let version_to_insert = active_versions.version_to_insert(&non_pk_col_vals)?;
+            let (version_to_insert, non_pk_col_vals) =
+                active_versions.version_to_insert(&non_pk_col_vals)?;
             let version_id = VersionId::new(&vtable_id, version_to_insert.number());
 
             version_repo
@@ -82,3 +68,29 @@ impl<'usecase, Types: ImmutableSchemaAbstractTypes> TxUseCase<Types>
         Ok(InsertUseCaseOutput)
     }
 }
+
+impl<'usecase, Types: ImmutableSchemaAbstractTypes> InsertUseCase<'usecase, Types> {
+    /// Splits `row` into ApparentPrimaryKey and non-PK column values.
+    pub(crate) fn split_pk(
+        vtable: &VTable,
+        columns: &[ColumnName],
+        row: Row,
+    ) -> ApllodbResult<(ApparentPrimaryKey, HashMap<ColumnName, SqlValue>)> {
+        let apk = ApparentPrimaryKey::from_table_pk_def(vtable, columns, &row)?;
+
+        let non_pk_col_vals: HashMap<ColumnName, SqlValue> = columns
+            .iter()
+            .cloned()
+            .zip(row.into_values())
+            .filter_map(|(column_name, sql_value)| {
+                if apk.column_names().iter().any(|pk_cn| pk_cn == &column_name) {
+                    None
+                } else {
+                    Some((column_name, sql_value))
+                }
+            })
+            .collect();
+
+        Ok((apk, non_pk_col_vals))
+    }
+}
@@ -10,7 +10,7 @@ use apllodb_immutable_schema_engine_domain::{
     row_selection_plan::RowSelectionPlan,
     vtable::{id::VTableId, repository::VTableRepository, VTable},
 };
-use apllodb_shared_components::{ApllodbError, ApllodbResult, DatabaseName, Expression, SqlValue};
+use apllodb_shared_components::{ApllodbResult, DatabaseName, Expression, Schema, SqlValue};
 use apllodb_storage_engine_interface::{
     ColumnName, Row, RowProjectionQuery, RowSchema, Rows, TableName,
 };
@@ -31,26 +31,6 @@ impl<'usecase, Types: ImmutableSchemaAbstractTypes> UseCaseInput
     for UpdateUseCaseInput<'usecase, Types>
 {
     fn validate(&self) -> ApllodbResult<()> {
-        self.validate_expression_type()?;
-        Ok(())
-    }
-}
-impl<'usecase, Types: ImmutableSchemaAbstractTypes> UpdateUseCaseInput<'usecase, Types> {
-    fn validate_expression_type(&self) -> ApllodbResult<()> {
-        for (column_name, expr) in &self.column_values {
-            match expr {
-                Expression::ConstantVariant(_) => {}
-                Expression::UnaryOperatorVariant(_, _) => {}
-                Expression::SchemaIndexVariant(_) | Expression::BooleanExpressionVariant(_) => {
-                    return Err(
-                        ApllodbError::feature_not_supported(
-                            format!("trying to UpdateAll `{:?}={:?}` while expr of `UpdateAll INTO ... VALUES (expr ...)`. `expr` can only be a constant", 
-                            column_name, expr
-                        ),
-                    ))
-                }
-            }
-        }
         Ok(())
     }
 }
@@ -72,8 +52,10 @@ impl<'usecase, Types: ImmutableSchemaAbstractTypes + Clone + 'usecase> TxUseCase
 
     /// # Failures
     ///
-    /// - [FeatureNotSupported](apllodb_shared_components::SqlState::FeatureNotSupported) when:
-    ///   - any column_values' Expression is not a ConstantVariant.
+    /// - Errors from [Expression::to_sql_value_for_expr_with_index()](apllodb_shared_components::Expression::to_sql_value_for_expr_with_index)
+    ///   when evaluating column_values' Expression for each row.
+    /// - [DataExceptionStringDataRightTruncation](apllodb_shared_components::SqlState::DataExceptionStringDataRightTruncation) when:
+    ///   - a new column value is longer than the column's VARCHAR(n) or CHAR(n).
     async fn run_core(
         vtable_repo: &Types::VTableRepo,
         version_repo: &Types::VersionRepo,
@@ -88,47 +70,34 @@ impl<'usecase, Types: ImmutableSchemaAbstractTypes + Clone + 'usecase> TxUseCase
             .select(&vtable, projection_result, input.selection.clone())
             .await?;
 
-        vtable_repo.delete(&vtable, input.selection).await?;
+        // Evaluate SET expressions before deleting old rows so that evaluation errors leave the table as it is.
+        let new_columns_to_insert = Self::new_columns_to_insert(rows.as_schema());
+        let new_rows_to_insert = Self::new_rows_to_insert(rows, input.column_values)?;
 
-        Self::insert_updated_rows(
-            vtable_repo,
-            version_repo,
-            &input.database_name,
-            &input.table_name,
-            rows,
-            input.column_values,
-        )
-        .await?;
-
-        Ok(UpdateUseCaseOutput)
-    }
-}
+        // Likewise, check if new rows are accepted (e.g. strings are not too long) before deleting old rows.
+        let active_versions = vtable_repo.active_versions(&vtable).await?;
+        for row in &new_rows_to_insert {
+            let (_, non_pk_col_vals) =
+                InsertUseCase::<'_, Types>::split_pk(&vtable, &new_columns_to_insert, row.clone())?;
+            let _ = active_versions.version_to_insert(&non_pk_col_vals)?;
+        }
 
-impl<'usecase, Types: ImmutableSchemaAbstractTypes> UpdateUseCase<'usecase, Types> {
-    async fn insert_updated_rows(
-        vtable_repo: &Types::VTableRepo,
-        version_repo: &Types::VersionRepo,
-        database_name: &DatabaseName,
-        table_name: &TableName,
-        rows_before: Rows,
-        column_values_to_set: HashMap<ColumnName, Expression>,
-    ) -> ApllodbResult<()> {
-        let schema = rows_before.as_schema();
-        let new_columns_to_insert = Self::new_columns_to_insert(schema);
-        let new_rows_to_insert = Self::new_rows_to_insert(rows_before, column_values_to_set)?;
+        vtable_repo.delete(&vtable, input.selection).await?;
 
         let insert_usecase_input = InsertUseCaseInput::new(
-            database_name,
-            table_name,
+            input.database_name,
+            input.table_name,
             &new_columns_to_insert,
             new_rows_to_insert,
         );
         let _ = InsertUseCase::<'_, Types>::run(vtable_repo, version_repo, insert_usecase_input)
             .await?;
 
-        Ok(())
+        Ok(UpdateUseCaseOutput)
     }
+}
 
+impl<'usecase, Types: ImmutableSchemaAbstractTypes> UpdateUseCase<'usecase, Types> {
     async fn projection_result(
         vtable_repo: &Types::VTableRepo,
         vtable: &VTable,
@@ -165,14 +134,12 @@ impl<'usecase, Types: ImmutableSchemaAbstractTypes> UpdateUseCase<'usecase, Type
             for (pos, tc) in schema.table_column_names_with_pos() {
                 let column_name = tc.as_column_name();
 
+                // SET expressions are evaluated against the row before update.
                 let val_after = if let Some(expr) = column_values_to_set.get(&column_name) {
-                    if let Expression::ConstantVariant(sql_value) = expr {
-                        Ok(sql_value.clone())
-                    } else {
-                        Err(ApllodbError::feature_not_supported(
-                            "only ConstantVariant is acceptable for now",
-                        ))
-                    }
+                    expr.to_sql_value_for_expr_with_index(&|index| {
+                        let (pos, _) = schema.index(index)?;
+                        row.get_sql_value(pos).cloned()
+                    })
                 } else {
                     let val_before = row.get_sql_value(pos)?;
                     Ok(val_before.clone())
@@ -81,6 +81,14 @@ impl ApparentPrimaryKey {
         ))
     }
 
+    /// PK values in `row` are converted into the types of PK columns.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionStringDataRightTruncation](apllodb_shared_components::SqlState::DataExceptionStringDataRightTruncation) when:
+    ///   - PK value (converted into a string) is longer than the column's VARCHAR(n) or CHAR(n).
+    /// - Errors from [NnSqlValue::cast()](apllodb_shared_components::NnSqlValue::cast) when:
+    ///   - PK value cannot be converted into the column's type.
     pub fn from_table_pk_def(
         vtable: &VTable,
         column_names: &[ColumnName],
@@ -106,12 +114,12 @@ impl ApparentPrimaryKey {
                     });
                 let sql_value = row.index(RPos::new(raw_pos)).clone();
                 if let SqlValue::NotNull(nn_sql_value) = sql_value {
-                    nn_sql_value
+                    nn_sql_value.assign(cdt.sql_type())
                 } else {
                     panic!("primary key's column must be NOT NULL")
                 }
             })
-            .collect::<Vec<NnSqlValue>>();
+            .collect::<ApllodbResult<Vec<NnSqlValue>>>()?;
 
         Ok(Self::new(
             vtable.table_name().clone(),
@@ -157,8 +165,9 @@ impl ApparentPrimaryKey {
             .zipped()
             .into_iter()
             .map(|(column_name, sql_value)| {
-                let index = SchemaIndex::from(
-                    format!("{}.{}", self.table_name.as_str(), column_name.as_str()).as_str(),
+                let index = SchemaIndex::new(
+                    Some(self.table_name.as_str().to_string()),
+                    column_name.as_str().to_string(),
                 );
                 ComparisonFunction::EqualVariant {
                     left: Box::new(Expression::SchemaIndexVariant(index)),
@@ -176,8 +185,10 @@ impl ApparentPrimaryKey {
         );
         while let Some(comparison) = comparisons.pop_front() {
             boolean_expr = BooleanExpression::LogicalFunctionVariant(LogicalFunction::AndVariant {
-                left: Box::new(boolean_expr),
-                right: Box::new(BooleanExpression::ComparisonFunctionVariant(comparison)),
+                left: Box::new(Expression::BooleanExpressionVariant(boolean_expr)),
+                right: Box::new(Expression::BooleanExpressionVariant(
+                    BooleanExpression::ComparisonFunctionVariant(comparison),
+                )),
             });
         }
 
@@ -120,7 +120,7 @@ impl ActiveVersion {
         }
     }
 
-    /// Returns the biggest version that can accept `column_values`.
+    /// Checks if this version can accept `column_values`, and returns the values converted into the types of columns.
     ///
     /// # Failures
     ///
@@ -131,10 +131,18 @@ impl ActiveVersion {
     ///   - `column_values` includes any column not defined in this version.
     /// - [CheckViolation](apllodb_shared_components::SqlState::CheckViolation) when:
     ///   - Column value does not satisfy CHECK constraint.
+    /// - [DataExceptionStringDataRightTruncation](apllodb_shared_components::SqlState::DataExceptionStringDataRightTruncation) when:
+    ///   - Column value (converted into a string) is longer than the column's VARCHAR(n) or CHAR(n).
+    /// - Errors from [NnSqlValue::cast()](apllodb_shared_components::NnSqlValue::cast) when:
+    ///   - Column value cannot be converted into the column's type. Notably:
+    ///     - [DataExceptionNumericValueOutOfRange](apllodb_shared_components::SqlState::DataExceptionNumericValueOutOfRange)
+    ///       for a number which does not fit in the column's type (e.g. NUMERIC(p, s)).
+    ///     - [DataExceptionInvalidJsonText](apllodb_shared_components::SqlState::DataExceptionInvalidJsonText)
+    ///       for a string which is not a JSON text for the column's JSON.
     pub(in crate::version) fn check_version_constraint(
         &self,
         column_values: &HashMap<ColumnName, SqlValue>,
-    ) -> ApllodbResult<()> {
+    ) -> ApllodbResult<HashMap<ColumnName, SqlValue>> {
         let version_column_data_types = self.column_data_types();
 
         // Check if all NOT NULL columns are included in `column_values`.
@@ -168,12 +176,24 @@ impl ActiveVersion {
         }
 
         // Check column value to insert.
-        // for (_column_name, _expr) in column_values {
+        let mut cast_column_values = HashMap::<ColumnName, SqlValue>::new();
+        for cdt in version_column_data_types {
+            match column_values.get(cdt.column_name()) {
+                Some(SqlValue::NotNull(nn_sql_value)) => {
+                    let cast_value = nn_sql_value.clone().assign(cdt.sql_type())?;
+                    cast_column_values
+                        .insert(cdt.column_name().clone(), SqlValue::NotNull(cast_value));
+                }
+                Some(SqlValue::Null) => {
+                    cast_column_values.insert(cdt.column_name().clone(), SqlValue::Null);
+                }
+                None => {}
+            }
+        }
         // TODO implement NullViolation error detection after Expression can hold NULL.
         // TODO implement CheckViolation error detection
-        // }
 
-        Ok(())
+        Ok(cast_column_values)
     }
 
     fn validate_col_not_exists(&self, column_name: &ColumnName) -> ApllodbResult<()> {
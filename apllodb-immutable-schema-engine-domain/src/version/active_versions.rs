@@ -38,16 +38,32 @@ impl ActiveVersions {
         Ok(&self.0)
     }
 
-    /// Returns the biggest version that can accept `column_values`.
+    /// Returns the biggest version that can accept `column_values`, with the values converted into the types of its columns.
     ///
     /// # Failures
     ///
     /// - [DataException](apllodb_shared_components::SqlState::DataException) when:
     ///   - No active version can accept the column value.
+    /// - [DataExceptionStringDataRightTruncation](apllodb_shared_components::SqlState::DataExceptionStringDataRightTruncation) when:
+    ///   - Every active version rejects a column value longer than the column's VARCHAR(n) or CHAR(n).
+    /// - [DataExceptionInvalidJsonText](apllodb_shared_components::SqlState::DataExceptionInvalidJsonText) when:
+    ///   - Every active version rejects a string which is not a JSON text for the column's JSON.
+    /// - [DataExceptionNumericValueOutOfRange](apllodb_shared_components::SqlState::DataExceptionNumericValueOutOfRange) when:
+    ///   - Every active version rejects a number which does not fit in the column's type (e.g. NUMERIC(p, s)).
+    /// - [DataExceptionInvalidCharacterValueForCast](apllodb_shared_components::SqlState::DataExceptionInvalidCharacterValueForCast) when:
+    ///   - Every active version rejects a string which does not represent a value of the column's type.
+    /// - [DataExceptionIllegalConversion](apllodb_shared_components::SqlState::DataExceptionIllegalConversion) when:
+    ///   - Every active version rejects a value which cannot be converted into the column's type.
+    /// - [DataExceptionInvalidDatetimeFormat](apllodb_shared_components::SqlState::DataExceptionInvalidDatetimeFormat) when:
+    ///   - Every active version rejects a string which is not in the format of the column's DATE, TIME or TIMESTAMP.
+    /// - [DataExceptionDatetimeFieldOverflow](apllodb_shared_components::SqlState::DataExceptionDatetimeFieldOverflow) when:
+    ///   - Every active version rejects a string with an out-of-range field for the column's DATE, TIME or TIMESTAMP (e.g. `2021-02-30`).
+    /// - [DataExceptionInvalidTimeZoneDisplacementValue](apllodb_shared_components::SqlState::DataExceptionInvalidTimeZoneDisplacementValue) when:
+    ///   - Every active version rejects a string with an out-of-range time zone for the column's TIMESTAMP WITH TIME ZONE.
     pub fn version_to_insert(
         &self,
         non_pk_column_values: &HashMap<ColumnName, SqlValue>,
-    ) -> ApllodbResult<&ActiveVersion> {
+    ) -> ApllodbResult<(&ActiveVersion, HashMap<ColumnName, SqlValue>)> {
         if self.0.is_empty() {
             return Err(ApllodbError::name_error_not_found(
                 "no active version found",
@@ -57,10 +73,9 @@ impl ActiveVersions {
         // FIXME use `map_while` after it is stabilized: https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.map_while
         let mut errors_per_versions: Vec<(&ActiveVersion, ApllodbError)> = Vec::new();
         for version in &self.0 {
-            if let Err(e) = version.check_version_constraint(non_pk_column_values) {
-                errors_per_versions.push((version, e));
-            } else {
-                return Ok(version);
+            match version.check_version_constraint(non_pk_column_values) {
+                Ok(cast_column_values) => return Ok((version, cast_column_values)),
+                Err(e) => errors_per_versions.push((version, e)),
             }
         }
 
@@ -76,6 +91,21 @@ impl ActiveVersions {
                 "at least 1 column does not exist in any version: {:?}",
                 errors_per_versions,
             )))
+        } else if errors_per_versions.iter().all(|(_, e)| {
+            matches!(
+                e.kind(),
+                SqlState::DataExceptionStringDataRightTruncation
+                    | SqlState::DataExceptionInvalidJsonText
+                    | SqlState::DataExceptionNumericValueOutOfRange
+                    | SqlState::DataExceptionInvalidCharacterValueForCast
+                    | SqlState::DataExceptionIllegalConversion
+                    | SqlState::DataExceptionInvalidDatetimeFormat
+                    | SqlState::DataExceptionDatetimeFieldOverflow
+                    | SqlState::DataExceptionInvalidTimeZoneDisplacementValue
+            )
+        }) {
+            let (_, e) = errors_per_versions.remove(0);
+            Err(e)
         } else {
             Err(ApllodbError::data_exception(format!(
                 "all versions reject INSERTing {:#?}: {:#?}",
@@ -35,21 +35,17 @@ impl<Types: ImmutableSchemaAbstractTypes> VrrEntry<Types> {
 
         let apk_and_revison_condition =
             BooleanExpression::LogicalFunctionVariant(LogicalFunction::AndVariant {
-                left: Box::new(apk_condition),
-                right: Box::new(revision_condition),
+                left: Box::new(Expression::BooleanExpressionVariant(apk_condition)),
+                right: Box::new(Expression::BooleanExpressionVariant(revision_condition)),
             });
 
         Ok(apk_and_revison_condition)
     }
 
     fn to_revision_condition(&self, revision_column_name: &ColumnName) -> BooleanExpression {
-        let index = SchemaIndex::from(
-            format!(
-                "{}.{}",
-                self.pk.table_name().as_str(),
-                revision_column_name.as_str()
-            )
-            .as_str(),
+        let index = SchemaIndex::new(
+            Some(self.pk.table_name().as_str().to_string()),
+            revision_column_name.as_str().to_string(),
         );
 
         let rev: i64 = TryFrom::try_from(self.revision.to_u64())
@@ -15,10 +15,10 @@ use apllodb_immutable_schema_engine_application::use_case::transaction::{
 };
 use apllodb_immutable_schema_engine_application::use_case::TxUseCase;
 use apllodb_immutable_schema_engine_domain::vtable::{id::VTableId, repository::VTableRepository};
-use apllodb_shared_components::{ApllodbError, ApllodbResult, Expression, SessionId};
+use apllodb_shared_components::{AdtType, ApllodbError, ApllodbResult, Expression, SessionId};
 use apllodb_storage_engine_interface::{
-    AlterTableAction, ColumnDefinition, ColumnName, Row, RowProjectionQuery, RowSelectionQuery,
-    Rows, TableConstraints, TableName, WithTxMethods,
+    AlterTableAction, ColumnDataType, ColumnDefinition, ColumnName, Row, RowProjectionQuery,
+    RowSelectionQuery, Rows, TableConstraints, TableName, WithTxMethods,
 };
 use futures::FutureExt;
 
@@ -148,6 +148,48 @@ impl WithTxMethods for WithTxMethodsImpl {
         .boxed_local()
     }
 
+    fn create_type_core(self, sid: SessionId, adt_type: AdtType) -> BoxFutRes<()> {
+        async move {
+            let tx_pool = self.tx_pool.borrow();
+            let tx = tx_pool.get_tx(&sid)?;
+
+            SqliteTx::vtable_repo(tx.clone())
+                .create_type(&adt_type)
+                .await
+        }
+        .boxed_local()
+    }
+
+    fn list_types_core(self, sid: SessionId) -> BoxFutRes<Vec<AdtType>> {
+        async move {
+            let tx_pool = self.tx_pool.borrow();
+            let tx = tx_pool.get_tx(&sid)?;
+
+            SqliteTx::vtable_repo(tx.clone()).list_types().await
+        }
+        .boxed_local()
+    }
+
+    fn list_columns_core(
+        self,
+        sid: SessionId,
+        table_name: TableName,
+    ) -> BoxFutRes<Vec<ColumnDataType>> {
+        async move {
+            let tx_pool = self.tx_pool.borrow();
+            let tx = tx_pool.get_tx(&sid)?;
+
+            let vtable_repo = SqliteTx::vtable_repo(tx.clone());
+
+            let database_name = tx.borrow().database_name().clone();
+            let vtable_id = VTableId::new(&database_name, &table_name);
+            let vtable = vtable_repo.read(&vtable_id).await?;
+
+            vtable_repo.list_columns(&vtable).await
+        }
+        .boxed_local()
+    }
+
     // ========================================================================
     // DML
     // ========================================================================
@@ -1,8 +1,8 @@
 use std::collections::HashSet;
 
 use apllodb_shared_components::{
-    ApllodbResult, I64LooseType, NumericComparableType, SqlConvertible, SqlType, SqlValue,
-    StringComparableLoseType,
+    ApllodbError, ApllodbResult, ArrayValue, Decimal, F64LooseType, I64LooseType, NnSqlValue,
+    NumericComparableType, SqlConvertible, SqlType, SqlValue, StringComparableLoseType,
 };
 use apllodb_storage_engine_interface::{
     ColumnDataType, ColumnName, Row, RowSchema, Rows, TableColumnName, TableName,
@@ -80,15 +80,75 @@ pub(crate) trait FromSqliteRows {
                         Self::_sqlite_row_value::<i64>(sqlite_row, column_data_type)?
                     }
                 },
+                NumericComparableType::F64Loose(f) => match f {
+                    F64LooseType::Real => {
+                        Self::_sqlite_row_value::<f32>(sqlite_row, column_data_type)?
+                    }
+                    F64LooseType::DoublePrecision => {
+                        Self::_sqlite_row_value::<f64>(sqlite_row, column_data_type)?
+                    }
+                },
+                NumericComparableType::Decimal(_) => {
+                    Self::_sqlite_row_text_encoded(sqlite_row, column_data_type)?
+                }
             },
             SqlType::StringComparableLoose(s) => match s {
                 StringComparableLoseType::Text => {
                     Self::_sqlite_row_value::<String>(sqlite_row, column_data_type)?
                 }
+                // typed as the column's type (e.g. CHAR values are padded)
+                StringComparableLoseType::VarChar { .. }
+                | StringComparableLoseType::Char { .. } => {
+                    Self::_sqlite_row_text_encoded(sqlite_row, column_data_type)?
+                }
             },
             SqlType::BooleanComparable => {
                 Self::_sqlite_row_value::<bool>(sqlite_row, column_data_type)?
             }
+            SqlType::BinaryComparable => {
+                Self::_sqlite_row_value::<Vec<u8>>(sqlite_row, column_data_type)?
+            }
+            SqlType::JsonComparable
+            | SqlType::DatetimeComparable(_)
+            | SqlType::IntervalComparable
+            | SqlType::AdtComparable(_)
+            | SqlType::ArrayComparable(_) => {
+                Self::_sqlite_row_text_encoded(sqlite_row, column_data_type)?
+            }
+        };
+
+        Ok(sql_value)
+    }
+
+    /// NUMERIC, VARCHAR, CHAR, JSON, date/time, INTERVAL, user-defined type and array values are stored as TEXT.
+    fn _sqlite_row_text_encoded(
+        sqlite_row: &sqlx::sqlite::SqliteRow,
+        column_data_type: &ColumnDataType,
+    ) -> ApllodbResult<SqlValue> {
+        use sqlx::Row;
+
+        let text: Option<String> = sqlite_row
+            .try_get(column_data_type.column_name().as_str())
+            .map_err(InfraError::from)?;
+
+        let sql_value = if let Some(text) = text {
+            let nn_sql_value = match column_data_type.sql_type() {
+                SqlType::NumericComparable(NumericComparableType::Decimal(_)) => {
+                    NnSqlValue::Numeric(text.parse::<Decimal>()?)
+                }
+                SqlType::ArrayComparable(_) => {
+                    NnSqlValue::Array(serde_yaml::from_str::<ArrayValue>(&text).map_err(|e| {
+                        ApllodbError::system_error(
+                            "failed to deserialize an array value",
+                            Box::new(e),
+                        )
+                    })?)
+                }
+                _ => NnSqlValue::Text(text),
+            };
+            SqlValue::NotNull(nn_sql_value.cast(column_data_type.sql_type())?)
+        } else {
+            SqlValue::Null
         };
 
         Ok(sql_value)
@@ -1,9 +1,13 @@
+use std::collections::HashMap;
+
 use super::sqlite_rowid::SqliteRowid;
 use apllodb_immutable_schema_engine_domain::{
     row::pk::full_pk::revision::Revision, version::version_number::VersionNumber,
 };
 use apllodb_shared_components::{
-    BooleanExpression, ComparisonFunction, Expression, I64LooseType, LogicalFunction, NnSqlValue,
+    ApllodbError, ApllodbResult, ArithmeticFunction, BetweenPredicate, BooleanExpression,
+    CaseExpression, ComparisonFunction, Expression, F64LooseType, FunctionCall, I64LooseType,
+    InPredicate, LikePredicate, LogicalFunction, MatchExpression, NnSqlValue, NullPredicate,
     NumericComparableType, SchemaIndex, SqlType, SqlValue, StringComparableLoseType, UnaryOperator,
 };
 use apllodb_storage_engine_interface::{ColumnDataType, ColumnName, TableName};
@@ -12,6 +16,51 @@ pub(in crate::sqlite) trait ToSqlString {
     fn to_sql_string(&self) -> String;
 }
 
+/// Lowers an expression into SQLite's one, if SQLite evaluates it as apllodb does.
+///
+/// # Failures
+///
+/// - [FeatureNotSupported](apllodb_shared_components::SqlState::FeatureNotSupported) when:
+///   - the expression includes what SQLite cannot evaluate as apllodb does (e.g. case-insensitive LIKE, POSSIBLY, MATCH).
+///     Such an expression is evaluated by apllodb instead of being pushed down to SQLite.
+pub(in crate::sqlite) trait TryToSqlString {
+    fn try_to_sql_string(&self) -> ApllodbResult<String>;
+}
+
+/// Encloses a table / column name in double quotes so that it can be a keyword or contain any characters.
+///
+/// Note that SQLite compares identifiers case-insensitively (for ASCII letters) even if they are quoted.
+/// Names which differ only in case must not live in the same namespace (see [validate_distinct_identifiers()](self::validate_distinct_identifiers)).
+pub(in crate::sqlite) fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+/// Checks if SQLite can tell `names` apart when they are quoted by [quote_identifier()](self::quote_identifier).
+///
+/// # Failures
+///
+/// - [NameErrorDuplicate](apllodb_shared_components::SqlState::NameErrorDuplicate) when:
+///   - 2 of `names` are the same except for case of ASCII letters (e.g. `a` and `"A"`).
+pub(in crate::sqlite) fn validate_distinct_identifiers<'a>(
+    names: impl IntoIterator<Item = &'a str>,
+) -> ApllodbResult<()> {
+    let mut folded_names = HashMap::<String, &str>::new();
+    for name in names {
+        if let Some(other) = folded_names.insert(name.to_ascii_lowercase(), name) {
+            return Err(ApllodbError::name_error_duplicate(format!(
+                "`{}` and `{}` collide since SQLite does not distinguish case of identifiers",
+                other, name
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Encloses a text in single quotes to make it a string literal.
+pub(in crate::sqlite) fn quote_string(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
 impl<T: ToSqlString + ?Sized> ToSqlString for &T {
     fn to_sql_string(&self) -> String {
         (*self).to_sql_string()
@@ -49,13 +98,13 @@ impl ToSqlString for str {
 
 impl ToSqlString for TableName {
     fn to_sql_string(&self) -> String {
-        self.as_str().to_string()
+        quote_identifier(self.as_str())
     }
 }
 
 impl ToSqlString for ColumnName {
     fn to_sql_string(&self) -> String {
-        self.as_str().to_string()
+        quote_identifier(self.as_str())
     }
 }
 
@@ -68,11 +117,31 @@ impl ToSqlString for SqlType {
                     I64LooseType::Integer => "INTEGER",
                     I64LooseType::BigInt => "BIGINT",
                 },
+                NumericComparableType::F64Loose(f) => match f {
+                    F64LooseType::Real => "REAL",
+                    F64LooseType::DoublePrecision => "DOUBLE PRECISION",
+                },
+                // Stored as text to keep all the digits and the scale
+                // (SQLite's NUMERIC affinity would convert values into 8-byte floating-point numbers).
+                NumericComparableType::Decimal(_) => "TEXT",
             },
             SqlType::StringComparableLoose(s) => match s {
-                StringComparableLoseType::Text => "TEXT",
+                // Length is checked on INSERT (SQLite ignores it anyway).
+                StringComparableLoseType::Text
+                | StringComparableLoseType::VarChar { .. }
+                | StringComparableLoseType::Char { .. } => "TEXT",
             },
             SqlType::BooleanComparable => "BOOLEAN",
+            SqlType::BinaryComparable => "BLOB",
+            // Stored as compact JSON text, which is parsed back into the same value.
+            SqlType::JsonComparable => "TEXT",
+            // Stored as text, which is parsed back into the same value
+            // (SQLite has no date/time types, and its date/time functions do not support INTERVAL or nanoseconds).
+            SqlType::DatetimeComparable(_) | SqlType::IntervalComparable => "TEXT",
+            // Stored as text representation (e.g. `circle(1)`), which is parsed back with the column's type.
+            SqlType::AdtComparable(_) => "TEXT",
+            // Stored as YAML text including the element type, which is deserialized and then cast into the column's type.
+            SqlType::ArrayComparable(_) => "TEXT",
         }
         .to_string()
     }
@@ -91,65 +160,260 @@ impl ToSqlString for ColumnDataType {
 
 impl ToSqlString for SchemaIndex {
     fn to_sql_string(&self) -> String {
-        format!("{}", self)
+        let attr = quote_identifier(self.attr());
+        match self.prefix() {
+            Some(prefix) => format!("{}.{}", quote_identifier(prefix), attr),
+            None => attr,
+        }
     }
 }
 
 impl ToSqlString for SqlValue {
     fn to_sql_string(&self) -> String {
-        self.to_string()
+        match self {
+            SqlValue::Null => self.to_string(),
+            SqlValue::NotNull(nn) => nn.to_sql_string(),
+        }
     }
 }
 impl ToSqlString for NnSqlValue {
-    fn to_sql_string(&self) -> String {
-        self.to_string()
-    }
-}
-
-impl ToSqlString for LogicalFunction {
     fn to_sql_string(&self) -> String {
         match self {
-            LogicalFunction::AndVariant { left, right } => {
-                format!("({} AND {})", left.to_sql_string(), right.to_sql_string())
+            NnSqlValue::Text(s) | NnSqlValue::Char(s) => quote_string(s),
+            NnSqlValue::Numeric(d) => quote_string(&d.to_string()),
+            NnSqlValue::Bytes(b) => format!(
+                "X'{}'",
+                b.iter()
+                    .map(|byte| format!("{:02x}", byte))
+                    .collect::<String>()
+            ),
+            NnSqlValue::Date(_)
+            | NnSqlValue::Time(_)
+            | NnSqlValue::Timestamp(_)
+            | NnSqlValue::TimestampWithTimeZone(_)
+            | NnSqlValue::FuzzyDate(_)
+            | NnSqlValue::Interval(_)
+            | NnSqlValue::Json(_)
+            | NnSqlValue::Adt(_) => quote_string(&self.to_string()),
+            NnSqlValue::Array(a) => {
+                quote_string(&serde_yaml::to_string(a).expect("array is serializable"))
             }
+            NnSqlValue::Real(_) | NnSqlValue::DoublePrecision(_) => {
+                let f = self.unpack::<f64>().expect("floating-point number");
+                if f.is_nan() {
+                    // SQLite stores NaN as NULL anyway
+                    "NULL".to_string()
+                } else if f.is_infinite() {
+                    // overflows into infinity
+                    (if f > 0.0 { "9e999" } else { "-9e999" }).to_string()
+                } else {
+                    self.to_string()
+                }
+            }
+            _ => self.to_string(),
         }
     }
 }
-impl ToSqlString for ComparisonFunction {
+
+impl ToSqlString for UnaryOperator {
     fn to_sql_string(&self) -> String {
         match self {
-            ComparisonFunction::EqualVariant { left, right } => {
-                format!("({} = {})", left.to_sql_string(), right.to_sql_string())
-            }
+            UnaryOperator::Minus => "-".to_string(),
         }
     }
 }
-impl ToSqlString for BooleanExpression {
-    fn to_sql_string(&self) -> String {
-        match self {
-            BooleanExpression::LogicalFunctionVariant(lf) => format!("({})", lf.to_sql_string()),
-            BooleanExpression::ComparisonFunctionVariant(cf) => format!("({})", cf.to_sql_string()),
-        }
+
+impl<T: TryToSqlString> TryToSqlString for [T] {
+    fn try_to_sql_string(&self) -> ApllodbResult<String> {
+        Ok(self
+            .iter()
+            .map(|t| t.try_to_sql_string())
+            .collect::<ApllodbResult<Vec<String>>>()?
+            .join(", "))
     }
 }
 
-impl ToSqlString for UnaryOperator {
-    fn to_sql_string(&self) -> String {
+impl TryToSqlString for LogicalFunction {
+    fn try_to_sql_string(&self) -> ApllodbResult<String> {
         match self {
-            UnaryOperator::Minus => "-".to_string(),
+            LogicalFunction::AndVariant { left, right } => Ok(format!(
+                "({} AND {})",
+                left.try_to_sql_string()?,
+                right.try_to_sql_string()?
+            )),
+            LogicalFunction::OrVariant { left, right } => Ok(format!(
+                "({} OR {})",
+                left.try_to_sql_string()?,
+                right.try_to_sql_string()?
+            )),
+            LogicalFunction::NotVariant { child } => {
+                Ok(format!("(NOT {})", child.try_to_sql_string()?))
+            }
+            LogicalFunction::PossiblyVariant { .. } => Err(ApllodbError::feature_not_supported(
+                "SQLite has no POSSIBLY: conditions on ambiguous values are evaluated by apllodb",
+            )),
         }
     }
 }
+impl TryToSqlString for ComparisonFunction {
+    fn try_to_sql_string(&self) -> ApllodbResult<String> {
+        let op = match self {
+            ComparisonFunction::EqualVariant { .. } => "=",
+            ComparisonFunction::NotEqualVariant { .. } => "<>",
+            ComparisonFunction::LessThanVariant { .. } => "<",
+            ComparisonFunction::LessThanOrEqualVariant { .. } => "<=",
+            ComparisonFunction::GreaterThanVariant { .. } => ">",
+            ComparisonFunction::GreaterThanOrEqualVariant { .. } => ">=",
+        };
+        let (left, right) = self.operands();
+        Ok(format!(
+            "({} {} {})",
+            left.try_to_sql_string()?,
+            op,
+            right.try_to_sql_string()?
+        ))
+    }
+}
+impl TryToSqlString for ArithmeticFunction {
+    fn try_to_sql_string(&self) -> ApllodbResult<String> {
+        let op = match self {
+            ArithmeticFunction::AddVariant { .. } => "+",
+            ArithmeticFunction::SubtractVariant { .. } => "-",
+            ArithmeticFunction::MultiplyVariant { .. } => "*",
+            ArithmeticFunction::DivideVariant { .. } => "/",
+            ArithmeticFunction::ModuloVariant { .. } => "%",
+        };
+        let (left, right) = self.operands();
+        Ok(format!(
+            "({} {} {})",
+            left.try_to_sql_string()?,
+            op,
+            right.try_to_sql_string()?
+        ))
+    }
+}
+impl TryToSqlString for NullPredicate {
+    fn try_to_sql_string(&self) -> ApllodbResult<String> {
+        let op = match self {
+            NullPredicate::IsNullVariant { .. } => "IS NULL",
+            NullPredicate::IsNotNullVariant { .. } => "IS NOT NULL",
+        };
+        Ok(format!("({} {})", self.child().try_to_sql_string()?, op))
+    }
+}
+impl TryToSqlString for InPredicate {
+    fn try_to_sql_string(&self) -> ApllodbResult<String> {
+        let op = match self {
+            InPredicate::InVariant { .. } => "IN",
+            InPredicate::NotInVariant { .. } => "NOT IN",
+        };
+        Ok(format!(
+            "({} {} ({}))",
+            self.child().try_to_sql_string()?,
+            op,
+            self.list().try_to_sql_string()?
+        ))
+    }
+}
+impl TryToSqlString for BetweenPredicate {
+    fn try_to_sql_string(&self) -> ApllodbResult<String> {
+        let op = match self {
+            BetweenPredicate::BetweenVariant { .. } => "BETWEEN",
+            BetweenPredicate::NotBetweenVariant { .. } => "NOT BETWEEN",
+        };
+        let (child, low, high) = self.operands();
+        Ok(format!(
+            "({} {} {} AND {})",
+            child.try_to_sql_string()?,
+            op,
+            low.try_to_sql_string()?,
+            high.try_to_sql_string()?
+        ))
+    }
+}
+impl TryToSqlString for LikePredicate {
+    fn try_to_sql_string(&self) -> ApllodbResult<String> {
+        Err(ApllodbError::feature_not_supported(
+            "SQLite's LIKE is case-insensitive for ASCII letters: LIKE is evaluated by apllodb",
+        ))
+    }
+}
+impl TryToSqlString for FunctionCall {
+    fn try_to_sql_string(&self) -> ApllodbResult<String> {
+        Err(ApllodbError::feature_not_supported(format!(
+            "function `{}` is registered in apllodb, not in SQLite",
+            self.function_name()
+        )))
+    }
+}
+impl TryToSqlString for CaseExpression {
+    fn try_to_sql_string(&self) -> ApllodbResult<String> {
+        let operand = match self.operand() {
+            Some(operand) => format!(" {}", operand.try_to_sql_string()?),
+            None => String::new(),
+        };
+        let when_clauses = self
+            .when_clauses()
+            .iter()
+            .map(|(when, then)| {
+                Ok(format!(
+                    " WHEN {} THEN {}",
+                    when.try_to_sql_string()?,
+                    then.try_to_sql_string()?
+                ))
+            })
+            .collect::<ApllodbResult<String>>()?;
+        let else_clause = match self.else_result() {
+            Some(else_result) => format!(" ELSE {}", else_result.try_to_sql_string()?),
+            None => String::new(),
+        };
+        Ok(format!(
+            "(CASE{}{}{} END)",
+            operand, when_clauses, else_clause
+        ))
+    }
+}
+impl TryToSqlString for MatchExpression {
+    fn try_to_sql_string(&self) -> ApllodbResult<String> {
+        Err(ApllodbError::feature_not_supported(
+            "SQLite has no MATCH: conditions on user-defined types are evaluated by apllodb",
+        ))
+    }
+}
+impl TryToSqlString for BooleanExpression {
+    fn try_to_sql_string(&self) -> ApllodbResult<String> {
+        let sql = match self {
+            BooleanExpression::LogicalFunctionVariant(lf) => lf.try_to_sql_string()?,
+            BooleanExpression::ComparisonFunctionVariant(cf) => cf.try_to_sql_string()?,
+            BooleanExpression::NullPredicateVariant(np) => np.try_to_sql_string()?,
+            BooleanExpression::InPredicateVariant(ip) => ip.try_to_sql_string()?,
+            BooleanExpression::BetweenPredicateVariant(bp) => bp.try_to_sql_string()?,
+            BooleanExpression::LikePredicateVariant(lp) => lp.try_to_sql_string()?,
+        };
+        Ok(format!("({})", sql))
+    }
+}
 
-impl ToSqlString for Expression {
-    fn to_sql_string(&self) -> String {
+impl TryToSqlString for Expression {
+    fn try_to_sql_string(&self) -> ApllodbResult<String> {
         match self {
-            Expression::ConstantVariant(c) => c.to_sql_string(),
-            Expression::SchemaIndexVariant(index) => index.to_sql_string(),
-            Expression::BooleanExpressionVariant(boolean_expr) => boolean_expr.to_sql_string(),
-            Expression::UnaryOperatorVariant(uni_op, expr) => {
-                format!("{} {}", uni_op.to_sql_string(), expr.to_sql_string())
-            }
+            Expression::ConstantVariant(c) => Ok(c.to_sql_string()),
+            Expression::SchemaIndexVariant(index) => Ok(index.to_sql_string()),
+            Expression::BooleanExpressionVariant(boolean_expr) => boolean_expr.try_to_sql_string(),
+            Expression::UnaryOperatorVariant(uni_op, expr) => Ok(format!(
+                "{} {}",
+                uni_op.to_sql_string(),
+                expr.try_to_sql_string()?
+            )),
+            Expression::ArithmeticFunctionVariant(af) => af.try_to_sql_string(),
+            Expression::FunctionCallVariant(fc) => fc.try_to_sql_string(),
+            Expression::CaseExpressionVariant(ce) => ce.try_to_sql_string(),
+            Expression::MatchExpressionVariant(me) => me.try_to_sql_string(),
+            Expression::CastVariant(expr, sql_type) => Ok(format!(
+                "CAST({} AS {})",
+                expr.try_to_sql_string()?,
+                sql_type.to_sql_string()
+            )),
         }
     }
 }
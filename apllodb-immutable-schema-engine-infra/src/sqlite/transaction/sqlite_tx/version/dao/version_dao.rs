@@ -135,7 +135,7 @@ impl VersionDao {
           ({navi_rowid}{comma_if_non_pk_column_names}{non_pk_column_names})
           VALUES ({navi_rowid_val}{comma_if_non_pk_column_names}{non_pk_column_values})
         ", // FIXME might lead to SQL injection.
-            tname = sqlite_table_name.as_str(),
+            tname = sqlite_table_name.to_sql_string(),
             navi_rowid = CNAME_NAVI_ROWID,
             navi_rowid_val = vrr_id.0,
             comma_if_non_pk_column_names = if column_values.is_empty() { "" } else { ", " },
@@ -26,7 +26,7 @@ CREATE TABLE {table_name} (
     {non_pk_columns}
 )
         ",
-            table_name = version_table_name.as_str(),
+            table_name = version_table_name.to_sql_string(),
             navi_rowid = super::CNAME_NAVI_ROWID,
             comma_if_non_pk_columns = if version.column_data_types().is_empty() {
                 ""
@@ -1,6 +1,9 @@
 mod model;
 
-use crate::{error::InfraError, sqlite::transaction::sqlite_tx::SqliteTx};
+use crate::{
+    error::InfraError,
+    sqlite::{to_sql_string::quote_string, transaction::sqlite_tx::SqliteTx},
+};
 use model::VersionMetadataModel;
 use std::{cell::RefCell, rc::Rc};
 
@@ -69,7 +72,7 @@ CREATE TABLE {tname} (
             r#"
             SELECT {cname_table_name}, {cname_version_number}, {cname_column_data_types}, {cname_version_constraints}, {cname_is_active}
               FROM {tname}
-              WHERE {cname_table_name} = "{table_name}" AND {cname_is_active}
+              WHERE {cname_table_name} = {table_name} AND {cname_is_active}
               ORDER BY {cname_table_name}, {cname_version_number}
             "#,
             tname = TNAME,
@@ -78,7 +81,7 @@ CREATE TABLE {tname} (
             cname_column_data_types = CNAME_COLUMN_DATA_TYPES,
             cname_version_constraints = CNAME_VERSION_CONSTRAINTS,
             cname_is_active = CNAME_IS_ACTIVE,
-            table_name = vtable_id.table_name().as_str(),
+            table_name = quote_string(vtable_id.table_name().as_str()),
         );
 
         let tname = TableName::new(TNAME)?;
@@ -141,7 +144,7 @@ CREATE TABLE {tname} (
         let sql = format!(
             r#"
             INSERT INTO {tname} ({cname_table_name}, {cname_version_number}, {cname_column_data_types}, {cname_version_constraints}, {cname_is_active})
-              VALUES ("{table_name}", {version_number}, "{column_data_types}", "{version_constraints}", {is_active});
+              VALUES ({table_name}, {version_number}, {column_data_types}, {version_constraints}, {is_active});
             "#,
             tname = TNAME,
             cname_table_name = CNAME_TABLE_NAME,
@@ -149,10 +152,10 @@ CREATE TABLE {tname} (
             cname_column_data_types = CNAME_COLUMN_DATA_TYPES,
             cname_version_constraints = CNAME_VERSION_CONSTRAINTS,
             cname_is_active = CNAME_IS_ACTIVE,
-            table_name = model.serialized_table_name(),
+            table_name = quote_string(&model.serialized_table_name()),
             version_number = model.serialized_version_number(),
-            column_data_types = model.serialized_column_data_types()?,
-            version_constraints = model.serialized_version_constraints()?,
+            column_data_types = quote_string(&model.serialized_column_data_types()?),
+            version_constraints = quote_string(&model.serialized_version_constraints()?),
             is_active = model.serialized_is_active()
         );
 
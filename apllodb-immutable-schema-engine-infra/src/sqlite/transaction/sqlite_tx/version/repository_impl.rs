@@ -1,5 +1,6 @@
-use crate::sqlite::transaction::sqlite_tx::{
-    version_revision_resolver::VersionRevisionResolverImpl, SqliteTx,
+use crate::sqlite::{
+    to_sql_string::validate_distinct_identifiers,
+    transaction::sqlite_tx::{version_revision_resolver::VersionRevisionResolverImpl, SqliteTx},
 };
 use apllodb_immutable_schema_engine_domain::{
     entity::Entity,
@@ -33,8 +34,15 @@ impl VersionRepository for VersionRepositoryImpl {
     ///
     /// - [NameErrorDuplicate](apllodb_shared_components::SqlState::NameErrorDuplicate) when:
     ///   - Table `table_name` is already visible to this transaction.
+    ///   - 2 columns of `version` are the same except for case (SQLite cannot tell them apart).
     /// - Errors from [TableDao::create()](foobar.html).
     async fn create(&self, version: &ActiveVersion) -> ApllodbResult<()> {
+        validate_distinct_identifiers(
+            version
+                .column_data_types()
+                .iter()
+                .map(|cdt| cdt.column_name().as_str()),
+        )?;
         self.version_metadata_dao().insert(&version).await?;
         self.version_dao().create_table(&version).await?;
         Ok(())
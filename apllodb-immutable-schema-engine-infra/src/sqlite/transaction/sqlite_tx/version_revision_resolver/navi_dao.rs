@@ -5,7 +5,9 @@ mod navi_table_name;
 use std::{cell::RefCell, rc::Rc};
 
 use crate::sqlite::{
-    sqlite_rowid::SqliteRowid, sqlite_types::VrrEntries, to_sql_string::ToSqlString,
+    sqlite_rowid::SqliteRowid,
+    sqlite_types::VrrEntries,
+    to_sql_string::{ToSqlString, TryToSqlString},
     transaction::sqlite_tx::SqliteTx,
 };
 use apllodb_immutable_schema_engine_domain::{
@@ -123,7 +125,7 @@ SELECT {cname_rowid}, {cname_version_number}, {cname_revision}
             cname_version_number = CNAME_VERSION_NUMBER,
             navi_table_name = navi_table_name.to_sql_string(),
             vtable_name = vtable_id.table_name().to_sql_string(),
-            apk_condition = apk.to_condition_expression()?.to_sql_string(),
+            apk_condition = apk.to_condition_expression()?.try_to_sql_string()?,
         );
 
         let cdt_rowid = self.cdt_rowid();
@@ -198,7 +200,7 @@ SELECT {cname_rowid}, {cname_version_number}, {cname_revision}
                 vtable_name = vtable.table_name().to_sql_string(),
                 vrr_entry_condition = vrr_entry
                     .to_condition_expression(self.cdt_revision().column_name())?
-                    .to_sql_string(),
+                    .try_to_sql_string()?,
             );
 
             let _ = self.sqlite_tx.borrow_mut().execute(&sql).await?;
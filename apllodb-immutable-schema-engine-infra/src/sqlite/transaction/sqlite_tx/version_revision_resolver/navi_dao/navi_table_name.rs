@@ -1,7 +1,7 @@
 use apllodb_storage_engine_interface::TableName;
 use serde::{Deserialize, Serialize};
 
-use crate::sqlite::to_sql_string::ToSqlString;
+use crate::sqlite::to_sql_string::{quote_identifier, ToSqlString};
 
 const TNAME_SUFFIX: &str = "navi";
 
@@ -27,6 +27,6 @@ impl From<TableName> for NaviTableName {
 
 impl ToSqlString for NaviTableName {
     fn to_sql_string(&self) -> String {
-        format!("{}__{}", self.0.as_str(), TNAME_SUFFIX)
+        quote_identifier(&format!("{}__{}", self.0.as_str(), TNAME_SUFFIX))
     }
 }
@@ -20,11 +20,12 @@ use apllodb_immutable_schema_engine_domain::{
     vtable::{id::VTableId, VTable},
 };
 use apllodb_shared_components::{
-    ApllodbError, ApllodbResult, BooleanExpression, ComparisonFunction, Expression, SqlState,
-    SqlValue,
+    AdtType, ApllodbError, ApllodbResult, BooleanExpression, ComparisonFunction, Expression,
+    Schema, SqlState, SqlValue,
 };
 use apllodb_storage_engine_interface::{
-    Row, RowSchema, RowSelectionQuery, Rows, SingleTableCondition,
+    ColumnDataType, Row, RowProjectionQuery, RowSchema, RowSelectionQuery, Rows,
+    SingleTableCondition,
 };
 use async_trait::async_trait;
 
@@ -132,6 +133,36 @@ impl VTableRepository<SqliteTypes> for VTableRepositoryImpl {
 }
 
 impl VTableRepositoryImpl {
+    /// # Failures
+    ///
+    /// - [NameErrorDuplicate](apllodb_shared_components::SqlState::NameErrorDuplicate) when:
+    ///   - a table or a type named `adt_type.name()` is already visible to this transaction.
+    pub(crate) async fn create_type(&self, adt_type: &AdtType) -> ApllodbResult<()> {
+        self.vtable_metadata_dao().insert_type(adt_type).await
+    }
+
+    pub(crate) async fn list_types(&self) -> ApllodbResult<Vec<AdtType>> {
+        self.vtable_metadata_dao().select_types().await
+    }
+
+    /// Primary key columns and non-PK columns in any of active versions.
+    pub(crate) async fn list_columns(&self, vtable: &VTable) -> ApllodbResult<Vec<ColumnDataType>> {
+        let active_versions = self.active_versions(vtable).await?;
+
+        let mut columns = vtable
+            .table_wide_constraints()
+            .pk_column_data_types()
+            .to_vec();
+        for active_version in active_versions.as_sorted_slice() {
+            for cdt in active_version.column_data_types() {
+                if !columns.iter().any(|c| c.column_name() == cdt.column_name()) {
+                    columns.push(cdt.clone());
+                }
+            }
+        }
+        Ok(columns)
+    }
+
     fn vtable_metadata_dao(&self) -> VTableMetadataDao {
         VTableMetadataDao::new(self.tx.clone())
     }
@@ -149,18 +180,51 @@ impl VTableRepositoryImpl {
         vtable: &VTable,
         condition: SingleTableCondition,
     ) -> ApllodbResult<RowSelectionPlan> {
-        match self.try_condition_into_apk(vtable, &condition) {
-            Ok(apk) => {
-                let vrr_entries = self.vrr().probe(vtable.id(), vec![apk]).await?;
-                Ok(RowSelectionPlan::VrrProbe(vrr_entries))
-            }
+        let apks = match self.try_condition_into_apk(vtable, &condition) {
+            Ok(apk) => Ok(vec![apk]),
             Err(e) => match e.kind() {
                 SqlState::FeatureNotSupported => {
-                    Err(ApllodbError::feature_not_supported("for storage-engine selection, only expression like `pk_col = 123` is supported currently"))
+                    self.full_scan_apks_matching_condition(vtable, &condition)
+                        .await
                 }
-                _ => Err(e)
+                _ => Err(e),
+            },
+        }?;
+        let vrr_entries = self.vrr().probe(vtable.id(), apks).await?;
+        Ok(RowSelectionPlan::VrrProbe(vrr_entries))
+    }
+
+    /// Evaluates `condition` for every row in `vtable` and returns PKs of matching rows.
+    /// Rows whose condition is evaluated to NULL (UNKNOWN) do not match.
+    ///
+    /// Used for conditions which cannot be resolved by PK probe.
+    async fn full_scan_apks_matching_condition(
+        &self,
+        vtable: &VTable,
+        condition: &SingleTableCondition,
+    ) -> ApllodbResult<Vec<ApparentPrimaryKey>> {
+        let active_versions = self.active_versions(vtable).await?;
+        let projection =
+            RowProjectionResult::new(vtable, active_versions, &RowProjectionQuery::All)?;
+        let rows = self._full_scan(vtable, projection).await?;
+        let schema = rows.as_schema().clone();
+
+        let mut apks = Vec::<ApparentPrimaryKey>::new();
+        for mut row in rows {
+            let matched = condition
+                .as_expression()
+                .to_sql_value_for_expr_with_index(&|index| {
+                    let (pos, _) = schema.index(index)?;
+                    row.get_sql_value(pos).cloned()
+                })?
+                .to_bool()?;
+            if matched {
+                apks.push(ApparentPrimaryKey::from_table_and_row(
+                    vtable, &schema, &mut row,
+                )?);
             }
         }
+        Ok(apks)
     }
 
     /// # Failures
@@ -192,35 +256,25 @@ impl VTableRepositoryImpl {
         let expr = condition.as_expression();
         match expr {
             Expression::BooleanExpressionVariant(BooleanExpression::ComparisonFunctionVariant(
-                cf,
-            )) => match cf {
-                ComparisonFunction::EqualVariant { left, right } => {
-                    match (left.as_ref(), right.as_ref()) {
-                        (
-                            Expression::ConstantVariant(sql_value),
-                            Expression::SchemaIndexVariant(index),
-                        )
-                        | (
-                            Expression::SchemaIndexVariant(index),
-                            Expression::ConstantVariant(sql_value),
-                        ) => {
-                            if index.attr() == pk_column_name.as_str() {
-                                if let SqlValue::NotNull(nn_sql_value) = sql_value {
-                                    Ok(ApparentPrimaryKey::new(
-                                        vtable.table_name().clone(),
-                                        vec![pk_column_name.clone()],
-                                        vec![nn_sql_value.clone()],
-                                    ))
-                                } else {
-                                    Err(err)
-                                }
-                            } else {
-                                Err(err)
-                            }
+                ComparisonFunction::EqualVariant { left, right },
+            )) => match (left.as_ref(), right.as_ref()) {
+                (Expression::ConstantVariant(sql_value), Expression::SchemaIndexVariant(index))
+                | (Expression::SchemaIndexVariant(index), Expression::ConstantVariant(sql_value)) => {
+                    if index.attr() == pk_column_name.as_str() {
+                        if let SqlValue::NotNull(nn_sql_value) = sql_value {
+                            Ok(ApparentPrimaryKey::new(
+                                vtable.table_name().clone(),
+                                vec![pk_column_name.clone()],
+                                vec![nn_sql_value.clone()],
+                            ))
+                        } else {
+                            Err(err)
                         }
-                        _ => Err(err),
+                    } else {
+                        Err(err)
                     }
                 }
+                _ => Err(err),
             },
             _ => Err(err),
         }
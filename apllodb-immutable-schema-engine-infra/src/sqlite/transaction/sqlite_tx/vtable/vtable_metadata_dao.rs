@@ -2,16 +2,23 @@ use std::{cell::RefCell, rc::Rc};
 
 use crate::{
     error::InfraError,
-    sqlite::{to_sql_string::ToSqlString, transaction::sqlite_tx::SqliteTx},
+    sqlite::{
+        to_sql_string::{quote_string, validate_distinct_identifiers},
+        transaction::sqlite_tx::SqliteTx,
+    },
 };
 use apllodb_immutable_schema_engine_domain::vtable::{
     constraints::TableWideConstraints, id::VTableId, VTable,
 };
 use apllodb_shared_components::{
-    ApllodbError, ApllodbResult, Schema, SchemaIndex, SqlState, SqlType,
+    AdtType, ApllodbError, ApllodbResult, Schema, SchemaIndex, SqlState, SqlType,
 };
 use apllodb_storage_engine_interface::{ColumnDataType, ColumnName, TableName};
 
+/// Metadata of tables and user-defined types.
+///
+/// Tables and types share the same namespace (a type cannot have the same name as a table).
+/// A table's row has `table_wide_constraints`, while a type's row has `type_definition`.
 #[derive(Debug)]
 pub(in crate::sqlite) struct VTableMetadataDao {
     sqlite_tx: Rc<RefCell<SqliteTx>>,
@@ -20,6 +27,7 @@ pub(in crate::sqlite) struct VTableMetadataDao {
 const TNAME: &str = "_vtable_metadata";
 const CNAME_TABLE_NAME: &str = "table_name";
 const CNAME_TABLE_WIDE_CONSTRAINTS: &str = "table_wide_constraints";
+const CNAME_TYPE_DEFINITION: &str = "type_definition";
 
 impl VTableMetadataDao {
     pub(in crate::sqlite) async fn create_table(
@@ -29,10 +37,11 @@ impl VTableMetadataDao {
             "
 CREATE TABLE {} (
   {} TEXT PRIMARY KEY,
-  {} TEXT NOT NULL
+  {} TEXT,
+  {} TEXT
 )
         ",
-            TNAME, CNAME_TABLE_NAME, CNAME_TABLE_WIDE_CONSTRAINTS
+            TNAME, CNAME_TABLE_NAME, CNAME_TABLE_WIDE_CONSTRAINTS, CNAME_TYPE_DEFINITION
         );
 
         sqlx::query(&sql)
@@ -69,12 +78,13 @@ CREATE TABLE {} (
         vtable_id: &VTableId,
     ) -> ApllodbResult<VTable> {
         let sql = format!(
-            "SELECT {}, {} FROM {} WHERE {} = \"{}\";",
+            "SELECT {}, {} FROM {} WHERE {} = {} AND {} IS NOT NULL;",
             CNAME_TABLE_NAME,
             CNAME_TABLE_WIDE_CONSTRAINTS,
             TNAME,
             CNAME_TABLE_NAME,
-            vtable_id.table_name().to_sql_string(),
+            quote_string(vtable_id.table_name().as_str()),
+            CNAME_TABLE_WIDE_CONSTRAINTS,
         );
 
         let tname = TableName::new(TNAME)?;
@@ -122,9 +132,13 @@ CREATE TABLE {} (
     ///   - transaction lock to metadata table takes too long time.
     /// - [NameErrorDuplicate](apllodb_shared_components::SqlState::NameErrorDuplicate) when:
     ///   - `table` is already created.
+    ///   - a table whose name is the same except for case is already created (SQLite cannot tell them apart).
     /// - [SystemError](apllodb_shared_components::SqlState::SystemError) when:
     ///   - Somehow failed to serialize part of [VTable](foobar.html).
     async fn insert_into_vtable_metadata(&self, vtable: &VTable) -> ApllodbResult<()> {
+        self.validate_table_name_distinct(vtable.table_name())
+            .await?;
+
         let table_wide_constraints = vtable.table_wide_constraints();
         let table_wide_constraints_str =
             serde_yaml::to_string(table_wide_constraints).map_err(|e| {
@@ -140,13 +154,107 @@ CREATE TABLE {} (
 
         let sql = format!(
             "
-            INSERT INTO {} ({}, {}) VALUES (\"{table_name}\", \"{table_wide_constraints}\");
+            INSERT INTO {} ({}, {}) VALUES ({table_name}, {table_wide_constraints});
             ",
             TNAME,
             CNAME_TABLE_NAME,
             CNAME_TABLE_WIDE_CONSTRAINTS,
-            table_name = vtable.table_name().to_sql_string(),
-            table_wide_constraints = table_wide_constraints_str
+            table_name = quote_string(vtable.table_name().as_str()),
+            table_wide_constraints = quote_string(&table_wide_constraints_str)
+        );
+
+        self.sqlite_tx
+            .borrow_mut()
+            .execute(&sql)
+            .await
+            .map_err(|e| match e.kind() {
+                SqlState::IntegrityConstraintUniqueViolation => {
+                    ApllodbError::name_error_duplicate(format!(
+                        "table or type `{:?}` is already created",
+                        vtable.table_name()
+                    ))
+                }
+                _ => e,
+            })?;
+
+        Ok(())
+    }
+
+    /// # Failures
+    ///
+    /// - [NameErrorDuplicate](apllodb_shared_components::SqlState::NameErrorDuplicate) when:
+    ///   - a table whose name is the same as `table_name` except for case is already created.
+    ///
+    /// A table of exactly the same name is reported on insertion into the metadata table.
+    async fn validate_table_name_distinct(&self, table_name: &TableName) -> ApllodbResult<()> {
+        let sql = format!(
+            "SELECT {}, {} FROM {} WHERE {} = {} COLLATE NOCASE AND {} <> {} AND {} IS NOT NULL;",
+            CNAME_TABLE_NAME,
+            CNAME_TABLE_WIDE_CONSTRAINTS,
+            TNAME,
+            CNAME_TABLE_NAME,
+            quote_string(table_name.as_str()),
+            CNAME_TABLE_NAME,
+            quote_string(table_name.as_str()),
+            CNAME_TABLE_WIDE_CONSTRAINTS,
+        );
+
+        let tname = TableName::new(TNAME)?;
+
+        let mut rows = self
+            .sqlite_tx
+            .borrow_mut()
+            .query(
+                &sql,
+                &tname,
+                &[&self.cdt_table_name(), &self.cdt_table_wide_constraints()],
+                &[],
+            )
+            .await?;
+
+        let (pos_table_name, _) = rows
+            .as_schema()
+            .index(&SchemaIndex::from(CNAME_TABLE_NAME))?;
+
+        match rows.next() {
+            Some(row) => {
+                let existing_table_name: String =
+                    row.get(pos_table_name)?.expect("must be NOT NULL");
+                validate_distinct_identifiers(vec![
+                    existing_table_name.as_str(),
+                    table_name.as_str(),
+                ])
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// # Failures
+    ///
+    /// - [NameErrorDuplicate](apllodb_shared_components::SqlState::NameErrorDuplicate) when:
+    ///   - a table or a type named `adt_type.name()` is already created.
+    /// - [SystemError](apllodb_shared_components::SqlState::SystemError) when:
+    ///   - Somehow failed to serialize `adt_type`.
+    pub(in crate::sqlite::transaction::sqlite_tx) async fn insert_type(
+        &self,
+        adt_type: &AdtType,
+    ) -> ApllodbResult<()> {
+        let type_definition_str = serde_yaml::to_string(adt_type).map_err(|e| {
+            ApllodbError::system_error(
+                format!("failed to serialize type `{}`", adt_type.name()),
+                Box::new(e),
+            )
+        })?;
+
+        let sql = format!(
+            "
+            INSERT INTO {} ({}, {}) VALUES ({type_name}, {type_definition});
+            ",
+            TNAME,
+            CNAME_TABLE_NAME,
+            CNAME_TYPE_DEFINITION,
+            type_name = quote_string(adt_type.name()),
+            type_definition = quote_string(&type_definition_str)
         );
 
         self.sqlite_tx
@@ -155,7 +263,7 @@ CREATE TABLE {} (
             .await
             .map_err(|e| match e.kind() {
                 SqlState::IntegrityConstraintUniqueViolation => ApllodbError::name_error_duplicate(
-                    format!("table `{:?}` is already created", vtable.table_name()),
+                    format!("table or type `{}` is already created", adt_type.name()),
                 ),
                 _ => e,
             })?;
@@ -163,6 +271,56 @@ CREATE TABLE {} (
         Ok(())
     }
 
+    /// All the types visible from this transaction.
+    ///
+    /// # Failures
+    ///
+    /// - [SystemError](apllodb_shared_components::SqlState::SystemError) when:
+    ///   - Somehow failed to deserialize a type definition.
+    pub(in crate::sqlite::transaction::sqlite_tx) async fn select_types(
+        &self,
+    ) -> ApllodbResult<Vec<AdtType>> {
+        let sql = format!(
+            "SELECT {} FROM {} WHERE {} IS NOT NULL ORDER BY {};",
+            CNAME_TYPE_DEFINITION, TNAME, CNAME_TYPE_DEFINITION, CNAME_TABLE_NAME,
+        );
+
+        let tname = TableName::new(TNAME)?;
+
+        let rows = self
+            .sqlite_tx
+            .borrow_mut()
+            .query(&sql, &tname, &[&self.cdt_type_definition()], &[])
+            .await?;
+
+        let (pos_type_definition, _) = rows
+            .as_schema()
+            .index(&SchemaIndex::from(CNAME_TYPE_DEFINITION))?;
+
+        rows.map(|row| {
+            let type_definition_str: String =
+                row.get(pos_type_definition)?.expect("must be NOT NULL");
+            serde_yaml::from_str(&type_definition_str).map_err(|e| {
+                ApllodbError::system_error(
+                    format!(
+                        "failed to deserialize type definition: `{:?}`",
+                        type_definition_str
+                    ),
+                    Box::new(e),
+                )
+            })
+        })
+        .collect()
+    }
+
+    fn cdt_table_name(&self) -> ColumnDataType {
+        ColumnDataType::new(
+            ColumnName::new(CNAME_TABLE_NAME).unwrap(),
+            SqlType::text(),
+            false,
+        )
+    }
+
     fn cdt_table_wide_constraints(&self) -> ColumnDataType {
         ColumnDataType::new(
             ColumnName::new(CNAME_TABLE_WIDE_CONSTRAINTS).unwrap(),
@@ -170,4 +328,12 @@ CREATE TABLE {} (
             false,
         )
     }
+
+    fn cdt_type_definition(&self) -> ColumnDataType {
+        ColumnDataType::new(
+            ColumnName::new(CNAME_TYPE_DEFINITION).unwrap(),
+            SqlType::text(),
+            false,
+        )
+    }
 }
@@ -3,27 +3,39 @@ mod use_case;
 
 use apllodb_immutable_schema_engine::ApllodbImmutableSchemaEngine;
 use apllodb_shared_components::{ApllodbSessionResult, Session};
-use apllodb_sql_processor::SqlProcessorContext;
+use apllodb_sql_processor::{FunctionRegistry, SqlProcessorContext};
 
-use std::sync::Arc;
+use std::rc::Rc;
 use use_case::UseCase;
 
-use crate::ApllodbCommandSuccess;
+use crate::{ApllodbCommandSuccess, ApllodbScriptSuccess};
 
 #[derive(Clone, Debug)]
 pub struct ApllodbServer {
-    context: Arc<SqlProcessorContext<ApllodbImmutableSchemaEngine>>,
+    context: Rc<SqlProcessorContext<ApllodbImmutableSchemaEngine>>,
 }
 
 impl Default for ApllodbServer {
     fn default() -> Self {
         let engine = ApllodbImmutableSchemaEngine::default();
-        let context = Arc::new(SqlProcessorContext::new(engine));
+        let context = Rc::new(SqlProcessorContext::new(engine));
         Self { context }
     }
 }
 
 impl ApllodbServer {
+    /// Server whose SQL can call functions registered in `function_registry`.
+    ///
+    /// Pass `FunctionRegistry::default()` with additional functions registered to keep built-in functions callable.
+    pub fn with_function_registry(function_registry: FunctionRegistry) -> Self {
+        let engine = ApllodbImmutableSchemaEngine::default();
+        let context = Rc::new(SqlProcessorContext::with_function_registry(
+            engine,
+            function_registry,
+        ));
+        Self { context }
+    }
+
     pub async fn command(
         &self,
         session: Session,
@@ -32,6 +44,18 @@ impl ApllodbServer {
         self.use_case().command(session, &sql).await
     }
 
+    /// Runs semicolon-separated commands in `sql` (e.g. a migration file) one by one, passing the session from one command to the next.
+    ///
+    /// Nothing is run if any command has a syntax error.
+    /// If a command fails, the commands before it are not rolled back and the error has the session at the failure.
+    pub async fn command_script(
+        &self,
+        session: Session,
+        sql: String,
+    ) -> ApllodbSessionResult<ApllodbScriptSuccess> {
+        self.use_case().command_script(session, &sql).await
+    }
+
     fn use_case(&self) -> UseCase<ApllodbImmutableSchemaEngine> {
         UseCase::new(self.context.clone())
     }
@@ -1,5 +1,5 @@
 use apllodb_shared_components::{Session, SessionWithDb, SessionWithTx};
-use apllodb_sql_processor::Records;
+use apllodb_sql_processor::{Records, SqlProcessorScriptSuccess};
 
 /// Successful response from apllodb-server's [command()](crate::ApllodbServer::command).
 #[derive(Debug)]
@@ -27,3 +27,21 @@ pub enum ApllodbCommandSuccess {
         session: SessionWithDb,
     },
 }
+
+/// Successful response from apllodb-server's [command_script()](crate::ApllodbServer::command_script).
+#[derive(Debug)]
+pub struct ApllodbScriptSuccess {
+    /// Session after the last command
+    pub session: Session,
+    /// Result records of SELECT commands in order
+    pub records: Vec<Records>,
+}
+
+impl From<SqlProcessorScriptSuccess> for ApllodbScriptSuccess {
+    fn from(s: SqlProcessorScriptSuccess) -> Self {
+        Self {
+            session: s.session,
+            records: s.records,
+        }
+    }
+}
@@ -1,18 +1,18 @@
 mod sql_processor_response;
 
-use std::sync::Arc;
+use std::rc::Rc;
 
 use apllodb_shared_components::{ApllodbSessionResult, Session};
 use apllodb_sql_processor::{SqlProcessor, SqlProcessorContext};
 use apllodb_storage_engine_interface::StorageEngine;
 
-use crate::ApllodbCommandSuccess;
+use crate::{ApllodbCommandSuccess, ApllodbScriptSuccess};
 
 use self::sql_processor_response::to_server_resp;
 
 #[derive(Clone, Debug, new)]
 pub(in crate::apllodb_server) struct UseCase<Engine: StorageEngine> {
-    context: Arc<SqlProcessorContext<Engine>>,
+    context: Rc<SqlProcessorContext<Engine>>,
 }
 
 impl<Engine: StorageEngine> UseCase<Engine> {
@@ -25,4 +25,14 @@ impl<Engine: StorageEngine> UseCase<Engine> {
         let sql_proc_succ = sql_proc.run(session, sql).await?;
         Ok(to_server_resp(sql_proc_succ))
     }
+
+    pub(in crate::apllodb_server) async fn command_script(
+        &self,
+        session: Session,
+        sql: &str,
+    ) -> ApllodbSessionResult<ApllodbScriptSuccess> {
+        let sql_proc = SqlProcessor::new(self.context.clone());
+        let sql_proc_succ = sql_proc.run_script(session, sql).await?;
+        Ok(ApllodbScriptSuccess::from(sql_proc_succ))
+    }
 }
@@ -26,11 +26,18 @@ extern crate derive_new;
 mod apllodb_server;
 
 // re-export from apllodb-shared-components
-pub use crate::apllodb_server::{response::success::ApllodbCommandSuccess, ApllodbServer};
+pub use crate::apllodb_server::{
+    response::success::{ApllodbCommandSuccess, ApllodbScriptSuccess},
+    ApllodbServer,
+};
 pub use apllodb_shared_components::{
-    ApllodbResult, ApllodbSessionResult, SchemaIndex, Session, SqlState,
+    AdtValue, ApllodbError, ApllodbResult, ApllodbSessionResult, ArrayValue, Decimal, FuzzyDate,
+    Interval, Json, NnSqlValue, SchemaIndex, Session, SqlState, SqlType, SqlValue,
+};
+pub use apllodb_sql_processor::{
+    FunctionRegistry, FunctionSignature, ParameterType, Record, RecordIndex, Records, ReturnType,
+    ScalarFunction,
 };
-pub use apllodb_sql_processor::{Record, RecordIndex, Records};
 
 #[cfg(any(test, feature = "test-support"))]
 pub mod test_support;
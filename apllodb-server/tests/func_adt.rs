@@ -0,0 +1,272 @@
+mod sql_test;
+
+use apllodb_server::{
+    test_support::test_setup, AdtValue, RecordIndex, SchemaIndex, SqlState, SqlType, SqlValue,
+};
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+use sql_test::{SqlTest, Step, StepRes, Steps};
+
+#[ctor::ctor]
+fn setup() {
+    test_setup();
+}
+
+fn setup_steps(sql_test: SqlTest) -> SqlTest {
+    sql_test
+        .add_steps(Steps::BeginTransaction)
+        .add_step(Step::new(
+            "CREATE TYPE shape AS VARIANT (circle(r INTEGER), rect(w INTEGER, h INTEGER), dot)",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "CREATE TABLE figure (id BIGINT NOT NULL, s shape, PRIMARY KEY (id))",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "INSERT INTO figure (id, s) VALUES
+              (1, circle(2)),
+              (2, rect(3, 4)),
+              (3, dot()),
+              (4, NULL)",
+            StepRes::Ok,
+        ))
+}
+
+fn int_column(records: apllodb_server::Records, column: &'static str) -> Vec<(i64, Option<i32>)> {
+    records
+        .map(|r| {
+            (
+                r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                    .unwrap()
+                    .unwrap(),
+                r.get::<i32>(&RecordIndex::Name(SchemaIndex::from(column)))
+                    .unwrap(),
+            )
+        })
+        .sorted_by_key(|row| row.0)
+        .collect()
+}
+
+#[async_std::test]
+async fn test_adt_persistence() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT id, s FROM figure",
+            StepRes::OkQuery(Box::new(|records| {
+                let shapes: Vec<Option<String>> = records
+                    .map(|r| {
+                        (
+                            r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                                .unwrap()
+                                .unwrap(),
+                            r.get::<AdtValue>(&RecordIndex::Name(SchemaIndex::from("s")))
+                                .unwrap(),
+                        )
+                    })
+                    .sorted_by_key(|row| row.0)
+                    .map(|row| row.1.map(|v| v.to_string()))
+                    .collect();
+                assert_eq!(
+                    shapes,
+                    vec![
+                        Some("circle(2)".to_string()),
+                        Some("rect(3, 4)".to_string()),
+                        Some("dot".to_string()),
+                        None,
+                    ]
+                );
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM figure WHERE s = rect(3, 4)",
+            StepRes::OkQuery(Box::new(|records| {
+                let ids: Vec<i64> = records
+                    .map(|r| {
+                        r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                            .unwrap()
+                            .unwrap()
+                    })
+                    .collect();
+                assert_eq!(ids, vec![2]);
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_match() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT id, MATCH s WITH circle(r) -> r * r * 3 | rect(w, h) -> w * h | dot -> 0 END AS area FROM figure",
+            StepRes::OkQuery(Box::new(|records| {
+                // every arm results in INTEGER
+                let types: Vec<SqlType> = records
+                    .clone()
+                    .flat_map(|r| r.into_name_values())
+                    .filter(|(name, _)| name == "area")
+                    .filter_map(|(_, v)| match v {
+                        SqlValue::NotNull(nn_sql_value) => Some(nn_sql_value.sql_type()),
+                        SqlValue::Null => None,
+                    })
+                    .collect();
+                assert_eq!(types, vec![SqlType::integer(); 3]);
+                assert_eq!(
+                    int_column(records, "area"),
+                    vec![(1, Some(12)), (2, Some(12)), (3, Some(0)), (4, None)]
+                );
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id, MATCH s WITH rect(_, h) -> h | _ -> -1 END AS h FROM figure",
+            StepRes::OkQuery(Box::new(|records| {
+                assert_eq!(
+                    int_column(records, "h"),
+                    vec![(1, Some(-1)), (2, Some(4)), (3, Some(-1)), (4, None)]
+                );
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_nullary_variant_values() {
+    SqlTest::default()
+        .add_steps(Steps::BeginTransaction)
+        .add_step(Step::new(
+            "CREATE TYPE color AS ENUM (red, green)",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "CREATE TABLE paint (id BIGINT NOT NULL, c color, PRIMARY KEY (id))",
+            StepRes::Ok,
+        ))
+        // a bare variant name is the same as `red()`
+        .add_step(Step::new(
+            "INSERT INTO paint (id, c) VALUES (1, red), (2, green()), (3, red())",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "UPDATE paint SET c = green WHERE id = 3",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "SELECT id, c FROM paint",
+            StepRes::OkQuery(Box::new(|records| {
+                let colors: Vec<(i64, String)> = records
+                    .map(|r| {
+                        (
+                            r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                                .unwrap()
+                                .unwrap(),
+                            r.get::<AdtValue>(&RecordIndex::Name(SchemaIndex::from("c")))
+                                .unwrap()
+                                .unwrap()
+                                .to_string(),
+                        )
+                    })
+                    .sorted_by_key(|row| row.0)
+                    .collect();
+                assert_eq!(
+                    colors,
+                    vec![
+                        (1, "red".to_string()),
+                        (2, "green".to_string()),
+                        (3, "green".to_string()),
+                    ]
+                );
+                Ok(())
+            })),
+        ))
+        // not a variant of the column's type
+        .add_step(Step::new(
+            "INSERT INTO paint (id, c) VALUES (4, blue)",
+            StepRes::Err(SqlState::NameErrorNotFound),
+        ))
+        .run()
+        .await;
+
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "INSERT INTO figure (id, s) VALUES (5, dot)",
+            StepRes::Ok,
+        ))
+        // a variant with fields needs them
+        .add_step(Step::new(
+            "INSERT INTO figure (id, s) VALUES (6, circle)",
+            StepRes::Err(SqlState::NameErrorNotFound),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_adt_errors() {
+    setup_steps(SqlTest::default())
+        // non-exhaustive
+        .add_step(Step::new(
+            "SELECT MATCH s WITH circle(r) -> r | dot -> 0 END FROM figure",
+            StepRes::Err(SqlState::DataExceptionIllegalOperation),
+        ))
+        // wrong number of bindings
+        .add_step(Step::new(
+            "SELECT MATCH s WITH circle(r, x) -> r | _ -> 0 END FROM figure",
+            StepRes::Err(SqlState::DataExceptionIllegalOperation),
+        ))
+        // unknown variant
+        .add_step(Step::new(
+            "SELECT MATCH s WITH triangle(a) -> a | _ -> 0 END FROM figure",
+            StepRes::Err(SqlState::NameErrorNotFound),
+        ))
+        .add_step(Step::new(
+            "INSERT INTO figure (id, s) VALUES (5, triangle(1))",
+            StepRes::Err(SqlState::NameErrorNotFound),
+        ))
+        // wrong number of fields
+        .add_step(Step::new(
+            "INSERT INTO figure (id, s) VALUES (5, circle(1, 2))",
+            StepRes::Err(SqlState::DataExceptionIllegalOperation),
+        ))
+        // not a value of the column's type
+        .add_step(Step::new(
+            "INSERT INTO figure (id, s) VALUES (6, 7)",
+            StepRes::Err(SqlState::SyntaxErrorOrAccessRuleViolationDatatypeMismatch),
+        ))
+        .add_step(Step::new(
+            "UPDATE figure SET s = 'circle(1)' WHERE id = 1",
+            StepRes::Err(SqlState::SyntaxErrorOrAccessRuleViolationDatatypeMismatch),
+        ))
+        // unknown type
+        .add_step(Step::new(
+            "CREATE TABLE t (id BIGINT NOT NULL, p polygon, PRIMARY KEY (id))",
+            StepRes::Err(SqlState::NameErrorNotFound),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_create_type_errors() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "CREATE TYPE shape AS ENUM (square)",
+            StepRes::Err(SqlState::NameErrorDuplicate),
+        ))
+        .run()
+        .await;
+
+    SqlTest::default()
+        .add_steps(Steps::BeginTransaction)
+        .add_step(Step::new(
+            "CREATE TYPE color AS ENUM (red, green, red)",
+            StepRes::Err(SqlState::NameErrorDuplicate),
+        ))
+        .run()
+        .await;
+}
@@ -0,0 +1,299 @@
+mod sql_test;
+
+use std::str::FromStr;
+
+use apllodb_server::{test_support::test_setup, Decimal, RecordIndex, SchemaIndex, SqlState};
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+use sql_test::{SqlTest, Step, StepRes, Steps};
+
+#[ctor::ctor]
+fn setup() {
+    test_setup();
+}
+
+fn idx(name: &str) -> RecordIndex {
+    RecordIndex::Name(SchemaIndex::from(name))
+}
+
+fn setup_steps(sql_test: SqlTest) -> SqlTest {
+    sql_test
+        .add_steps(Steps::BeginTransaction)
+        .add_step(Step::new(
+            "CREATE TABLE score (id INTEGER NOT NULL, team TEXT, point INTEGER, PRIMARY KEY (id))",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "INSERT INTO score (id, team, point) VALUES (1, 'a', 10), (2, 'a', 20), (3, 'a', 20), (4, 'b', 5), (5, 'b', NULL), (6, NULL, 7), (7, NULL, NULL)",
+            StepRes::Ok,
+        ))
+}
+
+#[async_std::test]
+async fn test_aggregate_without_group_by() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT COUNT(*) AS cnt_all, COUNT(point) AS cnt, COUNT(DISTINCT point) AS cnt_distinct, SUM(point) AS s, SUM(DISTINCT point) AS s_distinct, MIN(point) AS mn, MAX(point) AS mx FROM score",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(r.get::<i64>(&idx("cnt_all"))?, Some(7));
+                assert_eq!(r.get::<i64>(&idx("cnt"))?, Some(5));
+                assert_eq!(r.get::<i64>(&idx("cnt_distinct"))?, Some(4));
+                assert_eq!(r.get::<i64>(&idx("s"))?, Some(62));
+                assert_eq!(r.get::<i64>(&idx("s_distinct"))?, Some(42));
+                assert_eq!(r.get::<i32>(&idx("mn"))?, Some(5));
+                assert_eq!(r.get::<i32>(&idx("mx"))?, Some(20));
+                assert!(records.next().is_none());
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT AVG(point) AS a FROM score WHERE team = 'a'",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                let avg = r.get::<Decimal>(&idx("a"))?.unwrap();
+                assert_eq!(avg.to_string().parse::<f64>().unwrap(), 50.0 / 3.0);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT COUNT(*) + 1 AS cnt, MAX(point) - MIN(point) AS diff FROM score WHERE team = 'a'",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(r.get::<i64>(&idx("cnt"))?, Some(4));
+                assert_eq!(r.get::<i32>(&idx("diff"))?, Some(10));
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // aggregation over no record still yields a record
+            "SELECT COUNT(*) AS cnt, SUM(point) AS s, MAX(team) AS mx FROM score WHERE id > 100",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(r.get::<i64>(&idx("cnt"))?, Some(0));
+                assert_eq!(r.get::<i64>(&idx("s"))?, None);
+                assert_eq!(r.get::<String>(&idx("mx"))?, None);
+                assert!(records.next().is_none());
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_group_by() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT team, COUNT(*) AS cnt, SUM(point) AS s FROM score GROUP BY team",
+            StepRes::OkQuery(Box::new(|records| {
+                let rows: Vec<(Option<String>, i64, Option<i64>)> = records
+                    .map(|r| {
+                        (
+                            r.get::<String>(&idx("team")).unwrap(),
+                            r.get::<i64>(&idx("cnt")).unwrap().unwrap(),
+                            r.get::<i64>(&idx("s")).unwrap(),
+                        )
+                    })
+                    .sorted()
+                    .collect();
+                assert_eq!(
+                    rows,
+                    vec![
+                        // NULLs form a group
+                        (None, 2, Some(7)),
+                        (Some("a".to_string()), 3, Some(50)),
+                        (Some("b".to_string()), 2, Some(5)),
+                    ]
+                );
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT team, MAX(score.point) AS mx FROM score WHERE id < 5 GROUP BY score.team ORDER BY team DESC",
+            StepRes::OkQuery(Box::new(|records| {
+                let rows: Vec<(Option<String>, Option<i32>)> = records
+                    .map(|r| {
+                        (
+                            r.get::<String>(&idx("team")).unwrap(),
+                            r.get::<i32>(&idx("mx")).unwrap(),
+                        )
+                    })
+                    .collect();
+                assert_eq!(
+                    rows,
+                    vec![
+                        (Some("b".to_string()), Some(5)),
+                        (Some("a".to_string()), Some(20)),
+                    ]
+                );
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // grouping column does not have to be selected
+            "SELECT COUNT(point) AS cnt FROM score GROUP BY team",
+            StepRes::OkQuery(Box::new(|records| {
+                let counts: Vec<i64> = records
+                    .map(|r| r.get::<i64>(&idx("cnt")).unwrap().unwrap())
+                    .sorted()
+                    .collect();
+                assert_eq!(counts, vec![1, 1, 3]);
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_group_by_fuzzy_date() {
+    SqlTest::default()
+        .add_steps(Steps::BeginTransaction)
+        .add_step(Step::new(
+            "CREATE TABLE person (id BIGINT NOT NULL, born FUZZY DATE, PRIMARY KEY (id))",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "INSERT INTO person (id, born) VALUES
+              (1, FUZZY DATE '1920s'), (2, FUZZY DATE '1920s'), (3, FUZZY DATE '1925'), (4, NULL), (5, NULL)",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            // ambiguous values are grouped together although `=` is not TRUE for them
+            "SELECT born, COUNT(*) AS n FROM person GROUP BY born",
+            StepRes::OkQuery(Box::new(|records| {
+                let ns: Vec<i64> = records
+                    .map(|r| r.get::<i64>(&idx("n")).unwrap().unwrap())
+                    .sorted()
+                    .collect();
+                assert_eq!(ns, vec![1, 2, 2]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT COUNT(DISTINCT born) AS n FROM person",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(r.get::<i64>(&idx("n"))?, Some(2));
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_having() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT team, COUNT(*) AS cnt FROM score WHERE team IS NOT NULL GROUP BY team HAVING SUM(point) > 10",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(r.get::<String>(&idx("team"))?, Some("a".to_string()));
+                assert_eq!(r.get::<i64>(&idx("cnt"))?, Some(3));
+                assert!(records.next().is_none());
+                Ok(())
+            })),
+        ))
+        // aliases of select fields
+        .add_step(Step::new(
+            "SELECT team, SUM(point) AS total FROM score WHERE team IS NOT NULL GROUP BY team ORDER BY total DESC",
+            StepRes::OkQuery(Box::new(|records| {
+                let rows: Vec<(Option<String>, Option<i64>)> = records
+                    .map(|r| (r.get::<String>(&idx("team")).unwrap(), r.get::<i64>(&idx("total")).unwrap()))
+                    .collect();
+                assert_eq!(
+                    rows,
+                    vec![(Some("a".to_string()), Some(50)), (Some("b".to_string()), Some(5))]
+                );
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT team AS t, SUM(point) AS total FROM score GROUP BY team HAVING total < 10 AND t IS NOT NULL",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(r.get::<String>(&idx("t"))?, Some("b".to_string()));
+                assert!(records.next().is_none());
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT team FROM score GROUP BY team HAVING COUNT(point) = 1 AND team IS NOT NULL",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(r.get::<String>(&idx("team"))?, Some("b".to_string()));
+                assert!(records.next().is_none());
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_aggregate_errors() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT team, point FROM score GROUP BY team",
+            StepRes::Err(SqlState::SyntaxErrorOrAccessRuleViolationGroupingError),
+        ))
+        .add_step(Step::new(
+            "SELECT team, COUNT(*) FROM score",
+            StepRes::Err(SqlState::SyntaxErrorOrAccessRuleViolationGroupingError),
+        ))
+        .add_step(Step::new(
+            "SELECT team FROM score GROUP BY team HAVING point > 1",
+            StepRes::Err(SqlState::SyntaxErrorOrAccessRuleViolationGroupingError),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM score WHERE COUNT(*) > 1",
+            StepRes::Err(SqlState::SyntaxErrorOrAccessRuleViolationGroupingError),
+        ))
+        .add_step(Step::new(
+            "SELECT MAX(COUNT(*)) FROM score",
+            StepRes::Err(SqlState::SyntaxErrorOrAccessRuleViolationGroupingError),
+        ))
+        .add_step(Step::new(
+            "SELECT SUM(*) FROM score",
+            StepRes::Err(SqlState::NameErrorNotFound),
+        ))
+        .add_step(Step::new(
+            "SELECT SUM(team) FROM score",
+            StepRes::Err(SqlState::DataExceptionIllegalOperation),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_aggregate_pet() {
+    SqlTest::default()
+        .add_steps(Steps::SetupPetDataset)
+        .add_step(Step::new("BEGIN", StepRes::Ok))
+        .add_step(Step::new(
+            "SELECT kind, AVG(age) AS a FROM pet GROUP BY kind",
+            StepRes::OkQuery(Box::new(|records| {
+                let rows: Vec<(String, Decimal)> = records
+                    .map(|r| {
+                        (
+                            r.get::<String>(&idx("kind")).unwrap().unwrap(),
+                            r.get::<Decimal>(&idx("a")).unwrap().unwrap(),
+                        )
+                    })
+                    .sorted_by_key(|row| row.0.clone())
+                    .collect();
+                assert_eq!(
+                    rows,
+                    vec![
+                        ("cat".to_string(), Decimal::from_str("3").unwrap()),
+                        ("dog".to_string(), Decimal::from_str("9").unwrap()),
+                    ]
+                );
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
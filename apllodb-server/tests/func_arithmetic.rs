@@ -0,0 +1,192 @@
+mod sql_test;
+
+use apllodb_server::{test_support::test_setup, RecordIndex, SchemaIndex, SqlState};
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+use sql_test::{SqlTest, Step, StepRes, Steps};
+
+#[ctor::ctor]
+fn setup() {
+    test_setup();
+}
+
+#[async_std::test]
+async fn test_arithmetic_in_projection() {
+    SqlTest::default()
+        .add_steps(Steps::SetupPeopleDataset)
+        .add_step(Step::new("BEGIN", StepRes::Ok))
+        .add_step(Step::new(
+            "SELECT id, age + 1 AS next_age, (age - id) * 2 % 7 AS x, 10 / 3 AS c FROM people",
+            StepRes::OkQuery(Box::new(|records| {
+                let rows: Vec<(i64, i32, i64, i32)> = records
+                    .map(|r| {
+                        (
+                            r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                                .unwrap()
+                                .unwrap(),
+                            r.get::<i32>(&RecordIndex::Name(SchemaIndex::from("next_age")))
+                                .unwrap()
+                                .unwrap(),
+                            r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("x")))
+                                .unwrap()
+                                .unwrap(),
+                            r.get::<i32>(&RecordIndex::Name(SchemaIndex::from("c")))
+                                .unwrap()
+                                .unwrap(),
+                        )
+                    })
+                    .sorted()
+                    .collect();
+                assert_eq!(rows, vec![(1, 14, 3, 3), (2, 71, 3, 3), (3, 36, 1, 3)],);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT age * 2 FROM people WHERE id = 1",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(r.get::<i32>(&RecordIndex::from(0)).unwrap(), Some(26));
+                assert_eq!(
+                    r.into_name_values()
+                        .into_iter()
+                        .map(|(name, _)| name)
+                        .collect::<Vec<_>>(),
+                    vec!["?column?".to_string()]
+                );
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM people WHERE age - 30 > id * 10",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(
+                    r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                        .unwrap(),
+                    Some(2)
+                );
+                assert!(records.next().is_none());
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_arithmetic_errors() {
+    SqlTest::default()
+        .add_steps(Steps::SetupPeopleDataset)
+        .add_step(Step::new("BEGIN", StepRes::Ok))
+        .add_step(Step::new(
+            "SELECT age / 0 AS x FROM people",
+            StepRes::Err(SqlState::DataExceptionDivisionByZero),
+        ))
+        .add_step(Step::new(
+            "SELECT age % (id - id) AS x FROM people",
+            StepRes::Err(SqlState::DataExceptionDivisionByZero),
+        ))
+        .add_step(Step::new(
+            // INTEGER * INTEGER overflows INTEGER
+            "SELECT age * 2147483647 AS x FROM people",
+            StepRes::Err(SqlState::DataExceptionNumericValueOutOfRange),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_arithmetic_without_from() {
+    SqlTest::default()
+        .add_steps(Steps::BeginTransaction)
+        .add_step(Step::new(
+            "SELECT 1 + 2 AS three, 7 % 4 * 2",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(
+                    r.get::<i32>(&RecordIndex::Name(SchemaIndex::from("three")))?,
+                    Some(3)
+                );
+                assert_eq!(r.get::<i32>(&RecordIndex::from(1))?, Some(6));
+                assert!(records.next().is_none());
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // integer literals are INTEGER even if they fit in SMALLINT
+            "SELECT 200 * 200 AS a, 32767 + 1 AS b, -32768 - 1 AS c, 2147483648 + 1 AS d",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(
+                    r.get::<i32>(&RecordIndex::Name(SchemaIndex::from("a")))?,
+                    Some(40000)
+                );
+                assert_eq!(
+                    r.get::<i32>(&RecordIndex::Name(SchemaIndex::from("b")))?,
+                    Some(32768)
+                );
+                assert_eq!(
+                    r.get::<i32>(&RecordIndex::Name(SchemaIndex::from("c")))?,
+                    Some(-32769)
+                );
+                assert_eq!(
+                    r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("d")))?,
+                    Some(2147483649)
+                );
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // INTEGER + INTEGER overflows INTEGER
+            "SELECT 2147483647 + 1",
+            StepRes::Err(SqlState::DataExceptionNumericValueOutOfRange),
+        ))
+        .add_step(Step::new(
+            "SELECT 1 / 0",
+            StepRes::Err(SqlState::DataExceptionDivisionByZero),
+        ))
+        .add_step(Step::new(
+            "SELECT id + 1",
+            StepRes::Err(SqlState::NameErrorNotFound),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_arithmetic_in_update() {
+    SqlTest::default()
+        .add_steps(Steps::SetupPeopleDataset)
+        .add_step(Step::new("BEGIN", StepRes::Ok))
+        .add_step(Step::new("UPDATE people SET age = age + 1", StepRes::Ok))
+        .add_step(Step::new(
+            "UPDATE people SET age = age * 2 WHERE id = 3",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "SELECT id, age FROM people",
+            StepRes::OkQuery(Box::new(|records| {
+                let rows: Vec<(i64, i32)> = records
+                    .map(|r| {
+                        (
+                            r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                                .unwrap()
+                                .unwrap(),
+                            r.get::<i32>(&RecordIndex::Name(SchemaIndex::from("age")))
+                                .unwrap()
+                                .unwrap(),
+                        )
+                    })
+                    .sorted()
+                    .collect();
+                assert_eq!(rows, vec![(1, 14), (2, 71), (3, 72)]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "UPDATE people SET age = age / 0",
+            StepRes::Err(SqlState::DataExceptionDivisionByZero),
+        ))
+        .run()
+        .await;
+}
@@ -0,0 +1,250 @@
+mod sql_test;
+
+use apllodb_server::{
+    test_support::test_setup, ArrayValue, NnSqlValue, RecordIndex, SchemaIndex, SqlState, SqlValue,
+};
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+use sql_test::{SqlTest, Step, StepRes, Steps};
+
+#[ctor::ctor]
+fn setup() {
+    test_setup();
+}
+
+fn setup_steps(sql_test: SqlTest) -> SqlTest {
+    sql_test
+        .add_steps(Steps::BeginTransaction)
+        .add_step(Step::new(
+            "CREATE TABLE posts (id BIGINT NOT NULL, tags TEXT[], scores INTEGER[], PRIMARY KEY (id))",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            r#"INSERT INTO posts (id, tags, scores) VALUES
+              (1, ARRAY['rust', 'db'], ARRAY[3, 1, 2]),
+              (2, ARRAY['db', NULL], ARRAY[10]),
+              (3, ARRAY['a "quoted", tag'], NULL),
+              (4, NULL, NULL)"#,
+            StepRes::Ok,
+        ))
+}
+
+fn texts(elements: &[Option<&str>]) -> ArrayValue {
+    ArrayValue::from_elements(
+        elements
+            .iter()
+            .map(|e| match e {
+                Some(s) => SqlValue::NotNull(NnSqlValue::Text(s.to_string())),
+                None => SqlValue::Null,
+            })
+            .collect(),
+    )
+    .unwrap()
+}
+
+fn sorted_ids_where(condition: &str, expected: Vec<i64>) -> Step {
+    Step::new(
+        format!("SELECT id FROM posts WHERE {}", condition),
+        StepRes::OkQuery(Box::new(move |records| {
+            let ids: Vec<i64> = records
+                .map(|r| {
+                    r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                        .unwrap()
+                        .unwrap()
+                })
+                .sorted()
+                .collect();
+            assert_eq!(ids, expected);
+            Ok(())
+        })),
+    )
+}
+
+#[async_std::test]
+async fn test_array_persistence() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT id, tags, scores FROM posts",
+            StepRes::OkQuery(Box::new(|records| {
+                let rows: Vec<(i64, Option<ArrayValue>, Option<ArrayValue>)> = records
+                    .map(|r| {
+                        (
+                            r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                                .unwrap()
+                                .unwrap(),
+                            r.get::<ArrayValue>(&RecordIndex::Name(SchemaIndex::from("tags")))
+                                .unwrap(),
+                            r.get::<ArrayValue>(&RecordIndex::Name(SchemaIndex::from("scores")))
+                                .unwrap(),
+                        )
+                    })
+                    .sorted_by_key(|row| row.0)
+                    .collect();
+
+                assert_eq!(rows[0].1, Some(texts(&[Some("rust"), Some("db")])));
+                assert_eq!(rows[1].1, Some(texts(&[Some("db"), None])));
+                assert_eq!(rows[2].1, Some(texts(&[Some(r#"a "quoted", tag"#)])));
+                assert_eq!(rows[3].1, None);
+
+                assert_eq!(rows[0].2.as_ref().unwrap().to_string(), "{3,1,2}");
+                assert_eq!(rows[2].2, None);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "UPDATE posts SET tags = ARRAY['updated'] WHERE id = 4",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "SELECT tags FROM posts WHERE id = 4",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(
+                    r.get::<ArrayValue>(&RecordIndex::Name(SchemaIndex::from("tags")))
+                        .unwrap(),
+                    Some(texts(&[Some("updated")]))
+                );
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_array_subscript_and_length() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT tags[1] AS first_tag, tags[3] AS third_tag, scores[1] + scores[2] AS total, array_length(tags) AS n_tags FROM posts WHERE id = 1",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(
+                    r.get::<String>(&RecordIndex::Name(SchemaIndex::from("first_tag")))
+                        .unwrap(),
+                    Some("rust".to_string())
+                );
+                assert_eq!(
+                    r.get::<String>(&RecordIndex::Name(SchemaIndex::from("third_tag")))
+                        .unwrap(),
+                    None
+                );
+                assert_eq!(
+                    r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("total")))
+                        .unwrap(),
+                    Some(4)
+                );
+                assert_eq!(
+                    r.get::<i32>(&RecordIndex::Name(SchemaIndex::from("n_tags")))
+                        .unwrap(),
+                    Some(2)
+                );
+                Ok(())
+            })),
+        ))
+        .add_step(sorted_ids_where("tags[1] = 'db'", vec![2]))
+        .add_step(sorted_ids_where("array_length(scores) >= 1", vec![1, 2]))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_array_any() {
+    setup_steps(SqlTest::default())
+        .add_step(sorted_ids_where("'db' = ANY(tags)", vec![1, 2]))
+        .add_step(sorted_ids_where("'rust' <> ANY(tags)", vec![1, 2, 3]))
+        .add_step(sorted_ids_where("5 < ANY(scores)", vec![2]))
+        .add_step(sorted_ids_where("id = ANY(ARRAY[1, 3, 5])", vec![1, 3]))
+        // UNKNOWN for NULL elements and NULL arrays
+        .add_step(sorted_ids_where("NOT ('rust' = ANY(tags))", vec![3]))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_array_errors() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "INSERT INTO posts (id, tags) VALUES (9, ARRAY[])",
+            StepRes::Err(SqlState::DataExceptionIllegalOperation),
+        ))
+        .add_step(Step::new(
+            "INSERT INTO posts (id, tags) VALUES (9, ARRAY['a', 1])",
+            StepRes::Err(SqlState::DataExceptionIllegalOperation),
+        ))
+        // not of the column's array type
+        .add_step(Step::new(
+            "INSERT INTO posts (id, scores) VALUES (9, ARRAY['x'])",
+            StepRes::Err(SqlState::SyntaxErrorOrAccessRuleViolationDatatypeMismatch),
+        ))
+        .add_step(Step::new(
+            "INSERT INTO posts (id, scores) VALUES (9, 5)",
+            StepRes::Err(SqlState::SyntaxErrorOrAccessRuleViolationDatatypeMismatch),
+        ))
+        .add_step(Step::new(
+            "UPDATE posts SET scores = tags WHERE id = 1",
+            StepRes::Err(SqlState::SyntaxErrorOrAccessRuleViolationDatatypeMismatch),
+        ))
+        .add_step(Step::new(
+            "SELECT tags['a'] FROM posts",
+            StepRes::Err(SqlState::DataExceptionIllegalOperation),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM posts WHERE 1 = ANY(tags)",
+            StepRes::Err(SqlState::DataExceptionIllegalComparison),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM posts WHERE 'db' = ANY(id)",
+            StepRes::Err(SqlState::DataExceptionIllegalOperation),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_array_element_length() {
+    SqlTest::default()
+        .add_steps(Steps::BeginTransaction)
+        .add_step(Step::new(
+            "CREATE TABLE codes (id BIGINT NOT NULL, codes VARCHAR(2)[], PRIMARY KEY (id))",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "INSERT INTO codes (id, codes) VALUES (1, ARRAY['ab', 'c'])",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "INSERT INTO codes (id, codes) VALUES (2, ARRAY['ab', 'cde'])",
+            StepRes::Err(SqlState::DataExceptionStringDataRightTruncation),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_empty_array_with_cast() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "INSERT INTO posts (id, tags, scores) VALUES (9, ARRAY[]::TEXT[], CAST(ARRAY[] AS INTEGER[]))",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "SELECT tags, array_length(scores) AS n FROM posts WHERE id = 9",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(
+                    r.get::<ArrayValue>(&RecordIndex::Name(SchemaIndex::from("tags")))
+                        .unwrap()
+                        .map(|tags| tags.is_empty()),
+                    Some(true)
+                );
+                assert_eq!(
+                    r.get::<i32>(&RecordIndex::Name(SchemaIndex::from("n")))
+                        .unwrap(),
+                    Some(0)
+                );
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
@@ -0,0 +1,125 @@
+mod sql_test;
+
+use apllodb_server::{test_support::test_setup, RecordIndex, SchemaIndex, SqlState};
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+use sql_test::{SqlTest, Step, StepRes, Steps};
+
+#[ctor::ctor]
+fn setup() {
+    test_setup();
+}
+
+fn setup_steps(sql_test: SqlTest) -> SqlTest {
+    sql_test
+        .add_steps(Steps::BeginTransaction)
+        .add_step(Step::new(
+            "CREATE TABLE account (id BIGINT NOT NULL, active BOOLEAN, admin BOOL NOT NULL, PRIMARY KEY (id))",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "INSERT INTO account (id, active, admin) VALUES
+              (1, TRUE, false),
+              (2, FALSE, false),
+              (3, NULL, true),
+              (4, true, TRUE)",
+            StepRes::Ok,
+        ))
+}
+
+fn sorted_ids_where(condition: &str, expected: Vec<i64>) -> Step {
+    Step::new(
+        format!("SELECT id FROM account WHERE {}", condition),
+        StepRes::OkQuery(Box::new(move |records| {
+            let ids: Vec<i64> = records
+                .map(|r| {
+                    r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                        .unwrap()
+                        .unwrap()
+                })
+                .sorted()
+                .collect();
+            assert_eq!(ids, expected);
+            Ok(())
+        })),
+    )
+}
+
+#[async_std::test]
+async fn test_boolean_persistence() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT id, active, admin FROM account",
+            StepRes::OkQuery(Box::new(|records| {
+                let rows: Vec<(i64, Option<bool>, bool)> = records
+                    .map(|r| {
+                        (
+                            r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                                .unwrap()
+                                .unwrap(),
+                            r.get::<bool>(&RecordIndex::Name(SchemaIndex::from("active")))
+                                .unwrap(),
+                            r.get::<bool>(&RecordIndex::Name(SchemaIndex::from("admin")))
+                                .unwrap()
+                                .unwrap(),
+                        )
+                    })
+                    .sorted_by_key(|row| row.0)
+                    .collect();
+                assert_eq!(
+                    rows,
+                    vec![
+                        (1, Some(true), false),
+                        (2, Some(false), false),
+                        (3, None, true),
+                        (4, Some(true), true),
+                    ]
+                );
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_boolean_column_as_condition() {
+    setup_steps(SqlTest::default())
+        .add_step(sorted_ids_where("active", vec![1, 4]))
+        .add_step(sorted_ids_where("NOT active", vec![2]))
+        .add_step(sorted_ids_where("active = FALSE", vec![2]))
+        .add_step(sorted_ids_where("active IS NULL", vec![3]))
+        .add_step(sorted_ids_where("active AND admin", vec![4]))
+        .add_step(sorted_ids_where("active OR admin", vec![1, 3, 4]))
+        .add_step(sorted_ids_where("TRUE", vec![1, 2, 3, 4]))
+        .add_step(sorted_ids_where("FALSE", vec![]))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_boolean_update() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "UPDATE account SET active = NOT admin WHERE id = 2",
+            StepRes::Ok,
+        ))
+        .add_step(sorted_ids_where("active", vec![1, 2, 4]))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_non_boolean_condition() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT id FROM account WHERE id",
+            StepRes::Err(SqlState::DataExceptionIllegalOperation),
+        ))
+        .add_step(Step::new(
+            "INSERT INTO account (id, active, admin) VALUES (5, 'yes', NULL)",
+            StepRes::Err(SqlState::IntegrityConstraintNotNullViolation),
+        ))
+        .run()
+        .await;
+}
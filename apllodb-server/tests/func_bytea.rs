@@ -0,0 +1,158 @@
+mod sql_test;
+
+use apllodb_server::{test_support::test_setup, RecordIndex, SchemaIndex, SqlState};
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+use sql_test::{SqlTest, Step, StepRes, Steps};
+
+type Bytes = Vec<u8>;
+
+#[ctor::ctor]
+fn setup() {
+    test_setup();
+}
+
+fn setup_steps(sql_test: SqlTest) -> SqlTest {
+    sql_test
+        .add_steps(Steps::BeginTransaction)
+        .add_step(Step::new(
+            "CREATE TABLE blobs (id BIGINT NOT NULL, data BYTEA, thumbnail BLOB, PRIMARY KEY (id))",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            r"INSERT INTO blobs (id, data, thumbnail) VALUES
+              (1, X'01ff', X''),
+              (2, BYTEA '\x0100', NULL),
+              (3, BYTEA 'ab\000', BLOB '\\'),
+              (4, NULL, X'00 7F')",
+            StepRes::Ok,
+        ))
+}
+
+fn sorted_ids_where(condition: &str, expected: Vec<i64>) -> Step {
+    Step::new(
+        format!("SELECT id FROM blobs WHERE {}", condition),
+        StepRes::OkQuery(Box::new(move |records| {
+            let ids: Vec<i64> = records
+                .map(|r| {
+                    r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                        .unwrap()
+                        .unwrap()
+                })
+                .sorted()
+                .collect();
+            assert_eq!(ids, expected);
+            Ok(())
+        })),
+    )
+}
+
+#[async_std::test]
+async fn test_bytea_persistence() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT id, data, thumbnail FROM blobs",
+            StepRes::OkQuery(Box::new(|records| {
+                let rows: Vec<(i64, Option<Bytes>, Option<Bytes>)> = records
+                    .map(|r| {
+                        (
+                            r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                                .unwrap()
+                                .unwrap(),
+                            r.get::<Vec<u8>>(&RecordIndex::Name(SchemaIndex::from("data")))
+                                .unwrap(),
+                            r.get::<Vec<u8>>(&RecordIndex::Name(SchemaIndex::from("thumbnail")))
+                                .unwrap(),
+                        )
+                    })
+                    .sorted_by_key(|row| row.0)
+                    .collect();
+                assert_eq!(
+                    rows,
+                    vec![
+                        (1, Some(vec![0x01, 0xff]), Some(vec![])),
+                        (2, Some(vec![0x01, 0x00]), None),
+                        (3, Some(vec![b'a', b'b', 0x00]), Some(vec![b'\\'])),
+                        (4, None, Some(vec![0x00, 0x7f])),
+                    ]
+                );
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_bytea_comparison() {
+    setup_steps(SqlTest::default())
+        .add_step(sorted_ids_where("data = X'01FF'", vec![1]))
+        .add_step(sorted_ids_where(r"data = BYTEA '\x01ff'", vec![1]))
+        .add_step(sorted_ids_where("data <> X'01ff'", vec![2, 3]))
+        // byte-wise comparison: shorter one is smaller when it is a prefix of the other
+        .add_step(sorted_ids_where("data < X'01ff'", vec![2]))
+        .add_step(sorted_ids_where("data > X'01'", vec![1, 2, 3]))
+        .add_step(sorted_ids_where("thumbnail = X''", vec![1]))
+        .add_step(sorted_ids_where("data IS NULL", vec![4]))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_bytea_update() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "UPDATE blobs SET thumbnail = data WHERE id = 2",
+            StepRes::Ok,
+        ))
+        .add_step(sorted_ids_where("thumbnail = X'0100'", vec![2]))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_bytea_cast() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            r"SELECT CAST(data AS TEXT) AS t, CAST('\x6162' AS BYTEA) AS b FROM blobs WHERE id = 1",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(
+                    r.get::<String>(&RecordIndex::Name(SchemaIndex::from("t")))
+                        .unwrap(),
+                    Some(r"\x01ff".to_string())
+                );
+                assert_eq!(
+                    r.get::<Vec<u8>>(&RecordIndex::Name(SchemaIndex::from("b")))
+                        .unwrap(),
+                    Some(b"ab".to_vec())
+                );
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_invalid_bytea() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "INSERT INTO blobs (id, data) VALUES (5, X'012')",
+            StepRes::Err(SqlState::DataExceptionInvalidCharacterValueForCast),
+        ))
+        .add_step(Step::new(
+            "INSERT INTO blobs (id, data) VALUES (5, X'zz')",
+            StepRes::Err(SqlState::DataExceptionInvalidCharacterValueForCast),
+        ))
+        .add_step(Step::new(
+            r"INSERT INTO blobs (id, data) VALUES (5, BYTEA '\9')",
+            StepRes::Err(SqlState::DataExceptionInvalidCharacterValueForCast),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM blobs WHERE data = 1",
+            StepRes::Err(SqlState::DataExceptionIllegalComparison),
+        ))
+        .run()
+        .await;
+}
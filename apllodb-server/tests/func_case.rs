@@ -0,0 +1,151 @@
+mod sql_test;
+
+use apllodb_server::{
+    test_support::test_setup, RecordIndex, SchemaIndex, SqlState, SqlType, SqlValue,
+};
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+use sql_test::{SqlTest, Step, StepRes, Steps};
+
+#[ctor::ctor]
+fn setup() {
+    test_setup();
+}
+
+#[async_std::test]
+async fn test_searched_case() {
+    SqlTest::default()
+        .add_steps(Steps::SetupPeopleDataset)
+        .add_step(Step::new("BEGIN", StepRes::Ok))
+        .add_step(Step::new(
+            "SELECT id, CASE WHEN age > 60 THEN 'senior' WHEN age >= 20 THEN 'adult' ELSE 'child' END AS grade FROM people",
+            StepRes::OkQuery(Box::new(|records| {
+                let rows: Vec<(i64, String)> = records
+                    .map(|r| {
+                        (
+                            r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                                .unwrap()
+                                .unwrap(),
+                            r.get::<String>(&RecordIndex::Name(SchemaIndex::from("grade")))
+                                .unwrap()
+                                .unwrap(),
+                        )
+                    })
+                    .sorted()
+                    .collect();
+                assert_eq!(
+                    rows,
+                    vec![
+                        (1, "child".to_string()),
+                        (2, "senior".to_string()),
+                        (3, "adult".to_string())
+                    ]
+                );
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // no ELSE: NULL
+            "SELECT id FROM people WHERE CASE WHEN age < 20 THEN id END IS NULL",
+            StepRes::OkQuery(Box::new(|records| {
+                let ids: Vec<i64> = records
+                    .map(|r| {
+                        r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                            .unwrap()
+                            .unwrap()
+                    })
+                    .sorted()
+                    .collect();
+                assert_eq!(ids, vec![2, 3]);
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_simple_case() {
+    SqlTest::default()
+        .add_steps(Steps::SetupPeopleDataset)
+        .add_step(Step::new("BEGIN", StepRes::Ok))
+        .add_step(Step::new(
+            // `1` (SMALLINT) is unified with `100000` (INTEGER)
+            "SELECT CASE id WHEN 1 THEN 1 WHEN 2 THEN 100000 END AS x FROM people WHERE id = 1",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                let x = RecordIndex::Name(SchemaIndex::from("x"));
+                assert_eq!(r.get::<i32>(&x).unwrap(), Some(1));
+                assert_eq!(
+                    r.get::<i16>(&x).unwrap_err().kind(),
+                    &SqlState::DataExceptionIllegalConversion
+                );
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // untaken branch is not evaluated (would be division by zero)
+            "SELECT CASE id - 1 WHEN 0 THEN 0 ELSE age / (id - 1) END AS x FROM people",
+            StepRes::OkQuery(Box::new(|records| {
+                let xs: Vec<Option<i64>> = records
+                    .map(|r| {
+                        r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("x")))
+                            .unwrap()
+                    })
+                    .sorted()
+                    .collect();
+                assert_eq!(xs, vec![Some(0), Some(17), Some(70)]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // `0` (SMALLINT) is unified with `id` (BIGINT column)
+            "SELECT CASE WHEN age > 60 THEN 0 ELSE id END AS x FROM people",
+            StepRes::OkQuery(Box::new(|records| {
+                let types: Vec<SqlType> = records
+                    .flat_map(|r| r.into_name_values().into_iter().map(|(_, v)| v))
+                    .map(|v| match v {
+                        SqlValue::NotNull(nn_sql_value) => nn_sql_value.sql_type(),
+                        SqlValue::Null => panic!("x must not be NULL"),
+                    })
+                    .collect();
+                assert_eq!(types, vec![SqlType::big_int(); 3]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "UPDATE people SET age = CASE WHEN age < 20 THEN age + 10 ELSE age END",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "SELECT age FROM people WHERE id = 1",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(
+                    r.get::<i32>(&RecordIndex::Name(SchemaIndex::from("age")))
+                        .unwrap(),
+                    Some(23)
+                );
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_case_type_mismatch() {
+    SqlTest::default()
+        .add_steps(Steps::SetupPeopleDataset)
+        .add_step(Step::new("BEGIN", StepRes::Ok))
+        .add_step(Step::new(
+            "SELECT CASE WHEN age > 20 THEN 1 ELSE 'a' END FROM people",
+            StepRes::Err(SqlState::DataExceptionIllegalOperation),
+        ))
+        .add_step(Step::new(
+            "SELECT CASE WHEN age > 20 THEN 'a' ELSE age END FROM people",
+            StepRes::Err(SqlState::DataExceptionIllegalOperation),
+        ))
+        .run()
+        .await;
+}
@@ -0,0 +1,170 @@
+mod sql_test;
+
+use apllodb_server::{test_support::test_setup, RecordIndex, SchemaIndex, SqlState};
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+use sql_test::{SqlTest, Step, StepRes, Steps};
+
+#[ctor::ctor]
+fn setup() {
+    test_setup();
+}
+
+#[async_std::test]
+async fn test_explicit_cast() {
+    SqlTest::default()
+        .add_steps(Steps::SetupPeopleDataset)
+        .add_step(Step::new("BEGIN", StepRes::Ok))
+        .add_step(Step::new(
+            "SELECT CAST(age AS TEXT) AS a, '42'::INTEGER AS i, id::SMALLINT AS s FROM people WHERE id = 1",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(
+                    r.get::<String>(&RecordIndex::Name(SchemaIndex::from("a")))
+                        .unwrap(),
+                    Some("13".to_string())
+                );
+                assert_eq!(
+                    r.get::<i32>(&RecordIndex::Name(SchemaIndex::from("i")))
+                        .unwrap(),
+                    Some(42)
+                );
+                assert_eq!(
+                    r.get::<i16>(&RecordIndex::Name(SchemaIndex::from("s")))
+                        .unwrap(),
+                    Some(1)
+                );
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM people WHERE age::TEXT = '35' OR age = CAST('13' AS INTEGER)",
+            StepRes::OkQuery(Box::new(|records| {
+                let ids: Vec<i64> = records
+                    .map(|r| {
+                        r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                            .unwrap()
+                            .unwrap()
+                    })
+                    .sorted()
+                    .collect();
+                assert_eq!(ids, vec![1, 3]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "UPDATE people SET age = CAST(id AS INTEGER) + age::SMALLINT WHERE id = 1",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "SELECT age FROM people WHERE id = 1",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(
+                    r.get::<i32>(&RecordIndex::Name(SchemaIndex::from("age")))
+                        .unwrap(),
+                    Some(14)
+                );
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_cast_errors() {
+    SqlTest::default()
+        .add_steps(Steps::SetupPeopleDataset)
+        .add_step(Step::new("BEGIN", StepRes::Ok))
+        .add_step(Step::new(
+            "SELECT (age * 1000)::SMALLINT FROM people",
+            StepRes::Err(SqlState::DataExceptionNumericValueOutOfRange),
+        ))
+        .add_step(Step::new(
+            "SELECT CAST('abc' AS INTEGER) FROM people",
+            StepRes::Err(SqlState::DataExceptionInvalidCharacterValueForCast),
+        ))
+        .add_step(Step::new(
+            // no implicit conversion between TEXT and INTEGER
+            "SELECT id FROM people WHERE age = '13'",
+            StepRes::Err(SqlState::DataExceptionIllegalComparison),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_cast_on_write() {
+    SqlTest::default()
+        .add_steps(Steps::BeginTransaction)
+        .add_step(Step::new(
+            "CREATE TABLE t (id SMALLINT NOT NULL, i INTEGER, s SMALLINT, b BOOLEAN, data BYTEA, PRIMARY KEY (id))",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            // values are stored in the column types, as CAST does
+            r"INSERT INTO t (id, i, s, b, data) VALUES (1.5, 1.5, 2.4, 'yes', '\xdead')",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "SELECT id, i, s, b, data FROM t",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(r.get::<i16>(&RecordIndex::Name(SchemaIndex::from("id")))?, Some(2));
+                assert_eq!(r.get::<i32>(&RecordIndex::Name(SchemaIndex::from("i")))?, Some(2));
+                assert_eq!(r.get::<i16>(&RecordIndex::Name(SchemaIndex::from("s")))?, Some(2));
+                assert_eq!(r.get::<bool>(&RecordIndex::Name(SchemaIndex::from("b")))?, Some(true));
+                assert_eq!(
+                    r.get::<Vec<u8>>(&RecordIndex::Name(SchemaIndex::from("data")))?,
+                    Some(vec![0xde, 0xad])
+                );
+                assert!(records.next().is_none());
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new("UPDATE t SET s = 2.5", StepRes::Ok))
+        .add_step(Step::new("UPDATE t SET b = 'no'", StepRes::Ok))
+        .add_step(Step::new(
+            "SELECT id, s, b FROM t",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(r.get::<i16>(&RecordIndex::Name(SchemaIndex::from("id")))?, Some(2));
+                assert_eq!(r.get::<i16>(&RecordIndex::Name(SchemaIndex::from("s")))?, Some(3));
+                assert_eq!(r.get::<bool>(&RecordIndex::Name(SchemaIndex::from("b")))?, Some(false));
+                assert!(records.next().is_none());
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_join_with_implicit_coercion() {
+    SqlTest::default()
+        .add_steps(Steps::SetupPeopleBodyPetDataset)
+        .add_step(Step::new("BEGIN", StepRes::Ok))
+        .add_step(Step::new(
+            // people.age: INTEGER, pet.age: SMALLINT
+            "SELECT people.id, pet.kind FROM people INNER JOIN pet ON people.age = pet.age",
+            StepRes::OkQuery(Box::new(|records| {
+                let rows: Vec<(i64, String)> = records
+                    .map(|r| {
+                        (
+                            r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("people.id")))
+                                .unwrap()
+                                .unwrap(),
+                            r.get::<String>(&RecordIndex::Name(SchemaIndex::from("pet.kind")))
+                                .unwrap()
+                                .unwrap(),
+                        )
+                    })
+                    .collect();
+                assert_eq!(rows, vec![(1, "dog".to_string())]);
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
@@ -0,0 +1,273 @@
+mod sql_test;
+
+use apllodb_server::{test_support::test_setup, Interval, RecordIndex, SchemaIndex, SqlState};
+use chrono::{DateTime, NaiveDate, Utc};
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+use sql_test::{SqlTest, Step, StepRes, Steps};
+
+#[ctor::ctor]
+fn setup() {
+    test_setup();
+}
+
+fn setup_steps(sql_test: SqlTest) -> SqlTest {
+    sql_test
+        .add_steps(Steps::BeginTransaction)
+        .add_step(Step::new(
+            "CREATE TABLE event (id BIGINT NOT NULL, d DATE, t TIME, ts TIMESTAMP, tstz TIMESTAMP WITH TIME ZONE, span INTERVAL, PRIMARY KEY (id))",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "INSERT INTO event (id, d, t, ts, tstz, span) VALUES
+              (1, DATE '2021-01-31', TIME '12:34:56.789', TIMESTAMP '2021-01-31 23:59:59.123456789', TIMESTAMP WITH TIME ZONE '2021-01-31 09:00:00+09:00', INTERVAL '1 mon 2 days 03:00:00'),
+              (2, DATE '2020-02-29', TIME '00:00', TIMESTAMP '2020-02-29', TIMESTAMP WITH TIME ZONE '2020-02-29 00:00:00Z', INTERVAL '-1 day'),
+              (3, NULL, NULL, NULL, NULL, NULL)",
+            StepRes::Ok,
+        ))
+}
+
+fn text_of(records: apllodb_server::Records, column: &str) -> Vec<Option<String>> {
+    records
+        .map(|r| {
+            r.get::<String>(&RecordIndex::Name(SchemaIndex::from(column)))
+                .unwrap()
+        })
+        .collect()
+}
+
+#[async_std::test]
+async fn test_datetime_persistence() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT id, d, ts, tstz, span FROM event",
+            StepRes::OkQuery(Box::new(|records| {
+                type Row = (
+                    i64,
+                    Option<NaiveDate>,
+                    Option<String>,
+                    Option<DateTime<Utc>>,
+                    Option<Interval>,
+                );
+                let rows: Vec<Row> = records
+                    .map(|r| {
+                        (
+                            r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                                .unwrap()
+                                .unwrap(),
+                            r.get::<NaiveDate>(&RecordIndex::Name(SchemaIndex::from("d")))
+                                .unwrap(),
+                            r.get::<chrono::NaiveDateTime>(&RecordIndex::Name(SchemaIndex::from(
+                                "ts",
+                            )))
+                            .unwrap()
+                            .map(|ts| ts.to_string()),
+                            r.get::<DateTime<Utc>>(&RecordIndex::Name(SchemaIndex::from("tstz")))
+                                .unwrap(),
+                            r.get::<Interval>(&RecordIndex::Name(SchemaIndex::from("span")))
+                                .unwrap(),
+                        )
+                    })
+                    .sorted_by_key(|row| row.0)
+                    .collect();
+
+                assert_eq!(rows[0].1, Some(NaiveDate::from_ymd(2021, 1, 31)));
+                // nanoseconds are kept
+                assert_eq!(rows[0].2, Some("2021-01-31 23:59:59.123456789".to_string()));
+                // held in UTC
+                assert_eq!(
+                    rows[0].3,
+                    Some(DateTime::<Utc>::from_utc(
+                        NaiveDate::from_ymd(2021, 1, 31).and_hms(0, 0, 0),
+                        Utc
+                    ))
+                );
+                assert_eq!(
+                    rows[0].4.map(|i| (i.months(), i.days(), i.nanos())),
+                    Some((1, 2, 3 * 3600 * 1_000_000_000))
+                );
+                assert_eq!(
+                    rows[1].4.map(|i| i.to_string()),
+                    Some("-1 days".to_string())
+                );
+                assert_eq!(rows[2], (3, None, None, None, None));
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT t::TEXT AS t FROM event WHERE id = 1",
+            StepRes::OkQuery(Box::new(|records| {
+                assert_eq!(
+                    text_of(records, "t"),
+                    vec![Some("12:34:56.789".to_string())]
+                );
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_datetime_comparison_and_arithmetic() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            // DATE vs TIMESTAMP, TIMESTAMP WITH TIME ZONE vs DATE, INTERVAL vs INTERVAL
+            "SELECT id FROM event WHERE d < TIMESTAMP '2021-01-01 00:00:00' OR tstz > DATE '2021-01-30' OR span > INTERVAL '30 days'",
+            StepRes::OkQuery(Box::new(|records| {
+                let ids: Vec<i64> = records
+                    .map(|r| {
+                        r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                            .unwrap()
+                            .unwrap()
+                    })
+                    .sorted()
+                    .collect();
+                assert_eq!(ids, vec![1, 2]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM event WHERE d IS NOT NULL ORDER BY ts DESC",
+            StepRes::OkQuery(Box::new(|records| {
+                let ids: Vec<i64> = records
+                    .map(|r| {
+                        r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                            .unwrap()
+                            .unwrap()
+                    })
+                    .collect();
+                assert_eq!(ids, vec![1, 2]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT (ts + span)::TEXT AS v FROM event WHERE id = 1",
+            StepRes::OkQuery(Box::new(|records| {
+                // 2021-02-31 is clamped into 2021-02-28
+                assert_eq!(
+                    text_of(records, "v"),
+                    vec![Some("2021-03-03 02:59:59.123456789".to_string())]
+                );
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT (d + 1)::TEXT AS v FROM event WHERE id = 2",
+            StepRes::OkQuery(Box::new(|records| {
+                assert_eq!(text_of(records, "v"), vec![Some("2020-03-01".to_string())]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT (ts - TIMESTAMP '2021-01-01')::TEXT AS v FROM event WHERE id = 2",
+            StepRes::OkQuery(Box::new(|records| {
+                assert_eq!(text_of(records, "v"), vec![Some("-307 days".to_string())]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT (span * 2)::TEXT AS v FROM event WHERE id = 1",
+            StepRes::OkQuery(Box::new(|records| {
+                assert_eq!(
+                    text_of(records, "v"),
+                    vec![Some("2 mons 4 days 06:00:00".to_string())]
+                );
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "UPDATE event SET tstz = tstz - INTERVAL '1 hour' WHERE id = 1",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "SELECT tstz::TEXT AS v FROM event WHERE id = 1",
+            StepRes::OkQuery(Box::new(|records| {
+                assert_eq!(
+                    text_of(records, "v"),
+                    vec![Some("2021-01-30 23:00:00+00:00".to_string())]
+                );
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_current_timestamp() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT CURRENT_TIMESTAMP AS now, current_date AS today FROM event WHERE id = 1",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                let now = r
+                    .get::<DateTime<Utc>>(&RecordIndex::Name(SchemaIndex::from("now")))
+                    .unwrap()
+                    .unwrap();
+                let today = r
+                    .get::<NaiveDate>(&RecordIndex::Name(SchemaIndex::from("today")))
+                    .unwrap()
+                    .unwrap();
+                assert!((Utc::now() - now).num_seconds().abs() < 60);
+                assert!((Utc::now().naive_utc().date() - today).num_days().abs() <= 1);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM event WHERE ts < CURRENT_TIMESTAMP",
+            StepRes::OkQuery(Box::new(|records| {
+                assert_eq!(records.count(), 2);
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_datetime_errors() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT DATE '2021-02-30' FROM event",
+            StepRes::Err(SqlState::DataExceptionDatetimeFieldOverflow),
+        ))
+        .add_step(Step::new(
+            "SELECT TIMESTAMP 'yesterday' FROM event",
+            StepRes::Err(SqlState::DataExceptionInvalidDatetimeFormat),
+        ))
+        .add_step(Step::new(
+            "SELECT TIMESTAMP WITH TIME ZONE '2021-01-01 00:00+24:00' FROM event",
+            StepRes::Err(SqlState::DataExceptionInvalidTimeZoneDisplacementValue),
+        ))
+        .add_step(Step::new(
+            "INSERT INTO event (id, d) VALUES (3, 'garbage')",
+            StepRes::Err(SqlState::DataExceptionInvalidDatetimeFormat),
+        ))
+        .add_step(Step::new(
+            "INSERT INTO event (id, d) VALUES (3, '2021-02-30')",
+            StepRes::Err(SqlState::DataExceptionDatetimeFieldOverflow),
+        ))
+        .add_step(Step::new(
+            "INSERT INTO event (id, tstz) VALUES (3, '2021-01-01 00:00+24:00')",
+            StepRes::Err(SqlState::DataExceptionInvalidTimeZoneDisplacementValue),
+        ))
+        .add_step(Step::new(
+            "UPDATE event SET t = 'noon' WHERE id = 1",
+            StepRes::Err(SqlState::DataExceptionInvalidDatetimeFormat),
+        ))
+        .add_step(Step::new(
+            "SELECT CAST(d AS INTEGER) FROM event",
+            StepRes::Err(SqlState::DataExceptionIllegalConversion),
+        ))
+        .add_step(Step::new(
+            "SELECT d + d FROM event WHERE id = 1",
+            StepRes::Err(SqlState::DataExceptionIllegalOperation),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM event WHERE t = d",
+            StepRes::Err(SqlState::DataExceptionIllegalComparison),
+        ))
+        .run()
+        .await;
+}
@@ -0,0 +1,106 @@
+mod sql_test;
+
+use apllodb_server::{test_support::test_setup, RecordIndex, SchemaIndex, SqlState};
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+use sql_test::{SqlTest, Step, StepRes, Steps};
+
+#[ctor::ctor]
+fn setup() {
+    test_setup();
+}
+
+fn people_ids(expected: Vec<i64>) -> Step {
+    Step::new(
+        "SELECT id FROM people",
+        StepRes::OkQuery(Box::new(move |records| {
+            let ids: Vec<i64> = records
+                .map(|r| {
+                    r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                        .unwrap()
+                        .unwrap()
+                })
+                .sorted()
+                .collect();
+            assert_eq!(ids, expected);
+            Ok(())
+        })),
+    )
+}
+
+#[async_std::test]
+async fn test_delete() {
+    SqlTest::default()
+        .add_steps(Steps::SetupPeopleDataset)
+        .add_step(Step::new("BEGIN", StepRes::Ok))
+        .add_step(people_ids(vec![1, 2, 3]))
+        .add_step(Step::new("DELETE FROM people WHERE id = 2", StepRes::Ok))
+        .add_step(people_ids(vec![1, 3]))
+        // no record matches
+        .add_step(Step::new("DELETE FROM people WHERE id = 2", StepRes::Ok))
+        .add_step(people_ids(vec![1, 3]))
+        // non-PK condition
+        .add_step(Step::new("DELETE FROM people WHERE age > 20", StepRes::Ok))
+        .add_step(people_ids(vec![1]))
+        .add_step(Step::new("DELETE FROM people", StepRes::Ok))
+        .add_step(people_ids(vec![]))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_insert_after_delete() {
+    SqlTest::default()
+        .add_steps(Steps::SetupPeopleDataset)
+        .add_step(Step::new("BEGIN", StepRes::Ok))
+        .add_step(Step::new("DELETE FROM people WHERE id = 1", StepRes::Ok))
+        .add_step(Step::new(
+            "INSERT INTO people (id, age) VALUES (1, 99)",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "SELECT id, age FROM people WHERE id = 1",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(
+                    r.get::<i32>(&RecordIndex::Name(SchemaIndex::from("age")))?,
+                    Some(99)
+                );
+                assert!(records.next().is_none());
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_delete_abort() {
+    SqlTest::default()
+        .add_steps(Steps::SetupPeopleDataset)
+        .add_step(Step::new("BEGIN", StepRes::Ok))
+        .add_step(Step::new("DELETE FROM people", StepRes::Ok))
+        .add_step(people_ids(vec![]))
+        .add_step(Step::new("ABORT", StepRes::Ok))
+        .add_step(Step::new("BEGIN", StepRes::Ok))
+        .add_step(people_ids(vec![1, 2, 3]))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_delete_error() {
+    SqlTest::default()
+        .add_steps(Steps::SetupPeopleDataset)
+        .add_step(Step::new("BEGIN", StepRes::Ok))
+        .add_step(Step::new(
+            "DELETE FROM no_such_table",
+            StepRes::Err(SqlState::NameErrorNotFound),
+        ))
+        .add_step(Step::new(
+            "DELETE FROM people WHERE no_such_column = 1",
+            StepRes::Err(SqlState::NameErrorNotFound),
+        ))
+        .run()
+        .await;
+}
@@ -0,0 +1,221 @@
+mod sql_test;
+
+use apllodb_server::{
+    test_support::test_setup, ApllodbError, ApllodbServer, FunctionRegistry, FunctionSignature,
+    NnSqlValue, ParameterType, RecordIndex, ReturnType, ScalarFunction, SchemaIndex, SqlState,
+    SqlType, SqlValue,
+};
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+use sql_test::{SqlTest, Step, StepRes};
+
+#[ctor::ctor]
+fn setup() {
+    test_setup();
+}
+
+fn setup_steps(sql_test: SqlTest) -> SqlTest {
+    sql_test
+        .add_step(Step::new("BEGIN", StepRes::Ok))
+        .add_step(Step::new(
+            "CREATE TABLE t (id BIGINT NOT NULL, c INTEGER, name TEXT, PRIMARY KEY (id))",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "INSERT INTO t (id, c, name) VALUES (1, -10, 'foo')",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "INSERT INTO t (id, c, name) VALUES (2, 20, 'Hello')",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "INSERT INTO t (id, c, name) VALUES (3, NULL, NULL)",
+            StepRes::Ok,
+        ))
+}
+
+#[async_std::test]
+async fn test_builtin_functions() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT id, UPPER(name) AS u, length(name) AS l, abs(c) AS a, coalesce(c, id) AS x FROM t",
+            StepRes::OkQuery(Box::new(|records| {
+                type Row = (i64, Option<String>, Option<i32>, Option<i32>, Option<i64>);
+                let rows: Vec<Row> = records
+                        .map(|r| {
+                            (
+                                r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                                    .unwrap()
+                                    .unwrap(),
+                                r.get::<String>(&RecordIndex::Name(SchemaIndex::from("u")))
+                                    .unwrap(),
+                                r.get::<i32>(&RecordIndex::Name(SchemaIndex::from("l")))
+                                    .unwrap(),
+                                r.get::<i32>(&RecordIndex::Name(SchemaIndex::from("a")))
+                                    .unwrap(),
+                                // coalesce(INTEGER, BIGINT) is BIGINT
+                                r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("x")))
+                                    .unwrap(),
+                            )
+                        })
+                        .sorted()
+                        .collect();
+                assert_eq!(
+                    rows,
+                    vec![
+                        (1, Some("FOO".to_string()), Some(3), Some(10), Some(-10)),
+                        (2, Some("HELLO".to_string()), Some(5), Some(20), Some(20)),
+                        (3, None, None, None, Some(3)),
+                    ]
+                );
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM t WHERE lower(name) = 'hello' OR abs(c) + 1 = 11",
+            StepRes::OkQuery(Box::new(|records| {
+                let ids: Vec<i64> = records
+                    .map(|r| {
+                        r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                            .unwrap()
+                            .unwrap()
+                    })
+                    .sorted()
+                    .collect();
+                assert_eq!(ids, vec![1, 2]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // result type is chosen per call, not per row
+            "SELECT coalesce(c, 0) AS x FROM t",
+            StepRes::OkQuery(Box::new(|records| {
+                let xs: Vec<NnSqlValue> = records
+                    .flat_map(|r| r.into_name_values().into_iter().map(|(_, v)| v))
+                    .map(|v| match v {
+                        SqlValue::NotNull(nn_sql_value) => nn_sql_value,
+                        SqlValue::Null => panic!("coalesce(c, 0) must not be NULL"),
+                    })
+                    .sorted_by_key(|v| v.to_string())
+                    .collect();
+                // NnSqlValue equality is loose (`0 SMALLINT = 0 INTEGER`): compare types as well
+                assert_eq!(
+                    xs.iter().map(|v| v.sql_type()).collect::<Vec<SqlType>>(),
+                    vec![SqlType::integer(); 3]
+                );
+                assert_eq!(
+                    xs,
+                    vec![
+                        NnSqlValue::Integer(-10),
+                        NnSqlValue::Integer(0),
+                        NnSqlValue::Integer(20),
+                    ]
+                );
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_function_in_update() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "UPDATE t SET name = upper(name) WHERE id = 1",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "SELECT name FROM t WHERE id = 1",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(
+                    r.get::<String>(&RecordIndex::Name(SchemaIndex::from("name")))
+                        .unwrap(),
+                    Some("FOO".to_string())
+                );
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_function_errors() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT no_such_function(id) FROM t",
+            StepRes::Err(SqlState::NameErrorNotFound),
+        ))
+        .add_step(Step::new(
+            "SELECT length(name, name) FROM t",
+            StepRes::Err(SqlState::NameErrorNotFound),
+        ))
+        .add_step(Step::new(
+            "SELECT length(id) FROM t",
+            StepRes::Err(SqlState::DataExceptionIllegalOperation),
+        ))
+        .add_step(Step::new(
+            "SELECT coalesce(c, name) FROM t",
+            StepRes::Err(SqlState::DataExceptionIllegalOperation),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_custom_function() {
+    let mut function_registry = FunctionRegistry::default();
+    function_registry
+        .register(ScalarFunction::new(
+            "repeat",
+            FunctionSignature::exact(vec![ParameterType::String, ParameterType::Numeric]),
+            ReturnType::Fixed(SqlType::text()),
+            true,
+            |args| match args {
+                [SqlValue::NotNull(s), SqlValue::NotNull(n)] => {
+                    let n = n.unpack::<i64>()?;
+                    if n < 0 {
+                        Err(ApllodbError::data_exception_illegal_operation(
+                            "repeat count must not be negative",
+                        ))
+                    } else {
+                        Ok(SqlValue::NotNull(NnSqlValue::Text(
+                            s.unpack::<String>()?.repeat(n as usize),
+                        )))
+                    }
+                }
+                _ => unreachable!("strict function takes non-NULL arguments"),
+            },
+        ))
+        .unwrap();
+
+    setup_steps(SqlTest::with_server(ApllodbServer::with_function_registry(
+        function_registry,
+    )))
+    .add_step(Step::new(
+        "SELECT REPEAT(name, 2) AS r, upper(repeat(name, id)) AS ur FROM t WHERE id = 1",
+        StepRes::OkQuery(Box::new(|mut records| {
+            let r = records.next().unwrap();
+            assert_eq!(
+                r.get::<String>(&RecordIndex::Name(SchemaIndex::from("r")))
+                    .unwrap(),
+                Some("foofoo".to_string())
+            );
+            assert_eq!(
+                r.get::<String>(&RecordIndex::Name(SchemaIndex::from("ur")))
+                    .unwrap(),
+                Some("FOO".to_string())
+            );
+            Ok(())
+        })),
+    ))
+    .add_step(Step::new(
+        "SELECT repeat(name, c) FROM t WHERE id = 1",
+        StepRes::Err(SqlState::DataExceptionIllegalOperation),
+    ))
+    .run()
+    .await;
+}
@@ -0,0 +1,182 @@
+mod sql_test;
+
+use apllodb_server::{test_support::test_setup, FuzzyDate, RecordIndex, SchemaIndex, SqlState};
+use chrono::NaiveDate;
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+use sql_test::{SqlTest, Step, StepRes, Steps};
+
+#[ctor::ctor]
+fn setup() {
+    test_setup();
+}
+
+fn setup_steps(sql_test: SqlTest) -> SqlTest {
+    sql_test
+        .add_steps(Steps::BeginTransaction)
+        .add_step(Step::new(
+            "CREATE TABLE person (id BIGINT NOT NULL, born FUZZY DATE, PRIMARY KEY (id))",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "INSERT INTO person (id, born) VALUES
+              (1, FUZZY DATE '1923-05-17'),
+              (2, FUZZY DATE 'circa 1920s'),
+              (3, FUZZY DATE '1925'),
+              (4, NULL),
+              (5, FUZZY DATE '1950s')",
+            StepRes::Ok,
+        ))
+}
+
+fn ids_of(records: apllodb_server::Records) -> Vec<i64> {
+    records
+        .map(|r| {
+            r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                .unwrap()
+                .unwrap()
+        })
+        .collect()
+}
+
+fn sorted_ids_where(condition: &str, expected: Vec<i64>) -> Step {
+    Step::new(
+        format!("SELECT id FROM person WHERE {}", condition),
+        StepRes::OkQuery(Box::new(move |records| {
+            assert_eq!(
+                ids_of(records).into_iter().sorted().collect::<Vec<_>>(),
+                expected
+            );
+            Ok(())
+        })),
+    )
+}
+
+#[async_std::test]
+async fn test_fuzzy_date_persistence() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT id, born FROM person",
+            StepRes::OkQuery(Box::new(|records| {
+                let rows: Vec<(i64, Option<FuzzyDate>)> = records
+                    .map(|r| {
+                        (
+                            r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                                .unwrap()
+                                .unwrap(),
+                            r.get::<FuzzyDate>(&RecordIndex::Name(SchemaIndex::from("born")))
+                                .unwrap(),
+                        )
+                    })
+                    .sorted_by_key(|row| row.0)
+                    .collect();
+
+                let born: Vec<Option<String>> = rows
+                    .iter()
+                    .map(|row| row.1.map(|fd| fd.to_string()))
+                    .collect();
+                assert_eq!(
+                    born,
+                    vec![
+                        Some("1923-05-17".to_string()),
+                        Some("1910s..1930s".to_string()),
+                        Some("1925".to_string()),
+                        None,
+                        Some("1950s".to_string()),
+                    ]
+                );
+                assert_eq!(
+                    rows[1].1.map(|fd| (fd.earliest(), fd.latest())),
+                    Some((
+                        NaiveDate::from_ymd(1910, 1, 1),
+                        NaiveDate::from_ymd(1939, 12, 31)
+                    ))
+                );
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_fuzzy_date_comparison() {
+    setup_steps(SqlTest::default())
+        // definitely before 1940
+        .add_step(sorted_ids_where("born < FUZZY DATE '1940'", vec![1, 2, 3]))
+        // DATE is converted into an exact FUZZY DATE; 2 is UNKNOWN
+        .add_step(sorted_ids_where("born < DATE '1925-01-01'", vec![1]))
+        .add_step(sorted_ids_where(
+            "POSSIBLY born < DATE '1925-01-01'",
+            vec![1, 2],
+        ))
+        .add_step(sorted_ids_where("born = FUZZY DATE '1920s'", vec![]))
+        .add_step(sorted_ids_where(
+            "POSSIBLY born = FUZZY DATE '1920s'",
+            vec![1, 2, 3],
+        ))
+        .add_step(sorted_ids_where(
+            "NOT POSSIBLY born = FUZZY DATE '1920s'",
+            vec![5],
+        ))
+        .add_step(sorted_ids_where(
+            "POSSIBLY born BETWEEN DATE '1930-01-01' AND DATE '1960-01-01'",
+            vec![2, 5],
+        ))
+        .add_step(sorted_ids_where(
+            "born IN (FUZZY DATE '1923-05-17', FUZZY DATE '1950s')",
+            vec![1],
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_fuzzy_date_ordering() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            // ambiguous values are sorted by their ranges, and NULL comes last
+            "SELECT id FROM person ORDER BY born",
+            StepRes::OkQuery(Box::new(|records| {
+                assert_eq!(ids_of(records), vec![2, 1, 3, 5, 4]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM person ORDER BY born DESC",
+            StepRes::OkQuery(Box::new(|records| {
+                assert_eq!(ids_of(records), vec![5, 3, 1, 2, 4]);
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_fuzzy_date_conversion() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT born::DATE AS d FROM person WHERE id = 1",
+            StepRes::OkQuery(Box::new(|records| {
+                let d: Vec<Option<NaiveDate>> = records
+                    .map(|r| {
+                        r.get::<NaiveDate>(&RecordIndex::Name(SchemaIndex::from("d")))
+                            .unwrap()
+                    })
+                    .collect();
+                assert_eq!(d, vec![Some(NaiveDate::from_ymd(1923, 5, 17))]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT born::DATE AS d FROM person WHERE id = 2",
+            StepRes::Err(SqlState::DataExceptionIllegalConversion),
+        ))
+        .add_step(Step::new(
+            "SELECT FUZZY DATE '1920-13' AS d FROM person",
+            StepRes::Err(SqlState::DataExceptionDatetimeFieldOverflow),
+        ))
+        .run()
+        .await;
+}
@@ -0,0 +1,167 @@
+mod sql_test;
+
+use apllodb_server::{test_support::test_setup, RecordIndex, SchemaIndex, SqlState};
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+use sql_test::{SqlTest, Step, StepRes, Steps};
+
+#[ctor::ctor]
+fn setup() {
+    test_setup();
+}
+
+#[async_std::test]
+async fn test_unquoted_identifier_is_case_insensitive() {
+    SqlTest::default()
+        .add_steps(Steps::BeginTransaction)
+        .add_step(Step::new(
+            "CREATE TABLE People (ID BIGINT NOT NULL, Age INTEGER, PRIMARY KEY (Id))",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "INSERT INTO PEOPLE (id, AGE) VALUES (1, 13)",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "SELECT People.Id, aGe FROM people",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(
+                    r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("people.id")))
+                        .unwrap(),
+                    Some(1)
+                );
+                assert_eq!(
+                    r.get::<i32>(&RecordIndex::Name(SchemaIndex::from("age")))
+                        .unwrap(),
+                    Some(13)
+                );
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // quoted identifier is case-sensitive
+            r#"SELECT "ID" FROM people"#,
+            StepRes::Err(SqlState::NameErrorNotFound),
+        ))
+        .add_step(Step::new(
+            r#"SELECT "id" FROM "people""#,
+            StepRes::OkQuery(Box::new(|records| {
+                assert_eq!(records.count(), 1);
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_quoted_identifier() {
+    SqlTest::default()
+        .add_steps(Steps::BeginTransaction)
+        .add_step(Step::new(
+            r#"CREATE TABLE "Order" ("select" BIGINT NOT NULL, "Mixed Case" TEXT, "a""b.c" INTEGER, PRIMARY KEY ("select"))"#,
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            r#"INSERT INTO "Order" ("select", "Mixed Case", "a""b.c") VALUES (1, 'x', 10), (2, 'y', 20)"#,
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            r#"UPDATE "Order" SET "Mixed Case" = 'z' WHERE "a""b.c" = 20"#,
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            r#"SELECT "select", "Order"."Mixed Case", "a""b.c" FROM "Order""#,
+            StepRes::OkQuery(Box::new(|records| {
+                let rows: Vec<(i64, String, i32)> = records
+                    .map(|r| {
+                        (
+                            r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("select")))
+                                .unwrap()
+                                .unwrap(),
+                            r.get::<String>(&RecordIndex::Name(SchemaIndex::new(
+                                Some("Order".to_string()),
+                                "Mixed Case".to_string(),
+                            )))
+                            .unwrap()
+                            .unwrap(),
+                            r.get::<i32>(&RecordIndex::Name(SchemaIndex::new(
+                                None,
+                                r#"a"b.c"#.to_string(),
+                            )))
+                            .unwrap()
+                            .unwrap(),
+                        )
+                    })
+                    .sorted()
+                    .collect();
+                assert_eq!(
+                    rows,
+                    vec![(1, "x".to_string(), 10), (2, "z".to_string(), 20)]
+                );
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            r#"SELECT "mixed case" FROM "Order""#,
+            StepRes::Err(SqlState::NameErrorNotFound),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_identifiers_differing_only_in_case() {
+    SqlTest::default()
+        .add_steps(Steps::BeginTransaction)
+        .add_step(Step::new(
+            r#"CREATE TABLE "Foo" (id BIGINT NOT NULL, a INTEGER, PRIMARY KEY (id))"#,
+            StepRes::Ok,
+        ))
+        // the storage engine cannot tell them apart
+        .add_step(Step::new(
+            "CREATE TABLE foo (id BIGINT NOT NULL, PRIMARY KEY (id))",
+            StepRes::Err(SqlState::NameErrorDuplicate),
+        ))
+        .add_step(Step::new(
+            r#"CREATE TABLE bar (id BIGINT NOT NULL, a INTEGER, "A" INTEGER, PRIMARY KEY (id))"#,
+            StepRes::Err(SqlState::NameErrorDuplicate),
+        ))
+        .add_step(Step::new(
+            r#"ALTER TABLE "Foo" ADD COLUMN "A" INTEGER"#,
+            StepRes::Err(SqlState::NameErrorDuplicate),
+        ))
+        // a primary key column and a non-primary key column can
+        .add_step(Step::new(
+            r#"CREATE TABLE baz (id BIGINT NOT NULL, "ID" INTEGER, PRIMARY KEY (id))"#,
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            r#"INSERT INTO baz (id, "ID") VALUES (1, 2)"#,
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            r#"SELECT id, "ID" FROM baz"#,
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(
+                    r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                        .unwrap(),
+                    Some(1)
+                );
+                assert_eq!(
+                    r.get::<i32>(&RecordIndex::Name(SchemaIndex::new(None, "ID".to_string())))
+                        .unwrap(),
+                    Some(2)
+                );
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            r#"INSERT INTO "Foo" (id, a) VALUES (1, 10)"#,
+            StepRes::Ok,
+        ))
+        .run()
+        .await;
+}
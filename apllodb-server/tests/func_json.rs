@@ -0,0 +1,221 @@
+mod sql_test;
+
+use apllodb_server::{test_support::test_setup, Json, RecordIndex, SchemaIndex, SqlState};
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+use sql_test::{SqlTest, Step, StepRes, Steps};
+
+#[ctor::ctor]
+fn setup() {
+    test_setup();
+}
+
+fn setup_steps(sql_test: SqlTest) -> SqlTest {
+    sql_test
+        .add_steps(Steps::BeginTransaction)
+        .add_step(Step::new(
+            "CREATE TABLE docs (id BIGINT NOT NULL, doc JSON, PRIMARY KEY (id))",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            r#"INSERT INTO docs (id, doc) VALUES
+              (1, JSON '{"name": "apple", "tags": ["red", "sweet"], "price": {"yen": 100}}'),
+              (2, '{"name": "lemon", "tags": [], "price": null}'),
+              (3, JSON '[1, 2, 3]'),
+              (4, NULL)"#,
+            StepRes::Ok,
+        ))
+}
+
+fn json(s: &str) -> Json {
+    s.parse().unwrap()
+}
+
+fn sorted_ids_where(condition: &str, expected: Vec<i64>) -> Step {
+    Step::new(
+        format!("SELECT id FROM docs WHERE {}", condition),
+        StepRes::OkQuery(Box::new(move |records| {
+            let ids: Vec<i64> = records
+                .map(|r| {
+                    r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                        .unwrap()
+                        .unwrap()
+                })
+                .sorted()
+                .collect();
+            assert_eq!(ids, expected);
+            Ok(())
+        })),
+    )
+}
+
+#[async_std::test]
+async fn test_json_persistence() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT id, doc FROM docs",
+            StepRes::OkQuery(Box::new(|records| {
+                let rows: Vec<(i64, Option<Json>)> = records
+                    .map(|r| {
+                        (
+                            r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                                .unwrap()
+                                .unwrap(),
+                            r.get::<Json>(&RecordIndex::Name(SchemaIndex::from("doc")))
+                                .unwrap(),
+                        )
+                    })
+                    .sorted_by_key(|row| row.0)
+                    .collect();
+                assert_eq!(
+                    rows,
+                    vec![
+                        (
+                            1,
+                            Some(json(
+                                r#"{"name":"apple","price":{"yen":100},"tags":["red","sweet"]}"#
+                            ))
+                        ),
+                        (2, Some(json(r#"{"name":"lemon","price":null,"tags":[]}"#))),
+                        (3, Some(json("[1,2,3]"))),
+                        (4, None),
+                    ]
+                );
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_json_operators() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT doc -> 'tags' AS tags, doc ->> 'name' AS name, doc -> 'price' ->> 'yen' AS yen, doc -> 'tags' ->> -1 AS last_tag FROM docs WHERE id = 1",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(
+                    r.get::<Json>(&RecordIndex::Name(SchemaIndex::from("tags")))
+                        .unwrap(),
+                    Some(json(r#"["red", "sweet"]"#))
+                );
+                assert_eq!(
+                    r.get::<String>(&RecordIndex::Name(SchemaIndex::from("name")))
+                        .unwrap(),
+                    Some("apple".to_string())
+                );
+                assert_eq!(
+                    r.get::<String>(&RecordIndex::Name(SchemaIndex::from("yen")))
+                        .unwrap(),
+                    Some("100".to_string())
+                );
+                assert_eq!(
+                    r.get::<String>(&RecordIndex::Name(SchemaIndex::from("last_tag")))
+                        .unwrap(),
+                    Some("sweet".to_string())
+                );
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT doc ->> 'price' AS price, doc -> 'missing' AS missing FROM docs WHERE id = 2",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                // JSON null is NULL as TEXT
+                assert_eq!(
+                    r.get::<String>(&RecordIndex::Name(SchemaIndex::from("price")))
+                        .unwrap(),
+                    None
+                );
+                assert_eq!(
+                    r.get::<Json>(&RecordIndex::Name(SchemaIndex::from("missing")))
+                        .unwrap(),
+                    None
+                );
+                Ok(())
+            })),
+        ))
+        .add_step(sorted_ids_where("doc ->> 'name' = 'lemon'", vec![2]))
+        .add_step(sorted_ids_where("doc -> 1 = JSON '2'", vec![3]))
+        .add_step(sorted_ids_where(
+            r#"doc = JSON '{"price": null, "tags": [], "name": "lemon"}'"#,
+            vec![2],
+        ))
+        // documents are not ordered
+        .add_step(sorted_ids_where("doc < JSON '[1, 2, 4]'", vec![]))
+        .add_step(sorted_ids_where("doc IS NULL", vec![4]))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_json_functions() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT json_extract(doc, '$.price.yen') AS yen, json_array_length(doc -> 'tags') AS n_tags FROM docs WHERE id = 1",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(
+                    r.get::<Json>(&RecordIndex::Name(SchemaIndex::from("yen")))
+                        .unwrap(),
+                    Some(json("100"))
+                );
+                assert_eq!(
+                    r.get::<i32>(&RecordIndex::Name(SchemaIndex::from("n_tags")))
+                        .unwrap(),
+                    Some(2)
+                );
+                Ok(())
+            })),
+        ))
+        .add_step(sorted_ids_where(
+            "json_array_length(doc -> 'tags') = 0",
+            vec![2],
+        ))
+        .add_step(Step::new(
+            "SELECT json_array_length(doc) FROM docs WHERE id = 1",
+            StepRes::Err(SqlState::DataExceptionInvalidParameterValue),
+        ))
+        .add_step(Step::new(
+            "SELECT json_extract(doc, 'price') FROM docs WHERE id = 1",
+            StepRes::Err(SqlState::DataExceptionInvalidParameterValue),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_invalid_json() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "INSERT INTO docs (id, doc) VALUES (5, JSON '{\"a\": }')",
+            StepRes::Err(SqlState::DataExceptionInvalidJsonText),
+        ))
+        .add_step(Step::new(
+            "INSERT INTO docs (id, doc) VALUES (5, 'not json')",
+            StepRes::Err(SqlState::DataExceptionInvalidJsonText),
+        ))
+        .add_step(Step::new(
+            "SELECT doc ->> 1.5 FROM docs WHERE id = 3",
+            StepRes::Err(SqlState::DataExceptionIllegalOperation),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_order_by_json() {
+    setup_steps(SqlTest::default())
+        // JSON is not ordered
+        .add_step(Step::new(
+            "SELECT id, doc FROM docs ORDER BY doc",
+            StepRes::Err(SqlState::DataExceptionIllegalOperation),
+        ))
+        .add_step(Step::new(
+            "SELECT id, doc -> 'a' AS a FROM docs ORDER BY a DESC",
+            StepRes::Err(SqlState::DataExceptionIllegalOperation),
+        ))
+        .run()
+        .await;
+}
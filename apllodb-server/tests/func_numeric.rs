@@ -0,0 +1,236 @@
+mod sql_test;
+
+use std::str::FromStr;
+
+use apllodb_server::{test_support::test_setup, Decimal, RecordIndex, SchemaIndex, SqlState};
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+use sql_test::{SqlTest, Step, StepRes, Steps};
+
+#[ctor::ctor]
+fn setup() {
+    test_setup();
+}
+
+fn d(s: &str) -> Decimal {
+    Decimal::from_str(s).unwrap()
+}
+
+fn setup_steps(sql_test: SqlTest) -> SqlTest {
+    sql_test
+        .add_steps(Steps::BeginTransaction)
+        .add_step(Step::new(
+            "CREATE TABLE product (id BIGINT NOT NULL, price NUMERIC(10, 2), weight REAL, ratio DOUBLE PRECISION, PRIMARY KEY (id))",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "INSERT INTO product (id, price, weight, ratio) VALUES (1, 19.99, 1.5, 0.25), (2, 5, 0.25, 1e-3), (3, 100.005, NULL, 2.5)",
+            StepRes::Ok,
+        ))
+}
+
+#[async_std::test]
+async fn test_numeric_persistence() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT id, price, weight, ratio FROM product",
+            StepRes::OkQuery(Box::new(|records| {
+                type Row = (i64, Option<Decimal>, Option<f32>, Option<f64>);
+                let rows: Vec<Row> = records
+                    .map(|r| {
+                        (
+                            r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                                .unwrap()
+                                .unwrap(),
+                            r.get::<Decimal>(&RecordIndex::Name(SchemaIndex::from("price")))
+                                .unwrap(),
+                            r.get::<f32>(&RecordIndex::Name(SchemaIndex::from("weight")))
+                                .unwrap(),
+                            r.get::<f64>(&RecordIndex::Name(SchemaIndex::from("ratio")))
+                                .unwrap(),
+                        )
+                    })
+                    .sorted_by_key(|row| row.0)
+                    .collect();
+                assert_eq!(
+                    rows,
+                    vec![
+                        (1, Some(d("19.99")), Some(1.5), Some(0.25)),
+                        (2, Some(d("5")), Some(0.25), Some(0.001)),
+                        // rounded into NUMERIC(10, 2)
+                        (3, Some(d("100.01")), None, Some(2.5)),
+                    ]
+                );
+                // scale of NUMERIC(10, 2) is kept
+                let prices: Vec<String> =
+                    rows.iter().map(|row| row.1.unwrap().to_string()).collect();
+                assert_eq!(prices, vec!["19.99", "5.00", "100.01"]);
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_numeric_comparison_and_arithmetic() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            // NUMERIC vs integer, REAL vs NUMERIC, DOUBLE PRECISION vs DOUBLE PRECISION
+            "SELECT id FROM product WHERE price = 5 OR weight = 1.50 OR ratio > 2e0",
+            StepRes::OkQuery(Box::new(|records| {
+                let ids: Vec<i64> = records
+                    .map(|r| {
+                        r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                            .unwrap()
+                            .unwrap()
+                    })
+                    .sorted()
+                    .collect();
+                assert_eq!(ids, vec![1, 2, 3]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM product ORDER BY price DESC",
+            StepRes::OkQuery(Box::new(|records| {
+                let ids: Vec<i64> = records
+                    .map(|r| {
+                        r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                            .unwrap()
+                            .unwrap()
+                    })
+                    .collect();
+                assert_eq!(ids, vec![3, 1, 2]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT price * 3 AS p, price / 3 AS q, weight + 1 AS w, ratio * 2 AS r FROM product WHERE id = 1",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(
+                    r.get::<Decimal>(&RecordIndex::Name(SchemaIndex::from("p")))
+                        .unwrap()
+                        .unwrap()
+                        .to_string(),
+                    "59.97"
+                );
+                assert_eq!(
+                    r.get::<Decimal>(&RecordIndex::Name(SchemaIndex::from("q")))
+                        .unwrap()
+                        .unwrap()
+                        .to_string(),
+                    "6.6633333333333333"
+                );
+                // REAL + SMALLINT is DOUBLE PRECISION
+                assert_eq!(
+                    r.get::<f64>(&RecordIndex::Name(SchemaIndex::from("w")))
+                        .unwrap(),
+                    Some(2.5)
+                );
+                assert_eq!(
+                    r.get::<f64>(&RecordIndex::Name(SchemaIndex::from("r")))
+                        .unwrap(),
+                    Some(0.5)
+                );
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "UPDATE product SET price = price + 0.015 WHERE id = 2",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "SELECT price FROM product WHERE id = 2",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(
+                    r.get::<Decimal>(&RecordIndex::Name(SchemaIndex::from("price")))
+                        .unwrap()
+                        .unwrap()
+                        .to_string(),
+                    "5.02"
+                );
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_numeric_cast() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT CAST(price AS INTEGER) AS i, price::TEXT AS t, '1.5e2'::REAL AS r, CAST(ratio AS NUMERIC(3, 1)) AS n FROM product WHERE id = 1",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(
+                    r.get::<i32>(&RecordIndex::Name(SchemaIndex::from("i")))
+                        .unwrap(),
+                    Some(20)
+                );
+                assert_eq!(
+                    r.get::<String>(&RecordIndex::Name(SchemaIndex::from("t")))
+                        .unwrap(),
+                    Some("19.99".to_string())
+                );
+                assert_eq!(
+                    r.get::<f32>(&RecordIndex::Name(SchemaIndex::from("r")))
+                        .unwrap(),
+                    Some(150.0)
+                );
+                assert_eq!(
+                    r.get::<Decimal>(&RecordIndex::Name(SchemaIndex::from("n")))
+                        .unwrap()
+                        .unwrap()
+                        .to_string(),
+                    "0.3"
+                );
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_numeric_errors() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "CREATE TABLE t (id BIGINT NOT NULL, c NUMERIC(2, 3), PRIMARY KEY (id))",
+            StepRes::Err(SqlState::DataExceptionInvalidParameterValue),
+        ))
+        .add_step(Step::new(
+            "SELECT CAST(price AS NUMERIC(3, 2)) FROM product",
+            StepRes::Err(SqlState::DataExceptionNumericValueOutOfRange),
+        ))
+        .add_step(Step::new(
+            "SELECT price / 0 FROM product",
+            StepRes::Err(SqlState::DataExceptionDivisionByZero),
+        ))
+        .add_step(Step::new(
+            "SELECT ratio * 1e308 FROM product WHERE id = 3",
+            StepRes::Err(SqlState::DataExceptionNumericValueOutOfRange),
+        ))
+        .add_step(Step::new(
+            "SELECT CAST('1.2.3' AS NUMERIC) FROM product",
+            StepRes::Err(SqlState::DataExceptionInvalidCharacterValueForCast),
+        ))
+        .add_step(Step::new(
+            // NUMERIC(10, 2) has at most 8 integer digits
+            "INSERT INTO product (id, price) VALUES (100, 123456789)",
+            StepRes::Err(SqlState::DataExceptionNumericValueOutOfRange),
+        ))
+        .add_step(Step::new(
+            "UPDATE product SET price = 99999999.995 WHERE id = 1",
+            StepRes::Err(SqlState::DataExceptionNumericValueOutOfRange),
+        ))
+        .add_step(Step::new(
+            "INSERT INTO product (id, weight) VALUES (100, 1e39)",
+            StepRes::Err(SqlState::DataExceptionNumericValueOutOfRange),
+        ))
+        .run()
+        .await;
+}
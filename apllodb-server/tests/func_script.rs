@@ -0,0 +1,113 @@
+mod sql_test;
+
+use apllodb_server::{test_support::test_setup, RecordIndex, SchemaIndex, SqlState};
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+use sql_test::{SqlTest, Step, StepRes, Steps};
+
+#[ctor::ctor]
+fn setup() {
+    test_setup();
+}
+
+fn sorted_ids(sql: &str, expected: Vec<i64>) -> Step {
+    Step::new(
+        sql,
+        StepRes::OkQuery(Box::new(move |records| {
+            let ids: Vec<i64> = records
+                .map(|r| {
+                    r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                        .unwrap()
+                        .unwrap()
+                })
+                .sorted()
+                .collect();
+            assert_eq!(ids, expected);
+            Ok(())
+        })),
+    )
+}
+
+#[async_std::test]
+async fn test_comment() {
+    SqlTest::default()
+        .add_steps(Steps::BeginTransaction)
+        .add_step(Step::new(
+            "CREATE TABLE t (id INTEGER, /* no NOT NULL */ PRIMARY KEY (id)) -- trailing",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "-- heading\nINSERT INTO t (id) VALUES (1), /* 2 is skipped, /* nested */ */ (3);",
+            StepRes::Ok,
+        ))
+        .add_step(sorted_ids("SELECT id FROM t -- comment", vec![1, 3]))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_script() {
+    SqlTest::default()
+        .add_step(Step::script(
+            r#"
+            -- Migration
+            BEGIN;
+            CREATE TABLE t (id INTEGER, PRIMARY KEY (id));
+            INSERT INTO t (id) VALUES (1), (2);
+            COMMIT;
+
+            /* Check */
+            BEGIN;
+            SELECT id FROM t;
+            INSERT INTO t (id) VALUES (3);
+            SELECT id FROM t WHERE id = 2;
+            "#,
+            StepRes::OkQuery(Box::new(|records| {
+                assert_eq!(records.count(), 1);
+                Ok(())
+            })),
+        ))
+        // session is still in the transaction started in the script
+        .add_step(sorted_ids("SELECT id FROM t", vec![1, 2, 3]))
+        .add_step(Step::new("COMMIT", StepRes::Ok))
+        .add_step(Step::script("", StepRes::Ok))
+        .add_step(Step::script(";; -- nothing to do", StepRes::Ok))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_script_syntax_error() {
+    SqlTest::default()
+        .add_step(Step::script(
+            "BEGIN; CREATE TABLE t (id INTEGER, PRIMARY KEY (id)); DROP TABLE;",
+            StepRes::Err(SqlState::SyntaxErrorOrAccessRuleViolationSyntaxError),
+        ))
+        // no statement has been run
+        .add_steps(Steps::BeginTransaction)
+        .add_step(Step::new(
+            "SELECT id FROM t",
+            StepRes::Err(SqlState::NameErrorNotFound),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_script_stops_at_error() {
+    SqlTest::default()
+        .add_step(Step::script(
+            r#"
+            BEGIN;
+            CREATE TABLE t (id INTEGER, PRIMARY KEY (id));
+            INSERT INTO t (id) VALUES (1);
+            INSERT INTO t (id) VALUES (1);
+            INSERT INTO t (id) VALUES (2);
+            "#,
+            StepRes::Err(SqlState::IntegrityConstraintUniqueViolation),
+        ))
+        // statements before the error have been run in the transaction
+        .add_step(sorted_ids("SELECT id FROM t", vec![1]))
+        .run()
+        .await;
+}
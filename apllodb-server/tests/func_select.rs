@@ -145,6 +145,105 @@ async fn test_selection() {
                 })),
             ),
         )
+        .add_step(
+            // comparison operators
+            Step::new(
+                "SELECT id, age FROM people WHERE age > 20",
+                StepRes::OkQuery(Box::new(|records| {
+                    let id_index = RecordIndex::Name(SchemaIndex::from("id"));
+                    let ids: Vec<i64> = records
+                        .map(|r| r.get::<i64>(&id_index).unwrap().unwrap())
+                        .sorted()
+                        .collect();
+                    assert_eq!(ids, vec![2, 3]);
+                    Ok(())
+                })),
+            ),
+        )
+        .add_step(Step::new(
+            "SELECT id, age FROM people WHERE age >= 35",
+            StepRes::OkQuery(Box::new(|records| {
+                assert_eq!(records.count(), 2);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id, age FROM people WHERE id < 2",
+            StepRes::OkQuery(Box::new(|mut records| {
+                assert_eq!(records.next(), Some(Record::fx_people1()));
+                assert!(records.next().is_none());
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id, age FROM people WHERE 2 <= id",
+            StepRes::OkQuery(Box::new(|records| {
+                assert_eq!(records.count(), 2);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id, age FROM people WHERE age <> 70",
+            StepRes::OkQuery(Box::new(|records| {
+                assert_eq!(records.count(), 2);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id, age FROM people WHERE age != 13",
+            StepRes::OkQuery(Box::new(|records| {
+                assert_eq!(records.count(), 2);
+                Ok(())
+            })),
+        ))
+        .add_step(
+            // logical operators
+            Step::new(
+                "SELECT id, age FROM people WHERE age > 20 AND id <> 3",
+                StepRes::OkQuery(Box::new(|mut records| {
+                    assert_eq!(records.next(), Some(Record::fx_people2()));
+                    assert!(records.next().is_none());
+                    Ok(())
+                })),
+            ),
+        )
+        .add_step(Step::new(
+            "SELECT id, age FROM people WHERE id = 1 OR age = 70",
+            StepRes::OkQuery(Box::new(|records| {
+                let id_index = RecordIndex::Name(SchemaIndex::from("id"));
+                let ids: Vec<i64> = records
+                    .map(|r| r.get::<i64>(&id_index).unwrap().unwrap())
+                    .sorted()
+                    .collect();
+                assert_eq!(ids, vec![1, 2]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id, age FROM people WHERE NOT (id = 1 OR id = 2)",
+            StepRes::OkQuery(Box::new(|mut records| {
+                assert_eq!(records.next(), Some(Record::fx_people3()));
+                assert!(records.next().is_none());
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // NOT UNKNOWN is UNKNOWN
+            "SELECT id, age FROM people WHERE NOT NULL = 1",
+            StepRes::OkQuery(Box::new(|records| {
+                assert_eq!(records.count(), 0);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // UNKNOWN OR TRUE is TRUE
+            "SELECT id, age FROM people WHERE NULL OR id = 1",
+            StepRes::OkQuery(Box::new(|mut records| {
+                assert_eq!(records.next(), Some(Record::fx_people1()));
+                assert!(records.next().is_none());
+                Ok(())
+            })),
+        ))
         .add_step(
             // NULL is evaluated as FALSE
             Step::new(
@@ -166,6 +265,164 @@ async fn test_selection() {
         .await;
 }
 
+#[async_std::test]
+async fn test_null_predicate() {
+    let ids = |records: apllodb_server::Records| -> Vec<i64> {
+        records
+            .map(|r| {
+                r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                    .unwrap()
+                    .unwrap()
+            })
+            .sorted()
+            .collect()
+    };
+
+    SqlTest::default()
+        .add_step(Step::new("BEGIN", StepRes::Ok))
+        .add_step(Step::new(
+            "CREATE TABLE t (id BIGINT NOT NULL, c INTEGER, PRIMARY KEY (id))",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "INSERT INTO t (id, c) VALUES (1, 10)",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "INSERT INTO t (id, c) VALUES (2, NULL)",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM t WHERE c IS NULL",
+            StepRes::OkQuery(Box::new(move |records| {
+                assert_eq!(ids(records), vec![2]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM t WHERE c IS NOT NULL",
+            StepRes::OkQuery(Box::new(move |records| {
+                assert_eq!(ids(records), vec![1]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // `= NULL` is always UNKNOWN
+            "SELECT id FROM t WHERE c = NULL",
+            StepRes::OkQuery(Box::new(move |records| {
+                assert_eq!(ids(records), Vec::<i64>::new());
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM t WHERE c + 1 IS NULL OR id IS NULL",
+            StepRes::OkQuery(Box::new(move |records| {
+                assert_eq!(ids(records), vec![2]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new("UPDATE t SET c = 0 WHERE c IS NULL", StepRes::Ok))
+        .add_step(Step::new(
+            "SELECT id FROM t WHERE c IS NOT NULL",
+            StepRes::OkQuery(Box::new(move |records| {
+                assert_eq!(ids(records), vec![1, 2]);
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_in_between_like() {
+    let ids = |records: apllodb_server::Records| -> Vec<i64> {
+        records
+            .map(|r| {
+                r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                    .unwrap()
+                    .unwrap()
+            })
+            .sorted()
+            .collect()
+    };
+
+    SqlTest::default()
+        .add_step(Step::new("BEGIN", StepRes::Ok))
+        .add_step(Step::new(
+            "CREATE TABLE t (id BIGINT NOT NULL, c INTEGER, name TEXT, PRIMARY KEY (id))",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "INSERT INTO t (id, c, name) VALUES (1, 10, 'foo')",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "INSERT INTO t (id, c, name) VALUES (2, 20, 'foobar')",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "INSERT INTO t (id, c, name) VALUES (3, NULL, 'Foo')",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM t WHERE c IN (10, 30)",
+            StepRes::OkQuery(Box::new(move |records| {
+                assert_eq!(ids(records), vec![1]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // NULL operand is UNKNOWN also in NOT IN
+            "SELECT id FROM t WHERE c NOT IN (10, 30)",
+            StepRes::OkQuery(Box::new(move |records| {
+                assert_eq!(ids(records), vec![2]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM t WHERE id IN (1, c / 10) AND id <> 1",
+            StepRes::OkQuery(Box::new(move |records| {
+                assert_eq!(ids(records), vec![2]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM t WHERE c BETWEEN 10 AND 15 OR id BETWEEN 3 AND 3",
+            StepRes::OkQuery(Box::new(move |records| {
+                assert_eq!(ids(records), vec![1, 3]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM t WHERE c NOT BETWEEN 10 AND 15",
+            StepRes::OkQuery(Box::new(move |records| {
+                assert_eq!(ids(records), vec![2]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // case-sensitive
+            "SELECT id FROM t WHERE name LIKE 'foo%'",
+            StepRes::OkQuery(Box::new(move |records| {
+                assert_eq!(ids(records), vec![1, 2]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM t WHERE name NOT LIKE '_oo'",
+            StepRes::OkQuery(Box::new(move |records| {
+                assert_eq!(ids(records), vec![2]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM t WHERE c LIKE '1%'",
+            StepRes::Err(SqlState::DataExceptionIllegalOperation),
+        ))
+        .run()
+        .await;
+}
+
 #[async_std::test]
 async fn test_sort() {
     SqlTest::default()
@@ -263,6 +520,97 @@ async fn test_sort() {
         .await;
 }
 
+#[async_std::test]
+async fn test_limit() {
+    SqlTest::default()
+        .add_steps(Steps::SetupPeopleDataset)
+        .add_step(Step::new("BEGIN", StepRes::Ok))
+        .add_step(Step::new(
+            "SELECT id, age FROM people ORDER BY age LIMIT 2",
+            StepRes::OkQuery(Box::new(|mut records| {
+                assert_eq!(records.next(), Some(Record::fx_people1()));
+                assert_eq!(records.next(), Some(Record::fx_people3()));
+                assert!(records.next().is_none());
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id, age FROM people ORDER BY age DESC LIMIT 2 OFFSET 1",
+            StepRes::OkQuery(Box::new(|mut records| {
+                assert_eq!(records.next(), Some(Record::fx_people3()));
+                assert_eq!(records.next(), Some(Record::fx_people1()));
+                assert!(records.next().is_none());
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id, age FROM people ORDER BY id OFFSET 1",
+            StepRes::OkQuery(Box::new(|mut records| {
+                assert_eq!(records.next(), Some(Record::fx_people2()));
+                assert_eq!(records.next(), Some(Record::fx_people3()));
+                assert!(records.next().is_none());
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id, age FROM people ORDER BY id OFFSET 1 ROWS FETCH FIRST 1 ROWS ONLY",
+            StepRes::OkQuery(Box::new(|mut records| {
+                assert_eq!(records.next(), Some(Record::fx_people2()));
+                assert!(records.next().is_none());
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // count is 1 if omitted
+            "SELECT id, age FROM people ORDER BY id DESC FETCH NEXT ROW ONLY",
+            StepRes::OkQuery(Box::new(|mut records| {
+                assert_eq!(records.next(), Some(Record::fx_people3()));
+                assert!(records.next().is_none());
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // LIMIT larger than records
+            "SELECT id, age FROM people ORDER BY id LIMIT 10",
+            StepRes::OkQuery(Box::new(|mut records| {
+                assert_eq!(records.next(), Some(Record::fx_people1()));
+                assert_eq!(records.next(), Some(Record::fx_people2()));
+                assert_eq!(records.next(), Some(Record::fx_people3()));
+                assert!(records.next().is_none());
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // OFFSET larger than records
+            "SELECT id, age FROM people ORDER BY id LIMIT 10 OFFSET 5",
+            StepRes::OkQuery(Box::new(|mut records| {
+                assert!(records.next().is_none());
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id, age FROM people ORDER BY id LIMIT 0",
+            StepRes::OkQuery(Box::new(|mut records| {
+                assert!(records.next().is_none());
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // without ORDER BY
+            "SELECT id FROM people LIMIT 2",
+            StepRes::OkQuery(Box::new(|records| {
+                assert_eq!(records.count(), 2);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM people LIMIT 99999999999999999999999",
+            StepRes::Err(SqlState::DataExceptionNumericValueOutOfRange),
+        ))
+        .run()
+        .await;
+}
+
 #[async_std::test]
 async fn test_inner_join() {
     SqlTest::default()
@@ -329,7 +677,209 @@ async fn test_inner_join() {
                 Ok(())
             })),
         ))
-        // TODO 3-table join
+        .add_step(Step::new(
+            "SELECT people.id, body.height, pet.kind FROM people INNER JOIN body ON people.id = body.people_id INNER JOIN pet ON people.id = pet.people_id",
+            StepRes::OkQuery(Box::new(|records| {
+                let rows: Vec<(i64, i32, String)> = records
+                    .map(|r| {
+                        (
+                            r.get::<i64>(&idx("people.id")).unwrap().unwrap(),
+                            r.get::<i32>(&idx("height")).unwrap().unwrap(),
+                            r.get::<String>(&idx("kind")).unwrap().unwrap(),
+                        )
+                    })
+                    .sorted()
+                    .collect();
+                assert_eq!(
+                    rows,
+                    vec![
+                        (1, 145, "dog".to_string()),
+                        (3, 175, "cat".to_string()),
+                        (3, 175, "dog".to_string()),
+                    ]
+                );
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+fn idx(name: &str) -> RecordIndex {
+    RecordIndex::Name(SchemaIndex::from(name))
+}
+
+#[async_std::test]
+async fn test_outer_join() {
+    SqlTest::default()
+        .add_steps(Steps::SetupPeopleBodyPetDataset)
+        .add_step(Step::new("BEGIN", StepRes::Ok))
+        .add_step(Step::new(
+            "SELECT people.id, body.height FROM people LEFT OUTER JOIN body ON people.id = body.people_id",
+            StepRes::OkQuery(Box::new(|records| {
+                let rows: Vec<(i64, Option<i32>)> = records
+                    .map(|r| {
+                        (
+                            r.get::<i64>(&idx("people.id")).unwrap().unwrap(),
+                            r.get::<i32>(&idx("height")).unwrap(),
+                        )
+                    })
+                    .sorted()
+                    .collect();
+                assert_eq!(rows, vec![(1, Some(145)), (2, None), (3, Some(175))]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // operands of ON are matched to each side by their tables
+            "SELECT people.id, body.height FROM people LEFT JOIN body ON body.people_id = people.id",
+            StepRes::OkQuery(Box::new(|records| {
+                let rows: Vec<(i64, Option<i32>)> = records
+                    .map(|r| {
+                        (
+                            r.get::<i64>(&idx("people.id")).unwrap().unwrap(),
+                            r.get::<i32>(&idx("height")).unwrap(),
+                        )
+                    })
+                    .sorted()
+                    .collect();
+                assert_eq!(rows, vec![(1, Some(145)), (2, None), (3, Some(175))]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT people.id FROM people LEFT JOIN pet ON people.id = pet.people_id AND pet.kind = 'dog'",
+            StepRes::Err(SqlState::FeatureNotSupported),
+        ))
+        .add_step(Step::new(
+            "SELECT people.id FROM people LEFT JOIN pet ON people.id = people.age",
+            StepRes::Err(SqlState::FeatureNotSupported),
+        ))
+        .add_step(Step::new(
+            "SELECT people.id, body.height FROM body RIGHT JOIN people ON body.people_id = people.id",
+            StepRes::OkQuery(Box::new(|records| {
+                let rows: Vec<(i64, Option<i32>)> = records
+                    .map(|r| {
+                        (
+                            r.get::<i64>(&idx("people.id")).unwrap().unwrap(),
+                            r.get::<i32>(&idx("height")).unwrap(),
+                        )
+                    })
+                    .sorted()
+                    .collect();
+                assert_eq!(rows, vec![(1, Some(145)), (2, None), (3, Some(175))]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // people.age INTEGER and pet.age SMALLINT are comparable
+            "SELECT people.id, pet.id FROM people FULL OUTER JOIN pet ON people.age = pet.age",
+            StepRes::OkQuery(Box::new(|records| {
+                let rows: Vec<(Option<i64>, Option<i64>)> = records
+                    .map(|r| {
+                        (
+                            r.get::<i64>(&idx("people.id")).unwrap(),
+                            r.get::<i64>(&idx("pet.id")).unwrap(),
+                        )
+                    })
+                    .sorted()
+                    .collect();
+                assert_eq!(
+                    rows,
+                    vec![
+                        (None, Some(2)),
+                        (None, Some(3)),
+                        (Some(1), Some(1)),
+                        (Some(2), None),
+                        (Some(3), None),
+                    ]
+                );
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // people without pets
+            "SELECT people.id FROM people LEFT JOIN pet ON people.id = pet.people_id WHERE pet.id IS NULL",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(r.get::<i64>(&idx("people.id"))?, Some(2));
+                assert!(records.next().is_none());
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT people.id, body.height, pet.kind FROM people LEFT JOIN body ON people.id = body.people_id LEFT JOIN pet ON people.id = pet.people_id",
+            StepRes::OkQuery(Box::new(|records| {
+                let rows: Vec<(i64, Option<i32>, Option<String>)> = records
+                    .map(|r| {
+                        (
+                            r.get::<i64>(&idx("people.id")).unwrap().unwrap(),
+                            r.get::<i32>(&idx("height")).unwrap(),
+                            r.get::<String>(&idx("kind")).unwrap(),
+                        )
+                    })
+                    .sorted()
+                    .collect();
+                assert_eq!(
+                    rows,
+                    vec![
+                        (1, Some(145), Some("dog".to_string())),
+                        (2, None, None),
+                        (3, Some(175), Some("cat".to_string())),
+                        (3, Some(175), Some("dog".to_string())),
+                    ]
+                );
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_cross_join() {
+    SqlTest::default()
+        .add_steps(Steps::SetupPeopleBodyPetDataset)
+        .add_step(Step::new("BEGIN", StepRes::Ok))
+        .add_step(Step::new(
+            "SELECT people.id, pet.id FROM people CROSS JOIN pet",
+            StepRes::OkQuery(Box::new(|records| {
+                let rows: Vec<(i64, i64)> = records
+                    .map(|r| {
+                        (
+                            r.get::<i64>(&idx("people.id")).unwrap().unwrap(),
+                            r.get::<i64>(&idx("pet.id")).unwrap().unwrap(),
+                        )
+                    })
+                    .sorted()
+                    .collect();
+                assert_eq!(
+                    rows,
+                    (1..=3)
+                        .cartesian_product(1..=3)
+                        .collect::<Vec<(i64, i64)>>()
+                );
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT people.id FROM people, body WHERE people.id = body.people_id",
+            StepRes::OkQuery(Box::new(|records| {
+                let ids: Vec<i64> = records
+                    .map(|r| r.get::<i64>(&idx("people.id")).unwrap().unwrap())
+                    .sorted()
+                    .collect();
+                assert_eq!(ids, vec![1, 3]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT p.id, b.height FROM people AS p, body b, pet",
+            StepRes::OkQuery(Box::new(|records| {
+                assert_eq!(records.count(), 3 * 2 * 3);
+                Ok(())
+            })),
+        ))
         .run()
         .await;
 }
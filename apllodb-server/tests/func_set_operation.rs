@@ -0,0 +1,367 @@
+mod sql_test;
+
+use apllodb_server::{test_support::test_setup, RecordIndex, SchemaIndex, SqlState};
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+use sql_test::{SqlTest, Step, StepRes, Steps};
+
+#[ctor::ctor]
+fn setup() {
+    test_setup();
+}
+
+fn idx(name: &str) -> RecordIndex {
+    RecordIndex::Name(SchemaIndex::from(name))
+}
+
+fn setup_steps(sql_test: SqlTest) -> SqlTest {
+    sql_test
+        .add_steps(Steps::SetupPeopleBodyPetDataset)
+        .add_step(Step::new("BEGIN", StepRes::Ok))
+}
+
+#[async_std::test]
+async fn test_distinct() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT DISTINCT kind FROM pet",
+            StepRes::OkQuery(Box::new(|records| {
+                let kinds: Vec<String> = records
+                    .map(|r| r.get::<String>(&idx("kind")).unwrap().unwrap())
+                    .sorted()
+                    .collect();
+                assert_eq!(kinds, vec!["cat".to_string(), "dog".to_string()]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT DISTINCT people_id, kind FROM pet",
+            StepRes::OkQuery(Box::new(|records| {
+                assert_eq!(records.count(), 3);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT ALL people_id FROM pet",
+            StepRes::OkQuery(Box::new(|records| {
+                assert_eq!(records.count(), 3);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // LIMIT is applied after duplicates are eliminated
+            "SELECT DISTINCT people_id FROM pet ORDER BY people_id DESC LIMIT 1 OFFSET 1",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(r.get::<i64>(&idx("people_id"))?, Some(1));
+                assert!(records.next().is_none());
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // records eliminated as duplicates may have different `id`s
+            "SELECT DISTINCT people_id FROM pet ORDER BY id",
+            StepRes::Err(SqlState::SyntaxErrorOrAccessRuleViolationInvalidColumnReference),
+        ))
+        .add_step(Step::new(
+            "SELECT DISTINCT pet.people_id AS p FROM pet ORDER BY p DESC",
+            StepRes::OkQuery(Box::new(|records| {
+                let ps: Vec<i64> = records
+                    .map(|r| r.get::<i64>(&idx("p")).unwrap().unwrap())
+                    .collect();
+                assert_eq!(ps, vec![3, 1]);
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_distinct_null() {
+    SqlTest::default()
+        .add_steps(Steps::BeginTransaction)
+        .add_step(Step::new(
+            "CREATE TABLE t (id INTEGER NOT NULL, c INTEGER, PRIMARY KEY (id))",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "INSERT INTO t (id, c) VALUES (1, NULL), (2, 1), (3, NULL), (4, 1)",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            // NULLs are not distinct from each other
+            "SELECT DISTINCT c FROM t",
+            StepRes::OkQuery(Box::new(|records| {
+                let cs: Vec<Option<i32>> = records
+                    .map(|r| r.get::<i32>(&idx("c")).unwrap())
+                    .sorted()
+                    .collect();
+                assert_eq!(cs, vec![None, Some(1)]);
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_union() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT id FROM people UNION SELECT people_id FROM pet",
+            StepRes::OkQuery(Box::new(|records| {
+                let ids: Vec<i64> = records
+                    .map(|r| r.get::<i64>(&idx("id")).unwrap().unwrap())
+                    .sorted()
+                    .collect();
+                assert_eq!(ids, vec![1, 2, 3]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM people UNION ALL SELECT people_id FROM pet",
+            StepRes::OkQuery(Box::new(|records| {
+                let ids: Vec<i64> = records
+                    .map(|r| r.get::<i64>(&idx("id")).unwrap().unwrap())
+                    .sorted()
+                    .collect();
+                assert_eq!(ids, vec![1, 1, 2, 3, 3, 3]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // INTEGER and SMALLINT are compatible
+            "SELECT age FROM people UNION SELECT age FROM pet",
+            StepRes::OkQuery(Box::new(|records| {
+                let ages: Vec<i32> = records
+                    .map(|r| r.get::<i32>(&idx("age")).unwrap().unwrap())
+                    .sorted()
+                    .collect();
+                assert_eq!(ages, vec![3, 5, 13, 35, 70]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // the same table in both operands
+            "SELECT id FROM people WHERE age < 20 UNION ALL SELECT id FROM people WHERE age > 50",
+            StepRes::OkQuery(Box::new(|records| {
+                let ids: Vec<i64> = records
+                    .map(|r| r.get::<i64>(&idx("id")).unwrap().unwrap())
+                    .sorted()
+                    .collect();
+                assert_eq!(ids, vec![1, 2]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT people.id FROM people INNER JOIN pet ON people.id = pet.people_id UNION SELECT people.id FROM people INNER JOIN body ON people.id = body.people_id",
+            StepRes::OkQuery(Box::new(|records| {
+                let ids: Vec<i64> = records
+                    .map(|r| r.get::<i64>(&idx("id")).unwrap().unwrap())
+                    .sorted()
+                    .collect();
+                assert_eq!(ids, vec![1, 3]);
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_intersect_except() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT id FROM people INTERSECT SELECT people_id FROM pet",
+            StepRes::OkQuery(Box::new(|records| {
+                let ids: Vec<i64> = records
+                    .map(|r| r.get::<i64>(&idx("id")).unwrap().unwrap())
+                    .sorted()
+                    .collect();
+                assert_eq!(ids, vec![1, 3]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT people_id FROM pet INTERSECT ALL SELECT people_id FROM pet WHERE kind = 'dog'",
+            StepRes::OkQuery(Box::new(|records| {
+                let ids: Vec<i64> = records
+                    .map(|r| r.get::<i64>(&idx("people_id")).unwrap().unwrap())
+                    .sorted()
+                    .collect();
+                assert_eq!(ids, vec![1, 3]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM people EXCEPT SELECT people_id FROM pet",
+            StepRes::OkQuery(Box::new(|records| {
+                let ids: Vec<i64> = records
+                    .map(|r| r.get::<i64>(&idx("id")).unwrap().unwrap())
+                    .collect();
+                assert_eq!(ids, vec![2]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT people_id FROM pet EXCEPT ALL SELECT id FROM people",
+            StepRes::OkQuery(Box::new(|records| {
+                let ids: Vec<i64> = records
+                    .map(|r| r.get::<i64>(&idx("people_id")).unwrap().unwrap())
+                    .collect();
+                assert_eq!(ids, vec![3]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // INTERSECT binds tighter than EXCEPT
+            "SELECT id FROM people EXCEPT SELECT people_id FROM pet INTERSECT SELECT people_id FROM pet WHERE kind = 'cat'",
+            StepRes::OkQuery(Box::new(|records| {
+                let ids: Vec<i64> = records
+                    .map(|r| r.get::<i64>(&idx("id")).unwrap().unwrap())
+                    .sorted()
+                    .collect();
+                assert_eq!(ids, vec![1, 2]);
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_set_operation_with_order_by_limit() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT id FROM people UNION ALL SELECT people_id FROM pet ORDER BY id DESC LIMIT 3",
+            StepRes::OkQuery(Box::new(|records| {
+                let ids: Vec<i64> = records
+                    .map(|r| r.get::<i64>(&idx("id")).unwrap().unwrap())
+                    .collect();
+                assert_eq!(ids, vec![3, 3, 3]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM people UNION SELECT people_id FROM pet ORDER BY id OFFSET 1",
+            StepRes::OkQuery(Box::new(|records| {
+                let ids: Vec<i64> = records
+                    .map(|r| r.get::<i64>(&idx("id")).unwrap().unwrap())
+                    .collect();
+                assert_eq!(ids, vec![2, 3]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            // ORDER BY must refer to the result of set operations
+            "SELECT id FROM people UNION SELECT people_id FROM pet ORDER BY age",
+            StepRes::Err(SqlState::NameErrorNotFound),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_set_operation_mismatch() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT id, age FROM people UNION SELECT people_id FROM pet",
+            StepRes::Err(SqlState::SyntaxErrorOrAccessRuleViolationDatatypeMismatch),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM people INTERSECT SELECT kind FROM pet",
+            StepRes::Err(SqlState::SyntaxErrorOrAccessRuleViolationDatatypeMismatch),
+        ))
+        .add_step(Step::new(
+            // types are checked even if inputs are empty
+            "SELECT id FROM people WHERE id > 100 EXCEPT SELECT kind FROM pet WHERE people_id > 100",
+            StepRes::Err(SqlState::SyntaxErrorOrAccessRuleViolationDatatypeMismatch),
+        ))
+        .add_step(Step::new(
+            "SELECT id FROM people UNION SELECT people_id FROM pet UNION SELECT 'x'",
+            StepRes::Err(SqlState::SyntaxErrorOrAccessRuleViolationDatatypeMismatch),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_set_operation_null() {
+    SqlTest::default()
+        .add_steps(Steps::BeginTransaction)
+        .add_step(Step::new(
+            "CREATE TABLE t (id INTEGER NOT NULL, c INTEGER, PRIMARY KEY (id))",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "INSERT INTO t (id, c) VALUES (1, NULL), (2, 1), (3, NULL)",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            // NULLs are not distinct from each other
+            "SELECT c FROM t WHERE id = 1 INTERSECT SELECT c FROM t WHERE id >= 2",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(r.get::<i32>(&idx("c"))?, None);
+                assert!(records.next().is_none());
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT c FROM t EXCEPT SELECT c FROM t WHERE id = 3",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                assert_eq!(r.get::<i32>(&idx("c"))?, Some(1));
+                assert!(records.next().is_none());
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_set_operation_fuzzy_date() {
+    SqlTest::default()
+        .add_steps(Steps::BeginTransaction)
+        .add_step(Step::new(
+            "CREATE TABLE person (id BIGINT NOT NULL, born FUZZY DATE, PRIMARY KEY (id))",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "INSERT INTO person (id, born) VALUES (1, FUZZY DATE '1920s'), (2, FUZZY DATE '1920s')",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            // ambiguous values are not distinct from themselves although `=` is not TRUE for them
+            "SELECT DISTINCT born FROM person",
+            StepRes::OkQuery(Box::new(|records| {
+                assert_eq!(records.count(), 1);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT born FROM person UNION SELECT born FROM person",
+            StepRes::OkQuery(Box::new(|records| {
+                assert_eq!(records.count(), 1);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT born FROM person INTERSECT ALL SELECT born FROM person WHERE id = 1",
+            StepRes::OkQuery(Box::new(|records| {
+                assert_eq!(records.count(), 1);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "SELECT born FROM person EXCEPT SELECT born FROM person WHERE id = 2",
+            StepRes::OkQuery(Box::new(|records| {
+                assert_eq!(records.count(), 0);
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
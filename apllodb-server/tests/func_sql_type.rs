@@ -110,10 +110,7 @@ async fn test_text() {
             StepRes::Ok,
         ))
         .add_step(Step::new(
-            format!(
-                r#"INSERT INTO t (c) VALUES ("{}")"#,
-                r#"abc🍺@'\"#
-            ),
+            format!("INSERT INTO t (c) VALUES ('{}')", r#"abc🍺@''"\"#),
             StepRes::Ok,
         ))
         .add_step(Step::new(
@@ -126,10 +123,7 @@ async fn test_text() {
                     r.get::<i64>(&index).unwrap_err().kind(),
                     &SqlState::DataExceptionIllegalConversion
                 );
-                assert_eq!(
-                    r.get::<String>(&index).unwrap().unwrap(),
-                    r#"abc🍺@'\"#
-                );
+                assert_eq!(r.get::<String>(&index).unwrap().unwrap(), r#"abc🍺@'"\"#);
                 Ok(())
             })),
         ))
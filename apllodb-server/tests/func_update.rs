@@ -78,3 +78,47 @@ async fn test_update() {
         .run()
         .await;
 }
+
+#[async_std::test]
+async fn test_update_with_logical_condition() {
+    SqlTest::default()
+        .add_steps(Steps::SetupPeopleDataset)
+        .add_step(Step::new("BEGIN", StepRes::Ok))
+        .add_step(Step::new(
+            "UPDATE people SET age = 0 WHERE id = 1 OR age = 70",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "SELECT id, age FROM people WHERE age = 0",
+            StepRes::OkQuery(Box::new(|rec_iter| {
+                let mut ids: Vec<i64> = rec_iter
+                    .map(|r| {
+                        r.get::<i64>(&RecordIndex::Name(SchemaIndex::from("id")))
+                            .unwrap()
+                            .unwrap()
+                    })
+                    .collect();
+                ids.sort_unstable();
+                assert_eq!(ids, vec![1, 2]);
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "UPDATE people SET age = 100 WHERE NOT age = 0",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "SELECT id, age FROM people WHERE id = 3",
+            StepRes::OkQuery(Box::new(|mut rec_iter| {
+                let r = rec_iter.next().unwrap();
+                assert_eq!(
+                    r.get::<i32>(&RecordIndex::Name(SchemaIndex::from("age")))
+                        .unwrap(),
+                    Some(100)
+                );
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
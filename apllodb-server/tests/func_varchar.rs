@@ -0,0 +1,181 @@
+mod sql_test;
+
+use apllodb_server::{test_support::test_setup, RecordIndex, SchemaIndex, SqlState};
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+use sql_test::{SqlTest, Step, StepRes, Steps};
+
+#[ctor::ctor]
+fn setup() {
+    test_setup();
+}
+
+fn setup_steps(sql_test: SqlTest) -> SqlTest {
+    sql_test
+        .add_steps(Steps::BeginTransaction)
+        .add_step(Step::new(
+            "CREATE TABLE people (id VARCHAR(3) NOT NULL, name CHARACTER VARYING(5), code CHAR(4), flag CHARACTER, PRIMARY KEY (id))",
+            StepRes::Ok,
+        ))
+        .add_step(Step::new(
+            "INSERT INTO people (id, name, code, flag) VALUES
+              ('a', 'alice', 'ab', 'y'),
+              ('b', 'bob', 'abcd', NULL),
+              ('c', 'carol   ', 'ab  ', 'n')",
+            StepRes::Ok,
+        ))
+}
+
+fn sorted_ids_where(condition: &str, expected: Vec<&'static str>) -> Step {
+    Step::new(
+        format!("SELECT id FROM people WHERE {}", condition),
+        StepRes::OkQuery(Box::new(move |records| {
+            let ids: Vec<String> = records
+                .map(|r| {
+                    r.get::<String>(&RecordIndex::Name(SchemaIndex::from("id")))
+                        .unwrap()
+                        .unwrap()
+                })
+                .sorted()
+                .collect();
+            assert_eq!(ids, expected);
+            Ok(())
+        })),
+    )
+}
+
+#[async_std::test]
+async fn test_varchar_and_char_persistence() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT id, name, code, flag FROM people",
+            StepRes::OkQuery(Box::new(|records| {
+                type Row = (String, String, String, Option<String>);
+                let rows: Vec<Row> = records
+                    .map(|r| {
+                        let get = |name: &str| {
+                            r.get::<String>(&RecordIndex::Name(SchemaIndex::from(name)))
+                                .unwrap()
+                        };
+                        (
+                            get("id").unwrap(),
+                            get("name").unwrap(),
+                            get("code").unwrap(),
+                            get("flag"),
+                        )
+                    })
+                    .sorted_by_key(|row| row.0.clone())
+                    .collect();
+                assert_eq!(
+                    rows,
+                    vec![
+                        (
+                            "a".to_string(),
+                            "alice".to_string(),
+                            // padded into CHAR(4)
+                            "ab  ".to_string(),
+                            Some("y".to_string())
+                        ),
+                        ("b".to_string(), "bob".to_string(), "abcd".to_string(), None),
+                        (
+                            "c".to_string(),
+                            // excess spaces are truncated
+                            "carol".to_string(),
+                            "ab  ".to_string(),
+                            Some("n".to_string())
+                        ),
+                    ]
+                );
+                Ok(())
+            })),
+        ))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_char_comparison() {
+    setup_steps(SqlTest::default())
+        // trailing spaces are insignificant for CHAR
+        .add_step(sorted_ids_where("code = 'ab'", vec!["a", "c"]))
+        .add_step(sorted_ids_where("code = 'ab      '", vec!["a", "c"]))
+        .add_step(sorted_ids_where("code > 'ab'", vec!["b"]))
+        .add_step(sorted_ids_where("code LIKE '%b'", vec!["a", "c"]))
+        // but significant for VARCHAR
+        .add_step(sorted_ids_where("name = 'bob '", vec![]))
+        .add_step(sorted_ids_where("name = 'bob'", vec!["b"]))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_string_data_right_truncation() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "INSERT INTO people (id, name) VALUES ('d', 'dorothy')",
+            StepRes::Err(SqlState::DataExceptionStringDataRightTruncation),
+        ))
+        .add_step(Step::new(
+            "INSERT INTO people (id, code) VALUES ('d', 'abcde')",
+            StepRes::Err(SqlState::DataExceptionStringDataRightTruncation),
+        ))
+        .add_step(Step::new(
+            "INSERT INTO people (id) VALUES ('dddd')",
+            StepRes::Err(SqlState::DataExceptionStringDataRightTruncation),
+        ))
+        .add_step(Step::new(
+            "UPDATE people SET name = 'alice!' WHERE id = 'a'",
+            StepRes::Err(SqlState::DataExceptionStringDataRightTruncation),
+        ))
+        .add_step(Step::new(
+            "UPDATE people SET name = 'eve' WHERE id = 'a'",
+            StepRes::Ok,
+        ))
+        .add_step(sorted_ids_where("name = 'eve'", vec!["a"]))
+        .add_step(Step::new(
+            // non-string values are checked after converted into strings
+            "INSERT INTO people (id, name) VALUES ('d', 123456)",
+            StepRes::Err(SqlState::DataExceptionStringDataRightTruncation),
+        ))
+        .add_step(Step::new(
+            "INSERT INTO people (id) VALUES (1234)",
+            StepRes::Err(SqlState::DataExceptionStringDataRightTruncation),
+        ))
+        .add_step(Step::new(
+            "UPDATE people SET name = 123456 WHERE id = 'a'",
+            StepRes::Err(SqlState::DataExceptionStringDataRightTruncation),
+        ))
+        .add_step(Step::new(
+            "INSERT INTO people (id, name) VALUES (123, 12345)",
+            StepRes::Ok,
+        ))
+        .add_step(sorted_ids_where("name = '12345'", vec!["123"]))
+        .run()
+        .await;
+}
+
+#[async_std::test]
+async fn test_cast_truncates() {
+    setup_steps(SqlTest::default())
+        .add_step(Step::new(
+            "SELECT CAST(name AS VARCHAR(2)) AS v, CAST(name AS CHAR(7)) AS c, code::TEXT AS t FROM people WHERE id = 'a'",
+            StepRes::OkQuery(Box::new(|mut records| {
+                let r = records.next().unwrap();
+                let get = |name: &str| {
+                    r.get::<String>(&RecordIndex::Name(SchemaIndex::from(name)))
+                        .unwrap()
+                        .unwrap()
+                };
+                assert_eq!(get("v"), "al");
+                assert_eq!(get("c"), "alice  ");
+                assert_eq!(get("t"), "ab");
+                Ok(())
+            })),
+        ))
+        .add_step(Step::new(
+            "CREATE TABLE t (id INTEGER NOT NULL, c VARCHAR(0), PRIMARY KEY (id))",
+            StepRes::Err(SqlState::DataExceptionInvalidParameterValue),
+        ))
+        .run()
+        .await;
+}
@@ -45,10 +45,10 @@ async fn test_scenario_010_pre_demo() {
             r#"
             INSERT INTO company (id, name, hq_area, num_employees)
             VALUES
-              (101, "Sony", "Tokyo", 3000),
-              (102, "KONAMI", "Tokyo", 1000),
-              (103, "Hudson", "Tokyo", 500),
-              (104, "Ericsson", "Sweden", 1200);
+              (101, 'Sony', 'Tokyo', 3000),
+              (102, 'KONAMI', 'Tokyo', 1000),
+              (103, 'Hudson', 'Tokyo', 500),
+              (104, 'Ericsson', 'Sweden', 1200);
             "#,
             StepRes::Ok,
         ))
@@ -176,7 +176,7 @@ async fn test_scenario_010_pre_demo() {
             })),
         ))
         .add_step(Step::new(
-            r#"SELECT id, name FROM company WHERE hq_area = "Tokyo" ORDER BY name ASC;"#,
+            "SELECT id, name FROM company WHERE hq_area = 'Tokyo' ORDER BY name ASC;",
             StepRes::OkQuery(Box::new(|mut records| {
                 let r = records.next().unwrap();
                 assert_eq!(
@@ -390,7 +390,7 @@ async fn test_scenario_010_pre_demo() {
             r#"
             INSERT INTO company (id, name, hq_area, num_employees, market_cap)
               VALUES
-              (105, "Scala", "Tokyo", 300, 12900000000);
+              (105, 'Scala', 'Tokyo', 300, 12900000000);
             "#,
             StepRes::Ok,
         ))
@@ -399,7 +399,7 @@ async fn test_scenario_010_pre_demo() {
             r#"
             INSERT INTO company (id, name, hq_area, num_employees)
               VALUES
-              (106, "SOFTBRAIN", "Hokkaido", 500);
+              (106, 'SOFTBRAIN', 'Hokkaido', 500);
             "#,
             StepRes::Ok,
         ))
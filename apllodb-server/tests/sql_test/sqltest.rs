@@ -11,6 +11,14 @@ pub struct SqlTest {
 }
 
 impl SqlTest {
+    #[allow(dead_code)]
+    pub fn with_server(server: ApllodbServer) -> Self {
+        Self {
+            server,
+            steps: vec![],
+        }
+    }
+
     /// NOTE: do not pass database command like "CREATE DATABASE" / "USE DATABASE" / ...
     /// Database is automatically created / used in run().
     pub fn add_step(mut self, step: Step) -> Self {
@@ -1,5 +1,5 @@
-use apllodb_server::{ApllodbCommandSuccess, ApllodbServer};
-use apllodb_shared_components::Session;
+use apllodb_server::{ApllodbCommandSuccess, ApllodbScriptSuccess, ApllodbServer};
+use apllodb_shared_components::{ApllodbSessionError, Session};
 
 use self::step_res::StepRes;
 
@@ -10,6 +10,7 @@ pub(crate) mod steps;
 pub struct Step {
     sql: String,
     expected: StepRes,
+    script: bool,
 }
 
 impl Step {
@@ -17,10 +18,26 @@ impl Step {
         Self {
             sql: sql.into(),
             expected,
+            script: false,
+        }
+    }
+
+    /// Step to run semicolon-separated SQLs by ApllodbServer::command_script().
+    /// StepRes::OkQuery checks the records of the last SELECT.
+    #[allow(dead_code)]
+    pub fn script(sql: impl Into<String>, expected: StepRes) -> Self {
+        Self {
+            sql: sql.into(),
+            expected,
+            script: true,
         }
     }
 
     pub(super) async fn run(&self, server: &ApllodbServer, session: Session) -> Session {
+        if self.script {
+            return self.run_script(server, session).await;
+        }
+
         match server.command(session, self.sql.to_string()).await {
             Ok(success) => match success {
                 ApllodbCommandSuccess::QueryResponse {
@@ -71,27 +88,55 @@ impl Step {
                     Session::from(session)
                 }
             },
-            Err(sess_err) => {
-                let e = sess_err.err;
+            Err(sess_err) => self.check_err(sess_err),
+        }
+    }
 
+    async fn run_script(&self, server: &ApllodbServer, session: Session) -> Session {
+        match server.command_script(session, self.sql.to_string()).await {
+            Ok(ApllodbScriptSuccess { session, records }) => {
                 match &self.expected {
-                    StepRes::Err(kind) => {
-                        assert_eq!(
-                            kind,
-                            e.kind(),
-                            "\nexpected {:?} but got {:?} (got error detail follows)\n{:#?}\n",
-                            kind,
-                            e.kind(),
-                            e
-                        );
+                    StepRes::OkQuery(f) => {
+                        let last_records = records.into_iter().last().unwrap_or_else(|| {
+                            panic!(
+                                "StepRes::OkQuery needs SELECT SQL in script - step: {:#?}",
+                                self
+                            )
+                        });
+                        f(last_records).unwrap_or_else(|e| {
+                            panic!("closure in StepRes::OkQuery caused error: {:#?}", e)
+                        })
+                    }
+                    StepRes::Ok => {}
+                    StepRes::Err(_) => {
+                        panic!("SQL script has unexpectedly succeeded - step: {:#?}", self)
                     }
-                    _ => panic!(
-                        "unexpected error {} on ApllodbServer::command() - step: {:#?}",
-                        e, self
-                    ),
                 }
-                sess_err.session
+                session
+            }
+            Err(sess_err) => self.check_err(sess_err),
+        }
+    }
+
+    fn check_err(&self, sess_err: ApllodbSessionError) -> Session {
+        let e = sess_err.err;
+
+        match &self.expected {
+            StepRes::Err(kind) => {
+                assert_eq!(
+                    kind,
+                    e.kind(),
+                    "\nexpected {:?} but got {:?} (got error detail follows)\n{:#?}\n",
+                    kind,
+                    e.kind(),
+                    e
+                );
             }
+            _ => panic!(
+                "unexpected error {} on ApllodbServer - step: {:#?}",
+                e, self
+            ),
         }
+        sess_err.session
     }
 }
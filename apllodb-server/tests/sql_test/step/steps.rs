@@ -73,15 +73,15 @@ impl From<Steps> for Vec<Step> {
                 let mut steps = Self::from(Steps::CreateTablePet);
                 steps.push(Step::new("BEGIN", StepRes::Ok));
                 steps.push(Step::new(
-                    r#"INSERT INTO pet (id, people_id, kind, age) VALUES (1, 1, "dog", 13)"#,
+                    "INSERT INTO pet (id, people_id, kind, age) VALUES (1, 1, 'dog', 13)",
                     StepRes::Ok,
                 ));
                 steps.push(Step::new(
-                    r#"INSERT INTO pet (id, people_id, kind, age) VALUES (2, 3, "dog", 5)"#,
+                    "INSERT INTO pet (id, people_id, kind, age) VALUES (2, 3, 'dog', 5)",
                     StepRes::Ok,
                 ));
                 steps.push(Step::new(
-                    r#"INSERT INTO pet (id, people_id, kind, age) VALUES (3, 3, "cat", 3)"#,
+                    "INSERT INTO pet (id, people_id, kind, age) VALUES (3, 3, 'cat', 3)",
                     StepRes::Ok,
                 ));
                 steps.push(Step::new("COMMIT", StepRes::Ok));
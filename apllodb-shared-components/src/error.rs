@@ -78,11 +78,48 @@ impl ApllodbError {
         )
     }
 
+    /// Constructor of [SqlState::SyntaxErrorOrAccessRuleViolationGroupingError](crate::SqlState::SyntaxErrorOrAccessRuleViolationGroupingError).
+    pub fn grouping_error(desc: impl ToString) -> Self {
+        Self::new(
+            SqlState::SyntaxErrorOrAccessRuleViolationGroupingError,
+            desc,
+            None,
+        )
+    }
+
+    /// Constructor of [SqlState::SyntaxErrorOrAccessRuleViolationDatatypeMismatch](crate::SqlState::SyntaxErrorOrAccessRuleViolationDatatypeMismatch).
+    pub fn datatype_mismatch(desc: impl ToString) -> Self {
+        Self::new(
+            SqlState::SyntaxErrorOrAccessRuleViolationDatatypeMismatch,
+            desc,
+            None,
+        )
+    }
+
+    /// Constructor of [SqlState::SyntaxErrorOrAccessRuleViolationInvalidColumnReference](crate::SqlState::SyntaxErrorOrAccessRuleViolationInvalidColumnReference).
+    pub fn invalid_column_reference(desc: impl ToString) -> Self {
+        Self::new(
+            SqlState::SyntaxErrorOrAccessRuleViolationInvalidColumnReference,
+            desc,
+            None,
+        )
+    }
+
+    /// Constructor of [SqlState::DataExceptionStringDataRightTruncation](crate::SqlState::DataExceptionStringDataRightTruncation).
+    pub fn data_exception_string_data_right_truncation(desc: impl ToString) -> Self {
+        Self::new(SqlState::DataExceptionStringDataRightTruncation, desc, None)
+    }
+
     /// Constructor of [SqlState::DataExceptionNumericValueOutOfRange](crate::SqlState::DataExceptionNumericValueOutOfRange).
     pub fn data_exception_numeric_value_out_of_range(desc: impl ToString) -> Self {
         Self::new(SqlState::DataExceptionNumericValueOutOfRange, desc, None)
     }
 
+    /// Constructor of [SqlState::DataExceptionDivisionByZero](crate::SqlState::DataExceptionDivisionByZero).
+    pub fn data_exception_division_by_zero(desc: impl ToString) -> Self {
+        Self::new(SqlState::DataExceptionDivisionByZero, desc, None)
+    }
+
     /// Constructor of [SqlState::DataExceptionIllegalConversion](crate::SqlState::DataExceptionIllegalConversion).
     pub fn data_exception_illegal_conversion(desc: impl ToString) -> Self {
         Self::new(SqlState::DataExceptionIllegalConversion, desc, None)
@@ -98,6 +135,44 @@ impl ApllodbError {
         Self::new(SqlState::DataExceptionIllegalOperation, desc, None)
     }
 
+    /// Constructor of [SqlState::DataExceptionInvalidCharacterValueForCast](crate::SqlState::DataExceptionInvalidCharacterValueForCast).
+    pub fn data_exception_invalid_character_value_for_cast(desc: impl ToString) -> Self {
+        Self::new(
+            SqlState::DataExceptionInvalidCharacterValueForCast,
+            desc,
+            None,
+        )
+    }
+
+    /// Constructor of [SqlState::DataExceptionInvalidParameterValue](crate::SqlState::DataExceptionInvalidParameterValue).
+    pub fn data_exception_invalid_parameter_value(desc: impl ToString) -> Self {
+        Self::new(SqlState::DataExceptionInvalidParameterValue, desc, None)
+    }
+
+    /// Constructor of [SqlState::DataExceptionInvalidJsonText](crate::SqlState::DataExceptionInvalidJsonText).
+    pub fn data_exception_invalid_json_text(desc: impl ToString) -> Self {
+        Self::new(SqlState::DataExceptionInvalidJsonText, desc, None)
+    }
+
+    /// Constructor of [SqlState::DataExceptionInvalidDatetimeFormat](crate::SqlState::DataExceptionInvalidDatetimeFormat).
+    pub fn data_exception_invalid_datetime_format(desc: impl ToString) -> Self {
+        Self::new(SqlState::DataExceptionInvalidDatetimeFormat, desc, None)
+    }
+
+    /// Constructor of [SqlState::DataExceptionDatetimeFieldOverflow](crate::SqlState::DataExceptionDatetimeFieldOverflow).
+    pub fn data_exception_datetime_field_overflow(desc: impl ToString) -> Self {
+        Self::new(SqlState::DataExceptionDatetimeFieldOverflow, desc, None)
+    }
+
+    /// Constructor of [SqlState::DataExceptionInvalidTimeZoneDisplacementValue](crate::SqlState::DataExceptionInvalidTimeZoneDisplacementValue).
+    pub fn data_exception_invalid_time_zone_displacement_value(desc: impl ToString) -> Self {
+        Self::new(
+            SqlState::DataExceptionInvalidTimeZoneDisplacementValue,
+            desc,
+            None,
+        )
+    }
+
     /// Constructor of [SqlState::IntegrityConstraintNotNullViolation](crate::SqlState::IntegrityConstraintNotNullViolation).
     pub fn integrity_constraint_not_null_violation(desc: impl ToString) -> Self {
         Self::new(SqlState::IntegrityConstraintNotNullViolation, desc, None)
@@ -130,6 +130,7 @@ pub enum SqlState {
     DataExceptionArrayDataRightTruncation,
     DataExceptionInvalidRepeatArgumentInASampleClause,
     DataExceptionInvalidSampleSize,
+    DataExceptionInvalidJsonText,
     DataExceptionIllegalConversion,
     DataExceptionIllegalComparison,
     DataExceptionIllegalOperation,
@@ -189,6 +190,9 @@ pub enum SqlState {
     TransactionRollbackDeadlock,
     SyntaxErrorOrAccessRuleViolation,
     SyntaxErrorOrAccessRuleViolationSyntaxError,
+    SyntaxErrorOrAccessRuleViolationGroupingError,
+    SyntaxErrorOrAccessRuleViolationDatatypeMismatch,
+    SyntaxErrorOrAccessRuleViolationInvalidColumnReference,
     WithCheckOptionViolation,
     ReservedForISO9579,
     IoError,
@@ -619,6 +623,9 @@ impl SqlState {
             DataExceptionInvalidSampleSize => {
                 SqlStateDetail::new(class22, "02H", "invalid sample size")
             }
+            DataExceptionInvalidJsonText => {
+                SqlStateDetail::new(class22, "032", "invalid JSON text")
+            }
             DataExceptionIllegalConversion => {
                 SqlStateDetail::new(class22, "I00", "illegal data conversion")
             }
@@ -768,6 +775,15 @@ impl SqlState {
             SyntaxErrorOrAccessRuleViolationSyntaxError => {
                 SqlStateDetail::new(class42, "I00", "syntax error")
             }
+            SyntaxErrorOrAccessRuleViolationGroupingError => {
+                SqlStateDetail::new(class42, "I01", "grouping error")
+            }
+            SyntaxErrorOrAccessRuleViolationDatatypeMismatch => {
+                SqlStateDetail::new(class42, "I02", "datatype mismatch")
+            }
+            SyntaxErrorOrAccessRuleViolationInvalidColumnReference => {
+                SqlStateDetail::new(class42, "I03", "invalid column reference")
+            }
             WithCheckOptionViolation => SqlStateDetail::new(class44, "000", "(no subclass)"),
             ReservedForISO9579 => SqlStateDetail::new(classHZ, "???", ""),
             IoError => SqlStateDetail::new(classIO, "000", "(no subclass)"),
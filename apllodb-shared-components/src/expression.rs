@@ -1,10 +1,17 @@
+pub(crate) mod arithmetic_function;
 pub(crate) mod boolean_expression;
+pub(crate) mod case_expression;
+pub(crate) mod function_call;
+pub(crate) mod match_expression;
 pub(crate) mod operator;
 
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    ApllodbResult, ComparisonFunction, LogicalFunction, NnSqlValue, SchemaIndex, SqlValue,
+    ApllodbError, ApllodbResult, ArithmeticFunction, BetweenPredicate, CaseExpression,
+    ComparisonFunction, FunctionCall, InPredicate, LikePredicate, LogicalFunction, MatchExpression,
+    NnSqlValue, NullPredicate, NumericComparableType, SchemaIndex, SqlCompareResult, SqlType,
+    SqlValue,
 };
 
 use self::{boolean_expression::BooleanExpression, operator::UnaryOperator};
@@ -21,8 +28,70 @@ pub enum Expression {
     /// With unary operator
     UnaryOperatorVariant(UnaryOperator, Box<Expression>),
 
+    /// Arithmetic expression
+    ArithmeticFunctionVariant(ArithmeticFunction),
+
     /// Boolean expression
     BooleanExpressionVariant(BooleanExpression),
+
+    /// Scalar function call
+    FunctionCallVariant(FunctionCall),
+
+    /// CASE expression
+    CaseExpressionVariant(CaseExpression),
+
+    /// MATCH expression on a user-defined type
+    MatchExpressionVariant(MatchExpression),
+
+    /// Explicit conversion: `CAST(expression AS sql_type)`
+    CastVariant(Box<Expression>, SqlType),
+}
+
+/// How comparisons of ambiguous values (see [SqlCompareResult::Indefinite](crate::SqlCompareResult::Indefinite)) are evaluated.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+enum Modality {
+    /// TRUE or FALSE if definite, otherwise UNKNOWN.
+    Exact,
+    /// TRUE if possible (operand of `POSSIBLY`).
+    Possibly,
+    /// TRUE only if definite (operand of `NOT` in `POSSIBLY`).
+    Certainly,
+}
+
+impl Modality {
+    /// Modality of the operand of `NOT`: `NOT x` is possible iff `x` is not certain.
+    fn negated(self) -> Self {
+        match self {
+            Modality::Exact => Modality::Exact,
+            Modality::Possibly => Modality::Certainly,
+            Modality::Certainly => Modality::Possibly,
+        }
+    }
+
+    /// Whether a relation holds (None stands for UNKNOWN).
+    ///
+    /// `holds` and `negation_holds` tell whether the relation and its negation definitely hold (e.g. `is_less_than` and `is_greater_than_or_equal` for `<`).
+    fn judge(
+        self,
+        sql_compare_result: &SqlCompareResult,
+        holds: fn(&SqlCompareResult) -> bool,
+        negation_holds: fn(&SqlCompareResult) -> bool,
+    ) -> Option<bool> {
+        match sql_compare_result {
+            SqlCompareResult::Null => None,
+            SqlCompareResult::Indefinite { .. } => {
+                let definitely = holds(sql_compare_result);
+                let possibly = !negation_holds(sql_compare_result);
+                match self {
+                    Modality::Exact if definitely || !possibly => Some(definitely),
+                    Modality::Exact => None,
+                    Modality::Possibly => Some(possibly),
+                    Modality::Certainly => Some(definitely),
+                }
+            }
+            _ => Some(holds(sql_compare_result)),
+        }
+    }
 }
 
 impl Expression {
@@ -32,6 +101,16 @@ impl Expression {
     fn to_sql_value(
         &self,
         value_from_index: &dyn Fn(&SchemaIndex) -> ApllodbResult<SqlValue>,
+    ) -> ApllodbResult<SqlValue> {
+        self.eval(value_from_index, Modality::Exact)
+    }
+
+    /// Evaluates boolean operators and predicates in `modality`.
+    /// Other expressions (e.g. operands of comparisons and arguments of functions) are always evaluated exactly.
+    fn eval(
+        &self,
+        value_from_index: &dyn Fn(&SchemaIndex) -> ApllodbResult<SqlValue>,
+        modality: Modality,
     ) -> ApllodbResult<SqlValue> {
         match self {
             Expression::ConstantVariant(sql_value) => Ok(sql_value.clone()),
@@ -45,35 +124,275 @@ impl Expression {
                     }
                 }
             }
+            Expression::ArithmeticFunctionVariant(arithmetic_function) => {
+                let (left, right) = arithmetic_function.operands();
+                let left_sql_value = left.to_sql_value(value_from_index)?;
+                let right_sql_value = right.to_sql_value(value_from_index)?;
+                match (left_sql_value, right_sql_value) {
+                    (SqlValue::NotNull(left_nn), SqlValue::NotNull(right_nn)) => {
+                        let nn_sql_value = match arithmetic_function {
+                            ArithmeticFunction::AddVariant { .. } => left_nn.add(&right_nn),
+                            ArithmeticFunction::SubtractVariant { .. } => {
+                                left_nn.subtract(&right_nn)
+                            }
+                            ArithmeticFunction::MultiplyVariant { .. } => {
+                                left_nn.multiply(&right_nn)
+                            }
+                            ArithmeticFunction::DivideVariant { .. } => left_nn.divide(&right_nn),
+                            ArithmeticFunction::ModuloVariant { .. } => left_nn.modulo(&right_nn),
+                        }?;
+                        Ok(SqlValue::NotNull(nn_sql_value))
+                    }
+                    _ => Ok(SqlValue::Null),
+                }
+            }
+            Expression::FunctionCallVariant(function_call) => {
+                let args = function_call
+                    .arguments()
+                    .iter()
+                    .map(|arg| arg.to_sql_value(value_from_index))
+                    .collect::<ApllodbResult<Vec<SqlValue>>>()?;
+                function_call.call(&args)
+            }
+            Expression::CastVariant(child, sql_type) => {
+                match child.to_sql_value(value_from_index)? {
+                    SqlValue::Null => Ok(SqlValue::Null),
+                    SqlValue::NotNull(nn_sql_value) => {
+                        Ok(SqlValue::NotNull(nn_sql_value.cast(sql_type)?))
+                    }
+                }
+            }
+            Expression::CaseExpressionVariant(case_expression) => {
+                let operand = case_expression
+                    .operand()
+                    .map(|operand| operand.to_sql_value(value_from_index))
+                    .transpose()?;
+
+                // evaluates WHEN clauses until the first match, and then only the chosen result
+                let mut chosen = case_expression.else_result();
+                for (when, then) in case_expression.when_clauses() {
+                    let when_sql_value = when.to_sql_value(value_from_index)?;
+                    let matched = match &operand {
+                        // `operand = when` (NULL never matches)
+                        Some(operand) => operand.sql_compare(&when_sql_value)?.is_equal(),
+                        // NULL (UNKNOWN) is not TRUE
+                        None => when_sql_value.to_bool()?,
+                    };
+                    if matched {
+                        chosen = Some(then);
+                        break;
+                    }
+                }
+
+                match chosen
+                    .map(|result| result.to_sql_value(value_from_index))
+                    .transpose()?
+                {
+                    Some(SqlValue::NotNull(nn_sql_value)) => Ok(SqlValue::NotNull(
+                        case_expression.cast_result(nn_sql_value)?,
+                    )),
+                    Some(SqlValue::Null) | None => Ok(SqlValue::Null),
+                }
+            }
+            Expression::MatchExpressionVariant(match_expression) => {
+                let operand = match match_expression.operand().to_sql_value(value_from_index)? {
+                    SqlValue::Null => return Ok(SqlValue::Null),
+                    SqlValue::NotNull(nn_sql_value) => nn_sql_value,
+                };
+                let (result, bound_values) = match_expression.choose(operand)?;
+
+                // bound names shadow columns
+                let value_from_index_or_binding = |idx: &SchemaIndex| {
+                    let bound = bound_values
+                        .iter()
+                        .find(|(name, _)| idx.prefix().is_none() && idx.attr() == name);
+                    match bound {
+                        Some((_, v)) => Ok(SqlValue::NotNull(v.clone())),
+                        None => value_from_index(idx),
+                    }
+                };
+                match result.to_sql_value(&value_from_index_or_binding)? {
+                    SqlValue::NotNull(nn_sql_value) => Ok(SqlValue::NotNull(
+                        match_expression.cast_result(nn_sql_value)?,
+                    )),
+                    SqlValue::Null => Ok(SqlValue::Null),
+                }
+            }
             Expression::BooleanExpressionVariant(bool_expr) => match bool_expr {
                 BooleanExpression::ComparisonFunctionVariant(comparison_function) => {
-                    match comparison_function {
-                        ComparisonFunction::EqualVariant { left, right } => {
-                            let left_sql_value = left.to_sql_value(value_from_index)?;
-                            let right_sql_value = right.to_sql_value(value_from_index)?;
-                            left_sql_value
-                                .sql_compare(&right_sql_value)
-                                .map(|sql_compare_result| {
-                                    SqlValue::NotNull(NnSqlValue::Boolean(
-                                        sql_compare_result.is_equal(),
-                                    ))
-                                })
-                        }
-                    }
+                    let (left, right) = comparison_function.operands();
+                    let left_sql_value = left.to_sql_value(value_from_index)?;
+                    let right_sql_value = right.to_sql_value(value_from_index)?;
+                    let sql_compare_result = left_sql_value.sql_compare(&right_sql_value)?;
+                    let b = match comparison_function {
+                        ComparisonFunction::EqualVariant { .. } => modality.judge(
+                            &sql_compare_result,
+                            SqlCompareResult::is_equal,
+                            SqlCompareResult::is_not_equal,
+                        ),
+                        ComparisonFunction::NotEqualVariant { .. } => modality.judge(
+                            &sql_compare_result,
+                            SqlCompareResult::is_not_equal,
+                            SqlCompareResult::is_equal,
+                        ),
+                        ComparisonFunction::LessThanVariant { .. } => modality.judge(
+                            &sql_compare_result,
+                            SqlCompareResult::is_less_than,
+                            SqlCompareResult::is_greater_than_or_equal,
+                        ),
+                        ComparisonFunction::LessThanOrEqualVariant { .. } => modality.judge(
+                            &sql_compare_result,
+                            SqlCompareResult::is_less_than_or_equal,
+                            SqlCompareResult::is_greater_than,
+                        ),
+                        ComparisonFunction::GreaterThanVariant { .. } => modality.judge(
+                            &sql_compare_result,
+                            SqlCompareResult::is_greater_than,
+                            SqlCompareResult::is_less_than_or_equal,
+                        ),
+                        ComparisonFunction::GreaterThanOrEqualVariant { .. } => modality.judge(
+                            &sql_compare_result,
+                            SqlCompareResult::is_greater_than_or_equal,
+                            SqlCompareResult::is_less_than,
+                        ),
+                    };
+                    Ok(b.map_or(SqlValue::Null, |b| {
+                        SqlValue::NotNull(NnSqlValue::Boolean(b))
+                    }))
                 }
                 BooleanExpression::LogicalFunctionVariant(logical_function) => {
-                    match logical_function {
+                    // three-valued logic: None stands for UNKNOWN
+                    let b: Option<bool> = match logical_function {
                         LogicalFunction::AndVariant { left, right } => {
-                            let left_sql_value =
-                                Expression::BooleanExpressionVariant(*(left.clone()))
-                                    .to_sql_value(value_from_index)?;
-                            let right_sql_value =
-                                Expression::BooleanExpressionVariant(*(right.clone()))
-                                    .to_sql_value(value_from_index)?;
-
-                            let b = left_sql_value.to_bool()? && right_sql_value.to_bool()?;
+                            let left_b =
+                                left.eval(value_from_index, modality)?.to_nullable_bool()?;
+                            let right_b =
+                                right.eval(value_from_index, modality)?.to_nullable_bool()?;
+                            match (left_b, right_b) {
+                                (Some(false), _) | (_, Some(false)) => Some(false),
+                                (Some(true), Some(true)) => Some(true),
+                                _ => None,
+                            }
+                        }
+                        LogicalFunction::OrVariant { left, right } => {
+                            let left_b =
+                                left.eval(value_from_index, modality)?.to_nullable_bool()?;
+                            let right_b =
+                                right.eval(value_from_index, modality)?.to_nullable_bool()?;
+                            match (left_b, right_b) {
+                                (Some(true), _) | (_, Some(true)) => Some(true),
+                                (Some(false), Some(false)) => Some(false),
+                                _ => None,
+                            }
+                        }
+                        LogicalFunction::NotVariant { child } => child
+                            .eval(value_from_index, modality.negated())?
+                            .to_nullable_bool()?
+                            .map(|b| !b),
+                        LogicalFunction::PossiblyVariant { child } => child
+                            .eval(value_from_index, Modality::Possibly)?
+                            .to_nullable_bool()?,
+                    };
+                    Ok(b.map_or(SqlValue::Null, |b| {
+                        SqlValue::NotNull(NnSqlValue::Boolean(b))
+                    }))
+                }
+                BooleanExpression::NullPredicateVariant(null_predicate) => {
+                    let is_null = matches!(
+                        null_predicate.child().to_sql_value(value_from_index)?,
+                        SqlValue::Null
+                    );
+                    let b = match null_predicate {
+                        NullPredicate::IsNullVariant { .. } => is_null,
+                        NullPredicate::IsNotNullVariant { .. } => !is_null,
+                    };
+                    Ok(SqlValue::NotNull(NnSqlValue::Boolean(b)))
+                }
+                BooleanExpression::InPredicateVariant(in_predicate) => {
+                    let child_sql_value = in_predicate.child().to_sql_value(value_from_index)?;
+
+                    // `x IN (a, b)` is `x = a OR x = b`: None stands for UNKNOWN
+                    // (`x NOT IN (a, b)` is possible iff `x IN (a, b)` is not certain)
+                    let modality = match in_predicate {
+                        InPredicate::InVariant { .. } => modality,
+                        InPredicate::NotInVariant { .. } => modality.negated(),
+                    };
+                    let mut found: Option<bool> = Some(false);
+                    for expr in in_predicate.list() {
+                        let sql_value = expr.to_sql_value(value_from_index)?;
+                        match modality.judge(
+                            &child_sql_value.sql_compare(&sql_value)?,
+                            SqlCompareResult::is_equal,
+                            SqlCompareResult::is_not_equal,
+                        ) {
+                            Some(true) => {
+                                found = Some(true);
+                                break;
+                            }
+                            Some(false) => {}
+                            None => found = None,
+                        }
+                    }
+
+                    let b = match in_predicate {
+                        InPredicate::InVariant { .. } => found,
+                        InPredicate::NotInVariant { .. } => found.map(|b| !b),
+                    };
+                    Ok(b.map_or(SqlValue::Null, |b| {
+                        SqlValue::NotNull(NnSqlValue::Boolean(b))
+                    }))
+                }
+                BooleanExpression::BetweenPredicateVariant(between_predicate) => {
+                    let (child, low, high) = between_predicate.operands();
+                    let child_sql_value = child.to_sql_value(value_from_index)?;
+                    let low_cmp =
+                        child_sql_value.sql_compare(&low.to_sql_value(value_from_index)?)?;
+                    let high_cmp =
+                        child_sql_value.sql_compare(&high.to_sql_value(value_from_index)?)?;
+
+                    // `x BETWEEN a AND b` is `x >= a AND x <= b`: None stands for UNKNOWN
+                    let modality = match between_predicate {
+                        BetweenPredicate::BetweenVariant { .. } => modality,
+                        BetweenPredicate::NotBetweenVariant { .. } => modality.negated(),
+                    };
+                    let ge_low = modality.judge(
+                        &low_cmp,
+                        SqlCompareResult::is_greater_than_or_equal,
+                        SqlCompareResult::is_less_than,
+                    );
+                    let le_high = modality.judge(
+                        &high_cmp,
+                        SqlCompareResult::is_less_than_or_equal,
+                        SqlCompareResult::is_greater_than,
+                    );
+                    let between = match (ge_low, le_high) {
+                        (Some(false), _) | (_, Some(false)) => Some(false),
+                        (Some(true), Some(true)) => Some(true),
+                        _ => None,
+                    };
+
+                    let b = match between_predicate {
+                        BetweenPredicate::BetweenVariant { .. } => between,
+                        BetweenPredicate::NotBetweenVariant { .. } => between.map(|b| !b),
+                    };
+                    Ok(b.map_or(SqlValue::Null, |b| {
+                        SqlValue::NotNull(NnSqlValue::Boolean(b))
+                    }))
+                }
+                BooleanExpression::LikePredicateVariant(like_predicate) => {
+                    let (child, pattern) = like_predicate.operands();
+                    let child_sql_value = child.to_sql_value(value_from_index)?;
+                    let pattern_sql_value = pattern.to_sql_value(value_from_index)?;
+                    match (child_sql_value, pattern_sql_value) {
+                        (SqlValue::NotNull(child_nn), SqlValue::NotNull(pattern_nn)) => {
+                            let matched = child_nn.like(&pattern_nn)?;
+                            let b = match like_predicate {
+                                LikePredicate::LikeVariant { .. } => matched,
+                                LikePredicate::NotLikeVariant { .. } => !matched,
+                            };
                             Ok(SqlValue::NotNull(NnSqlValue::Boolean(b)))
                         }
+                        _ => Ok(SqlValue::Null),
                     }
                 }
             },
@@ -92,11 +411,17 @@ impl Expression {
 
     /// Fully evaluate an expression (without SchemaIndexVariant) to SqlValue.
     ///
-    /// # Panics
+    /// # Failures
     ///
-    /// if this expression includes at least 1 Expression::SchemaIndexVariant.
+    /// - [NameErrorNotFound](crate::SqlState::NameErrorNotFound) when:
+    ///   - this expression includes at least 1 Expression::SchemaIndexVariant (no row to refer to).
     pub fn to_sql_value_for_expr_without_index(&self) -> ApllodbResult<SqlValue> {
-        self.to_sql_value(&|_| panic!("this expression includes SchemaIndexVariant so you must use to_sql_value_for_expr_with_index to get an SqlValue: {:#?}", self))
+        self.to_sql_value(&|index| {
+            Err(ApllodbError::name_error_not_found(format!(
+                "`{}` cannot be referred here (no row to refer to)",
+                index
+            )))
+        })
     }
 
     /// retrieves all SchemaIndex in a expression
@@ -105,23 +430,47 @@ impl Expression {
             match boolean_expr {
                 BooleanExpression::LogicalFunctionVariant(logical_function) => {
                     match logical_function {
-                        LogicalFunction::AndVariant { left, right } => {
-                            let mut left = helper_boolean_expr(&*left);
-                            let mut right = helper_boolean_expr(&*right);
+                        LogicalFunction::AndVariant { left, right }
+                        | LogicalFunction::OrVariant { left, right } => {
+                            let mut left = left.to_schema_indexes();
+                            let mut right = right.to_schema_indexes();
                             left.append(&mut right);
                             left
                         }
+                        LogicalFunction::NotVariant { child }
+                        | LogicalFunction::PossiblyVariant { child } => child.to_schema_indexes(),
                     }
                 }
                 BooleanExpression::ComparisonFunctionVariant(comparison_function) => {
-                    match comparison_function {
-                        ComparisonFunction::EqualVariant { left, right } => {
-                            let mut left = left.to_schema_indexes();
-                            let mut right = right.to_schema_indexes();
-                            left.append(&mut right);
-                            left
-                        }
+                    let (left, right) = comparison_function.operands();
+                    let mut left = left.to_schema_indexes();
+                    let mut right = right.to_schema_indexes();
+                    left.append(&mut right);
+                    left
+                }
+                BooleanExpression::NullPredicateVariant(null_predicate) => {
+                    null_predicate.child().to_schema_indexes()
+                }
+                BooleanExpression::InPredicateVariant(in_predicate) => {
+                    let mut idxs = in_predicate.child().to_schema_indexes();
+                    for expr in in_predicate.list() {
+                        idxs.append(&mut expr.to_schema_indexes());
                     }
+                    idxs
+                }
+                BooleanExpression::BetweenPredicateVariant(between_predicate) => {
+                    let (child, low, high) = between_predicate.operands();
+                    let mut idxs = child.to_schema_indexes();
+                    idxs.append(&mut low.to_schema_indexes());
+                    idxs.append(&mut high.to_schema_indexes());
+                    idxs
+                }
+                BooleanExpression::LikePredicateVariant(like_predicate) => {
+                    let (child, pattern) = like_predicate.operands();
+                    let mut child = child.to_schema_indexes();
+                    let mut pattern = pattern.to_schema_indexes();
+                    child.append(&mut pattern);
+                    child
                 }
             }
         }
@@ -130,7 +479,87 @@ impl Expression {
             Expression::ConstantVariant(_) => vec![],
             Expression::SchemaIndexVariant(idx) => vec![idx.clone()],
             Expression::UnaryOperatorVariant(_op, expr) => expr.to_schema_indexes(),
+            Expression::CastVariant(expr, _sql_type) => expr.to_schema_indexes(),
+            Expression::ArithmeticFunctionVariant(arithmetic_function) => {
+                let (left, right) = arithmetic_function.operands();
+                let mut left = left.to_schema_indexes();
+                let mut right = right.to_schema_indexes();
+                left.append(&mut right);
+                left
+            }
             Expression::BooleanExpressionVariant(bool_expr) => helper_boolean_expr(bool_expr),
+            Expression::FunctionCallVariant(function_call) => function_call
+                .arguments()
+                .iter()
+                .flat_map(|arg| arg.to_schema_indexes())
+                .collect(),
+            Expression::CaseExpressionVariant(case_expression) => {
+                let mut idxs = case_expression
+                    .operand()
+                    .map_or_else(Vec::new, |operand| operand.to_schema_indexes());
+                for (when, then) in case_expression.when_clauses() {
+                    idxs.append(&mut when.to_schema_indexes());
+                    idxs.append(&mut then.to_schema_indexes());
+                }
+                if let Some(else_result) = case_expression.else_result() {
+                    idxs.append(&mut else_result.to_schema_indexes());
+                }
+                idxs
+            }
+            Expression::MatchExpressionVariant(match_expression) => {
+                let mut idxs = match_expression.operand().to_schema_indexes();
+                for arm in match_expression.arms() {
+                    idxs.extend(
+                        arm.result()
+                            .to_schema_indexes()
+                            .into_iter()
+                            .filter(|idx| idx.prefix().is_some() || !arm.binds(idx.attr())),
+                    );
+                }
+                idxs
+            }
+        }
+    }
+
+    /// Type of the value this expression evaluates to, inferred without evaluating it.
+    ///
+    /// Types of SchemaIndexVariant are supposed to be given via `type_from_index`.
+    /// None if the type is not known before evaluation (e.g. NULL constant, or `type_from_index` returns None).
+    pub fn sql_type_with_index(
+        &self,
+        type_from_index: &dyn Fn(&SchemaIndex) -> Option<SqlType>,
+    ) -> Option<SqlType> {
+        match self {
+            Expression::ConstantVariant(SqlValue::NotNull(nn_sql_value)) => {
+                Some(nn_sql_value.sql_type())
+            }
+            Expression::ConstantVariant(SqlValue::Null) => None,
+            Expression::SchemaIndexVariant(idx) => type_from_index(idx),
+            Expression::UnaryOperatorVariant(UnaryOperator::Minus, child) => {
+                child.sql_type_with_index(type_from_index)
+            }
+            Expression::ArithmeticFunctionVariant(arithmetic_function) => {
+                // only numeric operations are inferred (see NnSqlValue::numeric_arithmetic())
+                let (left, right) = arithmetic_function.operands();
+                let left_type = left.sql_type_with_index(type_from_index)?;
+                let right_type = right.sql_type_with_index(type_from_index)?;
+                match left_type.unified(&right_type) {
+                    Ok(SqlType::NumericComparable(NumericComparableType::Decimal(_))) => {
+                        Some(SqlType::numeric())
+                    }
+                    Ok(sql_type @ SqlType::NumericComparable(_)) => Some(sql_type),
+                    _ => None,
+                }
+            }
+            Expression::BooleanExpressionVariant(_) => Some(SqlType::boolean()),
+            Expression::FunctionCallVariant(function_call) => function_call.result_type().cloned(),
+            Expression::CaseExpressionVariant(case_expression) => {
+                case_expression.result_type().cloned()
+            }
+            Expression::MatchExpressionVariant(match_expression) => {
+                match_expression.result_type().cloned()
+            }
+            Expression::CastVariant(_, sql_type) => Some(sql_type.clone()),
         }
     }
 }
@@ -143,8 +572,13 @@ impl From<SqlValue> for Expression {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use crate::{
-        ApllodbResult, BooleanExpression, Expression, SchemaIndex, SqlValue, UnaryOperator,
+        AdtField, AdtType, AdtValue, AdtVariant, ApllodbResult, ArithmeticFunction,
+        BetweenPredicate, BooleanExpression, CaseExpression, ComparisonFunction, Expression,
+        FunctionCall, InPredicate, LikePredicate, MatchArm, MatchExpression, NnSqlValue,
+        NullPredicate, SchemaIndex, SqlState, SqlType, SqlValue, UnaryOperator,
     };
 
     #[test]
@@ -169,6 +603,31 @@ mod tests {
                 None,
                 SqlValue::factory_integer(-1),
             ),
+            // arithmetic
+            TestDatum::new(
+                Expression::factory_add(
+                    Expression::factory_integer(1),
+                    Expression::factory_integer(2),
+                ),
+                None,
+                SqlValue::factory_integer(3),
+            ),
+            TestDatum::new(
+                Expression::ArithmeticFunctionVariant(ArithmeticFunction::MultiplyVariant {
+                    left: Box::new(Expression::factory_add(
+                        Expression::factory_integer(1),
+                        Expression::factory_integer(2),
+                    )),
+                    right: Box::new(Expression::factory_integer(-3)),
+                }),
+                None,
+                SqlValue::factory_integer(-9),
+            ),
+            TestDatum::new(
+                Expression::factory_add(Expression::factory_null(), Expression::factory_integer(1)),
+                None,
+                SqlValue::Null,
+            ),
             // SchemaIndex
             TestDatum::new(
                 Expression::SchemaIndexVariant(SchemaIndex::from("x")),
@@ -179,7 +638,7 @@ mod tests {
             TestDatum::new(
                 Expression::factory_eq(Expression::factory_null(), Expression::factory_null()),
                 None,
-                SqlValue::factory_bool(false),
+                SqlValue::Null,
             ),
             TestDatum::new(
                 Expression::factory_eq(
@@ -197,6 +656,66 @@ mod tests {
                 None,
                 SqlValue::factory_bool(false),
             ),
+            TestDatum::new(
+                Expression::BooleanExpressionVariant(BooleanExpression::ComparisonFunctionVariant(
+                    ComparisonFunction::NotEqualVariant {
+                        left: Box::new(Expression::factory_integer(123)),
+                        right: Box::new(Expression::factory_integer(-123)),
+                    },
+                )),
+                None,
+                SqlValue::factory_bool(true),
+            ),
+            TestDatum::new(
+                Expression::BooleanExpressionVariant(BooleanExpression::ComparisonFunctionVariant(
+                    ComparisonFunction::NotEqualVariant {
+                        left: Box::new(Expression::factory_null()),
+                        right: Box::new(Expression::factory_integer(1)),
+                    },
+                )),
+                None,
+                SqlValue::Null,
+            ),
+            TestDatum::new(
+                Expression::BooleanExpressionVariant(BooleanExpression::ComparisonFunctionVariant(
+                    ComparisonFunction::LessThanVariant {
+                        left: Box::new(Expression::factory_integer(-123)),
+                        right: Box::new(Expression::factory_integer(123)),
+                    },
+                )),
+                None,
+                SqlValue::factory_bool(true),
+            ),
+            TestDatum::new(
+                Expression::BooleanExpressionVariant(BooleanExpression::ComparisonFunctionVariant(
+                    ComparisonFunction::LessThanOrEqualVariant {
+                        left: Box::new(Expression::factory_integer(123)),
+                        right: Box::new(Expression::factory_integer(123)),
+                    },
+                )),
+                None,
+                SqlValue::factory_bool(true),
+            ),
+            TestDatum::new(
+                Expression::BooleanExpressionVariant(BooleanExpression::ComparisonFunctionVariant(
+                    ComparisonFunction::GreaterThanVariant {
+                        left: Box::new(Expression::factory_integer(123)),
+                        right: Box::new(Expression::factory_integer(123)),
+                    },
+                )),
+                None,
+                SqlValue::factory_bool(false),
+            ),
+            TestDatum::new(
+                Expression::BooleanExpressionVariant(BooleanExpression::ComparisonFunctionVariant(
+                    ComparisonFunction::GreaterThanOrEqualVariant {
+                        left: Box::new(Expression::factory_integer(123)),
+                        right: Box::new(Expression::factory_integer(-123)),
+                    },
+                )),
+                None,
+                SqlValue::factory_bool(true),
+            ),
             TestDatum::new(
                 Expression::factory_and(
                     BooleanExpression::factory_eq(
@@ -225,6 +744,335 @@ mod tests {
                 None,
                 SqlValue::factory_bool(false),
             ),
+            // three-valued logic
+            TestDatum::new(
+                Expression::factory_and(
+                    BooleanExpression::factory_eq(
+                        Expression::factory_null(),
+                        Expression::factory_integer(1),
+                    ),
+                    BooleanExpression::factory_eq(
+                        Expression::factory_integer(1),
+                        Expression::factory_integer(2),
+                    ),
+                ),
+                None,
+                SqlValue::factory_bool(false),
+            ),
+            TestDatum::new(
+                Expression::factory_and(
+                    BooleanExpression::factory_eq(
+                        Expression::factory_null(),
+                        Expression::factory_integer(1),
+                    ),
+                    BooleanExpression::factory_eq(
+                        Expression::factory_integer(1),
+                        Expression::factory_integer(1),
+                    ),
+                ),
+                None,
+                SqlValue::Null,
+            ),
+            TestDatum::new(
+                Expression::factory_or(
+                    BooleanExpression::factory_eq(
+                        Expression::factory_integer(-123),
+                        Expression::factory_integer(123),
+                    ),
+                    BooleanExpression::factory_eq(
+                        Expression::factory_integer(456),
+                        Expression::factory_integer(456),
+                    ),
+                ),
+                None,
+                SqlValue::factory_bool(true),
+            ),
+            TestDatum::new(
+                Expression::factory_or(
+                    BooleanExpression::factory_eq(
+                        Expression::factory_null(),
+                        Expression::factory_integer(1),
+                    ),
+                    BooleanExpression::factory_eq(
+                        Expression::factory_integer(1),
+                        Expression::factory_integer(1),
+                    ),
+                ),
+                None,
+                SqlValue::factory_bool(true),
+            ),
+            TestDatum::new(
+                Expression::factory_or(
+                    BooleanExpression::factory_eq(
+                        Expression::factory_null(),
+                        Expression::factory_integer(1),
+                    ),
+                    BooleanExpression::factory_eq(
+                        Expression::factory_integer(1),
+                        Expression::factory_integer(2),
+                    ),
+                ),
+                None,
+                SqlValue::Null,
+            ),
+            TestDatum::new(
+                Expression::factory_not(Expression::factory_eq(
+                    Expression::factory_integer(1),
+                    Expression::factory_integer(2),
+                )),
+                None,
+                SqlValue::factory_bool(true),
+            ),
+            TestDatum::new(
+                Expression::factory_not(Expression::factory_eq(
+                    Expression::factory_null(),
+                    Expression::factory_integer(1),
+                )),
+                None,
+                SqlValue::Null,
+            ),
+            // null predicates
+            TestDatum::new(
+                Expression::factory_is_null(Expression::factory_null()),
+                None,
+                SqlValue::factory_bool(true),
+            ),
+            TestDatum::new(
+                Expression::factory_is_null(Expression::factory_integer(1)),
+                None,
+                SqlValue::factory_bool(false),
+            ),
+            TestDatum::new(
+                Expression::BooleanExpressionVariant(BooleanExpression::NullPredicateVariant(
+                    NullPredicate::IsNotNullVariant {
+                        child: Box::new(Expression::factory_eq(
+                            Expression::factory_null(),
+                            Expression::factory_integer(1),
+                        )),
+                    },
+                )),
+                None,
+                SqlValue::factory_bool(false),
+            ),
+            TestDatum::new(
+                Expression::BooleanExpressionVariant(BooleanExpression::NullPredicateVariant(
+                    NullPredicate::IsNotNullVariant {
+                        child: Box::new(Expression::SchemaIndexVariant(SchemaIndex::from("x"))),
+                    },
+                )),
+                Some(Box::new(|_| Ok(SqlValue::factory_integer(1)))),
+                SqlValue::factory_bool(true),
+            ),
+            // function call
+            TestDatum::new(
+                Expression::FunctionCallVariant(FunctionCall::new(
+                    "count_nulls",
+                    vec![
+                        Expression::factory_null(),
+                        Expression::SchemaIndexVariant(SchemaIndex::from("x")),
+                    ],
+                    Arc::new(|args| {
+                        let n = args.iter().filter(|v| matches!(v, SqlValue::Null)).count();
+                        Ok(SqlValue::factory_integer(n as i32))
+                    }),
+                )),
+                Some(Box::new(|_| Ok(SqlValue::Null))),
+                SqlValue::factory_integer(2),
+            ),
+            // IN
+            TestDatum::new(
+                Expression::factory_in(
+                    Expression::factory_integer(2),
+                    vec![
+                        Expression::factory_integer(1),
+                        Expression::factory_integer(2),
+                    ],
+                ),
+                None,
+                SqlValue::factory_bool(true),
+            ),
+            TestDatum::new(
+                Expression::factory_in(
+                    Expression::factory_integer(3),
+                    vec![
+                        Expression::factory_integer(1),
+                        Expression::factory_integer(2),
+                    ],
+                ),
+                None,
+                SqlValue::factory_bool(false),
+            ),
+            TestDatum::new(
+                Expression::factory_in(
+                    Expression::factory_integer(1),
+                    vec![Expression::factory_null(), Expression::factory_integer(1)],
+                ),
+                None,
+                SqlValue::factory_bool(true),
+            ),
+            TestDatum::new(
+                Expression::factory_in(
+                    Expression::factory_integer(3),
+                    vec![Expression::factory_null(), Expression::factory_integer(1)],
+                ),
+                None,
+                SqlValue::Null,
+            ),
+            TestDatum::new(
+                Expression::BooleanExpressionVariant(BooleanExpression::InPredicateVariant(
+                    InPredicate::NotInVariant {
+                        child: Box::new(Expression::factory_integer(3)),
+                        list: vec![Expression::factory_integer(1)],
+                    },
+                )),
+                None,
+                SqlValue::factory_bool(true),
+            ),
+            TestDatum::new(
+                Expression::BooleanExpressionVariant(BooleanExpression::InPredicateVariant(
+                    InPredicate::NotInVariant {
+                        child: Box::new(Expression::factory_integer(3)),
+                        list: vec![Expression::factory_null()],
+                    },
+                )),
+                None,
+                SqlValue::Null,
+            ),
+            // BETWEEN
+            TestDatum::new(
+                Expression::factory_between(
+                    Expression::factory_integer(2),
+                    Expression::factory_integer(2),
+                    Expression::factory_integer(3),
+                ),
+                None,
+                SqlValue::factory_bool(true),
+            ),
+            TestDatum::new(
+                Expression::factory_between(
+                    Expression::factory_integer(4),
+                    Expression::factory_integer(2),
+                    Expression::factory_integer(3),
+                ),
+                None,
+                SqlValue::factory_bool(false),
+            ),
+            TestDatum::new(
+                Expression::factory_between(
+                    Expression::factory_integer(4),
+                    Expression::factory_null(),
+                    Expression::factory_integer(3),
+                ),
+                None,
+                SqlValue::factory_bool(false),
+            ),
+            TestDatum::new(
+                Expression::factory_between(
+                    Expression::factory_integer(2),
+                    Expression::factory_null(),
+                    Expression::factory_integer(3),
+                ),
+                None,
+                SqlValue::Null,
+            ),
+            TestDatum::new(
+                Expression::BooleanExpressionVariant(BooleanExpression::BetweenPredicateVariant(
+                    BetweenPredicate::NotBetweenVariant {
+                        child: Box::new(Expression::factory_integer(4)),
+                        low: Box::new(Expression::factory_integer(2)),
+                        high: Box::new(Expression::factory_integer(3)),
+                    },
+                )),
+                None,
+                SqlValue::factory_bool(true),
+            ),
+            // LIKE
+            TestDatum::new(
+                Expression::factory_like(
+                    Expression::factory_text("foobar"),
+                    Expression::factory_text("foo%"),
+                ),
+                None,
+                SqlValue::factory_bool(true),
+            ),
+            TestDatum::new(
+                Expression::factory_like(
+                    Expression::factory_null(),
+                    Expression::factory_text("foo%"),
+                ),
+                None,
+                SqlValue::Null,
+            ),
+            TestDatum::new(
+                Expression::BooleanExpressionVariant(BooleanExpression::LikePredicateVariant(
+                    LikePredicate::NotLikeVariant {
+                        child: Box::new(Expression::factory_text("foobar")),
+                        pattern: Box::new(Expression::factory_text("_oo")),
+                    },
+                )),
+                None,
+                SqlValue::factory_bool(true),
+            ),
+            // CAST
+            TestDatum::new(
+                Expression::CastVariant(
+                    Box::new(Expression::factory_text("42")),
+                    SqlType::big_int(),
+                ),
+                None,
+                SqlValue::NotNull(NnSqlValue::BigInt(42)),
+            ),
+            TestDatum::new(
+                Expression::CastVariant(Box::new(Expression::factory_null()), SqlType::text()),
+                None,
+                SqlValue::Null,
+            ),
+            // CASE
+            TestDatum::new(
+                Expression::CaseExpressionVariant(CaseExpression::new(
+                    None,
+                    vec![
+                        (Expression::factory_null(), Expression::factory_integer(1)),
+                        (
+                            Expression::factory_eq(
+                                Expression::SchemaIndexVariant(SchemaIndex::from("x")),
+                                Expression::factory_integer(2),
+                            ),
+                            Expression::factory_integer(2),
+                        ),
+                    ],
+                    Some(Expression::factory_integer(3)),
+                    &|_| None,
+                )?),
+                Some(Box::new(|_| Ok(SqlValue::factory_integer(2)))),
+                SqlValue::factory_integer(2),
+            ),
+            TestDatum::new(
+                Expression::CaseExpressionVariant(CaseExpression::new(
+                    Some(Expression::SchemaIndexVariant(SchemaIndex::from("x"))),
+                    vec![
+                        (Expression::factory_null(), Expression::factory_text("null")),
+                        (
+                            Expression::factory_integer(1),
+                            Expression::factory_text("one"),
+                        ),
+                    ],
+                    Some(Expression::factory_text("other")),
+                    &|_| None,
+                )?),
+                Some(Box::new(|_| Ok(SqlValue::factory_integer(1)))),
+                SqlValue::factory_text("one"),
+            ),
+            TestDatum::new(
+                Expression::CaseExpressionVariant(CaseExpression::new(
+                    Some(Expression::factory_null()),
+                    vec![(Expression::factory_null(), Expression::factory_integer(1))],
+                    None,
+                    &|_| None,
+                )?),
+                None,
+                SqlValue::Null,
+            ),
         ];
 
         for t in test_data {
@@ -234,9 +1082,399 @@ mod tests {
                     .to_sql_value_for_expr_with_index(value_from_index.as_ref())?,
                 None => t.in_expr.to_sql_value_for_expr_without_index()?,
             };
-            assert_eq!(sql_value, t.expected_sql_value);
+            match t.expected_sql_value {
+                // NULL != NULL in SqlValue's PartialEq
+                SqlValue::Null => assert!(matches!(sql_value, SqlValue::Null), "{:?}", sql_value),
+                _ => assert_eq!(sql_value, t.expected_sql_value),
+            }
         }
 
         Ok(())
     }
+
+    #[test]
+    fn test_possibly() -> ApllodbResult<()> {
+        let fuzzy = |s: &str| {
+            Expression::ConstantVariant(SqlValue::NotNull(NnSqlValue::FuzzyDate(
+                s.parse().unwrap(),
+            )))
+        };
+        let lt = |left: Expression, right: Expression| {
+            Expression::BooleanExpressionVariant(BooleanExpression::ComparisonFunctionVariant(
+                ComparisonFunction::LessThanVariant {
+                    left: Box::new(left),
+                    right: Box::new(right),
+                },
+            ))
+        };
+        let le = |left: Expression, right: Expression| {
+            Expression::BooleanExpressionVariant(BooleanExpression::ComparisonFunctionVariant(
+                ComparisonFunction::LessThanOrEqualVariant {
+                    left: Box::new(left),
+                    right: Box::new(right),
+                },
+            ))
+        };
+
+        let test_data: Vec<(Expression, Option<bool>)> = vec![
+            (lt(fuzzy("1920s"), fuzzy("1930")), Some(true)),
+            (lt(fuzzy("1920s"), fuzzy("1925")), None),
+            (le(fuzzy("1920s"), fuzzy("1929-12-31")), Some(true)),
+            (Expression::factory_eq(fuzzy("1920s"), fuzzy("1920s")), None),
+            (
+                Expression::factory_eq(fuzzy("1923-05-17"), fuzzy("1923-05-17")),
+                Some(true),
+            ),
+            (
+                Expression::factory_possibly(lt(fuzzy("1920s"), fuzzy("1925"))),
+                Some(true),
+            ),
+            (
+                Expression::factory_possibly(lt(fuzzy("1920s"), fuzzy("1920-01-01"))),
+                Some(false),
+            ),
+            (
+                Expression::factory_possibly(Expression::factory_eq(fuzzy("1920s"), fuzzy("1930"))),
+                Some(false),
+            ),
+            (
+                Expression::factory_possibly(Expression::factory_not(Expression::factory_eq(
+                    fuzzy("1923-05-17"),
+                    fuzzy("1920s"),
+                ))),
+                Some(true),
+            ),
+            (
+                Expression::factory_possibly(Expression::factory_not(le(
+                    fuzzy("1920s"),
+                    fuzzy("1929-12-31"),
+                ))),
+                Some(false),
+            ),
+            (
+                Expression::factory_not(Expression::factory_possibly(Expression::factory_eq(
+                    fuzzy("1920s"),
+                    fuzzy("1930"),
+                ))),
+                Some(true),
+            ),
+            (
+                Expression::factory_possibly(Expression::factory_eq(
+                    Expression::factory_null(),
+                    fuzzy("1920s"),
+                )),
+                None,
+            ),
+            (
+                Expression::factory_in(fuzzy("1920s"), vec![fuzzy("1925"), fuzzy("1950")]),
+                None,
+            ),
+            (
+                Expression::factory_possibly(Expression::factory_in(
+                    fuzzy("1920s"),
+                    vec![fuzzy("1925"), fuzzy("1950")],
+                )),
+                Some(true),
+            ),
+            (
+                Expression::factory_between(fuzzy("1920s"), fuzzy("1925-06"), fuzzy("1940")),
+                None,
+            ),
+            (
+                Expression::factory_possibly(Expression::factory_between(
+                    fuzzy("1920s"),
+                    fuzzy("1925-06"),
+                    fuzzy("1940"),
+                )),
+                Some(true),
+            ),
+        ];
+
+        for (expr, expected) in test_data {
+            let b = expr
+                .to_sql_value_for_expr_without_index()?
+                .to_nullable_bool()?;
+            assert_eq!(b, expected, "{:?}", expr);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_case_expression_result_type() -> ApllodbResult<()> {
+        let x = || Expression::SchemaIndexVariant(SchemaIndex::from("x"));
+        let case_expr = Expression::CaseExpressionVariant(CaseExpression::new(
+            None,
+            vec![
+                (
+                    Expression::factory_eq(x(), Expression::factory_integer(0)),
+                    Expression::ConstantVariant(SqlValue::NotNull(NnSqlValue::SmallInt(1))),
+                ),
+                (
+                    Expression::BooleanExpressionVariant(
+                        BooleanExpression::ComparisonFunctionVariant(
+                            ComparisonFunction::GreaterThanVariant {
+                                left: Box::new(x()),
+                                right: Box::new(Expression::factory_integer(0)),
+                            },
+                        ),
+                    ),
+                    Expression::factory_integer(100),
+                ),
+            ],
+            Some(Expression::ArithmeticFunctionVariant(
+                ArithmeticFunction::DivideVariant {
+                    left: Box::new(Expression::factory_integer(1)),
+                    right: Box::new(x()),
+                },
+            )),
+            &|_| None,
+        )?);
+
+        // SMALLINT result is widened into INTEGER,
+        // and ELSE result is not evaluated (would be division by zero)
+        match case_expr.to_sql_value_for_expr_with_index(&|_| Ok(SqlValue::factory_integer(0)))? {
+            SqlValue::NotNull(nn_sql_value) => {
+                assert_eq!(nn_sql_value, NnSqlValue::Integer(1));
+                assert_eq!(nn_sql_value.sql_type(), SqlType::integer());
+            }
+            SqlValue::Null => panic!("must be 1"),
+        }
+        assert_eq!(
+            case_expr.to_sql_value_for_expr_with_index(&|_| Ok(SqlValue::factory_integer(-1)))?,
+            SqlValue::factory_integer(-1)
+        );
+
+        // typed non-constant results are unified on construction
+        let case_expr = Expression::CaseExpressionVariant(CaseExpression::new(
+            Some(x()),
+            vec![(
+                Expression::factory_integer(1),
+                Expression::factory_integer(1),
+            )],
+            Some(Expression::SchemaIndexVariant(SchemaIndex::from("y"))),
+            &|_| Some(SqlType::big_int()),
+        )?);
+        match case_expr.to_sql_value_for_expr_with_index(&|_| Ok(SqlValue::factory_integer(1)))? {
+            SqlValue::NotNull(nn_sql_value) => {
+                assert_eq!(nn_sql_value.sql_type(), SqlType::big_int());
+            }
+            SqlValue::Null => panic!("must be 1"),
+        }
+        assert_eq!(
+            CaseExpression::new(
+                None,
+                vec![(Expression::factory_null(), Expression::factory_integer(1))],
+                Some(x()),
+                &|_| Some(SqlType::text()),
+            )
+            .unwrap_err()
+            .kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+
+        // untyped non-constant result is checked on evaluation
+        let case_expr = Expression::CaseExpressionVariant(CaseExpression::new(
+            None,
+            vec![(Expression::factory_null(), Expression::factory_integer(1))],
+            Some(x()),
+            &|_| None,
+        )?);
+        assert_eq!(
+            case_expr
+                .to_sql_value_for_expr_with_index(&|_| Ok(SqlValue::factory_text("a")))
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+
+        // constant results are checked on construction
+        assert_eq!(
+            CaseExpression::new(
+                None,
+                vec![(Expression::factory_null(), Expression::factory_integer(1))],
+                Some(Expression::factory_text("a")),
+                &|_| None,
+            )
+            .unwrap_err()
+            .kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_match_expression() -> ApllodbResult<()> {
+        let shape = AdtType::new(
+            "shape",
+            vec![
+                AdtVariant::new(
+                    "circle".to_string(),
+                    vec![AdtField::new("r".to_string(), SqlType::integer())],
+                ),
+                AdtVariant::new(
+                    "rect".to_string(),
+                    vec![
+                        AdtField::new("w".to_string(), SqlType::integer()),
+                        AdtField::new("h".to_string(), SqlType::integer()),
+                    ],
+                ),
+            ],
+        )?;
+        let s = || Expression::SchemaIndexVariant(SchemaIndex::from("s"));
+        let w = || Expression::SchemaIndexVariant(SchemaIndex::from("w"));
+        let arm = |variant_name: Option<&str>, bindings: Vec<Option<&str>>, result: Expression| {
+            MatchArm::new(
+                variant_name.map(|v| v.to_string()),
+                bindings
+                    .into_iter()
+                    .map(|b| b.map(|b| b.to_string()))
+                    .collect(),
+                result,
+            )
+        };
+
+        // MATCH s WITH circle(r) -> r | rect(w, _) -> w * 10 END
+        let match_expr = Expression::MatchExpressionVariant(MatchExpression::new(
+            s(),
+            shape.clone(),
+            vec![
+                arm(
+                    Some("circle"),
+                    vec![Some("r")],
+                    Expression::SchemaIndexVariant(SchemaIndex::from("r")),
+                ),
+                arm(
+                    Some("rect"),
+                    vec![Some("w"), None],
+                    Expression::ArithmeticFunctionVariant(ArithmeticFunction::MultiplyVariant {
+                        left: Box::new(w()),
+                        right: Box::new(Expression::factory_integer(10)),
+                    }),
+                ),
+            ],
+            &|_| None,
+        )?);
+        // `w` is bound, not a column
+        assert_eq!(match_expr.to_schema_indexes(), vec![SchemaIndex::from("s")]);
+
+        let eval = |v: SqlValue| match_expr.to_sql_value_for_expr_with_index(&|_| Ok(v.clone()));
+        assert_eq!(
+            eval(SqlValue::NotNull(NnSqlValue::Adt(AdtValue::new(
+                shape.clone(),
+                "circle",
+                vec![NnSqlValue::Integer(3)]
+            )?)))?,
+            SqlValue::factory_integer(3)
+        );
+        assert_eq!(
+            eval(SqlValue::NotNull(NnSqlValue::Adt(AdtValue::new(
+                shape.clone(),
+                "rect",
+                vec![NnSqlValue::Integer(2), NnSqlValue::Integer(5)]
+            )?)))?,
+            SqlValue::factory_integer(20)
+        );
+        assert!(matches!(eval(SqlValue::Null)?, SqlValue::Null));
+        assert_eq!(
+            eval(SqlValue::factory_integer(1)).unwrap_err().kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+
+        // bound fields are typed as the variant's fields, and SMALLINT result is widened into INTEGER
+        let match_expr = MatchExpression::new(
+            s(),
+            shape.clone(),
+            vec![
+                arm(
+                    Some("circle"),
+                    vec![Some("r")],
+                    Expression::SchemaIndexVariant(SchemaIndex::from("r")),
+                ),
+                arm(
+                    None,
+                    vec![],
+                    Expression::ConstantVariant(SqlValue::NotNull(NnSqlValue::SmallInt(0))),
+                ),
+            ],
+            &|_| Some(SqlType::text()),
+        )?;
+        assert_eq!(match_expr.result_type(), Some(&SqlType::integer()));
+        match Expression::MatchExpressionVariant(match_expr).to_sql_value_for_expr_with_index(
+            &|_| {
+                Ok(SqlValue::NotNull(NnSqlValue::Adt(AdtValue::new(
+                    shape.clone(),
+                    "rect",
+                    vec![NnSqlValue::Integer(2), NnSqlValue::Integer(5)],
+                )?)))
+            },
+        )? {
+            SqlValue::NotNull(nn_sql_value) => {
+                assert_eq!(nn_sql_value.sql_type(), SqlType::integer())
+            }
+            SqlValue::Null => panic!("must be 0"),
+        }
+
+        // not exhaustive
+        assert_eq!(
+            MatchExpression::new(
+                s(),
+                shape.clone(),
+                vec![arm(
+                    Some("circle"),
+                    vec![None],
+                    Expression::factory_integer(1)
+                )],
+                &|_| None,
+            )
+            .unwrap_err()
+            .kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+        // wildcard covers the rest
+        assert!(MatchExpression::new(
+            s(),
+            shape.clone(),
+            vec![
+                arm(Some("circle"), vec![None], Expression::factory_integer(1)),
+                arm(None, vec![], Expression::factory_integer(0)),
+            ],
+            &|_| None,
+        )
+        .is_ok());
+        // wrong number of bindings
+        assert_eq!(
+            MatchExpression::new(
+                s(),
+                shape.clone(),
+                vec![arm(
+                    Some("rect"),
+                    vec![None],
+                    Expression::factory_integer(1)
+                )],
+                &|_| None,
+            )
+            .unwrap_err()
+            .kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+        // unknown variant
+        assert_eq!(
+            MatchExpression::new(
+                s(),
+                shape,
+                vec![arm(
+                    Some("triangle"),
+                    vec![],
+                    Expression::factory_integer(1)
+                )],
+                &|_| None,
+            )
+            .unwrap_err()
+            .kind(),
+            &SqlState::NameErrorNotFound
+        );
+
+        Ok(())
+    }
 }
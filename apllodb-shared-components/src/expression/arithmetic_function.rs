@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Expression;
+
+/// Arithmetic function and its operands
+#[derive(Clone, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub enum ArithmeticFunction {
+    /// `+` operation
+    AddVariant {
+        /// Left operand
+        left: Box<Expression>,
+        /// Right operand
+        right: Box<Expression>,
+    },
+
+    /// `-` operation
+    SubtractVariant {
+        /// Left operand
+        left: Box<Expression>,
+        /// Right operand
+        right: Box<Expression>,
+    },
+
+    /// `*` operation
+    MultiplyVariant {
+        /// Left operand
+        left: Box<Expression>,
+        /// Right operand
+        right: Box<Expression>,
+    },
+
+    /// `/` operation
+    DivideVariant {
+        /// Left operand
+        left: Box<Expression>,
+        /// Right operand
+        right: Box<Expression>,
+    },
+
+    /// `%` operation
+    ModuloVariant {
+        /// Left operand
+        left: Box<Expression>,
+        /// Right operand
+        right: Box<Expression>,
+    },
+}
+
+impl ArithmeticFunction {
+    /// Left and right operands
+    pub fn operands(&self) -> (&Expression, &Expression) {
+        match self {
+            ArithmeticFunction::AddVariant { left, right }
+            | ArithmeticFunction::SubtractVariant { left, right }
+            | ArithmeticFunction::MultiplyVariant { left, right }
+            | ArithmeticFunction::DivideVariant { left, right }
+            | ArithmeticFunction::ModuloVariant { left, right } => (left, right),
+        }
+    }
+}
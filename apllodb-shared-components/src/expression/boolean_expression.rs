@@ -1,9 +1,17 @@
+pub(crate) mod between_predicate;
 pub(crate) mod comparison_function;
+pub(crate) mod in_predicate;
+pub(crate) mod like_predicate;
 pub(crate) mod logical_function;
+pub(crate) mod null_predicate;
 
 use serde::{Deserialize, Serialize};
 
-use self::{comparison_function::ComparisonFunction, logical_function::LogicalFunction};
+use self::{
+    between_predicate::BetweenPredicate, comparison_function::ComparisonFunction,
+    in_predicate::InPredicate, like_predicate::LikePredicate, logical_function::LogicalFunction,
+    null_predicate::NullPredicate,
+};
 
 /// Boolean expression.
 #[derive(Clone, PartialEq, Hash, Debug, Serialize, Deserialize)]
@@ -13,4 +21,16 @@ pub enum BooleanExpression {
 
     /// Comparison functions
     ComparisonFunctionVariant(ComparisonFunction),
+
+    /// IS NULL, IS NOT NULL
+    NullPredicateVariant(NullPredicate),
+
+    /// IN, NOT IN
+    InPredicateVariant(InPredicate),
+
+    /// BETWEEN, NOT BETWEEN
+    BetweenPredicateVariant(BetweenPredicate),
+
+    /// LIKE, NOT LIKE
+    LikePredicateVariant(LikePredicate),
 }
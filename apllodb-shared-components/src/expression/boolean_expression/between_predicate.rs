@@ -0,0 +1,38 @@
+use crate::Expression;
+use serde::{Deserialize, Serialize};
+
+/// BETWEEN, NOT BETWEEN
+///
+/// `x BETWEEN a AND b` is evaluated as `x >= a AND x <= b`.
+#[derive(Clone, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub enum BetweenPredicate {
+    /// `BETWEEN` operation
+    BetweenVariant {
+        /// Operand
+        child: Box<Expression>,
+        /// Lower bound (inclusive)
+        low: Box<Expression>,
+        /// Upper bound (inclusive)
+        high: Box<Expression>,
+    },
+
+    /// `NOT BETWEEN` operation
+    NotBetweenVariant {
+        /// Operand
+        child: Box<Expression>,
+        /// Lower bound (inclusive)
+        low: Box<Expression>,
+        /// Upper bound (inclusive)
+        high: Box<Expression>,
+    },
+}
+
+impl BetweenPredicate {
+    /// Operand, lower bound and upper bound
+    pub fn operands(&self) -> (&Expression, &Expression, &Expression) {
+        match self {
+            BetweenPredicate::BetweenVariant { child, low, high }
+            | BetweenPredicate::NotBetweenVariant { child, low, high } => (child, low, high),
+        }
+    }
+}
@@ -12,4 +12,58 @@ pub enum ComparisonFunction {
         /// Right operand
         right: Box<Expression>,
     },
+
+    /// `<>` operation
+    NotEqualVariant {
+        /// Left operand
+        left: Box<Expression>,
+        /// Right operand
+        right: Box<Expression>,
+    },
+
+    /// `<` operation
+    LessThanVariant {
+        /// Left operand
+        left: Box<Expression>,
+        /// Right operand
+        right: Box<Expression>,
+    },
+
+    /// `<=` operation
+    LessThanOrEqualVariant {
+        /// Left operand
+        left: Box<Expression>,
+        /// Right operand
+        right: Box<Expression>,
+    },
+
+    /// `>` operation
+    GreaterThanVariant {
+        /// Left operand
+        left: Box<Expression>,
+        /// Right operand
+        right: Box<Expression>,
+    },
+
+    /// `>=` operation
+    GreaterThanOrEqualVariant {
+        /// Left operand
+        left: Box<Expression>,
+        /// Right operand
+        right: Box<Expression>,
+    },
+}
+
+impl ComparisonFunction {
+    /// Left and right operands
+    pub fn operands(&self) -> (&Expression, &Expression) {
+        match self {
+            ComparisonFunction::EqualVariant { left, right }
+            | ComparisonFunction::NotEqualVariant { left, right }
+            | ComparisonFunction::LessThanVariant { left, right }
+            | ComparisonFunction::LessThanOrEqualVariant { left, right }
+            | ComparisonFunction::GreaterThanVariant { left, right }
+            | ComparisonFunction::GreaterThanOrEqualVariant { left, right } => (left, right),
+        }
+    }
 }
@@ -0,0 +1,40 @@
+use crate::Expression;
+use serde::{Deserialize, Serialize};
+
+/// IN, NOT IN
+///
+/// `x IN (a, b)` is evaluated as `x = a OR x = b` (so NULL in the list can make the result UNKNOWN).
+#[derive(Clone, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub enum InPredicate {
+    /// `IN` operation
+    InVariant {
+        /// Operand
+        child: Box<Expression>,
+        /// Values to search `child` in
+        list: Vec<Expression>,
+    },
+
+    /// `NOT IN` operation
+    NotInVariant {
+        /// Operand
+        child: Box<Expression>,
+        /// Values to search `child` in
+        list: Vec<Expression>,
+    },
+}
+
+impl InPredicate {
+    /// Operand
+    pub fn child(&self) -> &Expression {
+        match self {
+            InPredicate::InVariant { child, .. } | InPredicate::NotInVariant { child, .. } => child,
+        }
+    }
+
+    /// Values to search the operand in
+    pub fn list(&self) -> &[Expression] {
+        match self {
+            InPredicate::InVariant { list, .. } | InPredicate::NotInVariant { list, .. } => list,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::Expression;
+use serde::{Deserialize, Serialize};
+
+/// LIKE, NOT LIKE
+///
+/// In a pattern, `%` matches any sequence of characters and `_` matches any single character.
+/// `\` escapes the following character.
+#[derive(Clone, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub enum LikePredicate {
+    /// `LIKE` operation
+    LikeVariant {
+        /// Operand
+        child: Box<Expression>,
+        /// Pattern
+        pattern: Box<Expression>,
+    },
+
+    /// `NOT LIKE` operation
+    NotLikeVariant {
+        /// Operand
+        child: Box<Expression>,
+        /// Pattern
+        pattern: Box<Expression>,
+    },
+}
+
+impl LikePredicate {
+    /// Operand and pattern
+    pub fn operands(&self) -> (&Expression, &Expression) {
+        match self {
+            LikePredicate::LikeVariant { child, pattern }
+            | LikePredicate::NotLikeVariant { child, pattern } => (child, pattern),
+        }
+    }
+}
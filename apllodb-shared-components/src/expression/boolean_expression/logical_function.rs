@@ -1,14 +1,42 @@
-use super::BooleanExpression;
+use crate::Expression;
 use serde::{Deserialize, Serialize};
 
-/// AND, OR, NOT
+/// AND, OR, NOT, POSSIBLY
+///
+/// Operands are evaluated in SQL's three-valued logic (TRUE, FALSE, and UNKNOWN represented as NULL).
 #[derive(Clone, PartialEq, Hash, Debug, Serialize, Deserialize)]
 pub enum LogicalFunction {
     /// `AND` operation
     AndVariant {
         /// Left operand
-        left: Box<BooleanExpression>,
+        left: Box<Expression>,
         /// Right operand
-        right: Box<BooleanExpression>,
+        right: Box<Expression>,
+    },
+
+    /// `OR` operation
+    OrVariant {
+        /// Left operand
+        left: Box<Expression>,
+        /// Right operand
+        right: Box<Expression>,
+    },
+
+    /// `NOT` operation
+    NotVariant {
+        /// Operand
+        child: Box<Expression>,
+    },
+
+    /// `POSSIBLY` operation: whether the operand can be TRUE for some actual values of ambiguous ones (e.g. [FuzzyDate](crate::FuzzyDate)).
+    ///
+    /// Comparisons in the operand are TRUE when they possibly hold, while they are UNKNOWN without POSSIBLY.
+    /// `NOT` in the operand is TRUE when its operand is not certainly TRUE.
+    /// `AND` and `OR` in the operand combine the possibilities of each side,
+    /// so `POSSIBLY (x > a AND x < b)` may be TRUE even if no single value of `x` satisfies both.
+    /// NULL operands of comparisons remain UNKNOWN.
+    PossiblyVariant {
+        /// Operand
+        child: Box<Expression>,
     },
 }
@@ -0,0 +1,31 @@
+use crate::Expression;
+use serde::{Deserialize, Serialize};
+
+/// IS NULL, IS NOT NULL
+///
+/// Unlike comparison functions, always evaluated into TRUE or FALSE (never UNKNOWN).
+#[derive(Clone, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub enum NullPredicate {
+    /// `IS NULL` operation
+    IsNullVariant {
+        /// Operand
+        child: Box<Expression>,
+    },
+
+    /// `IS NOT NULL` operation
+    IsNotNullVariant {
+        /// Operand
+        child: Box<Expression>,
+    },
+}
+
+impl NullPredicate {
+    /// Operand
+    pub fn child(&self) -> &Expression {
+        match self {
+            NullPredicate::IsNullVariant { child } | NullPredicate::IsNotNullVariant { child } => {
+                child
+            }
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{ApllodbError, ApllodbResult, Expression, NnSqlValue, SchemaIndex, SqlType};
+
+/// `CASE [operand] WHEN ... THEN ... [ELSE ...] END`
+///
+/// Simple CASE has `operand` and compares it with each WHEN value.
+/// Searched CASE does not have `operand` and evaluates each WHEN condition.
+///
+/// Only the WHEN clauses until the first match and the chosen result are evaluated.
+/// The chosen result is cast into the type unified from all THEN / ELSE results
+/// (e.g. `CASE WHEN c THEN 1 ELSE 100000 END` is always INTEGER).
+#[derive(Clone, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub struct CaseExpression {
+    operand: Option<Box<Expression>>,
+    when_clauses: Vec<(Expression, Expression)>,
+    else_result: Option<Box<Expression>>,
+    result_type: Option<SqlType>,
+}
+
+impl CaseExpression {
+    /// Constructor.
+    ///
+    /// `when_clauses` are pairs of (WHEN expression, THEN result).
+    /// Types of columns in results are supposed to be given via `type_from_index`
+    /// (see [Expression::sql_type_with_index()](crate::Expression::sql_type_with_index)).
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalOperation](crate::SqlState::DataExceptionIllegalOperation) when:
+    ///   - types of results cannot be unified (e.g. INTEGER and TEXT)
+    pub fn new(
+        operand: Option<Expression>,
+        when_clauses: Vec<(Expression, Expression)>,
+        else_result: Option<Expression>,
+        type_from_index: &dyn Fn(&SchemaIndex) -> Option<SqlType>,
+    ) -> ApllodbResult<Self> {
+        let mut result_type: Option<SqlType> = None;
+        for result in when_clauses
+            .iter()
+            .map(|(_, result)| result)
+            .chain(else_result.iter())
+        {
+            if let Some(sql_type) = result.sql_type_with_index(type_from_index) {
+                result_type = Some(match result_type {
+                    Some(t) => t.unified(&sql_type)?,
+                    None => sql_type,
+                });
+            }
+        }
+
+        Ok(Self {
+            operand: operand.map(Box::new),
+            when_clauses,
+            else_result: else_result.map(Box::new),
+            result_type,
+        })
+    }
+
+    /// Operand of simple CASE
+    pub fn operand(&self) -> Option<&Expression> {
+        self.operand.as_deref()
+    }
+
+    /// Pairs of (WHEN expression, THEN result)
+    pub fn when_clauses(&self) -> &[(Expression, Expression)] {
+        &self.when_clauses
+    }
+
+    /// ELSE result
+    pub fn else_result(&self) -> Option<&Expression> {
+        self.else_result.as_deref()
+    }
+
+    /// Type unified from THEN / ELSE results.
+    /// None if no result type is known before evaluation (e.g. all results are NULL).
+    pub fn result_type(&self) -> Option<&SqlType> {
+        self.result_type.as_ref()
+    }
+
+    /// Casts the chosen result into [result_type()](Self::result_type).
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalOperation](crate::SqlState::DataExceptionIllegalOperation) when:
+    ///   - the type of `result` is not implicitly coercible to [result_type()](Self::result_type)
+    ///     (only when the type of `result` was not known on construction)
+    pub(crate) fn cast_result(&self, result: NnSqlValue) -> ApllodbResult<NnSqlValue> {
+        match &self.result_type {
+            Some(result_type) if result.sql_type().is_implicitly_coercible_to(result_type) => {
+                result.cast(result_type)
+            }
+            Some(result_type) => Err(ApllodbError::data_exception_illegal_operation(format!(
+                "CASE result {:?} cannot be unified into {:?}",
+                result, result_type
+            ))),
+            None => Ok(result),
+        }
+    }
+}
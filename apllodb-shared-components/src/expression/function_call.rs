@@ -0,0 +1,115 @@
+use std::{
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ApllodbError, ApllodbResult, Expression, SqlType, SqlValue};
+
+/// Implementation of a scalar function.
+///
+/// Takes evaluated arguments and returns the function's result.
+pub type ScalarFunctionBody = Arc<dyn Fn(&[SqlValue]) -> ApllodbResult<SqlValue> + Send + Sync>;
+
+/// Scalar function call already resolved to its implementation.
+///
+/// Non-NULL results are widened into [result_type()](Self::result_type) if it is set.
+///
+/// Equality and hash only take the function name and arguments into account.
+/// A deserialized function call has lost its implementation and fails on evaluation.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FunctionCall {
+    function_name: String,
+    arguments: Vec<Expression>,
+    result_type: Option<SqlType>,
+
+    #[serde(skip, default = "unresolved_body")]
+    body: ScalarFunctionBody,
+}
+
+impl FunctionCall {
+    /// Constructor
+    pub fn new(
+        function_name: impl Into<String>,
+        arguments: Vec<Expression>,
+        body: ScalarFunctionBody,
+    ) -> Self {
+        Self {
+            function_name: function_name.into(),
+            arguments,
+            result_type: None,
+            body,
+        }
+    }
+
+    /// Sets the type of results.
+    pub fn with_result_type(self, result_type: Option<SqlType>) -> Self {
+        Self {
+            result_type,
+            ..self
+        }
+    }
+
+    /// Function name
+    pub fn function_name(&self) -> &str {
+        &self.function_name
+    }
+
+    /// Arguments
+    pub fn arguments(&self) -> &[Expression] {
+        &self.arguments
+    }
+
+    /// Type of results (None if not known before evaluation)
+    pub fn result_type(&self) -> Option<&SqlType> {
+        self.result_type.as_ref()
+    }
+
+    /// Calls the implementation with evaluated arguments.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalOperation](crate::SqlState::DataExceptionIllegalOperation) when:
+    ///   - the result cannot be unified with [result_type()](Self::result_type)
+    pub(crate) fn call(&self, args: &[SqlValue]) -> ApllodbResult<SqlValue> {
+        match ((self.body)(args)?, &self.result_type) {
+            (SqlValue::NotNull(nn_sql_value), Some(result_type)) => {
+                Ok(SqlValue::NotNull(nn_sql_value.unify_type(result_type)?))
+            }
+            (sql_value, _) => Ok(sql_value),
+        }
+    }
+}
+
+impl PartialEq for FunctionCall {
+    fn eq(&self, other: &Self) -> bool {
+        self.function_name == other.function_name && self.arguments == other.arguments
+    }
+}
+
+impl Hash for FunctionCall {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.function_name.hash(state);
+        self.arguments.hash(state);
+    }
+}
+
+impl Debug for FunctionCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FunctionCall")
+            .field("function_name", &self.function_name)
+            .field("arguments", &self.arguments)
+            .field("result_type", &self.result_type)
+            .finish()
+    }
+}
+
+fn unresolved_body() -> ScalarFunctionBody {
+    Arc::new(|_| {
+        Err(ApllodbError::feature_not_supported(
+            "deserialized function call cannot be evaluated",
+        ))
+    })
+}
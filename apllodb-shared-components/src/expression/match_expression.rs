@@ -0,0 +1,251 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    find_dup, AdtType, AdtValue, ApllodbError, ApllodbResult, Expression, NnSqlValue, SchemaIndex,
+    SqlType,
+};
+
+/// `MATCH operand WITH pattern -> result | ... END`
+///
+/// `operand` is a value of a user-defined type (see [AdtType](crate::AdtType)), and each arm has a pattern:
+/// a variant with bindings to its fields (`circle(r)`, `rect(w, _)`), or `_` matching any variant.
+/// Bound names are referred in the arm's result as unqualified columns, shadowing columns of the same name.
+///
+/// Arms are tried in order and must cover all the variants of the type.
+/// NULL operand results in NULL.
+/// The chosen result is cast into the type unified from all arm results (as [CaseExpression](crate::CaseExpression)).
+#[derive(Clone, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub struct MatchExpression {
+    operand: Box<Expression>,
+    adt_type: AdtType,
+    arms: Vec<MatchArm>,
+    result_type: Option<SqlType>,
+}
+
+/// Arm of [MatchExpression](crate::MatchExpression): `pattern -> result`.
+#[derive(Clone, PartialEq, Hash, Debug, Serialize, Deserialize, new)]
+pub struct MatchArm {
+    /// None for wildcard pattern (`_`).
+    variant_name: Option<String>,
+    /// Names bound to the variant's fields (None for `_`).
+    bindings: Vec<Option<String>>,
+    result: Expression,
+}
+
+impl MatchArm {
+    /// Variant to match (None for `_`)
+    pub fn variant_name(&self) -> Option<&str> {
+        self.variant_name.as_deref()
+    }
+
+    /// Names bound to the variant's fields
+    pub fn bindings(&self) -> &[Option<String>] {
+        &self.bindings
+    }
+
+    /// Result expression
+    pub fn result(&self) -> &Expression {
+        &self.result
+    }
+
+    /// Whether an unqualified column `name` in the result refers to a bound field.
+    pub(crate) fn binds(&self, name: &str) -> bool {
+        self.bindings.iter().any(|b| b.as_deref() == Some(name))
+    }
+
+    /// Pairs of (bound name, type of the bound field) in `adt_type`.
+    fn bound_types(&self, adt_type: &AdtType) -> Vec<(String, SqlType)> {
+        let fields = self
+            .variant_name
+            .as_deref()
+            .and_then(|variant_name| adt_type.variant(variant_name))
+            .map_or(&[][..], |(_, variant)| variant.fields());
+        self.bindings
+            .iter()
+            .zip(fields)
+            .filter_map(|(binding, field)| {
+                binding.clone().map(|name| (name, field.sql_type().clone()))
+            })
+            .collect()
+    }
+}
+
+impl MatchExpression {
+    /// Constructor.
+    ///
+    /// Types of columns in arm results are supposed to be given via `type_from_index`
+    /// (see [Expression::sql_type_with_index()](crate::Expression::sql_type_with_index)).
+    /// Bound names are typed as the variant's fields.
+    ///
+    /// # Failures
+    ///
+    /// - [NameErrorNotFound](crate::SqlState::NameErrorNotFound) when:
+    ///   - a pattern has a variant which `adt_type` does not have
+    /// - [NameErrorDuplicate](crate::SqlState::NameErrorDuplicate) when:
+    ///   - a pattern binds the same name twice
+    /// - [DataExceptionIllegalOperation](crate::SqlState::DataExceptionIllegalOperation) when:
+    ///   - a pattern does not have as many bindings as the variant's fields
+    ///   - arms do not cover all the variants of `adt_type`
+    ///   - types of arm results cannot be unified (e.g. INTEGER and TEXT)
+    pub fn new(
+        operand: Expression,
+        adt_type: AdtType,
+        arms: Vec<MatchArm>,
+        type_from_index: &dyn Fn(&SchemaIndex) -> Option<SqlType>,
+    ) -> ApllodbResult<Self> {
+        for arm in &arms {
+            let n_fields = match &arm.variant_name {
+                Some(variant_name) => {
+                    let (_, variant) = adt_type.variant(variant_name).ok_or_else(|| {
+                        ApllodbError::name_error_not_found(format!(
+                            "type `{}` does not have variant `{}`",
+                            adt_type.name(),
+                            variant_name
+                        ))
+                    })?;
+                    variant.fields().len()
+                }
+                None => 0,
+            };
+            if arm.bindings.len() != n_fields {
+                return Err(ApllodbError::data_exception_illegal_operation(format!(
+                    "pattern `{}` must have {} binding(s) but has {}",
+                    arm.variant_name.as_deref().unwrap_or("_"),
+                    n_fields,
+                    arm.bindings.len()
+                )));
+            }
+            if let Some(dup) = find_dup(arm.bindings.iter().flatten()) {
+                return Err(ApllodbError::name_error_duplicate(format!(
+                    "`{}` is bound twice in pattern `{}`",
+                    dup,
+                    arm.variant_name.as_deref().unwrap_or("_"),
+                )));
+            }
+        }
+
+        if !arms.iter().any(|arm| arm.variant_name.is_none()) {
+            if let Some(uncovered) = adt_type.variants().iter().find(|variant| {
+                !arms
+                    .iter()
+                    .any(|arm| arm.variant_name.as_deref() == Some(variant.name()))
+            }) {
+                return Err(ApllodbError::data_exception_illegal_operation(format!(
+                    "MATCH does not cover variant `{}` of type `{}`",
+                    uncovered.name(),
+                    adt_type.name()
+                )));
+            }
+        }
+
+        let mut result_type: Option<SqlType> = None;
+        for arm in &arms {
+            let bound_types = arm.bound_types(&adt_type);
+            let type_from_index_or_binding = |idx: &SchemaIndex| {
+                let bound = bound_types
+                    .iter()
+                    .find(|(name, _)| idx.prefix().is_none() && idx.attr() == name);
+                match bound {
+                    Some((_, sql_type)) => Some(sql_type.clone()),
+                    None => type_from_index(idx),
+                }
+            };
+            if let Some(sql_type) = arm.result.sql_type_with_index(&type_from_index_or_binding) {
+                result_type = Some(match result_type {
+                    Some(t) => t.unified(&sql_type)?,
+                    None => sql_type,
+                });
+            }
+        }
+
+        Ok(Self {
+            operand: Box::new(operand),
+            adt_type,
+            arms,
+            result_type,
+        })
+    }
+
+    /// Operand
+    pub fn operand(&self) -> &Expression {
+        &self.operand
+    }
+
+    /// Type of operand
+    pub fn adt_type(&self) -> &AdtType {
+        &self.adt_type
+    }
+
+    /// Arms in order
+    pub fn arms(&self) -> &[MatchArm] {
+        &self.arms
+    }
+
+    /// Type unified from arm results.
+    /// None if no result type is known before evaluation (e.g. all results are NULL).
+    pub fn result_type(&self) -> Option<&SqlType> {
+        self.result_type.as_ref()
+    }
+
+    /// Chooses the first arm matching `operand`.
+    /// Returns the arm's result and the bound field values.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalOperation](crate::SqlState::DataExceptionIllegalOperation) when:
+    ///   - `operand` is not a value of [adt_type()](Self::adt_type)
+    pub(crate) fn choose(
+        &self,
+        operand: NnSqlValue,
+    ) -> ApllodbResult<(&Expression, Vec<(String, NnSqlValue)>)> {
+        let adt_value = match operand {
+            NnSqlValue::Adt(v) if v.adt_type() == &self.adt_type => v,
+            _ => {
+                return Err(ApllodbError::data_exception_illegal_operation(format!(
+                    "MATCH operand `{}` is not a value of type `{}`",
+                    operand,
+                    self.adt_type.name()
+                )))
+            }
+        };
+
+        let variant_name = adt_value.variant().name();
+        let arm = self
+            .arms
+            .iter()
+            .find(|arm| match arm.variant_name() {
+                Some(v) => v == variant_name,
+                None => true,
+            })
+            .expect("arms cover all the variants (checked in constructor)");
+        Ok((&arm.result, Self::bound_values(arm, &adt_value)))
+    }
+
+    fn bound_values(arm: &MatchArm, adt_value: &AdtValue) -> Vec<(String, NnSqlValue)> {
+        arm.bindings
+            .iter()
+            .zip(adt_value.fields().iter())
+            .filter_map(|(binding, field)| binding.clone().map(|name| (name, field.clone())))
+            .collect()
+    }
+
+    /// Casts the chosen result into [result_type()](Self::result_type).
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalOperation](crate::SqlState::DataExceptionIllegalOperation) when:
+    ///   - the type of `result` is not implicitly coercible to [result_type()](Self::result_type)
+    ///     (only when the type of `result` was not known on construction)
+    pub(crate) fn cast_result(&self, result: NnSqlValue) -> ApllodbResult<NnSqlValue> {
+        match &self.result_type {
+            Some(result_type) if result.sql_type().is_implicitly_coercible_to(result_type) => {
+                result.cast(result_type)
+            }
+            Some(result_type) => Err(ApllodbError::data_exception_illegal_operation(format!(
+                "MATCH result {:?} cannot be unified into {:?}",
+                result, result_type
+            ))),
+            None => Ok(result),
+        }
+    }
+}
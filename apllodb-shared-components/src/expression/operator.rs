@@ -12,4 +12,32 @@ pub enum UnaryOperator {
 pub enum BinaryOperator {
     /// =
     Equal,
+    /// <>, !=
+    NotEqual,
+    /// <
+    LessThan,
+    /// <=
+    LessThanOrEqual,
+    /// >
+    GreaterThan,
+    /// >=
+    GreaterThanOrEqual,
+    /// AND
+    And,
+    /// OR
+    Or,
+    /// +
+    Plus,
+    /// -
+    Minus,
+    /// *
+    Multiply,
+    /// /
+    Divide,
+    /// %
+    Modulo,
+    /// ->
+    JsonExtract,
+    /// ->>
+    JsonExtractText,
 }
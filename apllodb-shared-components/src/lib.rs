@@ -21,10 +21,15 @@ pub use crate::{
         ApllodbError, ApllodbResult,
     },
     expression::{
+        arithmetic_function::ArithmeticFunction,
         boolean_expression::{
-            comparison_function::ComparisonFunction, logical_function::LogicalFunction,
-            BooleanExpression,
+            between_predicate::BetweenPredicate, comparison_function::ComparisonFunction,
+            in_predicate::InPredicate, like_predicate::LikePredicate,
+            logical_function::LogicalFunction, null_predicate::NullPredicate, BooleanExpression,
         },
+        case_expression::CaseExpression,
+        function_call::{FunctionCall, ScalarFunctionBody},
+        match_expression::{MatchArm, MatchExpression},
         operator::{BinaryOperator, UnaryOperator},
         Expression,
     },
@@ -38,8 +43,17 @@ pub use crate::{
         short_name::ShortName,
     },
     value::{
+        adt::{AdtField, AdtType, AdtValue, AdtVariant},
+        array::ArrayValue,
+        decimal::Decimal,
+        fuzzy_date::FuzzyDate,
+        interval::Interval,
+        json::Json,
         sql_convertible::SqlConvertible,
-        sql_type::{I64LooseType, NumericComparableType, SqlType, StringComparableLoseType},
+        sql_type::{
+            DatetimeType, DecimalType, F64LooseType, I64LooseType, NumericComparableType, SqlType,
+            StringComparableLoseType,
+        },
         sql_value::{
             nn_sql_value::NnSqlValue, sql_compare_result::SqlCompareResult,
             sql_value_hash_key::SqlValueHashKey, SqlValue,
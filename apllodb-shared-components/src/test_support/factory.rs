@@ -3,8 +3,9 @@
 //! Factory methods for testing
 
 use crate::{
-    BooleanExpression, ComparisonFunction, DatabaseName, Expression, LogicalFunction, NnSqlValue,
-    SqlValue, UnaryOperator,
+    ArithmeticFunction, BetweenPredicate, BooleanExpression, ComparisonFunction, DatabaseName,
+    Expression, InPredicate, LikePredicate, LogicalFunction, NnSqlValue, NullPredicate, SqlValue,
+    UnaryOperator,
 };
 use rand::Rng;
 
@@ -24,10 +25,21 @@ impl Expression {
         Self::ConstantVariant(SqlValue::factory_integer(integer))
     }
 
+    pub fn factory_text(text: &str) -> Self {
+        Self::ConstantVariant(SqlValue::factory_text(text))
+    }
+
     pub fn factory_uni_op(unary_operator: UnaryOperator, expression: Expression) -> Self {
         Self::UnaryOperatorVariant(unary_operator, Box::new(expression))
     }
 
+    pub fn factory_add(left: Expression, right: Expression) -> Self {
+        Self::ArithmeticFunctionVariant(ArithmeticFunction::AddVariant {
+            left: Box::new(left),
+            right: Box::new(right),
+        })
+    }
+
     pub fn factory_eq(left: Expression, right: Expression) -> Self {
         Self::BooleanExpressionVariant(BooleanExpression::factory_eq(left, right))
     }
@@ -35,8 +47,69 @@ impl Expression {
     pub fn factory_and(left: BooleanExpression, right: BooleanExpression) -> Self {
         Self::BooleanExpressionVariant(BooleanExpression::LogicalFunctionVariant(
             LogicalFunction::AndVariant {
-                left: Box::new(left),
-                right: Box::new(right),
+                left: Box::new(Self::BooleanExpressionVariant(left)),
+                right: Box::new(Self::BooleanExpressionVariant(right)),
+            },
+        ))
+    }
+
+    pub fn factory_or(left: BooleanExpression, right: BooleanExpression) -> Self {
+        Self::BooleanExpressionVariant(BooleanExpression::LogicalFunctionVariant(
+            LogicalFunction::OrVariant {
+                left: Box::new(Self::BooleanExpressionVariant(left)),
+                right: Box::new(Self::BooleanExpressionVariant(right)),
+            },
+        ))
+    }
+
+    pub fn factory_is_null(child: Expression) -> Self {
+        Self::BooleanExpressionVariant(BooleanExpression::NullPredicateVariant(
+            NullPredicate::IsNullVariant {
+                child: Box::new(child),
+            },
+        ))
+    }
+
+    pub fn factory_in(child: Expression, list: Vec<Expression>) -> Self {
+        Self::BooleanExpressionVariant(BooleanExpression::InPredicateVariant(
+            InPredicate::InVariant {
+                child: Box::new(child),
+                list,
+            },
+        ))
+    }
+
+    pub fn factory_between(child: Expression, low: Expression, high: Expression) -> Self {
+        Self::BooleanExpressionVariant(BooleanExpression::BetweenPredicateVariant(
+            BetweenPredicate::BetweenVariant {
+                child: Box::new(child),
+                low: Box::new(low),
+                high: Box::new(high),
+            },
+        ))
+    }
+
+    pub fn factory_like(child: Expression, pattern: Expression) -> Self {
+        Self::BooleanExpressionVariant(BooleanExpression::LikePredicateVariant(
+            LikePredicate::LikeVariant {
+                child: Box::new(child),
+                pattern: Box::new(pattern),
+            },
+        ))
+    }
+
+    pub fn factory_not(child: Expression) -> Self {
+        Self::BooleanExpressionVariant(BooleanExpression::LogicalFunctionVariant(
+            LogicalFunction::NotVariant {
+                child: Box::new(child),
+            },
+        ))
+    }
+
+    pub fn factory_possibly(child: Expression) -> Self {
+        Self::BooleanExpressionVariant(BooleanExpression::LogicalFunctionVariant(
+            LogicalFunction::PossiblyVariant {
+                child: Box::new(child),
             },
         ))
     }
@@ -59,6 +132,10 @@ impl SqlValue {
     pub fn factory_bool(bool_: bool) -> Self {
         Self::NotNull(NnSqlValue::factory_bool(bool_))
     }
+
+    pub fn factory_text(text: &str) -> Self {
+        Self::NotNull(NnSqlValue::factory_text(text))
+    }
 }
 
 impl NnSqlValue {
@@ -69,6 +146,10 @@ impl NnSqlValue {
     pub fn factory_bool(bool_: bool) -> Self {
         Self::Boolean(bool_)
     }
+
+    pub fn factory_text(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
 }
 
 pub fn random_id() -> String {
@@ -1,3 +1,12 @@
+pub(crate) mod adt;
+pub(crate) mod array;
+pub(crate) mod bytea;
+pub(crate) mod coercion;
+pub(crate) mod datetime;
+pub(crate) mod decimal;
+pub(crate) mod fuzzy_date;
+pub(crate) mod interval;
+pub(crate) mod json;
 pub(crate) mod sql_convertible;
 pub(crate) mod sql_type;
 pub(crate) mod sql_value;
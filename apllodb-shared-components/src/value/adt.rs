@@ -0,0 +1,459 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    find_dup, ApllodbError, ApllodbResult, NnSqlValue, SqlCompareResult, SqlType,
+    StringComparableLoseType,
+};
+
+/// Algebraic data type (sum type) defined by `CREATE TYPE name AS VARIANT (...)`.
+///
+/// A value of this type is one of the variants, each of which has its own fields.
+/// A type whose variants have no field works as an enumerated type.
+///
+/// Types are compared structurally: two types with the same name and the same variants are the same type.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub struct AdtType {
+    name: String,
+    variants: Vec<AdtVariant>,
+}
+
+/// Variant of an [AdtType](crate::AdtType).
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize, new)]
+pub struct AdtVariant {
+    name: String,
+    fields: Vec<AdtField>,
+}
+
+/// Field of an [AdtVariant](crate::AdtVariant).
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize, new)]
+pub struct AdtField {
+    name: String,
+    sql_type: SqlType,
+}
+
+impl AdtType {
+    /// Constructor.
+    ///
+    /// # Failures
+    ///
+    /// - [DdlError](crate::SqlState::DdlError) when:
+    ///   - `variants` is empty
+    /// - [NameErrorDuplicate](crate::SqlState::NameErrorDuplicate) when:
+    ///   - variants have the same name
+    ///   - fields of a variant have the same name
+    pub fn new(name: &str, variants: Vec<AdtVariant>) -> ApllodbResult<Self> {
+        if variants.is_empty() {
+            return Err(ApllodbError::ddl_error(format!(
+                "type `{}` must have at least 1 variant",
+                name
+            )));
+        }
+        if let Some(dup) = find_dup(variants.iter().map(|v| v.name.as_str())) {
+            return Err(ApllodbError::name_error_duplicate(format!(
+                "variant `{}` is defined twice in type `{}`",
+                dup, name
+            )));
+        }
+        for variant in &variants {
+            if let Some(dup) = find_dup(variant.fields.iter().map(|f| f.name.as_str())) {
+                return Err(ApllodbError::name_error_duplicate(format!(
+                    "field `{}` is defined twice in variant `{}` of type `{}`",
+                    dup, variant.name, name
+                )));
+            }
+        }
+        Ok(Self {
+            name: name.to_string(),
+            variants,
+        })
+    }
+
+    /// Type name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Variants in definition order
+    pub fn variants(&self) -> &[AdtVariant] {
+        &self.variants
+    }
+
+    /// Position and definition of the variant named `variant_name`.
+    pub fn variant(&self, variant_name: &str) -> Option<(usize, &AdtVariant)> {
+        self.variants
+            .iter()
+            .enumerate()
+            .find(|(_, v)| v.name == variant_name)
+    }
+}
+
+impl AdtVariant {
+    /// Variant name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Fields in definition order
+    pub fn fields(&self) -> &[AdtField] {
+        &self.fields
+    }
+}
+
+impl AdtField {
+    /// Field name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Field type
+    pub fn sql_type(&self) -> &SqlType {
+        &self.sql_type
+    }
+}
+
+/// Value of an [AdtType](crate::AdtType): a variant and its field values (fields are NOT NULL).
+///
+/// Values are ordered by the position of their variants in the type definition, and then by their fields lexicographically.
+///
+/// Text representation is like `circle(1)`, `rect(2, 3)`, or `red` (variant without fields),
+/// where TEXT fields are double-quoted (`""` stands for a double quote).
+#[derive(Clone, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub struct AdtValue {
+    adt_type: AdtType,
+    variant_index: usize,
+    fields: Vec<NnSqlValue>,
+}
+
+impl AdtValue {
+    /// Constructs a value of the variant `variant_name`.
+    /// Each of `fields` is implicitly converted into the type of the field.
+    ///
+    /// # Failures
+    ///
+    /// - [NameErrorNotFound](crate::SqlState::NameErrorNotFound) when:
+    ///   - `adt_type` does not have `variant_name`
+    /// - [DataExceptionIllegalOperation](crate::SqlState::DataExceptionIllegalOperation) when:
+    ///   - the number of `fields` does not match the variant's
+    /// - [DataExceptionIllegalConversion](crate::SqlState::DataExceptionIllegalConversion) when:
+    ///   - a field value is not implicitly converted into the field's type
+    /// - [DataExceptionStringDataRightTruncation](crate::SqlState::DataExceptionStringDataRightTruncation) when:
+    ///   - a string field value is longer than the field's VARCHAR(n) or CHAR(n)
+    pub fn new(
+        adt_type: AdtType,
+        variant_name: &str,
+        fields: Vec<NnSqlValue>,
+    ) -> ApllodbResult<Self> {
+        let (variant_index, variant) = adt_type.variant(variant_name).ok_or_else(|| {
+            ApllodbError::name_error_not_found(format!(
+                "type `{}` does not have variant `{}`",
+                adt_type.name, variant_name
+            ))
+        })?;
+
+        if variant.fields.len() != fields.len() {
+            return Err(ApllodbError::data_exception_illegal_operation(format!(
+                "variant `{}` of type `{}` takes {} field(s) but got {}",
+                variant.name,
+                adt_type.name,
+                variant.fields.len(),
+                fields.len()
+            )));
+        }
+        let fields = fields
+            .into_iter()
+            .zip(variant.fields.iter())
+            .map(|(v, field)| {
+                v.check_length(&field.sql_type)?;
+                v.coerce(&field.sql_type)
+            })
+            .collect::<ApllodbResult<Vec<_>>>()?;
+
+        Ok(Self {
+            adt_type,
+            variant_index,
+            fields,
+        })
+    }
+
+    /// Type of this value
+    pub fn adt_type(&self) -> &AdtType {
+        &self.adt_type
+    }
+
+    /// Variant of this value
+    pub fn variant(&self) -> &AdtVariant {
+        &self.adt_type.variants[self.variant_index]
+    }
+
+    /// Field values in definition order
+    pub fn fields(&self) -> &[NnSqlValue] {
+        &self.fields
+    }
+
+    /// Compares with a value of the same type.
+    pub(crate) fn compare(&self, other: &Self) -> ApllodbResult<SqlCompareResult> {
+        if self.variant_index != other.variant_index {
+            return Ok(SqlCompareResult::from(
+                self.variant_index.cmp(&other.variant_index),
+            ));
+        }
+        for (self_field, other_field) in self.fields.iter().zip(other.fields.iter()) {
+            match self_field.sql_compare(other_field)? {
+                SqlCompareResult::Eq => {}
+                result => return Ok(result),
+            }
+        }
+        Ok(SqlCompareResult::Eq)
+    }
+
+    /// Parses the text representation into a value of `adt_type`.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionInvalidCharacterValueForCast](crate::SqlState::DataExceptionInvalidCharacterValueForCast) when:
+    ///   - `s` is not in the text representation
+    /// - Errors from [new()](Self::new) and conversion of each field from TEXT.
+    pub(crate) fn parse(s: &str, adt_type: &AdtType) -> ApllodbResult<Self> {
+        let invalid = || {
+            ApllodbError::data_exception_invalid_character_value_for_cast(format!(
+                "`{}` is not a value of type `{}`",
+                s, adt_type.name
+            ))
+        };
+
+        let s = s.trim();
+        let (variant_name, fields_str) = match s.find('(') {
+            None => (s, None),
+            Some(open) if s.ends_with(')') => (&s[..open], Some(&s[open + 1..s.len() - 1])),
+            Some(_) => return Err(invalid()),
+        };
+        let variant_name = variant_name.trim();
+        let (_, variant) = adt_type.variant(variant_name).ok_or_else(invalid)?;
+
+        let field_strs = match fields_str {
+            None => vec![],
+            Some(fields_str) => split_fields(fields_str).ok_or_else(invalid)?,
+        };
+        if field_strs.len() != variant.fields.len() {
+            return Err(invalid());
+        }
+
+        let fields = field_strs
+            .into_iter()
+            .zip(variant.fields.iter())
+            .map(|(field_str, field)| match &field.sql_type {
+                SqlType::StringComparableLoose(StringComparableLoseType::Text) => {
+                    unquote(field_str).map(NnSqlValue::Text).ok_or_else(invalid)
+                }
+                sql_type @ SqlType::StringComparableLoose(_) => unquote(field_str)
+                    .ok_or_else(invalid)
+                    .and_then(|s| NnSqlValue::Text(s).cast(sql_type)),
+                SqlType::AdtComparable(field_type) => {
+                    Self::parse(field_str, field_type).map(NnSqlValue::Adt)
+                }
+                sql_type => NnSqlValue::Text(field_str.to_string()).cast(sql_type),
+            })
+            .collect::<ApllodbResult<Vec<_>>>()?;
+
+        Self::new(adt_type.clone(), variant_name, fields)
+    }
+}
+
+impl Display for AdtValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.variant().name)?;
+        if !self.fields.is_empty() {
+            let fields: Vec<String> = self
+                .fields
+                .iter()
+                .map(|field| match field {
+                    NnSqlValue::Text(s) | NnSqlValue::Char(s) => {
+                        format!(r#""{}""#, s.replace('"', r#""""#))
+                    }
+                    _ => field.to_string(),
+                })
+                .collect();
+            write!(f, "({})", fields.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Splits `a, "b, c", d(e, f)` into `["a", "\"b, c\"", "d(e, f)"]`.
+/// Returns None if quotes or parentheses are not balanced.
+fn split_fields(s: &str) -> Option<Vec<&str>> {
+    let mut fields = Vec::new();
+    let (mut depth, mut in_quote, mut start) = (0usize, false, 0usize);
+
+    for (i, c) in s.char_indices() {
+        match c {
+            '"' => in_quote = !in_quote, // `""` toggles twice
+            '(' if !in_quote => depth += 1,
+            ')' if !in_quote => depth = depth.checked_sub(1)?,
+            ',' if !in_quote && depth == 0 => {
+                fields.push(s[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if in_quote || depth != 0 {
+        return None;
+    }
+    fields.push(s[start..].trim());
+    Some(fields)
+}
+
+fn unquote(s: &str) -> Option<String> {
+    if s.len() >= 2 && s.starts_with('"') && s.ends_with('"') {
+        Some(s[1..s.len() - 1].replace(r#""""#, r#"""#))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{AdtField, AdtType, AdtValue, AdtVariant};
+    use crate::{ApllodbResult, NnSqlValue, SqlCompareResult, SqlState, SqlType};
+
+    fn shape() -> AdtType {
+        AdtType::new(
+            "shape",
+            vec![
+                AdtVariant::new(
+                    "circle".to_string(),
+                    vec![AdtField::new("r".to_string(), SqlType::integer())],
+                ),
+                AdtVariant::new(
+                    "rect".to_string(),
+                    vec![
+                        AdtField::new("w".to_string(), SqlType::integer()),
+                        AdtField::new("h".to_string(), SqlType::integer()),
+                    ],
+                ),
+                AdtVariant::new(
+                    "labeled".to_string(),
+                    vec![AdtField::new("label".to_string(), SqlType::text())],
+                ),
+                AdtVariant::new("empty".to_string(), vec![]),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn value(variant_name: &str, fields: Vec<NnSqlValue>) -> AdtValue {
+        AdtValue::new(shape(), variant_name, fields).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(
+            AdtType::new("t", vec![]).unwrap_err().kind(),
+            &SqlState::DdlError
+        );
+        assert_eq!(
+            AdtType::new(
+                "t",
+                vec![
+                    AdtVariant::new("a".to_string(), vec![]),
+                    AdtVariant::new("a".to_string(), vec![])
+                ]
+            )
+            .unwrap_err()
+            .kind(),
+            &SqlState::NameErrorDuplicate
+        );
+
+        // SMALLINT is widened into INTEGER
+        assert_eq!(
+            value("circle", vec![NnSqlValue::SmallInt(1)]).fields(),
+            &[NnSqlValue::Integer(1)]
+        );
+        assert_eq!(
+            AdtValue::new(shape(), "triangle", vec![])
+                .unwrap_err()
+                .kind(),
+            &SqlState::NameErrorNotFound
+        );
+        assert_eq!(
+            AdtValue::new(shape(), "circle", vec![]).unwrap_err().kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+        assert_eq!(
+            AdtValue::new(shape(), "circle", vec![NnSqlValue::Text("1".to_string())])
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalConversion
+        );
+    }
+
+    #[test]
+    fn test_display_and_parse() -> ApllodbResult<()> {
+        let values = vec![
+            (value("circle", vec![NnSqlValue::Integer(1)]), "circle(1)"),
+            (
+                value(
+                    "rect",
+                    vec![NnSqlValue::Integer(2), NnSqlValue::Integer(-3)],
+                ),
+                "rect(2, -3)",
+            ),
+            (
+                value(
+                    "labeled",
+                    vec![NnSqlValue::Text(r#"a, "b" (c)"#.to_string())],
+                ),
+                r#"labeled("a, ""b"" (c)")"#,
+            ),
+            (value("empty", vec![]), "empty"),
+        ];
+        for (v, s) in values {
+            assert_eq!(v.to_string(), s);
+            assert_eq!(AdtValue::parse(s, &shape())?, v);
+        }
+
+        assert_eq!(
+            AdtValue::parse(" rect( 2 ,3 ) ", &shape())?,
+            value("rect", vec![NnSqlValue::Integer(2), NnSqlValue::Integer(3)])
+        );
+
+        for s in &[
+            "circle",
+            "circle(1",
+            "rect(1)",
+            "labeled(abc)",
+            "triangle(1)",
+        ] {
+            assert_eq!(
+                AdtValue::parse(s, &shape()).unwrap_err().kind(),
+                &SqlState::DataExceptionInvalidCharacterValueForCast,
+                "{}",
+                s
+            );
+        }
+        assert_eq!(
+            AdtValue::parse("circle(x)", &shape()).unwrap_err().kind(),
+            &SqlState::DataExceptionInvalidCharacterValueForCast
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_compare() -> ApllodbResult<()> {
+        let circle1 = value("circle", vec![NnSqlValue::Integer(1)]);
+        let circle2 = value("circle", vec![NnSqlValue::Integer(2)]);
+        let rect = value("rect", vec![NnSqlValue::Integer(1), NnSqlValue::Integer(1)]);
+
+        assert_eq!(circle1.compare(&circle1.clone())?, SqlCompareResult::Eq);
+        assert_eq!(circle1.compare(&circle2)?, SqlCompareResult::LessThan);
+        // ordered by variants first
+        assert_eq!(circle2.compare(&rect)?, SqlCompareResult::LessThan);
+        assert_eq!(rect.compare(&circle1)?, SqlCompareResult::GreaterThan);
+        Ok(())
+    }
+}
@@ -0,0 +1,324 @@
+use std::{convert::TryFrom, fmt::Display, hash::Hash};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ApllodbError, ApllodbResult, NnSqlValue, SqlCompareResult, SqlType, SqlValue};
+
+/// Array of values of the same type, used as the value of `element_type[]` (e.g. `TEXT[]`).
+///
+/// Elements can be NULL.
+/// Arrays are ordered lexicographically by their elements (a prefix is smaller), where NULL elements are equal to each other
+/// and larger than any other element (same as PostgreSQL).
+///
+/// Text representation is like `{1,2,NULL}` or `{"a","b"}`, where strings are double-quoted
+/// (`\"` and `\\` stand for a double quote and a backslash).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ArrayValue {
+    element_type: SqlType,
+    elements: Vec<SqlValue>,
+}
+
+impl ArrayValue {
+    /// Constructs an array of `element_type`.
+    /// Each of `elements` is implicitly converted into `element_type`.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalConversion](crate::SqlState::DataExceptionIllegalConversion) when:
+    ///   - an element is not implicitly converted into `element_type`
+    /// - [DataExceptionStringDataRightTruncation](crate::SqlState::DataExceptionStringDataRightTruncation) when:
+    ///   - a string element is longer than `element_type`'s VARCHAR(n) or CHAR(n)
+    pub fn new(element_type: SqlType, elements: Vec<SqlValue>) -> ApllodbResult<Self> {
+        let elements = elements
+            .into_iter()
+            .map(|element| match element {
+                SqlValue::Null => Ok(SqlValue::Null),
+                SqlValue::NotNull(nn) => {
+                    nn.check_length(&element_type)?;
+                    nn.coerce(&element_type).map(SqlValue::NotNull)
+                }
+            })
+            .collect::<ApllodbResult<Vec<_>>>()?;
+        Ok(Self {
+            element_type,
+            elements,
+        })
+    }
+
+    /// Constructs an array whose element type is unified from `elements` (see [SqlType::unified()](crate::SqlType::unified)).
+    /// An array of only NULLs is TEXT[].
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalOperation](crate::SqlState::DataExceptionIllegalOperation) when:
+    ///   - `elements` is empty (element type cannot be determined)
+    ///   - types of elements cannot be unified (e.g. INTEGER and TEXT)
+    pub fn from_elements(elements: Vec<SqlValue>) -> ApllodbResult<Self> {
+        if elements.is_empty() {
+            return Err(ApllodbError::data_exception_illegal_operation(
+                "cannot determine the element type of an empty array",
+            ));
+        }
+
+        let mut element_type: Option<SqlType> = None;
+        for element in &elements {
+            if let SqlValue::NotNull(nn) = element {
+                let sql_type = nn.sql_type();
+                element_type = Some(match element_type {
+                    None => sql_type,
+                    Some(t) => t.unified(&sql_type)?,
+                });
+            }
+        }
+        Self::new(element_type.unwrap_or_else(SqlType::text), elements)
+    }
+
+    /// Element type
+    pub fn element_type(&self) -> &SqlType {
+        &self.element_type
+    }
+
+    /// Elements
+    pub fn elements(&self) -> &[SqlValue] {
+        &self.elements
+    }
+
+    /// Number of elements
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Whether this array has no element
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Element at `index` (1-origin). NULL if `index` is out of range.
+    pub fn element(&self, index: i64) -> SqlValue {
+        index
+            .checked_sub(1)
+            .and_then(|i| usize::try_from(i).ok())
+            .and_then(|i| self.elements.get(i))
+            .cloned()
+            .unwrap_or(SqlValue::Null)
+    }
+
+    /// Explicit conversion of each element into `element_type` (CAST).
+    ///
+    /// # Failures
+    ///
+    /// - Errors from [NnSqlValue::cast()](crate::NnSqlValue::cast) of each element.
+    pub(crate) fn cast(self, element_type: &SqlType) -> ApllodbResult<Self> {
+        let elements = self
+            .elements
+            .into_iter()
+            .map(|element| match element {
+                SqlValue::Null => Ok(SqlValue::Null),
+                SqlValue::NotNull(nn) => nn.cast(element_type).map(SqlValue::NotNull),
+            })
+            .collect::<ApllodbResult<Vec<_>>>()?;
+        Ok(Self {
+            element_type: element_type.clone(),
+            elements,
+        })
+    }
+
+    /// Compares with an array of comparable elements.
+    pub(crate) fn compare(&self, other: &Self) -> ApllodbResult<SqlCompareResult> {
+        for (self_element, other_element) in self.elements.iter().zip(other.elements.iter()) {
+            let result = match (self_element, other_element) {
+                (SqlValue::Null, SqlValue::Null) => SqlCompareResult::Eq,
+                (SqlValue::Null, SqlValue::NotNull(_)) => SqlCompareResult::GreaterThan,
+                (SqlValue::NotNull(_), SqlValue::Null) => SqlCompareResult::LessThan,
+                (SqlValue::NotNull(self_nn), SqlValue::NotNull(other_nn)) => {
+                    self_nn.sql_compare(other_nn)?
+                }
+            };
+            if !matches!(result, SqlCompareResult::Eq) {
+                return Ok(result);
+            }
+        }
+        Ok(SqlCompareResult::from(
+            self.elements.len().cmp(&other.elements.len()),
+        ))
+    }
+}
+
+impl PartialEq for ArrayValue {
+    fn eq(&self, other: &Self) -> bool {
+        matches!(self.compare(other), Ok(SqlCompareResult::Eq))
+    }
+}
+
+impl Hash for ArrayValue {
+    /// Unlike [SqlValue](crate::SqlValue), NULL elements have the same hash value since they are equal to each other.
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.elements.len().hash(state);
+        for element in &self.elements {
+            match element {
+                SqlValue::Null => 0u8.hash(state),
+                SqlValue::NotNull(nn) => {
+                    1u8.hash(state);
+                    nn.hash(state);
+                }
+            }
+        }
+    }
+}
+
+impl Display for ArrayValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let quoted = |s: &str| format!(r#""{}""#, s.replace('\\', r"\\").replace('"', r#"\""#));
+        let elements: Vec<String> =
+            self.elements
+                .iter()
+                .map(|element| match element {
+                    SqlValue::Null => "NULL".to_string(),
+                    SqlValue::NotNull(NnSqlValue::Text(s))
+                    | SqlValue::NotNull(NnSqlValue::Char(s)) => quoted(s),
+                    SqlValue::NotNull(NnSqlValue::Json(j)) => quoted(&j.to_string()),
+                    SqlValue::NotNull(nn) => nn.to_string(),
+                })
+                .collect();
+        write!(f, "{{{}}}", elements.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::ArrayValue;
+    use crate::{ApllodbResult, NnSqlValue, SqlCompareResult, SqlState, SqlType, SqlValue};
+
+    fn integers(values: &[Option<i32>]) -> ArrayValue {
+        ArrayValue::new(
+            SqlType::integer(),
+            values
+                .iter()
+                .map(|v| {
+                    v.map_or(SqlValue::Null, |i| {
+                        SqlValue::NotNull(NnSqlValue::Integer(i))
+                    })
+                })
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    fn text(s: &str) -> SqlValue {
+        SqlValue::NotNull(NnSqlValue::Text(s.to_string()))
+    }
+
+    #[test]
+    fn test_new() -> ApllodbResult<()> {
+        // SMALLINT is widened into INTEGER
+        assert_eq!(
+            ArrayValue::new(
+                SqlType::integer(),
+                vec![SqlValue::NotNull(NnSqlValue::SmallInt(1))]
+            )?
+            .elements(),
+            &[SqlValue::NotNull(NnSqlValue::Integer(1))]
+        );
+        assert_eq!(
+            ArrayValue::new(SqlType::integer(), vec![text("1")])
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalConversion
+        );
+        assert_eq!(
+            ArrayValue::new(SqlType::varchar(2)?, vec![text("abc")])
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionStringDataRightTruncation
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_elements() -> ApllodbResult<()> {
+        let array = ArrayValue::from_elements(vec![
+            SqlValue::NotNull(NnSqlValue::SmallInt(1)),
+            SqlValue::Null,
+            SqlValue::NotNull(NnSqlValue::BigInt(3)),
+        ])?;
+        assert_eq!(array.element_type(), &SqlType::big_int());
+        assert_eq!(array, integers(&[Some(1), None, Some(3)]));
+
+        assert_eq!(
+            ArrayValue::from_elements(vec![SqlValue::Null])?.element_type(),
+            &SqlType::text()
+        );
+        assert_eq!(
+            ArrayValue::from_elements(vec![]).unwrap_err().kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+        assert_eq!(
+            ArrayValue::from_elements(vec![SqlValue::NotNull(NnSqlValue::Integer(1)), text("a")])
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_element() {
+        let array = integers(&[Some(10), None, Some(30)]);
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.element(1), SqlValue::NotNull(NnSqlValue::Integer(10)));
+        assert_eq!(array.element(3), SqlValue::NotNull(NnSqlValue::Integer(30)));
+        for index in &[0, 2, 4, -1, i64::MIN] {
+            assert!(matches!(array.element(*index), SqlValue::Null), "{}", index);
+        }
+    }
+
+    #[test]
+    fn test_compare() -> ApllodbResult<()> {
+        let cmp = |l: &[Option<i32>], r: &[Option<i32>]| integers(l).compare(&integers(r));
+
+        assert!(matches!(
+            cmp(&[Some(1), Some(2)], &[Some(1), Some(2)])?,
+            SqlCompareResult::Eq
+        ));
+        assert!(matches!(
+            cmp(&[Some(1), None], &[Some(1), None])?,
+            SqlCompareResult::Eq
+        ));
+        assert!(matches!(
+            cmp(&[Some(1), Some(2)], &[Some(1), Some(3)])?,
+            SqlCompareResult::LessThan
+        ));
+        assert!(matches!(
+            cmp(&[Some(1)], &[Some(1), Some(0)])?,
+            SqlCompareResult::LessThan
+        ));
+        assert!(matches!(
+            cmp(&[None], &[Some(9)])?,
+            SqlCompareResult::GreaterThan
+        ));
+        assert!(matches!(cmp(&[], &[])?, SqlCompareResult::Eq));
+
+        let texts = ArrayValue::from_elements(vec![text("a")])?;
+        assert_eq!(
+            integers(&[Some(1)]).compare(&texts).unwrap_err().kind(),
+            &SqlState::DataExceptionIllegalComparison
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_display() -> ApllodbResult<()> {
+        assert_eq!(
+            integers(&[Some(1), None, Some(-3)]).to_string(),
+            "{1,NULL,-3}"
+        );
+        assert_eq!(integers(&[]).to_string(), "{}");
+        assert_eq!(
+            ArrayValue::from_elements(vec![text("a b"), text(r#"c"\d"#)])?.to_string(),
+            r#"{"a b","c\"\\d"}"#
+        );
+        Ok(())
+    }
+}
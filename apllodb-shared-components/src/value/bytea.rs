@@ -0,0 +1,131 @@
+//! Text representations of BYTEA values.
+//!
+//! Values are printed in PostgreSQL's hex format (e.g. `\x01ff`), and parsed from either of the hex format or the escape format
+//! (e.g. `ab\000\\`, in which `\ooo` is an octal byte value and `\\` is a backslash).
+
+use crate::{ApllodbError, ApllodbResult};
+
+/// `\x` followed by 2 lowercase hex digits per byte.
+pub(crate) fn format_bytea(bytes: &[u8]) -> String {
+    let mut s = String::with_capacity(2 + bytes.len() * 2);
+    s.push_str("\\x");
+    for b in bytes {
+        s.push_str(&format!("{:02x}", b));
+    }
+    s
+}
+
+/// Parses the hex format (`\x01ff`) or the escape format (`ab\000\\`).
+///
+/// # Failures
+///
+/// - [DataExceptionInvalidCharacterValueForCast](crate::SqlState::DataExceptionInvalidCharacterValueForCast) when:
+///   - `s` is in neither of the formats above
+pub(crate) fn parse_bytea(s: &str) -> ApllodbResult<Vec<u8>> {
+    match s.strip_prefix("\\x").or_else(|| s.strip_prefix("\\X")) {
+        Some(hex) => parse_hex(hex),
+        None => parse_escape(s),
+    }
+}
+
+/// Parses pairs of hex digits (case-insensitive), which may be separated by whitespaces.
+///
+/// # Failures
+///
+/// - [DataExceptionInvalidCharacterValueForCast](crate::SqlState::DataExceptionInvalidCharacterValueForCast) when:
+///   - `hex` contains a character other than hex digits and whitespaces
+///   - `hex` has an odd number of hex digits
+pub(crate) fn parse_hex(hex: &str) -> ApllodbResult<Vec<u8>> {
+    let invalid = |reason: &str| {
+        ApllodbError::data_exception_invalid_character_value_for_cast(format!(
+            "invalid hex BYTEA `{}`: {}",
+            hex, reason
+        ))
+    };
+
+    let mut bytes = Vec::with_capacity(hex.len() / 2);
+    let mut chars = hex.chars().filter(|c| !c.is_whitespace());
+    while let Some(high) = chars.next() {
+        let low = chars
+            .next()
+            .ok_or_else(|| invalid("odd number of hex digits"))?;
+        let digit = |c: char| {
+            c.to_digit(16)
+                .ok_or_else(|| invalid(&format!("`{}` is not a hex digit", c)))
+        };
+        bytes.push((digit(high)? * 16 + digit(low)?) as u8);
+    }
+    Ok(bytes)
+}
+
+fn parse_escape(s: &str) -> ApllodbResult<Vec<u8>> {
+    let invalid = || {
+        ApllodbError::data_exception_invalid_character_value_for_cast(format!(
+            "invalid escaped BYTEA `{}`: backslash must be followed by another backslash or 3 octal digits (000-377)",
+            s
+        ))
+    };
+
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut rest = s.as_bytes();
+    while let Some((&b, tail)) = rest.split_first() {
+        if b != b'\\' {
+            bytes.push(b);
+            rest = tail;
+        } else if tail.first() == Some(&b'\\') {
+            bytes.push(b'\\');
+            rest = &tail[1..];
+        } else {
+            match tail {
+                [d0 @ b'0'..=b'3', d1 @ b'0'..=b'7', d2 @ b'0'..=b'7', ..] => {
+                    bytes.push((d0 - b'0') * 64 + (d1 - b'0') * 8 + (d2 - b'0'));
+                    rest = &tail[3..];
+                }
+                _ => return Err(invalid()),
+            }
+        }
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{format_bytea, parse_bytea, parse_hex};
+    use crate::{ApllodbResult, SqlState};
+
+    #[test]
+    fn test_format_and_parse() -> ApllodbResult<()> {
+        assert_eq!(format_bytea(&[]), r"\x");
+        assert_eq!(format_bytea(&[0x01, 0xab, 0xff]), r"\x01abff");
+
+        assert_eq!(parse_bytea(r"\x01ABff")?, vec![0x01, 0xab, 0xff]);
+        assert_eq!(parse_bytea(r"\x01 ab")?, vec![0x01, 0xab]);
+        assert_eq!(parse_bytea(r"\x")?, Vec::<u8>::new());
+        assert_eq!(parse_bytea("ab")?, b"ab".to_vec());
+        assert_eq!(parse_bytea(r"a\000\\\377")?, vec![b'a', 0, b'\\', 0xff]);
+        assert_eq!(parse_bytea("")?, Vec::<u8>::new());
+
+        for bytes in &[vec![], vec![0u8, 1, 2], vec![0xff; 3]] {
+            assert_eq!(&parse_bytea(&format_bytea(bytes))?, bytes);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for s in &[r"\x0", r"\x0g", r"a\", r"\400", r"\12", r"\n"] {
+            assert_eq!(
+                parse_bytea(s).unwrap_err().kind(),
+                &SqlState::DataExceptionInvalidCharacterValueForCast,
+                "{}",
+                s
+            );
+        }
+        assert_eq!(
+            parse_hex("abc").unwrap_err().kind(),
+            &SqlState::DataExceptionInvalidCharacterValueForCast
+        );
+    }
+}
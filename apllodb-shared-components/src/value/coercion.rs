@@ -0,0 +1,1200 @@
+use std::convert::TryFrom;
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+use crate::{
+    value::{
+        bytea::parse_bytea,
+        datetime::{parse_date, parse_time, parse_timestamp, parse_timestamp_with_time_zone},
+    },
+    AdtValue, ApllodbError, ApllodbResult, ArrayValue, DatetimeType, Decimal, DecimalType,
+    F64LooseType, FuzzyDate, I64LooseType, Interval, Json, NnSqlValue, NumericComparableType,
+    SqlType, SqlValue, StringComparableLoseType,
+};
+
+/// Conversions between SqlTypes.
+///
+/// - **Implicit** conversions are applied without CAST, e.g. to compare or calculate values in different types.
+///   Numeric types are converted into each other: integer types are widened (SMALLINT < INTEGER < BIGINT),
+///   integers are converted into NUMERIC, and integers and NUMERIC are converted into floating-point types.
+///   DATE is converted into TIMESTAMP, and DATE and TIMESTAMP are converted into TIMESTAMP WITH TIME ZONE.
+///   DATE is converted into FUZZY DATE.
+/// - **Explicit** conversions are applied by `CAST(expression AS type)` (or `expression::type`).
+///   Numeric types, TEXT and BOOLEAN can be explicitly converted into each other, although some values cannot (e.g. `"abc"` into INTEGER).
+///   Date/time types and INTERVAL can be explicitly converted from / into TEXT and some of date/time types (see [NnSqlValue::cast()](crate::NnSqlValue::cast)).
+///   Values of a user-defined type can be explicitly converted from / into TEXT in their text representation (see [AdtValue](crate::AdtValue)).
+///   BYTEA can be explicitly converted from / into TEXT in hex or escape format.
+///   JSON can be explicitly converted from / into TEXT.
+///   Arrays can be explicitly converted into arrays of another element type (element by element), and into TEXT.
+///   VARCHAR(n) and CHAR(n) are converted as TEXT, and a longer string is truncated into n characters by CAST.
+impl SqlType {
+    /// Type into which values of both `self` and `other` are implicitly converted.
+    ///
+    /// - Integer types are unified into the wider one (SMALLINT < INTEGER < BIGINT).
+    /// - Floating-point types are unified into the wider one (REAL < DOUBLE PRECISION).
+    /// - NUMERIC and integer types are unified into NUMERIC
+    ///   (without precision and scale unless both are NUMERIC with the same precision and scale).
+    /// - Floating-point types and other numeric types are unified into DOUBLE PRECISION.
+    /// - DATE and TIMESTAMP are unified into TIMESTAMP.
+    /// - DATE or TIMESTAMP and TIMESTAMP WITH TIME ZONE are unified into TIMESTAMP WITH TIME ZONE.
+    /// - DATE and FUZZY DATE are unified into FUZZY DATE.
+    /// - CHAR types are unified into the longer one, and other pairs of string types (TEXT, VARCHAR and CHAR) are unified into TEXT.
+    /// - A user-defined type is unified only with itself.
+    /// - Arrays are unified into the array of their unified element type.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalOperation](crate::SqlState::DataExceptionIllegalOperation) when:
+    ///   - `self` and `other` are not in the same category (e.g. INTEGER and TEXT)
+    pub fn unified(&self, other: &SqlType) -> ApllodbResult<SqlType> {
+        match (self, other) {
+            (
+                SqlType::NumericComparable(NumericComparableType::I64Loose(self_t)),
+                SqlType::NumericComparable(NumericComparableType::I64Loose(other_t)),
+            ) => Ok(SqlType::NumericComparable(NumericComparableType::I64Loose(
+                self_t.max(other_t).clone(),
+            ))),
+            (
+                SqlType::NumericComparable(NumericComparableType::F64Loose(self_t)),
+                SqlType::NumericComparable(NumericComparableType::F64Loose(other_t)),
+            ) => Ok(SqlType::NumericComparable(NumericComparableType::F64Loose(
+                self_t.max(other_t).clone(),
+            ))),
+            (
+                SqlType::NumericComparable(NumericComparableType::F64Loose(_)),
+                SqlType::NumericComparable(_),
+            )
+            | (
+                SqlType::NumericComparable(_),
+                SqlType::NumericComparable(NumericComparableType::F64Loose(_)),
+            ) => Ok(SqlType::double_precision()),
+            (
+                SqlType::NumericComparable(NumericComparableType::Decimal(self_t)),
+                SqlType::NumericComparable(NumericComparableType::Decimal(other_t)),
+            ) if self_t == other_t => Ok(self.clone()),
+            (
+                SqlType::NumericComparable(NumericComparableType::Decimal(_)),
+                SqlType::NumericComparable(_),
+            )
+            | (
+                SqlType::NumericComparable(_),
+                SqlType::NumericComparable(NumericComparableType::Decimal(_)),
+            ) => Ok(SqlType::numeric()),
+            (
+                SqlType::StringComparableLoose(StringComparableLoseType::Char { length: self_l }),
+                SqlType::StringComparableLoose(StringComparableLoseType::Char { length: other_l }),
+            ) => Ok(SqlType::StringComparableLoose(
+                StringComparableLoseType::Char {
+                    length: *self_l.max(other_l),
+                },
+            )),
+            (SqlType::StringComparableLoose(_), SqlType::StringComparableLoose(_)) => {
+                Ok(SqlType::text())
+            }
+            (SqlType::BooleanComparable, SqlType::BooleanComparable)
+            | (SqlType::BinaryComparable, SqlType::BinaryComparable)
+            | (SqlType::JsonComparable, SqlType::JsonComparable)
+            | (SqlType::IntervalComparable, SqlType::IntervalComparable) => Ok(self.clone()),
+            (SqlType::DatetimeComparable(self_t), SqlType::DatetimeComparable(other_t))
+                if self_t == other_t =>
+            {
+                Ok(self.clone())
+            }
+            (
+                SqlType::DatetimeComparable(DatetimeType::Date),
+                SqlType::DatetimeComparable(DatetimeType::Timestamp),
+            )
+            | (
+                SqlType::DatetimeComparable(DatetimeType::Timestamp),
+                SqlType::DatetimeComparable(DatetimeType::Date),
+            ) => Ok(SqlType::timestamp()),
+            (
+                SqlType::DatetimeComparable(DatetimeType::Date | DatetimeType::Timestamp),
+                SqlType::DatetimeComparable(DatetimeType::TimestampWithTimeZone),
+            )
+            | (
+                SqlType::DatetimeComparable(DatetimeType::TimestampWithTimeZone),
+                SqlType::DatetimeComparable(DatetimeType::Date | DatetimeType::Timestamp),
+            ) => Ok(SqlType::timestamp_with_time_zone()),
+            (
+                SqlType::DatetimeComparable(DatetimeType::Date),
+                SqlType::DatetimeComparable(DatetimeType::FuzzyDate),
+            )
+            | (
+                SqlType::DatetimeComparable(DatetimeType::FuzzyDate),
+                SqlType::DatetimeComparable(DatetimeType::Date),
+            ) => Ok(SqlType::fuzzy_date()),
+            (SqlType::AdtComparable(self_t), SqlType::AdtComparable(other_t))
+                if self_t == other_t =>
+            {
+                Ok(self.clone())
+            }
+            (SqlType::ArrayComparable(self_t), SqlType::ArrayComparable(other_t)) => {
+                Ok(SqlType::array(self_t.unified(other_t)?))
+            }
+            _ => Err(ApllodbError::data_exception_illegal_operation(format!(
+                "{:?} and {:?} cannot be unified",
+                self, other
+            ))),
+        }
+    }
+
+    /// Whether values of `self` are implicitly converted into `target`.
+    ///
+    /// String types are implicitly converted into each other (e.g. TEXT into VARCHAR(n)),
+    /// and so are arrays whose element types are (e.g. INTEGER[] into BIGINT[]).
+    pub fn is_implicitly_coercible_to(&self, target: &SqlType) -> bool {
+        match (self, target) {
+            (SqlType::StringComparableLoose(_), SqlType::StringComparableLoose(_)) => true,
+            (SqlType::ArrayComparable(self_t), SqlType::ArrayComparable(target_t)) => {
+                self_t.is_implicitly_coercible_to(target_t)
+            }
+            _ => matches!(self.unified(target), Ok(unified) if &unified == target),
+        }
+    }
+}
+
+impl NnSqlValue {
+    /// Implicit conversion into `target`.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalConversion](crate::SqlState::DataExceptionIllegalConversion) when:
+    ///   - this value's type is not implicitly coercible to `target` (see [SqlType::is_implicitly_coercible_to()](crate::SqlType::is_implicitly_coercible_to))
+    pub fn coerce(self, target: &SqlType) -> ApllodbResult<Self> {
+        if self.sql_type().is_implicitly_coercible_to(target) {
+            self.cast(target)
+        } else {
+            Err(ApllodbError::data_exception_illegal_conversion(format!(
+                "{:?} is not implicitly converted into {:?} (use CAST)",
+                self, target
+            )))
+        }
+    }
+
+    /// Implicit conversion into the type unified from this value's type and `sql_type`
+    /// (see [SqlType::unified()](crate::SqlType::unified)).
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalOperation](crate::SqlState::DataExceptionIllegalOperation) when:
+    ///   - this value's type and `sql_type` cannot be unified
+    pub(crate) fn unify_type(self, sql_type: &SqlType) -> ApllodbResult<Self> {
+        let unified = self.sql_type().unified(sql_type)?;
+        self.coerce(&unified)
+    }
+
+    /// Explicit conversion into `target` (CAST).
+    ///
+    /// - Numeric -> integer: value is kept (floating-point and NUMERIC values are rounded to the nearest integer, half away from zero).
+    /// - Numeric -> floating-point: nearest value.
+    /// - Numeric -> NUMERIC: value is kept (rounded into the scale of `NUMERIC(precision, scale)`).
+    /// - Numeric -> TEXT: decimal representation.
+    /// - TEXT -> integer: parses decimal representation (leading and trailing whitespaces are ignored).
+    /// - TEXT -> floating-point / NUMERIC: parses decimal representation, optionally with fractional digits and exponent (e.g. `-1.5e3`).
+    /// - Numeric -> BOOLEAN: 0 is FALSE and others are TRUE.
+    /// - BOOLEAN -> numeric: FALSE is 0 and TRUE is 1.
+    /// - BOOLEAN -> TEXT: `"true"` or `"false"`.
+    /// - TEXT -> BOOLEAN: `"true"`, `"t"`, `"yes"`, `"y"`, `"on"`, `"1"` are TRUE and
+    ///   `"false"`, `"f"`, `"no"`, `"n"`, `"off"`, `"0"` are FALSE (case-insensitive, leading and trailing whitespaces are ignored).
+    /// - TEXT -> date/time types: parses ISO 8601 style representation
+    ///   (`YYYY-MM-DD` for DATE, `HH:MM[:SS[.fraction]]` for TIME, `YYYY-MM-DD[( |T)HH:MM[:SS[.fraction]]]` for TIMESTAMP,
+    ///   and TIMESTAMP followed by an optional UTC offset (`Z` or `[+-]HH[[:]MM]`) for TIMESTAMP WITH TIME ZONE).
+    /// - TEXT -> INTERVAL: parses PostgreSQL style representation (see [Interval](crate::Interval)).
+    /// - Date/time types / INTERVAL -> TEXT: representation parsed back into the same value.
+    /// - DATE -> TIMESTAMP [WITH TIME ZONE]: midnight of the date (in UTC).
+    /// - TIMESTAMP <-> TIMESTAMP WITH TIME ZONE: TIMESTAMP is regarded as in UTC.
+    /// - TIMESTAMP [WITH TIME ZONE] -> DATE / TIME: date / time part (in UTC).
+    /// - TEXT -> FUZZY DATE: parses partial dates, ranges and `circa` (see [FuzzyDate](crate::FuzzyDate)).
+    /// - DATE / TIMESTAMP [WITH TIME ZONE] -> FUZZY DATE: exact date (date part in UTC).
+    /// - FUZZY DATE -> DATE: only an exact one.
+    /// - TEXT -> BYTEA: parses hex format (`\x01ff`) or escape format (`ab\000\\`).
+    /// - BYTEA -> TEXT: hex format.
+    /// - TEXT -> JSON: parses JSON text.
+    /// - JSON -> TEXT: compact JSON text (see [Json](crate::Json)).
+    /// - Array -> array: each element is converted into the target element type.
+    /// - Array -> TEXT: text representation (see [ArrayValue](crate::ArrayValue)).
+    /// - Any type -> VARCHAR(n) / CHAR(n): same as TEXT, truncated into n characters (CHAR is padded with trailing spaces into n characters).
+    /// - CHAR -> any type: same as TEXT without trailing spaces.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalConversion](crate::SqlState::DataExceptionIllegalConversion) when:
+    ///   - this value's type cannot be converted into `target` (e.g. DATE into INTEGER)
+    ///   - an ambiguous FUZZY DATE is converted into DATE
+    /// - [DataExceptionNumericValueOutOfRange](crate::SqlState::DataExceptionNumericValueOutOfRange) when:
+    ///   - a number does not fit in the target numeric type
+    /// - [DataExceptionInvalidCharacterValueForCast](crate::SqlState::DataExceptionInvalidCharacterValueForCast) when:
+    ///   - a text does not represent a value of the target type
+    /// - [DataExceptionInvalidDatetimeFormat](crate::SqlState::DataExceptionInvalidDatetimeFormat) when:
+    ///   - a text does not represent a value of the target date/time type or INTERVAL
+    /// - [DataExceptionDatetimeFieldOverflow](crate::SqlState::DataExceptionDatetimeFieldOverflow) when:
+    ///   - a field of date/time or INTERVAL text is out of range (e.g. month 13)
+    /// - [DataExceptionInvalidTimeZoneDisplacementValue](crate::SqlState::DataExceptionInvalidTimeZoneDisplacementValue) when:
+    ///   - a UTC offset of TIMESTAMP WITH TIME ZONE text is out of range
+    /// - [DataExceptionInvalidJsonText](crate::SqlState::DataExceptionInvalidJsonText) when:
+    ///   - a text is not a JSON text
+    pub fn cast(self, target: &SqlType) -> ApllodbResult<Self> {
+        if let NnSqlValue::Char(s) = self {
+            return NnSqlValue::Text(s.trim_end_matches(' ').to_string()).cast(target);
+        }
+
+        let invalid_text = |s: &str| {
+            ApllodbError::data_exception_invalid_character_value_for_cast(format!(
+                "{} cannot be converted into {:?}",
+                s, target
+            ))
+        };
+        let out_of_range = |v: &dyn std::fmt::Display| {
+            ApllodbError::data_exception_numeric_value_out_of_range(format!(
+                "{} is out of range of {:?}",
+                v, target
+            ))
+        };
+        let illegal = |v: &NnSqlValue| {
+            ApllodbError::data_exception_illegal_conversion(format!(
+                "{:?} cannot be converted into {:?}",
+                v, target
+            ))
+        };
+
+        match target {
+            SqlType::NumericComparable(NumericComparableType::I64Loose(target_t)) => {
+                let v: i64 = match &self {
+                    NnSqlValue::SmallInt(_) | NnSqlValue::Integer(_) | NnSqlValue::BigInt(_) => {
+                        self.unpack::<i64>()?
+                    }
+                    NnSqlValue::Real(_) | NnSqlValue::DoublePrecision(_) => {
+                        let f = self.unpack::<f64>()?.round();
+                        // `as` saturates at i64::MIN / MAX
+                        if f >= -(2f64.powi(63)) && f < 2f64.powi(63) {
+                            f as i64
+                        } else {
+                            return Err(out_of_range(&self));
+                        }
+                    }
+                    NnSqlValue::Numeric(d) => d.to_i64().map_err(|_| out_of_range(&self))?,
+                    NnSqlValue::Text(s) | NnSqlValue::Char(s) => s
+                        .trim()
+                        .parse::<i64>()
+                        .map_err(|_| invalid_text(&self.to_string()))?,
+                    NnSqlValue::Boolean(b) => i64::from(*b),
+                    NnSqlValue::Date(_)
+                    | NnSqlValue::Time(_)
+                    | NnSqlValue::Timestamp(_)
+                    | NnSqlValue::TimestampWithTimeZone(_)
+                    | NnSqlValue::FuzzyDate(_)
+                    | NnSqlValue::Interval(_)
+                    | NnSqlValue::Bytes(_)
+                    | NnSqlValue::Json(_)
+                    | NnSqlValue::Adt(_)
+                    | NnSqlValue::Array(_) => return Err(illegal(&self)),
+                };
+                Self::i64_into(v, target_t)
+            }
+            SqlType::NumericComparable(NumericComparableType::F64Loose(target_t)) => {
+                let v: f64 = match &self {
+                    NnSqlValue::SmallInt(_) | NnSqlValue::Integer(_) | NnSqlValue::BigInt(_) => {
+                        self.unpack::<i64>()? as f64
+                    }
+                    NnSqlValue::Real(_) | NnSqlValue::DoublePrecision(_) => self.unpack::<f64>()?,
+                    NnSqlValue::Numeric(d) => d.to_f64(),
+                    NnSqlValue::Text(s) | NnSqlValue::Char(s) => match s.trim().parse::<f64>() {
+                        Ok(f) if f.is_finite() => f,
+                        Ok(_) => return Err(out_of_range(&self)),
+                        Err(_) => return Err(invalid_text(&self.to_string())),
+                    },
+                    NnSqlValue::Boolean(b) => f64::from(u8::from(*b)),
+                    NnSqlValue::Date(_)
+                    | NnSqlValue::Time(_)
+                    | NnSqlValue::Timestamp(_)
+                    | NnSqlValue::TimestampWithTimeZone(_)
+                    | NnSqlValue::FuzzyDate(_)
+                    | NnSqlValue::Interval(_)
+                    | NnSqlValue::Bytes(_)
+                    | NnSqlValue::Json(_)
+                    | NnSqlValue::Adt(_)
+                    | NnSqlValue::Array(_) => return Err(illegal(&self)),
+                };
+                match target_t {
+                    F64LooseType::Real => {
+                        let f = v as f32;
+                        if f.is_infinite() && v.is_finite() {
+                            Err(out_of_range(&self))
+                        } else {
+                            Ok(NnSqlValue::Real(f))
+                        }
+                    }
+                    F64LooseType::DoublePrecision => Ok(NnSqlValue::DoublePrecision(v)),
+                }
+            }
+            SqlType::NumericComparable(NumericComparableType::Decimal(target_t)) => {
+                let d: Decimal = match &self {
+                    NnSqlValue::SmallInt(_) | NnSqlValue::Integer(_) | NnSqlValue::BigInt(_) => {
+                        Decimal::from(self.unpack::<i64>()?)
+                    }
+                    // shortest representation as f32 (e.g. `0.1`, not `0.10000000149011612`)
+                    NnSqlValue::Real(v) if v.is_finite() => v.to_string().parse::<Decimal>()?,
+                    NnSqlValue::Real(v) => Decimal::from_f64(f64::from(*v))?,
+                    NnSqlValue::DoublePrecision(v) => Decimal::from_f64(*v)?,
+                    NnSqlValue::Numeric(d) => *d,
+                    NnSqlValue::Text(s) | NnSqlValue::Char(s) => s.parse::<Decimal>()?,
+                    NnSqlValue::Boolean(b) => Decimal::from(i64::from(*b)),
+                    NnSqlValue::Date(_)
+                    | NnSqlValue::Time(_)
+                    | NnSqlValue::Timestamp(_)
+                    | NnSqlValue::TimestampWithTimeZone(_)
+                    | NnSqlValue::FuzzyDate(_)
+                    | NnSqlValue::Interval(_)
+                    | NnSqlValue::Bytes(_)
+                    | NnSqlValue::Json(_)
+                    | NnSqlValue::Adt(_)
+                    | NnSqlValue::Array(_) => return Err(illegal(&self)),
+                };
+                match target_t {
+                    DecimalType::Unconstrained => Ok(NnSqlValue::Numeric(d)),
+                    DecimalType::Constrained { precision, scale } => {
+                        Ok(NnSqlValue::Numeric(d.fit(*precision, *scale)?))
+                    }
+                }
+            }
+            SqlType::StringComparableLoose(target_t) => {
+                let s = match self {
+                    NnSqlValue::SmallInt(_)
+                    | NnSqlValue::Integer(_)
+                    | NnSqlValue::BigInt(_)
+                    | NnSqlValue::Real(_)
+                    | NnSqlValue::DoublePrecision(_)
+                    | NnSqlValue::Numeric(_)
+                    | NnSqlValue::Date(_)
+                    | NnSqlValue::Time(_)
+                    | NnSqlValue::Timestamp(_)
+                    | NnSqlValue::TimestampWithTimeZone(_)
+                    | NnSqlValue::FuzzyDate(_)
+                    | NnSqlValue::Interval(_)
+                    | NnSqlValue::Bytes(_)
+                    | NnSqlValue::Json(_)
+                    | NnSqlValue::Adt(_)
+                    | NnSqlValue::Array(_) => self.to_string(),
+                    NnSqlValue::Text(s) | NnSqlValue::Char(s) => s,
+                    NnSqlValue::Boolean(b) => b.to_string(),
+                };
+                let truncated = |s: String, n: u32| s.chars().take(n as usize).collect::<String>();
+                match target_t {
+                    StringComparableLoseType::Text => Ok(NnSqlValue::Text(s)),
+                    StringComparableLoseType::VarChar { max_length } => {
+                        Ok(NnSqlValue::Text(truncated(s, *max_length)))
+                    }
+                    StringComparableLoseType::Char { length } => {
+                        let s = truncated(s, *length);
+                        let padding = *length as usize - s.chars().count();
+                        Ok(NnSqlValue::Char(s + &" ".repeat(padding)))
+                    }
+                }
+            }
+            SqlType::BooleanComparable => {
+                let b = match &self {
+                    NnSqlValue::SmallInt(_) | NnSqlValue::Integer(_) | NnSqlValue::BigInt(_) => {
+                        self.unpack::<i64>()? != 0
+                    }
+                    NnSqlValue::Real(_) | NnSqlValue::DoublePrecision(_) => {
+                        self.unpack::<f64>()? != 0.0
+                    }
+                    NnSqlValue::Numeric(d) => !d.is_zero(),
+                    NnSqlValue::Text(s) | NnSqlValue::Char(s) => {
+                        match s.trim().to_lowercase().as_str() {
+                            "true" | "t" | "yes" | "y" | "on" | "1" => true,
+                            "false" | "f" | "no" | "n" | "off" | "0" => false,
+                            _ => return Err(invalid_text(&self.to_string())),
+                        }
+                    }
+                    NnSqlValue::Boolean(b) => *b,
+                    NnSqlValue::Date(_)
+                    | NnSqlValue::Time(_)
+                    | NnSqlValue::Timestamp(_)
+                    | NnSqlValue::TimestampWithTimeZone(_)
+                    | NnSqlValue::FuzzyDate(_)
+                    | NnSqlValue::Interval(_)
+                    | NnSqlValue::Bytes(_)
+                    | NnSqlValue::Json(_)
+                    | NnSqlValue::Adt(_)
+                    | NnSqlValue::Array(_) => return Err(illegal(&self)),
+                };
+                Ok(NnSqlValue::Boolean(b))
+            }
+            SqlType::DatetimeComparable(target_t) => {
+                let as_utc = |t: &NaiveDateTime| DateTime::<Utc>::from_utc(*t, Utc);
+                match (target_t, &self) {
+                    (DatetimeType::Date, NnSqlValue::Text(s)) => {
+                        parse_date(s).map(NnSqlValue::Date)
+                    }
+                    (DatetimeType::Time, NnSqlValue::Text(s)) => {
+                        parse_time(s).map(NnSqlValue::Time)
+                    }
+                    (DatetimeType::Timestamp, NnSqlValue::Text(s)) => {
+                        parse_timestamp(s).map(NnSqlValue::Timestamp)
+                    }
+                    (DatetimeType::TimestampWithTimeZone, NnSqlValue::Text(s)) => {
+                        parse_timestamp_with_time_zone(s).map(NnSqlValue::TimestampWithTimeZone)
+                    }
+                    (DatetimeType::FuzzyDate, NnSqlValue::Text(s)) => {
+                        s.parse::<FuzzyDate>().map(NnSqlValue::FuzzyDate)
+                    }
+
+                    (DatetimeType::Date, NnSqlValue::Date(d)) => Ok(NnSqlValue::Date(*d)),
+                    (DatetimeType::Date, NnSqlValue::Timestamp(t)) => {
+                        Ok(NnSqlValue::Date(t.date()))
+                    }
+                    (DatetimeType::Date, NnSqlValue::TimestampWithTimeZone(t)) => {
+                        Ok(NnSqlValue::Date(t.naive_utc().date()))
+                    }
+                    (DatetimeType::Date, NnSqlValue::FuzzyDate(fd)) => match fd.as_exact() {
+                        Some(d) => Ok(NnSqlValue::Date(d)),
+                        None => Err(ApllodbError::data_exception_illegal_conversion(format!(
+                            "FUZZY DATE `{}` is not an exact date",
+                            fd
+                        ))),
+                    },
+
+                    (DatetimeType::Time, NnSqlValue::Time(t)) => Ok(NnSqlValue::Time(*t)),
+                    (DatetimeType::Time, NnSqlValue::Timestamp(t)) => {
+                        Ok(NnSqlValue::Time(t.time()))
+                    }
+                    (DatetimeType::Time, NnSqlValue::TimestampWithTimeZone(t)) => {
+                        Ok(NnSqlValue::Time(t.naive_utc().time()))
+                    }
+
+                    (DatetimeType::Timestamp, NnSqlValue::Date(d)) => {
+                        Ok(NnSqlValue::Timestamp(d.and_hms(0, 0, 0)))
+                    }
+                    (DatetimeType::Timestamp, NnSqlValue::Timestamp(t)) => {
+                        Ok(NnSqlValue::Timestamp(*t))
+                    }
+                    (DatetimeType::Timestamp, NnSqlValue::TimestampWithTimeZone(t)) => {
+                        Ok(NnSqlValue::Timestamp(t.naive_utc()))
+                    }
+
+                    (DatetimeType::TimestampWithTimeZone, NnSqlValue::Date(d)) => Ok(
+                        NnSqlValue::TimestampWithTimeZone(as_utc(&d.and_hms(0, 0, 0))),
+                    ),
+                    (DatetimeType::TimestampWithTimeZone, NnSqlValue::Timestamp(t)) => {
+                        Ok(NnSqlValue::TimestampWithTimeZone(as_utc(t)))
+                    }
+                    (DatetimeType::TimestampWithTimeZone, NnSqlValue::TimestampWithTimeZone(t)) => {
+                        Ok(NnSqlValue::TimestampWithTimeZone(*t))
+                    }
+
+                    (DatetimeType::FuzzyDate, NnSqlValue::Date(d)) => {
+                        Ok(NnSqlValue::FuzzyDate(FuzzyDate::exact(*d)))
+                    }
+                    (DatetimeType::FuzzyDate, NnSqlValue::Timestamp(t)) => {
+                        Ok(NnSqlValue::FuzzyDate(FuzzyDate::exact(t.date())))
+                    }
+                    (DatetimeType::FuzzyDate, NnSqlValue::TimestampWithTimeZone(t)) => Ok(
+                        NnSqlValue::FuzzyDate(FuzzyDate::exact(t.naive_utc().date())),
+                    ),
+                    (DatetimeType::FuzzyDate, NnSqlValue::FuzzyDate(fd)) => {
+                        Ok(NnSqlValue::FuzzyDate(*fd))
+                    }
+
+                    _ => Err(illegal(&self)),
+                }
+            }
+            SqlType::BinaryComparable => match self {
+                NnSqlValue::Text(s) => parse_bytea(&s).map(NnSqlValue::Bytes),
+                NnSqlValue::Bytes(b) => Ok(NnSqlValue::Bytes(b)),
+                _ => Err(illegal(&self)),
+            },
+            SqlType::JsonComparable => match self {
+                NnSqlValue::Text(s) => s.parse::<Json>().map(NnSqlValue::Json),
+                NnSqlValue::Json(j) => Ok(NnSqlValue::Json(j)),
+                _ => Err(illegal(&self)),
+            },
+            SqlType::IntervalComparable => match &self {
+                NnSqlValue::Text(s) => s.parse::<Interval>().map(NnSqlValue::Interval),
+                NnSqlValue::Interval(i) => Ok(NnSqlValue::Interval(*i)),
+                _ => Err(illegal(&self)),
+            },
+            SqlType::AdtComparable(target_t) => match self {
+                NnSqlValue::Text(s) => AdtValue::parse(&s, target_t).map(NnSqlValue::Adt),
+                NnSqlValue::Adt(v) if v.adt_type() == target_t => Ok(NnSqlValue::Adt(v)),
+                _ => Err(illegal(&self)),
+            },
+            SqlType::ArrayComparable(element_type) => match self {
+                NnSqlValue::Array(a) => a.cast(element_type).map(NnSqlValue::Array),
+                _ => Err(illegal(&self)),
+            },
+        }
+    }
+
+    /// Converts this value into `target` to store it into a column (on INSERT or UPDATE).
+    ///
+    /// Same as [cast()](Self::cast) except that a string is not truncated into VARCHAR(n) or CHAR(n) but rejected (see [check_length()](Self::check_length)).
+    /// The length is checked after conversion into TEXT, so that non-string values (e.g. `12345` into VARCHAR(3)) are also rejected.
+    ///
+    /// # Failures
+    ///
+    /// - Errors from [cast()](Self::cast) and [check_length()](Self::check_length).
+    pub fn assign(self, target: &SqlType) -> ApllodbResult<Self> {
+        let value = match Self::without_length(target) {
+            Some(unbounded_target) => self.cast(&unbounded_target)?,
+            None => self,
+        };
+        value.check_length(target)?;
+        value.cast(target)
+    }
+
+    /// `target` whose VARCHAR(n) and CHAR(n) (including array elements) are replaced with TEXT,
+    /// or None if `target` has neither of them.
+    fn without_length(target: &SqlType) -> Option<SqlType> {
+        match target {
+            SqlType::StringComparableLoose(target_t) if target_t.max_length().is_some() => {
+                Some(SqlType::text())
+            }
+            SqlType::ArrayComparable(element_type) => {
+                Self::without_length(element_type).map(SqlType::array)
+            }
+            _ => None,
+        }
+    }
+
+    /// Checks if this value is stored into a column of `target` (on INSERT or UPDATE) without losing characters.
+    ///
+    /// Unlike CAST, a string longer than VARCHAR(n) or CHAR(n) is rejected unless the excess characters are all spaces.
+    /// Elements of an array are checked against the element type.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionStringDataRightTruncation](crate::SqlState::DataExceptionStringDataRightTruncation) when:
+    ///   - this value is a string longer than `target`'s length and its excess characters are not all spaces
+    pub fn check_length(&self, target: &SqlType) -> ApllodbResult<()> {
+        if let (NnSqlValue::Array(a), SqlType::ArrayComparable(element_type)) = (self, target) {
+            return Self::check_elements(a, |nn| nn.check_length(element_type));
+        }
+
+        let max_length = match target {
+            SqlType::StringComparableLoose(target_t) => target_t.max_length(),
+            _ => None,
+        };
+        match (self, max_length) {
+            (NnSqlValue::Text(s), Some(max_length)) | (NnSqlValue::Char(s), Some(max_length))
+                if s.chars().skip(max_length as usize).any(|c| c != ' ') =>
+            {
+                Err(ApllodbError::data_exception_string_data_right_truncation(
+                    format!("value too long for {:?}: {:?}", target, s),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn check_elements(
+        array: &ArrayValue,
+        check: impl Fn(&NnSqlValue) -> ApllodbResult<()>,
+    ) -> ApllodbResult<()> {
+        array
+            .elements()
+            .iter()
+            .filter_map(|element| match element {
+                SqlValue::NotNull(nn) => Some(nn),
+                SqlValue::Null => None,
+            })
+            .try_for_each(check)
+    }
+
+    fn i64_into(v: i64, target: &I64LooseType) -> ApllodbResult<Self> {
+        let out_of_range = || {
+            ApllodbError::data_exception_numeric_value_out_of_range(format!(
+                "{} is out of range of {:?}",
+                v, target
+            ))
+        };
+        match target {
+            I64LooseType::SmallInt => i16::try_from(v)
+                .map(NnSqlValue::SmallInt)
+                .map_err(|_| out_of_range()),
+            I64LooseType::Integer => i32::try_from(v)
+                .map(NnSqlValue::Integer)
+                .map_err(|_| out_of_range()),
+            I64LooseType::BigInt => Ok(NnSqlValue::BigInt(v)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ApllodbResult, ArrayValue, NnSqlValue, SqlState, SqlType, SqlValue};
+
+    #[test]
+    fn test_unified() -> ApllodbResult<()> {
+        assert_eq!(
+            SqlType::small_int().unified(&SqlType::big_int())?,
+            SqlType::big_int()
+        );
+        assert_eq!(
+            SqlType::integer().unified(&SqlType::small_int())?,
+            SqlType::integer()
+        );
+        assert_eq!(
+            SqlType::real().unified(&SqlType::double_precision())?,
+            SqlType::double_precision()
+        );
+        assert_eq!(
+            SqlType::real().unified(&SqlType::small_int())?,
+            SqlType::double_precision()
+        );
+        assert_eq!(
+            SqlType::big_int().unified(&SqlType::numeric_with_precision(10, 2)?)?,
+            SqlType::numeric()
+        );
+        assert_eq!(
+            SqlType::numeric_with_precision(10, 2)?
+                .unified(&SqlType::numeric_with_precision(10, 2)?)?,
+            SqlType::numeric_with_precision(10, 2)?
+        );
+        assert_eq!(
+            SqlType::numeric().unified(&SqlType::real())?,
+            SqlType::double_precision()
+        );
+        assert_eq!(SqlType::text().unified(&SqlType::text())?, SqlType::text());
+        assert_eq!(
+            SqlType::integer()
+                .unified(&SqlType::text())
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+        assert_eq!(
+            SqlType::boolean()
+                .unified(&SqlType::integer())
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+        assert_eq!(
+            SqlType::date().unified(&SqlType::timestamp())?,
+            SqlType::timestamp()
+        );
+        assert_eq!(
+            SqlType::timestamp_with_time_zone().unified(&SqlType::date())?,
+            SqlType::timestamp_with_time_zone()
+        );
+        assert_eq!(
+            SqlType::interval().unified(&SqlType::interval())?,
+            SqlType::interval()
+        );
+        assert_eq!(
+            SqlType::date().unified(&SqlType::fuzzy_date())?,
+            SqlType::fuzzy_date()
+        );
+        assert_eq!(
+            SqlType::char(3)?.unified(&SqlType::char(5)?)?,
+            SqlType::char(5)?
+        );
+        assert_eq!(
+            SqlType::char(3)?.unified(&SqlType::varchar(5)?)?,
+            SqlType::text()
+        );
+        assert_eq!(
+            SqlType::varchar(3)?.unified(&SqlType::varchar(3)?)?,
+            SqlType::text()
+        );
+        assert_eq!(
+            SqlType::time()
+                .unified(&SqlType::timestamp())
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_coerce() -> ApllodbResult<()> {
+        let v = NnSqlValue::SmallInt(1).coerce(&SqlType::big_int())?;
+        assert_eq!(v.sql_type(), SqlType::big_int());
+
+        assert_eq!(
+            NnSqlValue::BigInt(1)
+                .coerce(&SqlType::small_int())
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalConversion
+        );
+        assert_eq!(
+            NnSqlValue::Integer(1)
+                .coerce(&SqlType::text())
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalConversion
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast() -> ApllodbResult<()> {
+        let cast = |v: NnSqlValue, sql_type: SqlType| -> ApllodbResult<NnSqlValue> {
+            let casted = v.cast(&sql_type)?;
+            assert_eq!(casted.sql_type(), sql_type);
+            Ok(casted)
+        };
+
+        // integers
+        assert_eq!(
+            cast(NnSqlValue::BigInt(-3), SqlType::small_int())?,
+            NnSqlValue::SmallInt(-3)
+        );
+        assert_eq!(
+            cast(NnSqlValue::Integer(40000), SqlType::small_int())
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionNumericValueOutOfRange
+        );
+
+        // integers <-> floating-point / NUMERIC
+        assert_eq!(
+            cast(NnSqlValue::DoublePrecision(2.5), SqlType::integer())?,
+            NnSqlValue::Integer(3)
+        );
+        assert_eq!(
+            cast(NnSqlValue::DoublePrecision(1e20), SqlType::big_int())
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionNumericValueOutOfRange
+        );
+        assert_eq!(
+            cast(NnSqlValue::Numeric("-2.5".parse()?), SqlType::small_int())?,
+            NnSqlValue::SmallInt(-3)
+        );
+        assert_eq!(
+            cast(NnSqlValue::Integer(7), SqlType::real())?,
+            NnSqlValue::Real(7.0)
+        );
+        assert_eq!(
+            cast(NnSqlValue::DoublePrecision(1e300), SqlType::real())
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionNumericValueOutOfRange
+        );
+        assert_eq!(
+            cast(NnSqlValue::Real(0.1), SqlType::numeric())?.to_string(),
+            "0.1"
+        );
+        assert_eq!(
+            NnSqlValue::Numeric("123.456".parse()?)
+                .cast(&SqlType::numeric_with_precision(5, 2)?)?
+                .to_string(),
+            "123.46"
+        );
+        assert_eq!(
+            NnSqlValue::Numeric("1234.5".parse()?)
+                .cast(&SqlType::numeric_with_precision(5, 2)?)
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionNumericValueOutOfRange
+        );
+
+        // floating-point / NUMERIC <-> TEXT
+        assert_eq!(
+            cast(NnSqlValue::Numeric("1.50".parse()?), SqlType::text())?,
+            NnSqlValue::Text("1.50".to_string())
+        );
+        assert_eq!(
+            cast(
+                NnSqlValue::Text(" -1.5e3 ".to_string()),
+                SqlType::double_precision()
+            )?,
+            NnSqlValue::DoublePrecision(-1500.0)
+        );
+        assert_eq!(
+            cast(
+                NnSqlValue::Text("NaN".to_string()),
+                SqlType::double_precision()
+            )
+            .unwrap_err()
+            .kind(),
+            &SqlState::DataExceptionNumericValueOutOfRange
+        );
+        assert_eq!(
+            cast(NnSqlValue::Text("1.5x".to_string()), SqlType::numeric())
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionInvalidCharacterValueForCast
+        );
+
+        // integer <-> TEXT
+        assert_eq!(
+            cast(NnSqlValue::Integer(-42), SqlType::text())?,
+            NnSqlValue::Text("-42".to_string())
+        );
+        assert_eq!(
+            cast(NnSqlValue::Text(" 42 ".to_string()), SqlType::integer())?,
+            NnSqlValue::Integer(42)
+        );
+        assert_eq!(
+            cast(NnSqlValue::Text("4.2".to_string()), SqlType::integer())
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionInvalidCharacterValueForCast
+        );
+        assert_eq!(
+            cast(NnSqlValue::Text("100000".to_string()), SqlType::small_int())
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionNumericValueOutOfRange
+        );
+
+        // BOOLEAN <-> others
+        assert_eq!(
+            cast(NnSqlValue::Integer(2), SqlType::boolean())?,
+            NnSqlValue::Boolean(true)
+        );
+        assert_eq!(
+            cast(NnSqlValue::Boolean(true), SqlType::big_int())?,
+            NnSqlValue::BigInt(1)
+        );
+        assert_eq!(
+            cast(NnSqlValue::Boolean(false), SqlType::text())?,
+            NnSqlValue::Text("false".to_string())
+        );
+        assert_eq!(
+            cast(NnSqlValue::Text("Yes".to_string()), SqlType::boolean())?,
+            NnSqlValue::Boolean(true)
+        );
+        assert_eq!(
+            cast(NnSqlValue::Text("maybe".to_string()), SqlType::boolean())
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionInvalidCharacterValueForCast
+        );
+
+        // BYTEA <-> TEXT
+        assert_eq!(
+            cast(NnSqlValue::Text(r"\x00ff".to_string()), SqlType::bytea())?,
+            NnSqlValue::Bytes(vec![0x00, 0xff])
+        );
+        assert_eq!(
+            cast(NnSqlValue::Text(r"a\001".to_string()), SqlType::bytea())?,
+            NnSqlValue::Bytes(vec![b'a', 0x01])
+        );
+        assert_eq!(
+            cast(NnSqlValue::Bytes(vec![0x00, 0xff]), SqlType::text())?,
+            NnSqlValue::Text(r"\x00ff".to_string())
+        );
+        assert_eq!(
+            cast(NnSqlValue::Integer(1), SqlType::bytea())
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalConversion
+        );
+
+        // JSON <-> TEXT
+        let json = cast(
+            NnSqlValue::Text(r#"{"b": [1, 2], "a": "x"}"#.to_string()),
+            SqlType::json(),
+        )?;
+        assert_eq!(json.sql_type(), SqlType::json());
+        assert_eq!(
+            cast(json, SqlType::text())?,
+            NnSqlValue::Text(r#"{"a":"x","b":[1,2]}"#.to_string())
+        );
+        assert_eq!(
+            cast(NnSqlValue::Text("{a: 1}".to_string()), SqlType::json())
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionInvalidJsonText
+        );
+        assert_eq!(
+            cast(NnSqlValue::Integer(1), SqlType::json())
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalConversion
+        );
+
+        // date/time types <-> TEXT
+        let text = |s: &str| NnSqlValue::Text(s.to_string());
+        for (s, sql_type, printed) in [
+            ("2021-01-01", SqlType::date(), "2021-01-01"),
+            ("-0044-03-15", SqlType::date(), "-0044-03-15"),
+            ("12:34", SqlType::time(), "12:34:00"),
+            ("23:59:59.999999999", SqlType::time(), "23:59:59.999999999"),
+            ("2021-01-01", SqlType::timestamp(), "2021-01-01 00:00:00"),
+            (
+                "2021-01-01T12:34:56.5",
+                SqlType::timestamp(),
+                "2021-01-01 12:34:56.500",
+            ),
+            (
+                "2021-01-01 12:34:56-05:30",
+                SqlType::timestamp_with_time_zone(),
+                "2021-01-01 18:04:56+00:00",
+            ),
+            (
+                "1 year -2 days 3 hours",
+                SqlType::interval(),
+                "1 year -2 days 03:00:00",
+            ),
+        ] {
+            let v = cast(text(s), sql_type.clone())?;
+            assert_eq!(v.to_string(), printed);
+            assert_eq!(cast(v.clone(), SqlType::text())?, text(printed));
+            assert_eq!(cast(text(printed), sql_type)?, v);
+        }
+        for (s, sql_type, sql_state) in [
+            (
+                "2021/01/01",
+                SqlType::date(),
+                SqlState::DataExceptionInvalidDatetimeFormat,
+            ),
+            (
+                "2021-02-30",
+                SqlType::date(),
+                SqlState::DataExceptionDatetimeFieldOverflow,
+            ),
+            (
+                "24:00",
+                SqlType::time(),
+                SqlState::DataExceptionDatetimeFieldOverflow,
+            ),
+            (
+                "2021-01-01 00:00+25:00",
+                SqlType::timestamp_with_time_zone(),
+                SqlState::DataExceptionInvalidTimeZoneDisplacementValue,
+            ),
+            (
+                "1 eon",
+                SqlType::interval(),
+                SqlState::DataExceptionInvalidDatetimeFormat,
+            ),
+        ] {
+            assert_eq!(cast(text(s), sql_type).unwrap_err().kind(), &sql_state);
+        }
+
+        // date/time types <-> each other
+        let timestamp = cast(text("2021-01-01 12:34:56"), SqlType::timestamp())?;
+        assert_eq!(
+            cast(timestamp.clone(), SqlType::date())?.to_string(),
+            "2021-01-01"
+        );
+        assert_eq!(
+            cast(timestamp.clone(), SqlType::time())?.to_string(),
+            "12:34:56"
+        );
+        assert_eq!(
+            cast(timestamp, SqlType::timestamp_with_time_zone())?.to_string(),
+            "2021-01-01 12:34:56+00:00"
+        );
+        assert_eq!(
+            cast(text("2021-01-01"), SqlType::date())?
+                .cast(&SqlType::timestamp_with_time_zone())?
+                .to_string(),
+            "2021-01-01 00:00:00+00:00"
+        );
+        assert_eq!(
+            cast(text("12:00"), SqlType::time())?
+                .cast(&SqlType::date())
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalConversion
+        );
+        assert_eq!(
+            cast(text("2021-01-01"), SqlType::date())?
+                .cast(&SqlType::big_int())
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalConversion
+        );
+        assert_eq!(
+            NnSqlValue::Integer(20210101)
+                .cast(&SqlType::date())
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalConversion
+        );
+
+        // FUZZY DATE <-> others
+        let circa = cast(text("circa 1920s"), SqlType::fuzzy_date())?;
+        assert_eq!(cast(circa.clone(), SqlType::text())?, text("1910s..1930s"));
+        assert_eq!(
+            cast(circa, SqlType::date()).unwrap_err().kind(),
+            &SqlState::DataExceptionIllegalConversion
+        );
+        let exact = cast(
+            cast(text("2021-01-01 12:34:56"), SqlType::timestamp())?,
+            SqlType::fuzzy_date(),
+        )?;
+        assert_eq!(exact.to_string(), "2021-01-01");
+        assert_eq!(
+            cast(exact, SqlType::date())?,
+            cast(text("2021-01-01"), SqlType::date())?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_varchar_and_char() -> ApllodbResult<()> {
+        let text = |s: &str| NnSqlValue::Text(s.to_string());
+
+        // CAST truncates and pads
+        match text("abcde").cast(&SqlType::varchar(3)?)? {
+            NnSqlValue::Text(s) => assert_eq!(s, "abc"),
+            v => panic!("{:?}", v),
+        }
+        match text("ab").cast(&SqlType::char(4)?)? {
+            NnSqlValue::Char(s) => assert_eq!(s, "ab  "),
+            v => panic!("{:?}", v),
+        }
+        match text("abcde").cast(&SqlType::char(3)?)? {
+            NnSqlValue::Char(s) => assert_eq!(s, "abc"),
+            v => panic!("{:?}", v),
+        }
+        // trailing spaces of CHAR are removed into other types
+        let char_v = NnSqlValue::Char("42  ".to_string());
+        match char_v.clone().cast(&SqlType::text())? {
+            NnSqlValue::Text(s) => assert_eq!(s, "42"),
+            v => panic!("{:?}", v),
+        }
+        assert_eq!(
+            char_v.clone().cast(&SqlType::integer())?.unpack::<i64>()?,
+            42
+        );
+        assert_eq!(char_v.sql_type(), SqlType::char(4)?);
+
+        // INSERT / UPDATE rejects truncation except for spaces
+        text("abc").check_length(&SqlType::varchar(3)?)?;
+        text("abc  ").check_length(&SqlType::char(3)?)?;
+        text("abcd").check_length(&SqlType::text())?;
+        NnSqlValue::Integer(12345).check_length(&SqlType::varchar(3)?)?;
+        match NnSqlValue::Integer(123).assign(&SqlType::char(4)?)? {
+            NnSqlValue::Char(s) => assert_eq!(s, "123 "),
+            v => panic!("{:?}", v),
+        }
+        assert_eq!(
+            NnSqlValue::Integer(12345)
+                .assign(&SqlType::varchar(3)?)
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionStringDataRightTruncation
+        );
+        assert_eq!(
+            NnSqlValue::Array(ArrayValue::new(
+                SqlType::integer(),
+                vec![SqlValue::NotNull(NnSqlValue::Integer(12345))]
+            )?)
+            .assign(&SqlType::array(SqlType::varchar(3)?))
+            .unwrap_err()
+            .kind(),
+            &SqlState::DataExceptionStringDataRightTruncation
+        );
+        assert_eq!(
+            text("abcd")
+                .check_length(&SqlType::varchar(3)?)
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionStringDataRightTruncation
+        );
+        assert_eq!(
+            text("ab d")
+                .check_length(&SqlType::char(2)?)
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionStringDataRightTruncation
+        );
+
+        assert_eq!(
+            SqlType::varchar(0).unwrap_err().kind(),
+            &SqlState::DataExceptionInvalidParameterValue
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_array() -> ApllodbResult<()> {
+        let array = |element_type: SqlType, elements: Vec<NnSqlValue>| {
+            NnSqlValue::Array(
+                ArrayValue::new(
+                    element_type,
+                    elements.into_iter().map(SqlValue::NotNull).collect(),
+                )
+                .unwrap(),
+            )
+        };
+        let text = |s: &str| NnSqlValue::Text(s.to_string());
+
+        assert_eq!(
+            SqlType::array(SqlType::small_int()).unified(&SqlType::array(SqlType::big_int()))?,
+            SqlType::array(SqlType::big_int())
+        );
+        assert_eq!(
+            SqlType::array(SqlType::integer())
+                .unified(&SqlType::integer())
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+
+        // implicit conversion element by element
+        let integers = array(
+            SqlType::integer(),
+            vec![NnSqlValue::Integer(1), NnSqlValue::Integer(2)],
+        );
+        assert_eq!(
+            integers
+                .clone()
+                .coerce(&SqlType::array(SqlType::big_int()))?
+                .sql_type(),
+            SqlType::array(SqlType::big_int())
+        );
+        assert_eq!(
+            integers
+                .clone()
+                .coerce(&SqlType::array(SqlType::text()))
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalConversion
+        );
+        array(SqlType::text(), vec![text("ab")]).coerce(&SqlType::array(SqlType::varchar(2)?))?;
+
+        // explicit conversion
+        assert_eq!(
+            integers
+                .clone()
+                .cast(&SqlType::array(SqlType::text()))?
+                .unpack::<ArrayValue>()?
+                .elements(),
+            &[SqlValue::NotNull(text("1")), SqlValue::NotNull(text("2"))]
+        );
+        assert_eq!(integers.clone().cast(&SqlType::text())?, text("{1,2}"));
+        assert_eq!(
+            text("{1,2}")
+                .cast(&SqlType::array(SqlType::integer()))
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalConversion
+        );
+        assert_eq!(
+            integers.cast(&SqlType::integer()).unwrap_err().kind(),
+            &SqlState::DataExceptionIllegalConversion
+        );
+
+        // INSERT / UPDATE checks each element
+        let texts = array(SqlType::text(), vec![text("ab"), text("abc")]);
+        texts.check_length(&SqlType::array(SqlType::text()))?;
+        assert_eq!(
+            texts
+                .check_length(&SqlType::array(SqlType::varchar(2)?))
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionStringDataRightTruncation
+        );
+        assert_eq!(
+            texts
+                .cast(&SqlType::array(SqlType::json()))
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionInvalidJsonText
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,308 @@
+//! Text representations of date/time values.
+//!
+//! Values are printed in ISO 8601 style with a space between date and time (e.g. `2021-01-01 12:34:56.789`),
+//! and fractional seconds are printed only as many digits as necessary (up to nanoseconds) so that texts are parsed back into the same values.
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+
+use crate::{ApllodbError, ApllodbResult};
+
+pub(crate) const DATE_FORMAT: &str = "%Y-%m-%d";
+pub(crate) const TIME_FORMAT: &str = "%H:%M:%S%.f";
+pub(crate) const TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.f";
+pub(crate) const TIMESTAMP_WITH_TIME_ZONE_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.f%:z";
+
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+/// Parses `[+-]YYYY-MM-DD`.
+///
+/// # Failures
+///
+/// - [DataExceptionInvalidDatetimeFormat](crate::SqlState::DataExceptionInvalidDatetimeFormat) when:
+///   - `s` is not in the format above
+/// - [DataExceptionDatetimeFieldOverflow](crate::SqlState::DataExceptionDatetimeFieldOverflow) when:
+///   - a field is out of range (e.g. month 13)
+pub(crate) fn parse_date(s: &str) -> ApllodbResult<NaiveDate> {
+    let invalid = || invalid_format(s, "DATE");
+
+    let s = s.trim();
+    let (sign, unsigned) = match s.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let fields: Vec<&str> = unsigned.split('-').collect();
+    match fields[..] {
+        [year, month, day]
+            if is_digits(year, 1, 9) && is_digits(month, 1, 2) && is_digits(day, 1, 2) =>
+        {
+            let year = sign * year.parse::<i32>().map_err(|_| invalid())?;
+            NaiveDate::from_ymd_opt(year, month.parse().unwrap(), day.parse().unwrap())
+                .ok_or_else(|| field_overflow(s, "DATE"))
+        }
+        _ => Err(invalid()),
+    }
+}
+
+/// Parses `HH:MM[:SS[.fraction]]`.
+///
+/// `SS` can be `60` for a leap second. Digits of `fraction` beyond nanoseconds are truncated.
+///
+/// # Failures
+///
+/// - [DataExceptionInvalidDatetimeFormat](crate::SqlState::DataExceptionInvalidDatetimeFormat) when:
+///   - `s` is not in the format above
+/// - [DataExceptionDatetimeFieldOverflow](crate::SqlState::DataExceptionDatetimeFieldOverflow) when:
+///   - a field is out of range (e.g. hour 24)
+pub(crate) fn parse_time(s: &str) -> ApllodbResult<NaiveTime> {
+    let invalid = || invalid_format(s, "TIME");
+
+    let s = s.trim();
+    let (hms, fraction) = match s.split_once('.') {
+        Some((hms, fraction)) if is_digits(fraction, 1, usize::MAX) => (hms, fraction),
+        Some(_) => return Err(invalid()),
+        None => (s, ""),
+    };
+    let fields: Vec<&str> = hms.split(':').collect();
+    let (hour, min, sec) = match fields[..] {
+        [hour, min] if fraction.is_empty() => (hour, min, "0"),
+        [hour, min, sec] => (hour, min, sec),
+        _ => return Err(invalid()),
+    };
+    if !(is_digits(hour, 1, 2) && is_digits(min, 2, 2) && is_digits(sec, 1, 2)) {
+        return Err(invalid());
+    }
+    let (hour, min, sec): (u32, u32, u32) = (
+        hour.parse().unwrap(),
+        min.parse().unwrap(),
+        sec.parse().unwrap(),
+    );
+
+    let nano: u32 = format!("{:0<9}", &fraction[..fraction.len().min(9)])
+        .parse()
+        .unwrap();
+    // chrono represents a leap second as the 59th second with more than 1,000,000,000 nanoseconds.
+    let (sec, nano) = if sec == 60 {
+        (59, NANOS_PER_SEC + nano)
+    } else {
+        (sec, nano)
+    };
+    NaiveTime::from_hms_nano_opt(hour, min, sec, nano).ok_or_else(|| field_overflow(s, "TIME"))
+}
+
+/// Parses `date[( |T)time]` (see [parse_date()](parse_date) and [parse_time()](parse_time)).
+/// Time is 00:00:00 if omitted.
+///
+/// # Failures
+///
+/// Same as [parse_date()](parse_date) and [parse_time()](parse_time).
+pub(crate) fn parse_timestamp(s: &str) -> ApllodbResult<NaiveDateTime> {
+    let s = s.trim();
+    // the first `-` may be a sign of year
+    let date_end = s
+        .char_indices()
+        .skip(1)
+        .find(|(_, c)| *c == ' ' || *c == 'T' || *c == 't')
+        .map(|(i, _)| i);
+    match date_end {
+        Some(i) => Ok(parse_date(&s[..i])?.and_time(parse_time(&s[i + 1..])?)),
+        None => Ok(parse_date(s)?.and_hms(0, 0, 0)),
+    }
+}
+
+/// Parses `timestamp[offset]` (see [parse_timestamp()](parse_timestamp)),
+/// where `offset` is `Z` or `(+|-)HH[[:]MM]` (UTC if omitted).
+///
+/// # Failures
+///
+/// Same as [parse_timestamp()](parse_timestamp), and:
+///
+/// - [DataExceptionInvalidTimeZoneDisplacementValue](crate::SqlState::DataExceptionInvalidTimeZoneDisplacementValue) when:
+///   - `offset` is out of range (must be less than 24 hours)
+pub(crate) fn parse_timestamp_with_time_zone(s: &str) -> ApllodbResult<DateTime<Utc>> {
+    let s = s.trim();
+
+    // time part does not contain `+`, `-` or `Z` except for an offset
+    let time_start = s
+        .char_indices()
+        .skip(1)
+        .find(|(_, c)| *c == ' ' || *c == 'T' || *c == 't')
+        .map(|(i, _)| i + 1);
+    let offset_start = time_start.and_then(|time_start| {
+        s[time_start..]
+            .find(['+', '-', 'Z', 'z'])
+            .map(|i| time_start + i)
+    });
+
+    let (timestamp, offset_secs) = match offset_start {
+        Some(i) => (parse_timestamp(&s[..i])?, parse_offset(&s[i..])?),
+        None => (parse_timestamp(s)?, 0),
+    };
+    let offset = FixedOffset::east_opt(offset_secs).ok_or_else(|| {
+        ApllodbError::data_exception_invalid_time_zone_displacement_value(format!(
+            "time zone offset of `{}` is out of range",
+            s
+        ))
+    })?;
+    offset
+        .from_local_datetime(&timestamp)
+        .single()
+        .map(|datetime| datetime.with_timezone(&Utc))
+        .ok_or_else(|| field_overflow(s, "TIMESTAMP WITH TIME ZONE"))
+}
+
+/// Seconds east of UTC.
+fn parse_offset(s: &str) -> ApllodbResult<i32> {
+    let invalid = || invalid_format(s, "time zone offset");
+
+    if s.eq_ignore_ascii_case("Z") {
+        return Ok(0);
+    }
+    let (sign, hhmm) = match s.split_at(1) {
+        ("+", hhmm) => (1, hhmm),
+        ("-", hhmm) => (-1, hhmm),
+        _ => return Err(invalid()),
+    };
+    let (hour, min) = match hhmm.split_once(':') {
+        Some((hour, min)) => (hour, min),
+        None if hhmm.len() == 4 => hhmm.split_at(2),
+        None => (hhmm, "0"),
+    };
+    if !(is_digits(hour, 1, 2) && is_digits(min, 1, 2)) {
+        return Err(invalid());
+    }
+    let (hour, min): (i32, i32) = (hour.parse().unwrap(), min.parse().unwrap());
+    Ok(sign * (hour * 3600 + min * 60))
+}
+
+pub(crate) fn is_digits(s: &str, min_len: usize, max_len: usize) -> bool {
+    (min_len..=max_len).contains(&s.len()) && s.chars().all(|c| c.is_ascii_digit())
+}
+
+pub(crate) fn invalid_format(s: &str, type_name: &str) -> ApllodbError {
+    ApllodbError::data_exception_invalid_datetime_format(format!(
+        "invalid input syntax for {}: `{}`",
+        type_name, s
+    ))
+}
+
+pub(crate) fn field_overflow(s: &str, type_name: &str) -> ApllodbError {
+    ApllodbError::data_exception_datetime_field_overflow(format!(
+        "field value out of range for {}: `{}`",
+        type_name, s
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveTime};
+
+    use super::{parse_date, parse_time, parse_timestamp, parse_timestamp_with_time_zone};
+    use crate::{ApllodbResult, SqlState};
+
+    #[test]
+    fn test_parse_date() -> ApllodbResult<()> {
+        assert_eq!(parse_date(" 2021-1-02 ")?, NaiveDate::from_ymd(2021, 1, 2));
+        assert_eq!(parse_date("-0044-03-15")?, NaiveDate::from_ymd(-44, 3, 15));
+        assert_eq!(
+            parse_date("+12345-01-01")?,
+            NaiveDate::from_ymd(12345, 1, 1)
+        );
+
+        for (s, sql_state) in &[
+            ("2021/01/01", SqlState::DataExceptionInvalidDatetimeFormat),
+            ("2021-01", SqlState::DataExceptionInvalidDatetimeFormat),
+            ("2021-001-01", SqlState::DataExceptionInvalidDatetimeFormat),
+            ("2021-13-01", SqlState::DataExceptionDatetimeFieldOverflow),
+            ("2021-02-29", SqlState::DataExceptionDatetimeFieldOverflow),
+        ] {
+            assert_eq!(parse_date(s).unwrap_err().kind(), sql_state, "{}", s);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_time() -> ApllodbResult<()> {
+        assert_eq!(parse_time("1:02")?, NaiveTime::from_hms(1, 2, 0));
+        assert_eq!(
+            parse_time("23:59:59.123456789")?,
+            NaiveTime::from_hms_nano(23, 59, 59, 123_456_789)
+        );
+        assert_eq!(
+            parse_time("00:00:00.5")?,
+            NaiveTime::from_hms_milli(0, 0, 0, 500)
+        );
+        assert_eq!(
+            parse_time("23:59:60")?,
+            NaiveTime::from_hms_nano(23, 59, 59, 1_000_000_000)
+        );
+
+        for (s, sql_state) in &[
+            ("12", SqlState::DataExceptionInvalidDatetimeFormat),
+            ("12:3", SqlState::DataExceptionInvalidDatetimeFormat),
+            ("12:34.5", SqlState::DataExceptionInvalidDatetimeFormat),
+            ("12:34:56.", SqlState::DataExceptionInvalidDatetimeFormat),
+            ("24:00:00", SqlState::DataExceptionDatetimeFieldOverflow),
+            ("12:60:00", SqlState::DataExceptionDatetimeFieldOverflow),
+        ] {
+            assert_eq!(parse_time(s).unwrap_err().kind(), sql_state, "{}", s);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_timestamp() -> ApllodbResult<()> {
+        assert_eq!(
+            parse_timestamp("2021-01-02")?,
+            NaiveDate::from_ymd(2021, 1, 2).and_hms(0, 0, 0)
+        );
+        assert_eq!(
+            parse_timestamp("2021-01-02T03:04:05.6")?,
+            NaiveDate::from_ymd(2021, 1, 2).and_hms_milli(3, 4, 5, 600)
+        );
+        assert_eq!(
+            parse_timestamp("-0001-01-02 03:04")?,
+            NaiveDate::from_ymd(-1, 1, 2).and_hms(3, 4, 0)
+        );
+        assert_eq!(
+            parse_timestamp("2021-01-02 03:04:05+09")
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionInvalidDatetimeFormat
+        );
+
+        let utc = |s: &str| parse_timestamp(s).unwrap();
+        assert_eq!(
+            parse_timestamp_with_time_zone("2021-01-02 03:04:05")?.naive_utc(),
+            utc("2021-01-02 03:04:05")
+        );
+        assert_eq!(
+            parse_timestamp_with_time_zone("2021-01-02T03:04:05Z")?.naive_utc(),
+            utc("2021-01-02 03:04:05")
+        );
+        assert_eq!(
+            parse_timestamp_with_time_zone("2021-01-02 03:04:05+09")?.naive_utc(),
+            utc("2021-01-01 18:04:05")
+        );
+        assert_eq!(
+            parse_timestamp_with_time_zone("2021-01-02 03:04:05.5 -05:30")?.naive_utc(),
+            utc("2021-01-02 08:34:05.5")
+        );
+        assert_eq!(
+            parse_timestamp_with_time_zone("2021-01-02 03:04:05-0530")?.naive_utc(),
+            utc("2021-01-02 08:34:05")
+        );
+        assert_eq!(
+            parse_timestamp_with_time_zone("2021-01-02 03:04:05+24")
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionInvalidTimeZoneDisplacementValue
+        );
+        assert_eq!(
+            parse_timestamp_with_time_zone("2021-01-02 03:04:05 JST")
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionInvalidDatetimeFormat
+        );
+        Ok(())
+    }
+}
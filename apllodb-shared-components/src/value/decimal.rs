@@ -0,0 +1,607 @@
+use std::{cmp::Ordering, convert::TryFrom, fmt::Display, hash::Hash, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ApllodbError, ApllodbResult};
+
+/// Exact decimal number, used as the value of NUMERIC / DECIMAL.
+///
+/// Represented as `unscaled * 10^(-scale)` (e.g. `1.50` is `150 * 10^(-2)`),
+/// so that the number of fractional digits (scale) is kept as-is.
+/// Up to [MAX_PRECISION](Self::MAX_PRECISION) significant digits are supported.
+///
+/// Two decimals are equal if they represent the same number, even if their scales differ (`1.5 = 1.50`).
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct Decimal {
+    unscaled: i128,
+    scale: u8,
+}
+
+impl Decimal {
+    /// Max number of significant digits.
+    pub const MAX_PRECISION: u8 = 38;
+
+    /// Scale of the quotient is at least this.
+    const MIN_DIVISION_SCALE: u8 = 16;
+
+    /// Constructor.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionNumericValueOutOfRange](crate::SqlState::DataExceptionNumericValueOutOfRange) when:
+    ///   - `unscaled` has more than [MAX_PRECISION](Self::MAX_PRECISION) digits
+    ///   - `scale` is larger than [MAX_PRECISION](Self::MAX_PRECISION)
+    pub fn new(unscaled: i128, scale: u8) -> ApllodbResult<Self> {
+        if scale > Self::MAX_PRECISION
+            || unscaled.unsigned_abs() >= Self::pow10_u(Self::MAX_PRECISION)
+        {
+            Err(ApllodbError::data_exception_numeric_value_out_of_range(
+                format!(
+                    "{}E-{} exceeds the max precision of NUMERIC ({})",
+                    unscaled,
+                    scale,
+                    Self::MAX_PRECISION
+                ),
+            ))
+        } else {
+            Ok(Self { unscaled, scale })
+        }
+    }
+
+    /// Number of fractional digits.
+    pub fn scale(&self) -> u8 {
+        self.scale
+    }
+
+    /// Number of significant digits (0 has 1 digit).
+    pub fn precision(&self) -> u8 {
+        let mut digits = 1;
+        let mut v = self.unscaled.unsigned_abs() / 10;
+        while v > 0 {
+            digits += 1;
+            v /= 10;
+        }
+        digits
+    }
+
+    /// Rounds (half away from zero) or zero-pads fractional digits into `scale` digits.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionNumericValueOutOfRange](crate::SqlState::DataExceptionNumericValueOutOfRange) when:
+    ///   - the result has more than [MAX_PRECISION](Self::MAX_PRECISION) digits
+    pub fn rescale(&self, scale: u8) -> ApllodbResult<Self> {
+        match scale.cmp(&self.scale) {
+            Ordering::Equal => Ok(*self),
+            Ordering::Greater => {
+                let unscaled = Self::pow10(scale - self.scale)
+                    .and_then(|p| self.unscaled.checked_mul(p))
+                    .ok_or_else(|| self.out_of_range())?;
+                Self::new(unscaled, scale)
+            }
+            Ordering::Less => {
+                let unscaled = Self::div_round(
+                    self.unscaled,
+                    Self::pow10(self.scale - scale).expect("scale <= MAX_PRECISION"),
+                );
+                Self::new(unscaled, scale)
+            }
+        }
+    }
+
+    /// Conversion into NUMERIC(`precision`, `scale`): rounded into `scale` fractional digits.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionNumericValueOutOfRange](crate::SqlState::DataExceptionNumericValueOutOfRange) when:
+    ///   - the rounded value has more than `precision` digits
+    pub fn fit(&self, precision: u8, scale: u8) -> ApllodbResult<Self> {
+        let rounded = self.rescale(scale)?;
+        if rounded.unscaled.unsigned_abs() >= Self::pow10_u(precision) {
+            Err(ApllodbError::data_exception_numeric_value_out_of_range(
+                format!("{} does not fit in NUMERIC({}, {})", self, precision, scale),
+            ))
+        } else {
+            Ok(rounded)
+        }
+    }
+
+    /// Rounds (half away from zero) into an integer.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionNumericValueOutOfRange](crate::SqlState::DataExceptionNumericValueOutOfRange) when:
+    ///   - the rounded value does not fit in i64
+    pub fn to_i64(&self) -> ApllodbResult<i64> {
+        let rounded = self.rescale(0)?;
+        i64::try_from(rounded.unscaled).map_err(|_| {
+            ApllodbError::data_exception_numeric_value_out_of_range(format!(
+                "{} is out of range of i64",
+                self
+            ))
+        })
+    }
+
+    /// Nearest f64 value.
+    pub fn to_f64(&self) -> f64 {
+        self.to_string()
+            .parse()
+            .expect("decimal representation is always parsed as f64")
+    }
+
+    /// Exact decimal representation of a finite f64 value
+    /// (shortest one which is parsed back into the same f64).
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionNumericValueOutOfRange](crate::SqlState::DataExceptionNumericValueOutOfRange) when:
+    ///   - `v` is not finite
+    ///   - `v` needs more than [MAX_PRECISION](Self::MAX_PRECISION) digits
+    pub fn from_f64(v: f64) -> ApllodbResult<Self> {
+        if v.is_finite() {
+            format!("{:e}", v).parse()
+        } else {
+            Err(ApllodbError::data_exception_numeric_value_out_of_range(
+                format!("{} cannot be NUMERIC", v),
+            ))
+        }
+    }
+
+    /// `-self`
+    pub fn negate(&self) -> Self {
+        Self {
+            unscaled: -self.unscaled,
+            scale: self.scale,
+        }
+    }
+
+    /// `|self|`
+    pub fn abs(&self) -> Self {
+        Self {
+            unscaled: self.unscaled.abs(),
+            scale: self.scale,
+        }
+    }
+
+    /// Whether this is 0.
+    pub fn is_zero(&self) -> bool {
+        self.unscaled == 0
+    }
+
+    /// `self + other`. The result has the larger scale of the two.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionNumericValueOutOfRange](crate::SqlState::DataExceptionNumericValueOutOfRange) when:
+    ///   - the result has more than [MAX_PRECISION](Self::MAX_PRECISION) digits
+    pub fn add(&self, other: &Self) -> ApllodbResult<Self> {
+        let (l, r, scale) = self.aligned(other)?;
+        let unscaled = l.checked_add(r).ok_or_else(|| self.out_of_range())?;
+        Self::new(unscaled, scale)
+    }
+
+    /// `self - other`. The result has the larger scale of the two.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionNumericValueOutOfRange](crate::SqlState::DataExceptionNumericValueOutOfRange) when:
+    ///   - the result has more than [MAX_PRECISION](Self::MAX_PRECISION) digits
+    pub fn subtract(&self, other: &Self) -> ApllodbResult<Self> {
+        self.add(&other.negate())
+    }
+
+    /// `self * other`. The result has the sum of the two scales.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionNumericValueOutOfRange](crate::SqlState::DataExceptionNumericValueOutOfRange) when:
+    ///   - the result has more than [MAX_PRECISION](Self::MAX_PRECISION) digits
+    pub fn multiply(&self, other: &Self) -> ApllodbResult<Self> {
+        let unscaled = self
+            .unscaled
+            .checked_mul(other.unscaled)
+            .ok_or_else(|| self.out_of_range())?;
+        let scale = self.scale + other.scale;
+        if scale > Self::MAX_PRECISION {
+            Self::new(
+                Self::div_round(
+                    unscaled,
+                    Self::pow10(scale - Self::MAX_PRECISION).expect("scale <= 2 * MAX_PRECISION"),
+                ),
+                Self::MAX_PRECISION,
+            )
+        } else {
+            Self::new(unscaled, scale)
+        }
+    }
+
+    /// `self / other`, rounded (half away from zero).
+    ///
+    /// The quotient is calculated in at least 16 fractional digits (as long as it fits in [MAX_PRECISION](Self::MAX_PRECISION) digits),
+    /// and then trailing zeros beyond the larger scale of the two are removed.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionDivisionByZero](crate::SqlState::DataExceptionDivisionByZero) when:
+    ///   - `other` is 0
+    /// - [DataExceptionNumericValueOutOfRange](crate::SqlState::DataExceptionNumericValueOutOfRange) when:
+    ///   - the result has more than [MAX_PRECISION](Self::MAX_PRECISION) digits
+    pub fn divide(&self, other: &Self) -> ApllodbResult<Self> {
+        if other.is_zero() {
+            return Err(ApllodbError::data_exception_division_by_zero(format!(
+                "{} / {}",
+                self, other
+            )));
+        }
+
+        let min_scale = self.scale.max(other.scale);
+        let max_scale = min_scale.max(Self::MIN_DIVISION_SCALE);
+        // self / other = (self.unscaled * 10^e / other.unscaled) * 10^(-scale)
+        // where e = scale - self.scale + other.scale
+        for scale in (min_scale..=max_scale).rev() {
+            let e = scale + other.scale - self.scale;
+            if let Some(dividend) = Self::pow10(e).and_then(|p| self.unscaled.checked_mul(p)) {
+                let quotient = Self::new(Self::div_round(dividend, other.unscaled), scale)?;
+                return Ok(quotient.trim_trailing_zeros(min_scale));
+            }
+        }
+        Err(self.out_of_range())
+    }
+
+    /// `self % other`. The sign of the result follows `self`.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionDivisionByZero](crate::SqlState::DataExceptionDivisionByZero) when:
+    ///   - `other` is 0
+    /// - [DataExceptionNumericValueOutOfRange](crate::SqlState::DataExceptionNumericValueOutOfRange) when:
+    ///   - the operands cannot be aligned into the same scale
+    pub fn modulo(&self, other: &Self) -> ApllodbResult<Self> {
+        if other.is_zero() {
+            return Err(ApllodbError::data_exception_division_by_zero(format!(
+                "{} % {}",
+                self, other
+            )));
+        }
+        let (l, r, scale) = self.aligned(other)?;
+        Self::new(l % r, scale)
+    }
+
+    /// Unscaled values of `self` and `other` in the same (larger) scale.
+    fn aligned(&self, other: &Self) -> ApllodbResult<(i128, i128, u8)> {
+        let scale = self.scale.max(other.scale);
+        let (l, r) = (self.rescale(scale)?, other.rescale(scale)?);
+        Ok((l.unscaled, r.unscaled, scale))
+    }
+
+    /// Removes trailing fractional zeros while the scale is larger than `min_scale`.
+    fn trim_trailing_zeros(mut self, min_scale: u8) -> Self {
+        while self.scale > min_scale && self.unscaled % 10 == 0 {
+            self.unscaled /= 10;
+            self.scale -= 1;
+        }
+        self
+    }
+
+    /// `dividend / divisor` rounded half away from zero.
+    fn div_round(dividend: i128, divisor: i128) -> i128 {
+        let (q, r) = (dividend / divisor, dividend % divisor);
+        if r.unsigned_abs() >= divisor.unsigned_abs() - r.unsigned_abs() {
+            if (dividend < 0) == (divisor < 0) {
+                q + 1
+            } else {
+                q - 1
+            }
+        } else {
+            q
+        }
+    }
+
+    fn pow10(e: u8) -> Option<i128> {
+        10i128.checked_pow(e as u32)
+    }
+
+    fn pow10_u(e: u8) -> u128 {
+        10u128.pow(e as u32)
+    }
+
+    fn out_of_range(&self) -> ApllodbError {
+        ApllodbError::data_exception_numeric_value_out_of_range(format!(
+            "result of an operation on {} exceeds the max precision of NUMERIC ({})",
+            self,
+            Self::MAX_PRECISION
+        ))
+    }
+}
+
+impl From<i64> for Decimal {
+    fn from(v: i64) -> Self {
+        Self {
+            unscaled: v as i128,
+            scale: 0,
+        }
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = ApllodbError;
+
+    /// Parses `[+-]digits[.digits][e[+-]digits]` (e.g. `-1.50`, `.5`, `1e-3`).
+    /// Leading and trailing whitespaces are ignored.
+    ///
+    /// Fractional digits beyond [MAX_PRECISION](Self::MAX_PRECISION) are rounded.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionInvalidCharacterValueForCast](crate::SqlState::DataExceptionInvalidCharacterValueForCast) when:
+    ///   - `s` is not in the format above
+    /// - [DataExceptionNumericValueOutOfRange](crate::SqlState::DataExceptionNumericValueOutOfRange) when:
+    ///   - `s` has more than [MAX_PRECISION](Self::MAX_PRECISION) significant digits in its integer part
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            ApllodbError::data_exception_invalid_character_value_for_cast(format!(
+                "`{}` is not a decimal number",
+                s
+            ))
+        };
+
+        let trimmed = s.trim();
+        let (negative, unsigned) = match trimmed.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+        };
+        let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+            Some(pos) => {
+                let exponent = unsigned[pos + 1..].parse::<i32>().map_err(|_| invalid())?;
+                (&unsigned[..pos], exponent)
+            }
+            None => (unsigned, 0),
+        };
+        let (int_digits, frac_digits) = match mantissa.find('.') {
+            Some(pos) => (&mantissa[..pos], &mantissa[pos + 1..]),
+            None => (mantissa, ""),
+        };
+        if int_digits.is_empty() && frac_digits.is_empty()
+            || !int_digits
+                .chars()
+                .chain(frac_digits.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        // digits * 10^(-scale)
+        let digits: String = int_digits
+            .chars()
+            .chain(frac_digits.chars())
+            .skip_while(|c| *c == '0')
+            .collect();
+        let scale = frac_digits.len() as i64 - exponent as i64;
+
+        let too_large = || {
+            ApllodbError::data_exception_numeric_value_out_of_range(format!(
+                "`{}` exceeds the max precision of NUMERIC ({})",
+                s,
+                Self::MAX_PRECISION
+            ))
+        };
+        let max_precision = Self::MAX_PRECISION as i64;
+        if digits.is_empty() {
+            return Ok(Self {
+                unscaled: 0,
+                scale: scale.max(0).min(max_precision) as u8,
+            });
+        }
+        if digits.len() as i64 - scale > max_precision {
+            return Err(too_large());
+        }
+
+        // round off digits beyond MAX_PRECISION (both in the number of digits and in scale).
+        let dropped_len = (digits.len() as i64 - max_precision)
+            .max(scale - max_precision)
+            .max(0)
+            .min(digits.len() as i64) as usize;
+        let (kept, dropped) = digits.split_at(digits.len() - dropped_len);
+        let mut scale = scale - dropped_len as i64;
+        let mut unscaled: i128 = if kept.is_empty() {
+            0
+        } else {
+            kept.parse().expect("checked digits")
+        };
+        if dropped.starts_with(|c: char| ('5'..='9').contains(&c)) {
+            unscaled += 1;
+            if unscaled.unsigned_abs() == Self::pow10_u(Self::MAX_PRECISION) {
+                // e.g. 0.99..9 (39 digits) -> 1.00..0 (38 digits)
+                unscaled /= 10;
+                scale -= 1;
+            }
+        }
+        if negative {
+            unscaled = -unscaled;
+        }
+
+        if scale < 0 {
+            // digits.len() - scale <= MAX_PRECISION
+            unscaled *= Self::pow10((-scale) as u8).expect("checked precision");
+            scale = 0;
+        }
+        Self::new(unscaled, scale as u8).map_err(|_| too_large())
+    }
+}
+
+impl Display for Decimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let digits = self.unscaled.unsigned_abs().to_string();
+        let scale = self.scale as usize;
+        let digits = if digits.len() <= scale {
+            format!("{}{}", "0".repeat(scale + 1 - digits.len()), digits)
+        } else {
+            digits
+        };
+        let (int_part, frac_part) = digits.split_at(digits.len() - scale);
+
+        let sign = if self.unscaled < 0 { "-" } else { "" };
+        if frac_part.is_empty() {
+            write!(f, "{}{}", sign, int_part)
+        } else {
+            write!(f, "{}{}.{}", sign, int_part, frac_part)
+        }
+    }
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let scale = self.scale.max(other.scale);
+        match (self.rescale(scale), other.rescale(scale)) {
+            (Ok(l), Ok(r)) => l.unscaled.cmp(&r.unscaled),
+            // a decimal which cannot be rescaled has larger absolute value than the other one.
+            (Err(_), _) => self.unscaled.cmp(&0),
+            (_, Err(_)) => 0.cmp(&other.unscaled),
+        }
+    }
+}
+
+impl Hash for Decimal {
+    /// `1.5` and `1.50` have the same hash value.
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let normalized = self.trim_trailing_zeros(0);
+        normalized.unscaled.hash(state);
+        normalized.scale.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::{ApllodbResult, Decimal, SqlState};
+
+    fn d(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_from_str_and_display() -> ApllodbResult<()> {
+        let test_data = vec![
+            ("0", "0"),
+            ("-0", "0"),
+            ("1.50", "1.50"),
+            ("+1.50", "1.50"),
+            (" -0.05 ", "-0.05"),
+            (".5", "0.5"),
+            ("5.", "5"),
+            ("007.10", "7.10"),
+            ("1e3", "1000"),
+            ("1.5E-3", "0.0015"),
+            ("-12.345e1", "-123.45"),
+            (
+                "12345678901234567890123456789012345678",
+                "12345678901234567890123456789012345678",
+            ),
+            // rounded into MAX_PRECISION
+            (
+                "0.123456789012345678901234567890123456789",
+                "0.12345678901234567890123456789012345679",
+            ),
+        ];
+        for (input, expected) in test_data {
+            assert_eq!(Decimal::from_str(input)?.to_string(), expected, "{}", input);
+        }
+
+        for invalid in &["", ".", "-", "1.2.3", "1e", "abc", "1,000", "e3"] {
+            assert_eq!(
+                Decimal::from_str(invalid).unwrap_err().kind(),
+                &SqlState::DataExceptionInvalidCharacterValueForCast,
+                "{}",
+                invalid
+            );
+        }
+        assert_eq!(
+            Decimal::from_str("123456789012345678901234567890123456789")
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionNumericValueOutOfRange
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(d("1.5"), d("1.50"));
+        assert!(d("1.5") < d("1.51"));
+        assert!(d("-1.5") < d("-1.49"));
+        assert!(d("10") > d("9.999"));
+        assert!(
+            d("12345678901234567890123456789012345678")
+                > d("0.00000000000000000000000000000000000001")
+        );
+    }
+
+    #[test]
+    fn test_rounding() -> ApllodbResult<()> {
+        assert_eq!(d("1.25").rescale(1)?.to_string(), "1.3");
+        assert_eq!(d("-1.25").rescale(1)?.to_string(), "-1.3");
+        assert_eq!(d("1.24").rescale(1)?.to_string(), "1.2");
+        assert_eq!(d("1.2").rescale(3)?.to_string(), "1.200");
+
+        assert_eq!(d("123.456").fit(5, 2)?.to_string(), "123.46");
+        assert_eq!(
+            d("999.995").fit(5, 2).unwrap_err().kind(),
+            &SqlState::DataExceptionNumericValueOutOfRange
+        );
+
+        assert_eq!(d("2.5").to_i64()?, 3);
+        assert_eq!(d("-2.5").to_i64()?, -3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_arithmetic() -> ApllodbResult<()> {
+        assert_eq!(d("1.10").add(&d("2.205"))?.to_string(), "3.305");
+        assert_eq!(d("1.10").subtract(&d("2.2"))?.to_string(), "-1.10");
+        assert_eq!(d("1.10").multiply(&d("-2.5"))?.to_string(), "-2.750");
+        assert_eq!(d("1").divide(&d("3"))?.to_string(), "0.3333333333333333");
+        assert_eq!(d("2").divide(&d("3"))?.to_string(), "0.6666666666666667");
+        assert_eq!(d("6.00").divide(&d("3"))?.to_string(), "2.00");
+        assert_eq!(d("-7.5").modulo(&d("2"))?.to_string(), "-1.5");
+
+        assert_eq!(
+            d("1").divide(&d("0.00")).unwrap_err().kind(),
+            &SqlState::DataExceptionDivisionByZero
+        );
+        assert_eq!(
+            d("99999999999999999999999999999999999999")
+                .add(&d("1"))
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionNumericValueOutOfRange
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_f64() -> ApllodbResult<()> {
+        assert_eq!(Decimal::from_f64(0.1)?.to_string(), "0.1");
+        assert_eq!(Decimal::from_f64(-1.5e10)?.to_string(), "-15000000000");
+        assert_eq!(d("0.1").to_f64(), 0.1);
+        assert_eq!(
+            Decimal::from_f64(f64::INFINITY).unwrap_err().kind(),
+            &SqlState::DataExceptionNumericValueOutOfRange
+        );
+        Ok(())
+    }
+}
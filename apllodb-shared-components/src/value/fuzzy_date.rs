@@ -0,0 +1,429 @@
+use std::{fmt::Display, str::FromStr};
+
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::{ApllodbError, ApllodbResult, SqlCompareResult, SqlState};
+
+use super::datetime::{field_overflow, invalid_format, is_digits, parse_date, DATE_FORMAT};
+
+/// Ambiguous date, used as the value of FUZZY DATE.
+///
+/// Represented as an inclusive range of dates: the actual (unknown) date is one of them.
+/// An exact date is a range of one day.
+///
+/// Text representations are:
+///
+/// - `YYYY-MM-DD`: exact date
+/// - `YYYY-MM`: some day in a month
+/// - `YYYY`: some day in a year
+/// - `YYY0s`: some day in a decade (e.g. `1920s`)
+/// - `partial..partial`: from the first day of the left to the last day of the right (e.g. `1920-05..1921`)
+/// - `circa partial` (or `ca. partial`): widened by one unit of its precision on each side (e.g. `circa 1920s` is `1910s..1930s`)
+///
+/// Values are ordered by the earliest date and then by the latest date.
+/// This order is only for sorting; use [compare()](FuzzyDate::compare) for SQL comparison.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize, Deserialize)]
+pub struct FuzzyDate {
+    earliest: NaiveDate,
+    latest: NaiveDate,
+}
+
+/// Unit of a partial date.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+enum Precision {
+    Decade,
+    Year,
+    Month,
+    Day,
+}
+
+const TYPE_NAME: &str = "FUZZY DATE";
+
+impl FuzzyDate {
+    /// Constructor.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionInvalidDatetimeFormat](crate::SqlState::DataExceptionInvalidDatetimeFormat) when:
+    ///   - `earliest` is after `latest`
+    pub fn new(earliest: NaiveDate, latest: NaiveDate) -> ApllodbResult<Self> {
+        if earliest > latest {
+            Err(ApllodbError::data_exception_invalid_datetime_format(
+                format!(
+                    "range of {} is empty: {} is after {}",
+                    TYPE_NAME, earliest, latest
+                ),
+            ))
+        } else {
+            Ok(Self { earliest, latest })
+        }
+    }
+
+    /// Constructor of an exact date.
+    pub fn exact(date: NaiveDate) -> Self {
+        Self {
+            earliest: date,
+            latest: date,
+        }
+    }
+
+    /// The earliest possible date.
+    pub fn earliest(&self) -> NaiveDate {
+        self.earliest
+    }
+
+    /// The latest possible date.
+    pub fn latest(&self) -> NaiveDate {
+        self.latest
+    }
+
+    /// The date if this value is exact.
+    pub fn as_exact(&self) -> Option<NaiveDate> {
+        if self.earliest == self.latest {
+            Some(self.earliest)
+        } else {
+            None
+        }
+    }
+
+    /// SQL comparison.
+    ///
+    /// Returns [SqlCompareResult::Indefinite](crate::SqlCompareResult::Indefinite) when the ranges overlap,
+    /// unless both are the same exact date.
+    pub fn compare(&self, other: &Self) -> SqlCompareResult {
+        if self.latest < other.earliest {
+            SqlCompareResult::LessThan
+        } else if self.earliest > other.latest {
+            SqlCompareResult::GreaterThan
+        } else if self.as_exact().is_some() && self == other {
+            SqlCompareResult::Eq
+        } else {
+            SqlCompareResult::Indefinite {
+                possibly_less: self.earliest < other.latest,
+                possibly_greater: self.latest > other.earliest,
+            }
+        }
+    }
+
+    /// Parses a partial date into its range.
+    fn parse_partial(s: &str) -> ApllodbResult<(NaiveDate, NaiveDate, Precision)> {
+        let invalid = || invalid_format(s, TYPE_NAME);
+        let overflow = || field_overflow(s, TYPE_NAME);
+
+        if let Some(decade) = s.strip_suffix('s') {
+            return if is_digits(decade, 4, 4) && decade.ends_with('0') {
+                let year: i32 = decade.parse().unwrap();
+                Ok((
+                    NaiveDate::from_ymd(year, 1, 1),
+                    NaiveDate::from_ymd(year + 9, 12, 31),
+                    Precision::Decade,
+                ))
+            } else {
+                Err(invalid())
+            };
+        }
+
+        let fields: Vec<&str> = s.split('-').collect();
+        match fields[..] {
+            [year] if is_digits(year, 4, 4) => {
+                let year: i32 = year.parse().unwrap();
+                Ok((
+                    NaiveDate::from_ymd(year, 1, 1),
+                    NaiveDate::from_ymd(year, 12, 31),
+                    Precision::Year,
+                ))
+            }
+            [year, month] if is_digits(year, 4, 4) && is_digits(month, 1, 2) => {
+                let first =
+                    NaiveDate::from_ymd_opt(year.parse().unwrap(), month.parse().unwrap(), 1)
+                        .ok_or_else(overflow)?;
+                Ok((first, Self::last_day_of_month(first), Precision::Month))
+            }
+            _ => {
+                let date = parse_date(s).map_err(|e| match e.kind() {
+                    SqlState::DataExceptionDatetimeFieldOverflow => overflow(),
+                    _ => invalid(),
+                })?;
+                Ok((date, date, Precision::Day))
+            }
+        }
+    }
+
+    /// Widens a range by one unit of `precision` on each side.
+    fn widen(
+        earliest: NaiveDate,
+        latest: NaiveDate,
+        precision: Precision,
+    ) -> Option<(NaiveDate, NaiveDate)> {
+        match precision {
+            Precision::Decade => Some((
+                NaiveDate::from_ymd_opt(earliest.year() - 10, 1, 1)?,
+                NaiveDate::from_ymd_opt(latest.year() + 10, 12, 31)?,
+            )),
+            Precision::Year => Some((
+                NaiveDate::from_ymd_opt(earliest.year() - 1, 1, 1)?,
+                NaiveDate::from_ymd_opt(latest.year() + 1, 12, 31)?,
+            )),
+            Precision::Month => {
+                let previous = earliest.pred_opt()?;
+                let next = latest.succ_opt()?;
+                Some((
+                    previous.with_day(1)?,
+                    Self::last_day_of_month(next.with_day(1)?),
+                ))
+            }
+            Precision::Day => Some((earliest.pred_opt()?, latest.succ_opt()?)),
+        }
+    }
+
+    fn last_day_of_month(first: NaiveDate) -> NaiveDate {
+        let (year, month) = if first.month() == 12 {
+            (first.year() + 1, 1)
+        } else {
+            (first.year(), first.month() + 1)
+        };
+        NaiveDate::from_ymd_opt(year, month, 1)
+            .map_or_else(|| first.with_day(31).unwrap(), |next| next.pred())
+    }
+
+    /// Whether `earliest` is the first day and `latest` is the last day of units of `precision`,
+    /// and both can be written in partial forms.
+    fn is_aligned(&self, precision: Precision) -> bool {
+        let (e, l) = (self.earliest, self.latest);
+        let writable = (0..=9999).contains(&e.year()) && (0..=9999).contains(&l.year());
+        let year_aligned = e.ordinal() == 1 && l.succ_opt().map(|d| d.ordinal()) == Some(1);
+        match precision {
+            Precision::Decade => {
+                writable && year_aligned && e.year() % 10 == 0 && l.year() % 10 == 9
+            }
+            Precision::Year => writable && year_aligned,
+            Precision::Month => {
+                writable && e.day() == 1 && l.succ_opt().map(|d| d.day()) == Some(1)
+            }
+            Precision::Day => true,
+        }
+    }
+
+    fn format_partial(date: NaiveDate, precision: Precision) -> String {
+        match precision {
+            Precision::Decade => format!("{:04}s", date.year() - date.year() % 10),
+            Precision::Year => format!("{:04}", date.year()),
+            Precision::Month => format!("{:04}-{:02}", date.year(), date.month()),
+            Precision::Day => date.format(DATE_FORMAT).to_string(),
+        }
+    }
+}
+
+/// Parses text representations listed in [FuzzyDate](crate::FuzzyDate).
+impl FromStr for FuzzyDate {
+    type Err = ApllodbError;
+
+    /// # Failures
+    ///
+    /// - [DataExceptionInvalidDatetimeFormat](crate::SqlState::DataExceptionInvalidDatetimeFormat) when:
+    ///   - `s` is not in the formats above
+    ///   - the left of `..` is after the right
+    /// - [DataExceptionDatetimeFieldOverflow](crate::SqlState::DataExceptionDatetimeFieldOverflow) when:
+    ///   - a field is out of range (e.g. month 13)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        let circa = ["circa ", "ca. "].iter().find_map(|prefix| {
+            s.get(..prefix.len())
+                .filter(|head| head.eq_ignore_ascii_case(prefix))
+                .map(|_| s[prefix.len()..].trim_start())
+        });
+        if let Some(partial) = circa {
+            let (earliest, latest, precision) = Self::parse_partial(partial)?;
+            let (earliest, latest) = Self::widen(earliest, latest, precision)
+                .ok_or_else(|| field_overflow(s, TYPE_NAME))?;
+            return Self::new(earliest, latest);
+        }
+
+        match s.split_once("..") {
+            Some((left, right)) => {
+                let (earliest, _, _) = Self::parse_partial(left.trim())?;
+                let (_, latest, _) = Self::parse_partial(right.trim())?;
+                Self::new(earliest, latest)
+            }
+            None => {
+                let (earliest, latest, _) = Self::parse_partial(s)?;
+                Self::new(earliest, latest)
+            }
+        }
+    }
+}
+
+/// Prints the shortest text in the coarsest precision both ends are aligned to
+/// (e.g. `1920s`, `1919-12..1920-02`, `2021-01-01..2021-01-15`), which is parsed back into the same value.
+impl Display for FuzzyDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = [
+            Precision::Decade,
+            Precision::Year,
+            Precision::Month,
+            Precision::Day,
+        ]
+        .iter()
+        .copied()
+        .find(|precision| self.is_aligned(*precision))
+        .unwrap_or(Precision::Day);
+
+        let earliest = Self::format_partial(self.earliest, precision);
+        let latest = Self::format_partial(self.latest, precision);
+        if earliest == latest {
+            write!(f, "{}", earliest)
+        } else {
+            write!(f, "{}..{}", earliest, latest)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::FuzzyDate;
+    use crate::{ApllodbResult, SqlCompareResult, SqlState};
+
+    fn fd(s: &str) -> FuzzyDate {
+        s.parse().unwrap()
+    }
+
+    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd(y, m, d)
+    }
+
+    #[test]
+    fn test_from_str_and_display() -> ApllodbResult<()> {
+        for (input, earliest, latest, display) in &[
+            (
+                "1923-05-17",
+                ymd(1923, 5, 17),
+                ymd(1923, 5, 17),
+                "1923-05-17",
+            ),
+            ("1923-5", ymd(1923, 5, 1), ymd(1923, 5, 31), "1923-05"),
+            ("1924-02", ymd(1924, 2, 1), ymd(1924, 2, 29), "1924-02"),
+            ("1923", ymd(1923, 1, 1), ymd(1923, 12, 31), "1923"),
+            ("1920s", ymd(1920, 1, 1), ymd(1929, 12, 31), "1920s"),
+            (
+                "circa 1920s",
+                ymd(1910, 1, 1),
+                ymd(1939, 12, 31),
+                "1910s..1930s",
+            ),
+            ("CA. 1923", ymd(1922, 1, 1), ymd(1924, 12, 31), "1922..1924"),
+            (
+                "circa 1920-01",
+                ymd(1919, 12, 1),
+                ymd(1920, 2, 29),
+                "1919-12..1920-02",
+            ),
+            (
+                "circa 2021-01-01",
+                ymd(2020, 12, 31),
+                ymd(2021, 1, 2),
+                "2020-12-31..2021-01-02",
+            ),
+            (
+                "1920-05..1921",
+                ymd(1920, 5, 1),
+                ymd(1921, 12, 31),
+                "1920-05..1921-12",
+            ),
+            ("1920 .. 1929", ymd(1920, 1, 1), ymd(1929, 12, 31), "1920s"),
+            (
+                "1923-05-17..1923-05-17",
+                ymd(1923, 5, 17),
+                ymd(1923, 5, 17),
+                "1923-05-17",
+            ),
+            (
+                "-0044-03-15",
+                ymd(-44, 3, 15),
+                ymd(-44, 3, 15),
+                "-0044-03-15",
+            ),
+        ] {
+            let fuzzy_date: FuzzyDate = input.parse()?;
+            assert_eq!(
+                (fuzzy_date.earliest(), fuzzy_date.latest()),
+                (*earliest, *latest),
+                "{}",
+                input
+            );
+            assert_eq!(fuzzy_date.to_string(), *display, "{}", input);
+            assert_eq!(display.parse::<FuzzyDate>()?, fuzzy_date, "{}", display);
+        }
+
+        for (input, sql_state) in &[
+            ("", SqlState::DataExceptionInvalidDatetimeFormat),
+            ("1920's", SqlState::DataExceptionInvalidDatetimeFormat),
+            ("1925s", SqlState::DataExceptionInvalidDatetimeFormat),
+            ("192", SqlState::DataExceptionInvalidDatetimeFormat),
+            ("about 1920", SqlState::DataExceptionInvalidDatetimeFormat),
+            ("1930..1920", SqlState::DataExceptionInvalidDatetimeFormat),
+            ("1920-13", SqlState::DataExceptionDatetimeFieldOverflow),
+            ("1921-02-29", SqlState::DataExceptionDatetimeFieldOverflow),
+        ] {
+            assert_eq!(
+                input.parse::<FuzzyDate>().unwrap_err().kind(),
+                sql_state,
+                "{}",
+                input
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_compare() {
+        for (left, right, expected) in &[
+            ("1920s", "1930", SqlCompareResult::LessThan),
+            ("1931", "1920s", SqlCompareResult::GreaterThan),
+            ("1923-05-17", "1923-05-17", SqlCompareResult::Eq),
+            (
+                "1920s",
+                "1923",
+                SqlCompareResult::Indefinite {
+                    possibly_less: true,
+                    possibly_greater: true,
+                },
+            ),
+            (
+                "1920s",
+                "1920s",
+                SqlCompareResult::Indefinite {
+                    possibly_less: true,
+                    possibly_greater: true,
+                },
+            ),
+            (
+                "1920s",
+                "1929-12-31",
+                SqlCompareResult::Indefinite {
+                    possibly_less: true,
+                    possibly_greater: false,
+                },
+            ),
+            (
+                "1920s",
+                "1920-01-01",
+                SqlCompareResult::Indefinite {
+                    possibly_less: false,
+                    possibly_greater: true,
+                },
+            ),
+        ] {
+            assert_eq!(
+                fd(left).compare(&fd(right)),
+                *expected,
+                "{} vs {}",
+                left,
+                right
+            );
+        }
+    }
+}
@@ -0,0 +1,566 @@
+use std::{cmp::Ordering, convert::TryFrom, fmt::Display, hash::Hash, str::FromStr};
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime};
+use serde::{Deserialize, Serialize};
+
+use crate::{ApllodbError, ApllodbResult};
+
+/// Time span, used as the value of INTERVAL.
+///
+/// Represented as months, days and nanoseconds (e.g. `1 year 2 mons 3 days 04:05:06`),
+/// since the lengths of a month and a day vary in calendars:
+/// `TIMESTAMP '2021-01-31' + INTERVAL '1 mon'` is `2021-02-28` and `TIMESTAMP '2021-03-31' + INTERVAL '1 mon'` is `2021-04-30`.
+///
+/// In comparison, a month is regarded as 30 days and a day as 24 hours (`INTERVAL '1 mon' = INTERVAL '30 days'`).
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct Interval {
+    months: i32,
+    days: i32,
+    nanos: i64,
+}
+
+const NANOS_PER_MICRO: i64 = 1_000;
+const NANOS_PER_MILLI: i64 = 1_000_000;
+const NANOS_PER_SEC: i64 = 1_000_000_000;
+const NANOS_PER_MIN: i64 = 60 * NANOS_PER_SEC;
+const NANOS_PER_HOUR: i64 = 60 * NANOS_PER_MIN;
+const NANOS_PER_DAY: i64 = 24 * NANOS_PER_HOUR;
+const DAYS_PER_MONTH: i32 = 30;
+
+impl Interval {
+    /// Constructor.
+    pub fn new(months: i32, days: i32, nanos: i64) -> Self {
+        Self {
+            months,
+            days,
+            nanos,
+        }
+    }
+
+    /// Months part.
+    pub fn months(&self) -> i32 {
+        self.months
+    }
+
+    /// Days part.
+    pub fn days(&self) -> i32 {
+        self.days
+    }
+
+    /// Time part in nanoseconds.
+    pub fn nanos(&self) -> i64 {
+        self.nanos
+    }
+
+    /// `end - start`, in days and time (less than 24 hours).
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionDatetimeFieldOverflow](crate::SqlState::DataExceptionDatetimeFieldOverflow) when:
+    ///   - the result does not fit in INTERVAL
+    pub fn between(end: NaiveDateTime, start: NaiveDateTime) -> ApllodbResult<Self> {
+        let duration = end.signed_duration_since(start);
+        let days = duration.num_days();
+        let nanos = (duration - Duration::days(days))
+            .num_nanoseconds()
+            .expect("less than a day");
+        let days = i32::try_from(days).map_err(|_| {
+            ApllodbError::data_exception_datetime_field_overflow(format!(
+                "`{} - {}` is out of range of INTERVAL",
+                end, start
+            ))
+        })?;
+        Ok(Self::new(0, days, nanos))
+    }
+
+    /// `-self`
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionDatetimeFieldOverflow](crate::SqlState::DataExceptionDatetimeFieldOverflow) when:
+    ///   - the result does not fit in INTERVAL
+    pub fn negate(&self) -> ApllodbResult<Self> {
+        match (
+            self.months.checked_neg(),
+            self.days.checked_neg(),
+            self.nanos.checked_neg(),
+        ) {
+            (Some(months), Some(days), Some(nanos)) => Ok(Self::new(months, days, nanos)),
+            _ => Err(self.out_of_range("negate")),
+        }
+    }
+
+    /// `self + other`
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionDatetimeFieldOverflow](crate::SqlState::DataExceptionDatetimeFieldOverflow) when:
+    ///   - the result does not fit in INTERVAL
+    pub fn add(&self, other: &Self) -> ApllodbResult<Self> {
+        match (
+            self.months.checked_add(other.months),
+            self.days.checked_add(other.days),
+            self.nanos.checked_add(other.nanos),
+        ) {
+            (Some(months), Some(days), Some(nanos)) => Ok(Self::new(months, days, nanos)),
+            _ => Err(self.out_of_range(&format!("add {}", other))),
+        }
+    }
+
+    /// `self - other`
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionDatetimeFieldOverflow](crate::SqlState::DataExceptionDatetimeFieldOverflow) when:
+    ///   - the result does not fit in INTERVAL
+    pub fn subtract(&self, other: &Self) -> ApllodbResult<Self> {
+        self.add(&other.negate()?)
+    }
+
+    /// `self * factor`
+    ///
+    /// Fractional months are carried down into days (a month as 30 days), and fractional days into time (a day as 24 hours)
+    /// (e.g. `INTERVAL '1 mon' * 1.5` is `1 mon 15 days`).
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionDatetimeFieldOverflow](crate::SqlState::DataExceptionDatetimeFieldOverflow) when:
+    ///   - the result does not fit in INTERVAL
+    pub fn multiply(&self, factor: f64) -> ApllodbResult<Self> {
+        self.scale(|v| v * factor)
+            .ok_or_else(|| self.out_of_range(&format!("multiply by {}", factor)))
+    }
+
+    /// `self / divisor`
+    ///
+    /// Fractional parts are carried down in the same way as [multiply()](Self::multiply).
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionDivisionByZero](crate::SqlState::DataExceptionDivisionByZero) when:
+    ///   - `divisor` is 0
+    /// - [DataExceptionDatetimeFieldOverflow](crate::SqlState::DataExceptionDatetimeFieldOverflow) when:
+    ///   - the result does not fit in INTERVAL
+    pub fn divide(&self, divisor: f64) -> ApllodbResult<Self> {
+        if divisor == 0.0 {
+            return Err(ApllodbError::data_exception_division_by_zero(format!(
+                "`{} / {}`",
+                self, divisor
+            )));
+        }
+        self.scale(|v| v / divisor)
+            .ok_or_else(|| self.out_of_range(&format!("divide by {}", divisor)))
+    }
+
+    /// `timestamp + self`
+    ///
+    /// Months are added first, with the day clamped to the end of the month, and then days and time.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionDatetimeFieldOverflow](crate::SqlState::DataExceptionDatetimeFieldOverflow) when:
+    ///   - the result is out of range of TIMESTAMP
+    pub fn add_to_timestamp(&self, timestamp: NaiveDateTime) -> ApllodbResult<NaiveDateTime> {
+        let out_of_range = || {
+            ApllodbError::data_exception_datetime_field_overflow(format!(
+                "`{} + {}` is out of range of TIMESTAMP",
+                timestamp, self
+            ))
+        };
+
+        let date = timestamp.date();
+        let month0 = date.year() as i64 * 12 + date.month0() as i64 + self.months as i64;
+        let (year, month) = (month0.div_euclid(12), month0.rem_euclid(12) as u32 + 1);
+        let year = i32::try_from(year).map_err(|_| out_of_range())?;
+        let day = date.day().min(Self::days_in_month(year, month));
+        let date = NaiveDate::from_ymd_opt(year, month, day).ok_or_else(out_of_range)?;
+
+        date.and_time(timestamp.time())
+            .checked_add_signed(Duration::days(self.days as i64))
+            .and_then(|t| t.checked_add_signed(Duration::nanoseconds(self.nanos)))
+            .ok_or_else(out_of_range)
+    }
+
+    /// `time + self`, wrapping around midnight.
+    ///
+    /// Months and days are ignored.
+    pub fn add_to_time(&self, time: NaiveTime) -> NaiveTime {
+        time.overflowing_add_signed(Duration::nanoseconds(self.nanos))
+            .0
+    }
+
+    /// Applies `f` to each part, carrying fractional parts down. `None` if out of range.
+    fn scale(&self, f: impl Fn(f64) -> f64) -> Option<Self> {
+        let months = f(self.months as f64);
+        let days = f(self.days as f64) + months.fract() * DAYS_PER_MONTH as f64;
+        let nanos = f(self.nanos as f64) + days.fract() * NANOS_PER_DAY as f64;
+
+        let (months, days, nanos) = (months.trunc(), days.trunc(), nanos.round());
+        // `as` saturates at MIN / MAX (and NaN into 0)
+        let in_range = |v: f64, min: f64, max: f64| v.is_finite() && v >= min && v <= max;
+        if in_range(months, i32::MIN as f64, i32::MAX as f64)
+            && in_range(days, i32::MIN as f64, i32::MAX as f64)
+            && in_range(nanos, i64::MIN as f64, i64::MAX as f64)
+        {
+            Some(Self::new(months as i32, days as i32, nanos as i64))
+        } else {
+            None
+        }
+    }
+
+    fn days_in_month(year: i32, month: u32) -> u32 {
+        let (next_year, next_month) = if month == 12 {
+            (year + 1, 1)
+        } else {
+            (year, month + 1)
+        };
+        NaiveDate::from_ymd_opt(next_year, next_month, 1)
+            .and_then(|d| d.pred_opt())
+            .map_or(31, |d| d.day())
+    }
+
+    /// Comparable length, in which a month is 30 days and a day is 24 hours.
+    fn total_nanos(&self) -> i128 {
+        (self.months as i128 * DAYS_PER_MONTH as i128 + self.days as i128) * NANOS_PER_DAY as i128
+            + self.nanos as i128
+    }
+
+    fn out_of_range(&self, operation: &str) -> ApllodbError {
+        ApllodbError::data_exception_datetime_field_overflow(format!(
+            "{} ({}) is out of range of INTERVAL",
+            self, operation
+        ))
+    }
+}
+
+/// Parses PostgreSQL-style text: any combination of `quantity unit` (e.g. `1 year 2 months -3 days`)
+/// and `[+-]HH:MM[:SS[.fraction]]`.
+///
+/// Units are `year`, `month` (`mon`), `week`, `day`, `hour`, `minute` (`min`), `second` (`sec`), `millisecond` and `microsecond`,
+/// optionally in plural forms (e.g. `years`, `mons`), and case-insensitive.
+/// Quantities of hours and smaller units may have fractional digits.
+impl FromStr for Interval {
+    type Err = ApllodbError;
+
+    /// # Failures
+    ///
+    /// - [DataExceptionInvalidDatetimeFormat](crate::SqlState::DataExceptionInvalidDatetimeFormat) when:
+    ///   - `s` is not in the format above
+    /// - [DataExceptionDatetimeFieldOverflow](crate::SqlState::DataExceptionDatetimeFieldOverflow) when:
+    ///   - the value does not fit in INTERVAL
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            ApllodbError::data_exception_invalid_datetime_format(format!(
+                "invalid input syntax for INTERVAL: `{}`",
+                s
+            ))
+        };
+        let out_of_range = || {
+            ApllodbError::data_exception_datetime_field_overflow(format!(
+                "`{}` is out of range of INTERVAL",
+                s
+            ))
+        };
+
+        let (mut months, mut days, mut nanos): (i64, i64, i128) = (0, 0, 0);
+        let mut tokens = s.split_whitespace().peekable();
+        if tokens.peek().is_none() {
+            return Err(invalid());
+        }
+        while let Some(token) = tokens.next() {
+            if token.contains(':') {
+                nanos += Self::parse_time_token(token).ok_or_else(invalid)?;
+                continue;
+            }
+
+            let unit = tokens.next().ok_or_else(invalid)?.to_ascii_lowercase();
+            let unit = unit.strip_suffix('s').unwrap_or(&unit);
+            let (unit_months, unit_days, unit_nanos) = match unit {
+                "year" => (12, 0, 0),
+                "mon" | "month" => (1, 0, 0),
+                "week" => (0, 7, 0),
+                "day" => (0, 1, 0),
+                "hour" => (0, 0, NANOS_PER_HOUR),
+                "min" | "minute" => (0, 0, NANOS_PER_MIN),
+                "sec" | "second" => (0, 0, NANOS_PER_SEC),
+                "millisecond" => (0, 0, NANOS_PER_MILLI),
+                "microsecond" => (0, 0, NANOS_PER_MICRO),
+                _ => return Err(invalid()),
+            };
+            if unit_nanos == 0 {
+                let quantity: i64 = token.parse().map_err(|_| invalid())?;
+                months += quantity.checked_mul(unit_months).ok_or_else(out_of_range)?;
+                days += quantity.checked_mul(unit_days).ok_or_else(out_of_range)?;
+            } else {
+                nanos += Self::parse_quantity(token, unit_nanos).ok_or_else(invalid)?;
+            }
+        }
+
+        match (
+            i32::try_from(months),
+            i32::try_from(days),
+            i64::try_from(nanos),
+        ) {
+            (Ok(months), Ok(days), Ok(nanos)) => Ok(Self::new(months, days, nanos)),
+            _ => Err(out_of_range()),
+        }
+    }
+}
+
+impl Interval {
+    /// `[+-]digits[.digits]` multiplied by `unit_nanos`, truncated into nanoseconds.
+    fn parse_quantity(s: &str, unit_nanos: i64) -> Option<i128> {
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (int_part, frac_part) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        if int_part.is_empty() && frac_part.is_empty()
+            || !int_part
+                .chars()
+                .chain(frac_part.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return None;
+        }
+
+        let mut v: i128 = 0;
+        if !int_part.is_empty() {
+            v = int_part
+                .parse::<i128>()
+                .ok()?
+                .checked_mul(unit_nanos as i128)?;
+        }
+        let mut place = unit_nanos as i128;
+        for digit in frac_part.chars() {
+            place /= 10;
+            v += digit.to_digit(10).unwrap() as i128 * place;
+        }
+        Some(if negative { -v } else { v })
+    }
+
+    /// `[+-]HH:MM[:SS[.fraction]]` in nanoseconds.
+    fn parse_time_token(s: &str) -> Option<i128> {
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let fields: Vec<&str> = unsigned.split(':').collect();
+        let (hours, mins, secs) = match fields[..] {
+            [hours, mins] => (hours, mins, "0"),
+            [hours, mins, secs] => (hours, mins, secs),
+            _ => return None,
+        };
+        let is_digits = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+        if !(is_digits(hours) && is_digits(mins)) || secs.starts_with(['+', '-']) {
+            return None;
+        }
+
+        let v = Self::parse_quantity(hours, NANOS_PER_HOUR)?
+            + Self::parse_quantity(mins, NANOS_PER_MIN)?
+            + Self::parse_quantity(secs, NANOS_PER_SEC)?;
+        Some(if negative { -v } else { v })
+    }
+}
+
+/// PostgreSQL-style text (e.g. `1 year 2 mons -3 days 04:05:06.789`), which is parsed back into the same value.
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts: Vec<String> = vec![];
+        let mut push_part = |quantity: i64, unit: &str| {
+            if quantity != 0 {
+                let plural = if quantity == 1 { "" } else { "s" };
+                parts.push(format!("{} {}{}", quantity, unit, plural));
+            }
+        };
+        push_part(self.months as i64 / 12, "year");
+        push_part(self.months as i64 % 12, "mon");
+        push_part(self.days as i64, "day");
+
+        if self.nanos != 0 || parts.is_empty() {
+            let sign = if self.nanos < 0 { "-" } else { "" };
+            let nanos = self.nanos.unsigned_abs();
+            let (hours, nanos) = (nanos / NANOS_PER_HOUR as u64, nanos % NANOS_PER_HOUR as u64);
+            let (mins, nanos) = (nanos / NANOS_PER_MIN as u64, nanos % NANOS_PER_MIN as u64);
+            let (secs, nanos) = (nanos / NANOS_PER_SEC as u64, nanos % NANOS_PER_SEC as u64);
+            let fraction = if nanos == 0 {
+                String::new()
+            } else {
+                format!(".{:09}", nanos).trim_end_matches('0').to_string()
+            };
+            parts.push(format!(
+                "{}{:02}:{:02}:{:02}{}",
+                sign, hours, mins, secs, fraction
+            ));
+        }
+
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+impl PartialEq for Interval {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Interval {}
+
+impl PartialOrd for Interval {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Interval {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total_nanos().cmp(&other.total_nanos())
+    }
+}
+
+impl Hash for Interval {
+    /// Equal intervals (e.g. `1 mon` and `30 days`) have the same hash value.
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.total_nanos().hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::{Interval, NANOS_PER_HOUR, NANOS_PER_SEC};
+    use crate::{ApllodbResult, SqlState};
+
+    fn i(s: &str) -> Interval {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_from_str_and_display() -> ApllodbResult<()> {
+        for (input, months, days, nanos, display) in &[
+            ("1 day", 0, 1, 0, "1 day"),
+            (
+                "1 year 2 mons 3 days 04:05:06.789",
+                14,
+                3,
+                4 * NANOS_PER_HOUR + 5 * 60 * NANOS_PER_SEC + 6_789_000_000,
+                "1 year 2 mons 3 days 04:05:06.789",
+            ),
+            ("2 Weeks -1 DAY", 0, 13, 0, "13 days"),
+            ("-1 months", -1, 0, 0, "-1 mons"),
+            ("1.5 hours", 0, 0, 3 * NANOS_PER_HOUR / 2, "01:30:00"),
+            ("-00:00:01.5", 0, 0, -NANOS_PER_SEC * 3 / 2, "-00:00:01.5"),
+            ("25:00", 0, 0, 25 * NANOS_PER_HOUR, "25:00:00"),
+            (
+                "1 millisecond 1 microsecond",
+                0,
+                0,
+                1_001_000,
+                "00:00:00.001001",
+            ),
+            ("0 sec", 0, 0, 0, "00:00:00"),
+        ] {
+            let interval: Interval = input.parse()?;
+            assert_eq!(
+                (interval.months(), interval.days(), interval.nanos()),
+                (*months, *days, *nanos),
+                "{}",
+                input
+            );
+            assert_eq!(interval.to_string(), *display);
+
+            let reparsed: Interval = interval.to_string().parse()?;
+            assert_eq!(
+                (reparsed.months(), reparsed.days(), reparsed.nanos()),
+                (*months, *days, *nanos)
+            );
+        }
+
+        for (input, sql_state) in &[
+            ("", SqlState::DataExceptionInvalidDatetimeFormat),
+            ("1", SqlState::DataExceptionInvalidDatetimeFormat),
+            ("1 fortnight", SqlState::DataExceptionInvalidDatetimeFormat),
+            ("1.5 days", SqlState::DataExceptionInvalidDatetimeFormat),
+            ("1:2:3:4", SqlState::DataExceptionInvalidDatetimeFormat),
+            (
+                "3000000000 days",
+                SqlState::DataExceptionDatetimeFieldOverflow,
+            ),
+        ] {
+            assert_eq!(
+                input.parse::<Interval>().unwrap_err().kind(),
+                sql_state,
+                "{}",
+                input
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(i("1 mon"), i("30 days"));
+        assert_eq!(i("1 day"), i("24 hours"));
+        assert!(i("1 mon 1 sec") > i("30 days"));
+        assert!(i("-1 day") < i("00:00:00"));
+    }
+
+    #[test]
+    fn test_arithmetic() -> ApllodbResult<()> {
+        assert_eq!(i("1 day").add(&i("2 hours"))?.to_string(), "1 day 02:00:00");
+        assert_eq!(
+            i("1 day").subtract(&i("1 mon"))?.to_string(),
+            "-1 mons 1 day"
+        );
+        assert_eq!(i("1 mon").multiply(1.5)?.to_string(), "1 mon 15 days");
+        assert_eq!(i("1 day").divide(4.0)?.to_string(), "06:00:00");
+        assert_eq!(
+            i("1 day").divide(0.0).unwrap_err().kind(),
+            &SqlState::DataExceptionDivisionByZero
+        );
+        assert_eq!(
+            i("1000000000 mons").multiply(10.0).unwrap_err().kind(),
+            &SqlState::DataExceptionDatetimeFieldOverflow
+        );
+
+        let ts = |y, m, d| NaiveDate::from_ymd(y, m, d).and_hms(12, 0, 0);
+        assert_eq!(
+            i("1 mon").add_to_timestamp(ts(2021, 1, 31))?,
+            ts(2021, 2, 28)
+        );
+        assert_eq!(
+            i("1 mon").add_to_timestamp(ts(2020, 1, 31))?,
+            ts(2020, 2, 29)
+        );
+        assert_eq!(
+            i("-13 mons").add_to_timestamp(ts(2021, 3, 31))?,
+            ts(2020, 2, 29)
+        );
+        assert_eq!(
+            i("1 day -12:00").add_to_timestamp(ts(2021, 12, 31))?,
+            NaiveDate::from_ymd(2022, 1, 1).and_hms(0, 0, 0)
+        );
+        assert_eq!(
+            i("1000000 years")
+                .add_to_timestamp(ts(2021, 1, 1))
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionDatetimeFieldOverflow
+        );
+
+        assert_eq!(
+            Interval::between(
+                ts(2021, 1, 2),
+                NaiveDate::from_ymd(2020, 12, 30).and_hms(10, 0, 0)
+            )?
+            .to_string(),
+            "3 days 02:00:00"
+        );
+        assert_eq!(
+            Interval::between(ts(2021, 1, 1), ts(2021, 1, 2))?.to_string(),
+            "-1 days"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,261 @@
+use std::{convert::TryFrom, fmt::Display, hash::Hash, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::{ApllodbError, ApllodbResult};
+
+/// JSON document, used as the value of JSON.
+///
+/// Text is validated on parse and printed in the compact form with object members sorted by key
+/// (e.g. `{ "b": [1, 2], "a": null }` is printed as `{"a":null,"b":[1,2]}`).
+///
+/// Two documents are equal if they are structurally the same (object members in any order).
+/// Documents are not ordered.
+///
+/// Paths for [extract()](Json::extract) start with `$` followed by any number of:
+///
+/// - `.key` or `."key"`: member of an object
+/// - `[index]`: element of an array (0-origin)
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Json(Value);
+
+impl Json {
+    /// Member of an object. None if this is not an object or does not have `key`.
+    pub fn member(&self, key: &str) -> Option<Json> {
+        self.0.get(key).cloned().map(Self)
+    }
+
+    /// Element of an array. A negative `index` counts from the end (e.g. `-1` for the last element).
+    /// None if this is not an array or `index` is out of range.
+    pub fn element(&self, index: i64) -> Option<Json> {
+        let array = self.0.as_array()?;
+        let index = if index < 0 {
+            i64::try_from(array.len()).ok()? + index
+        } else {
+            index
+        };
+        let index = usize::try_from(index).ok()?;
+        array.get(index).cloned().map(Self)
+    }
+
+    /// Document at `path` (see [Json](crate::Json) for its syntax). None if no document is there.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionInvalidParameterValue](crate::SqlState::DataExceptionInvalidParameterValue) when:
+    ///   - `path` is not in the syntax
+    pub fn extract(&self, path: &str) -> ApllodbResult<Option<Json>> {
+        let mut current = Some(self.clone());
+        for step in Self::parse_path(path)? {
+            current = match (current, step) {
+                (Some(json), PathStep::Member(key)) => json.member(&key),
+                (Some(json), PathStep::Element(index)) => json.element(index),
+                (None, _) => None,
+            };
+        }
+        Ok(current)
+    }
+
+    /// Number of elements if this is an array.
+    pub fn array_length(&self) -> Option<usize> {
+        self.0.as_array().map(|array| array.len())
+    }
+
+    /// Text of this document, where a string is unquoted and `null` is None
+    /// (e.g. `"abc"` is `abc` and `[1,2]` is `[1,2]`).
+    pub fn to_text(&self) -> Option<String> {
+        match &self.0 {
+            Value::Null => None,
+            Value::String(s) => Some(s.clone()),
+            _ => Some(self.to_string()),
+        }
+    }
+
+    fn parse_path(path: &str) -> ApllodbResult<Vec<PathStep>> {
+        let invalid = |reason: &str| {
+            ApllodbError::data_exception_invalid_parameter_value(format!(
+                "invalid JSON path `{}`: {}",
+                path, reason
+            ))
+        };
+
+        let mut rest = path
+            .trim()
+            .strip_prefix('$')
+            .ok_or_else(|| invalid("must start with `$`"))?;
+        let mut steps = Vec::new();
+        while !rest.is_empty() {
+            if let Some(after_dot) = rest.strip_prefix('.') {
+                let (key, tail) = if let Some(quoted) = after_dot.strip_prefix('"') {
+                    let end = quoted
+                        .find('"')
+                        .ok_or_else(|| invalid("unterminated quoted key"))?;
+                    (&quoted[..end], &quoted[end + 1..])
+                } else {
+                    let end = after_dot.find(&['.', '['][..]).unwrap_or(after_dot.len());
+                    (&after_dot[..end], &after_dot[end..])
+                };
+                if key.is_empty() {
+                    return Err(invalid("empty key"));
+                }
+                steps.push(PathStep::Member(key.to_string()));
+                rest = tail;
+            } else if let Some(after_bracket) = rest.strip_prefix('[') {
+                let end = after_bracket
+                    .find(']')
+                    .ok_or_else(|| invalid("unterminated `[`"))?;
+                let index = after_bracket[..end]
+                    .trim()
+                    .parse::<i64>()
+                    .ok()
+                    .filter(|index| *index >= 0)
+                    .ok_or_else(|| invalid("array index must be a non-negative integer"))?;
+                steps.push(PathStep::Element(index));
+                rest = &after_bracket[end + 1..];
+            } else {
+                return Err(invalid("expected `.` or `[`"));
+            }
+        }
+        Ok(steps)
+    }
+}
+
+/// Step of a path.
+#[derive(Clone, Eq, PartialEq, Debug)]
+enum PathStep {
+    Member(String),
+    Element(i64),
+}
+
+impl FromStr for Json {
+    type Err = ApllodbError;
+
+    /// # Failures
+    ///
+    /// - [DataExceptionInvalidJsonText](crate::SqlState::DataExceptionInvalidJsonText) when:
+    ///   - `s` is not a JSON text
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s).map(Self).map_err(|e| {
+            ApllodbError::data_exception_invalid_json_text(format!(
+                "`{}` is not a JSON text: {}",
+                s, e
+            ))
+        })
+    }
+}
+
+impl Display for Json {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Hash for Json {
+    /// Equal documents have the same text since object members are sorted by key.
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.to_string().hash(state)
+    }
+}
+
+/// Serialized as text.
+impl Serialize for Json {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Json {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::Json;
+    use crate::{ApllodbResult, SqlState};
+
+    fn j(s: &str) -> Json {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_from_str_and_display() -> ApllodbResult<()> {
+        for (input, display) in &[
+            (r#"{ "b": [1, 2], "a": null }"#, r#"{"a":null,"b":[1,2]}"#),
+            (r#" "abc" "#, r#""abc""#),
+            ("-1.5e3", "-1500.0"),
+            ("true", "true"),
+            ("[]", "[]"),
+        ] {
+            assert_eq!(input.parse::<Json>()?.to_string(), *display, "{}", input);
+        }
+
+        for input in &["", "{", "{'a': 1}", "[1,]", "abc", "1 2"] {
+            assert_eq!(
+                input.parse::<Json>().unwrap_err().kind(),
+                &SqlState::DataExceptionInvalidJsonText,
+                "{}",
+                input
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_member_and_element() {
+        let doc = j(r#"{"a": {"b": [10, 20, 30]}, "c": "x"}"#);
+
+        assert_eq!(doc.member("c"), Some(j(r#""x""#)));
+        assert_eq!(doc.member("z"), None);
+        assert_eq!(doc.element(0), None);
+
+        let array = doc.member("a").unwrap().member("b").unwrap();
+        assert_eq!(array.element(0), Some(j("10")));
+        assert_eq!(array.element(-1), Some(j("30")));
+        assert_eq!(array.element(3), None);
+        assert_eq!(array.element(-4), None);
+        assert_eq!(array.array_length(), Some(3));
+        assert_eq!(doc.array_length(), None);
+    }
+
+    #[test]
+    fn test_extract() -> ApllodbResult<()> {
+        let doc = j(r#"{"a": {"b": [10, {"c d": true}]}, "e": null}"#);
+
+        assert_eq!(doc.extract("$")?, Some(doc.clone()));
+        assert_eq!(doc.extract("$.a.b[0]")?, Some(j("10")));
+        assert_eq!(doc.extract(r#"$.a.b[1]."c d""#)?, Some(j("true")));
+        assert_eq!(doc.extract("$.e")?, Some(j("null")));
+        assert_eq!(doc.extract("$.a.x.y")?, None);
+        assert_eq!(doc.extract("$.a.b[2]")?, None);
+
+        for path in &["", "a.b", "$.", "$a", "$[-1]", "$[x]", "$[0", r#"$."a"#] {
+            assert_eq!(
+                doc.extract(path).unwrap_err().kind(),
+                &SqlState::DataExceptionInvalidParameterValue,
+                "{}",
+                path
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_text() {
+        assert_eq!(j(r#""abc""#).to_text(), Some("abc".to_string()));
+        assert_eq!(j("[1, 2]").to_text(), Some("[1,2]".to_string()));
+        assert_eq!(j("1").to_text(), Some("1".to_string()));
+        assert_eq!(j("null").to_text(), None);
+    }
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(j(r#"{"a": 1, "b": 2}"#), j(r#"{"b":2,"a":1}"#));
+        assert_ne!(j("[1, 2]"), j("[2, 1]"));
+    }
+}
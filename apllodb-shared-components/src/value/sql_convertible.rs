@@ -1,11 +1,19 @@
+mod adt;
+mod array;
 mod boolean;
+mod bytes;
+mod datetime;
+mod decimal;
+mod float;
 mod int;
+mod json;
 mod text;
 
 use crate::{
     error::{ApllodbError, ApllodbResult},
-    NnSqlValue,
+    AdtValue, ArrayValue, Decimal, FuzzyDate, Interval, Json, NnSqlValue,
 };
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use std::any::type_name;
 
 /// Rust values which can have bidirectional mapping to/from SQL [NnSqlValue](crate::NnSqlValue).
@@ -37,6 +45,30 @@ pub trait SqlConvertible: Sized {
         Self::default_err("i64")
     }
 
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalConversion](crate::SqlState::DataExceptionIllegalConversion) when:
+    ///   - the type implementing SqlConvertible is not convertible from f32
+    fn try_from_f32(_: &f32) -> ApllodbResult<Self> {
+        Self::default_err("f32")
+    }
+
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalConversion](crate::SqlState::DataExceptionIllegalConversion) when:
+    ///   - the type implementing SqlConvertible is not convertible from f64
+    fn try_from_f64(_: &f64) -> ApllodbResult<Self> {
+        Self::default_err("f64")
+    }
+
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalConversion](crate::SqlState::DataExceptionIllegalConversion) when:
+    ///   - the type implementing SqlConvertible is not convertible from Decimal
+    fn try_from_decimal(_: &Decimal) -> ApllodbResult<Self> {
+        Self::default_err("Decimal")
+    }
+
     /// # Failures
     ///
     /// - [DataExceptionIllegalConversion](crate::SqlState::DataExceptionIllegalConversion) when:
@@ -53,6 +85,86 @@ pub trait SqlConvertible: Sized {
         Self::default_err("bool")
     }
 
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalConversion](crate::SqlState::DataExceptionIllegalConversion) when:
+    ///   - the type implementing SqlConvertible is not convertible from bytes
+    fn try_from_bytes(_: &[u8]) -> ApllodbResult<Self> {
+        Self::default_err("Vec<u8>")
+    }
+
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalConversion](crate::SqlState::DataExceptionIllegalConversion) when:
+    ///   - the type implementing SqlConvertible is not convertible from Json
+    fn try_from_json(_: &Json) -> ApllodbResult<Self> {
+        Self::default_err("Json")
+    }
+
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalConversion](crate::SqlState::DataExceptionIllegalConversion) when:
+    ///   - the type implementing SqlConvertible is not convertible from NaiveDate
+    fn try_from_date(_: &NaiveDate) -> ApllodbResult<Self> {
+        Self::default_err("NaiveDate")
+    }
+
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalConversion](crate::SqlState::DataExceptionIllegalConversion) when:
+    ///   - the type implementing SqlConvertible is not convertible from NaiveTime
+    fn try_from_time(_: &NaiveTime) -> ApllodbResult<Self> {
+        Self::default_err("NaiveTime")
+    }
+
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalConversion](crate::SqlState::DataExceptionIllegalConversion) when:
+    ///   - the type implementing SqlConvertible is not convertible from NaiveDateTime
+    fn try_from_timestamp(_: &NaiveDateTime) -> ApllodbResult<Self> {
+        Self::default_err("NaiveDateTime")
+    }
+
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalConversion](crate::SqlState::DataExceptionIllegalConversion) when:
+    ///   - the type implementing SqlConvertible is not convertible from DateTime<Utc>
+    fn try_from_timestamp_with_time_zone(_: &DateTime<Utc>) -> ApllodbResult<Self> {
+        Self::default_err("DateTime<Utc>")
+    }
+
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalConversion](crate::SqlState::DataExceptionIllegalConversion) when:
+    ///   - the type implementing SqlConvertible is not convertible from Interval
+    fn try_from_interval(_: &Interval) -> ApllodbResult<Self> {
+        Self::default_err("Interval")
+    }
+
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalConversion](crate::SqlState::DataExceptionIllegalConversion) when:
+    ///   - the type implementing SqlConvertible is not convertible from FuzzyDate
+    fn try_from_fuzzy_date(_: &FuzzyDate) -> ApllodbResult<Self> {
+        Self::default_err("FuzzyDate")
+    }
+
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalConversion](crate::SqlState::DataExceptionIllegalConversion) when:
+    ///   - the type implementing SqlConvertible is not convertible from AdtValue
+    fn try_from_adt(_: &AdtValue) -> ApllodbResult<Self> {
+        Self::default_err("AdtValue")
+    }
+
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalConversion](crate::SqlState::DataExceptionIllegalConversion) when:
+    ///   - the type implementing SqlConvertible is not convertible from ArrayValue
+    fn try_from_array(_: &ArrayValue) -> ApllodbResult<Self> {
+        Self::default_err("ArrayValue")
+    }
+
     #[doc(hidden)]
     fn default_err(from_type: &str) -> ApllodbResult<Self> {
         Err(ApllodbError::data_exception_illegal_conversion(format!(
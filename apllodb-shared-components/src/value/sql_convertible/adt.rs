@@ -0,0 +1,13 @@
+use crate::{AdtValue, ApllodbResult, NnSqlValue};
+
+use super::SqlConvertible;
+
+impl SqlConvertible for AdtValue {
+    fn into_sql_value(self) -> NnSqlValue {
+        NnSqlValue::Adt(self)
+    }
+
+    fn try_from_adt(v: &AdtValue) -> ApllodbResult<Self> {
+        Ok(v.clone())
+    }
+}
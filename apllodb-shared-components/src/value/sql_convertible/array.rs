@@ -0,0 +1,13 @@
+use crate::{ApllodbResult, ArrayValue, NnSqlValue};
+
+use super::SqlConvertible;
+
+impl SqlConvertible for ArrayValue {
+    fn into_sql_value(self) -> NnSqlValue {
+        NnSqlValue::Array(self)
+    }
+
+    fn try_from_array(v: &ArrayValue) -> ApllodbResult<Self> {
+        Ok(v.clone())
+    }
+}
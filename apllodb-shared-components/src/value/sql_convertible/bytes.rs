@@ -0,0 +1,13 @@
+use crate::{ApllodbResult, NnSqlValue};
+
+use super::SqlConvertible;
+
+impl SqlConvertible for Vec<u8> {
+    fn into_sql_value(self) -> NnSqlValue {
+        NnSqlValue::Bytes(self)
+    }
+
+    fn try_from_bytes(v: &[u8]) -> ApllodbResult<Self> {
+        Ok(v.to_vec())
+    }
+}
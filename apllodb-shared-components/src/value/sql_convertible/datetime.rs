@@ -0,0 +1,65 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+use crate::{ApllodbResult, FuzzyDate, Interval, NnSqlValue};
+
+use super::SqlConvertible;
+
+impl SqlConvertible for NaiveDate {
+    fn into_sql_value(self) -> NnSqlValue {
+        NnSqlValue::Date(self)
+    }
+
+    fn try_from_date(v: &NaiveDate) -> ApllodbResult<Self> {
+        Ok(*v)
+    }
+}
+
+impl SqlConvertible for NaiveTime {
+    fn into_sql_value(self) -> NnSqlValue {
+        NnSqlValue::Time(self)
+    }
+
+    fn try_from_time(v: &NaiveTime) -> ApllodbResult<Self> {
+        Ok(*v)
+    }
+}
+
+impl SqlConvertible for NaiveDateTime {
+    fn into_sql_value(self) -> NnSqlValue {
+        NnSqlValue::Timestamp(self)
+    }
+
+    fn try_from_timestamp(v: &NaiveDateTime) -> ApllodbResult<Self> {
+        Ok(*v)
+    }
+}
+
+impl SqlConvertible for DateTime<Utc> {
+    fn into_sql_value(self) -> NnSqlValue {
+        NnSqlValue::TimestampWithTimeZone(self)
+    }
+
+    fn try_from_timestamp_with_time_zone(v: &DateTime<Utc>) -> ApllodbResult<Self> {
+        Ok(*v)
+    }
+}
+
+impl SqlConvertible for FuzzyDate {
+    fn into_sql_value(self) -> NnSqlValue {
+        NnSqlValue::FuzzyDate(self)
+    }
+
+    fn try_from_fuzzy_date(v: &FuzzyDate) -> ApllodbResult<Self> {
+        Ok(*v)
+    }
+}
+
+impl SqlConvertible for Interval {
+    fn into_sql_value(self) -> NnSqlValue {
+        NnSqlValue::Interval(self)
+    }
+
+    fn try_from_interval(v: &Interval) -> ApllodbResult<Self> {
+        Ok(*v)
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{ApllodbResult, Decimal, NnSqlValue};
+
+use super::SqlConvertible;
+
+impl SqlConvertible for Decimal {
+    fn into_sql_value(self) -> NnSqlValue {
+        NnSqlValue::Numeric(self)
+    }
+
+    fn try_from_i16(v: &i16) -> ApllodbResult<Self> {
+        Ok(Decimal::from(*v as i64))
+    }
+
+    fn try_from_i32(v: &i32) -> ApllodbResult<Self> {
+        Ok(Decimal::from(*v as i64))
+    }
+
+    fn try_from_i64(v: &i64) -> ApllodbResult<Self> {
+        Ok(Decimal::from(*v))
+    }
+
+    fn try_from_decimal(v: &Decimal) -> ApllodbResult<Self> {
+        Ok(*v)
+    }
+}
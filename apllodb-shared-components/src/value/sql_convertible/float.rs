@@ -0,0 +1,70 @@
+use crate::{ApllodbResult, NnSqlValue};
+
+use super::SqlConvertible;
+
+impl SqlConvertible for f32 {
+    fn into_sql_value(self) -> NnSqlValue {
+        NnSqlValue::Real(self)
+    }
+
+    fn try_from_i16(v: &i16) -> ApllodbResult<Self> {
+        Ok(*v as f32)
+    }
+
+    fn try_from_f32(v: &f32) -> ApllodbResult<Self> {
+        Ok(*v)
+    }
+}
+
+impl SqlConvertible for f64 {
+    fn into_sql_value(self) -> NnSqlValue {
+        NnSqlValue::DoublePrecision(self)
+    }
+
+    fn try_from_i16(v: &i16) -> ApllodbResult<Self> {
+        Ok(*v as f64)
+    }
+
+    fn try_from_i32(v: &i32) -> ApllodbResult<Self> {
+        Ok(*v as f64)
+    }
+
+    fn try_from_f32(v: &f32) -> ApllodbResult<Self> {
+        Ok(*v as f64)
+    }
+
+    fn try_from_f64(v: &f64) -> ApllodbResult<Self> {
+        Ok(*v)
+    }
+}
+
+#[cfg(test)]
+mod tests_f64 {
+    use crate::{error::ApllodbResult, NnSqlValue, SqlState};
+
+    #[test]
+    fn test_pack_unpack() -> ApllodbResult<()> {
+        let rust_values = vec![0.0, 1.5, -1.5, f64::MAX, f64::MIN_POSITIVE];
+
+        for v in rust_values {
+            let sql_value = NnSqlValue::DoublePrecision(v);
+            let unpacked: f64 = sql_value.unpack()?;
+            assert_eq!(unpacked, v);
+        }
+
+        assert_eq!(NnSqlValue::Real(0.5).unpack::<f64>()?, 0.5);
+        assert_eq!(NnSqlValue::Integer(-3).unpack::<f64>()?, -3.0);
+        assert_eq!(
+            NnSqlValue::BigInt(1).unpack::<f64>().unwrap_err().kind(),
+            &SqlState::DataExceptionIllegalConversion
+        );
+        assert_eq!(
+            NnSqlValue::DoublePrecision(0.5)
+                .unpack::<f32>()
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalConversion
+        );
+        Ok(())
+    }
+}
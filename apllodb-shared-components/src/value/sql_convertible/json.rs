@@ -0,0 +1,13 @@
+use crate::{ApllodbResult, Json, NnSqlValue};
+
+use super::SqlConvertible;
+
+impl SqlConvertible for Json {
+    fn into_sql_value(self) -> NnSqlValue {
+        NnSqlValue::Json(self)
+    }
+
+    fn try_from_json(v: &Json) -> ApllodbResult<Self> {
+        Ok(v.clone())
+    }
+}
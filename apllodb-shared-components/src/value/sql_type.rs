@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::{AdtType, ApllodbError, ApllodbResult, Decimal};
+
 /// SQL type.
 ///
 /// SQL types are hierarchically categorized as follows:
@@ -17,6 +19,24 @@ pub enum SqlType {
 
     /// Boolean types
     BooleanComparable,
+
+    /// Binary string type
+    BinaryComparable,
+
+    /// JSON document type (only equality is defined)
+    JsonComparable,
+
+    /// Date and time types
+    DatetimeComparable(DatetimeType),
+
+    /// Time span type
+    IntervalComparable,
+
+    /// User-defined algebraic data type (see [AdtType](crate::AdtType))
+    AdtComparable(AdtType),
+
+    /// Array of the element type (see [ArrayValue](crate::ArrayValue))
+    ArrayComparable(Box<SqlType>),
 }
 
 impl SqlType {
@@ -33,15 +53,148 @@ impl SqlType {
         SqlType::NumericComparable(NumericComparableType::I64Loose(I64LooseType::BigInt))
     }
 
+    /// Constructor of Real
+    pub fn real() -> SqlType {
+        SqlType::NumericComparable(NumericComparableType::F64Loose(F64LooseType::Real))
+    }
+    /// Constructor of Double Precision
+    pub fn double_precision() -> SqlType {
+        SqlType::NumericComparable(NumericComparableType::F64Loose(
+            F64LooseType::DoublePrecision,
+        ))
+    }
+
+    /// Constructor of Numeric (without precision and scale)
+    pub fn numeric() -> SqlType {
+        SqlType::NumericComparable(NumericComparableType::Decimal(DecimalType::Unconstrained))
+    }
+    /// Constructor of Numeric(precision, scale)
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionInvalidParameterValue](crate::SqlState::DataExceptionInvalidParameterValue) when:
+    ///   - `precision` is not in `1..=38`
+    ///   - `scale` is larger than `precision`
+    pub fn numeric_with_precision(precision: u8, scale: u8) -> ApllodbResult<SqlType> {
+        if !(1..=Decimal::MAX_PRECISION).contains(&precision) {
+            Err(ApllodbError::data_exception_invalid_parameter_value(
+                format!(
+                    "NUMERIC precision {} must be between 1 and {}",
+                    precision,
+                    Decimal::MAX_PRECISION
+                ),
+            ))
+        } else if scale > precision {
+            Err(ApllodbError::data_exception_invalid_parameter_value(
+                format!(
+                    "NUMERIC scale {} must not be larger than precision {}",
+                    scale, precision
+                ),
+            ))
+        } else {
+            Ok(SqlType::NumericComparable(NumericComparableType::Decimal(
+                DecimalType::Constrained { precision, scale },
+            )))
+        }
+    }
+
     /// Constructor of Text
     pub fn text() -> SqlType {
         SqlType::StringComparableLoose(StringComparableLoseType::Text)
     }
 
+    /// Constructor of Varchar(max_length)
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionInvalidParameterValue](crate::SqlState::DataExceptionInvalidParameterValue) when:
+    ///   - `max_length` is not in `1..=10485760`
+    pub fn varchar(max_length: u32) -> ApllodbResult<SqlType> {
+        StringComparableLoseType::validate_length("VARCHAR", max_length)?;
+        Ok(SqlType::StringComparableLoose(
+            StringComparableLoseType::VarChar { max_length },
+        ))
+    }
+    /// Constructor of Char(length)
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionInvalidParameterValue](crate::SqlState::DataExceptionInvalidParameterValue) when:
+    ///   - `length` is not in `1..=10485760`
+    pub fn char(length: u32) -> ApllodbResult<SqlType> {
+        StringComparableLoseType::validate_length("CHAR", length)?;
+        Ok(SqlType::StringComparableLoose(
+            StringComparableLoseType::Char { length },
+        ))
+    }
+
     /// Constructor of Boolean
     pub fn boolean() -> SqlType {
         SqlType::BooleanComparable
     }
+
+    /// Constructor of Bytea (binary string)
+    pub fn bytea() -> SqlType {
+        SqlType::BinaryComparable
+    }
+
+    /// Constructor of Json
+    pub fn json() -> SqlType {
+        SqlType::JsonComparable
+    }
+
+    /// Constructor of Date
+    pub fn date() -> SqlType {
+        SqlType::DatetimeComparable(DatetimeType::Date)
+    }
+    /// Constructor of Time (without time zone)
+    pub fn time() -> SqlType {
+        SqlType::DatetimeComparable(DatetimeType::Time)
+    }
+    /// Constructor of Timestamp (without time zone)
+    pub fn timestamp() -> SqlType {
+        SqlType::DatetimeComparable(DatetimeType::Timestamp)
+    }
+    /// Constructor of Timestamp With Time Zone
+    pub fn timestamp_with_time_zone() -> SqlType {
+        SqlType::DatetimeComparable(DatetimeType::TimestampWithTimeZone)
+    }
+
+    /// Constructor of Fuzzy Date
+    pub fn fuzzy_date() -> SqlType {
+        SqlType::DatetimeComparable(DatetimeType::FuzzyDate)
+    }
+
+    /// Constructor of Interval
+    pub fn interval() -> SqlType {
+        SqlType::IntervalComparable
+    }
+
+    /// Constructor of user-defined algebraic data type
+    pub fn adt(adt_type: AdtType) -> SqlType {
+        SqlType::AdtComparable(adt_type)
+    }
+
+    /// Constructor of array (`element_type[]`)
+    pub fn array(element_type: SqlType) -> SqlType {
+        SqlType::ArrayComparable(Box::new(element_type))
+    }
+
+    /// Whether values of this type are ordered, so that they can be sorted (ORDER BY).
+    ///
+    /// JSON is not ordered (only equality is defined), and neither are arrays and user-defined types containing JSON.
+    pub fn is_ordered(&self) -> bool {
+        match self {
+            SqlType::JsonComparable => false,
+            SqlType::ArrayComparable(element_type) => element_type.is_ordered(),
+            SqlType::AdtComparable(adt_type) => adt_type
+                .variants()
+                .iter()
+                .flat_map(|variant| variant.fields())
+                .all(|field| field.sql_type().is_ordered()),
+            _ => true,
+        }
+    }
 }
 
 /// Numeric types (comparable).
@@ -49,10 +202,18 @@ impl SqlType {
 pub enum NumericComparableType {
     /// Loosely typed as i64
     I64Loose(I64LooseType),
+
+    /// Loosely typed as f64 (inexact)
+    F64Loose(F64LooseType),
+
+    /// Exact decimal number
+    Decimal(DecimalType),
 }
 
 /// Integer types (loosely typed as i64).
-#[derive(Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
+///
+/// Ordered by width, so that arithmetic can pick the wider type of its operands.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize, Deserialize)]
 pub enum I64LooseType {
     /// 2-byte signed integer.
     SmallInt,
@@ -64,9 +225,97 @@ pub enum I64LooseType {
     BigInt,
 }
 
+/// Floating-point types (loosely typed as f64).
+///
+/// Ordered by width, so that arithmetic can pick the wider type of its operands.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize, Deserialize)]
+pub enum F64LooseType {
+    /// 4-byte floating-point number (IEEE 754 single precision).
+    Real,
+
+    /// 8-byte floating-point number (IEEE 754 double precision).
+    DoublePrecision,
+}
+
+/// Exact decimal types (typed as [Decimal](crate::Decimal)).
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub enum DecimalType {
+    /// `NUMERIC` without precision and scale: values keep their own scale.
+    Unconstrained,
+
+    /// `NUMERIC(precision, scale)`: values are rounded into `scale` fractional digits
+    /// and must have at most `precision` digits in total.
+    Constrained {
+        /// Max number of digits.
+        precision: u8,
+        /// Number of fractional digits.
+        scale: u8,
+    },
+}
+
 /// Text types (comparable, loosely typed as String).
 #[derive(Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
 pub enum StringComparableLoseType {
     /// Arbitrary length text (UTF-8).
     Text,
+
+    /// `VARCHAR(max_length)`: text with at most `max_length` characters.
+    VarChar {
+        /// Max number of characters.
+        max_length: u32,
+    },
+
+    /// `CHAR(length)`: text of exactly `length` characters, padded with trailing spaces.
+    Char {
+        /// Number of characters.
+        length: u32,
+    },
+}
+
+impl StringComparableLoseType {
+    /// Max length of VARCHAR and CHAR (same as PostgreSQL).
+    pub const MAX_LENGTH: u32 = 10_485_760;
+
+    fn validate_length(type_name: &str, length: u32) -> ApllodbResult<()> {
+        if (1..=Self::MAX_LENGTH).contains(&length) {
+            Ok(())
+        } else {
+            Err(ApllodbError::data_exception_invalid_parameter_value(
+                format!(
+                    "{} length {} must be between 1 and {}",
+                    type_name,
+                    length,
+                    Self::MAX_LENGTH
+                ),
+            ))
+        }
+    }
+
+    /// Max number of characters of a value, if limited.
+    pub fn max_length(&self) -> Option<u32> {
+        match self {
+            StringComparableLoseType::Text => None,
+            StringComparableLoseType::VarChar { max_length } => Some(*max_length),
+            StringComparableLoseType::Char { length } => Some(*length),
+        }
+    }
+}
+
+/// Date and time types (comparable).
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub enum DatetimeType {
+    /// Calendar date (year, month, day).
+    Date,
+
+    /// Time of day (without time zone).
+    Time,
+
+    /// Date and time (without time zone).
+    Timestamp,
+
+    /// Date and time (with time zone), held in UTC.
+    TimestampWithTimeZone,
+
+    /// Ambiguous date held as a range of possible dates (see [FuzzyDate](crate::FuzzyDate)).
+    FuzzyDate,
 }
@@ -158,6 +158,20 @@ impl SqlValue {
         }
     }
 
+    /// `self IS NOT DISTINCT FROM other`: NULL is not distinct from NULL,
+    /// and see [NnSqlValue::is_not_distinct_from()](crate::NnSqlValue::is_not_distinct_from) for non-NULL values.
+    pub fn is_not_distinct_from(&self, other: &Self) -> bool {
+        match (self, other) {
+            (SqlValue::Null, SqlValue::Null) => true,
+            (SqlValue::Null, SqlValue::NotNull(_)) | (SqlValue::NotNull(_), SqlValue::Null) => {
+                false
+            }
+            (SqlValue::NotNull(nn_self), SqlValue::NotNull(nn_other)) => {
+                nn_self.is_not_distinct_from(nn_other)
+            }
+        }
+    }
+
     /// Eval as bool if possible.
     ///
     /// # Failures
@@ -165,10 +179,22 @@ impl SqlValue {
     /// - [DataExceptionIllegalOperation](crate::SqlState::DataExceptionIllegalOperation) when:
     ///   - this SqlValue cannot be evaluated as SQL BOOLEAN
     pub fn to_bool(&self) -> ApllodbResult<bool> {
+        // NULL is always evaluated as FALSE
+        self.to_nullable_bool().map(|b| b.unwrap_or(false))
+    }
+
+    /// Eval as bool in three-valued logic if possible.
+    /// NULL is evaluated as `None` (UNKNOWN).
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalOperation](crate::SqlState::DataExceptionIllegalOperation) when:
+    ///   - this SqlValue cannot be evaluated as SQL BOOLEAN
+    pub fn to_nullable_bool(&self) -> ApllodbResult<Option<bool>> {
         match self {
-            SqlValue::Null => Ok(false), // NULL is always evaluated as FALSE
+            SqlValue::Null => Ok(None),
             SqlValue::NotNull(nn_sql_value) => match nn_sql_value {
-                NnSqlValue::Boolean(b) => Ok(*b),
+                NnSqlValue::Boolean(b) => Ok(Some(*b)),
                 _ => Err(ApllodbError::data_exception_illegal_operation(format!(
                     "{:?} cannot be evaluated as BOOLEAN",
                     nn_sql_value.sql_type()
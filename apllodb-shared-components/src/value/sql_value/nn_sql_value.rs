@@ -1,14 +1,22 @@
-use std::{fmt::Display, hash::Hash};
+use std::{cmp::Ordering, convert::TryFrom, fmt::Display, hash::Hash};
 
 use crate::{
     error::{ApllodbError, ApllodbResult},
-    SqlConvertible,
+    value::{
+        bytea::format_bytea,
+        datetime::{DATE_FORMAT, TIMESTAMP_FORMAT, TIMESTAMP_WITH_TIME_ZONE_FORMAT, TIME_FORMAT},
+    },
+    AdtValue, ArrayValue, Decimal, FuzzyDate, Interval, Json, SqlConvertible,
 };
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use super::sql_compare_result::SqlCompareResult;
 
-use crate::{NumericComparableType, SqlType, StringComparableLoseType};
+use crate::{
+    DatetimeType, F64LooseType, I64LooseType, NumericComparableType, SqlType,
+    StringComparableLoseType,
+};
 
 /// NOT NULL value.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -20,36 +28,96 @@ pub enum NnSqlValue {
     /// BIGINT
     BigInt(i64),
 
-    /// TEXT
+    /// REAL
+    Real(f32),
+    /// DOUBLE PRECISION
+    DoublePrecision(f64),
+
+    /// NUMERIC
+    Numeric(Decimal),
+
+    /// TEXT (and VARCHAR)
     Text(String),
+    /// CHAR (padded with trailing spaces into its length)
+    Char(String),
 
     /// BOOLEAN
     Boolean(bool),
+
+    /// BYTEA
+    Bytes(Vec<u8>),
+
+    /// JSON
+    Json(Json),
+
+    /// DATE
+    Date(NaiveDate),
+    /// TIME (without time zone)
+    Time(NaiveTime),
+    /// TIMESTAMP (without time zone)
+    Timestamp(NaiveDateTime),
+    /// TIMESTAMP WITH TIME ZONE
+    TimestampWithTimeZone(DateTime<Utc>),
+    /// FUZZY DATE
+    FuzzyDate(FuzzyDate),
+
+    /// INTERVAL
+    Interval(Interval),
+
+    /// User-defined algebraic data type
+    Adt(AdtValue),
+
+    /// Array
+    Array(ArrayValue),
 }
 
 /// Although function is better to use,
 ///
 /// ```
-/// fn for_all_loose_types<R, FnNull, FnI64, FnString>(
+/// fn for_all_loose_types<R, FnNull, FnI64, FnF64, FnDecimal, FnString>(
 ///     &self,
 ///     f_i64: FnI64,
+///     f_f64: FnF64,
+///     f_decimal: FnDecimal,
 ///     f_string: FnString,
 /// ) -> R
 /// where
 ///     FnI64: FnOnce(i64) -> R,
+///     FnF64: FnOnce(f64) -> R,
+///     FnDecimal: FnOnce(Decimal) -> R,
 ///     FnString: FnOnce(String) -> R,
 /// ```
 ///
 /// does not work properly with closures which capture &mut environments.
 macro_rules! for_all_loose_types {
-    ( $nn_sql_value:expr, $closure_i64:expr, $closure_string:expr, $closure_bool:expr ) => {{
+    ( $nn_sql_value:expr, $closure_i64:expr, $closure_f64:expr, $closure_decimal:expr, $closure_string:expr, $closure_bool:expr ) => {{
         match &$nn_sql_value {
             NnSqlValue::SmallInt(_) | NnSqlValue::Integer(_) | NnSqlValue::BigInt(_) => {
                 let v = $nn_sql_value.unpack::<i64>().unwrap();
                 $closure_i64(v)
             }
-            NnSqlValue::Text(s) => $closure_string(s.to_string()),
+            NnSqlValue::Real(_) | NnSqlValue::DoublePrecision(_) => {
+                let v = $nn_sql_value.unpack::<f64>().unwrap();
+                $closure_f64(v)
+            }
+            NnSqlValue::Numeric(d) => $closure_decimal(*d),
+            NnSqlValue::Text(s) | NnSqlValue::Char(s) => $closure_string(s.to_string()),
             NnSqlValue::Boolean(b) => $closure_bool(b.clone()),
+            NnSqlValue::Bytes(_)
+            | NnSqlValue::Json(_)
+            | NnSqlValue::Date(_)
+            | NnSqlValue::Time(_)
+            | NnSqlValue::Timestamp(_)
+            | NnSqlValue::TimestampWithTimeZone(_)
+            | NnSqlValue::FuzzyDate(_)
+            | NnSqlValue::Interval(_)
+            | NnSqlValue::Adt(_)
+            | NnSqlValue::Array(_) => {
+                unreachable!(
+                    "binary, JSON, date/time, user-defined and array values are not loosely typed: {:?}",
+                    $nn_sql_value
+                )
+            }
         }
     }};
 }
@@ -63,28 +131,116 @@ impl PartialEq for NnSqlValue {
 impl Hash for NnSqlValue {
     /// Although raw format are different between two NnSqlValue, this hash function must return the same value if loosely typed values are the same.
     /// E.g. `42 SMALLINT`'s hash value must be equal to that of `42 INTEGER`.
+    ///
+    /// Numeric values are hashed as f64, since any numeric types are comparable with each other
+    /// (e.g. `42 INTEGER = 42.0 DOUBLE PRECISION = 42.00 NUMERIC`).
+    /// Likewise, DATE and TIMESTAMP [WITH TIME ZONE] values are hashed as TIMESTAMP in UTC
+    /// (e.g. `DATE '2021-01-01' = TIMESTAMP '2021-01-01 00:00:00'`).
+    /// An exact FUZZY DATE is hashed as DATE, and an ambiguous one is hashed as its range,
+    /// so that values with the same range (e.g. `'1920s'` and `'1920..1929'`) share a hash value although they are not equal in SQL.
+    /// Strings are hashed without trailing spaces, since CHAR values ignore them in comparison (e.g. `'a  ' CHAR(3) = 'a' TEXT`).
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        match self {
+            NnSqlValue::Bytes(b) => return b.hash(state),
+            NnSqlValue::Json(j) => return j.hash(state),
+            NnSqlValue::Date(d) => return d.and_hms(0, 0, 0).hash(state),
+            NnSqlValue::Timestamp(t) => return t.hash(state),
+            NnSqlValue::TimestampWithTimeZone(t) => return t.naive_utc().hash(state),
+            NnSqlValue::Time(t) => return t.hash(state),
+            NnSqlValue::FuzzyDate(fd) => {
+                return match fd.as_exact() {
+                    Some(d) => d.and_hms(0, 0, 0).hash(state),
+                    None => (fd.earliest(), fd.latest()).hash(state),
+                }
+            }
+            NnSqlValue::Interval(i) => return i.hash(state),
+            NnSqlValue::Adt(v) => return v.hash(state),
+            NnSqlValue::Array(a) => return a.hash(state),
+            _ => {}
+        }
+
+        let mut hash_f64 = |v: f64| {
+            let v = if v == 0.0 { 0.0 } else { v }; // -0.0 = 0.0
+            if v.is_nan() {
+                f64::NAN.to_bits().hash(state)
+            } else {
+                v.to_bits().hash(state)
+            }
+        };
         for_all_loose_types!(
             self,
             |i: i64| {
-                i.hash(state);
+                hash_f64(i as f64);
+            },
+            |f: f64| {
+                hash_f64(f);
+            },
+            |d: Decimal| {
+                hash_f64(d.to_f64());
             },
             |s: String| {
-                s.hash(state);
+                s.trim_end_matches(' ').hash(state);
             },
             |b: bool| { b.hash(state) }
         )
     }
 }
 
+/// Arithmetic operators on numbers.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+enum ArithmeticOperator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Modulo,
+}
+
+impl ArithmeticOperator {
+    /// Whether the right operand must not be 0.
+    fn is_division(&self) -> bool {
+        matches!(self, Self::Divide | Self::Modulo)
+    }
+}
+
+impl Display for ArithmeticOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Add => "+",
+            Self::Subtract => "-",
+            Self::Multiply => "*",
+            Self::Divide => "/",
+            Self::Modulo => "%",
+        };
+        write!(f, "{}", s)
+    }
+}
+
 impl Display for NnSqlValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let s: String = for_all_loose_types!(
-            self,
-            |i: i64| i.to_string(),
-            |s: String| format!(r#""{}""#, s),
-            |b: bool| (if b { "TRUE" } else { "FALSE" }).to_string()
-        );
+        let s: String = match self {
+            NnSqlValue::Real(v) => v.to_string(),
+            NnSqlValue::Bytes(b) => format_bytea(b),
+            NnSqlValue::Json(j) => j.to_string(),
+            NnSqlValue::Date(d) => d.format(DATE_FORMAT).to_string(),
+            NnSqlValue::Time(t) => t.format(TIME_FORMAT).to_string(),
+            NnSqlValue::Timestamp(t) => t.format(TIMESTAMP_FORMAT).to_string(),
+            NnSqlValue::TimestampWithTimeZone(t) => {
+                t.format(TIMESTAMP_WITH_TIME_ZONE_FORMAT).to_string()
+            }
+            NnSqlValue::FuzzyDate(fd) => fd.to_string(),
+            NnSqlValue::Interval(i) => i.to_string(),
+            NnSqlValue::Adt(v) => v.to_string(),
+            NnSqlValue::Array(a) => a.to_string(),
+            _ => for_all_loose_types!(
+                self,
+                |i: i64| i.to_string(),
+                |f: f64| f.to_string(),
+                |d: Decimal| d.to_string(),
+                |s: String| format!(r#""{}""#, s),
+                |b: bool| (if b { "TRUE" } else { "FALSE" }).to_string()
+            ),
+        };
         write!(f, "{}", s)
     }
 }
@@ -107,8 +263,21 @@ impl NnSqlValue {
             NnSqlValue::SmallInt(i16_) => T::try_from_i16(i16_),
             NnSqlValue::Integer(i32_) => T::try_from_i32(i32_),
             NnSqlValue::BigInt(i64_) => T::try_from_i64(i64_),
-            NnSqlValue::Text(string) => T::try_from_string(string),
+            NnSqlValue::Real(f32_) => T::try_from_f32(f32_),
+            NnSqlValue::DoublePrecision(f64_) => T::try_from_f64(f64_),
+            NnSqlValue::Numeric(decimal) => T::try_from_decimal(decimal),
+            NnSqlValue::Text(string) | NnSqlValue::Char(string) => T::try_from_string(string),
             NnSqlValue::Boolean(b) => T::try_from_bool(b),
+            NnSqlValue::Bytes(b) => T::try_from_bytes(b),
+            NnSqlValue::Json(j) => T::try_from_json(j),
+            NnSqlValue::Date(d) => T::try_from_date(d),
+            NnSqlValue::Time(t) => T::try_from_time(t),
+            NnSqlValue::Timestamp(t) => T::try_from_timestamp(t),
+            NnSqlValue::TimestampWithTimeZone(t) => T::try_from_timestamp_with_time_zone(t),
+            NnSqlValue::FuzzyDate(fd) => T::try_from_fuzzy_date(fd),
+            NnSqlValue::Interval(i) => T::try_from_interval(i),
+            NnSqlValue::Adt(v) => T::try_from_adt(v),
+            NnSqlValue::Array(a) => T::try_from_array(a),
         }
     }
 
@@ -118,38 +287,154 @@ impl NnSqlValue {
             NnSqlValue::SmallInt(_) => SqlType::small_int(),
             NnSqlValue::Integer(_) => SqlType::integer(),
             NnSqlValue::BigInt(_) => SqlType::big_int(),
+            NnSqlValue::Real(_) => SqlType::real(),
+            NnSqlValue::DoublePrecision(_) => SqlType::double_precision(),
+            NnSqlValue::Numeric(_) => SqlType::numeric(),
             NnSqlValue::Text(_) => SqlType::text(),
+            NnSqlValue::Char(s) => SqlType::StringComparableLoose(StringComparableLoseType::Char {
+                length: s.chars().count() as u32,
+            }),
             NnSqlValue::Boolean(_) => SqlType::boolean(),
+            NnSqlValue::Bytes(_) => SqlType::bytea(),
+            NnSqlValue::Json(_) => SqlType::json(),
+            NnSqlValue::Date(_) => SqlType::date(),
+            NnSqlValue::Time(_) => SqlType::time(),
+            NnSqlValue::Timestamp(_) => SqlType::timestamp(),
+            NnSqlValue::TimestampWithTimeZone(_) => SqlType::timestamp_with_time_zone(),
+            NnSqlValue::FuzzyDate(_) => SqlType::fuzzy_date(),
+            NnSqlValue::Interval(_) => SqlType::interval(),
+            NnSqlValue::Adt(v) => SqlType::adt(v.adt_type().clone()),
+            NnSqlValue::Array(a) => SqlType::array(a.element_type().clone()),
         }
     }
 
-    pub(super) fn sql_compare(&self, other: &Self) -> ApllodbResult<SqlCompareResult> {
-        match (self.sql_type(), other.sql_type()) {
-            (SqlType::NumericComparable(self_n), SqlType::NumericComparable(other_n)) => {
-                match (self_n, other_n) {
-                    (NumericComparableType::I64Loose(_), NumericComparableType::I64Loose(_)) => {
-                        let (self_i64, other_i64) = (self.unpack::<i64>()?, other.unpack::<i64>()?);
-                        Ok(SqlCompareResult::from(self_i64.cmp(&other_i64)))
-                    }
-                }
+    /// Compares values after implicitly converting them into their unified type
+    /// (see [SqlType::unified()](crate::SqlType::unified)).
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalComparison](crate::SqlState::DataExceptionIllegalComparison) when:
+    ///   - types of `self` and `other` cannot be unified (e.g. INTEGER and TEXT)
+    pub(crate) fn sql_compare(&self, other: &Self) -> ApllodbResult<SqlCompareResult> {
+        let unified = self.sql_type().unified(&other.sql_type()).map_err(|_| {
+            ApllodbError::data_exception_illegal_comparison(format!(
+                "`self` and `other` are not in comparable type - self: {:?}, other: {:?}",
+                self, other
+            ))
+        })?;
+        match &unified {
+            SqlType::NumericComparable(NumericComparableType::I64Loose(_)) => {
+                let (self_i64, other_i64) = (self.unpack::<i64>()?, other.unpack::<i64>()?);
+                Ok(SqlCompareResult::from(self_i64.cmp(&other_i64)))
             }
-            (SqlType::StringComparableLoose(self_s), SqlType::StringComparableLoose(other_s)) => {
-                match (self_s, other_s) {
-                    (StringComparableLoseType::Text, StringComparableLoseType::Text) => {
-                        let (self_string, other_string) =
-                            (self.unpack::<String>()?, other.unpack::<String>()?);
-                        Ok(SqlCompareResult::from(self_string.cmp(&other_string)))
-                    }
-                }
+            SqlType::NumericComparable(NumericComparableType::F64Loose(_)) => {
+                let (self_f64, other_f64) = (self.to_f64()?, other.to_f64()?);
+                Ok(SqlCompareResult::from(Self::cmp_f64(self_f64, other_f64)))
+            }
+            SqlType::NumericComparable(NumericComparableType::Decimal(_)) => {
+                let (self_decimal, other_decimal) = (self.to_decimal()?, other.to_decimal()?);
+                Ok(SqlCompareResult::from(self_decimal.cmp(&other_decimal)))
             }
-            (SqlType::BooleanComparable, SqlType::BooleanComparable) => {
+            SqlType::StringComparableLoose(_) => {
+                let (self_string, other_string) =
+                    (self.unpack::<String>()?, other.unpack::<String>()?);
+                let ordering = if matches!(self, NnSqlValue::Char(_))
+                    || matches!(other, NnSqlValue::Char(_))
+                {
+                    Self::cmp_pad_space(&self_string, &other_string)
+                } else {
+                    self_string.cmp(&other_string)
+                };
+                Ok(SqlCompareResult::from(ordering))
+            }
+            SqlType::BooleanComparable => {
                 let (self_b, other_b) = (self.unpack::<bool>()?, other.unpack::<bool>()?);
                 Ok(SqlCompareResult::from(self_b.cmp(&other_b)))
             }
-            (_, _) => Err(ApllodbError::data_exception_illegal_comparison(format!(
-                "`self` and `other` are not in comparable type - self: {:?}, other: {:?}",
-                self, other
-            ))),
+            SqlType::BinaryComparable => {
+                // byte-wise, and a prefix is smaller
+                let (self_b, other_b) = (self.unpack::<Vec<u8>>()?, other.unpack::<Vec<u8>>()?);
+                Ok(SqlCompareResult::from(self_b.cmp(&other_b)))
+            }
+            SqlType::JsonComparable => {
+                let (self_j, other_j) = (self.unpack::<Json>()?, other.unpack::<Json>()?);
+                Ok(if self_j == other_j {
+                    SqlCompareResult::Eq
+                } else {
+                    SqlCompareResult::NotEq
+                })
+            }
+            SqlType::DatetimeComparable(datetime_type) => {
+                let (self_v, other_v) =
+                    (self.clone().cast(&unified)?, other.clone().cast(&unified)?);
+                let ordering = match datetime_type {
+                    DatetimeType::Date => self_v
+                        .unpack::<NaiveDate>()?
+                        .cmp(&other_v.unpack::<NaiveDate>()?),
+                    DatetimeType::Time => self_v
+                        .unpack::<NaiveTime>()?
+                        .cmp(&other_v.unpack::<NaiveTime>()?),
+                    DatetimeType::Timestamp => self_v
+                        .unpack::<NaiveDateTime>()?
+                        .cmp(&other_v.unpack::<NaiveDateTime>()?),
+                    DatetimeType::TimestampWithTimeZone => self_v
+                        .unpack::<DateTime<Utc>>()?
+                        .cmp(&other_v.unpack::<DateTime<Utc>>()?),
+                    DatetimeType::FuzzyDate => {
+                        return Ok(self_v
+                            .unpack::<FuzzyDate>()?
+                            .compare(&other_v.unpack::<FuzzyDate>()?))
+                    }
+                };
+                Ok(SqlCompareResult::from(ordering))
+            }
+            SqlType::IntervalComparable => {
+                let (self_i, other_i) = (self.unpack::<Interval>()?, other.unpack::<Interval>()?);
+                Ok(SqlCompareResult::from(self_i.cmp(&other_i)))
+            }
+            SqlType::AdtComparable(_) => {
+                let (self_v, other_v) = (self.unpack::<AdtValue>()?, other.unpack::<AdtValue>()?);
+                self_v.compare(&other_v)
+            }
+            SqlType::ArrayComparable(_) => {
+                let (self_a, other_a) =
+                    (self.unpack::<ArrayValue>()?, other.unpack::<ArrayValue>()?);
+                self_a.compare(&other_a)
+            }
+        }
+    }
+
+    /// `self IS NOT DISTINCT FROM other`, used to find duplicate values (GROUP BY, DISTINCT, set operations).
+    ///
+    /// Same as `=` by [sql_compare()](Self::sql_compare) except that it is reflexive:
+    /// FUZZY DATE values with the same range are not distinct even if they are ambiguous (e.g. two `'1920s'`),
+    /// and elements of arrays and fields of user-defined values are compared in the same way (NULL elements are not distinct from each other).
+    /// Values in incomparable types are distinct.
+    pub fn is_not_distinct_from(&self, other: &Self) -> bool {
+        match (self, other) {
+            (NnSqlValue::FuzzyDate(self_fd), NnSqlValue::FuzzyDate(other_fd))
+                if self_fd == other_fd =>
+            {
+                true
+            }
+            (NnSqlValue::Array(self_a), NnSqlValue::Array(other_a)) => {
+                self_a.len() == other_a.len()
+                    && self_a
+                        .elements()
+                        .iter()
+                        .zip(other_a.elements())
+                        .all(|(self_e, other_e)| self_e.is_not_distinct_from(other_e))
+            }
+            (NnSqlValue::Adt(self_v), NnSqlValue::Adt(other_v)) => {
+                self_v.adt_type() == other_v.adt_type()
+                    && self_v.variant() == other_v.variant()
+                    && self_v
+                        .fields()
+                        .iter()
+                        .zip(other_v.fields())
+                        .all(|(self_f, other_f)| self_f.is_not_distinct_from(other_f))
+            }
+            _ => matches!(self.sql_compare(other), Ok(SqlCompareResult::Eq)),
         }
     }
 
@@ -157,21 +442,448 @@ impl NnSqlValue {
     ///
     /// - [DataExceptionIllegalOperation](apllodb_shared_components::SqlState::DataExceptionIllegalOperation) when:
     ///   - inner value cannot negate
+    /// - [DataExceptionNumericValueOutOfRange](apllodb_shared_components::SqlState::DataExceptionNumericValueOutOfRange) when:
+    ///   - negated value does not fit in the inner type
     pub(crate) fn negate(self) -> ApllodbResult<Self> {
+        let out_of_range =
+            || ApllodbError::data_exception_numeric_value_out_of_range(format!("-({})", self));
         match self {
-            NnSqlValue::SmallInt(v) => Ok(Self::SmallInt(-v)),
-            NnSqlValue::Integer(v) => Ok(Self::Integer(-v)),
-            NnSqlValue::BigInt(v) => Ok(Self::BigInt(-v)),
-            NnSqlValue::Text(_) | NnSqlValue::Boolean(_) => Err(
-                ApllodbError::data_exception_illegal_operation(format!("{} cannot negate", self)),
+            NnSqlValue::SmallInt(v) => v.checked_neg().map(Self::SmallInt).ok_or_else(out_of_range),
+            NnSqlValue::Integer(v) => v.checked_neg().map(Self::Integer).ok_or_else(out_of_range),
+            NnSqlValue::BigInt(v) => v.checked_neg().map(Self::BigInt).ok_or_else(out_of_range),
+            NnSqlValue::Real(v) => Ok(Self::Real(-v)),
+            NnSqlValue::DoublePrecision(v) => Ok(Self::DoublePrecision(-v)),
+            NnSqlValue::Numeric(v) => Ok(Self::Numeric(v.negate())),
+            NnSqlValue::Interval(v) => v.negate().map(Self::Interval),
+            NnSqlValue::Text(_)
+            | NnSqlValue::Char(_)
+            | NnSqlValue::Boolean(_)
+            | NnSqlValue::Bytes(_)
+            | NnSqlValue::Json(_)
+            | NnSqlValue::Date(_)
+            | NnSqlValue::Time(_)
+            | NnSqlValue::Timestamp(_)
+            | NnSqlValue::TimestampWithTimeZone(_)
+            | NnSqlValue::FuzzyDate(_)
+            | NnSqlValue::Adt(_)
+            | NnSqlValue::Array(_) => Err(ApllodbError::data_exception_illegal_operation(format!(
+                "{} cannot negate",
+                self
+            ))),
+        }
+    }
+
+    /// `self + other`
+    ///
+    /// Date/time operands are calculated as follows (operands can be swapped):
+    ///
+    /// - DATE + integer = DATE (days later)
+    /// - DATE + INTERVAL = TIMESTAMP
+    /// - DATE + TIME = TIMESTAMP
+    /// - TIMESTAMP [WITH TIME ZONE] + INTERVAL = TIMESTAMP [WITH TIME ZONE]
+    /// - TIME + INTERVAL = TIME (wrapping around midnight)
+    /// - INTERVAL + INTERVAL = INTERVAL
+    ///
+    /// See [numeric_arithmetic()](Self::numeric_arithmetic) for type rules and failures of numeric operands.
+    /// Date/time operations fail with [DataExceptionDatetimeFieldOverflow](apllodb_shared_components::SqlState::DataExceptionDatetimeFieldOverflow)
+    /// when the result is out of range.
+    pub(crate) fn add(&self, other: &Self) -> ApllodbResult<Self> {
+        match (self, other) {
+            (NnSqlValue::Date(d), n) | (n, NnSqlValue::Date(d)) if n.is_integer() => {
+                Self::add_days(d, n.unpack::<i64>()?)
+            }
+            (NnSqlValue::Date(d), NnSqlValue::Interval(i))
+            | (NnSqlValue::Interval(i), NnSqlValue::Date(d)) => {
+                i.add_to_timestamp(d.and_hms(0, 0, 0)).map(Self::Timestamp)
+            }
+            (NnSqlValue::Date(d), NnSqlValue::Time(t))
+            | (NnSqlValue::Time(t), NnSqlValue::Date(d)) => Ok(Self::Timestamp(d.and_time(*t))),
+            (NnSqlValue::Timestamp(t), NnSqlValue::Interval(i))
+            | (NnSqlValue::Interval(i), NnSqlValue::Timestamp(t)) => {
+                i.add_to_timestamp(*t).map(Self::Timestamp)
+            }
+            (NnSqlValue::TimestampWithTimeZone(t), NnSqlValue::Interval(i))
+            | (NnSqlValue::Interval(i), NnSqlValue::TimestampWithTimeZone(t)) => i
+                .add_to_timestamp(t.naive_utc())
+                .map(|t| Self::TimestampWithTimeZone(DateTime::from_utc(t, Utc))),
+            (NnSqlValue::Time(t), NnSqlValue::Interval(i))
+            | (NnSqlValue::Interval(i), NnSqlValue::Time(t)) => Ok(Self::Time(i.add_to_time(*t))),
+            (NnSqlValue::Interval(l), NnSqlValue::Interval(r)) => l.add(r).map(Self::Interval),
+            _ => self.numeric_arithmetic(
+                other,
+                ArithmeticOperator::Add,
+                i64::checked_add,
+                |l, r| l + r,
+                Decimal::add,
+            ),
+        }
+    }
+
+    /// `self - other`
+    ///
+    /// Date/time operands are calculated as follows:
+    ///
+    /// - DATE - integer = DATE (days before)
+    /// - DATE - DATE = INTEGER (days)
+    /// - DATE / TIMESTAMP [WITH TIME ZONE] / TIME - INTERVAL = same as [add()](Self::add) with negated INTERVAL
+    /// - TIMESTAMP [WITH TIME ZONE] - TIMESTAMP [WITH TIME ZONE] = INTERVAL (days and time)
+    /// - TIME - TIME = INTERVAL
+    /// - INTERVAL - INTERVAL = INTERVAL
+    ///
+    /// See [numeric_arithmetic()](Self::numeric_arithmetic) for type rules and failures of numeric operands.
+    /// Date/time operations fail with [DataExceptionDatetimeFieldOverflow](apllodb_shared_components::SqlState::DataExceptionDatetimeFieldOverflow)
+    /// when the result is out of range.
+    pub(crate) fn subtract(&self, other: &Self) -> ApllodbResult<Self> {
+        match (self, other) {
+            (NnSqlValue::Date(d), n) if n.is_integer() => {
+                let days = n.unpack::<i64>()?;
+                Self::add_days(d, days.checked_neg().unwrap_or(i64::MAX))
+            }
+            (NnSqlValue::Date(l), NnSqlValue::Date(r)) => {
+                // NaiveDate covers less than i32::MAX days
+                Ok(Self::Integer(l.signed_duration_since(*r).num_days() as i32))
+            }
+            (
+                NnSqlValue::Date(_)
+                | NnSqlValue::Timestamp(_)
+                | NnSqlValue::TimestampWithTimeZone(_)
+                | NnSqlValue::Time(_),
+                NnSqlValue::Interval(i),
+            ) => self.add(&Self::Interval(i.negate()?)),
+            (NnSqlValue::Time(l), NnSqlValue::Time(r)) => Ok(Self::Interval(Interval::new(
+                0,
+                0,
+                l.signed_duration_since(*r)
+                    .num_nanoseconds()
+                    .expect("less than a day"),
+            ))),
+            (NnSqlValue::Interval(l), NnSqlValue::Interval(r)) => l.subtract(r).map(Self::Interval),
+            (
+                NnSqlValue::Date(_)
+                | NnSqlValue::Timestamp(_)
+                | NnSqlValue::TimestampWithTimeZone(_),
+                NnSqlValue::Date(_)
+                | NnSqlValue::Timestamp(_)
+                | NnSqlValue::TimestampWithTimeZone(_),
+            ) => {
+                // DATE - TIMESTAMP, for example, is calculated in TIMESTAMP
+                let unified = self.sql_type().unified(&other.sql_type())?;
+                let to_naive_utc = |v: &Self| -> ApllodbResult<NaiveDateTime> {
+                    match v.clone().cast(&unified)? {
+                        NnSqlValue::Timestamp(t) => Ok(t),
+                        NnSqlValue::TimestampWithTimeZone(t) => Ok(t.naive_utc()),
+                        _ => unreachable!(
+                            "DATE and TIMESTAMP are unified into TIMESTAMP [WITH TIME ZONE]"
+                        ),
+                    }
+                };
+                Interval::between(to_naive_utc(self)?, to_naive_utc(other)?).map(Self::Interval)
+            }
+            _ => self.numeric_arithmetic(
+                other,
+                ArithmeticOperator::Subtract,
+                i64::checked_sub,
+                |l, r| l - r,
+                Decimal::subtract,
             ),
         }
     }
+
+    /// `self * other`
+    ///
+    /// INTERVAL can be multiplied by a number (see [Interval::multiply()](crate::Interval::multiply)).
+    ///
+    /// See [numeric_arithmetic()](Self::numeric_arithmetic) for type rules and failures of numeric operands.
+    pub(crate) fn multiply(&self, other: &Self) -> ApllodbResult<Self> {
+        match (self, other) {
+            (NnSqlValue::Interval(i), n) | (n, NnSqlValue::Interval(i)) if n.is_numeric() => {
+                i.multiply(n.to_f64()?).map(Self::Interval)
+            }
+            _ => self.numeric_arithmetic(
+                other,
+                ArithmeticOperator::Multiply,
+                i64::checked_mul,
+                |l, r| l * r,
+                Decimal::multiply,
+            ),
+        }
+    }
+
+    /// `self / other` (integer division is truncated toward zero)
+    ///
+    /// INTERVAL can be divided by a number (see [Interval::divide()](crate::Interval::divide)).
+    ///
+    /// See [numeric_arithmetic()](Self::numeric_arithmetic) for type rules and failures of numeric operands.
+    pub(crate) fn divide(&self, other: &Self) -> ApllodbResult<Self> {
+        match (self, other) {
+            (NnSqlValue::Interval(i), n) if n.is_numeric() => {
+                i.divide(n.to_f64()?).map(Self::Interval)
+            }
+            _ => self.numeric_arithmetic(
+                other,
+                ArithmeticOperator::Divide,
+                i64::checked_div,
+                |l, r| l / r,
+                Decimal::divide,
+            ),
+        }
+    }
+
+    /// `self % other` (sign follows `self`)
+    ///
+    /// See [numeric_arithmetic()](Self::numeric_arithmetic) for type rules and failures.
+    pub(crate) fn modulo(&self, other: &Self) -> ApllodbResult<Self> {
+        self.numeric_arithmetic(
+            other,
+            ArithmeticOperator::Modulo,
+            i64::checked_rem,
+            |l, r| l % r,
+            Decimal::modulo,
+        )
+    }
+
+    /// Operands are converted into their unified type (see [SqlType::unified()](crate::SqlType::unified)) and the result is in that type:
+    ///
+    /// - Integer operands are widened to the wider type of the two (SMALLINT < INTEGER < BIGINT).
+    /// - Floating-point operands are widened to the wider type of the two (REAL < DOUBLE PRECISION).
+    ///   Either of them is DOUBLE PRECISION if the other is an integer or NUMERIC.
+    /// - Integer and NUMERIC operands are calculated in NUMERIC (see [Decimal](crate::Decimal) for the scale of the result).
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalOperation](apllodb_shared_components::SqlState::DataExceptionIllegalOperation) when:
+    ///   - either operand is not a number
+    /// - [DataExceptionDivisionByZero](apllodb_shared_components::SqlState::DataExceptionDivisionByZero) when:
+    ///   - `other` is 0 in `/` or `%`
+    /// - [DataExceptionNumericValueOutOfRange](apllodb_shared_components::SqlState::DataExceptionNumericValueOutOfRange) when:
+    ///   - the result does not fit in the result type
+    fn numeric_arithmetic(
+        &self,
+        other: &Self,
+        op: ArithmeticOperator,
+        i64_op: fn(i64, i64) -> Option<i64>,
+        f64_op: fn(f64, f64) -> f64,
+        decimal_op: fn(&Decimal, &Decimal) -> ApllodbResult<Decimal>,
+    ) -> ApllodbResult<Self> {
+        match self.sql_type().unified(&other.sql_type()) {
+            Ok(SqlType::NumericComparable(NumericComparableType::I64Loose(result_type))) => {
+                self.integer_arithmetic(other, op, i64_op, result_type)
+            }
+            Ok(SqlType::NumericComparable(NumericComparableType::F64Loose(result_type))) => {
+                self.float_arithmetic(other, op, f64_op, result_type)
+            }
+            Ok(SqlType::NumericComparable(NumericComparableType::Decimal(_))) => {
+                let (self_decimal, other_decimal) = (self.to_decimal()?, other.to_decimal()?);
+                decimal_op(&self_decimal, &other_decimal).map(Self::Numeric)
+            }
+            _ => Err(ApllodbError::data_exception_illegal_operation(format!(
+                "`{} {} {}` is not a numeric operation",
+                self, op, other
+            ))),
+        }
+    }
+
+    fn integer_arithmetic(
+        &self,
+        other: &Self,
+        op: ArithmeticOperator,
+        i64_op: fn(i64, i64) -> Option<i64>,
+        result_type: I64LooseType,
+    ) -> ApllodbResult<Self> {
+        let (self_i64, other_i64) = (self.unpack::<i64>()?, other.unpack::<i64>()?);
+
+        if other_i64 == 0 && op.is_division() {
+            return Err(ApllodbError::data_exception_division_by_zero(format!(
+                "`{} {} {}`",
+                self, op, other
+            )));
+        }
+
+        let out_of_range = || {
+            ApllodbError::data_exception_numeric_value_out_of_range(format!(
+                "`{} {} {}` is out of range of {:?}",
+                self, op, other, result_type
+            ))
+        };
+        let v = i64_op(self_i64, other_i64).ok_or_else(out_of_range)?;
+        match result_type {
+            I64LooseType::SmallInt => i16::try_from(v)
+                .map(Self::SmallInt)
+                .map_err(|_| out_of_range()),
+            I64LooseType::Integer => i32::try_from(v)
+                .map(Self::Integer)
+                .map_err(|_| out_of_range()),
+            I64LooseType::BigInt => Ok(Self::BigInt(v)),
+        }
+    }
+
+    fn float_arithmetic(
+        &self,
+        other: &Self,
+        op: ArithmeticOperator,
+        f64_op: fn(f64, f64) -> f64,
+        result_type: F64LooseType,
+    ) -> ApllodbResult<Self> {
+        let (self_f64, other_f64) = (self.to_f64()?, other.to_f64()?);
+
+        if other_f64 == 0.0 && op.is_division() {
+            return Err(ApllodbError::data_exception_division_by_zero(format!(
+                "`{} {} {}`",
+                self, op, other
+            )));
+        }
+
+        let v = f64_op(self_f64, other_f64);
+        let result = match result_type {
+            F64LooseType::Real => Self::Real(v as f32),
+            F64LooseType::DoublePrecision => Self::DoublePrecision(v),
+        };
+        // overflow into infinity
+        if self_f64.is_finite() && other_f64.is_finite() && !result.to_f64()?.is_finite() {
+            Err(ApllodbError::data_exception_numeric_value_out_of_range(
+                format!(
+                    "`{} {} {}` is out of range of {:?}",
+                    self, op, other, result_type
+                ),
+            ))
+        } else {
+            Ok(result)
+        }
+    }
+
+    fn is_numeric(&self) -> bool {
+        matches!(self.sql_type(), SqlType::NumericComparable(_))
+    }
+
+    fn is_integer(&self) -> bool {
+        matches!(
+            self.sql_type(),
+            SqlType::NumericComparable(NumericComparableType::I64Loose(_))
+        )
+    }
+
+    fn add_days(date: &NaiveDate, days: i64) -> ApllodbResult<Self> {
+        // Duration::days() panics on too many days, which are out of range of NaiveDate anyway
+        Some(days)
+            .filter(|days| days.abs() <= i32::MAX as i64)
+            .and_then(|days| date.checked_add_signed(Duration::days(days)))
+            .map(Self::Date)
+            .ok_or_else(|| {
+                ApllodbError::data_exception_datetime_field_overflow(format!(
+                    "`{} + {} days` is out of range of DATE",
+                    date, days
+                ))
+            })
+    }
+
+    /// Numeric value as f64 (possibly rounded).
+    fn to_f64(&self) -> ApllodbResult<f64> {
+        self.clone().cast(&SqlType::double_precision())?.unpack()
+    }
+
+    /// Integer or NUMERIC value as Decimal.
+    fn to_decimal(&self) -> ApllodbResult<Decimal> {
+        self.clone().cast(&SqlType::numeric())?.unpack()
+    }
+
+    /// Total order of f64 values, in which NaN is equal to itself and larger than any other values.
+    fn cmp_f64(l: f64, r: f64) -> Ordering {
+        l.partial_cmp(&r)
+            .unwrap_or_else(|| l.is_nan().cmp(&r.is_nan()))
+    }
+
+    /// Compares strings as if the shorter one is padded with trailing spaces (PAD SPACE), as CHAR values are compared in SQL.
+    fn cmp_pad_space(l: &str, r: &str) -> Ordering {
+        let len = l.chars().count().max(r.chars().count());
+        let padded = |s: &str| {
+            s.chars()
+                .chain(std::iter::repeat(' '))
+                .take(len)
+                .collect::<String>()
+        };
+        padded(l).cmp(&padded(r))
+    }
+
+    /// `self LIKE pattern`
+    ///
+    /// `%` in `pattern` matches any sequence of characters, `_` matches any single character,
+    /// and `\` escapes the following character.
+    /// Trailing spaces of CHAR values are ignored.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalOperation](apllodb_shared_components::SqlState::DataExceptionIllegalOperation) when:
+    ///   - either of `self` or `pattern` is not a string
+    pub(crate) fn like(&self, pattern: &Self) -> ApllodbResult<bool> {
+        fn as_str(v: &NnSqlValue) -> Option<&str> {
+            match v {
+                NnSqlValue::Text(s) => Some(s.as_str()),
+                NnSqlValue::Char(s) => Some(s.trim_end_matches(' ')),
+                _ => None,
+            }
+        }
+        match (as_str(self), as_str(pattern)) {
+            (Some(s), Some(pattern)) => {
+                let s: Vec<char> = s.chars().collect();
+                let pattern: Vec<char> = pattern.chars().collect();
+                Ok(Self::like_match(&s, &pattern))
+            }
+            _ => Err(ApllodbError::data_exception_illegal_operation(format!(
+                "`{} LIKE {}` is not a string operation",
+                self, pattern
+            ))),
+        }
+    }
+
+    fn like_match(s: &[char], pattern: &[char]) -> bool {
+        match pattern.split_first() {
+            None => s.is_empty(),
+            Some(('%', rest)) => (0..=s.len()).any(|i| Self::like_match(&s[i..], rest)),
+            Some(('_', rest)) => !s.is_empty() && Self::like_match(&s[1..], rest),
+            Some((c, rest)) => {
+                let (c, rest) = match (c, rest.split_first()) {
+                    ('\\', Some((escaped, rest))) => (escaped, rest),
+                    _ => (c, rest),
+                };
+                s.first() == Some(c) && Self::like_match(&s[1..], rest)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{ApllodbResult, NnSqlValue, SqlState};
+    use super::SqlCompareResult;
+    use crate::{ApllodbResult, ArrayValue, NnSqlValue, SqlState, SqlType, SqlValue};
+
+    #[test]
+    fn test_is_not_distinct_from() -> ApllodbResult<()> {
+        let fuzzy =
+            |s: &str| -> ApllodbResult<NnSqlValue> { Ok(NnSqlValue::FuzzyDate(s.parse()?)) };
+
+        // `=` is indefinite for ambiguous values, but they are not distinct from themselves
+        assert!(matches!(
+            fuzzy("1920s")?.sql_compare(&fuzzy("1920s")?)?,
+            SqlCompareResult::Indefinite { .. }
+        ));
+        assert!(fuzzy("1920s")?.is_not_distinct_from(&fuzzy("1920s")?));
+        assert!(fuzzy("1920s")?.is_not_distinct_from(&fuzzy("1920..1929")?));
+        assert!(!fuzzy("1920s")?.is_not_distinct_from(&fuzzy("1920")?));
+
+        let array = |elements: Vec<SqlValue>| -> ApllodbResult<NnSqlValue> {
+            Ok(NnSqlValue::Array(ArrayValue::from_elements(elements)?))
+        };
+        assert!(
+            array(vec![SqlValue::NotNull(fuzzy("1920s")?), SqlValue::Null])?.is_not_distinct_from(
+                &array(vec![SqlValue::NotNull(fuzzy("1920s")?), SqlValue::Null])?
+            )
+        );
+
+        assert!(NnSqlValue::SmallInt(1).is_not_distinct_from(&NnSqlValue::BigInt(1)));
+        assert!(!NnSqlValue::Integer(1).is_not_distinct_from(&NnSqlValue::Text("1".to_string())));
+
+        Ok(())
+    }
 
     #[test]
     fn test_unpack_loosely() -> ApllodbResult<()> {
@@ -203,4 +915,402 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_integer_arithmetic() -> ApllodbResult<()> {
+        // widened to the wider type
+        assert!(matches!(
+            NnSqlValue::SmallInt(1).add(&NnSqlValue::SmallInt(2))?,
+            NnSqlValue::SmallInt(3)
+        ));
+        assert!(matches!(
+            NnSqlValue::SmallInt(1).add(&NnSqlValue::Integer(2))?,
+            NnSqlValue::Integer(3)
+        ));
+        assert!(matches!(
+            NnSqlValue::BigInt(7).subtract(&NnSqlValue::SmallInt(2))?,
+            NnSqlValue::BigInt(5)
+        ));
+        assert!(matches!(
+            NnSqlValue::Integer(-7).multiply(&NnSqlValue::Integer(3))?,
+            NnSqlValue::Integer(-21)
+        ));
+        assert!(matches!(
+            NnSqlValue::Integer(-7).divide(&NnSqlValue::Integer(2))?,
+            NnSqlValue::Integer(-3)
+        ));
+        assert!(matches!(
+            NnSqlValue::Integer(-7).modulo(&NnSqlValue::Integer(2))?,
+            NnSqlValue::Integer(-1)
+        ));
+
+        // out of range in the result type
+        assert_eq!(
+            NnSqlValue::SmallInt(i16::MAX)
+                .add(&NnSqlValue::SmallInt(1))
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionNumericValueOutOfRange
+        );
+        assert!(matches!(
+            NnSqlValue::SmallInt(i16::MAX).add(&NnSqlValue::Integer(1))?,
+            NnSqlValue::Integer(32768)
+        ));
+        assert_eq!(
+            NnSqlValue::Integer(i32::MIN)
+                .divide(&NnSqlValue::Integer(-1))
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionNumericValueOutOfRange
+        );
+        assert_eq!(
+            NnSqlValue::BigInt(i64::MAX)
+                .multiply(&NnSqlValue::SmallInt(2))
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionNumericValueOutOfRange
+        );
+        assert_eq!(
+            NnSqlValue::SmallInt(i16::MIN).negate().unwrap_err().kind(),
+            &SqlState::DataExceptionNumericValueOutOfRange
+        );
+
+        // division by zero
+        assert_eq!(
+            NnSqlValue::Integer(1)
+                .divide(&NnSqlValue::SmallInt(0))
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionDivisionByZero
+        );
+        assert_eq!(
+            NnSqlValue::Integer(1)
+                .modulo(&NnSqlValue::BigInt(0))
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionDivisionByZero
+        );
+
+        // non-integer operands
+        assert_eq!(
+            NnSqlValue::Integer(1)
+                .add(&NnSqlValue::Text("a".to_string()))
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_non_integer_arithmetic() -> ApllodbResult<()> {
+        let numeric = |s: &str| NnSqlValue::Numeric(s.parse().unwrap());
+
+        assert!(matches!(
+            NnSqlValue::Real(1.5).add(&NnSqlValue::Real(1.0))?,
+            NnSqlValue::Real(v) if v == 2.5
+        ));
+        assert!(matches!(
+            NnSqlValue::Real(1.5).multiply(&NnSqlValue::DoublePrecision(2.0))?,
+            NnSqlValue::DoublePrecision(v) if v == 3.0
+        ));
+        assert!(matches!(
+            NnSqlValue::Integer(1).divide(&NnSqlValue::DoublePrecision(4.0))?,
+            NnSqlValue::DoublePrecision(v) if v == 0.25
+        ));
+        assert_eq!(
+            numeric("1.10").add(&NnSqlValue::Integer(2))?.to_string(),
+            "3.10"
+        );
+        assert_eq!(
+            NnSqlValue::SmallInt(1).divide(&numeric("3"))?.to_string(),
+            "0.3333333333333333"
+        );
+        assert!(matches!(
+            numeric("0.5").multiply(&NnSqlValue::DoublePrecision(3.0))?,
+            NnSqlValue::DoublePrecision(v) if v == 1.5
+        ));
+        assert_eq!(numeric("1.5").negate()?.to_string(), "-1.5");
+
+        assert_eq!(
+            NnSqlValue::Real(f32::MAX)
+                .multiply(&NnSqlValue::Real(2.0))
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionNumericValueOutOfRange
+        );
+        assert_eq!(
+            NnSqlValue::DoublePrecision(1.0)
+                .divide(&NnSqlValue::Integer(0))
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionDivisionByZero
+        );
+        assert_eq!(
+            numeric("1.0")
+                .modulo(&NnSqlValue::Integer(0))
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionDivisionByZero
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_numeric_compare_and_hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let hash = |v: &NnSqlValue| {
+            let mut hasher = DefaultHasher::new();
+            v.hash(&mut hasher);
+            hasher.finish()
+        };
+        let numeric = |s: &str| NnSqlValue::Numeric(s.parse().unwrap());
+
+        let equal_values = vec![
+            NnSqlValue::SmallInt(42),
+            NnSqlValue::BigInt(42),
+            NnSqlValue::Real(42.0),
+            NnSqlValue::DoublePrecision(42.0),
+            numeric("42.00"),
+        ];
+        for l in &equal_values {
+            for r in &equal_values {
+                assert_eq!(l, r);
+                assert_eq!(hash(l), hash(r));
+            }
+        }
+
+        assert_eq!(NnSqlValue::DoublePrecision(-0.0), numeric("0"));
+        assert_eq!(
+            hash(&NnSqlValue::DoublePrecision(-0.0)),
+            hash(&numeric("0"))
+        );
+        // 0.1 in REAL is not exactly 0.1
+        assert_ne!(NnSqlValue::Real(0.1), numeric("0.1"));
+        assert_eq!(NnSqlValue::DoublePrecision(0.1), numeric("0.1"));
+    }
+
+    #[test]
+    fn test_datetime_arithmetic() -> ApllodbResult<()> {
+        let date = |s: &str| {
+            NnSqlValue::Text(s.to_string())
+                .cast(&SqlType::date())
+                .unwrap()
+        };
+        let time = |s: &str| {
+            NnSqlValue::Text(s.to_string())
+                .cast(&SqlType::time())
+                .unwrap()
+        };
+        let timestamp = |s: &str| {
+            NnSqlValue::Text(s.to_string())
+                .cast(&SqlType::timestamp())
+                .unwrap()
+        };
+        let timestamptz = |s: &str| {
+            NnSqlValue::Text(s.to_string())
+                .cast(&SqlType::timestamp_with_time_zone())
+                .unwrap()
+        };
+        let interval = |s: &str| NnSqlValue::Interval(s.parse().unwrap());
+
+        assert_eq!(
+            date("2021-02-28").add(&NnSqlValue::Integer(1))?.to_string(),
+            "2021-03-01"
+        );
+        assert_eq!(
+            NnSqlValue::SmallInt(-1)
+                .add(&date("2021-01-01"))?
+                .to_string(),
+            "2020-12-31"
+        );
+        assert_eq!(
+            date("2021-01-01")
+                .subtract(&NnSqlValue::BigInt(i64::MIN))
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionDatetimeFieldOverflow
+        );
+        assert!(matches!(
+            date("2021-03-01").subtract(&date("2020-03-01"))?,
+            NnSqlValue::Integer(365)
+        ));
+
+        assert_eq!(
+            date("2021-01-31")
+                .add(&interval("1 mon 1 hour"))?
+                .to_string(),
+            "2021-02-28 01:00:00"
+        );
+        assert_eq!(
+            date("2021-01-01").add(&time("12:34:56"))?.to_string(),
+            "2021-01-01 12:34:56"
+        );
+        assert_eq!(
+            interval("1 day")
+                .add(&timestamp("2021-01-01 23:00:00"))?
+                .to_string(),
+            "2021-01-02 23:00:00"
+        );
+        assert_eq!(
+            timestamptz("2021-01-01 00:00:00+09:00")
+                .subtract(&interval("1 year"))?
+                .to_string(),
+            "2019-12-31 15:00:00+00:00"
+        );
+        assert_eq!(
+            time("23:00").add(&interval("2 hours"))?.to_string(),
+            "01:00:00"
+        );
+        assert_eq!(
+            time("01:00").subtract(&time("02:30"))?.to_string(),
+            "-01:30:00"
+        );
+        assert_eq!(
+            timestamp("2021-03-01 00:00:00")
+                .subtract(&timestamp("2021-02-27 12:00:00"))?
+                .to_string(),
+            "1 day 12:00:00"
+        );
+        assert_eq!(
+            date("2021-01-02")
+                .subtract(&timestamptz("2021-01-01 12:00:00Z"))?
+                .to_string(),
+            "12:00:00"
+        );
+        assert_eq!(
+            interval("1 day")
+                .subtract(&interval("1 hour"))?
+                .multiply(&NnSqlValue::Integer(2))?
+                .to_string(),
+            "2 days -02:00:00"
+        );
+        assert_eq!(
+            interval("1 day")
+                .divide(&NnSqlValue::DoublePrecision(3.0))?
+                .to_string(),
+            "08:00:00"
+        );
+        assert_eq!(interval("1 day").negate()?.to_string(), "-1 days");
+
+        assert_eq!(
+            date("2021-01-01")
+                .add(&date("2021-01-01"))
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+        assert_eq!(
+            timestamp("2021-01-01 00:00:00")
+                .add(&NnSqlValue::Integer(1))
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_datetime_compare_and_hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let hash = |v: &NnSqlValue| {
+            let mut hasher = DefaultHasher::new();
+            v.hash(&mut hasher);
+            hasher.finish()
+        };
+        let cast =
+            |s: &str, sql_type: SqlType| NnSqlValue::Text(s.to_string()).cast(&sql_type).unwrap();
+
+        let equal_values = vec![
+            cast("2021-01-01", SqlType::date()),
+            cast("2021-01-01 00:00:00", SqlType::timestamp()),
+            cast(
+                "2021-01-01 09:00:00+09:00",
+                SqlType::timestamp_with_time_zone(),
+            ),
+        ];
+        for l in &equal_values {
+            for r in &equal_values {
+                assert_eq!(l, r);
+                assert_eq!(hash(l), hash(r));
+            }
+        }
+
+        assert!(
+            cast("2021-01-01", SqlType::date())
+                .sql_compare(&cast("2021-01-01 00:00:01", SqlType::timestamp()))
+                .unwrap()
+                == SqlCompareResult::LessThan
+        );
+        assert_eq!(
+            NnSqlValue::Interval("1 mon".parse().unwrap()),
+            NnSqlValue::Interval("30 days".parse().unwrap())
+        );
+        assert_eq!(
+            cast("12:00", SqlType::time())
+                .sql_compare(&cast("2021-01-01", SqlType::date()))
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalComparison
+        );
+    }
+
+    #[test]
+    fn test_char_compare_and_hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let hash = |v: &NnSqlValue| {
+            let mut hasher = DefaultHasher::new();
+            v.hash(&mut hasher);
+            hasher.finish()
+        };
+        let text = |s: &str| NnSqlValue::Text(s.to_string());
+        let char = |s: &str| NnSqlValue::Char(s.to_string());
+
+        // PAD SPACE
+        assert_eq!(char("ab  "), text("ab"));
+        assert_eq!(char("ab  "), char("ab"));
+        assert_eq!(hash(&char("ab  ")), hash(&text("ab")));
+        assert!(
+            char("a").sql_compare(&text("a\t")).unwrap() == SqlCompareResult::GreaterThan,
+            "'a' is padded into 'a ', which is greater than 'a\\t'"
+        );
+        // TEXT is compared as it is
+        assert_ne!(text("ab  "), text("ab"));
+    }
+
+    #[test]
+    fn test_like() -> ApllodbResult<()> {
+        let like = |s: &str, pattern: &str| {
+            NnSqlValue::Text(s.to_string()).like(&NnSqlValue::Text(pattern.to_string()))
+        };
+
+        assert!(like("foobar", "foo%")?);
+        assert!(like("foo", "foo%")?);
+        assert!(!like("xfoo", "foo%")?);
+        assert!(like("abc", "a_c")?);
+        assert!(!like("ac", "a_c")?);
+        assert!(like("abcbc", "%b_")?);
+        assert!(!like("abc", "ABC")?);
+        assert!(like("100%", "100\\%")?);
+        assert!(!like("1000", "100\\%")?);
+        assert!(like("", "%")?);
+        assert!(NnSqlValue::Char("ab  ".to_string()).like(&NnSqlValue::Text("%b".to_string()))?);
+
+        assert_eq!(
+            NnSqlValue::Integer(1)
+                .like(&NnSqlValue::Text("1".to_string()))
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+
+        Ok(())
+    }
 }
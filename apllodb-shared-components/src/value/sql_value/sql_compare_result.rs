@@ -22,12 +22,67 @@ pub enum SqlCompareResult {
 
     /// Either of v1 or v2 is NULL.
     Null,
+
+    /// Whether v1 < v2, v1 = v2, or v1 > v2 depends on the actual values of ambiguous ones (e.g. overlapping [FuzzyDate](crate::FuzzyDate)s).
+    /// v1 = v2 is always possible.
+    Indefinite {
+        /// Whether v1 < v2 is possible.
+        possibly_less: bool,
+        /// Whether v1 > v2 is possible.
+        possibly_greater: bool,
+    },
 }
+/// `is_*` methods tell whether the relation definitely holds.
+/// For Self::Indefinite, only `<=` (when v1 > v2 is impossible) and `>=` (when v1 < v2 is impossible) can definitely hold.
 impl SqlCompareResult {
     /// Whether self is Self::Eq
     pub fn is_equal(&self) -> bool {
         matches!(self, SqlCompareResult::Eq)
     }
+
+    /// Whether self is Self::NotEq, Self::LessThan, or Self::GreaterThan
+    pub fn is_not_equal(&self) -> bool {
+        matches!(
+            self,
+            SqlCompareResult::NotEq | SqlCompareResult::LessThan | SqlCompareResult::GreaterThan
+        )
+    }
+
+    /// Whether self is Self::LessThan
+    pub fn is_less_than(&self) -> bool {
+        matches!(self, SqlCompareResult::LessThan)
+    }
+
+    /// Whether self is Self::LessThan or Self::Eq, or v1 > v2 is impossible
+    pub fn is_less_than_or_equal(&self) -> bool {
+        matches!(
+            self,
+            SqlCompareResult::LessThan
+                | SqlCompareResult::Eq
+                | SqlCompareResult::Indefinite {
+                    possibly_greater: false,
+                    ..
+                }
+        )
+    }
+
+    /// Whether self is Self::GreaterThan
+    pub fn is_greater_than(&self) -> bool {
+        matches!(self, SqlCompareResult::GreaterThan)
+    }
+
+    /// Whether self is Self::GreaterThan or Self::Eq, or v1 < v2 is impossible
+    pub fn is_greater_than_or_equal(&self) -> bool {
+        matches!(
+            self,
+            SqlCompareResult::GreaterThan
+                | SqlCompareResult::Eq
+                | SqlCompareResult::Indefinite {
+                    possibly_less: false,
+                    ..
+                }
+        )
+    }
 }
 
 impl From<Ordering> for SqlCompareResult {
@@ -37,4 +37,41 @@ impl ApllodbSqlParser {
     pub fn parse<S: Into<String>>(&self, apllodb_sql: S) -> ApllodbSqlParserResult<ApllodbAst> {
         self.0.parse(apllodb_sql)
     }
+
+    /// Parses semicolon-separated apllodb-SQLs (e.g. a migration file) into apllodb-ASTs in order.
+    ///
+    /// Empty statements are ignored, so an empty script (or a script only with comments) results in no AST.
+    ///
+    /// # Panics
+    ///
+    /// Only when internal implementation encounters a bug.
+    /// Please report to the authors.
+    ///
+    /// # Failures
+    ///
+    /// When failed to parse any statement in input str as apllodb-SQL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use apllodb_sql_parser::ApllodbSqlParser;
+    ///
+    /// let parser = ApllodbSqlParser::default();
+    /// let asts = parser
+    ///     .parse_script(
+    ///         "
+    ///         -- create a table
+    ///         CREATE TABLE people (id INTEGER, PRIMARY KEY (id));
+    ///         DROP TABLE people; /* and drop it */
+    ///         ",
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(asts.len(), 2);
+    /// ```
+    pub fn parse_script<S: Into<String>>(
+        &self,
+        apllodb_sql: S,
+    ) -> ApllodbSqlParserResult<Vec<ApllodbAst>> {
+        self.0.parse_script(apllodb_sql)
+    }
 }
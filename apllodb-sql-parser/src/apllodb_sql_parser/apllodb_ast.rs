@@ -37,23 +37,76 @@ pub struct ApllodbAst(pub Command);
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Constant {
     NullVariant,
+    BooleanConstantVariant(BooleanConstant),
     NumericConstantVariant(NumericConstant),
     StringConstantVariant(StringConstant),
+    DatetimeConstantVariant(DatetimeConstant),
+    BinaryConstantVariant(BinaryConstant),
+    JsonConstantVariant(JsonConstant),
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BooleanConstant {
+    TrueVariant,
+    FalseVariant,
 }
 
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NumericConstant {
     IntegerConstantVariant(IntegerConstant),
+    DecimalConstantVariant(DecimalConstant),
+    FloatConstantVariant(FloatConstant),
 }
 
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IntegerConstant(pub String);
 
+/// Number with a decimal point (e.g. `1.50`).
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DecimalConstant(pub String);
+
+/// Number with an exponent (e.g. `1.5e3`).
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct StringConstant(pub String);
+pub struct FloatConstant(pub String);
+
+/// Holds the raw content between the quotes; escape sequences are not yet interpreted.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum StringConstant {
+    /// `'...'`, in which `''` stands for a single quote.
+    StandardVariant(String),
+
+    /// `E'...'`, in which backslash escape sequences (`\n`, `\'`, ...) are also available.
+    EscapeVariant(String),
+}
+
+/// Typed string literal of a date/time type (e.g. `DATE '2021-01-01'`).
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DatetimeConstant {
+    pub datetime_type: DatetimeType,
+    pub string_constant: StringConstant,
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BinaryConstant {
+    /// `X'01ff'`. Holds the raw content between the quotes, which is not yet validated as hex digits.
+    HexVariant(String),
+
+    /// `BYTEA '...'`, whose string is in hex (`\x01ff`) or escape (`ab\000`) format.
+    TypedVariant(StringConstant),
+}
+
+/// Typed string literal of JSON (e.g. `JSON '{"a": 1}'`), whose string is not yet validated as JSON text.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct JsonConstant(pub StringConstant);
 
 /*
  * ----------------------------------------------------------------------------
@@ -65,12 +118,33 @@ pub struct StringConstant(pub String);
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum UnaryOperator {
     Minus,
+    Not,
+    /// `POSSIBLY condition`: whether `condition` can be true for fuzzy values
+    Possibly,
+    IsNull,
+    IsNotNull,
 }
 
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BinaryOperator {
     Equal,
+    NotEqual,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    And,
+    Or,
+    Plus,
+    Minus,
+    Multiply,
+    Divide,
+    Modulo,
+    /// `json -> key_or_index`
+    JsonExtract,
+    /// `json ->> key_or_index`
+    JsonExtractText,
 }
 
 /*
@@ -79,10 +153,24 @@ pub enum BinaryOperator {
  * ================================================================================================
  */
 
+/// Name of database objects (tables, columns, ...), which is compared case-sensitively.
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Identifier(pub String);
 
+impl Identifier {
+    /// Unquoted identifier (`Abc`) is case-insensitive: it is folded into lower case (`abc`).
+    pub fn from_unquoted(s: &str) -> Self {
+        Self(s.to_lowercase())
+    }
+
+    /// Quoted identifier (`"Abc"`) preserves its case (`Abc`).
+    /// `content` is the string between double quotes, in which `""` stands for a double quote.
+    pub fn from_quoted(content: &str) -> Self {
+        Self(content.replace("\"\"", "\""))
+    }
+}
+
 /*
  * ================================================================================================
  * Value Expressions:
@@ -102,6 +190,175 @@ pub enum Expression {
     ColumnReferenceVariant(ColumnReference),
     UnaryOperatorVariant(UnaryOperator, Box<Expression>),
     BinaryOperatorVariant(BinaryOperator, Box<Expression>, Box<Expression>),
+    InPredicateVariant(InPredicate),
+    BetweenPredicateVariant(BetweenPredicate),
+    LikePredicateVariant(LikePredicate),
+    FunctionCallVariant(FunctionCall),
+    CaseExpressionVariant(CaseExpression),
+    MatchExpressionVariant(MatchExpression),
+    /// `CAST(expression AS data_type)` or `expression::data_type`
+    CastVariant(Box<Expression>, DataType),
+    CurrentDatetimeFunctionVariant(CurrentDatetimeFunction),
+    /// `ARRAY[expression, ...]`
+    ArrayConstructorVariant(Vec<Expression>),
+    /// `array[index]`
+    SubscriptVariant(Box<Expression>, Box<Expression>),
+    /// `expression comparison_operator ANY(array)`
+    AnyComparisonVariant(BinaryOperator, Box<Expression>, Box<Expression>),
+}
+
+/*
+ * ----------------------------------------------------------------------------
+ * Function Calls
+ * ----------------------------------------------------------------------------
+ */
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FunctionCall {
+    pub function_name: FunctionName,
+    pub arguments: FunctionArguments,
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FunctionArguments {
+    /// `f(*)`
+    AsteriskVariant,
+    /// `f([ALL | DISTINCT] expression, ...)`
+    ExpressionsVariant {
+        set_quantifier: Option<SetQuantifier>,
+        expressions: Vec<Expression>,
+    },
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SetQuantifier {
+    AllVariant,
+    DistinctVariant,
+}
+
+/*
+ * ----------------------------------------------------------------------------
+ * Current Date/Time
+ * ----------------------------------------------------------------------------
+ */
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CurrentDatetimeFunction {
+    /// `CURRENT_DATE`
+    CurrentDateVariant,
+    /// `CURRENT_TIMESTAMP`
+    CurrentTimestampVariant,
+}
+
+/*
+ * ----------------------------------------------------------------------------
+ * Conditional Expressions
+ * ----------------------------------------------------------------------------
+ */
+
+/// `CASE [operand] WHEN ... THEN ... [ELSE ...] END`
+///
+/// Simple CASE has `operand` and searched CASE does not.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CaseExpression {
+    pub operand: Option<Box<Expression>>,
+    pub when_clauses: NonEmptyVec<WhenClause>,
+    pub else_result: Option<Box<Expression>>,
+}
+
+/// `WHEN expression THEN result`
+///
+/// `expression` is a condition in searched CASE and a value compared with the operand in simple CASE.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WhenClause {
+    pub expression: Expression,
+    pub result: Expression,
+}
+
+/*
+ * ----------------------------------------------------------------------------
+ * Pattern Matching
+ * ----------------------------------------------------------------------------
+ */
+
+/// `MATCH operand WITH pattern -> result | ... END`
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MatchExpression {
+    pub operand: Box<Expression>,
+    pub match_arms: NonEmptyVec<MatchArm>,
+}
+
+/// `pattern -> result`
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MatchArm {
+    pub pattern: MatchPattern,
+    pub result: Expression,
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MatchPattern {
+    /// `_`
+    WildcardVariant,
+    VariantPatternVariant(VariantPattern),
+}
+
+/// `variant_name [(binding, ...)]`
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VariantPattern {
+    pub variant_name: VariantName,
+    pub pattern_bindings: Vec<PatternBinding>,
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PatternBinding {
+    /// `_`
+    WildcardVariant,
+    BindingNameVariant(BindingName),
+}
+
+/*
+ * ----------------------------------------------------------------------------
+ * Predicates
+ * ----------------------------------------------------------------------------
+ */
+
+/// `expression [NOT] IN (list...)`
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InPredicate {
+    pub negated: bool,
+    pub expression: Box<Expression>,
+    pub list: NonEmptyVec<Expression>,
+}
+
+/// `expression [NOT] BETWEEN low AND high`
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BetweenPredicate {
+    pub negated: bool,
+    pub expression: Box<Expression>,
+    pub low: Box<Expression>,
+    pub high: Box<Expression>,
+}
+
+/// `expression [NOT] LIKE pattern`
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LikePredicate {
+    pub negated: bool,
+    pub expression: Box<Expression>,
+    pub pattern: Box<Expression>,
 }
 
 /*
@@ -127,7 +384,17 @@ pub struct ColumnReference {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DataType {
     IntegerTypeVariant(IntegerType),
+    FloatingPointTypeVariant(FloatingPointType),
+    DecimalTypeVariant(DecimalType),
     CharacterTypeVariant(CharacterType),
+    BooleanTypeVariant(BooleanType),
+    BinaryTypeVariant(BinaryType),
+    JsonTypeVariant(JsonType),
+    DatetimeTypeVariant(DatetimeType),
+    /// Type defined by CREATE TYPE
+    UserDefinedTypeVariant(TypeName),
+    /// `data_type[]`
+    ArrayTypeVariant(Box<DataType>),
 }
 
 /*
@@ -144,6 +411,33 @@ pub enum IntegerType {
     BigIntVariant,
 }
 
+/*
+ * ----------------------------------------------------------------------------
+ * Floating-Point Types
+ * ----------------------------------------------------------------------------
+ */
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FloatingPointType {
+    RealVariant,
+    DoublePrecisionVariant,
+}
+
+/*
+ * ----------------------------------------------------------------------------
+ * Arbitrary Precision Numbers
+ * ----------------------------------------------------------------------------
+ */
+
+/// `NUMERIC [(precision [, scale])]` or `DECIMAL [(precision [, scale])]`
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DecimalType {
+    pub precision: Option<IntegerConstant>,
+    pub scale: Option<IntegerConstant>,
+}
+
 /*
  * ----------------------------------------------------------------------------
  * Character Types
@@ -154,6 +448,71 @@ pub enum IntegerType {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CharacterType {
     TextVariant,
+
+    /// `VARCHAR(length)` or `CHARACTER VARYING(length)`
+    VarcharVariant(IntegerConstant),
+
+    /// `CHAR[(length)]` or `CHARACTER[(length)]`
+    CharVariant(Option<IntegerConstant>),
+}
+
+/*
+ * ----------------------------------------------------------------------------
+ * Boolean Type
+ * ----------------------------------------------------------------------------
+ */
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BooleanType {
+    /// `BOOLEAN` or `BOOL`
+    BooleanVariant,
+}
+
+/*
+ * ----------------------------------------------------------------------------
+ * Binary Data Types
+ * ----------------------------------------------------------------------------
+ */
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BinaryType {
+    /// `BYTEA` or `BLOB`
+    ByteaVariant,
+}
+
+/*
+ * ----------------------------------------------------------------------------
+ * JSON Types
+ * ----------------------------------------------------------------------------
+ */
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JsonType {
+    JsonVariant,
+}
+
+/*
+ * ----------------------------------------------------------------------------
+ * Date/Time Types
+ * ----------------------------------------------------------------------------
+ */
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DatetimeType {
+    DateVariant,
+    /// `TIME [WITHOUT TIME ZONE]`
+    TimeVariant,
+    /// `TIMESTAMP [WITHOUT TIME ZONE]`
+    TimestampVariant,
+    /// `TIMESTAMP WITH TIME ZONE`
+    TimestampWithTimeZoneVariant,
+    IntervalVariant,
+    /// `FUZZY DATE`
+    FuzzyDateVariant,
 }
 
 /*
@@ -176,6 +535,8 @@ pub enum Command {
     CreateTableCommandVariant(CreateTableCommand),
     DropTableCommandVariant(DropTableCommand),
 
+    CreateTypeCommandVariant(CreateTypeCommand),
+
     SelectCommandVariant(SelectCommand),
     InsertCommandVariant(InsertCommand),
     UpdateCommandVariant(UpdateCommand),
@@ -251,6 +612,35 @@ pub struct CreateTableCommand {
     pub table_elements: NonEmptyVec<TableElement>,
 }
 
+/*
+ * ----------------------------------------------------------------------------
+ * CREATE TYPE
+ * ----------------------------------------------------------------------------
+ */
+
+/// `CREATE TYPE type_name AS {ENUM | VARIANT} (variant_definition, ...)`
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CreateTypeCommand {
+    pub type_name: TypeName,
+    pub variant_definitions: NonEmptyVec<VariantDefinition>,
+}
+
+/// `variant_name [(field_name data_type, ...)]`
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VariantDefinition {
+    pub variant_name: VariantName,
+    pub field_definitions: Vec<FieldDefinition>,
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FieldDefinition {
+    pub field_name: FieldName,
+    pub data_type: DataType,
+}
+
 /*
  * ----------------------------------------------------------------------------
  * DELETE
@@ -307,12 +697,38 @@ pub struct InsertValue {
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SelectCommand {
+    /// `SELECT DISTINCT` or `SELECT ALL`
+    pub set_quantifier: Option<SetQuantifier>,
     pub select_fields: NonEmptyVec<SelectField>,
     pub from_item: Option<FromItem>,
     pub where_condition: Option<Condition>,
     pub grouping_elements: Option<NonEmptyVec<GroupingElement>>,
     pub having_conditions: Option<NonEmptyVec<Condition>>,
+    /// Applied from left to right in the written order (operator precedence is not considered here).
+    pub set_operations: Vec<SetOperation>,
+    /// Applied to the result of `set_operations`.
     pub order_bys: Option<NonEmptyVec<OrderBy>>,
+    /// Applied to the result of `set_operations`.
+    pub limit: Option<Limit>,
+}
+
+/// `UNION [ALL | DISTINCT] select`, ...
+///
+/// `select` does not have set operations, ORDER BY nor LIMIT.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SetOperation {
+    pub set_operator: SetOperator,
+    pub set_quantifier: Option<SetQuantifier>,
+    pub select_command: SelectCommand,
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SetOperator {
+    UnionVariant,
+    IntersectVariant,
+    ExceptVariant,
 }
 
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
@@ -333,7 +749,8 @@ pub enum FromItem {
         join_type: JoinType,
         left: Box<FromItem>,
         right: Box<FromItem>,
-        on: Condition,
+        /// `None` for CROSS JOIN.
+        on: Option<Condition>,
     },
 }
 
@@ -341,6 +758,11 @@ pub enum FromItem {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum JoinType {
     InnerJoin,
+    LeftOuterJoin,
+    RightOuterJoin,
+    FullOuterJoin,
+    /// `CROSS JOIN` or `,`
+    CrossJoin,
 }
 
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
@@ -363,6 +785,15 @@ pub enum Ordering {
     DescVariant,
 }
 
+/// `LIMIT count OFFSET offset` or `OFFSET offset ROWS FETCH FIRST count ROWS ONLY`
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Limit {
+    /// `None` for `OFFSET offset` only (all the rest records are returned).
+    pub count: Option<IntegerConstant>,
+    pub offset: Option<IntegerConstant>,
+}
+
 /*
  * ----------------------------------------------------------------------------
  * UPDATE
@@ -411,6 +842,27 @@ pub struct Alias(pub Identifier);
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Correlation(pub Identifier);
 
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FunctionName(pub Identifier);
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TypeName(pub Identifier);
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VariantName(pub Identifier);
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FieldName(pub Identifier);
+
+/// Name bound to a field in a pattern of MATCH.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BindingName(pub Identifier);
+
 /*
  * ----------------------------------------------------------------------------
  * Table Elements
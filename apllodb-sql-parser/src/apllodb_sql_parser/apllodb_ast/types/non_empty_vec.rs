@@ -17,6 +17,11 @@ impl<T> NonEmptyVec<T> {
         &self.0
     }
 
+    /// Mutable iterator over elements. Elements can be modified in place but not added or removed.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.0.iter_mut()
+    }
+
     /// Moves ownership of internal Vec.
     pub fn into_vec(self) -> Vec<T> {
         self.0
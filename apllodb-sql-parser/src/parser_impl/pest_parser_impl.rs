@@ -5,13 +5,20 @@ mod helper;
 
 use crate::{
     apllodb_ast::{
-        types::NonEmptyVec, Action, AddColumn, Alias, AlterTableCommand, BinaryOperator,
-        CharacterType, ColumnConstraint, ColumnDefinition, ColumnName, ColumnReference, Command,
-        Condition, Constant, Correlation, CreateDatabaseCommand, CreateTableCommand, DataType,
-        DatabaseName, DeleteCommand, DropColumn, DropTableCommand, Expression, FromItem,
-        Identifier, InsertCommand, InsertValue, IntegerConstant, IntegerType, JoinType,
-        NumericConstant, OrderBy, Ordering, SelectCommand, SelectField, StringConstant,
-        TableConstraint, TableElement, TableName, UnaryOperator, UpdateCommand, UseDatabaseCommand,
+        types::NonEmptyVec, Action, AddColumn, Alias, AlterTableCommand, BetweenPredicate,
+        BinaryConstant, BinaryOperator, BinaryType, BindingName, BooleanConstant, BooleanType,
+        CaseExpression, CharacterType, ColumnConstraint, ColumnDefinition, ColumnName,
+        ColumnReference, Command, Condition, Constant, Correlation, CreateDatabaseCommand,
+        CreateTableCommand, CreateTypeCommand, CurrentDatetimeFunction, DataType, DatabaseName,
+        DatetimeConstant, DatetimeType, DecimalConstant, DecimalType, DeleteCommand, DropColumn,
+        DropTableCommand, Expression, FieldDefinition, FieldName, FloatConstant, FloatingPointType,
+        FromItem, FunctionArguments, FunctionCall, FunctionName, GroupingElement, Identifier,
+        InPredicate, InsertCommand, InsertValue, IntegerConstant, IntegerType, JoinType,
+        JsonConstant, JsonType, LikePredicate, Limit, MatchArm, MatchExpression, MatchPattern,
+        NumericConstant, OrderBy, Ordering, PatternBinding, SelectCommand, SelectField,
+        SetOperation, SetOperator, SetQuantifier, StringConstant, TableConstraint, TableElement,
+        TableName, TypeName, UnaryOperator, UpdateCommand, UseDatabaseCommand, VariantDefinition,
+        VariantName, VariantPattern, WhenClause,
     },
     apllodb_sql_parser::error::{ApllodbSqlParserError, ApllodbSqlParserResult},
     ApllodbAst,
@@ -30,20 +37,35 @@ impl PestParserImpl {
         apllodb_sql: S,
     ) -> ApllodbSqlParserResult<ApllodbAst> {
         let apllodb_sql = apllodb_sql.into();
+        let mut params = Self::generated_parse(Rule::command, &apllodb_sql)?;
+        parse_child(&mut params, Rule::command, Self::parse_command, ApllodbAst)
+    }
+
+    pub(crate) fn parse_script<S: Into<String>>(
+        &self,
+        apllodb_sql: S,
+    ) -> ApllodbSqlParserResult<Vec<ApllodbAst>> {
+        let apllodb_sql = apllodb_sql.into();
+        let mut params = Self::generated_parse(Rule::script, &apllodb_sql)?;
+        parse_child(
+            &mut params,
+            Rule::script,
+            Self::parse_script_commands,
+            identity,
+        )
+    }
 
-        let pairs: Pairs<Rule> =
-            GeneratedParser::parse(Rule::command, &apllodb_sql).map_err(|e| {
-                let reason = format!("{}", e);
-                ApllodbSqlParserError::new(&apllodb_sql, reason)
-            })?;
+    fn generated_parse(rule: Rule, apllodb_sql: &str) -> ApllodbSqlParserResult<FnParseParams<'_>> {
+        let pairs: Pairs<Rule> = GeneratedParser::parse(rule, apllodb_sql).map_err(|e| {
+            let reason = format!("{}", e);
+            ApllodbSqlParserError::new(apllodb_sql, reason)
+        })?;
 
-        let mut params = FnParseParams {
-            apllodb_sql: &apllodb_sql,
+        Ok(FnParseParams {
+            apllodb_sql,
             children_pairs: pairs.collect(),
-            self_string: apllodb_sql.clone(),
-        };
-
-        parse_child(&mut params, Rule::command, Self::parse_command, ApllodbAst)
+            self_string: apllodb_sql.to_string(),
+        })
     }
 }
 
@@ -67,6 +89,12 @@ impl PestParserImpl {
             |_| Ok(Constant::NullVariant),
             identity,
         )?
+        .or(try_parse_child(
+            &mut params,
+            Rule::boolean_constant,
+            Self::parse_boolean_constant,
+            Constant::BooleanConstantVariant,
+        )?)
         .or(try_parse_child(
             &mut params,
             Rule::numeric_constant,
@@ -79,6 +107,24 @@ impl PestParserImpl {
             Self::parse_string_constant,
             Constant::StringConstantVariant,
         )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::datetime_constant,
+            Self::parse_datetime_constant,
+            Constant::DatetimeConstantVariant,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::binary_constant,
+            Self::parse_binary_constant,
+            Constant::BinaryConstantVariant,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::json_constant,
+            Self::parse_json_constant,
+            Constant::JsonConstantVariant,
+        )?)
         .ok_or_else(|| {
             ApllodbSqlParserError::new(
                 params.apllodb_sql,
@@ -87,15 +133,59 @@ impl PestParserImpl {
         })
     }
 
+    fn parse_boolean_constant(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<BooleanConstant> {
+        let s = self_as_str(&mut params);
+        match s.to_ascii_uppercase().as_str() {
+            "TRUE" => Ok(BooleanConstant::TrueVariant),
+            "FALSE" => Ok(BooleanConstant::FalseVariant),
+            x => {
+                eprintln!("Unexpected boolean constant parsed: {}", x);
+                unreachable!();
+            }
+        }
+    }
+
     fn parse_numeric_constant(
         mut params: FnParseParams,
     ) -> ApllodbSqlParserResult<NumericConstant> {
-        parse_child(
+        try_parse_child(
+            &mut params,
+            Rule::float_constant,
+            Self::parse_float_constant,
+            NumericConstant::FloatConstantVariant,
+        )?
+        .or(try_parse_child(
+            &mut params,
+            Rule::decimal_constant,
+            Self::parse_decimal_constant,
+            NumericConstant::DecimalConstantVariant,
+        )?)
+        .or(try_parse_child(
             &mut params,
             Rule::integer_constant,
             Self::parse_integer_constant,
             NumericConstant::IntegerConstantVariant,
-        )
+        )?)
+        .ok_or_else(|| {
+            ApllodbSqlParserError::new(
+                params.apllodb_sql,
+                "Does not match any child rule of numeric_constant.",
+            )
+        })
+    }
+
+    fn parse_float_constant(mut params: FnParseParams) -> ApllodbSqlParserResult<FloatConstant> {
+        let s = self_as_str(&mut params);
+        Ok(FloatConstant(s.into()))
+    }
+
+    fn parse_decimal_constant(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<DecimalConstant> {
+        let s = self_as_str(&mut params);
+        Ok(DecimalConstant(s.into()))
     }
 
     fn parse_integer_constant(
@@ -106,17 +196,120 @@ impl PestParserImpl {
     }
 
     fn parse_string_constant(mut params: FnParseParams) -> ApllodbSqlParserResult<StringConstant> {
+        try_parse_child(
+            &mut params,
+            Rule::escape_string_constant,
+            Self::parse_escape_string_constant,
+            identity,
+        )?
+        .or(try_parse_child(
+            &mut params,
+            Rule::standard_string_constant,
+            Self::parse_standard_string_constant,
+            identity,
+        )?)
+        .ok_or_else(|| {
+            ApllodbSqlParserError::new(
+                params.apllodb_sql,
+                "Does not match any child rule of string_constant.",
+            )
+        })
+    }
+
+    fn parse_standard_string_constant(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<StringConstant> {
         parse_child(
             &mut params,
-            Rule::string_content,
+            Rule::standard_string_content,
             Self::parse_string_content,
-            identity,
+            StringConstant::StandardVariant,
+        )
+    }
+
+    fn parse_escape_string_constant(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<StringConstant> {
+        parse_child(
+            &mut params,
+            Rule::escape_string_content,
+            Self::parse_string_content,
+            StringConstant::EscapeVariant,
         )
     }
 
-    fn parse_string_content(mut params: FnParseParams) -> ApllodbSqlParserResult<StringConstant> {
+    fn parse_string_content(mut params: FnParseParams) -> ApllodbSqlParserResult<String> {
         let s = self_as_str(&mut params);
-        Ok(StringConstant(s.into()))
+        Ok(s.into())
+    }
+
+    fn parse_datetime_constant(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<DatetimeConstant> {
+        let datetime_type = parse_child(
+            &mut params,
+            Rule::datetime_type,
+            Self::parse_datetime_type,
+            identity,
+        )?;
+        let string_constant = parse_child(
+            &mut params,
+            Rule::string_constant,
+            Self::parse_string_constant,
+            identity,
+        )?;
+        Ok(DatetimeConstant {
+            datetime_type,
+            string_constant,
+        })
+    }
+
+    fn parse_binary_constant(mut params: FnParseParams) -> ApllodbSqlParserResult<BinaryConstant> {
+        if let Some(hex) = try_parse_child(
+            &mut params,
+            Rule::hex_binary_constant,
+            Self::parse_hex_binary_constant,
+            BinaryConstant::HexVariant,
+        )? {
+            return Ok(hex);
+        }
+
+        let _ = parse_child(
+            &mut params,
+            Rule::binary_type,
+            Self::parse_binary_type,
+            identity,
+        )?;
+        parse_child(
+            &mut params,
+            Rule::string_constant,
+            Self::parse_string_constant,
+            BinaryConstant::TypedVariant,
+        )
+    }
+
+    fn parse_hex_binary_constant(mut params: FnParseParams) -> ApllodbSqlParserResult<String> {
+        parse_child(
+            &mut params,
+            Rule::hex_binary_content,
+            Self::parse_string_content,
+            identity,
+        )
+    }
+
+    fn parse_json_constant(mut params: FnParseParams) -> ApllodbSqlParserResult<JsonConstant> {
+        let _ = parse_child(
+            &mut params,
+            Rule::json_type,
+            Self::parse_json_type,
+            identity,
+        )?;
+        parse_child(
+            &mut params,
+            Rule::string_constant,
+            Self::parse_string_constant,
+            JsonConstant,
+        )
     }
 
     /*
@@ -127,8 +320,10 @@ impl PestParserImpl {
 
     fn parse_unary_operator(mut params: FnParseParams) -> ApllodbSqlParserResult<UnaryOperator> {
         let s = self_as_str(&mut params);
-        match s {
+        match s.to_lowercase().as_str() {
             "-" => Ok(UnaryOperator::Minus),
+            "not" => Ok(UnaryOperator::Not),
+            "possibly" => Ok(UnaryOperator::Possibly),
             _ => Err(ApllodbSqlParserError::new(
                 params.apllodb_sql,
                 "Does not match any child rule of unary_operator.",
@@ -136,10 +331,47 @@ impl PestParserImpl {
         }
     }
 
+    fn parse_null_predicate_operator(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<UnaryOperator> {
+        try_parse_child(
+            &mut params,
+            Rule::is_not_null_operator,
+            |_| Ok(UnaryOperator::IsNotNull),
+            identity,
+        )?
+        .or(try_parse_child(
+            &mut params,
+            Rule::is_null_operator,
+            |_| Ok(UnaryOperator::IsNull),
+            identity,
+        )?)
+        .ok_or_else(|| {
+            ApllodbSqlParserError::new(
+                params.apllodb_sql,
+                "Does not match any child rule of null_predicate_operator.",
+            )
+        })
+    }
+
     fn parse_binary_operator(mut params: FnParseParams) -> ApllodbSqlParserResult<BinaryOperator> {
         let s = self_as_str(&mut params);
         match s.to_lowercase().as_str() {
             "=" => Ok(BinaryOperator::Equal),
+            "<>" | "!=" => Ok(BinaryOperator::NotEqual),
+            "<" => Ok(BinaryOperator::LessThan),
+            "<=" => Ok(BinaryOperator::LessThanOrEqual),
+            ">" => Ok(BinaryOperator::GreaterThan),
+            ">=" => Ok(BinaryOperator::GreaterThanOrEqual),
+            "and" => Ok(BinaryOperator::And),
+            "or" => Ok(BinaryOperator::Or),
+            "+" => Ok(BinaryOperator::Plus),
+            "-" => Ok(BinaryOperator::Minus),
+            "*" => Ok(BinaryOperator::Multiply),
+            "/" => Ok(BinaryOperator::Divide),
+            "%" => Ok(BinaryOperator::Modulo),
+            "->" => Ok(BinaryOperator::JsonExtract),
+            "->>" => Ok(BinaryOperator::JsonExtractText),
             _ => Err(ApllodbSqlParserError::new(
                 params.apllodb_sql,
                 "Does not match any child rule of binary_operator.",
@@ -154,8 +386,45 @@ impl PestParserImpl {
      */
 
     fn parse_identifier(mut params: FnParseParams) -> ApllodbSqlParserResult<Identifier> {
+        try_parse_child(
+            &mut params,
+            Rule::quoted_identifier,
+            Self::parse_quoted_identifier,
+            identity,
+        )?
+        .or(try_parse_child(
+            &mut params,
+            Rule::unquoted_identifier,
+            Self::parse_unquoted_identifier,
+            identity,
+        )?)
+        .ok_or_else(|| {
+            ApllodbSqlParserError::new(
+                params.apllodb_sql,
+                "Does not match any child rule of identifier.",
+            )
+        })
+    }
+
+    fn parse_unquoted_identifier(mut params: FnParseParams) -> ApllodbSqlParserResult<Identifier> {
+        let s = self_as_str(&mut params);
+        Ok(Identifier::from_unquoted(s))
+    }
+
+    fn parse_quoted_identifier(mut params: FnParseParams) -> ApllodbSqlParserResult<Identifier> {
+        parse_child(
+            &mut params,
+            Rule::quoted_identifier_content,
+            Self::parse_quoted_identifier_content,
+            identity,
+        )
+    }
+
+    fn parse_quoted_identifier_content(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<Identifier> {
         let s = self_as_str(&mut params);
-        Ok(Identifier(s.into()))
+        Ok(Identifier::from_quoted(s))
     }
 
     /*
@@ -176,119 +445,787 @@ impl PestParserImpl {
     }
 
     fn parse_expression(mut params: FnParseParams) -> ApllodbSqlParserResult<Expression> {
-        let expr = parse_child(
+        Self::parse_left_assoc_binary_operations(
             &mut params,
-            Rule::sub_expression,
-            Self::parse_sub_expression,
-            identity,
-        )?;
+            Rule::and_expression,
+            Self::parse_and_expression,
+            Rule::or_operator,
+        )
+    }
 
-        if let Some(bin_op) = try_parse_child(
+    fn parse_and_expression(mut params: FnParseParams) -> ApllodbSqlParserResult<Expression> {
+        Self::parse_left_assoc_binary_operations(
             &mut params,
-            Rule::binary_operator,
-            Self::parse_binary_operator,
-            identity,
-        )? {
-            let right_expr = parse_child(
-                &mut params,
-                Rule::expression,
-                Self::parse_expression,
-                identity,
-            )?;
-            Ok(Expression::BinaryOperatorVariant(
-                bin_op,
-                Box::new(expr),
-                Box::new(right_expr),
-            ))
-        } else {
-            Ok(expr)
-        }
+            Rule::not_expression,
+            Self::parse_not_expression,
+            Rule::and_operator,
+        )
     }
 
-    fn parse_sub_expression(mut params: FnParseParams) -> ApllodbSqlParserResult<Expression> {
-        try_parse_child(
+    fn parse_not_expression(mut params: FnParseParams) -> ApllodbSqlParserResult<Expression> {
+        if let Some(uni_op) = try_parse_child(
             &mut params,
-            Rule::constant,
-            Self::parse_constant,
-            Expression::ConstantVariant,
+            Rule::not_operator,
+            Self::parse_unary_operator,
+            identity,
         )?
         .or(try_parse_child(
             &mut params,
-            Rule::column_reference,
-            Self::parse_column_reference,
-            Expression::ColumnReferenceVariant,
-        )?)
-        .or({
-            if let Some(uni_op) = try_parse_child(
+            Rule::possibly_operator,
+            Self::parse_unary_operator,
+            identity,
+        )?) {
+            parse_child(
                 &mut params,
-                Rule::unary_operator,
-                Self::parse_unary_operator,
+                Rule::not_expression,
+                Self::parse_not_expression,
+                |expr| Expression::UnaryOperatorVariant(uni_op.clone(), Box::new(expr)),
+            )
+        } else {
+            parse_child(
+                &mut params,
+                Rule::null_predicate_expression,
+                Self::parse_null_predicate_expression,
                 identity,
-            )? {
-                Some(parse_child(
-                    &mut params,
-                    Rule::expression,
-                    Self::parse_expression,
-                    |expr| Expression::UnaryOperatorVariant(uni_op.clone(), Box::new(expr)),
-                )?)
-            } else {
-                None
-            }
-        })
-        .ok_or_else(|| {
-            ApllodbSqlParserError::new(
-                params.apllodb_sql,
-                "Does not match any child rule of sub_expression.",
             )
-        })
+        }
     }
 
-    /*
-     * ----------------------------------------------------------------------------
-     * Column References
-     * ----------------------------------------------------------------------------
-     */
-
-    fn parse_column_reference(
+    fn parse_comparison_expression(
         mut params: FnParseParams,
-    ) -> ApllodbSqlParserResult<ColumnReference> {
-        let correlation = try_parse_child(
+    ) -> ApllodbSqlParserResult<Expression> {
+        let left = parse_child(
             &mut params,
-            Rule::correlation,
-            Self::parse_correlation,
+            Rule::predicate_expression,
+            Self::parse_predicate_expression,
             identity,
         )?;
-        let column_name = parse_child(
+
+        match try_parse_child(
             &mut params,
-            Rule::column_name,
-            Self::parse_column_name,
+            Rule::comparison_operator,
+            Self::parse_binary_operator,
             identity,
-        )?;
-        Ok(ColumnReference {
-            correlation,
-            column_name,
-        })
+        )? {
+            None => Ok(left),
+            Some(bin_op) => {
+                let left = Box::new(left);
+                try_parse_child(
+                    &mut params,
+                    Rule::any_predicate,
+                    Self::parse_any_predicate,
+                    |array| Expression::AnyComparisonVariant(bin_op.clone(), left.clone(), array),
+                )?
+                .or(try_parse_child(
+                    &mut params,
+                    Rule::predicate_expression,
+                    Self::parse_predicate_expression,
+                    |right| {
+                        Expression::BinaryOperatorVariant(
+                            bin_op.clone(),
+                            left.clone(),
+                            Box::new(right),
+                        )
+                    },
+                )?)
+                .ok_or_else(|| {
+                    ApllodbSqlParserError::new(
+                        params.apllodb_sql,
+                        "Does not match any right-hand side of comparison_expression.",
+                    )
+                })
+            }
+        }
     }
 
-    /*
-     * ================================================================================================
-     * Data Types:
-     * ================================================================================================
-     */
-
-    fn parse_data_type(mut params: FnParseParams) -> ApllodbSqlParserResult<DataType> {
-        try_parse_child(
+    fn parse_any_predicate(mut params: FnParseParams) -> ApllodbSqlParserResult<Box<Expression>> {
+        parse_child(&mut params, Rule::any_keyword, |_| Ok(()), identity)?;
+        parse_child(
             &mut params,
-            Rule::integer_type,
-            Self::parse_integer_type,
-            DataType::IntegerTypeVariant,
+            Rule::expression,
+            Self::parse_expression,
+            Box::new,
+        )
+    }
+
+    fn parse_predicate_expression(mut params: FnParseParams) -> ApllodbSqlParserResult<Expression> {
+        let expr = Box::new(parse_child(
+            &mut params,
+            Rule::json_expression,
+            Self::parse_json_expression,
+            identity,
+        )?);
+
+        let predicate = try_parse_child(
+            &mut params,
+            Rule::in_predicate,
+            Self::parse_in_predicate,
+            |(negated, list)| {
+                Expression::InPredicateVariant(InPredicate {
+                    negated,
+                    expression: expr.clone(),
+                    list,
+                })
+            },
+        )?
+        .or(try_parse_child(
+            &mut params,
+            Rule::between_predicate,
+            Self::parse_between_predicate,
+            |(negated, low, high)| {
+                Expression::BetweenPredicateVariant(BetweenPredicate {
+                    negated,
+                    expression: expr.clone(),
+                    low: Box::new(low),
+                    high: Box::new(high),
+                })
+            },
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::like_predicate,
+            Self::parse_like_predicate,
+            |(negated, pattern)| {
+                Expression::LikePredicateVariant(LikePredicate {
+                    negated,
+                    expression: expr.clone(),
+                    pattern: Box::new(pattern),
+                })
+            },
+        )?);
+
+        Ok(predicate.unwrap_or(*expr))
+    }
+
+    fn parse_in_predicate(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<(bool, NonEmptyVec<Expression>)> {
+        let negated = Self::parse_predicate_negation(&mut params)?;
+        parse_child(&mut params, Rule::in_operator, |_| Ok(()), identity)?;
+        let list = parse_child_seq(
+            &mut params,
+            Rule::expression,
+            &Self::parse_expression,
+            &identity,
+        )?;
+        Ok((negated, NonEmptyVec::new(list)))
+    }
+
+    fn parse_between_predicate(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<(bool, Expression, Expression)> {
+        let negated = Self::parse_predicate_negation(&mut params)?;
+        parse_child(&mut params, Rule::between_operator, |_| Ok(()), identity)?;
+        let low = parse_child(
+            &mut params,
+            Rule::additive_expression,
+            Self::parse_additive_expression,
+            identity,
+        )?;
+        parse_child(&mut params, Rule::and_operator, |_| Ok(()), identity)?;
+        let high = parse_child(
+            &mut params,
+            Rule::additive_expression,
+            Self::parse_additive_expression,
+            identity,
+        )?;
+        Ok((negated, low, high))
+    }
+
+    fn parse_like_predicate(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<(bool, Expression)> {
+        let negated = Self::parse_predicate_negation(&mut params)?;
+        parse_child(&mut params, Rule::like_operator, |_| Ok(()), identity)?;
+        let pattern = parse_child(
+            &mut params,
+            Rule::additive_expression,
+            Self::parse_additive_expression,
+            identity,
+        )?;
+        Ok((negated, pattern))
+    }
+
+    /// Parses optional `NOT` in `[NOT] IN`, `[NOT] BETWEEN` and `[NOT] LIKE`.
+    fn parse_predicate_negation(params: &mut FnParseParams) -> ApllodbSqlParserResult<bool> {
+        let not = try_parse_child(params, Rule::not_operator, |_| Ok(()), identity)?;
+        Ok(not.is_some())
+    }
+
+    fn parse_null_predicate_expression(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<Expression> {
+        let expr = parse_child(
+            &mut params,
+            Rule::comparison_expression,
+            Self::parse_comparison_expression,
+            identity,
+        )?;
+        let uni_op = try_parse_child(
+            &mut params,
+            Rule::null_predicate_operator,
+            Self::parse_null_predicate_operator,
+            identity,
+        )?;
+        Ok(match uni_op {
+            Some(uni_op) => Expression::UnaryOperatorVariant(uni_op, Box::new(expr)),
+            None => expr,
+        })
+    }
+
+    fn parse_json_expression(mut params: FnParseParams) -> ApllodbSqlParserResult<Expression> {
+        Self::parse_left_assoc_binary_operations(
+            &mut params,
+            Rule::additive_expression,
+            Self::parse_additive_expression,
+            Rule::json_operator,
+        )
+    }
+
+    fn parse_additive_expression(mut params: FnParseParams) -> ApllodbSqlParserResult<Expression> {
+        Self::parse_left_assoc_binary_operations(
+            &mut params,
+            Rule::multiplicative_expression,
+            Self::parse_multiplicative_expression,
+            Rule::additive_operator,
+        )
+    }
+
+    fn parse_multiplicative_expression(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<Expression> {
+        Self::parse_left_assoc_binary_operations(
+            &mut params,
+            Rule::typecast_expression,
+            Self::parse_typecast_expression,
+            Rule::multiplicative_operator,
+        )
+    }
+
+    fn parse_typecast_expression(mut params: FnParseParams) -> ApllodbSqlParserResult<Expression> {
+        let mut expr = parse_child(
+            &mut params,
+            Rule::sub_expression,
+            Self::parse_sub_expression,
+            identity,
+        )?;
+        loop {
+            if let Some(index) = try_parse_child(
+                &mut params,
+                Rule::array_subscript,
+                Self::parse_array_subscript,
+                identity,
+            )? {
+                expr = Expression::SubscriptVariant(Box::new(expr), Box::new(index));
+            } else if let Some(data_type) = try_parse_child(
+                &mut params,
+                Rule::data_type,
+                Self::parse_data_type,
+                identity,
+            )? {
+                expr = Expression::CastVariant(Box::new(expr), data_type);
+            } else {
+                return Ok(expr);
+            }
+        }
+    }
+
+    fn parse_array_subscript(mut params: FnParseParams) -> ApllodbSqlParserResult<Expression> {
+        parse_child(
+            &mut params,
+            Rule::expression,
+            Self::parse_expression,
+            identity,
+        )
+    }
+
+    fn parse_sub_expression(mut params: FnParseParams) -> ApllodbSqlParserResult<Expression> {
+        try_parse_child(
+            &mut params,
+            Rule::constant,
+            Self::parse_constant,
+            Expression::ConstantVariant,
+        )?
+        .or(try_parse_child(
+            &mut params,
+            Rule::case_expression,
+            Self::parse_case_expression,
+            Expression::CaseExpressionVariant,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::cast_expression,
+            Self::parse_cast_expression,
+            identity,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::match_expression,
+            Self::parse_match_expression,
+            Expression::MatchExpressionVariant,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::current_datetime_function,
+            Self::parse_current_datetime_function,
+            Expression::CurrentDatetimeFunctionVariant,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::array_constructor,
+            Self::parse_array_constructor,
+            Expression::ArrayConstructorVariant,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::function_call,
+            Self::parse_function_call,
+            Expression::FunctionCallVariant,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::column_reference,
+            Self::parse_column_reference,
+            Expression::ColumnReferenceVariant,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::expression,
+            Self::parse_expression,
+            identity,
+        )?)
+        .or({
+            if let Some(uni_op) = try_parse_child(
+                &mut params,
+                Rule::unary_operator,
+                Self::parse_unary_operator,
+                identity,
+            )? {
+                Some(parse_child(
+                    &mut params,
+                    Rule::typecast_expression,
+                    Self::parse_typecast_expression,
+                    |expr| Expression::UnaryOperatorVariant(uni_op.clone(), Box::new(expr)),
+                )?)
+            } else {
+                None
+            }
+        })
+        .ok_or_else(|| {
+            ApllodbSqlParserError::new(
+                params.apllodb_sql,
+                "Does not match any child rule of sub_expression.",
+            )
+        })
+    }
+
+    /// Parses `operand (operator operand)*` into left-associative BinaryOperatorVariant's.
+    fn parse_left_assoc_binary_operations(
+        params: &mut FnParseParams,
+        operand_rule: Rule,
+        operand_parser: impl Fn(FnParseParams) -> ApllodbSqlParserResult<Expression>,
+        operator_rule: Rule,
+    ) -> ApllodbSqlParserResult<Expression> {
+        let mut expr = parse_child(params, operand_rule, &operand_parser, identity)?;
+
+        while let Some(bin_op) =
+            try_parse_child(params, operator_rule, Self::parse_binary_operator, identity)?
+        {
+            let right_expr = parse_child(params, operand_rule, &operand_parser, identity)?;
+            expr = Expression::BinaryOperatorVariant(bin_op, Box::new(expr), Box::new(right_expr));
+        }
+
+        Ok(expr)
+    }
+
+    /*
+     * ----------------------------------------------------------------------------
+     * Column References
+     * ----------------------------------------------------------------------------
+     */
+
+    fn parse_column_reference(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<ColumnReference> {
+        let correlation = try_parse_child(
+            &mut params,
+            Rule::correlation,
+            Self::parse_correlation,
+            identity,
+        )?;
+        let column_name = parse_child(
+            &mut params,
+            Rule::column_name,
+            Self::parse_column_name,
+            identity,
+        )?;
+        Ok(ColumnReference {
+            correlation,
+            column_name,
+        })
+    }
+
+    /*
+     * ----------------------------------------------------------------------------
+     * Function Calls
+     * ----------------------------------------------------------------------------
+     */
+
+    fn parse_function_call(mut params: FnParseParams) -> ApllodbSqlParserResult<FunctionCall> {
+        let function_name = parse_child(
+            &mut params,
+            Rule::function_name,
+            Self::parse_function_name,
+            identity,
+        )?;
+        let arguments =
+            if try_parse_child(&mut params, Rule::asterisk, |_| Ok(()), identity)?.is_some() {
+                FunctionArguments::AsteriskVariant
+            } else {
+                let set_quantifier = try_parse_child(
+                    &mut params,
+                    Rule::set_quantifier,
+                    Self::parse_set_quantifier,
+                    identity,
+                )?;
+                let expressions = parse_child_seq(
+                    &mut params,
+                    Rule::expression,
+                    &Self::parse_expression,
+                    &identity,
+                )?;
+                FunctionArguments::ExpressionsVariant {
+                    set_quantifier,
+                    expressions,
+                }
+            };
+        Ok(FunctionCall {
+            function_name,
+            arguments,
+        })
+    }
+
+    fn parse_set_quantifier(mut params: FnParseParams) -> ApllodbSqlParserResult<SetQuantifier> {
+        let s = self_as_str(&mut params);
+        match s.to_lowercase().as_str() {
+            "all" => Ok(SetQuantifier::AllVariant),
+            "distinct" => Ok(SetQuantifier::DistinctVariant),
+            x => {
+                eprintln!("Unexpected set_quantifier parsed: {}", x);
+                unreachable!();
+            }
+        }
+    }
+
+    /*
+     * ----------------------------------------------------------------------------
+     * Array Constructors
+     * ----------------------------------------------------------------------------
+     */
+
+    fn parse_array_constructor(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<Vec<Expression>> {
+        parse_child(&mut params, Rule::array_keyword, |_| Ok(()), identity)?;
+        parse_child_seq(
+            &mut params,
+            Rule::expression,
+            &Self::parse_expression,
+            &identity,
+        )
+    }
+
+    /*
+     * ----------------------------------------------------------------------------
+     * Type Casts
+     * ----------------------------------------------------------------------------
+     */
+
+    fn parse_cast_expression(mut params: FnParseParams) -> ApllodbSqlParserResult<Expression> {
+        parse_child(&mut params, Rule::cast_keyword, |_| Ok(()), identity)?;
+        let expression = parse_child(
+            &mut params,
+            Rule::expression,
+            Self::parse_expression,
+            identity,
+        )?;
+        let data_type = parse_child(
+            &mut params,
+            Rule::data_type,
+            Self::parse_data_type,
+            identity,
+        )?;
+        Ok(Expression::CastVariant(Box::new(expression), data_type))
+    }
+
+    /*
+     * ----------------------------------------------------------------------------
+     * Current Date/Time
+     * ----------------------------------------------------------------------------
+     */
+
+    fn parse_current_datetime_function(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<CurrentDatetimeFunction> {
+        let s = self_as_str(&mut params);
+        match s.to_ascii_uppercase().as_str() {
+            "CURRENT_DATE" => Ok(CurrentDatetimeFunction::CurrentDateVariant),
+            "CURRENT_TIMESTAMP" => Ok(CurrentDatetimeFunction::CurrentTimestampVariant),
+            x => {
+                eprintln!("Unexpected function parsed: {}", x);
+                unreachable!();
+            }
+        }
+    }
+
+    /*
+     * ----------------------------------------------------------------------------
+     * Conditional Expressions
+     * ----------------------------------------------------------------------------
+     */
+
+    fn parse_case_expression(mut params: FnParseParams) -> ApllodbSqlParserResult<CaseExpression> {
+        parse_child(&mut params, Rule::case_keyword, |_| Ok(()), identity)?;
+        let operand = try_parse_child(
+            &mut params,
+            Rule::case_operand,
+            Self::parse_case_operand,
+            Box::new,
+        )?;
+        let when_clauses = parse_child_seq(
+            &mut params,
+            Rule::when_clause,
+            &Self::parse_when_clause,
+            &identity,
+        )?;
+        let else_result = try_parse_child(
+            &mut params,
+            Rule::else_clause,
+            Self::parse_else_clause,
+            Box::new,
+        )?;
+        parse_child(&mut params, Rule::end_keyword, |_| Ok(()), identity)?;
+        Ok(CaseExpression {
+            operand,
+            when_clauses: NonEmptyVec::new(when_clauses),
+            else_result,
+        })
+    }
+
+    fn parse_case_operand(mut params: FnParseParams) -> ApllodbSqlParserResult<Expression> {
+        parse_child(
+            &mut params,
+            Rule::expression,
+            Self::parse_expression,
+            identity,
+        )
+    }
+
+    fn parse_when_clause(mut params: FnParseParams) -> ApllodbSqlParserResult<WhenClause> {
+        parse_child(&mut params, Rule::when_keyword, |_| Ok(()), identity)?;
+        let expression = parse_child(
+            &mut params,
+            Rule::expression,
+            Self::parse_expression,
+            identity,
+        )?;
+        parse_child(&mut params, Rule::then_keyword, |_| Ok(()), identity)?;
+        let result = parse_child(
+            &mut params,
+            Rule::expression,
+            Self::parse_expression,
+            identity,
+        )?;
+        Ok(WhenClause { expression, result })
+    }
+
+    fn parse_else_clause(mut params: FnParseParams) -> ApllodbSqlParserResult<Expression> {
+        parse_child(&mut params, Rule::else_keyword, |_| Ok(()), identity)?;
+        parse_child(
+            &mut params,
+            Rule::expression,
+            Self::parse_expression,
+            identity,
+        )
+    }
+
+    /*
+     * ----------------------------------------------------------------------------
+     * Pattern Matching
+     * ----------------------------------------------------------------------------
+     */
+
+    fn parse_match_expression(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<MatchExpression> {
+        parse_child(&mut params, Rule::match_keyword, |_| Ok(()), identity)?;
+        let operand = parse_child(
+            &mut params,
+            Rule::expression,
+            Self::parse_expression,
+            Box::new,
+        )?;
+        parse_child(&mut params, Rule::with_keyword, |_| Ok(()), identity)?;
+        let match_arms = parse_child_seq(
+            &mut params,
+            Rule::match_arm,
+            &Self::parse_match_arm,
+            &identity,
+        )?;
+        parse_child(&mut params, Rule::end_keyword, |_| Ok(()), identity)?;
+        Ok(MatchExpression {
+            operand,
+            match_arms: NonEmptyVec::new(match_arms),
+        })
+    }
+
+    fn parse_match_arm(mut params: FnParseParams) -> ApllodbSqlParserResult<MatchArm> {
+        let pattern = parse_child(
+            &mut params,
+            Rule::match_pattern,
+            Self::parse_match_pattern,
+            identity,
+        )?;
+        let result = parse_child(
+            &mut params,
+            Rule::expression,
+            Self::parse_expression,
+            identity,
+        )?;
+        Ok(MatchArm { pattern, result })
+    }
+
+    fn parse_match_pattern(mut params: FnParseParams) -> ApllodbSqlParserResult<MatchPattern> {
+        try_parse_child(
+            &mut params,
+            Rule::wildcard,
+            |_| Ok(()),
+            |()| MatchPattern::WildcardVariant,
+        )?
+        .or(try_parse_child(
+            &mut params,
+            Rule::variant_pattern,
+            Self::parse_variant_pattern,
+            MatchPattern::VariantPatternVariant,
+        )?)
+        .ok_or_else(|| {
+            ApllodbSqlParserError::new(
+                params.apllodb_sql,
+                "Does not match any child rule of match_pattern.",
+            )
+        })
+    }
+
+    fn parse_variant_pattern(mut params: FnParseParams) -> ApllodbSqlParserResult<VariantPattern> {
+        let variant_name = parse_child(
+            &mut params,
+            Rule::variant_name,
+            Self::parse_variant_name,
+            identity,
+        )?;
+        let pattern_bindings = parse_child_seq(
+            &mut params,
+            Rule::pattern_binding,
+            &Self::parse_pattern_binding,
+            &identity,
+        )?;
+        Ok(VariantPattern {
+            variant_name,
+            pattern_bindings,
+        })
+    }
+
+    fn parse_pattern_binding(mut params: FnParseParams) -> ApllodbSqlParserResult<PatternBinding> {
+        try_parse_child(
+            &mut params,
+            Rule::wildcard,
+            |_| Ok(()),
+            |()| PatternBinding::WildcardVariant,
         )?
         .or(try_parse_child(
             &mut params,
+            Rule::binding_name,
+            Self::parse_binding_name,
+            PatternBinding::BindingNameVariant,
+        )?)
+        .ok_or_else(|| {
+            ApllodbSqlParserError::new(
+                params.apllodb_sql,
+                "Does not match any child rule of pattern_binding.",
+            )
+        })
+    }
+
+    /*
+     * ================================================================================================
+     * Data Types:
+     * ================================================================================================
+     */
+
+    fn parse_data_type(mut params: FnParseParams) -> ApllodbSqlParserResult<DataType> {
+        let mut data_type = Self::parse_non_array_data_type(&mut params)?;
+        while try_parse_child(&mut params, Rule::array_type_suffix, |_| Ok(()), identity)?.is_some()
+        {
+            data_type = DataType::ArrayTypeVariant(Box::new(data_type));
+        }
+        Ok(data_type)
+    }
+
+    fn parse_non_array_data_type(params: &mut FnParseParams) -> ApllodbSqlParserResult<DataType> {
+        try_parse_child(
+            params,
+            Rule::user_defined_type,
+            Self::parse_user_defined_type,
+            DataType::UserDefinedTypeVariant,
+        )?
+        .or(try_parse_child(
+            params,
+            Rule::integer_type,
+            Self::parse_integer_type,
+            DataType::IntegerTypeVariant,
+        )?)
+        .or(try_parse_child(
+            params,
+            Rule::floating_point_type,
+            Self::parse_floating_point_type,
+            DataType::FloatingPointTypeVariant,
+        )?)
+        .or(try_parse_child(
+            params,
+            Rule::decimal_type,
+            Self::parse_decimal_type,
+            DataType::DecimalTypeVariant,
+        )?)
+        .or(try_parse_child(
+            params,
             Rule::character_type,
             Self::parse_character_type,
             DataType::CharacterTypeVariant,
         )?)
+        .or(try_parse_child(
+            params,
+            Rule::boolean_type,
+            Self::parse_boolean_type,
+            DataType::BooleanTypeVariant,
+        )?)
+        .or(try_parse_child(
+            params,
+            Rule::binary_type,
+            Self::parse_binary_type,
+            DataType::BinaryTypeVariant,
+        )?)
+        .or(try_parse_child(
+            params,
+            Rule::json_type,
+            Self::parse_json_type,
+            DataType::JsonTypeVariant,
+        )?)
+        .or(try_parse_child(
+            params,
+            Rule::datetime_type,
+            Self::parse_datetime_type,
+            DataType::DatetimeTypeVariant,
+        )?)
         .ok_or_else(|| {
             ApllodbSqlParserError::new(
                 params.apllodb_sql,
@@ -299,16 +1236,183 @@ impl PestParserImpl {
 
     /*
      * ----------------------------------------------------------------------------
-     * Integer Types
+     * Integer Types
+     * ----------------------------------------------------------------------------
+     */
+
+    fn parse_integer_type(mut params: FnParseParams) -> ApllodbSqlParserResult<IntegerType> {
+        let s = self_as_str(&mut params);
+        match s.to_ascii_uppercase().as_str() {
+            "SMALLINT" => Ok(IntegerType::SmallIntVariant),
+            "INTEGER" => Ok(IntegerType::IntegerVariant),
+            "BIGINT" => Ok(IntegerType::BigIntVariant),
+            x => {
+                eprintln!("Unexpected data type parsed: {}", x);
+                unreachable!();
+            }
+        }
+    }
+
+    /*
+     * ----------------------------------------------------------------------------
+     * Floating-Point Types
+     * ----------------------------------------------------------------------------
+     */
+
+    fn parse_floating_point_type(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<FloatingPointType> {
+        let s = self_as_str(&mut params);
+        // `DOUBLE` and `PRECISION` may be separated by any whitespaces.
+        match s
+            .split_whitespace()
+            .next()
+            .map(|w| w.to_ascii_uppercase())
+            .as_deref()
+        {
+            Some("REAL") => Ok(FloatingPointType::RealVariant),
+            Some("DOUBLE") => Ok(FloatingPointType::DoublePrecisionVariant),
+            x => {
+                eprintln!("Unexpected data type parsed: {:?}", x);
+                unreachable!();
+            }
+        }
+    }
+
+    /*
+     * ----------------------------------------------------------------------------
+     * Arbitrary Precision Numbers
+     * ----------------------------------------------------------------------------
+     */
+
+    fn parse_decimal_type(mut params: FnParseParams) -> ApllodbSqlParserResult<DecimalType> {
+        let precision = try_parse_child(
+            &mut params,
+            Rule::decimal_precision,
+            Self::parse_decimal_precision,
+            identity,
+        )?;
+        let scale = try_parse_child(
+            &mut params,
+            Rule::decimal_scale,
+            Self::parse_decimal_scale,
+            identity,
+        )?;
+        Ok(DecimalType { precision, scale })
+    }
+
+    fn parse_decimal_precision(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<IntegerConstant> {
+        parse_child(
+            &mut params,
+            Rule::integer_constant,
+            Self::parse_integer_constant,
+            identity,
+        )
+    }
+
+    fn parse_decimal_scale(mut params: FnParseParams) -> ApllodbSqlParserResult<IntegerConstant> {
+        parse_child(
+            &mut params,
+            Rule::integer_constant,
+            Self::parse_integer_constant,
+            identity,
+        )
+    }
+
+    /*
+     * ----------------------------------------------------------------------------
+     * Character Types
+     * ----------------------------------------------------------------------------
+     */
+
+    fn parse_character_type(mut params: FnParseParams) -> ApllodbSqlParserResult<CharacterType> {
+        if let Some(varchar) = try_parse_child(
+            &mut params,
+            Rule::varchar_type,
+            Self::parse_varchar_type,
+            CharacterType::VarcharVariant,
+        )? {
+            return Ok(varchar);
+        }
+        if let Some(char) = try_parse_child(
+            &mut params,
+            Rule::char_type,
+            Self::parse_char_type,
+            CharacterType::CharVariant,
+        )? {
+            return Ok(char);
+        }
+
+        let s = self_as_str(&mut params);
+        match s.to_ascii_uppercase().as_str() {
+            "TEXT" => Ok(CharacterType::TextVariant),
+            x => {
+                eprintln!("Unexpected data type parsed: {}", x);
+                unreachable!();
+            }
+        }
+    }
+
+    fn parse_varchar_type(mut params: FnParseParams) -> ApllodbSqlParserResult<IntegerConstant> {
+        parse_child(
+            &mut params,
+            Rule::character_length,
+            Self::parse_character_length,
+            identity,
+        )
+    }
+
+    fn parse_char_type(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<Option<IntegerConstant>> {
+        try_parse_child(
+            &mut params,
+            Rule::character_length,
+            Self::parse_character_length,
+            identity,
+        )
+    }
+
+    fn parse_character_length(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<IntegerConstant> {
+        parse_child(
+            &mut params,
+            Rule::integer_constant,
+            Self::parse_integer_constant,
+            identity,
+        )
+    }
+
+    /*
+     * ----------------------------------------------------------------------------
+     * Boolean Type
+     * ----------------------------------------------------------------------------
+     */
+
+    fn parse_boolean_type(mut params: FnParseParams) -> ApllodbSqlParserResult<BooleanType> {
+        let s = self_as_str(&mut params);
+        match s.to_ascii_uppercase().as_str() {
+            "BOOLEAN" | "BOOL" => Ok(BooleanType::BooleanVariant),
+            x => {
+                eprintln!("Unexpected data type parsed: {}", x);
+                unreachable!();
+            }
+        }
+    }
+
+    /*
+     * ----------------------------------------------------------------------------
+     * Binary Data Types
      * ----------------------------------------------------------------------------
      */
 
-    fn parse_integer_type(mut params: FnParseParams) -> ApllodbSqlParserResult<IntegerType> {
+    fn parse_binary_type(mut params: FnParseParams) -> ApllodbSqlParserResult<BinaryType> {
         let s = self_as_str(&mut params);
         match s.to_ascii_uppercase().as_str() {
-            "SMALLINT" => Ok(IntegerType::SmallIntVariant),
-            "INTEGER" => Ok(IntegerType::IntegerVariant),
-            "BIGINT" => Ok(IntegerType::BigIntVariant),
+            "BYTEA" | "BLOB" => Ok(BinaryType::ByteaVariant),
             x => {
                 eprintln!("Unexpected data type parsed: {}", x);
                 unreachable!();
@@ -318,14 +1422,14 @@ impl PestParserImpl {
 
     /*
      * ----------------------------------------------------------------------------
-     * Character Types
+     * JSON Types
      * ----------------------------------------------------------------------------
      */
 
-    fn parse_character_type(mut params: FnParseParams) -> ApllodbSqlParserResult<CharacterType> {
+    fn parse_json_type(mut params: FnParseParams) -> ApllodbSqlParserResult<JsonType> {
         let s = self_as_str(&mut params);
         match s.to_ascii_uppercase().as_str() {
-            "TEXT" => Ok(CharacterType::TextVariant),
+            "JSON" => Ok(JsonType::JsonVariant),
             x => {
                 eprintln!("Unexpected data type parsed: {}", x);
                 unreachable!();
@@ -333,12 +1437,63 @@ impl PestParserImpl {
         }
     }
 
+    /*
+     * ----------------------------------------------------------------------------
+     * Date/Time Types
+     * ----------------------------------------------------------------------------
+     */
+
+    fn parse_datetime_type(mut params: FnParseParams) -> ApllodbSqlParserResult<DatetimeType> {
+        let s = self_as_str(&mut params);
+        // Words may be separated by any whitespaces.
+        let words: Vec<String> = s
+            .split_whitespace()
+            .map(|w| w.to_ascii_uppercase())
+            .collect();
+        match words.iter().map(String::as_str).collect::<Vec<&str>>()[..] {
+            ["DATE"] => Ok(DatetimeType::DateVariant),
+            ["TIME", ..] => Ok(DatetimeType::TimeVariant),
+            ["TIMESTAMP", "WITH", ..] => Ok(DatetimeType::TimestampWithTimeZoneVariant),
+            ["TIMESTAMP", ..] => Ok(DatetimeType::TimestampVariant),
+            ["INTERVAL"] => Ok(DatetimeType::IntervalVariant),
+            ["FUZZY", "DATE"] => Ok(DatetimeType::FuzzyDateVariant),
+            ref x => {
+                eprintln!("Unexpected data type parsed: {:?}", x);
+                unreachable!();
+            }
+        }
+    }
+
+    /*
+     * ----------------------------------------------------------------------------
+     * User-Defined Types
+     * ----------------------------------------------------------------------------
+     */
+
+    fn parse_user_defined_type(mut params: FnParseParams) -> ApllodbSqlParserResult<TypeName> {
+        parse_child(
+            &mut params,
+            Rule::type_name,
+            Self::parse_type_name,
+            identity,
+        )
+    }
+
     /*
      * ================================================================================================
      * Commands:
      * ================================================================================================
      */
 
+    fn parse_script_commands(mut params: FnParseParams) -> ApllodbSqlParserResult<Vec<ApllodbAst>> {
+        parse_child_seq(
+            &mut params,
+            Rule::script_command,
+            &Self::parse_command,
+            &ApllodbAst,
+        )
+    }
+
     fn parse_command(mut params: FnParseParams) -> ApllodbSqlParserResult<Command> {
         try_parse_child(
             &mut params,
@@ -394,6 +1549,12 @@ impl PestParserImpl {
             Self::parse_drop_table_command,
             Command::DropTableCommandVariant,
         )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::create_type_command,
+            Self::parse_create_type_command,
+            Command::CreateTypeCommandVariant,
+        )?)
         .or(try_parse_child(
             &mut params,
             Rule::insert_command,
@@ -547,6 +1708,75 @@ impl PestParserImpl {
         })
     }
 
+    /*
+     * ----------------------------------------------------------------------------
+     * CREATE TYPE
+     * ----------------------------------------------------------------------------
+     */
+
+    fn parse_create_type_command(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<CreateTypeCommand> {
+        let type_name = parse_child(
+            &mut params,
+            Rule::type_name,
+            Self::parse_type_name,
+            identity,
+        )?;
+        let variant_definitions = parse_child_seq(
+            &mut params,
+            Rule::variant_definition,
+            &Self::parse_variant_definition,
+            &identity,
+        )?;
+        Ok(CreateTypeCommand {
+            type_name,
+            variant_definitions: NonEmptyVec::new(variant_definitions),
+        })
+    }
+
+    fn parse_variant_definition(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<VariantDefinition> {
+        let variant_name = parse_child(
+            &mut params,
+            Rule::variant_name,
+            Self::parse_variant_name,
+            identity,
+        )?;
+        let field_definitions = parse_child_seq(
+            &mut params,
+            Rule::field_definition,
+            &Self::parse_field_definition,
+            &identity,
+        )?;
+        Ok(VariantDefinition {
+            variant_name,
+            field_definitions,
+        })
+    }
+
+    fn parse_field_definition(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<FieldDefinition> {
+        let field_name = parse_child(
+            &mut params,
+            Rule::field_name,
+            Self::parse_field_name,
+            identity,
+        )?;
+        let data_type = parse_child(
+            &mut params,
+            Rule::data_type,
+            Self::parse_data_type,
+            identity,
+        )?;
+        Ok(FieldDefinition {
+            field_name,
+            data_type,
+        })
+    }
+
     /*
      * ----------------------------------------------------------------------------
      * DELETE
@@ -646,24 +1876,13 @@ impl PestParserImpl {
      */
 
     fn parse_select_command(mut params: FnParseParams) -> ApllodbSqlParserResult<SelectCommand> {
-        let select_fields = parse_child_seq(
+        let select_command = Self::parse_select_core(&mut params)?;
+        let set_operations = parse_child_seq(
             &mut params,
-            Rule::select_field,
-            &Self::parse_select_field,
+            Rule::set_operation,
+            &Self::parse_set_operation,
             &identity,
         )?;
-        let from_item: Option<FromItem> = try_parse_child(
-            &mut params,
-            Rule::from_item,
-            Self::parse_from_item,
-            identity,
-        )?;
-        let where_condition = try_parse_child(
-            &mut params,
-            Rule::condition,
-            Self::parse_condition,
-            identity,
-        )?;
         let order_bys: Option<Vec<OrderBy>> = {
             if let Some(first_order_by) =
                 try_parse_child(&mut params, Rule::order_by, Self::parse_order_by, identity)?
@@ -681,18 +1900,114 @@ impl PestParserImpl {
                 None
             }
         };
+        let limit = try_parse_child(
+            &mut params,
+            Rule::limit_clause,
+            Self::parse_limit_clause,
+            identity,
+        )?;
+        Ok(SelectCommand {
+            set_operations,
+            order_bys: order_bys.map(NonEmptyVec::new),
+            limit,
+            ..select_command
+        })
+    }
+
+    /// Parses children of `select_core` (silent rule) into a SelectCommand without set operations, ORDER BY nor LIMIT.
+    fn parse_select_core(params: &mut FnParseParams) -> ApllodbSqlParserResult<SelectCommand> {
+        let set_quantifier = try_parse_child(
+            params,
+            Rule::set_quantifier,
+            Self::parse_set_quantifier,
+            identity,
+        )?;
+        let select_fields = parse_child_seq(
+            params,
+            Rule::select_field,
+            &Self::parse_select_field,
+            &identity,
+        )?;
+        let from_item: Option<FromItem> =
+            try_parse_child(params, Rule::from_item, Self::parse_from_item, identity)?;
+        let where_condition =
+            try_parse_child(params, Rule::condition, Self::parse_condition, identity)?;
+        let grouping_elements = parse_child_seq(
+            params,
+            Rule::grouping_element,
+            &Self::parse_grouping_element,
+            &identity,
+        )?;
+        let having_conditions =
+            if try_parse_child(params, Rule::having_keyword, |_| Ok(()), identity)?.is_some() {
+                Some(parse_child_seq(
+                    params,
+                    Rule::condition,
+                    &Self::parse_condition,
+                    &identity,
+                )?)
+            } else {
+                None
+            };
         Ok(SelectCommand {
+            set_quantifier,
             select_fields: NonEmptyVec::new(select_fields),
             from_item,
             where_condition,
-            // TODO: grouping_elements, having_conditions, order_bys
-            grouping_elements: None,
-            having_conditions: None,
+            grouping_elements: (!grouping_elements.is_empty())
+                .then(|| NonEmptyVec::new(grouping_elements)),
+            having_conditions: having_conditions.map(NonEmptyVec::new),
+            set_operations: vec![],
+            order_bys: None,
+            limit: None,
+        })
+    }
 
-            order_bys: order_bys.map(NonEmptyVec::new),
+    fn parse_set_operation(mut params: FnParseParams) -> ApllodbSqlParserResult<SetOperation> {
+        let set_operator = parse_child(
+            &mut params,
+            Rule::set_operator,
+            Self::parse_set_operator,
+            identity,
+        )?;
+        let set_quantifier = try_parse_child(
+            &mut params,
+            Rule::set_operation_quantifier,
+            Self::parse_set_operation_quantifier,
+            identity,
+        )?;
+        let select_command = Self::parse_select_core(&mut params)?;
+        Ok(SetOperation {
+            set_operator,
+            set_quantifier,
+            select_command,
         })
     }
 
+    fn parse_set_operator(mut params: FnParseParams) -> ApllodbSqlParserResult<SetOperator> {
+        let s = self_as_str(&mut params);
+        match s.to_lowercase().as_str() {
+            "union" => Ok(SetOperator::UnionVariant),
+            "intersect" => Ok(SetOperator::IntersectVariant),
+            "except" => Ok(SetOperator::ExceptVariant),
+            x => {
+                eprintln!("Unexpected set_operator parsed: {}", x);
+                unreachable!();
+            }
+        }
+    }
+
+    fn parse_set_operation_quantifier(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<SetQuantifier> {
+        parse_child(
+            &mut params,
+            Rule::set_quantifier,
+            Self::parse_set_quantifier,
+            identity,
+        )
+    }
+
     fn parse_select_field(mut params: FnParseParams) -> ApllodbSqlParserResult<SelectField> {
         let expression = parse_child(
             &mut params,
@@ -711,17 +2026,50 @@ impl PestParserImpl {
             Self::parse_sub_from_item,
             identity,
         )?;
+        let join_clauses = parse_child_seq(
+            &mut params,
+            Rule::join_clause,
+            &Self::parse_join_clause,
+            &identity,
+        )?;
+
+        Ok(join_clauses
+            .into_iter()
+            .fold(from_item, |left, (join_type, right, on)| {
+                FromItem::JoinVariant {
+                    join_type,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                    on,
+                }
+            }))
+    }
+
+    fn parse_sub_from_item(mut params: FnParseParams) -> ApllodbSqlParserResult<FromItem> {
+        let table_name = parse_child(
+            &mut params,
+            Rule::table_name,
+            Self::parse_table_name,
+            identity,
+        )?;
+        let alias = try_parse_child(&mut params, Rule::alias, Self::parse_alias, identity)?;
+        Ok(FromItem::TableNameVariant { table_name, alias })
+    }
 
+    /// Returns join type, right from item, and ON condition.
+    fn parse_join_clause(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<(JoinType, FromItem, Option<Condition>)> {
         if let Some(join_type) = try_parse_child(
             &mut params,
             Rule::join_type,
             Self::parse_join_type,
             identity,
         )? {
-            let right_from_item = parse_child(
+            let right = parse_child(
                 &mut params,
-                Rule::from_item,
-                Self::parse_from_item,
+                Rule::sub_from_item,
+                Self::parse_sub_from_item,
                 identity,
             )?;
             let on = parse_child(
@@ -730,32 +2078,37 @@ impl PestParserImpl {
                 Self::parse_condition,
                 identity,
             )?;
-            Ok(FromItem::JoinVariant {
-                join_type,
-                left: Box::new(from_item),
-                right: Box::new(right_from_item),
-                on,
-            })
+            Ok((join_type, right, Some(on)))
         } else {
-            Ok(from_item)
+            let join_type = parse_child(
+                &mut params,
+                Rule::cross_join_type,
+                Self::parse_cross_join_type,
+                identity,
+            )?;
+            let right = parse_child(
+                &mut params,
+                Rule::sub_from_item,
+                Self::parse_sub_from_item,
+                identity,
+            )?;
+            Ok((join_type, right, None))
         }
     }
 
-    fn parse_sub_from_item(mut params: FnParseParams) -> ApllodbSqlParserResult<FromItem> {
-        let table_name = parse_child(
-            &mut params,
-            Rule::table_name,
-            Self::parse_table_name,
-            identity,
-        )?;
-        let alias = try_parse_child(&mut params, Rule::alias, Self::parse_alias, identity)?;
-        Ok(FromItem::TableNameVariant { table_name, alias })
-    }
-
     fn parse_join_type(mut params: FnParseParams) -> ApllodbSqlParserResult<JoinType> {
         let s = self_as_str(&mut params);
-        match s.to_lowercase().as_str() {
-            "inner join" => Ok(JoinType::InnerJoin),
+        let words: Vec<String> = s
+            .to_lowercase()
+            .split_whitespace()
+            .filter(|word| *word != "inner" && *word != "outer")
+            .map(str::to_string)
+            .collect();
+        match words.join(" ").as_str() {
+            "join" => Ok(JoinType::InnerJoin),
+            "left join" => Ok(JoinType::LeftOuterJoin),
+            "right join" => Ok(JoinType::RightOuterJoin),
+            "full join" => Ok(JoinType::FullOuterJoin),
             x => {
                 eprintln!("Unexpected join_type parsed: {}", x);
                 unreachable!();
@@ -763,6 +2116,22 @@ impl PestParserImpl {
         }
     }
 
+    fn parse_cross_join_type(_: FnParseParams) -> ApllodbSqlParserResult<JoinType> {
+        Ok(JoinType::CrossJoin)
+    }
+
+    fn parse_grouping_element(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<GroupingElement> {
+        let expression = parse_child(
+            &mut params,
+            Rule::expression,
+            Self::parse_expression,
+            identity,
+        )?;
+        Ok(GroupingElement::ExpressionVariant(expression))
+    }
+
     fn parse_order_by(mut params: FnParseParams) -> ApllodbSqlParserResult<OrderBy> {
         let expression = parse_child(
             &mut params,
@@ -790,6 +2159,70 @@ impl PestParserImpl {
         }
     }
 
+    fn parse_limit_clause(mut params: FnParseParams) -> ApllodbSqlParserResult<Limit> {
+        let limit_count = try_parse_child(
+            &mut params,
+            Rule::limit_count,
+            Self::parse_limit_count,
+            identity,
+        )?;
+        let offset = try_parse_child(
+            &mut params,
+            Rule::offset_clause,
+            Self::parse_offset_clause,
+            identity,
+        )?;
+        let fetch_first_count = try_parse_child(
+            &mut params,
+            Rule::fetch_first_clause,
+            Self::parse_fetch_first_clause,
+            identity,
+        )?;
+        Ok(Limit {
+            count: limit_count.or(fetch_first_count),
+            offset,
+        })
+    }
+
+    fn parse_offset_clause(mut params: FnParseParams) -> ApllodbSqlParserResult<IntegerConstant> {
+        parse_child(
+            &mut params,
+            Rule::limit_offset,
+            Self::parse_limit_offset,
+            identity,
+        )
+    }
+
+    fn parse_fetch_first_clause(
+        mut params: FnParseParams,
+    ) -> ApllodbSqlParserResult<IntegerConstant> {
+        let count = try_parse_child(
+            &mut params,
+            Rule::limit_count,
+            Self::parse_limit_count,
+            identity,
+        )?;
+        Ok(count.unwrap_or_else(|| IntegerConstant("1".into())))
+    }
+
+    fn parse_limit_count(mut params: FnParseParams) -> ApllodbSqlParserResult<IntegerConstant> {
+        parse_child(
+            &mut params,
+            Rule::integer_constant,
+            Self::parse_integer_constant,
+            identity,
+        )
+    }
+
+    fn parse_limit_offset(mut params: FnParseParams) -> ApllodbSqlParserResult<IntegerConstant> {
+        parse_child(
+            &mut params,
+            Rule::integer_constant,
+            Self::parse_integer_constant,
+            identity,
+        )
+    }
+
     /*
      * ----------------------------------------------------------------------------
      * UPDATE
@@ -883,6 +2316,51 @@ impl PestParserImpl {
         )
     }
 
+    fn parse_function_name(mut params: FnParseParams) -> ApllodbSqlParserResult<FunctionName> {
+        parse_child(
+            &mut params,
+            Rule::identifier,
+            Self::parse_identifier,
+            FunctionName,
+        )
+    }
+
+    fn parse_type_name(mut params: FnParseParams) -> ApllodbSqlParserResult<TypeName> {
+        parse_child(
+            &mut params,
+            Rule::identifier,
+            Self::parse_identifier,
+            TypeName,
+        )
+    }
+
+    fn parse_variant_name(mut params: FnParseParams) -> ApllodbSqlParserResult<VariantName> {
+        parse_child(
+            &mut params,
+            Rule::identifier,
+            Self::parse_identifier,
+            VariantName,
+        )
+    }
+
+    fn parse_field_name(mut params: FnParseParams) -> ApllodbSqlParserResult<FieldName> {
+        parse_child(
+            &mut params,
+            Rule::identifier,
+            Self::parse_identifier,
+            FieldName,
+        )
+    }
+
+    fn parse_binding_name(mut params: FnParseParams) -> ApllodbSqlParserResult<BindingName> {
+        parse_child(
+            &mut params,
+            Rule::identifier,
+            Self::parse_identifier,
+            BindingName,
+        )
+    }
+
     /*
      * ----------------------------------------------------------------------------
      * Table Elements
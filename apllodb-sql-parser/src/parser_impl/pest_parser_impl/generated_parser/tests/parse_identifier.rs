@@ -82,6 +82,12 @@ accepted_parameterized_tests! {
 
     has_keyword1: AcceptedTestParameter("SELECT_"),
     has_keyword2: AcceptedTestParameter("_SELECT"),
+
+    quoted: AcceptedTestParameter("\"a\""),
+    quoted_keyword: AcceptedTestParameter("\"SELECT\""),
+    quoted_with_space: AcceptedTestParameter("\"a b\""),
+    quoted_starts_with_digit: AcceptedTestParameter("\"1a\""),
+    quoted_with_escaped_double_quote: AcceptedTestParameter("\"a\"\"b\""),
 }
 
 partially_accepted_parameterized_tests! {
@@ -129,4 +135,7 @@ rejected_parameterized_tests! {
     starts_with_minus_sign: RejectedTestParameter("-"),
 
     keyword: RejectedTestParameter("SELECT"),
+
+    empty_quoted: RejectedTestParameter("\"\""),
+    unterminated_quoted: RejectedTestParameter("\"a"),
 }
@@ -13,17 +13,13 @@ fn test_parse_keyword_accepted() -> PestResult<()> {
         // "ACOS",
         // "ACTION",
         // "ADA",
-        "ADD",
-        // "ADMIN",
+        "ADD", // "ADMIN",
         // "AFTER",
-        // "ALL",
-        // "ALLOCATE",
+        "ALL",   // "ALLOCATE",
         "ALTER", // "ALWAYS",
-        // "AND",
-        // "ANY",
-        // "ARE",
-        // "ARRAY",
-        // "ARRAY_AGG",
+        "AND",
+        "ANY",   // "ARE",
+        "ARRAY", // "ARRAY_AGG",
         // "ARRAY_MAX_CARDINALITY",
         "AS",
         // "ASC",
@@ -119,7 +115,7 @@ fn test_parse_keyword_accepted() -> PestResult<()> {
         // "COVAR_POP",
         // "COVAR_SAMP",
         "CREATE",
-        // "CROSS",
+        "CROSS",
         // "CUBE",
         // "CUME_DIST",
         // "CURRENT",
@@ -175,8 +171,7 @@ fn test_parse_keyword_accepted() -> PestResult<()> {
         // "DIAGNOSTICS",
         // "DISCONNECT",
         // "DISPATCH",
-        // "DISTINCT",
-        // "DOMAIN",
+        "DISTINCT", // "DOMAIN",
         // "DOUBLE",
         "DROP",
         // "DYNAMIC",
@@ -196,7 +191,7 @@ fn test_parse_keyword_accepted() -> PestResult<()> {
         // "ERROR",
         // "ESCAPE",
         // "EVERY",
-        // "EXCEPT",
+        "EXCEPT",
         // "EXCLUDE",
         // "EXCLUDING",
         // "EXEC",
@@ -207,7 +202,7 @@ fn test_parse_keyword_accepted() -> PestResult<()> {
         // "EXTERNAL",
         // "EXTRACT",
         // "FALSE",
-        // "FETCH",
+        "FETCH",
         // "FILTER",
         // "FINAL",
         // "FINISH",
@@ -238,7 +233,7 @@ fn test_parse_keyword_accepted() -> PestResult<()> {
         // "FULFILL_PROCEDURE_SPECIFIC_NAME",
         // "FULFILL_PROCEDURE_SPECIFIC_SCHEMA",
         // "FULFILL_SCHEMA",
-        // "FULL",
+        "FULL",
         // "FUNCTION",
         // "FUSION",
         // "G",
@@ -278,7 +273,7 @@ fn test_parse_keyword_accepted() -> PestResult<()> {
         // "INSTEAD",
         "INT",
         // "INTEGER",
-        // "INTERSECT",
+        "INTERSECT",
         // "INTERSECTION",
         // "INTERVAL",
         // "INTO",
@@ -311,7 +306,7 @@ fn test_parse_keyword_accepted() -> PestResult<()> {
         // "LATERAL",
         // "LEAD",
         // "LEADING",
-        // "LEFT",
+        "LEFT",
         // "LENGTH",
         // "LEVEL",
         // "LIKE",
@@ -381,7 +376,7 @@ fn test_parse_keyword_accepted() -> PestResult<()> {
         // "OCTETS",
         // "OCTET_LENGTH",
         // "OF",
-        // "OFFSET",
+        "OFFSET",
         // "OLD",
         // "OMIT",
         // "ON",
@@ -390,12 +385,13 @@ fn test_parse_keyword_accepted() -> PestResult<()> {
         // "OPEN",
         // "OPTION",
         // "OPTIONS",
-        "OR", "ORDER",
+        "OR",
+        "ORDER",
         // "ORDERING",
         // "ORDINALITY",
         // "OTHERS",
         // "OUT",
-        // "OUTER",
+        "OUTER",
         // "OUTPUT",
         // "OVER",
         // "OVERFLOW",
@@ -483,7 +479,7 @@ fn test_parse_keyword_accepted() -> PestResult<()> {
         // "RETURNS_ONLY_PASS_THROUGH",
         // "RET_ONLY_PASS_THRU",
         // "REVOKE",
-        // "RIGHT",
+        "RIGHT",
         // "ROLE",
         // "ROLLBACK",
         // "ROLLUP",
@@ -608,7 +604,7 @@ fn test_parse_keyword_accepted() -> PestResult<()> {
         // "UNCOMMITTED",
         // "UNCONDITIONAL",
         // "UNDER",
-        // "UNION",
+        "UNION",
         // "UNIQUE",
         // "UNKNOWN",
         // "UNNAMED",
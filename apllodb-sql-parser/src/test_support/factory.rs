@@ -1,11 +1,17 @@
 use crate::apllodb_ast::{
-    Action, AddColumn, Alias, AlterTableCommand, BinaryOperator, CharacterType, ColumnConstraint,
-    ColumnDefinition, ColumnName, ColumnReference, Condition, Constant, Correlation,
-    CreateDatabaseCommand, CreateTableCommand, DataType, DatabaseName, DeleteCommand, DropColumn,
-    DropTableCommand, Expression, FromItem, GroupingElement, Identifier, InsertCommand,
-    InsertValue, IntegerConstant, IntegerType, JoinType, NonEmptyVec, NumericConstant, OrderBy,
-    Ordering, SelectCommand, SelectField, StringConstant, TableConstraint, TableElement, TableName,
-    UnaryOperator, UpdateCommand, UseDatabaseCommand,
+    Action, AddColumn, Alias, AlterTableCommand, BetweenPredicate, BinaryConstant, BinaryOperator,
+    BinaryType, BindingName, BooleanConstant, BooleanType, CaseExpression, CharacterType,
+    ColumnConstraint, ColumnDefinition, ColumnName, ColumnReference, Condition, Constant,
+    Correlation, CreateDatabaseCommand, CreateTableCommand, CreateTypeCommand,
+    CurrentDatetimeFunction, DataType, DatabaseName, DatetimeConstant, DatetimeType,
+    DecimalConstant, DecimalType, DeleteCommand, DropColumn, DropTableCommand, Expression,
+    FieldDefinition, FieldName, FloatConstant, FloatingPointType, FromItem, FunctionArguments,
+    FunctionCall, FunctionName, GroupingElement, Identifier, InPredicate, InsertCommand,
+    InsertValue, IntegerConstant, IntegerType, JoinType, JsonConstant, JsonType, LikePredicate,
+    Limit, MatchArm, MatchExpression, MatchPattern, NonEmptyVec, NumericConstant, OrderBy,
+    Ordering, PatternBinding, SelectCommand, SelectField, SetOperation, SetOperator, SetQuantifier,
+    StringConstant, TableConstraint, TableElement, TableName, TypeName, UnaryOperator,
+    UpdateCommand, UseDatabaseCommand, VariantDefinition, VariantName, VariantPattern, WhenClause,
 };
 
 impl AlterTableCommand {
@@ -54,6 +60,33 @@ impl CreateTableCommand {
     }
 }
 
+impl CreateTypeCommand {
+    /// `variant_definitions`: (variant name, [(field name, data type)])
+    pub fn factory(
+        type_name: &str,
+        variant_definitions: Vec<(&str, Vec<(&str, DataType)>)>,
+    ) -> Self {
+        Self {
+            type_name: TypeName(Identifier(type_name.to_string())),
+            variant_definitions: NonEmptyVec::new(
+                variant_definitions
+                    .into_iter()
+                    .map(|(variant_name, field_definitions)| VariantDefinition {
+                        variant_name: VariantName(Identifier(variant_name.to_string())),
+                        field_definitions: field_definitions
+                            .into_iter()
+                            .map(|(field_name, data_type)| FieldDefinition {
+                                field_name: FieldName(Identifier(field_name.to_string())),
+                                data_type,
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
 impl SelectCommand {
     pub fn factory(
         select_fields: Vec<SelectField>,
@@ -64,12 +97,46 @@ impl SelectCommand {
         order_bys: Option<Vec<OrderBy>>,
     ) -> Self {
         Self {
+            set_quantifier: None,
             select_fields: NonEmptyVec::new(select_fields),
             from_item,
             where_condition,
             grouping_elements: grouping_elements.map(NonEmptyVec::new),
             having_conditions: having_conditions.map(NonEmptyVec::new),
+            set_operations: vec![],
             order_bys: order_bys.map(NonEmptyVec::new),
+            limit: None,
+        }
+    }
+
+    pub fn with_set_quantifier(self, set_quantifier: SetQuantifier) -> Self {
+        Self {
+            set_quantifier: Some(set_quantifier),
+            ..self
+        }
+    }
+
+    pub fn with_set_operation(
+        mut self,
+        set_operator: SetOperator,
+        set_quantifier: Option<SetQuantifier>,
+        select_command: SelectCommand,
+    ) -> Self {
+        self.set_operations.push(SetOperation {
+            set_operator,
+            set_quantifier,
+            select_command,
+        });
+        self
+    }
+
+    pub fn with_limit(self, count: Option<&str>, offset: Option<&str>) -> Self {
+        Self {
+            limit: Some(Limit {
+                count: count.map(|c| IntegerConstant(c.to_string())),
+                offset: offset.map(|o| IntegerConstant(o.to_string())),
+            }),
+            ..self
         }
     }
 }
@@ -92,11 +159,20 @@ impl FromItem {
     }
 
     pub fn factory_inner_join(left: Self, right: Self, on: Expression) -> Self {
+        Self::factory_join(JoinType::InnerJoin, left, right, Some(on))
+    }
+
+    pub fn factory_join(
+        join_type: JoinType,
+        left: Self,
+        right: Self,
+        on: Option<Expression>,
+    ) -> Self {
         Self::JoinVariant {
-            join_type: JoinType::InnerJoin,
+            join_type,
             left: Box::new(left),
             right: Box::new(right),
-            on: Condition::factory(on),
+            on: on.map(Condition::factory),
         }
     }
 }
@@ -229,14 +305,56 @@ impl Expression {
         Self::ConstantVariant(Constant::factory_null())
     }
 
+    pub fn factory_boolean(b: bool) -> Self {
+        Self::ConstantVariant(Constant::factory_boolean(b))
+    }
+
     pub fn factory_integer(integer: &str) -> Self {
         Self::ConstantVariant(Constant::factory_integer(integer))
     }
 
+    pub fn factory_decimal(decimal: &str) -> Self {
+        Self::ConstantVariant(Constant::factory_decimal(decimal))
+    }
+
+    pub fn factory_float(float: &str) -> Self {
+        Self::ConstantVariant(Constant::factory_float(float))
+    }
+
     pub fn factory_text(text: &str) -> Self {
         Self::ConstantVariant(Constant::factory_text(text))
     }
 
+    pub fn factory_escape_text(text: &str) -> Self {
+        Self::ConstantVariant(Constant::factory_escape_text(text))
+    }
+
+    pub fn factory_datetime(datetime_type: DatetimeType, text: &str) -> Self {
+        Self::ConstantVariant(Constant::factory_datetime(datetime_type, text))
+    }
+
+    pub fn factory_hex_binary(hex: &str) -> Self {
+        Self::ConstantVariant(Constant::BinaryConstantVariant(BinaryConstant::HexVariant(
+            hex.to_string(),
+        )))
+    }
+
+    pub fn factory_typed_binary(text: &str) -> Self {
+        Self::ConstantVariant(Constant::BinaryConstantVariant(
+            BinaryConstant::TypedVariant(StringConstant::StandardVariant(text.to_string())),
+        ))
+    }
+
+    pub fn factory_json(text: &str) -> Self {
+        Self::ConstantVariant(Constant::JsonConstantVariant(JsonConstant(
+            StringConstant::StandardVariant(text.to_string()),
+        )))
+    }
+
+    pub fn factory_current_datetime(current_datetime_function: CurrentDatetimeFunction) -> Self {
+        Self::CurrentDatetimeFunctionVariant(current_datetime_function)
+    }
+
     pub fn factory_colref(column_reference: ColumnReference) -> Self {
         Self::ColumnReferenceVariant(column_reference)
     }
@@ -246,12 +364,130 @@ impl Expression {
     }
 
     pub fn factory_eq(left_expression: Expression, right_expression: Expression) -> Self {
+        Self::factory_bin_op(BinaryOperator::Equal, left_expression, right_expression)
+    }
+
+    pub fn factory_bin_op(
+        binary_operator: BinaryOperator,
+        left_expression: Expression,
+        right_expression: Expression,
+    ) -> Self {
         Self::BinaryOperatorVariant(
-            BinaryOperator::Equal,
+            binary_operator,
             Box::new(left_expression),
             Box::new(right_expression),
         )
     }
+
+    pub fn factory_function_call(function_name: &str, arguments: Vec<Expression>) -> Self {
+        Self::FunctionCallVariant(FunctionCall {
+            function_name: FunctionName(Identifier(function_name.to_string())),
+            arguments: FunctionArguments::ExpressionsVariant {
+                set_quantifier: None,
+                expressions: arguments,
+            },
+        })
+    }
+
+    /// `function_name(DISTINCT arguments...)`
+    pub fn factory_function_call_distinct(function_name: &str, arguments: Vec<Expression>) -> Self {
+        Self::FunctionCallVariant(FunctionCall {
+            function_name: FunctionName(Identifier(function_name.to_string())),
+            arguments: FunctionArguments::ExpressionsVariant {
+                set_quantifier: Some(SetQuantifier::DistinctVariant),
+                expressions: arguments,
+            },
+        })
+    }
+
+    /// `function_name(*)`
+    pub fn factory_function_call_asterisk(function_name: &str) -> Self {
+        Self::FunctionCallVariant(FunctionCall {
+            function_name: FunctionName(Identifier(function_name.to_string())),
+            arguments: FunctionArguments::AsteriskVariant,
+        })
+    }
+
+    /// `when_clauses`: (WHEN expression, THEN result)
+    pub fn factory_case(
+        operand: Option<Expression>,
+        when_clauses: Vec<(Expression, Expression)>,
+        else_result: Option<Expression>,
+    ) -> Self {
+        Self::CaseExpressionVariant(CaseExpression {
+            operand: operand.map(Box::new),
+            when_clauses: NonEmptyVec::new(
+                when_clauses
+                    .into_iter()
+                    .map(|(expression, result)| WhenClause { expression, result })
+                    .collect(),
+            ),
+            else_result: else_result.map(Box::new),
+        })
+    }
+
+    /// `match_arms`: (pattern, result)
+    pub fn factory_match(operand: Expression, match_arms: Vec<(MatchPattern, Expression)>) -> Self {
+        Self::MatchExpressionVariant(MatchExpression {
+            operand: Box::new(operand),
+            match_arms: NonEmptyVec::new(
+                match_arms
+                    .into_iter()
+                    .map(|(pattern, result)| MatchArm { pattern, result })
+                    .collect(),
+            ),
+        })
+    }
+
+    pub fn factory_cast(expression: Expression, data_type: DataType) -> Self {
+        Self::CastVariant(Box::new(expression), data_type)
+    }
+
+    pub fn factory_in(negated: bool, expression: Expression, list: Vec<Expression>) -> Self {
+        Self::InPredicateVariant(InPredicate {
+            negated,
+            expression: Box::new(expression),
+            list: NonEmptyVec::new(list),
+        })
+    }
+
+    pub fn factory_between(
+        negated: bool,
+        expression: Expression,
+        low: Expression,
+        high: Expression,
+    ) -> Self {
+        Self::BetweenPredicateVariant(BetweenPredicate {
+            negated,
+            expression: Box::new(expression),
+            low: Box::new(low),
+            high: Box::new(high),
+        })
+    }
+
+    pub fn factory_like(negated: bool, expression: Expression, pattern: Expression) -> Self {
+        Self::LikePredicateVariant(LikePredicate {
+            negated,
+            expression: Box::new(expression),
+            pattern: Box::new(pattern),
+        })
+    }
+
+    pub fn factory_array(elements: Vec<Expression>) -> Self {
+        Self::ArrayConstructorVariant(elements)
+    }
+
+    pub fn factory_subscript(array: Expression, index: Expression) -> Self {
+        Self::SubscriptVariant(Box::new(array), Box::new(index))
+    }
+
+    pub fn factory_any(
+        binary_operator: BinaryOperator,
+        left_expression: Expression,
+        array: Expression,
+    ) -> Self {
+        Self::AnyComparisonVariant(binary_operator, Box::new(left_expression), Box::new(array))
+    }
 }
 
 impl Constant {
@@ -259,14 +495,63 @@ impl Constant {
         Self::NullVariant
     }
 
+    pub fn factory_boolean(b: bool) -> Self {
+        Self::BooleanConstantVariant(if b {
+            BooleanConstant::TrueVariant
+        } else {
+            BooleanConstant::FalseVariant
+        })
+    }
+
     pub fn factory_integer(integer: &str) -> Self {
         Self::NumericConstantVariant(NumericConstant::IntegerConstantVariant(IntegerConstant(
             integer.to_string(),
         )))
     }
 
+    pub fn factory_decimal(decimal: &str) -> Self {
+        Self::NumericConstantVariant(NumericConstant::DecimalConstantVariant(DecimalConstant(
+            decimal.to_string(),
+        )))
+    }
+
+    pub fn factory_float(float: &str) -> Self {
+        Self::NumericConstantVariant(NumericConstant::FloatConstantVariant(FloatConstant(
+            float.to_string(),
+        )))
+    }
+
     pub fn factory_text(text: &str) -> Self {
-        Self::StringConstantVariant(StringConstant(text.to_string()))
+        Self::StringConstantVariant(StringConstant::StandardVariant(text.to_string()))
+    }
+
+    pub fn factory_escape_text(text: &str) -> Self {
+        Self::StringConstantVariant(StringConstant::EscapeVariant(text.to_string()))
+    }
+
+    pub fn factory_datetime(datetime_type: DatetimeType, text: &str) -> Self {
+        Self::DatetimeConstantVariant(DatetimeConstant {
+            datetime_type,
+            string_constant: StringConstant::StandardVariant(text.to_string()),
+        })
+    }
+}
+
+impl MatchPattern {
+    /// `pattern_bindings`: binding name, or None for `_`
+    pub fn factory_variant(variant_name: &str, pattern_bindings: Vec<Option<&str>>) -> Self {
+        Self::VariantPatternVariant(VariantPattern {
+            variant_name: VariantName(Identifier(variant_name.to_string())),
+            pattern_bindings: pattern_bindings
+                .into_iter()
+                .map(|binding| match binding {
+                    Some(name) => PatternBinding::BindingNameVariant(BindingName(Identifier(
+                        name.to_string(),
+                    ))),
+                    None => PatternBinding::WildcardVariant,
+                })
+                .collect(),
+        })
     }
 }
 
@@ -314,7 +599,78 @@ impl DataType {
         DataType::IntegerTypeVariant(IntegerType::IntegerVariant)
     }
 
+    pub fn real() -> Self {
+        DataType::FloatingPointTypeVariant(FloatingPointType::RealVariant)
+    }
+
+    pub fn double_precision() -> Self {
+        DataType::FloatingPointTypeVariant(FloatingPointType::DoublePrecisionVariant)
+    }
+
+    pub fn numeric(precision: Option<&str>, scale: Option<&str>) -> Self {
+        DataType::DecimalTypeVariant(DecimalType {
+            precision: precision.map(|p| IntegerConstant(p.to_string())),
+            scale: scale.map(|s| IntegerConstant(s.to_string())),
+        })
+    }
+
     pub fn text() -> Self {
         DataType::CharacterTypeVariant(CharacterType::TextVariant)
     }
+
+    pub fn varchar(length: &str) -> Self {
+        DataType::CharacterTypeVariant(CharacterType::VarcharVariant(IntegerConstant(
+            length.to_string(),
+        )))
+    }
+
+    pub fn char(length: Option<&str>) -> Self {
+        DataType::CharacterTypeVariant(CharacterType::CharVariant(
+            length.map(|l| IntegerConstant(l.to_string())),
+        ))
+    }
+
+    pub fn boolean() -> Self {
+        DataType::BooleanTypeVariant(BooleanType::BooleanVariant)
+    }
+
+    pub fn bytea() -> Self {
+        DataType::BinaryTypeVariant(BinaryType::ByteaVariant)
+    }
+
+    pub fn json() -> Self {
+        DataType::JsonTypeVariant(JsonType::JsonVariant)
+    }
+
+    pub fn date() -> Self {
+        DataType::DatetimeTypeVariant(DatetimeType::DateVariant)
+    }
+
+    pub fn time() -> Self {
+        DataType::DatetimeTypeVariant(DatetimeType::TimeVariant)
+    }
+
+    pub fn timestamp() -> Self {
+        DataType::DatetimeTypeVariant(DatetimeType::TimestampVariant)
+    }
+
+    pub fn timestamp_with_time_zone() -> Self {
+        DataType::DatetimeTypeVariant(DatetimeType::TimestampWithTimeZoneVariant)
+    }
+
+    pub fn interval() -> Self {
+        DataType::DatetimeTypeVariant(DatetimeType::IntervalVariant)
+    }
+
+    pub fn fuzzy_date() -> Self {
+        DataType::DatetimeTypeVariant(DatetimeType::FuzzyDateVariant)
+    }
+
+    pub fn user_defined(type_name: &str) -> Self {
+        DataType::UserDefinedTypeVariant(TypeName(Identifier(type_name.to_string())))
+    }
+
+    pub fn array(element_type: DataType) -> Self {
+        DataType::ArrayTypeVariant(Box::new(element_type))
+    }
 }
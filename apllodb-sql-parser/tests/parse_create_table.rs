@@ -56,6 +56,167 @@ fn test_create_table_accepted() {
                 ))],
             ),
         ),
+        (
+            "CREATE TABLE t (r REAL, d DOUBLE  PRECISION, n NUMERIC, n1 numeric(5), n2 DECIMAL (10, 2))",
+            CreateTableCommand::factory(
+                "t",
+                vec![
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "r",
+                        DataType::real(),
+                        vec![],
+                    )),
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "d",
+                        DataType::double_precision(),
+                        vec![],
+                    )),
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "n",
+                        DataType::numeric(None, None),
+                        vec![],
+                    )),
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "n1",
+                        DataType::numeric(Some("5"), None),
+                        vec![],
+                    )),
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "n2",
+                        DataType::numeric(Some("10"), Some("2")),
+                        vec![],
+                    )),
+                ],
+            ),
+        ),
+        (
+            "CREATE TABLE t (d DATE, t TIME, ts TIMESTAMP, tstz TIMESTAMP WITH TIME ZONE, i INTERVAL)",
+            CreateTableCommand::factory(
+                "t",
+                vec![
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "d",
+                        DataType::date(),
+                        vec![],
+                    )),
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "t",
+                        DataType::time(),
+                        vec![],
+                    )),
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "ts",
+                        DataType::timestamp(),
+                        vec![],
+                    )),
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "tstz",
+                        DataType::timestamp_with_time_zone(),
+                        vec![],
+                    )),
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "i",
+                        DataType::interval(),
+                        vec![],
+                    )),
+                ],
+            ),
+        ),
+        (
+            "CREATE TABLE t (b1 BOOLEAN, b2 bool, booleans TEXT)",
+            CreateTableCommand::factory(
+                "t",
+                vec![
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "b1",
+                        DataType::boolean(),
+                        vec![],
+                    )),
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "b2",
+                        DataType::boolean(),
+                        vec![],
+                    )),
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "booleans",
+                        DataType::text(),
+                        vec![],
+                    )),
+                ],
+            ),
+        ),
+        (
+            "CREATE TABLE t (v1 VARCHAR(10), v2 character  varying (3), c1 CHAR(5), c2 character, c3 char (2), chars TEXT)",
+            CreateTableCommand::factory(
+                "t",
+                vec![
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "v1",
+                        DataType::varchar("10"),
+                        vec![],
+                    )),
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "v2",
+                        DataType::varchar("3"),
+                        vec![],
+                    )),
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "c1",
+                        DataType::char(Some("5")),
+                        vec![],
+                    )),
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "c2",
+                        DataType::char(None),
+                        vec![],
+                    )),
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "c3",
+                        DataType::char(Some("2")),
+                        vec![],
+                    )),
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "chars",
+                        DataType::text(),
+                        vec![],
+                    )),
+                ],
+            ),
+        ),
+        (
+            "CREATE TABLE t (b1 BYTEA, b2 blob, j JSON)",
+            CreateTableCommand::factory(
+                "t",
+                vec![
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "b1",
+                        DataType::bytea(),
+                        vec![],
+                    )),
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "b2",
+                        DataType::bytea(),
+                        vec![],
+                    )),
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "j",
+                        DataType::json(),
+                        vec![],
+                    )),
+                ],
+            ),
+        ),
+        (
+            "CREATE TABLE t (born FUZZY DATE)",
+            CreateTableCommand::factory(
+                "t",
+                vec![TableElement::factory_coldef(ColumnDefinition::factory(
+                    "born",
+                    DataType::fuzzy_date(),
+                    vec![],
+                ))],
+            ),
+        ),
         (
             "CREATE TABLE t (id INTEGER, c1 INTEGER, PRIMARY KEY (id, c1))",
             CreateTableCommand::factory(
@@ -113,6 +274,53 @@ fn test_create_table_accepted() {
                 ],
             ),
         ),
+        (
+            // user-defined types, even starting with a built-in type name
+            "CREATE TABLE t (s shape NOT NULL, tx texture, \"integer\" \"INTEGER\")",
+            CreateTableCommand::factory(
+                "t",
+                vec![
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "s",
+                        DataType::user_defined("shape"),
+                        vec![ColumnConstraint::NotNullVariant],
+                    )),
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "tx",
+                        DataType::user_defined("texture"),
+                        vec![],
+                    )),
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "integer",
+                        DataType::user_defined("INTEGER"),
+                        vec![],
+                    )),
+                ],
+            ),
+        ),
+        (
+            "CREATE TABLE t (tags TEXT[] NOT NULL, matrix INTEGER[][], shapes shape[])",
+            CreateTableCommand::factory(
+                "t",
+                vec![
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "tags",
+                        DataType::array(DataType::text()),
+                        vec![ColumnConstraint::NotNullVariant],
+                    )),
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "matrix",
+                        DataType::array(DataType::array(DataType::integer())),
+                        vec![],
+                    )),
+                    TableElement::factory_coldef(ColumnDefinition::factory(
+                        "shapes",
+                        DataType::array(DataType::user_defined("shape")),
+                        vec![],
+                    )),
+                ],
+            ),
+        ),
     ];
 
     let parser = ApllodbSqlParser::default();
@@ -142,6 +350,18 @@ fn test_create_table_rejected() {
         "CREATE TABLE t c1 INTEGER NOT NULL",
         // `NOT NULL` is a keyword, only a space is allowed.
         "CREATE TABLE t (c1 INTEGER NOT  NULL)",
+        // `DOUBLE` alone is not a type.
+        "CREATE TABLE t (c1 DOUBLE)",
+        // Scale without precision.
+        "CREATE TABLE t (c1 NUMERIC(, 2))",
+        // Precision must be an integer.
+        "CREATE TABLE t (c1 NUMERIC(1.5))",
+        // VARCHAR requires length.
+        "CREATE TABLE t (c1 VARCHAR)",
+        // `CHARACTER VARYING` is not separated by a space.
+        "CREATE TABLE t (c1 CHARACTERVARYING(3))",
+        // Array types do not have a size.
+        "CREATE TABLE t (c1 TEXT[3])",
     ];
 
     let parser = ApllodbSqlParser::default();
@@ -0,0 +1,89 @@
+use apllodb_sql_parser::{
+    apllodb_ast::{Command, CreateTypeCommand, DataType},
+    ApllodbAst, ApllodbSqlParser,
+};
+
+use apllodb_test_support::setup::setup_test_logger;
+
+#[ctor::ctor]
+fn test_setup() {
+    setup_test_logger();
+}
+
+#[test]
+fn test_create_type_accepted() {
+    let sql_vs_expected_ast: Vec<(&str, CreateTypeCommand)> = vec![
+        (
+            "CREATE TYPE shape AS VARIANT (circle(r INTEGER), rect(w INTEGER, h INTEGER))",
+            CreateTypeCommand::factory(
+                "shape",
+                vec![
+                    ("circle", vec![("r", DataType::integer())]),
+                    (
+                        "rect",
+                        vec![("w", DataType::integer()), ("h", DataType::integer())],
+                    ),
+                ],
+            ),
+        ),
+        (
+            "create type color as enum (red, green, blue);",
+            CreateTypeCommand::factory(
+                "color",
+                vec![("red", vec![]), ("green", vec![]), ("blue", vec![])],
+            ),
+        ),
+        (
+            // field of a user-defined type
+            "CREATE TYPE tagged AS ENUM (untagged, tagged(c color, label TEXT))",
+            CreateTypeCommand::factory(
+                "tagged",
+                vec![
+                    ("untagged", vec![]),
+                    (
+                        "tagged",
+                        vec![
+                            ("c", DataType::user_defined("color")),
+                            ("label", DataType::text()),
+                        ],
+                    ),
+                ],
+            ),
+        ),
+    ];
+
+    let parser = ApllodbSqlParser::default();
+
+    for (sql, expected_ast) in sql_vs_expected_ast {
+        match parser.parse(sql) {
+            Ok(ApllodbAst(Command::CreateTypeCommandVariant(create_type_command))) => {
+                assert_eq!(create_type_command, expected_ast);
+            }
+            Ok(ast) => panic!(
+                "'{}' should be parsed as CREATE TYPE but is parsed like: {:?}",
+                sql, ast
+            ),
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
+#[test]
+fn test_create_type_rejected() {
+    let sqls: Vec<&str> = vec![
+        // Lack ENUM / VARIANT.
+        "CREATE TYPE shape AS (circle(r INTEGER))",
+        // Lack variants.
+        "CREATE TYPE shape AS VARIANT ()",
+        // Lack data-type of a field.
+        "CREATE TYPE shape AS VARIANT (circle(r))",
+        // Empty fields should be omitted with parentheses.
+        "CREATE TYPE shape AS VARIANT (point())",
+    ];
+
+    let parser = ApllodbSqlParser::default();
+
+    for sql in sqls {
+        assert!(parser.parse(sql).is_err(), "{}", sql);
+    }
+}
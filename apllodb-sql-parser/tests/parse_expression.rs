@@ -1,5 +1,9 @@
 use apllodb_sql_parser::{
-    apllodb_ast::{Command, Expression, SelectCommand, UnaryOperator},
+    apllodb_ast::{
+        BinaryOperator, ColumnReference, Command, CurrentDatetimeFunction, DataType, DatetimeType,
+        Expression, FunctionArguments, FunctionCall, FunctionName, Identifier, MatchPattern,
+        SelectCommand, SetQuantifier, UnaryOperator,
+    },
     ApllodbAst, ApllodbSqlParser,
 };
 
@@ -14,6 +18,16 @@ fn test_setup() {
 fn test_constant_accepted() {
     let expression_vs_expected_ast: Vec<(&str, Expression)> = vec![
         ("null", Expression::factory_null()),
+        ("TRUE", Expression::factory_boolean(true)),
+        ("false", Expression::factory_boolean(false)),
+        (
+            "true_flag",
+            Expression::factory_colref(ColumnReference::factory(None, "true_flag")),
+        ),
+        (
+            "NOT false",
+            Expression::factory_uni_op(UnaryOperator::Not, Expression::factory_boolean(false)),
+        ),
         ("0", Expression::factory_integer("0")),
         (
             // u128::MAX + 1
@@ -31,6 +45,450 @@ fn test_constant_accepted() {
                 Expression::factory_uni_op(UnaryOperator::Minus, Expression::factory_integer("1")),
             ),
         ),
+        ("1.50", Expression::factory_decimal("1.50")),
+        ("1.", Expression::factory_decimal("1.")),
+        (".5", Expression::factory_decimal(".5")),
+        (
+            "-0.5",
+            Expression::factory_uni_op(UnaryOperator::Minus, Expression::factory_decimal("0.5")),
+        ),
+        ("1e3", Expression::factory_float("1e3")),
+        ("1.5E-3", Expression::factory_float("1.5E-3")),
+        (".5e+2", Expression::factory_float(".5e+2")),
+    ];
+
+    let parser = ApllodbSqlParser::default();
+
+    for (expression, expected_ast) in expression_vs_expected_ast {
+        match parser.parse(format!("SELECT {}", expression)) {
+            Ok(ApllodbAst(Command::SelectCommandVariant(SelectCommand {
+                select_fields, ..
+            }))) => {
+                let fields = select_fields.into_vec();
+                assert_eq!(fields.len(), 1);
+                assert_eq!(&fields[0].expression, &expected_ast);
+            }
+            x => panic!("{:#?}", x),
+        }
+    }
+}
+
+#[test]
+fn test_comparison_accepted() {
+    let expression_vs_expected_ast: Vec<(&str, Expression)> = vec![
+        (
+            "c = 1",
+            Expression::factory_eq(
+                Expression::factory_colref(ColumnReference::factory(None, "c")),
+                Expression::factory_integer("1"),
+            ),
+        ),
+        (
+            "c <> 1",
+            Expression::factory_bin_op(
+                BinaryOperator::NotEqual,
+                Expression::factory_colref(ColumnReference::factory(None, "c")),
+                Expression::factory_integer("1"),
+            ),
+        ),
+        (
+            "c != 1",
+            Expression::factory_bin_op(
+                BinaryOperator::NotEqual,
+                Expression::factory_colref(ColumnReference::factory(None, "c")),
+                Expression::factory_integer("1"),
+            ),
+        ),
+        (
+            "c<1",
+            Expression::factory_bin_op(
+                BinaryOperator::LessThan,
+                Expression::factory_colref(ColumnReference::factory(None, "c")),
+                Expression::factory_integer("1"),
+            ),
+        ),
+        (
+            "c <= 1",
+            Expression::factory_bin_op(
+                BinaryOperator::LessThanOrEqual,
+                Expression::factory_colref(ColumnReference::factory(None, "c")),
+                Expression::factory_integer("1"),
+            ),
+        ),
+        (
+            "c>1",
+            Expression::factory_bin_op(
+                BinaryOperator::GreaterThan,
+                Expression::factory_colref(ColumnReference::factory(None, "c")),
+                Expression::factory_integer("1"),
+            ),
+        ),
+        (
+            "c >= -1",
+            Expression::factory_bin_op(
+                BinaryOperator::GreaterThanOrEqual,
+                Expression::factory_colref(ColumnReference::factory(None, "c")),
+                Expression::factory_uni_op(UnaryOperator::Minus, Expression::factory_integer("1")),
+            ),
+        ),
+    ];
+
+    let parser = ApllodbSqlParser::default();
+
+    for (expression, expected_ast) in expression_vs_expected_ast {
+        match parser.parse(format!("SELECT {}", expression)) {
+            Ok(ApllodbAst(Command::SelectCommandVariant(SelectCommand {
+                select_fields, ..
+            }))) => {
+                let fields = select_fields.into_vec();
+                assert_eq!(fields.len(), 1);
+                assert_eq!(&fields[0].expression, &expected_ast);
+            }
+            x => panic!("{:#?}", x),
+        }
+    }
+}
+
+#[test]
+fn test_logical_operator_accepted() {
+    let colref = |name: &str| Expression::factory_colref(ColumnReference::factory(None, name));
+
+    let expression_vs_expected_ast: Vec<(&str, Expression)> = vec![
+        (
+            "a AND b",
+            Expression::factory_bin_op(BinaryOperator::And, colref("a"), colref("b")),
+        ),
+        (
+            "a or b",
+            Expression::factory_bin_op(BinaryOperator::Or, colref("a"), colref("b")),
+        ),
+        (
+            "NOT a",
+            Expression::factory_uni_op(UnaryOperator::Not, colref("a")),
+        ),
+        (
+            // AND binds tighter than OR
+            "a OR b AND c",
+            Expression::factory_bin_op(
+                BinaryOperator::Or,
+                colref("a"),
+                Expression::factory_bin_op(BinaryOperator::And, colref("b"), colref("c")),
+            ),
+        ),
+        (
+            // left-associative
+            "a AND b AND c",
+            Expression::factory_bin_op(
+                BinaryOperator::And,
+                Expression::factory_bin_op(BinaryOperator::And, colref("a"), colref("b")),
+                colref("c"),
+            ),
+        ),
+        (
+            // parentheses
+            "(a OR b) AND c",
+            Expression::factory_bin_op(
+                BinaryOperator::And,
+                Expression::factory_bin_op(BinaryOperator::Or, colref("a"), colref("b")),
+                colref("c"),
+            ),
+        ),
+        (
+            // NOT binds looser than comparison
+            "NOT a = 1 AND NOT NOT b",
+            Expression::factory_bin_op(
+                BinaryOperator::And,
+                Expression::factory_uni_op(
+                    UnaryOperator::Not,
+                    Expression::factory_eq(colref("a"), Expression::factory_integer("1")),
+                ),
+                Expression::factory_uni_op(
+                    UnaryOperator::Not,
+                    Expression::factory_uni_op(UnaryOperator::Not, colref("b")),
+                ),
+            ),
+        ),
+        (
+            // unary minus binds tighter than comparison
+            "-a = 1",
+            Expression::factory_eq(
+                Expression::factory_uni_op(UnaryOperator::Minus, colref("a")),
+                Expression::factory_integer("1"),
+            ),
+        ),
+        (
+            // keyword-prefixed identifiers are not operators
+            "orange AND notice",
+            Expression::factory_bin_op(BinaryOperator::And, colref("orange"), colref("notice")),
+        ),
+    ];
+
+    let parser = ApllodbSqlParser::default();
+
+    for (expression, expected_ast) in expression_vs_expected_ast {
+        match parser.parse(format!("SELECT {}", expression)) {
+            Ok(ApllodbAst(Command::SelectCommandVariant(SelectCommand {
+                select_fields, ..
+            }))) => {
+                let fields = select_fields.into_vec();
+                assert_eq!(fields.len(), 1);
+                assert_eq!(&fields[0].expression, &expected_ast);
+            }
+            x => panic!("{:#?}", x),
+        }
+    }
+}
+
+#[test]
+fn test_arithmetic_operator_accepted() {
+    let colref = |name: &str| Expression::factory_colref(ColumnReference::factory(None, name));
+
+    let expression_vs_expected_ast: Vec<(&str, Expression)> = vec![
+        (
+            "a + 1",
+            Expression::factory_bin_op(
+                BinaryOperator::Plus,
+                colref("a"),
+                Expression::factory_integer("1"),
+            ),
+        ),
+        (
+            "a - b",
+            Expression::factory_bin_op(BinaryOperator::Minus, colref("a"), colref("b")),
+        ),
+        (
+            "a * b",
+            Expression::factory_bin_op(BinaryOperator::Multiply, colref("a"), colref("b")),
+        ),
+        (
+            "a / b",
+            Expression::factory_bin_op(BinaryOperator::Divide, colref("a"), colref("b")),
+        ),
+        (
+            "a % b",
+            Expression::factory_bin_op(BinaryOperator::Modulo, colref("a"), colref("b")),
+        ),
+        (
+            // multiplicative binds tighter than additive
+            "a + b * c",
+            Expression::factory_bin_op(
+                BinaryOperator::Plus,
+                colref("a"),
+                Expression::factory_bin_op(BinaryOperator::Multiply, colref("b"), colref("c")),
+            ),
+        ),
+        (
+            // left-associative
+            "a - b - c",
+            Expression::factory_bin_op(
+                BinaryOperator::Minus,
+                Expression::factory_bin_op(BinaryOperator::Minus, colref("a"), colref("b")),
+                colref("c"),
+            ),
+        ),
+        (
+            // parentheses
+            "(a + b) % c",
+            Expression::factory_bin_op(
+                BinaryOperator::Modulo,
+                Expression::factory_bin_op(BinaryOperator::Plus, colref("a"), colref("b")),
+                colref("c"),
+            ),
+        ),
+        (
+            // unary minus binds tighter than binary operators
+            "a - -b",
+            Expression::factory_bin_op(
+                BinaryOperator::Minus,
+                colref("a"),
+                Expression::factory_uni_op(UnaryOperator::Minus, colref("b")),
+            ),
+        ),
+        (
+            // arithmetic binds tighter than comparison
+            "a + 1 >= b * 2",
+            Expression::factory_bin_op(
+                BinaryOperator::GreaterThanOrEqual,
+                Expression::factory_bin_op(
+                    BinaryOperator::Plus,
+                    colref("a"),
+                    Expression::factory_integer("1"),
+                ),
+                Expression::factory_bin_op(
+                    BinaryOperator::Multiply,
+                    colref("b"),
+                    Expression::factory_integer("2"),
+                ),
+            ),
+        ),
+    ];
+
+    let parser = ApllodbSqlParser::default();
+
+    for (expression, expected_ast) in expression_vs_expected_ast {
+        match parser.parse(format!("SELECT {}", expression)) {
+            Ok(ApllodbAst(Command::SelectCommandVariant(SelectCommand {
+                select_fields, ..
+            }))) => {
+                let fields = select_fields.into_vec();
+                assert_eq!(fields.len(), 1);
+                assert_eq!(&fields[0].expression, &expected_ast);
+            }
+            x => panic!("{:#?}", x),
+        }
+    }
+}
+
+#[test]
+fn test_null_predicate_accepted() {
+    let colref = |name: &str| Expression::factory_colref(ColumnReference::factory(None, name));
+
+    let expression_vs_expected_ast: Vec<(&str, Expression)> = vec![
+        (
+            "a IS NULL",
+            Expression::factory_uni_op(UnaryOperator::IsNull, colref("a")),
+        ),
+        (
+            "a is  not\tnull",
+            Expression::factory_uni_op(UnaryOperator::IsNotNull, colref("a")),
+        ),
+        (
+            "NULL IS NULL",
+            Expression::factory_uni_op(UnaryOperator::IsNull, Expression::factory_null()),
+        ),
+        (
+            // binds tighter than NOT, looser than comparison
+            "NOT a + 1 = b IS NOT NULL",
+            Expression::factory_uni_op(
+                UnaryOperator::Not,
+                Expression::factory_uni_op(
+                    UnaryOperator::IsNotNull,
+                    Expression::factory_eq(
+                        Expression::factory_bin_op(
+                            BinaryOperator::Plus,
+                            colref("a"),
+                            Expression::factory_integer("1"),
+                        ),
+                        colref("b"),
+                    ),
+                ),
+            ),
+        ),
+        (
+            "a IS NULL OR b IS NOT NULL",
+            Expression::factory_bin_op(
+                BinaryOperator::Or,
+                Expression::factory_uni_op(UnaryOperator::IsNull, colref("a")),
+                Expression::factory_uni_op(UnaryOperator::IsNotNull, colref("b")),
+            ),
+        ),
+        (
+            // comments between keywords
+            "a IS /* c */ NOT -- c\n NULL",
+            Expression::factory_uni_op(UnaryOperator::IsNotNull, colref("a")),
+        ),
+        (
+            // keyword-prefixed identifiers are not operators
+            "issue",
+            colref("issue"),
+        ),
+    ];
+
+    let parser = ApllodbSqlParser::default();
+
+    for (expression, expected_ast) in expression_vs_expected_ast {
+        match parser.parse(format!("SELECT {}", expression)) {
+            Ok(ApllodbAst(Command::SelectCommandVariant(SelectCommand {
+                select_fields, ..
+            }))) => {
+                let fields = select_fields.into_vec();
+                assert_eq!(fields.len(), 1);
+                assert_eq!(&fields[0].expression, &expected_ast);
+            }
+            x => panic!("{:#?}", x),
+        }
+    }
+
+    for expression in &["a IS NULLX", "a IS NOTNULL", "a IS"] {
+        assert!(
+            parser.parse(format!("SELECT {}", expression)).is_err(),
+            "{}",
+            expression
+        );
+    }
+}
+
+#[test]
+fn test_in_between_like_accepted() {
+    let colref = |name: &str| Expression::factory_colref(ColumnReference::factory(None, name));
+
+    let expression_vs_expected_ast: Vec<(&str, Expression)> = vec![
+        (
+            "a IN (1, 2 + 3)",
+            Expression::factory_in(
+                false,
+                colref("a"),
+                vec![
+                    Expression::factory_integer("1"),
+                    Expression::factory_bin_op(
+                        BinaryOperator::Plus,
+                        Expression::factory_integer("2"),
+                        Expression::factory_integer("3"),
+                    ),
+                ],
+            ),
+        ),
+        (
+            "a NOT IN (1)",
+            Expression::factory_in(true, colref("a"), vec![Expression::factory_integer("1")]),
+        ),
+        (
+            // AND of BETWEEN is not a logical operator
+            "a BETWEEN 1 AND b + 1 AND c",
+            Expression::factory_bin_op(
+                BinaryOperator::And,
+                Expression::factory_between(
+                    false,
+                    colref("a"),
+                    Expression::factory_integer("1"),
+                    Expression::factory_bin_op(
+                        BinaryOperator::Plus,
+                        colref("b"),
+                        Expression::factory_integer("1"),
+                    ),
+                ),
+                colref("c"),
+            ),
+        ),
+        (
+            "a NOT BETWEEN 1 AND 2",
+            Expression::factory_between(
+                true,
+                colref("a"),
+                Expression::factory_integer("1"),
+                Expression::factory_integer("2"),
+            ),
+        ),
+        (
+            "a LIKE 'foo%'",
+            Expression::factory_like(false, colref("a"), Expression::factory_text("foo%")),
+        ),
+        (
+            // binds tighter than comparison and NOT
+            "NOT a NOT LIKE '_x' = b",
+            Expression::factory_uni_op(
+                UnaryOperator::Not,
+                Expression::factory_eq(
+                    Expression::factory_like(true, colref("a"), Expression::factory_text("_x")),
+                    colref("b"),
+                ),
+            ),
+        ),
+        (
+            // keyword-prefixed identifiers are not operators
+            "a = index",
+            Expression::factory_eq(colref("a"), colref("index")),
+        ),
     ];
 
     let parser = ApllodbSqlParser::default();
@@ -47,4 +505,840 @@ fn test_constant_accepted() {
             x => panic!("{:#?}", x),
         }
     }
+
+    for expression in &["a IN ()", "a BETWEEN 1", "a LIKE", "a NOT"] {
+        assert!(
+            parser.parse(format!("SELECT {}", expression)).is_err(),
+            "{}",
+            expression
+        );
+    }
+}
+
+#[test]
+fn test_function_call_accepted() {
+    let colref = |name: &str| Expression::factory_colref(ColumnReference::factory(None, name));
+
+    let expression_vs_expected_ast: Vec<(&str, Expression)> = vec![
+        ("now()", Expression::factory_function_call("now", vec![])),
+        (
+            "length(name)",
+            Expression::factory_function_call("length", vec![colref("name")]),
+        ),
+        (
+            "COALESCE(a, abs(b - 1), 0)",
+            // unquoted function name is folded into lower case
+            Expression::factory_function_call(
+                "coalesce",
+                vec![
+                    colref("a"),
+                    Expression::factory_function_call(
+                        "abs",
+                        vec![Expression::factory_bin_op(
+                            BinaryOperator::Minus,
+                            colref("b"),
+                            Expression::factory_integer("1"),
+                        )],
+                    ),
+                    Expression::factory_integer("0"),
+                ],
+            ),
+        ),
+        (
+            "upper (name) = a",
+            Expression::factory_eq(
+                Expression::factory_function_call("upper", vec![colref("name")]),
+                colref("a"),
+            ),
+        ),
+        // aggregate function calls
+        (
+            "COUNT(*)",
+            Expression::factory_function_call_asterisk("count"),
+        ),
+        (
+            "count(DISTINCT c)",
+            Expression::factory_function_call_distinct("count", vec![colref("c")]),
+        ),
+        (
+            "sum(ALL c)",
+            Expression::FunctionCallVariant(FunctionCall {
+                function_name: FunctionName(Identifier("sum".to_string())),
+                arguments: FunctionArguments::ExpressionsVariant {
+                    set_quantifier: Some(SetQuantifier::AllVariant),
+                    expressions: vec![colref("c")],
+                },
+            }),
+        ),
+        (
+            // not a set quantifier but a column name starting with it
+            "max(distinctive)",
+            Expression::factory_function_call("max", vec![colref("distinctive")]),
+        ),
+    ];
+
+    let parser = ApllodbSqlParser::default();
+
+    for (expression, expected_ast) in expression_vs_expected_ast {
+        match parser.parse(format!("SELECT {}", expression)) {
+            Ok(ApllodbAst(Command::SelectCommandVariant(SelectCommand {
+                select_fields, ..
+            }))) => {
+                let fields = select_fields.into_vec();
+                assert_eq!(fields.len(), 1);
+                assert_eq!(&fields[0].expression, &expected_ast);
+            }
+            x => panic!("{:#?}", x),
+        }
+    }
+
+    for expression in &[
+        "f(a,)",
+        "f(",
+        "t.f(a)",
+        "count(DISTINCT)",
+        "count(*, a)",
+        "count(DISTINCT *)",
+    ] {
+        assert!(
+            parser.parse(format!("SELECT {}", expression)).is_err(),
+            "{}",
+            expression
+        );
+    }
+}
+
+#[test]
+fn test_case_accepted() {
+    let colref = |name: &str| Expression::factory_colref(ColumnReference::factory(None, name));
+
+    let expression_vs_expected_ast: Vec<(&str, Expression)> = vec![
+        (
+            "CASE WHEN score > 90 THEN 'A' ELSE 'B' END",
+            Expression::factory_case(
+                None,
+                vec![(
+                    Expression::factory_bin_op(
+                        BinaryOperator::GreaterThan,
+                        colref("score"),
+                        Expression::factory_integer("90"),
+                    ),
+                    Expression::factory_text("A"),
+                )],
+                Some(Expression::factory_text("B")),
+            ),
+        ),
+        (
+            "case c when 1 then a when 2 then b end",
+            Expression::factory_case(
+                Some(colref("c")),
+                vec![
+                    (Expression::factory_integer("1"), colref("a")),
+                    (Expression::factory_integer("2"), colref("b")),
+                ],
+                None,
+            ),
+        ),
+        (
+            // nested CASE and CASE as an operand
+            "CASE WHEN a IS NULL THEN CASE b WHEN 1 THEN 0 END END + 1",
+            Expression::factory_bin_op(
+                BinaryOperator::Plus,
+                Expression::factory_case(
+                    None,
+                    vec![(
+                        Expression::factory_uni_op(UnaryOperator::IsNull, colref("a")),
+                        Expression::factory_case(
+                            Some(colref("b")),
+                            vec![(
+                                Expression::factory_integer("1"),
+                                Expression::factory_integer("0"),
+                            )],
+                            None,
+                        ),
+                    )],
+                    None,
+                ),
+                Expression::factory_integer("1"),
+            ),
+        ),
+        (
+            // identifiers starting with keywords
+            "CASE cased WHEN ended THEN elsewhere END",
+            Expression::factory_case(
+                Some(colref("cased")),
+                vec![(colref("ended"), colref("elsewhere"))],
+                None,
+            ),
+        ),
+    ];
+
+    let parser = ApllodbSqlParser::default();
+
+    for (expression, expected_ast) in expression_vs_expected_ast {
+        match parser.parse(format!("SELECT {}", expression)) {
+            Ok(ApllodbAst(Command::SelectCommandVariant(SelectCommand {
+                select_fields, ..
+            }))) => {
+                let fields = select_fields.into_vec();
+                assert_eq!(fields.len(), 1);
+                assert_eq!(&fields[0].expression, &expected_ast);
+            }
+            x => panic!("{:#?}", x),
+        }
+    }
+
+    for expression in &[
+        "CASE END",
+        "CASE WHEN a THEN 1",
+        "CASE ELSE 1 END",
+        "CASE WHEN a THEN 1 ELSE 2 ELSE 3 END",
+    ] {
+        assert!(
+            parser.parse(format!("SELECT {}", expression)).is_err(),
+            "{}",
+            expression
+        );
+    }
+}
+
+#[test]
+fn test_match_accepted() {
+    let colref = |name: &str| Expression::factory_colref(ColumnReference::factory(None, name));
+
+    let expression_vs_expected_ast: Vec<(&str, Expression)> = vec![
+        (
+            "MATCH s WITH circle(r) -> r * r | rect(w, h) -> w * h END",
+            Expression::factory_match(
+                colref("s"),
+                vec![
+                    (
+                        MatchPattern::factory_variant("circle", vec![Some("r")]),
+                        Expression::factory_bin_op(
+                            BinaryOperator::Multiply,
+                            colref("r"),
+                            colref("r"),
+                        ),
+                    ),
+                    (
+                        MatchPattern::factory_variant("rect", vec![Some("w"), Some("h")]),
+                        Expression::factory_bin_op(
+                            BinaryOperator::Multiply,
+                            colref("w"),
+                            colref("h"),
+                        ),
+                    ),
+                ],
+            ),
+        ),
+        (
+            // leading `|`, wildcards, and variants without fields
+            "match c with | red -> 1 | rect(_, h) -> h | _ -> 0 end",
+            Expression::factory_match(
+                colref("c"),
+                vec![
+                    (
+                        MatchPattern::factory_variant("red", vec![]),
+                        Expression::factory_integer("1"),
+                    ),
+                    (
+                        MatchPattern::factory_variant("rect", vec![None, Some("h")]),
+                        colref("h"),
+                    ),
+                    (
+                        MatchPattern::WildcardVariant,
+                        Expression::factory_integer("0"),
+                    ),
+                ],
+            ),
+        ),
+        (
+            // `match` is not reserved
+            "match(matched)",
+            Expression::factory_function_call("match", vec![colref("matched")]),
+        ),
+    ];
+
+    let parser = ApllodbSqlParser::default();
+
+    for (expression, expected_ast) in expression_vs_expected_ast {
+        match parser.parse(format!("SELECT {}", expression)) {
+            Ok(ApllodbAst(Command::SelectCommandVariant(SelectCommand {
+                select_fields, ..
+            }))) => {
+                let fields = select_fields.into_vec();
+                assert_eq!(fields.len(), 1);
+                assert_eq!(&fields[0].expression, &expected_ast);
+            }
+            x => panic!("{:#?}", x),
+        }
+    }
+
+    for expression in &[
+        "MATCH s WITH END",
+        "MATCH s WITH circle(r) -> r",
+        "MATCH s circle(r) -> r END",
+        "MATCH s WITH circle(r) r END",
+    ] {
+        assert!(
+            parser.parse(format!("SELECT {}", expression)).is_err(),
+            "{}",
+            expression
+        );
+    }
+}
+
+#[test]
+fn test_cast_accepted() {
+    let colref = |name: &str| Expression::factory_colref(ColumnReference::factory(None, name));
+
+    let expression_vs_expected_ast: Vec<(&str, Expression)> = vec![
+        (
+            "CAST(a AS TEXT)",
+            Expression::factory_cast(colref("a"), DataType::text()),
+        ),
+        (
+            "cast(a + 1 as integer) * 2",
+            Expression::factory_bin_op(
+                BinaryOperator::Multiply,
+                Expression::factory_cast(
+                    Expression::factory_bin_op(
+                        BinaryOperator::Plus,
+                        colref("a"),
+                        Expression::factory_integer("1"),
+                    ),
+                    DataType::integer(),
+                ),
+                Expression::factory_integer("2"),
+            ),
+        ),
+        (
+            // `::` binds tighter than any other operator
+            "a + b::INTEGER::TEXT",
+            Expression::factory_bin_op(
+                BinaryOperator::Plus,
+                colref("a"),
+                Expression::factory_cast(
+                    Expression::factory_cast(colref("b"), DataType::integer()),
+                    DataType::text(),
+                ),
+            ),
+        ),
+        (
+            "-a::INTEGER",
+            Expression::factory_uni_op(
+                UnaryOperator::Minus,
+                Expression::factory_cast(colref("a"), DataType::integer()),
+            ),
+        ),
+        (
+            "(a + b)::TEXT",
+            Expression::factory_cast(
+                Expression::factory_bin_op(BinaryOperator::Plus, colref("a"), colref("b")),
+                DataType::text(),
+            ),
+        ),
+        (
+            // identifier starting with CAST is a function name
+            "casts(a)",
+            Expression::factory_function_call("casts", vec![colref("a")]),
+        ),
+        (
+            // user-defined type (not checked whether it exists)
+            "CAST(a AS shape)",
+            Expression::factory_cast(colref("a"), DataType::user_defined("shape")),
+        ),
+    ];
+
+    let parser = ApllodbSqlParser::default();
+
+    for (expression, expected_ast) in expression_vs_expected_ast {
+        match parser.parse(format!("SELECT {}", expression)) {
+            Ok(ApllodbAst(Command::SelectCommandVariant(SelectCommand {
+                select_fields, ..
+            }))) => {
+                let fields = select_fields.into_vec();
+                assert_eq!(fields.len(), 1);
+                assert_eq!(&fields[0].expression, &expected_ast);
+            }
+            x => panic!("{:#?}", x),
+        }
+    }
+
+    for expression in &["CAST(a)", "CAST(a AS)", "CAST(a AS 1)", "a::", "cast(a, b)"] {
+        assert!(
+            parser.parse(format!("SELECT {}", expression)).is_err(),
+            "{}",
+            expression
+        );
+    }
+}
+
+#[test]
+fn test_datetime_accepted() {
+    let colref = |name: &str| Expression::factory_colref(ColumnReference::factory(None, name));
+
+    let expression_vs_expected_ast: Vec<(&str, Expression)> = vec![
+        (
+            "DATE '2021-01-01'",
+            Expression::factory_datetime(DatetimeType::DateVariant, "2021-01-01"),
+        ),
+        (
+            "time '12:34:56'",
+            Expression::factory_datetime(DatetimeType::TimeVariant, "12:34:56"),
+        ),
+        (
+            "TIME WITHOUT TIME ZONE '12:34'",
+            Expression::factory_datetime(DatetimeType::TimeVariant, "12:34"),
+        ),
+        (
+            "TIMESTAMP '2021-01-01 00:00:00'",
+            Expression::factory_datetime(DatetimeType::TimestampVariant, "2021-01-01 00:00:00"),
+        ),
+        (
+            "TIMESTAMP WITHOUT TIME ZONE '2021-01-01'",
+            Expression::factory_datetime(DatetimeType::TimestampVariant, "2021-01-01"),
+        ),
+        (
+            "timestamp  with time\nzone '2021-01-01 09:00:00+09'",
+            Expression::factory_datetime(
+                DatetimeType::TimestampWithTimeZoneVariant,
+                "2021-01-01 09:00:00+09",
+            ),
+        ),
+        (
+            "CURRENT_TIMESTAMP - INTERVAL '1 day'",
+            Expression::factory_bin_op(
+                BinaryOperator::Minus,
+                Expression::factory_current_datetime(
+                    CurrentDatetimeFunction::CurrentTimestampVariant,
+                ),
+                Expression::factory_datetime(DatetimeType::IntervalVariant, "1 day"),
+            ),
+        ),
+        (
+            "current_date",
+            Expression::factory_current_datetime(CurrentDatetimeFunction::CurrentDateVariant),
+        ),
+        (
+            "'2021-01-01'::TIMESTAMP WITH TIME ZONE",
+            Expression::factory_cast(
+                Expression::factory_text("2021-01-01"),
+                DataType::timestamp_with_time_zone(),
+            ),
+        ),
+        (
+            "CAST(a AS interval)",
+            Expression::factory_cast(colref("a"), DataType::interval()),
+        ),
+        // type names are not reserved
+        ("date", colref("date")),
+        ("timestamp", colref("timestamp")),
+        ("current_dates", colref("current_dates")),
+    ];
+
+    let parser = ApllodbSqlParser::default();
+
+    for (expression, expected_ast) in expression_vs_expected_ast {
+        match parser.parse(format!("SELECT {}", expression)) {
+            Ok(ApllodbAst(Command::SelectCommandVariant(SelectCommand {
+                select_fields, ..
+            }))) => {
+                let fields = select_fields.into_vec();
+                assert_eq!(fields.len(), 1, "{}", expression);
+                assert_eq!(&fields[0].expression, &expected_ast, "{}", expression);
+            }
+            x => panic!("{}: {:#?}", expression, x),
+        }
+    }
+
+    for expression in &[
+        "DATE 20210101",
+        "TIME WITH TIME ZONE '12:00'",
+        "TIMESTAMP WITH '2021-01-01'",
+        "current_date()",
+    ] {
+        assert!(
+            parser.parse(format!("SELECT {}", expression)).is_err(),
+            "{}",
+            expression
+        );
+    }
+}
+
+#[test]
+fn test_fuzzy_date_accepted() {
+    let colref = |name: &str| Expression::factory_colref(ColumnReference::factory(None, name));
+
+    let expression_vs_expected_ast: Vec<(&str, Expression)> = vec![
+        (
+            "FUZZY DATE '1920s'",
+            Expression::factory_datetime(DatetimeType::FuzzyDateVariant, "1920s"),
+        ),
+        (
+            "'1920'::fuzzy  date",
+            Expression::factory_cast(Expression::factory_text("1920"), DataType::fuzzy_date()),
+        ),
+        (
+            "POSSIBLY a < FUZZY DATE '1920s'",
+            Expression::factory_uni_op(
+                UnaryOperator::Possibly,
+                Expression::factory_bin_op(
+                    BinaryOperator::LessThan,
+                    colref("a"),
+                    Expression::factory_datetime(DatetimeType::FuzzyDateVariant, "1920s"),
+                ),
+            ),
+        ),
+        // same precedence as NOT
+        (
+            "possibly NOT a = b AND c",
+            Expression::factory_bin_op(
+                BinaryOperator::And,
+                Expression::factory_uni_op(
+                    UnaryOperator::Possibly,
+                    Expression::factory_uni_op(
+                        UnaryOperator::Not,
+                        Expression::factory_eq(colref("a"), colref("b")),
+                    ),
+                ),
+                colref("c"),
+            ),
+        ),
+        // not reserved
+        ("possibly", colref("possibly")),
+        ("fuzzy", colref("fuzzy")),
+        (
+            "possiblyx = 1",
+            Expression::factory_eq(colref("possiblyx"), Expression::factory_integer("1")),
+        ),
+    ];
+
+    let parser = ApllodbSqlParser::default();
+
+    for (expression, expected_ast) in expression_vs_expected_ast {
+        match parser.parse(format!("SELECT {}", expression)) {
+            Ok(ApllodbAst(Command::SelectCommandVariant(SelectCommand {
+                select_fields, ..
+            }))) => {
+                let fields = select_fields.into_vec();
+                assert_eq!(fields.len(), 1, "{}", expression);
+                assert_eq!(&fields[0].expression, &expected_ast, "{}", expression);
+            }
+            x => panic!("{}: {:#?}", expression, x),
+        }
+    }
+
+    for expression in &["FUZZY '1920s'", "FUZZY DATE 1920", "POSSIBLY a ="] {
+        assert!(
+            parser.parse(format!("SELECT {}", expression)).is_err(),
+            "{}",
+            expression
+        );
+    }
+}
+
+#[test]
+fn test_binary_accepted() {
+    let colref = |name: &str| Expression::factory_colref(ColumnReference::factory(None, name));
+
+    let expression_vs_expected_ast: Vec<(&str, Expression)> = vec![
+        ("X'01ff'", Expression::factory_hex_binary("01ff")),
+        ("x''", Expression::factory_hex_binary("")),
+        (
+            // hex digits are validated later
+            "X'0g'",
+            Expression::factory_hex_binary("0g"),
+        ),
+        (
+            r"BYTEA '\x01ff'",
+            Expression::factory_typed_binary(r"\x01ff"),
+        ),
+        (
+            r"blob 'ab\000'",
+            Expression::factory_typed_binary(r"ab\000"),
+        ),
+        (
+            "a = X'00'",
+            Expression::factory_eq(colref("a"), Expression::factory_hex_binary("00")),
+        ),
+        (
+            "CAST(a AS BLOB)",
+            Expression::factory_cast(colref("a"), DataType::bytea()),
+        ),
+        // not reserved
+        ("x", colref("x")),
+        ("bytea", colref("bytea")),
+        ("blobs", colref("blobs")),
+    ];
+
+    let parser = ApllodbSqlParser::default();
+
+    for (expression, expected_ast) in expression_vs_expected_ast {
+        match parser.parse(format!("SELECT {}", expression)) {
+            Ok(ApllodbAst(Command::SelectCommandVariant(SelectCommand {
+                select_fields, ..
+            }))) => {
+                let fields = select_fields.into_vec();
+                assert_eq!(fields.len(), 1, "{}", expression);
+                assert_eq!(&fields[0].expression, &expected_ast, "{}", expression);
+            }
+            x => panic!("{}: {:#?}", expression, x),
+        }
+    }
+
+    for expression in &["X'01", "BYTEA 1", "X '01'"] {
+        assert!(
+            parser.parse(format!("SELECT {}", expression)).is_err(),
+            "{}",
+            expression
+        );
+    }
+}
+
+#[test]
+fn test_json_accepted() {
+    let colref = |name: &str| Expression::factory_colref(ColumnReference::factory(None, name));
+    let json_op = |op: BinaryOperator, left: Expression, right: Expression| {
+        Expression::factory_bin_op(op, left, right)
+    };
+
+    let expression_vs_expected_ast: Vec<(&str, Expression)> = vec![
+        (
+            r#"JSON '{"a": [1, 2]}'"#,
+            Expression::factory_json(r#"{"a": [1, 2]}"#),
+        ),
+        (
+            // JSON text is validated later
+            "json '{'",
+            Expression::factory_json("{"),
+        ),
+        (
+            "d -> 'a'",
+            json_op(
+                BinaryOperator::JsonExtract,
+                colref("d"),
+                Expression::factory_text("a"),
+            ),
+        ),
+        (
+            "d->>0",
+            json_op(
+                BinaryOperator::JsonExtractText,
+                colref("d"),
+                Expression::factory_integer("0"),
+            ),
+        ),
+        (
+            // left-associative
+            "d -> 'a' -> -1",
+            json_op(
+                BinaryOperator::JsonExtract,
+                json_op(
+                    BinaryOperator::JsonExtract,
+                    colref("d"),
+                    Expression::factory_text("a"),
+                ),
+                Expression::factory_uni_op(UnaryOperator::Minus, Expression::factory_integer("1")),
+            ),
+        ),
+        (
+            // lower than `+`, higher than `=`
+            "d ->> 'a' = 'x'",
+            Expression::factory_eq(
+                json_op(
+                    BinaryOperator::JsonExtractText,
+                    colref("d"),
+                    Expression::factory_text("a"),
+                ),
+                Expression::factory_text("x"),
+            ),
+        ),
+        (
+            "d -> i + 1",
+            json_op(
+                BinaryOperator::JsonExtract,
+                colref("d"),
+                Expression::factory_bin_op(
+                    BinaryOperator::Plus,
+                    colref("i"),
+                    Expression::factory_integer("1"),
+                ),
+            ),
+        ),
+        (
+            "CAST(a AS JSON)",
+            Expression::factory_cast(colref("a"), DataType::json()),
+        ),
+        (
+            "json_extract(d, '$.a')",
+            Expression::factory_function_call(
+                "json_extract",
+                vec![colref("d"), Expression::factory_text("$.a")],
+            ),
+        ),
+        // not reserved
+        ("json", colref("json")),
+        ("jsons", colref("jsons")),
+    ];
+
+    let parser = ApllodbSqlParser::default();
+
+    for (expression, expected_ast) in expression_vs_expected_ast {
+        match parser.parse(format!("SELECT {}", expression)) {
+            Ok(ApllodbAst(Command::SelectCommandVariant(SelectCommand {
+                select_fields, ..
+            }))) => {
+                let fields = select_fields.into_vec();
+                assert_eq!(fields.len(), 1, "{}", expression);
+                assert_eq!(&fields[0].expression, &expected_ast, "{}", expression);
+            }
+            x => panic!("{}: {:#?}", expression, x),
+        }
+    }
+
+    for expression in &["JSON 1", "d ->", "d - > 'a'"] {
+        assert!(
+            parser.parse(format!("SELECT {}", expression)).is_err(),
+            "{}",
+            expression
+        );
+    }
+}
+
+#[test]
+fn test_array_accepted() {
+    let colref = |name: &str| Expression::factory_colref(ColumnReference::factory(None, name));
+
+    let expression_vs_expected_ast: Vec<(&str, Expression)> = vec![
+        (
+            "ARRAY['a', 'b']",
+            Expression::factory_array(vec![
+                Expression::factory_text("a"),
+                Expression::factory_text("b"),
+            ]),
+        ),
+        (
+            // element type cannot be determined but it is checked later
+            "array[]",
+            Expression::factory_array(vec![]),
+        ),
+        (
+            "ARRAY[ARRAY[1], ARRAY[x + 1]]",
+            Expression::factory_array(vec![
+                Expression::factory_array(vec![Expression::factory_integer("1")]),
+                Expression::factory_array(vec![Expression::factory_bin_op(
+                    BinaryOperator::Plus,
+                    colref("x"),
+                    Expression::factory_integer("1"),
+                )]),
+            ]),
+        ),
+        (
+            "tags[1]",
+            Expression::factory_subscript(colref("tags"), Expression::factory_integer("1")),
+        ),
+        (
+            // subscripts and casts are applied from left to right
+            "m[i][j + 1]::TEXT",
+            Expression::factory_cast(
+                Expression::factory_subscript(
+                    Expression::factory_subscript(colref("m"), colref("i")),
+                    Expression::factory_bin_op(
+                        BinaryOperator::Plus,
+                        colref("j"),
+                        Expression::factory_integer("1"),
+                    ),
+                ),
+                DataType::text(),
+            ),
+        ),
+        (
+            // higher than unary minus
+            "-a[1]",
+            Expression::factory_uni_op(
+                UnaryOperator::Minus,
+                Expression::factory_subscript(colref("a"), Expression::factory_integer("1")),
+            ),
+        ),
+        (
+            "ARRAY[1, 2][2]",
+            Expression::factory_subscript(
+                Expression::factory_array(vec![
+                    Expression::factory_integer("1"),
+                    Expression::factory_integer("2"),
+                ]),
+                Expression::factory_integer("2"),
+            ),
+        ),
+        (
+            "'1'::INTEGER[]",
+            Expression::factory_cast(
+                Expression::factory_text("1"),
+                DataType::array(DataType::integer()),
+            ),
+        ),
+        (
+            "'a' = ANY(tags)",
+            Expression::factory_any(
+                BinaryOperator::Equal,
+                Expression::factory_text("a"),
+                colref("tags"),
+            ),
+        ),
+        (
+            "x + 1 >= any (ARRAY[1, 2])",
+            Expression::factory_any(
+                BinaryOperator::GreaterThanOrEqual,
+                Expression::factory_bin_op(
+                    BinaryOperator::Plus,
+                    colref("x"),
+                    Expression::factory_integer("1"),
+                ),
+                Expression::factory_array(vec![
+                    Expression::factory_integer("1"),
+                    Expression::factory_integer("2"),
+                ]),
+            ),
+        ),
+        (
+            "array_length(tags)",
+            Expression::factory_function_call("array_length", vec![colref("tags")]),
+        ),
+        // not reserved as a part of identifiers
+        ("arrays", colref("arrays")),
+        ("anything", colref("anything")),
+    ];
+
+    let parser = ApllodbSqlParser::default();
+
+    for (expression, expected_ast) in expression_vs_expected_ast {
+        match parser.parse(format!("SELECT {}", expression)) {
+            Ok(ApllodbAst(Command::SelectCommandVariant(SelectCommand {
+                select_fields, ..
+            }))) => {
+                let fields = select_fields.into_vec();
+                assert_eq!(fields.len(), 1, "{}", expression);
+                assert_eq!(&fields[0].expression, &expected_ast, "{}", expression);
+            }
+            x => panic!("{}: {:#?}", expression, x),
+        }
+    }
+
+    for expression in &[
+        "ARRAY[1,]",
+        "ARRAY(1, 2)",
+        "tags[]",
+        "tags[1",
+        "ANY(tags)",
+        "ANY(tags) = 'a'",
+        "'a' = ANY tags",
+        "array",
+    ] {
+        assert!(
+            parser.parse(format!("SELECT {}", expression)).is_err(),
+            "{}",
+            expression
+        );
+    }
 }
@@ -27,27 +27,51 @@ fn test_insert_accepted() {
             ),
         ),
         (
-            r#"INSERT INTO t (c) VALUES ("abc")"#,
+            "INSERT INTO t (c) VALUES ('abc')",
             InsertCommand::factory(
                 "t",
                 None,
                 vec!["c"],
-                vec![InsertValue::factory(vec![Expression::factory_text(
-                    r#"abc"#,
-                )])],
+                vec![InsertValue::factory(vec![Expression::factory_text("abc")])],
             ),
         ),
         (
-            r#"INSERT INTO t (c) VALUES ("abc🍣 '@\")"#,
+            // escapes are kept as-is in AST
+            r#"INSERT INTO t (c) VALUES ('abc🍣 ''@\"')"#,
             InsertCommand::factory(
                 "t",
                 None,
                 vec!["c"],
                 vec![InsertValue::factory(vec![Expression::factory_text(
-                    r#"abc🍣 '@\"#,
+                    r#"abc🍣 ''@\""#,
                 )])],
             ),
         ),
+        (
+            // whitespaces inside quotes are not skipped
+            "INSERT INTO t (c) VALUES (' a ', '')",
+            InsertCommand::factory(
+                "t",
+                None,
+                vec!["c"],
+                vec![InsertValue::factory(vec![
+                    Expression::factory_text(" a "),
+                    Expression::factory_text(""),
+                ])],
+            ),
+        ),
+        (
+            r#"INSERT INTO t (c) VALUES (E'it\'s''\n', e'\\')"#,
+            InsertCommand::factory(
+                "t",
+                None,
+                vec!["c"],
+                vec![InsertValue::factory(vec![
+                    Expression::factory_escape_text(r#"it\'s''\n"#),
+                    Expression::factory_escape_text(r#"\\"#),
+                ])],
+            ),
+        ),
         (
             "INSERT INTO long_table_name AS t (id, c1) VALUES (1, 123)",
             InsertCommand::factory(
@@ -115,6 +139,10 @@ fn test_insert_rejected() {
     let sqls: Vec<&str> = vec![
         // Lack parentheses.
         "INSERT INTO t (id) VALUES 1",
+        // Unterminated string constant.
+        "INSERT INTO t (c) VALUES ('abc)",
+        // Single quote inside string constant must be doubled.
+        "INSERT INTO t (c) VALUES ('it's')",
     ];
 
     let parser = ApllodbSqlParser::default();
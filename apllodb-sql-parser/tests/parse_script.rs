@@ -0,0 +1,168 @@
+use apllodb_sql_parser::{
+    apllodb_ast::{
+        ColumnDefinition, ColumnReference, Command, CreateTableCommand, DataType, DropTableCommand,
+        Expression, FromItem, InsertCommand, InsertValue, SelectCommand, SelectField, TableElement,
+    },
+    ApllodbAst, ApllodbSqlParser,
+};
+
+use apllodb_test_support::setup::setup_test_logger;
+
+#[ctor::ctor]
+fn test_setup() {
+    setup_test_logger();
+}
+
+#[test]
+fn test_comment_accepted() {
+    let sql_vs_expected_ast: Vec<(&str, DropTableCommand)> = vec![
+        ("-- drop\nDROP TABLE t", DropTableCommand::factory("t")),
+        ("DROP TABLE t -- trailing", DropTableCommand::factory("t")),
+        (
+            "DROP TABLE t; -- after semicolon",
+            DropTableCommand::factory("t"),
+        ),
+        ("DROP /* inline */ TABLE t", DropTableCommand::factory("t")),
+        (
+            "DROP/**/TABLE/* multi\nline */t",
+            DropTableCommand::factory("t"),
+        ),
+        (
+            "/* nested /* comment */ still in comment */ DROP TABLE t",
+            DropTableCommand::factory("t"),
+        ),
+        ("--\nDROP TABLE t\n--", DropTableCommand::factory("t")),
+    ];
+
+    let parser = ApllodbSqlParser::default();
+
+    for (sql, expected_ast) in sql_vs_expected_ast {
+        match parser.parse(sql) {
+            Ok(ApllodbAst(Command::DropTableCommandVariant(drop_table_command))) => {
+                assert_eq!(drop_table_command, expected_ast, "{}", sql);
+            }
+            Ok(ast) => panic!(
+                "'{}' should be parsed as DROP TABLE but is parsed like: {:?}",
+                sql, ast
+            ),
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
+#[test]
+fn test_comment_in_expression() {
+    let parser = ApllodbSqlParser::default();
+
+    match parser
+        .parse("SELECT a -- comment\n - -1, 'not -- a comment', \"/* not a comment */\" FROM t")
+    {
+        Ok(ApllodbAst(Command::SelectCommandVariant(SelectCommand { select_fields, .. }))) => {
+            let fields = select_fields.into_vec();
+            assert_eq!(fields.len(), 3);
+            assert_eq!(
+                fields[1].expression,
+                Expression::factory_text("not -- a comment")
+            );
+            assert_eq!(
+                fields[2].expression,
+                Expression::factory_colref(ColumnReference::factory(None, "/* not a comment */"))
+            );
+        }
+        x => panic!("{:#?}", x),
+    }
+}
+
+#[test]
+fn test_comment_rejected() {
+    let sqls: Vec<&str> = vec![
+        // Unterminated block comment.
+        "DROP TABLE t /* comment",
+        // Nested block comment is not closed.
+        "DROP TABLE t /* /* */",
+        // Line comment comments out the rest of the line.
+        "DROP -- TABLE t",
+        // Comment does not separate tokens inside a keyword.
+        "DR/**/OP TABLE t",
+    ];
+
+    let parser = ApllodbSqlParser::default();
+
+    for sql in sqls {
+        assert!(parser.parse(sql).is_err(), "{}", sql);
+    }
+}
+
+#[test]
+fn test_script_accepted() {
+    let parser = ApllodbSqlParser::default();
+
+    let script = "
+        -- migration #1
+        CREATE TABLE t (id INTEGER);
+
+        /* seed */
+        INSERT INTO t (id) VALUES (1);;
+        SELECT id FROM t;
+        DROP TABLE t
+    ";
+    let asts = parser.parse_script(script).unwrap();
+    assert_eq!(
+        asts,
+        vec![
+            ApllodbAst(Command::CreateTableCommandVariant(
+                CreateTableCommand::factory(
+                    "t",
+                    vec![TableElement::factory_coldef(ColumnDefinition::factory(
+                        "id",
+                        DataType::integer(),
+                        vec![],
+                    ))],
+                )
+            )),
+            ApllodbAst(Command::InsertCommandVariant(InsertCommand::factory(
+                "t",
+                None,
+                vec!["id"],
+                vec![InsertValue::factory(vec![Expression::factory_integer("1")])],
+            ))),
+            ApllodbAst(Command::SelectCommandVariant(SelectCommand::factory(
+                vec![SelectField::factory(
+                    Expression::factory_colref(ColumnReference::factory(None, "id")),
+                    None,
+                )],
+                Some(FromItem::factory_tn("t", None)),
+                None,
+                None,
+                None,
+                None,
+            ))),
+            ApllodbAst(Command::DropTableCommandVariant(DropTableCommand::factory(
+                "t"
+            ))),
+        ]
+    );
+
+    // empty scripts
+    for script in &["", " ;; ", "-- nothing to do", "/* nothing */ ;"] {
+        assert_eq!(parser.parse_script(*script).unwrap(), vec![], "{}", script);
+    }
+}
+
+#[test]
+fn test_script_rejected() {
+    let scripts: Vec<&str> = vec![
+        // Statements must be separated by semicolons.
+        "DROP TABLE t DROP TABLE t2",
+        // Any statement has a syntax error.
+        "DROP TABLE t; DROP TABLE; DROP TABLE t2",
+        // Trailing garbage.
+        "DROP TABLE t; x",
+    ];
+
+    let parser = ApllodbSqlParser::default();
+
+    for script in scripts {
+        assert!(parser.parse_script(script).is_err(), "{}", script);
+    }
+}
@@ -1,7 +1,8 @@
 use apllodb_sql_parser::{
     apllodb_ast::{
-        ColumnReference, Command, Condition, Correlation, Expression, FromItem, OrderBy, Ordering,
-        SelectCommand, SelectField, UnaryOperator,
+        BinaryOperator, ColumnReference, Command, Condition, Correlation, Expression, FromItem,
+        GroupingElement, JoinType, OrderBy, Ordering, SelectCommand, SelectField, SetOperator,
+        SetQuantifier, UnaryOperator,
     },
     ApllodbAst, ApllodbSqlParser,
 };
@@ -16,6 +17,58 @@ fn test_setup() {
 #[test]
 fn test_select_accepted() {
     let sql_vs_expected_ast: Vec<(&str, SelectCommand)> = vec![
+        (
+            // unquoted identifiers are folded into lower case
+            "SELECT ID, T.C1 FROM T",
+            SelectCommand::factory(
+                vec![
+                    SelectField::factory(
+                        Expression::factory_colref(ColumnReference::factory(None, "id")),
+                        None,
+                    ),
+                    SelectField::factory(
+                        Expression::factory_colref(ColumnReference::factory(
+                            Some(Correlation::factory("t")),
+                            "c1",
+                        )),
+                        None,
+                    ),
+                ],
+                Some(FromItem::factory_tn("t", None)),
+                None,
+                None,
+                None,
+                None,
+            ),
+        ),
+        (
+            // quoted identifiers preserve case and can be keywords or contain any characters
+            r#"SELECT "ID", "My Table"."select", "a""b" AS "X" FROM "My Table""#,
+            SelectCommand::factory(
+                vec![
+                    SelectField::factory(
+                        Expression::factory_colref(ColumnReference::factory(None, "ID")),
+                        None,
+                    ),
+                    SelectField::factory(
+                        Expression::factory_colref(ColumnReference::factory(
+                            Some(Correlation::factory("My Table")),
+                            "select",
+                        )),
+                        None,
+                    ),
+                    SelectField::factory(
+                        Expression::factory_colref(ColumnReference::factory(None, r#"a"b"#)),
+                        Some("X"),
+                    ),
+                ],
+                Some(FromItem::factory_tn("My Table", None)),
+                None,
+                None,
+                None,
+                None,
+            ),
+        ),
         (
             "SELECT id FROM t",
             SelectCommand::factory(
@@ -169,6 +222,32 @@ fn test_select_accepted() {
             ),
         ),
         // Sort
+        (
+            "SELECT id FROM t WHERE id = 1 OR c ORDER BY id",
+            SelectCommand::factory(
+                vec![SelectField::factory(
+                    Expression::factory_colref(ColumnReference::factory(None, "id")),
+                    None,
+                )],
+                Some(FromItem::factory_tn("t", None)),
+                Some(Condition {
+                    expression: Expression::factory_bin_op(
+                        BinaryOperator::Or,
+                        Expression::factory_eq(
+                            Expression::factory_colref(ColumnReference::factory(None, "id")),
+                            Expression::factory_integer("1"),
+                        ),
+                        Expression::factory_colref(ColumnReference::factory(None, "c")),
+                    ),
+                }),
+                None,
+                None,
+                Some(vec![OrderBy::factory_colref(
+                    ColumnReference::factory(None, "id"),
+                    None,
+                )]),
+            ),
+        ),
         (
             "SELECT id FROM t ORDER BY id",
             SelectCommand::factory(
@@ -294,6 +373,397 @@ fn test_select_accepted() {
                 None,
             ),
         ),
+        (
+            // joins are left-associative
+            "SELECT id FROM t LEFT OUTER JOIN s ON t.id = s.t_id FULL JOIN u ON t.id = u.t_id",
+            SelectCommand::factory(
+                vec![SelectField::factory(
+                    Expression::factory_colref(ColumnReference::factory(None, "id")),
+                    None,
+                )],
+                Some(FromItem::factory_join(
+                    JoinType::FullOuterJoin,
+                    FromItem::factory_join(
+                        JoinType::LeftOuterJoin,
+                        FromItem::factory_tn("t", None),
+                        FromItem::factory_tn("s", None),
+                        Some(Expression::factory_eq(
+                            Expression::factory_colref(ColumnReference::factory(
+                                Some(Correlation::factory("t")),
+                                "id",
+                            )),
+                            Expression::factory_colref(ColumnReference::factory(
+                                Some(Correlation::factory("s")),
+                                "t_id",
+                            )),
+                        )),
+                    ),
+                    FromItem::factory_tn("u", None),
+                    Some(Expression::factory_eq(
+                        Expression::factory_colref(ColumnReference::factory(
+                            Some(Correlation::factory("t")),
+                            "id",
+                        )),
+                        Expression::factory_colref(ColumnReference::factory(
+                            Some(Correlation::factory("u")),
+                            "t_id",
+                        )),
+                    )),
+                )),
+                None,
+                None,
+                None,
+                None,
+            ),
+        ),
+        (
+            "SELECT id FROM t JOIN s ON t.id = s.t_id RIGHT JOIN u ON t.id = u.t_id",
+            SelectCommand::factory(
+                vec![SelectField::factory(
+                    Expression::factory_colref(ColumnReference::factory(None, "id")),
+                    None,
+                )],
+                Some(FromItem::factory_join(
+                    JoinType::RightOuterJoin,
+                    FromItem::factory_inner_join(
+                        FromItem::factory_tn("t", None),
+                        FromItem::factory_tn("s", None),
+                        Expression::factory_eq(
+                            Expression::factory_colref(ColumnReference::factory(
+                                Some(Correlation::factory("t")),
+                                "id",
+                            )),
+                            Expression::factory_colref(ColumnReference::factory(
+                                Some(Correlation::factory("s")),
+                                "t_id",
+                            )),
+                        ),
+                    ),
+                    FromItem::factory_tn("u", None),
+                    Some(Expression::factory_eq(
+                        Expression::factory_colref(ColumnReference::factory(
+                            Some(Correlation::factory("t")),
+                            "id",
+                        )),
+                        Expression::factory_colref(ColumnReference::factory(
+                            Some(Correlation::factory("u")),
+                            "t_id",
+                        )),
+                    )),
+                )),
+                None,
+                None,
+                None,
+                None,
+            ),
+        ),
+        (
+            // `,` is the same as CROSS JOIN
+            "SELECT id FROM t, s AS x CROSS JOIN u",
+            SelectCommand::factory(
+                vec![SelectField::factory(
+                    Expression::factory_colref(ColumnReference::factory(None, "id")),
+                    None,
+                )],
+                Some(FromItem::factory_join(
+                    JoinType::CrossJoin,
+                    FromItem::factory_join(
+                        JoinType::CrossJoin,
+                        FromItem::factory_tn("t", None),
+                        FromItem::factory_tn("s", Some("x")),
+                        None,
+                    ),
+                    FromItem::factory_tn("u", None),
+                    None,
+                )),
+                None,
+                None,
+                None,
+                None,
+            ),
+        ),
+        // Aggregation
+        (
+            "SELECT c, COUNT(*) FROM t GROUP BY c HAVING count(*) > 1 ORDER BY c",
+            SelectCommand::factory(
+                vec![
+                    SelectField::factory(
+                        Expression::factory_colref(ColumnReference::factory(None, "c")),
+                        None,
+                    ),
+                    SelectField::factory(Expression::factory_function_call_asterisk("count"), None),
+                ],
+                Some(FromItem::factory_tn("t", None)),
+                None,
+                Some(vec![GroupingElement::ExpressionVariant(
+                    Expression::factory_colref(ColumnReference::factory(None, "c")),
+                )]),
+                Some(vec![Condition {
+                    expression: Expression::factory_bin_op(
+                        BinaryOperator::GreaterThan,
+                        Expression::factory_function_call_asterisk("count"),
+                        Expression::factory_integer("1"),
+                    ),
+                }]),
+                Some(vec![OrderBy::factory_colref(
+                    ColumnReference::factory(None, "c"),
+                    None,
+                )]),
+            ),
+        ),
+        (
+            // HAVING without WHERE nor GROUP BY
+            "SELECT sum(c) FROM t having sum(c) > 0",
+            SelectCommand::factory(
+                vec![SelectField::factory(
+                    Expression::factory_function_call(
+                        "sum",
+                        vec![Expression::factory_colref(ColumnReference::factory(
+                            None, "c",
+                        ))],
+                    ),
+                    None,
+                )],
+                Some(FromItem::factory_tn("t", None)),
+                None,
+                None,
+                Some(vec![Condition {
+                    expression: Expression::factory_bin_op(
+                        BinaryOperator::GreaterThan,
+                        Expression::factory_function_call(
+                            "sum",
+                            vec![Expression::factory_colref(ColumnReference::factory(
+                                None, "c",
+                            ))],
+                        ),
+                        Expression::factory_integer("0"),
+                    ),
+                }]),
+                None,
+            ),
+        ),
+        (
+            "SELECT a, b FROM t WHERE c group by a, t.b",
+            SelectCommand::factory(
+                vec![
+                    SelectField::factory(
+                        Expression::factory_colref(ColumnReference::factory(None, "a")),
+                        None,
+                    ),
+                    SelectField::factory(
+                        Expression::factory_colref(ColumnReference::factory(None, "b")),
+                        None,
+                    ),
+                ],
+                Some(FromItem::factory_tn("t", None)),
+                Some(Condition {
+                    expression: Expression::factory_colref(ColumnReference::factory(None, "c")),
+                }),
+                Some(vec![
+                    GroupingElement::ExpressionVariant(Expression::factory_colref(
+                        ColumnReference::factory(None, "a"),
+                    )),
+                    GroupingElement::ExpressionVariant(Expression::factory_colref(
+                        ColumnReference::factory(Some(Correlation::factory("t")), "b"),
+                    )),
+                ]),
+                None,
+                None,
+            ),
+        ),
+        (
+            "SELECT id FROM t ORDER BY id LIMIT 10",
+            SelectCommand::factory(
+                vec![SelectField::factory(
+                    Expression::factory_colref(ColumnReference::factory(None, "id")),
+                    None,
+                )],
+                Some(FromItem::factory_tn("t", None)),
+                None,
+                None,
+                None,
+                Some(vec![OrderBy::factory_colref(
+                    ColumnReference::factory(None, "id"),
+                    None,
+                )]),
+            )
+            .with_limit(Some("10"), None),
+        ),
+        (
+            "SELECT id FROM t limit 10 offset 20",
+            SelectCommand::factory(
+                vec![SelectField::factory(
+                    Expression::factory_colref(ColumnReference::factory(None, "id")),
+                    None,
+                )],
+                Some(FromItem::factory_tn("t", None)),
+                None,
+                None,
+                None,
+                None,
+            )
+            .with_limit(Some("10"), Some("20")),
+        ),
+        (
+            "SELECT id FROM t OFFSET 20",
+            SelectCommand::factory(
+                vec![SelectField::factory(
+                    Expression::factory_colref(ColumnReference::factory(None, "id")),
+                    None,
+                )],
+                Some(FromItem::factory_tn("t", None)),
+                None,
+                None,
+                None,
+                None,
+            )
+            .with_limit(None, Some("20")),
+        ),
+        (
+            "SELECT id FROM t ORDER BY id OFFSET 20 ROWS FETCH FIRST 10 ROWS ONLY",
+            SelectCommand::factory(
+                vec![SelectField::factory(
+                    Expression::factory_colref(ColumnReference::factory(None, "id")),
+                    None,
+                )],
+                Some(FromItem::factory_tn("t", None)),
+                None,
+                None,
+                None,
+                Some(vec![OrderBy::factory_colref(
+                    ColumnReference::factory(None, "id"),
+                    None,
+                )]),
+            )
+            .with_limit(Some("10"), Some("20")),
+        ),
+        (
+            // count is 1 if omitted
+            "SELECT id FROM t FETCH NEXT ROW ONLY",
+            SelectCommand::factory(
+                vec![SelectField::factory(
+                    Expression::factory_colref(ColumnReference::factory(None, "id")),
+                    None,
+                )],
+                Some(FromItem::factory_tn("t", None)),
+                None,
+                None,
+                None,
+                None,
+            )
+            .with_limit(Some("1"), None),
+        ),
+        (
+            "SELECT DISTINCT id FROM t",
+            SelectCommand::factory(
+                vec![SelectField::factory(
+                    Expression::factory_colref(ColumnReference::factory(None, "id")),
+                    None,
+                )],
+                Some(FromItem::factory_tn("t", None)),
+                None,
+                None,
+                None,
+                None,
+            )
+            .with_set_quantifier(SetQuantifier::DistinctVariant),
+        ),
+        (
+            // ALL after UNION and after SELECT
+            "SELECT id FROM t UNION ALL SELECT id FROM u union select all id from v",
+            SelectCommand::factory(
+                vec![SelectField::factory(
+                    Expression::factory_colref(ColumnReference::factory(None, "id")),
+                    None,
+                )],
+                Some(FromItem::factory_tn("t", None)),
+                None,
+                None,
+                None,
+                None,
+            )
+            .with_set_operation(
+                SetOperator::UnionVariant,
+                Some(SetQuantifier::AllVariant),
+                SelectCommand::factory(
+                vec![SelectField::factory(
+                    Expression::factory_colref(ColumnReference::factory(None, "id")),
+                    None,
+                )],
+                Some(FromItem::factory_tn("u", None)),
+                None,
+                None,
+                None,
+                None,
+            ),
+            )
+            .with_set_operation(
+                SetOperator::UnionVariant,
+                None,
+                SelectCommand::factory(
+                vec![SelectField::factory(
+                    Expression::factory_colref(ColumnReference::factory(None, "id")),
+                    None,
+                )],
+                Some(FromItem::factory_tn("v", None)),
+                None,
+                None,
+                None,
+                None,
+            )
+            .with_set_quantifier(SetQuantifier::AllVariant),
+            ),
+        ),
+        (
+            // ORDER BY and LIMIT belong to the whole query
+            "SELECT id FROM t INTERSECT SELECT DISTINCT id FROM u EXCEPT DISTINCT SELECT id FROM v ORDER BY id LIMIT 1",
+            SelectCommand::factory(
+                vec![SelectField::factory(
+                    Expression::factory_colref(ColumnReference::factory(None, "id")),
+                    None,
+                )],
+                Some(FromItem::factory_tn("t", None)),
+                None,
+                None,
+                None,
+                Some(vec![OrderBy::factory_colref(
+                    ColumnReference::factory(None, "id"),
+                    None,
+                )]),
+            )
+            .with_set_operation(
+                SetOperator::IntersectVariant,
+                None,
+                SelectCommand::factory(
+                vec![SelectField::factory(
+                    Expression::factory_colref(ColumnReference::factory(None, "id")),
+                    None,
+                )],
+                Some(FromItem::factory_tn("u", None)),
+                None,
+                None,
+                None,
+                None,
+            )
+            .with_set_quantifier(SetQuantifier::DistinctVariant),
+            )
+            .with_set_operation(
+                SetOperator::ExceptVariant,
+                Some(SetQuantifier::DistinctVariant),
+                SelectCommand::factory(
+                vec![SelectField::factory(
+                    Expression::factory_colref(ColumnReference::factory(None, "id")),
+                    None,
+                )],
+                Some(FromItem::factory_tn("v", None)),
+                None,
+                None,
+                None,
+                None,
+            ),
+            )
+            .with_limit(Some("1"), None),
+        ),
     ];
 
     let parser = ApllodbSqlParser::default();
@@ -317,6 +787,28 @@ fn test_select_rejected() {
     let sqls: Vec<&str> = vec![
         // Lack select_field.
         "SELECT FROM t",
+        // Empty quoted identifier.
+        r#"SELECT "" FROM t"#,
+        // Unterminated quoted identifier.
+        r#"SELECT "id FROM t"#,
+        // LIMIT without count.
+        "SELECT id FROM t LIMIT",
+        // Negative LIMIT.
+        "SELECT id FROM t LIMIT -1",
+        // OFFSET before LIMIT.
+        "SELECT id FROM t OFFSET 1 LIMIT 1",
+        // FETCH FIRST without ONLY.
+        "SELECT id FROM t FETCH FIRST 1 ROWS",
+        // Set operation without operand.
+        "SELECT id FROM t UNION",
+        // ORDER BY before set operation.
+        "SELECT id FROM t ORDER BY id UNION SELECT id FROM u",
+        // Outer join without ON.
+        "SELECT id FROM t LEFT JOIN s",
+        // CROSS JOIN with ON.
+        "SELECT id FROM t CROSS JOIN s ON t.id = s.t_id",
+        // OUTER without LEFT, RIGHT nor FULL.
+        "SELECT id FROM t OUTER JOIN s ON t.id = s.t_id",
     ];
 
     let parser = ApllodbSqlParser::default();
@@ -4,14 +4,17 @@ use apllodb_shared_components::ApllodbResult;
 use apllodb_storage_engine_interface::AlterTableAction;
 
 use super::AstTranslator;
+use crate::type_catalog::TypeCatalog;
 
 impl AstTranslator {
     pub fn alter_table_action(
         ast_alter_table_action: apllodb_ast::Action,
+        type_catalog: &TypeCatalog,
     ) -> ApllodbResult<AlterTableAction> {
         match ast_alter_table_action {
             apllodb_ast::Action::AddColumnVariant(ac) => {
-                let column_definition = Self::column_definition(ac.column_definition)?;
+                let column_definition =
+                    Self::column_definition(ac.column_definition, type_catalog)?;
                 Ok(AlterTableAction::AddColumn { column_definition })
             }
             apllodb_ast::Action::DropColumnVariant(dc) => {
@@ -4,17 +4,18 @@ use apllodb_storage_engine_interface::{
     ColumnConstraintKind, ColumnConstraints, ColumnDataType, ColumnDefinition,
 };
 
-use crate::ast_translator::AstTranslator;
+use crate::{ast_translator::AstTranslator, type_catalog::TypeCatalog};
 
 impl AstTranslator {
     pub fn column_definition(
         ast_column_definition: apllodb_ast::ColumnDefinition,
+        type_catalog: &TypeCatalog,
     ) -> ApllodbResult<ColumnDefinition> {
         let column_name = Self::column_name(ast_column_definition.column_name)?;
 
         let nullable = Self::nullable(&ast_column_definition.column_constraints);
 
-        let sql_type = Self::data_type(ast_column_definition.data_type);
+        let sql_type = Self::data_type(ast_column_definition.data_type, type_catalog)?;
 
         let column_constraint_kinds: Vec<ColumnConstraintKind> = ast_column_definition
             .column_constraints
@@ -3,13 +3,21 @@ use apllodb_sql_parser::apllodb_ast;
 
 use crate::{
     ast_translator::AstTranslator, correlation::aliased_correlation_name::AliasedCorrelationName,
+    function::function_registry::FunctionRegistry, type_catalog::TypeCatalog,
 };
 
 impl AstTranslator {
     pub fn condition_in_select(
         ast_condition: apllodb_ast::Condition,
         from_item_correlations: &[AliasedCorrelationName],
+        function_registry: &FunctionRegistry,
+        type_catalog: &TypeCatalog,
     ) -> ApllodbResult<Expression> {
-        Self::expression_in_select(ast_condition.expression, from_item_correlations)
+        Self::expression_in_select(
+            ast_condition.expression,
+            from_item_correlations,
+            function_registry,
+            type_catalog,
+        )
     }
 }
@@ -1,19 +1,190 @@
-use apllodb_shared_components::SqlType;
+use apllodb_shared_components::{ApllodbError, ApllodbResult, SqlType};
 use apllodb_sql_parser::apllodb_ast;
 
-use crate::ast_translator::AstTranslator;
+use crate::{ast_translator::AstTranslator, type_catalog::TypeCatalog};
 
 impl AstTranslator {
-    pub fn data_type(ast_data_type: apllodb_ast::DataType) -> SqlType {
-        match ast_data_type {
+    /// # Failures
+    ///
+    /// - [DataExceptionInvalidParameterValue](apllodb_shared_components::SqlState::DataExceptionInvalidParameterValue) when:
+    ///   - precision or scale of NUMERIC is out of range.
+    ///   - length of VARCHAR or CHAR is out of range.
+    /// - [NameErrorNotFound](apllodb_shared_components::SqlState::NameErrorNotFound) when:
+    ///   - user-defined type is not in `type_catalog`.
+    pub fn data_type(
+        ast_data_type: apllodb_ast::DataType,
+        type_catalog: &TypeCatalog,
+    ) -> ApllodbResult<SqlType> {
+        let sql_type = match ast_data_type {
             apllodb_ast::DataType::IntegerTypeVariant(i) => match i {
                 apllodb_ast::IntegerType::SmallIntVariant => SqlType::small_int(),
                 apllodb_ast::IntegerType::IntegerVariant => SqlType::integer(),
                 apllodb_ast::IntegerType::BigIntVariant => SqlType::big_int(),
             },
+            apllodb_ast::DataType::FloatingPointTypeVariant(f) => match f {
+                apllodb_ast::FloatingPointType::RealVariant => SqlType::real(),
+                apllodb_ast::FloatingPointType::DoublePrecisionVariant => {
+                    SqlType::double_precision()
+                }
+            },
+            apllodb_ast::DataType::DecimalTypeVariant(d) => match d.precision {
+                None => SqlType::numeric(),
+                Some(precision) => {
+                    let precision = Self::decimal_type_modifier(precision)?;
+                    let scale = d.scale.map(Self::decimal_type_modifier).transpose()?;
+                    SqlType::numeric_with_precision(precision, scale.unwrap_or(0))?
+                }
+            },
             apllodb_ast::DataType::CharacterTypeVariant(c) => match c {
                 apllodb_ast::CharacterType::TextVariant => SqlType::text(),
+                apllodb_ast::CharacterType::VarcharVariant(length) => {
+                    SqlType::varchar(Self::character_type_length(length)?)?
+                }
+                apllodb_ast::CharacterType::CharVariant(length) => SqlType::char(
+                    length
+                        .map(Self::character_type_length)
+                        .transpose()?
+                        .unwrap_or(1),
+                )?,
+            },
+            apllodb_ast::DataType::BooleanTypeVariant(b) => match b {
+                apllodb_ast::BooleanType::BooleanVariant => SqlType::boolean(),
+            },
+            apllodb_ast::DataType::BinaryTypeVariant(b) => match b {
+                apllodb_ast::BinaryType::ByteaVariant => SqlType::bytea(),
             },
+            apllodb_ast::DataType::JsonTypeVariant(j) => match j {
+                apllodb_ast::JsonType::JsonVariant => SqlType::json(),
+            },
+            apllodb_ast::DataType::DatetimeTypeVariant(d) => Self::datetime_type(d),
+            apllodb_ast::DataType::UserDefinedTypeVariant(type_name) => {
+                SqlType::adt(type_catalog.type_by_name(&type_name.0 .0)?.clone())
+            }
+            apllodb_ast::DataType::ArrayTypeVariant(element_type) => {
+                SqlType::array(Self::data_type(*element_type, type_catalog)?)
+            }
+        };
+        Ok(sql_type)
+    }
+
+    pub(crate) fn datetime_type(ast_datetime_type: apllodb_ast::DatetimeType) -> SqlType {
+        match ast_datetime_type {
+            apllodb_ast::DatetimeType::DateVariant => SqlType::date(),
+            apllodb_ast::DatetimeType::TimeVariant => SqlType::time(),
+            apllodb_ast::DatetimeType::TimestampVariant => SqlType::timestamp(),
+            apllodb_ast::DatetimeType::TimestampWithTimeZoneVariant => {
+                SqlType::timestamp_with_time_zone()
+            }
+            apllodb_ast::DatetimeType::FuzzyDateVariant => SqlType::fuzzy_date(),
+            apllodb_ast::DatetimeType::IntervalVariant => SqlType::interval(),
+        }
+    }
+
+    fn decimal_type_modifier(
+        ast_integer_constant: apllodb_ast::IntegerConstant,
+    ) -> ApllodbResult<u8> {
+        let s = ast_integer_constant.0;
+        s.parse::<u8>().map_err(|_| {
+            ApllodbError::data_exception_invalid_parameter_value(format!(
+                "NUMERIC precision / scale `{}` is out of range",
+                s
+            ))
+        })
+    }
+
+    fn character_type_length(
+        ast_integer_constant: apllodb_ast::IntegerConstant,
+    ) -> ApllodbResult<u32> {
+        let s = ast_integer_constant.0;
+        s.parse::<u32>().map_err(|_| {
+            ApllodbError::data_exception_invalid_parameter_value(format!(
+                "VARCHAR / CHAR length `{}` is out of range",
+                s
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use apllodb_shared_components::{ApllodbResult, SqlState, SqlType};
+    use apllodb_sql_parser::apllodb_ast;
+
+    use super::AstTranslator;
+    use crate::type_catalog::TypeCatalog;
+
+    fn numeric(precision: Option<&str>, scale: Option<&str>) -> apllodb_ast::DataType {
+        apllodb_ast::DataType::DecimalTypeVariant(apllodb_ast::DecimalType {
+            precision: precision.map(|p| apllodb_ast::IntegerConstant(p.to_string())),
+            scale: scale.map(|s| apllodb_ast::IntegerConstant(s.to_string())),
+        })
+    }
+
+    #[test]
+    fn test_decimal_type() -> ApllodbResult<()> {
+        assert_eq!(
+            AstTranslator::data_type(numeric(None, None), &TypeCatalog::default())?,
+            SqlType::numeric()
+        );
+        assert_eq!(
+            AstTranslator::data_type(numeric(Some("5"), None), &TypeCatalog::default())?,
+            SqlType::numeric_with_precision(5, 0)?
+        );
+        assert_eq!(
+            AstTranslator::data_type(numeric(Some("10"), Some("2")), &TypeCatalog::default())?,
+            SqlType::numeric_with_precision(10, 2)?
+        );
+
+        for (precision, scale) in &[("0", None), ("39", None), ("1000", None), ("2", Some("3"))] {
+            assert_eq!(
+                AstTranslator::data_type(numeric(Some(precision), *scale), &TypeCatalog::default())
+                    .unwrap_err()
+                    .kind(),
+                &SqlState::DataExceptionInvalidParameterValue
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_character_type() -> ApllodbResult<()> {
+        let varchar = |length: &str| {
+            apllodb_ast::DataType::CharacterTypeVariant(apllodb_ast::CharacterType::VarcharVariant(
+                apllodb_ast::IntegerConstant(length.to_string()),
+            ))
+        };
+        let char = |length: Option<&str>| {
+            apllodb_ast::DataType::CharacterTypeVariant(apllodb_ast::CharacterType::CharVariant(
+                length.map(|l| apllodb_ast::IntegerConstant(l.to_string())),
+            ))
+        };
+
+        assert_eq!(
+            AstTranslator::data_type(varchar("10"), &TypeCatalog::default())?,
+            SqlType::varchar(10)?
+        );
+        assert_eq!(
+            AstTranslator::data_type(char(Some("3")), &TypeCatalog::default())?,
+            SqlType::char(3)?
+        );
+        assert_eq!(
+            AstTranslator::data_type(char(None), &TypeCatalog::default())?,
+            SqlType::char(1)?
+        );
+
+        for data_type in [varchar("0"), char(Some("10485761")), varchar("99999999999")]
+            .iter()
+            .cloned()
+        {
+            assert_eq!(
+                AstTranslator::data_type(data_type, &TypeCatalog::default())
+                    .unwrap_err()
+                    .kind(),
+                &SqlState::DataExceptionInvalidParameterValue
+            );
         }
+        Ok(())
     }
 }
@@ -3,71 +3,478 @@ pub(crate) mod constant;
 pub(crate) mod unary_operator;
 
 use apllodb_shared_components::{
-    ApllodbResult, BinaryOperator, BooleanExpression, ComparisonFunction, Expression, SchemaIndex,
+    ApllodbError, ApllodbResult, ArithmeticFunction, ArrayValue, BetweenPredicate, BinaryOperator,
+    BooleanExpression, CaseExpression, ComparisonFunction, Expression, InPredicate, LikePredicate,
+    LogicalFunction, MatchArm, MatchExpression, NnSqlValue, SchemaIndex, SqlType, SqlValue,
 };
 use apllodb_sql_parser::apllodb_ast;
 use apllodb_storage_engine_interface::TableName;
+use chrono::Utc;
 
 use crate::{
     ast_translator::AstTranslator,
     correlation::{
         aliased_correlation_name::AliasedCorrelationName, correlation_name::CorrelationName,
     },
+    function::{
+        aggregate_function::AggregateFunction, builtin, function_registry::FunctionRegistry,
+    },
+    type_catalog::TypeCatalog,
 };
 
 impl AstTranslator {
     pub fn expression_in_select(
         ast_expression: apllodb_ast::Expression,
         from_item_correlations: &[AliasedCorrelationName],
+        function_registry: &FunctionRegistry,
+        type_catalog: &TypeCatalog,
     ) -> ApllodbResult<Expression> {
-        let expression: Expression = match ast_expression {
-            apllodb_ast::Expression::ConstantVariant(c) => {
-                let sql_value = Self::constant(c)?;
-                Expression::ConstantVariant(sql_value)
-            }
-            apllodb_ast::Expression::ColumnReferenceVariant(ast_colref) => {
-                let index = SchemaIndex::from(
-                    format!(
-                        "{}{}",
-                        if let Some(corr) = ast_colref.correlation {
-                            format!("{}.", corr.0 .0)
-                        } else {
-                            "".to_string()
-                        },
-                        ast_colref.column_name.0 .0
-                    )
-                    .as_str(),
-                );
-                Expression::SchemaIndexVariant(index)
-            }
-            apllodb_ast::Expression::UnaryOperatorVariant(uni_op, expr) => {
-                let uni_op = Self::unary_operator(uni_op);
-                let expr = Self::expression_in_select(*expr, from_item_correlations)?;
-                Expression::UnaryOperatorVariant(uni_op, Box::new(expr))
-            }
-            apllodb_ast::Expression::BinaryOperatorVariant(bin_op, left, right) => {
-                let bin_op = Self::binary_operator(bin_op);
-                let left = Self::expression_in_select(*left, from_item_correlations)?;
-                let right = Self::expression_in_select(*right, from_item_correlations)?;
+        let type_from_index =
+            |index: &SchemaIndex| type_catalog.column_type(from_item_correlations, index);
+
+        let expression: Expression =
+            match ast_expression {
+                apllodb_ast::Expression::ConstantVariant(c) => {
+                    let sql_value = Self::constant(c)?;
+                    Expression::ConstantVariant(sql_value)
+                }
+                apllodb_ast::Expression::ColumnReferenceVariant(ast_colref) => {
+                    let index = SchemaIndex::new(
+                        ast_colref.correlation.map(|corr| corr.0 .0),
+                        ast_colref.column_name.0 .0,
+                    );
+                    Expression::SchemaIndexVariant(index)
+                }
+                apllodb_ast::Expression::UnaryOperatorVariant(uni_op, expr) => {
+                    let expr = Self::expression_in_select(
+                        *expr,
+                        from_item_correlations,
+                        function_registry,
+                        type_catalog,
+                    )?;
+                    Self::unary_operator(uni_op, expr)
+                }
+                apllodb_ast::Expression::CastVariant(expr, data_type) => {
+                    let sql_type = Self::data_type(data_type, type_catalog)?;
+                    match (*expr, sql_type) {
+                        // element type of an empty array is given by CAST (e.g. `ARRAY[]::TEXT[]`)
+                        (
+                            apllodb_ast::Expression::ArrayConstructorVariant(ast_elements),
+                            SqlType::ArrayComparable(element_type),
+                        ) if ast_elements.is_empty() => {
+                            Expression::ConstantVariant(SqlValue::NotNull(NnSqlValue::Array(
+                                ArrayValue::new(*element_type, vec![])?,
+                            )))
+                        }
+                        (expr, sql_type) => {
+                            let expr = Self::expression_in_select(
+                                expr,
+                                from_item_correlations,
+                                function_registry,
+                                type_catalog,
+                            )?;
+                            Expression::CastVariant(Box::new(expr), sql_type)
+                        }
+                    }
+                }
+                apllodb_ast::Expression::BinaryOperatorVariant(bin_op, left, right) => {
+                    let bin_op = Self::binary_operator(bin_op);
+                    let left = Self::expression_in_select(
+                        *left,
+                        from_item_correlations,
+                        function_registry,
+                        type_catalog,
+                    )?;
+                    let right = Self::expression_in_select(
+                        *right,
+                        from_item_correlations,
+                        function_registry,
+                        type_catalog,
+                    )?;
 
-                match bin_op {
-                    BinaryOperator::Equal => Expression::BooleanExpressionVariant(
-                        BooleanExpression::ComparisonFunctionVariant(
-                            ComparisonFunction::EqualVariant {
-                                left: Box::new(left),
-                                right: Box::new(right),
-                            },
+                    let (left, right) = (Box::new(left), Box::new(right));
+                    let comparison = |f: ComparisonFunction| {
+                        Expression::BooleanExpressionVariant(
+                            BooleanExpression::ComparisonFunctionVariant(f),
+                        )
+                    };
+                    let logical = |f: LogicalFunction| {
+                        Expression::BooleanExpressionVariant(
+                            BooleanExpression::LogicalFunctionVariant(f),
+                        )
+                    };
+                    // JSON operators are built-in functions which are not callable by name.
+                    let json_operator =
+                        |as_text: bool, left: Box<Expression>, right: Box<Expression>| {
+                            builtin::json_operator(as_text)
+                                .resolve_call(vec![*left, *right], &type_from_index)
+                                .map(Expression::FunctionCallVariant)
+                        };
+                    match bin_op {
+                        BinaryOperator::Equal => {
+                            comparison(ComparisonFunction::EqualVariant { left, right })
+                        }
+                        BinaryOperator::NotEqual => {
+                            comparison(ComparisonFunction::NotEqualVariant { left, right })
+                        }
+                        BinaryOperator::LessThan => {
+                            comparison(ComparisonFunction::LessThanVariant { left, right })
+                        }
+                        BinaryOperator::LessThanOrEqual => {
+                            comparison(ComparisonFunction::LessThanOrEqualVariant { left, right })
+                        }
+                        BinaryOperator::GreaterThan => {
+                            comparison(ComparisonFunction::GreaterThanVariant { left, right })
+                        }
+                        BinaryOperator::GreaterThanOrEqual => {
+                            comparison(ComparisonFunction::GreaterThanOrEqualVariant {
+                                left,
+                                right,
+                            })
+                        }
+                        BinaryOperator::And => logical(LogicalFunction::AndVariant { left, right }),
+                        BinaryOperator::Or => logical(LogicalFunction::OrVariant { left, right }),
+                        BinaryOperator::Plus => {
+                            Expression::ArithmeticFunctionVariant(ArithmeticFunction::AddVariant {
+                                left,
+                                right,
+                            })
+                        }
+                        BinaryOperator::Minus => Expression::ArithmeticFunctionVariant(
+                            ArithmeticFunction::SubtractVariant { left, right },
                         ),
-                    ),
+                        BinaryOperator::Multiply => Expression::ArithmeticFunctionVariant(
+                            ArithmeticFunction::MultiplyVariant { left, right },
+                        ),
+                        BinaryOperator::Divide => Expression::ArithmeticFunctionVariant(
+                            ArithmeticFunction::DivideVariant { left, right },
+                        ),
+                        BinaryOperator::Modulo => Expression::ArithmeticFunctionVariant(
+                            ArithmeticFunction::ModuloVariant { left, right },
+                        ),
+                        BinaryOperator::JsonExtract => json_operator(false, left, right)?,
+                        BinaryOperator::JsonExtractText => json_operator(true, left, right)?,
+                    }
+                }
+                apllodb_ast::Expression::FunctionCallVariant(ast_function_call) => {
+                    let function_name = &ast_function_call.function_name.0 .0;
+                    if AggregateFunction::from_name(function_name).is_some() {
+                        // aggregate function calls are replaced with their results before translation if allowed
+                        return Err(ApllodbError::grouping_error(format!(
+                            "aggregate function `{}` is not allowed here",
+                            function_name
+                        )));
+                    }
+                    let expressions = match ast_function_call.arguments {
+                        apllodb_ast::FunctionArguments::ExpressionsVariant {
+                            set_quantifier: None,
+                            expressions,
+                        } => expressions,
+                        _ => {
+                            return Err(ApllodbError::grouping_error(format!(
+                        "`*`, ALL or DISTINCT is specified but `{}` is not an aggregate function",
+                        function_name
+                    )))
+                        }
+                    };
+                    let arguments = expressions
+                        .into_iter()
+                        .map(|expr| {
+                            Self::expression_in_select(
+                                expr,
+                                from_item_correlations,
+                                function_registry,
+                                type_catalog,
+                            )
+                        })
+                        .collect::<ApllodbResult<Vec<Expression>>>()?;
+                    let function_call = if function_registry.contains(function_name) {
+                        function_registry.resolve(function_name, arguments, &type_from_index)?
+                    } else {
+                        // `variant(fields...)` constructs a value of user-defined type
+                        match type_catalog.constructor(function_name, arguments.clone())? {
+                            Some(constructor) => constructor,
+                            None => function_registry.resolve(
+                                function_name,
+                                arguments,
+                                &type_from_index,
+                            )?,
+                        }
+                    };
+                    Expression::FunctionCallVariant(function_call)
+                }
+                apllodb_ast::Expression::InPredicateVariant(ast_in_predicate) => {
+                    let child = Box::new(Self::expression_in_select(
+                        *ast_in_predicate.expression,
+                        from_item_correlations,
+                        function_registry,
+                        type_catalog,
+                    )?);
+                    let list = ast_in_predicate
+                        .list
+                        .into_vec()
+                        .into_iter()
+                        .map(|expr| {
+                            Self::expression_in_select(
+                                expr,
+                                from_item_correlations,
+                                function_registry,
+                                type_catalog,
+                            )
+                        })
+                        .collect::<ApllodbResult<Vec<Expression>>>()?;
+                    let in_predicate = if ast_in_predicate.negated {
+                        InPredicate::NotInVariant { child, list }
+                    } else {
+                        InPredicate::InVariant { child, list }
+                    };
+                    Expression::BooleanExpressionVariant(BooleanExpression::InPredicateVariant(
+                        in_predicate,
+                    ))
+                }
+                apllodb_ast::Expression::BetweenPredicateVariant(ast_between_predicate) => {
+                    let child = Box::new(Self::expression_in_select(
+                        *ast_between_predicate.expression,
+                        from_item_correlations,
+                        function_registry,
+                        type_catalog,
+                    )?);
+                    let low = Box::new(Self::expression_in_select(
+                        *ast_between_predicate.low,
+                        from_item_correlations,
+                        function_registry,
+                        type_catalog,
+                    )?);
+                    let high = Box::new(Self::expression_in_select(
+                        *ast_between_predicate.high,
+                        from_item_correlations,
+                        function_registry,
+                        type_catalog,
+                    )?);
+                    let between_predicate = if ast_between_predicate.negated {
+                        BetweenPredicate::NotBetweenVariant { child, low, high }
+                    } else {
+                        BetweenPredicate::BetweenVariant { child, low, high }
+                    };
+                    Expression::BooleanExpressionVariant(
+                        BooleanExpression::BetweenPredicateVariant(between_predicate),
+                    )
+                }
+                apllodb_ast::Expression::LikePredicateVariant(ast_like_predicate) => {
+                    let child = Box::new(Self::expression_in_select(
+                        *ast_like_predicate.expression,
+                        from_item_correlations,
+                        function_registry,
+                        type_catalog,
+                    )?);
+                    let pattern = Box::new(Self::expression_in_select(
+                        *ast_like_predicate.pattern,
+                        from_item_correlations,
+                        function_registry,
+                        type_catalog,
+                    )?);
+                    let like_predicate = if ast_like_predicate.negated {
+                        LikePredicate::NotLikeVariant { child, pattern }
+                    } else {
+                        LikePredicate::LikeVariant { child, pattern }
+                    };
+                    Expression::BooleanExpressionVariant(BooleanExpression::LikePredicateVariant(
+                        like_predicate,
+                    ))
+                }
+                apllodb_ast::Expression::CaseExpressionVariant(ast_case_expression) => {
+                    let translate = |expr: apllodb_ast::Expression| {
+                        Self::expression_in_select(
+                            expr,
+                            from_item_correlations,
+                            function_registry,
+                            type_catalog,
+                        )
+                    };
+                    let operand = ast_case_expression
+                        .operand
+                        .map(|operand| translate(*operand))
+                        .transpose()?;
+                    let when_clauses = ast_case_expression
+                        .when_clauses
+                        .into_vec()
+                        .into_iter()
+                        .map(|when_clause| {
+                            Ok((
+                                translate(when_clause.expression)?,
+                                translate(when_clause.result)?,
+                            ))
+                        })
+                        .collect::<ApllodbResult<Vec<(Expression, Expression)>>>()?;
+                    let else_result = ast_case_expression
+                        .else_result
+                        .map(|else_result| translate(*else_result))
+                        .transpose()?;
+                    Expression::CaseExpressionVariant(CaseExpression::new(
+                        operand,
+                        when_clauses,
+                        else_result,
+                        &type_from_index,
+                    )?)
+                }
+                apllodb_ast::Expression::MatchExpressionVariant(ast_match_expression) => {
+                    let operand = Self::expression_in_select(
+                        *ast_match_expression.operand,
+                        from_item_correlations,
+                        function_registry,
+                        type_catalog,
+                    )?;
+
+                    let ast_match_arms = ast_match_expression.match_arms.into_vec();
+                    let variant_names: Vec<String> = ast_match_arms
+                        .iter()
+                        .filter_map(|arm| match &arm.pattern {
+                            apllodb_ast::MatchPattern::VariantPatternVariant(p) => {
+                                Some(p.variant_name.0 .0.clone())
+                            }
+                            apllodb_ast::MatchPattern::WildcardVariant => None,
+                        })
+                        .collect();
+                    let variant_name_refs: Vec<&str> =
+                        variant_names.iter().map(|v| v.as_str()).collect();
+                    if variant_name_refs.is_empty() {
+                        return Err(ApllodbError::data_exception_illegal_operation(
+                            "MATCH must have at least 1 variant pattern to infer operand's type",
+                        ));
+                    }
+                    let adt_type = type_catalog
+                        .type_by_variants(&variant_name_refs)?
+                        .ok_or_else(|| {
+                            ApllodbError::name_error_not_found(format!(
+                                "no type has all of the variants {:?}",
+                                variant_name_refs
+                            ))
+                        })?
+                        .clone();
+
+                    let arms = ast_match_arms
+                        .into_iter()
+                        .map(|arm| {
+                            let (variant_name, bindings) = match arm.pattern {
+                                apllodb_ast::MatchPattern::WildcardVariant => (None, vec![]),
+                                apllodb_ast::MatchPattern::VariantPatternVariant(p) => (
+                                    Some(p.variant_name.0 .0),
+                                    p.pattern_bindings
+                                        .into_iter()
+                                        .map(|b| match b {
+                                            apllodb_ast::PatternBinding::WildcardVariant => None,
+                                            apllodb_ast::PatternBinding::BindingNameVariant(n) => {
+                                                Some(n.0 .0)
+                                            }
+                                        })
+                                        .collect(),
+                                ),
+                            };
+                            // bound names shadow columns in the result
+                            let fields = variant_name
+                                .as_deref()
+                                .and_then(|v| adt_type.variant(v))
+                                .map_or(&[][..], |(_, variant)| variant.fields());
+                            let bound_types: Vec<(String, SqlType)> = bindings
+                                .iter()
+                                .zip(fields)
+                                .filter_map(|(binding, field)| {
+                                    binding.clone().map(|name| (name, field.sql_type().clone()))
+                                })
+                                .collect();
+                            let result = Self::expression_in_select(
+                                arm.result,
+                                from_item_correlations,
+                                function_registry,
+                                &type_catalog.with_bindings(bound_types),
+                            )?;
+                            Ok(MatchArm::new(variant_name, bindings, result))
+                        })
+                        .collect::<ApllodbResult<Vec<MatchArm>>>()?;
+
+                    Expression::MatchExpressionVariant(MatchExpression::new(
+                        operand,
+                        adt_type,
+                        arms,
+                        &type_from_index,
+                    )?)
+                }
+                apllodb_ast::Expression::CurrentDatetimeFunctionVariant(f) => {
+                    // evaluated once per statement
+                    let now = Utc::now();
+                    let nn_sql_value = match f {
+                        apllodb_ast::CurrentDatetimeFunction::CurrentDateVariant => {
+                            NnSqlValue::Date(now.naive_utc().date())
+                        }
+                        apllodb_ast::CurrentDatetimeFunction::CurrentTimestampVariant => {
+                            NnSqlValue::TimestampWithTimeZone(now)
+                        }
+                    };
+                    Expression::ConstantVariant(SqlValue::NotNull(nn_sql_value))
+                }
+                // Array operations are built-in functions which are not callable by name.
+                apllodb_ast::Expression::ArrayConstructorVariant(ast_elements) => {
+                    let elements = ast_elements
+                        .into_iter()
+                        .map(|expr| {
+                            Self::expression_in_select(
+                                expr,
+                                from_item_correlations,
+                                function_registry,
+                                type_catalog,
+                            )
+                        })
+                        .collect::<ApllodbResult<Vec<Expression>>>()?;
+                    Expression::FunctionCallVariant(
+                        builtin::array_constructor().resolve_call(elements, &type_from_index)?,
+                    )
+                }
+                apllodb_ast::Expression::SubscriptVariant(array, index) => {
+                    let array = Self::expression_in_select(
+                        *array,
+                        from_item_correlations,
+                        function_registry,
+                        type_catalog,
+                    )?;
+                    let index = Self::expression_in_select(
+                        *index,
+                        from_item_correlations,
+                        function_registry,
+                        type_catalog,
+                    )?;
+                    Expression::FunctionCallVariant(
+                        builtin::array_subscript()
+                            .resolve_call(vec![array, index], &type_from_index)?,
+                    )
+                }
+                apllodb_ast::Expression::AnyComparisonVariant(bin_op, left, array) => {
+                    let bin_op = Self::binary_operator(bin_op);
+                    let left = Self::expression_in_select(
+                        *left,
+                        from_item_correlations,
+                        function_registry,
+                        type_catalog,
+                    )?;
+                    let array = Self::expression_in_select(
+                        *array,
+                        from_item_correlations,
+                        function_registry,
+                        type_catalog,
+                    )?;
+                    Expression::FunctionCallVariant(
+                        builtin::any_comparison(&bin_op)
+                            .resolve_call(vec![left, array], &type_from_index)?,
+                    )
                 }
-            }
-        };
+            };
         Ok(expression)
     }
 
     pub fn expression_in_non_select(
         ast_expression: apllodb_ast::Expression,
         table_names: Vec<TableName>,
+        function_registry: &FunctionRegistry,
+        type_catalog: &TypeCatalog,
     ) -> ApllodbResult<Expression> {
         let corrs: Vec<AliasedCorrelationName> = table_names
             .into_iter()
@@ -76,6 +483,6 @@ impl AstTranslator {
                 AliasedCorrelationName::new(corr_name, None)
             })
             .collect();
-        Self::expression_in_select(ast_expression, &corrs)
+        Self::expression_in_select(ast_expression, &corrs, function_registry, type_catalog)
     }
 }
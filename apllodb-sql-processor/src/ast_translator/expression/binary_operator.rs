@@ -10,6 +10,20 @@ impl AstTranslator {
     ) -> BinaryOperator {
         match ast_binary_operator {
             apllodb_ast::BinaryOperator::Equal => BinaryOperator::Equal,
+            apllodb_ast::BinaryOperator::NotEqual => BinaryOperator::NotEqual,
+            apllodb_ast::BinaryOperator::LessThan => BinaryOperator::LessThan,
+            apllodb_ast::BinaryOperator::LessThanOrEqual => BinaryOperator::LessThanOrEqual,
+            apllodb_ast::BinaryOperator::GreaterThan => BinaryOperator::GreaterThan,
+            apllodb_ast::BinaryOperator::GreaterThanOrEqual => BinaryOperator::GreaterThanOrEqual,
+            apllodb_ast::BinaryOperator::And => BinaryOperator::And,
+            apllodb_ast::BinaryOperator::Or => BinaryOperator::Or,
+            apllodb_ast::BinaryOperator::Plus => BinaryOperator::Plus,
+            apllodb_ast::BinaryOperator::Minus => BinaryOperator::Minus,
+            apllodb_ast::BinaryOperator::Multiply => BinaryOperator::Multiply,
+            apllodb_ast::BinaryOperator::Divide => BinaryOperator::Divide,
+            apllodb_ast::BinaryOperator::Modulo => BinaryOperator::Modulo,
+            apllodb_ast::BinaryOperator::JsonExtract => BinaryOperator::JsonExtract,
+            apllodb_ast::BinaryOperator::JsonExtractText => BinaryOperator::JsonExtractText,
         }
     }
 }
@@ -1,7 +1,10 @@
+pub(crate) mod binary_constant;
+pub(crate) mod datetime_constant;
+pub(crate) mod json_constant;
 pub(crate) mod numeric_constant;
 pub(crate) mod string_constant;
 
-use apllodb_shared_components::{ApllodbResult, SqlValue};
+use apllodb_shared_components::{ApllodbResult, NnSqlValue, SqlValue};
 use apllodb_sql_parser::apllodb_ast;
 
 use crate::ast_translator::AstTranslator;
@@ -10,8 +13,17 @@ impl AstTranslator {
     pub(crate) fn constant(ast_constant: apllodb_ast::Constant) -> ApllodbResult<SqlValue> {
         let sql_value: SqlValue = match ast_constant {
             apllodb_ast::Constant::NullVariant => SqlValue::Null,
+            apllodb_ast::Constant::BooleanConstantVariant(bc) => {
+                SqlValue::NotNull(NnSqlValue::Boolean(match bc {
+                    apllodb_ast::BooleanConstant::TrueVariant => true,
+                    apllodb_ast::BooleanConstant::FalseVariant => false,
+                }))
+            }
             apllodb_ast::Constant::NumericConstantVariant(nc) => Self::numeric_constant(nc)?,
             apllodb_ast::Constant::StringConstantVariant(sc) => Self::string_constant(sc),
+            apllodb_ast::Constant::DatetimeConstantVariant(dc) => Self::datetime_constant(dc)?,
+            apllodb_ast::Constant::BinaryConstantVariant(bc) => Self::binary_constant(bc)?,
+            apllodb_ast::Constant::JsonConstantVariant(jc) => Self::json_constant(jc)?,
         };
         Ok(sql_value)
     }
@@ -0,0 +1,78 @@
+use apllodb_shared_components::{ApllodbResult, NnSqlValue, SqlType, SqlValue};
+use apllodb_sql_parser::apllodb_ast;
+
+use crate::ast_translator::AstTranslator;
+
+impl AstTranslator {
+    /// `X'01ff'` and `BYTEA '\x01ff'`, for example, are translated into a BYTEA value so that a malformed literal is reported before execution.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionInvalidCharacterValueForCast](apllodb_shared_components::SqlState::DataExceptionInvalidCharacterValueForCast) when:
+    ///   - `X'...'` contains a character other than hex digits and whitespaces, or has an odd number of hex digits.
+    ///   - the string of `BYTEA '...'` is in neither of the hex format nor the escape format.
+    pub(crate) fn binary_constant(
+        ast_binary_constant: apllodb_ast::BinaryConstant,
+    ) -> ApllodbResult<SqlValue> {
+        let text = match ast_binary_constant {
+            apllodb_ast::BinaryConstant::HexVariant(hex) => NnSqlValue::Text(format!("\\x{}", hex)),
+            apllodb_ast::BinaryConstant::TypedVariant(sc) => match Self::string_constant(sc) {
+                SqlValue::NotNull(text) => text,
+                SqlValue::Null => unreachable!("string constant is not NULL"),
+            },
+        };
+        Ok(SqlValue::NotNull(text.cast(&SqlType::bytea())?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use apllodb_shared_components::{ApllodbResult, NnSqlValue, SqlState, SqlValue};
+    use apllodb_sql_parser::apllodb_ast;
+
+    use super::AstTranslator;
+
+    fn typed(s: &str) -> apllodb_ast::BinaryConstant {
+        apllodb_ast::BinaryConstant::TypedVariant(apllodb_ast::StringConstant::StandardVariant(
+            s.to_string(),
+        ))
+    }
+
+    #[test]
+    fn test_binary_constant() -> ApllodbResult<()> {
+        let test_data: Vec<(apllodb_ast::BinaryConstant, Vec<u8>)> = vec![
+            (
+                apllodb_ast::BinaryConstant::HexVariant("01fF".to_string()),
+                vec![0x01, 0xff],
+            ),
+            (
+                apllodb_ast::BinaryConstant::HexVariant("".to_string()),
+                vec![],
+            ),
+            (typed(r"\x00 7f"), vec![0x00, 0x7f]),
+            (typed(r"ab\000\\"), vec![b'a', b'b', 0, b'\\']),
+        ];
+        for (input, expected) in test_data {
+            match AstTranslator::binary_constant(input)? {
+                SqlValue::NotNull(NnSqlValue::Bytes(bytes)) => assert_eq!(bytes, expected),
+                x => panic!("{:?}", x),
+            }
+        }
+
+        for input in &[
+            apllodb_ast::BinaryConstant::HexVariant("0".to_string()),
+            apllodb_ast::BinaryConstant::HexVariant("zz".to_string()),
+            typed(r"\q"),
+        ] {
+            assert_eq!(
+                AstTranslator::binary_constant(input.clone())
+                    .unwrap_err()
+                    .kind(),
+                &SqlState::DataExceptionInvalidCharacterValueForCast
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+use apllodb_shared_components::{ApllodbResult, SqlValue};
+use apllodb_sql_parser::apllodb_ast;
+
+use crate::ast_translator::AstTranslator;
+
+impl AstTranslator {
+    /// `DATE '2021-01-01'`, for example, is translated into a DATE value (not into `CAST('2021-01-01' AS DATE)`)
+    /// so that a malformed literal is reported before execution.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionInvalidDatetimeFormat](apllodb_shared_components::SqlState::DataExceptionInvalidDatetimeFormat) when:
+    ///   - the string does not represent a value of the type.
+    /// - [DataExceptionDatetimeFieldOverflow](apllodb_shared_components::SqlState::DataExceptionDatetimeFieldOverflow) when:
+    ///   - a field of the string is out of range (e.g. `DATE '2021-02-30'`).
+    pub(crate) fn datetime_constant(
+        ast_datetime_constant: apllodb_ast::DatetimeConstant,
+    ) -> ApllodbResult<SqlValue> {
+        let sql_type = Self::datetime_type(ast_datetime_constant.datetime_type);
+        match Self::string_constant(ast_datetime_constant.string_constant) {
+            SqlValue::NotNull(text) => Ok(SqlValue::NotNull(text.cast(&sql_type)?)),
+            SqlValue::Null => unreachable!("string constant is not NULL"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use apllodb_shared_components::{ApllodbResult, SqlState};
+    use apllodb_sql_parser::apllodb_ast;
+
+    use super::AstTranslator;
+
+    fn datetime_constant(
+        datetime_type: apllodb_ast::DatetimeType,
+        s: &str,
+    ) -> apllodb_ast::DatetimeConstant {
+        apllodb_ast::DatetimeConstant {
+            datetime_type,
+            string_constant: apllodb_ast::StringConstant::StandardVariant(s.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_datetime_constant() -> ApllodbResult<()> {
+        let test_data: Vec<(apllodb_ast::DatetimeType, &str, &str)> = vec![
+            (
+                apllodb_ast::DatetimeType::DateVariant,
+                "2021-1-2",
+                "2021-01-02",
+            ),
+            (apllodb_ast::DatetimeType::TimeVariant, "12:34", "12:34:00"),
+            (
+                apllodb_ast::DatetimeType::TimestampVariant,
+                "2021-01-02 03:04:05.6",
+                "2021-01-02 03:04:05.600",
+            ),
+            (
+                apllodb_ast::DatetimeType::TimestampWithTimeZoneVariant,
+                "2021-01-02 03:04:05+09",
+                "2021-01-01 18:04:05+00:00",
+            ),
+            (
+                apllodb_ast::DatetimeType::IntervalVariant,
+                "1 day 2 hours",
+                "1 day 02:00:00",
+            ),
+        ];
+        for (datetime_type, input, expected) in test_data {
+            assert_eq!(
+                AstTranslator::datetime_constant(datetime_constant(datetime_type, input))?
+                    .to_string(),
+                expected
+            );
+        }
+
+        assert_eq!(
+            AstTranslator::datetime_constant(datetime_constant(
+                apllodb_ast::DatetimeType::DateVariant,
+                "2021-02-30"
+            ))
+            .unwrap_err()
+            .kind(),
+            &SqlState::DataExceptionDatetimeFieldOverflow
+        );
+        assert_eq!(
+            AstTranslator::datetime_constant(datetime_constant(
+                apllodb_ast::DatetimeType::TimeVariant,
+                "noon"
+            ))
+            .unwrap_err()
+            .kind(),
+            &SqlState::DataExceptionInvalidDatetimeFormat
+        );
+        Ok(())
+    }
+}
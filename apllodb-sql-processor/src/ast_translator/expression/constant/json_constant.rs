@@ -0,0 +1,53 @@
+use apllodb_shared_components::{ApllodbResult, SqlType, SqlValue};
+use apllodb_sql_parser::apllodb_ast;
+
+use crate::ast_translator::AstTranslator;
+
+impl AstTranslator {
+    /// `JSON '{"a": 1}'`, for example, is translated into a JSON value so that a malformed literal is reported before execution.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionInvalidJsonText](apllodb_shared_components::SqlState::DataExceptionInvalidJsonText) when:
+    ///   - the string of `JSON '...'` is not a JSON text.
+    pub(crate) fn json_constant(
+        ast_json_constant: apllodb_ast::JsonConstant,
+    ) -> ApllodbResult<SqlValue> {
+        match Self::string_constant(ast_json_constant.0) {
+            SqlValue::NotNull(text) => Ok(SqlValue::NotNull(text.cast(&SqlType::json())?)),
+            SqlValue::Null => unreachable!("string constant is not NULL"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use apllodb_shared_components::{ApllodbResult, NnSqlValue, SqlState, SqlValue};
+    use apllodb_sql_parser::apllodb_ast;
+
+    use super::AstTranslator;
+
+    fn json_constant(s: &str) -> apllodb_ast::JsonConstant {
+        apllodb_ast::JsonConstant(apllodb_ast::StringConstant::StandardVariant(s.to_string()))
+    }
+
+    #[test]
+    fn test_json_constant() -> ApllodbResult<()> {
+        match AstTranslator::json_constant(json_constant(r#"{"b": 1, "a": [true]}"#))? {
+            SqlValue::NotNull(NnSqlValue::Json(json)) => {
+                assert_eq!(json.to_string(), r#"{"a":[true],"b":1}"#)
+            }
+            x => panic!("{:?}", x),
+        }
+
+        assert_eq!(
+            AstTranslator::json_constant(json_constant("{"))
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionInvalidJsonText
+        );
+        Ok(())
+    }
+}
@@ -1,3 +1,5 @@
+pub(crate) mod decimal_constant;
+pub(crate) mod float_constant;
 pub(crate) mod integer_constant;
 
 use apllodb_shared_components::{ApllodbResult, SqlValue};
@@ -11,6 +13,8 @@ impl AstTranslator {
     ) -> ApllodbResult<SqlValue> {
         let sql_value: SqlValue = match ast_numeric_constant {
             apllodb_ast::NumericConstant::IntegerConstantVariant(ic) => Self::integer_constant(ic)?,
+            apllodb_ast::NumericConstant::DecimalConstantVariant(dc) => Self::decimal_constant(dc)?,
+            apllodb_ast::NumericConstant::FloatConstantVariant(fc) => Self::float_constant(fc)?,
         };
         Ok(sql_value)
     }
@@ -0,0 +1,59 @@
+use apllodb_shared_components::{ApllodbResult, Decimal, NnSqlValue, SqlValue};
+use apllodb_sql_parser::apllodb_ast;
+
+use crate::ast_translator::AstTranslator;
+
+impl AstTranslator {
+    /// Number with a decimal point is NUMERIC (exact), keeping its fractional digits (`1.50` has scale 2).
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionNumericValueOutOfRange](apllodb_shared_components::SqlState::DataExceptionNumericValueOutOfRange) when:
+    ///   - `ast_decimal_constant` has more digits than NUMERIC supports.
+    pub(crate) fn decimal_constant(
+        ast_decimal_constant: apllodb_ast::DecimalConstant,
+    ) -> ApllodbResult<SqlValue> {
+        let d = ast_decimal_constant.0.parse::<Decimal>()?;
+        Ok(SqlValue::NotNull(NnSqlValue::Numeric(d)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use apllodb_shared_components::{ApllodbResult, NnSqlValue, SqlState, SqlType, SqlValue};
+    use apllodb_sql_parser::apllodb_ast;
+
+    use super::AstTranslator;
+
+    #[test]
+    fn test_decimal_constant() -> ApllodbResult<()> {
+        for (input, expected) in &[
+            ("1.50", "1.50"),
+            ("1.", "1"),
+            (".5", "0.5"),
+            ("007.0", "7.0"),
+        ] {
+            match AstTranslator::decimal_constant(apllodb_ast::DecimalConstant(input.to_string()))?
+            {
+                SqlValue::NotNull(nn @ NnSqlValue::Numeric(_)) => {
+                    assert_eq!(nn.sql_type(), SqlType::numeric());
+                    assert_eq!(nn.to_string(), *expected);
+                }
+                x => panic!("{:?}", x),
+            }
+        }
+
+        assert_eq!(
+            AstTranslator::decimal_constant(apllodb_ast::DecimalConstant(format!(
+                "{}.5",
+                "9".repeat(39)
+            )))
+            .unwrap_err()
+            .kind(),
+            &SqlState::DataExceptionNumericValueOutOfRange
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use apllodb_shared_components::{ApllodbError, ApllodbResult, NnSqlValue, SqlValue};
+use apllodb_sql_parser::apllodb_ast;
+
+use crate::ast_translator::AstTranslator;
+
+impl AstTranslator {
+    /// Number with an exponent is DOUBLE PRECISION.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionNumericValueOutOfRange](apllodb_shared_components::SqlState::DataExceptionNumericValueOutOfRange) when:
+    ///   - `ast_float_constant` is out of range of `f64`.
+    pub(crate) fn float_constant(
+        ast_float_constant: apllodb_ast::FloatConstant,
+    ) -> ApllodbResult<SqlValue> {
+        let s = ast_float_constant.0;
+        match s.parse::<f64>() {
+            Ok(f) if f.is_finite() => Ok(SqlValue::NotNull(NnSqlValue::DoublePrecision(f))),
+            _ => Err(ApllodbError::data_exception_numeric_value_out_of_range(
+                format!("`{}` is out of range of DOUBLE PRECISION", s),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use apllodb_shared_components::{ApllodbResult, NnSqlValue, SqlState, SqlValue};
+    use apllodb_sql_parser::apllodb_ast;
+
+    use super::AstTranslator;
+
+    #[test]
+    fn test_float_constant() -> ApllodbResult<()> {
+        for (input, expected) in &[("1e3", 1000.0), ("1.5E-3", 0.0015), (".5e+2", 50.0)] {
+            match AstTranslator::float_constant(apllodb_ast::FloatConstant(input.to_string()))? {
+                SqlValue::NotNull(NnSqlValue::DoublePrecision(f)) => assert_eq!(f, *expected),
+                x => panic!("{:?}", x),
+            }
+        }
+
+        assert_eq!(
+            AstTranslator::float_constant(apllodb_ast::FloatConstant("1e999".to_string()))
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionNumericValueOutOfRange
+        );
+        Ok(())
+    }
+}
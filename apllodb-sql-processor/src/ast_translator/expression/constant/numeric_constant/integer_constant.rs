@@ -4,6 +4,10 @@ use apllodb_sql_parser::apllodb_ast;
 use crate::ast_translator::AstTranslator;
 
 impl AstTranslator {
+    /// Integer literal is typed as INTEGER, or BIGINT if it does not fit in INTEGER (as PostgreSQL does).
+    /// It is not typed as SMALLINT even if it fits, since arithmetic results keep the operand type
+    /// (e.g. `200 * 200` would be out of range of SMALLINT).
+    ///
     /// # Failures
     ///
     /// - [DataExceptionNumericValueOutOfRange](apllodb_shared_components::SqlState::DataExceptionNumericValueOutOfRange) when:
@@ -13,12 +17,8 @@ impl AstTranslator {
     ) -> ApllodbResult<SqlValue> {
         let s = ast_integer_constant.0;
 
-        s.parse::<i16>()
-            .map(|i| SqlValue::NotNull(NnSqlValue::SmallInt(i)))
-            .or_else(|_| {
-                s.parse::<i32>()
-                    .map(|i| SqlValue::NotNull(NnSqlValue::Integer(i)))
-            })
+        s.parse::<i32>()
+            .map(|i| SqlValue::NotNull(NnSqlValue::Integer(i)))
             .or_else(|_| {
                 s.parse::<i64>()
                     .map(|i| SqlValue::NotNull(NnSqlValue::BigInt(i)))
@@ -50,28 +50,27 @@ mod test {
         }
 
         let test_data: Vec<TestDatum<'_>> = vec![
-            // I16
+            // I32 (even if it fits in I16)
             TestDatum {
                 input_ast_integer_constant: "0",
-                expected_sql_type: SqlType::small_int(),
+                expected_sql_type: SqlType::integer(),
                 expected_rust_value: 0,
             },
             TestDatum {
                 input_ast_integer_constant: "-1",
-                expected_sql_type: SqlType::small_int(),
+                expected_sql_type: SqlType::integer(),
                 expected_rust_value: -1,
             },
             TestDatum {
                 input_ast_integer_constant: "32767",
-                expected_sql_type: SqlType::small_int(),
+                expected_sql_type: SqlType::integer(),
                 expected_rust_value: i16::MAX as i64,
             },
             TestDatum {
                 input_ast_integer_constant: "-32768",
-                expected_sql_type: SqlType::small_int(),
+                expected_sql_type: SqlType::integer(),
                 expected_rust_value: i16::MIN as i64,
             },
-            // I32
             TestDatum {
                 input_ast_integer_constant: "32768",
                 expected_sql_type: SqlType::integer(),
@@ -5,6 +5,80 @@ use crate::ast_translator::AstTranslator;
 
 impl AstTranslator {
     pub(crate) fn string_constant(ast_string_constant: apllodb_ast::StringConstant) -> SqlValue {
-        SqlValue::NotNull(NnSqlValue::Text(ast_string_constant.0))
+        let s = match ast_string_constant {
+            apllodb_ast::StringConstant::StandardVariant(raw) => raw.replace("''", "'"),
+            apllodb_ast::StringConstant::EscapeVariant(raw) => Self::unescape(&raw),
+        };
+        SqlValue::NotNull(NnSqlValue::Text(s))
+    }
+
+    /// Interprets `''` and backslash escape sequences in `E'...'`.
+    ///
+    /// `\b`, `\f`, `\n`, `\r` and `\t` are control characters; a backslash followed by any other character stands for the character itself (e.g. `\\`, `\'`).
+    fn unescape(raw: &str) -> String {
+        let mut s = String::with_capacity(raw.len());
+        let mut chars = raw.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => match chars.next() {
+                    Some('b') => s.push('\u{8}'),
+                    Some('f') => s.push('\u{c}'),
+                    Some('n') => s.push('\n'),
+                    Some('r') => s.push('\r'),
+                    Some('t') => s.push('\t'),
+                    Some(escaped) => s.push(escaped),
+                    None => unreachable!("parser does not accept trailing backslash"),
+                },
+                '\'' => {
+                    let _quote = chars.next(); // `''`
+                    s.push('\'');
+                }
+                _ => s.push(c),
+            }
+        }
+        s
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use apllodb_shared_components::{NnSqlValue, SqlValue};
+    use apllodb_sql_parser::apllodb_ast;
+
+    use super::AstTranslator;
+
+    #[test]
+    fn test_string_constant() {
+        let test_data: Vec<(apllodb_ast::StringConstant, &str)> = vec![
+            (
+                apllodb_ast::StringConstant::StandardVariant("".to_string()),
+                "",
+            ),
+            (
+                apllodb_ast::StringConstant::StandardVariant("It''s".to_string()),
+                "It's",
+            ),
+            (
+                apllodb_ast::StringConstant::StandardVariant(r#"a\n"b""#.to_string()),
+                r#"a\n"b""#,
+            ),
+            (
+                apllodb_ast::StringConstant::EscapeVariant(r#"It\'s ''\\"#.to_string()),
+                r#"It's '\"#,
+            ),
+            (
+                apllodb_ast::StringConstant::EscapeVariant(r#"a\tb\nc\rd\be\ff\g"#.to_string()),
+                "a\tb\nc\rd\u{8}e\u{c}fg",
+            ),
+        ];
+
+        for (input, expected) in test_data {
+            assert_eq!(
+                AstTranslator::string_constant(input),
+                SqlValue::NotNull(NnSqlValue::Text(expected.to_string()))
+            );
+        }
     }
 }
@@ -1,12 +1,38 @@
-use apllodb_shared_components::UnaryOperator;
+use apllodb_shared_components::{
+    BooleanExpression, Expression, LogicalFunction, NullPredicate, UnaryOperator,
+};
 use apllodb_sql_parser::apllodb_ast;
 
 use crate::ast_translator::AstTranslator;
 
 impl AstTranslator {
-    pub(crate) fn unary_operator(ast_unary_operator: apllodb_ast::UnaryOperator) -> UnaryOperator {
+    /// Makes an expression applying `ast_unary_operator` to (already translated) `child`.
+    ///
+    /// Logical operators (NOT, POSSIBLY) and IS [NOT] NULL are translated into boolean expressions,
+    /// and others into [Expression::UnaryOperatorVariant](apllodb_shared_components::Expression::UnaryOperatorVariant).
+    pub(crate) fn unary_operator(
+        ast_unary_operator: apllodb_ast::UnaryOperator,
+        child: Expression,
+    ) -> Expression {
+        let child = Box::new(child);
         match ast_unary_operator {
-            apllodb_ast::UnaryOperator::Minus => UnaryOperator::Minus,
+            apllodb_ast::UnaryOperator::Minus => {
+                Expression::UnaryOperatorVariant(UnaryOperator::Minus, child)
+            }
+            apllodb_ast::UnaryOperator::Not => Expression::BooleanExpressionVariant(
+                BooleanExpression::LogicalFunctionVariant(LogicalFunction::NotVariant { child }),
+            ),
+            apllodb_ast::UnaryOperator::Possibly => {
+                Expression::BooleanExpressionVariant(BooleanExpression::LogicalFunctionVariant(
+                    LogicalFunction::PossiblyVariant { child },
+                ))
+            }
+            apllodb_ast::UnaryOperator::IsNull => Expression::BooleanExpressionVariant(
+                BooleanExpression::NullPredicateVariant(NullPredicate::IsNullVariant { child }),
+            ),
+            apllodb_ast::UnaryOperator::IsNotNull => Expression::BooleanExpressionVariant(
+                BooleanExpression::NullPredicateVariant(NullPredicate::IsNotNullVariant { child }),
+            ),
         }
     }
 }
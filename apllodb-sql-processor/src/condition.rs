@@ -9,6 +9,10 @@ use crate::Record;
 pub(crate) struct Condition(Expression);
 
 impl Condition {
+    pub(crate) fn as_expression(&self) -> &Expression {
+        &self.0
+    }
+
     /// # Failures
     ///
     /// - [DataExceptionIllegalOperation](apllodb-shared-components::SqlState::DataExceptionIllegalOperation) when:
@@ -16,10 +16,9 @@ pub(crate) struct FieldName {
 
 impl From<&FieldName> for SchemaIndex {
     fn from(n: &FieldName) -> Self {
-        let s = format!(
-            "{}.{}",
-            n.aliased_correlation_name.correlation_name, n.attribute_name
-        );
-        Self::from(s.as_str())
+        Self::new(
+            Some(n.aliased_correlation_name.correlation_name.to_string()),
+            n.attribute_name.to_string(),
+        )
     }
 }
@@ -0,0 +1,4 @@
+pub(crate) mod aggregate_function;
+pub(crate) mod builtin;
+pub(crate) mod function_registry;
+pub(crate) mod scalar_function;
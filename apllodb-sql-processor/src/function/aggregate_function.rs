@@ -0,0 +1,247 @@
+use std::collections::HashSet;
+
+use apllodb_shared_components::{
+    ApllodbError, ApllodbResult, Decimal, Expression, NnSqlValue, SchemaIndex, SqlCompareResult,
+    SqlValue,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::records::{distinct_key::DistinctKey, record::Record};
+
+/// Built-in aggregate functions, which compute a value from a group of records.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub(crate) enum AggregateFunction {
+    /// `COUNT(*)` counts records and `COUNT(expression)` counts non-NULL values (BIGINT).
+    Count,
+    /// Sum of non-NULL values: BIGINT for integers, and the same type as the argument for others.
+    Sum,
+    /// Average of non-NULL values: NUMERIC for integers and NUMERIC, DOUBLE PRECISION for floating-point numbers.
+    Avg,
+    /// Minimum non-NULL value.
+    Min,
+    /// Maximum non-NULL value.
+    Max,
+}
+
+impl AggregateFunction {
+    /// Aggregate function named `function_name` (case-insensitive) if any.
+    pub(crate) fn from_name(function_name: &str) -> Option<Self> {
+        match function_name.to_lowercase().as_str() {
+            "count" => Some(Self::Count),
+            "sum" => Some(Self::Sum),
+            "avg" => Some(Self::Avg),
+            "min" => Some(Self::Min),
+            "max" => Some(Self::Max),
+            _ => None,
+        }
+    }
+}
+
+/// Aggregate function call: `COUNT(*)`, `SUM(DISTINCT c)`, ...
+#[derive(Clone, PartialEq, Hash, Debug, Serialize, Deserialize, new)]
+pub(crate) struct AggregateCall {
+    function: AggregateFunction,
+    /// `None` for `COUNT(*)`.
+    argument: Option<Expression>,
+    /// Whether duplicate argument values are aggregated only once.
+    distinct: bool,
+}
+
+impl AggregateCall {
+    /// Accumulates records in a group one by one.
+    pub(crate) fn accumulator(&self) -> Accumulator {
+        let state = match self.function {
+            AggregateFunction::Count => AccumulatorState::Count(0),
+            AggregateFunction::Sum => AccumulatorState::Sum(None),
+            AggregateFunction::Avg => AccumulatorState::Avg(None, 0),
+            AggregateFunction::Min => AccumulatorState::Min(None),
+            AggregateFunction::Max => AccumulatorState::Max(None),
+        };
+        Accumulator {
+            distinct_values: self.distinct.then(HashSet::new),
+            state,
+        }
+    }
+
+    /// Fields referred by the argument.
+    pub(crate) fn argument_indexes(&self) -> Vec<SchemaIndex> {
+        self.argument
+            .as_ref()
+            .map_or_else(Vec::new, Expression::to_schema_indexes)
+    }
+
+    /// Evaluates the argument with `record`.
+    /// `COUNT(*)` takes a record itself, so it is evaluated as non-NULL.
+    ///
+    /// # Failures
+    ///
+    /// - Errors from [Expression::to_sql_value_for_expr_with_index()](apllodb_shared_components::Expression::to_sql_value_for_expr_with_index).
+    pub(crate) fn argument_value(&self, record: &Record) -> ApllodbResult<SqlValue> {
+        match &self.argument {
+            Some(expression) => expression
+                .to_sql_value_for_expr_with_index(&|index| record.get_sql_value(index).cloned()),
+            None => Ok(SqlValue::NotNull(NnSqlValue::Boolean(true))),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum AccumulatorState {
+    Count(i64),
+    Sum(Option<NnSqlValue>),
+    Avg(Option<NnSqlValue>, i64),
+    Min(Option<NnSqlValue>),
+    Max(Option<NnSqlValue>),
+}
+
+/// Intermediate state of an [AggregateCall](AggregateCall) for a group.
+#[derive(Clone, Debug)]
+pub(crate) struct Accumulator {
+    /// Values already accumulated (only for DISTINCT).
+    distinct_values: Option<HashSet<DistinctKey>>,
+    state: AccumulatorState,
+}
+
+impl Accumulator {
+    /// Accumulates an argument value. NULL is ignored.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalOperation](apllodb_shared_components::SqlState::DataExceptionIllegalOperation) when:
+    ///   - SUM or AVG takes a non-numeric value.
+    /// - [DataExceptionNumericValueOutOfRange](apllodb_shared_components::SqlState::DataExceptionNumericValueOutOfRange) when:
+    ///   - the sum overflows.
+    /// - [DataExceptionIllegalComparison](apllodb_shared_components::SqlState::DataExceptionIllegalComparison) when:
+    ///   - MIN or MAX takes values which cannot be ordered.
+    pub(crate) fn accumulate(&mut self, value: SqlValue) -> ApllodbResult<()> {
+        let nn = match &value {
+            SqlValue::Null => return Ok(()),
+            SqlValue::NotNull(nn) => nn.clone(),
+        };
+        if let Some(distinct_values) = &mut self.distinct_values {
+            if !distinct_values.insert(DistinctKey::new(vec![value])) {
+                return Ok(());
+            }
+        }
+
+        match &mut self.state {
+            AccumulatorState::Count(count) => *count += 1,
+            AccumulatorState::Sum(sum) => *sum = Some(Self::add(sum.take(), Self::summand(nn)?)?),
+            AccumulatorState::Avg(sum, count) => {
+                let summand = match Self::summand(nn)? {
+                    NnSqlValue::BigInt(v) => NnSqlValue::Numeric(Decimal::new(v.into(), 0)?),
+                    NnSqlValue::Real(v) => NnSqlValue::DoublePrecision(v.into()),
+                    summand => summand,
+                };
+                *sum = Some(Self::add(sum.take(), summand)?);
+                *count += 1;
+            }
+            AccumulatorState::Min(min) => {
+                if Self::replaces(min, &nn, SqlCompareResult::LessThan)? {
+                    *min = Some(nn);
+                }
+            }
+            AccumulatorState::Max(max) => {
+                if Self::replaces(max, &nn, SqlCompareResult::GreaterThan)? {
+                    *max = Some(nn);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Result of the aggregate function. SUM, AVG, MIN and MAX of no value are NULL.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionNumericValueOutOfRange](apllodb_shared_components::SqlState::DataExceptionNumericValueOutOfRange) when:
+    ///   - the average of NUMERIC values has too many digits.
+    pub(crate) fn finish(self) -> ApllodbResult<SqlValue> {
+        let nn = match self.state {
+            AccumulatorState::Count(count) => Some(NnSqlValue::BigInt(count)),
+            AccumulatorState::Sum(sum) => sum,
+            AccumulatorState::Avg(sum, count) => match sum {
+                Some(NnSqlValue::Numeric(sum)) => Some(NnSqlValue::Numeric(
+                    sum.divide(&Decimal::new(count.into(), 0)?)?,
+                )),
+                Some(NnSqlValue::DoublePrecision(sum)) => {
+                    Some(NnSqlValue::DoublePrecision(sum / count as f64))
+                }
+                Some(_) => unreachable!("AVG is accumulated in NUMERIC or DOUBLE PRECISION"),
+                None => None,
+            },
+            AccumulatorState::Min(v) | AccumulatorState::Max(v) => v,
+        };
+        Ok(nn.map_or(SqlValue::Null, SqlValue::NotNull))
+    }
+
+    /// Integers are summed up in BIGINT.
+    fn summand(nn: NnSqlValue) -> ApllodbResult<NnSqlValue> {
+        match nn {
+            NnSqlValue::SmallInt(v) => Ok(NnSqlValue::BigInt(v.into())),
+            NnSqlValue::Integer(v) => Ok(NnSqlValue::BigInt(v.into())),
+            NnSqlValue::BigInt(_)
+            | NnSqlValue::Real(_)
+            | NnSqlValue::DoublePrecision(_)
+            | NnSqlValue::Numeric(_) => Ok(nn),
+            _ => Err(ApllodbError::data_exception_illegal_operation(format!(
+                "cannot sum up {:?} value: {}",
+                nn.sql_type(),
+                nn
+            ))),
+        }
+    }
+
+    fn add(sum: Option<NnSqlValue>, summand: NnSqlValue) -> ApllodbResult<NnSqlValue> {
+        match (sum, summand) {
+            (None, summand) => Ok(summand),
+            (Some(NnSqlValue::BigInt(l)), NnSqlValue::BigInt(r)) => {
+                l.checked_add(r).map(NnSqlValue::BigInt).ok_or_else(|| {
+                    ApllodbError::data_exception_numeric_value_out_of_range(format!(
+                        "sum {} + {} is out of range of BIGINT",
+                        l, r
+                    ))
+                })
+            }
+            (Some(NnSqlValue::Real(l)), NnSqlValue::Real(r)) => Ok(NnSqlValue::Real(l + r)),
+            (Some(NnSqlValue::DoublePrecision(l)), NnSqlValue::DoublePrecision(r)) => {
+                Ok(NnSqlValue::DoublePrecision(l + r))
+            }
+            (Some(NnSqlValue::Numeric(l)), NnSqlValue::Numeric(r)) => {
+                l.add(&r).map(NnSqlValue::Numeric)
+            }
+            (Some(l), r) => Err(ApllodbError::data_exception_illegal_operation(format!(
+                "cannot sum up {:?} and {:?} values: {} + {}",
+                l.sql_type(),
+                r.sql_type(),
+                l,
+                r
+            ))),
+        }
+    }
+
+    /// Whether `candidate` replaces `current` minimum (`replacing_result` = LessThan) or maximum (GreaterThan).
+    /// Ambiguous values (see [SqlCompareResult::Indefinite](apllodb_shared_components::SqlCompareResult::Indefinite)) do not replace each other.
+    fn replaces(
+        current: &Option<NnSqlValue>,
+        candidate: &NnSqlValue,
+        replacing_result: SqlCompareResult,
+    ) -> ApllodbResult<bool> {
+        match current {
+            None => Ok(true),
+            Some(current) => match SqlValue::NotNull(candidate.clone())
+                .sql_compare(&SqlValue::NotNull(current.clone()))?
+            {
+                SqlCompareResult::NotEq => {
+                    Err(ApllodbError::data_exception_illegal_comparison(format!(
+                        "{:?} values cannot be ordered: {}, {}",
+                        candidate.sql_type(),
+                        candidate,
+                        current
+                    )))
+                }
+                res => Ok(res == replacing_result),
+            },
+        }
+    }
+}
@@ -0,0 +1,332 @@
+//! Built-in scalar functions.
+
+use std::convert::TryFrom;
+
+use apllodb_shared_components::{
+    ApllodbError, ApllodbResult, ArrayValue, BinaryOperator, Json, NnSqlValue, SqlCompareResult,
+    SqlType, SqlValue,
+};
+
+use super::scalar_function::{FunctionSignature, ParameterType, ReturnType, ScalarFunction};
+
+pub(super) fn functions() -> Vec<ScalarFunction> {
+    vec![
+        ScalarFunction::new(
+            "length",
+            FunctionSignature::exact(vec![ParameterType::String]),
+            ReturnType::Fixed(SqlType::integer()),
+            true,
+            |args| {
+                let n_chars = nn_arg(args, 0).unpack::<String>()?.chars().count();
+                let n_chars = i32::try_from(n_chars).map_err(|_| {
+                    ApllodbError::data_exception_numeric_value_out_of_range(format!(
+                        "length {} does not fit in INTEGER",
+                        n_chars
+                    ))
+                })?;
+                Ok(SqlValue::NotNull(NnSqlValue::Integer(n_chars)))
+            },
+        ),
+        ScalarFunction::new(
+            "upper",
+            FunctionSignature::exact(vec![ParameterType::String]),
+            ReturnType::Fixed(SqlType::text()),
+            true,
+            |args| {
+                let s = nn_arg(args, 0).unpack::<String>()?;
+                Ok(SqlValue::NotNull(NnSqlValue::Text(s.to_uppercase())))
+            },
+        ),
+        ScalarFunction::new(
+            "lower",
+            FunctionSignature::exact(vec![ParameterType::String]),
+            ReturnType::Fixed(SqlType::text()),
+            true,
+            |args| {
+                let s = nn_arg(args, 0).unpack::<String>()?;
+                Ok(SqlValue::NotNull(NnSqlValue::Text(s.to_lowercase())))
+            },
+        ),
+        ScalarFunction::new(
+            "abs",
+            FunctionSignature::exact(vec![ParameterType::Numeric]),
+            ReturnType::UnifiedArguments,
+            true,
+            |args| {
+                let nn = nn_arg(args, 0);
+                let out_of_range = || {
+                    ApllodbError::data_exception_numeric_value_out_of_range(format!(
+                        "abs({}) is out of range of {:?}",
+                        nn,
+                        nn.sql_type()
+                    ))
+                };
+                let abs = match nn {
+                    NnSqlValue::SmallInt(v) => v
+                        .checked_abs()
+                        .map(NnSqlValue::SmallInt)
+                        .ok_or_else(out_of_range),
+                    NnSqlValue::Integer(v) => v
+                        .checked_abs()
+                        .map(NnSqlValue::Integer)
+                        .ok_or_else(out_of_range),
+                    NnSqlValue::BigInt(v) => v
+                        .checked_abs()
+                        .map(NnSqlValue::BigInt)
+                        .ok_or_else(out_of_range),
+                    NnSqlValue::Real(v) => Ok(NnSqlValue::Real(v.abs())),
+                    NnSqlValue::DoublePrecision(v) => Ok(NnSqlValue::DoublePrecision(v.abs())),
+                    NnSqlValue::Numeric(v) => Ok(NnSqlValue::Numeric(v.abs())),
+                    NnSqlValue::Text(_)
+                    | NnSqlValue::Char(_)
+                    | NnSqlValue::Boolean(_)
+                    | NnSqlValue::Bytes(_)
+                    | NnSqlValue::Json(_)
+                    | NnSqlValue::Date(_)
+                    | NnSqlValue::Time(_)
+                    | NnSqlValue::Timestamp(_)
+                    | NnSqlValue::TimestampWithTimeZone(_)
+                    | NnSqlValue::FuzzyDate(_)
+                    | NnSqlValue::Interval(_)
+                    | NnSqlValue::Adt(_)
+                    | NnSqlValue::Array(_) => {
+                        unreachable!("argument type is checked by signature")
+                    }
+                }?;
+                Ok(SqlValue::NotNull(abs))
+            },
+        ),
+        ScalarFunction::new(
+            "json_extract",
+            FunctionSignature::exact(vec![ParameterType::Json, ParameterType::String]),
+            ReturnType::Fixed(SqlType::json()),
+            true,
+            |args| {
+                let json = nn_arg(args, 0).unpack::<Json>()?;
+                let path = nn_arg(args, 1).unpack::<String>()?;
+                Ok(json
+                    .extract(&path)?
+                    .map_or(SqlValue::Null, |j| SqlValue::NotNull(NnSqlValue::Json(j))))
+            },
+        ),
+        ScalarFunction::new(
+            "json_array_length",
+            FunctionSignature::exact(vec![ParameterType::Json]),
+            ReturnType::Fixed(SqlType::integer()),
+            true,
+            |args| {
+                let json = nn_arg(args, 0).unpack::<Json>()?;
+                let len = json.array_length().ok_or_else(|| {
+                    ApllodbError::data_exception_invalid_parameter_value(format!(
+                        "cannot get array length of a non-array: {}",
+                        json
+                    ))
+                })?;
+                let len = i32::try_from(len).map_err(|_| {
+                    ApllodbError::data_exception_numeric_value_out_of_range(format!(
+                        "array length {} does not fit in INTEGER",
+                        len
+                    ))
+                })?;
+                Ok(SqlValue::NotNull(NnSqlValue::Integer(len)))
+            },
+        ),
+        ScalarFunction::new(
+            "array_length",
+            FunctionSignature::exact(vec![ParameterType::Array]),
+            ReturnType::Fixed(SqlType::integer()),
+            true,
+            |args| {
+                let array = nn_arg(args, 0).unpack::<ArrayValue>()?;
+                let len = i32::try_from(array.len()).map_err(|_| {
+                    ApllodbError::data_exception_numeric_value_out_of_range(format!(
+                        "array length {} does not fit in INTEGER",
+                        array.len()
+                    ))
+                })?;
+                Ok(SqlValue::NotNull(NnSqlValue::Integer(len)))
+            },
+        ),
+        ScalarFunction::new(
+            "coalesce",
+            FunctionSignature::variadic(vec![ParameterType::Any]),
+            ReturnType::UnifiedArguments,
+            false,
+            |args| {
+                Ok(args
+                    .iter()
+                    .find(|arg| matches!(arg, SqlValue::NotNull(_)))
+                    .cloned()
+                    .unwrap_or(SqlValue::Null))
+            },
+        ),
+    ]
+}
+
+/// Function behind `json -> key_or_index` (or `json ->> key_or_index` if `as_text`).
+///
+/// A string key gets an object member and an integer index gets an array element (negative one counts from the end).
+/// Returns NULL if no member or element is there.
+/// `->` returns JSON and `->>` returns TEXT, in which a JSON string is unquoted and a JSON `null` is NULL.
+pub(crate) fn json_operator(as_text: bool) -> ScalarFunction {
+    ScalarFunction::new(
+        if as_text { "->>" } else { "->" },
+        FunctionSignature::exact(vec![ParameterType::Json, ParameterType::Any]),
+        if as_text {
+            ReturnType::Fixed(SqlType::text())
+        } else {
+            ReturnType::Fixed(SqlType::json())
+        },
+        true,
+        move |args| {
+            let json = nn_arg(args, 0).unpack::<Json>()?;
+            let extracted = json_member_or_element(&json, nn_arg(args, 1))?;
+            let sql_value = match extracted {
+                None => SqlValue::Null,
+                Some(j) if as_text => j
+                    .to_text()
+                    .map_or(SqlValue::Null, |s| SqlValue::NotNull(NnSqlValue::Text(s))),
+                Some(j) => SqlValue::NotNull(NnSqlValue::Json(j)),
+            };
+            Ok(sql_value)
+        },
+    )
+}
+
+/// Function behind `ARRAY[element, ...]`.
+///
+/// The element type is unified from the elements (see [ArrayValue::from_elements()](apllodb_shared_components::ArrayValue::from_elements)).
+pub(crate) fn array_constructor() -> ScalarFunction {
+    ScalarFunction::new(
+        "array",
+        FunctionSignature::variadic(vec![ParameterType::Any]),
+        ReturnType::ArrayOfUnifiedArguments,
+        false,
+        |args| {
+            let array = ArrayValue::from_elements(args.to_vec())?;
+            Ok(SqlValue::NotNull(NnSqlValue::Array(array)))
+        },
+    )
+}
+
+/// Function behind `array[index]`.
+///
+/// `index` is 1-origin and NULL is returned if it is out of range.
+pub(crate) fn array_subscript() -> ScalarFunction {
+    ScalarFunction::new(
+        "[]",
+        FunctionSignature::exact(vec![ParameterType::Array, ParameterType::Numeric]),
+        ReturnType::Unknown,
+        true,
+        |args| {
+            let array = nn_arg(args, 0).unpack::<ArrayValue>()?;
+            let index = nn_arg(args, 1).unpack::<i64>().map_err(|_| {
+                ApllodbError::data_exception_illegal_operation(format!(
+                    "array subscript must be an integer but got {:?}",
+                    nn_arg(args, 1)
+                ))
+            })?;
+            Ok(array.element(index))
+        },
+    )
+}
+
+/// Function behind `value comparison_operator ANY(array)`.
+///
+/// TRUE if the comparison holds for any element, NULL if it is UNKNOWN for some element (e.g. NULL element) and does not hold for the others,
+/// and FALSE otherwise (including an empty array).
+///
+/// # Panics
+///
+/// If `comparison_operator` is not a comparison operator.
+pub(crate) fn any_comparison(comparison_operator: &BinaryOperator) -> ScalarFunction {
+    type Relation = fn(&SqlCompareResult) -> bool;
+    let (name, holds, negation_holds): (&str, Relation, Relation) = match comparison_operator {
+        BinaryOperator::Equal => (
+            "= any",
+            SqlCompareResult::is_equal,
+            SqlCompareResult::is_not_equal,
+        ),
+        BinaryOperator::NotEqual => (
+            "<> any",
+            SqlCompareResult::is_not_equal,
+            SqlCompareResult::is_equal,
+        ),
+        BinaryOperator::LessThan => (
+            "< any",
+            SqlCompareResult::is_less_than,
+            SqlCompareResult::is_greater_than_or_equal,
+        ),
+        BinaryOperator::LessThanOrEqual => (
+            "<= any",
+            SqlCompareResult::is_less_than_or_equal,
+            SqlCompareResult::is_greater_than,
+        ),
+        BinaryOperator::GreaterThan => (
+            "> any",
+            SqlCompareResult::is_greater_than,
+            SqlCompareResult::is_less_than_or_equal,
+        ),
+        BinaryOperator::GreaterThanOrEqual => (
+            ">= any",
+            SqlCompareResult::is_greater_than_or_equal,
+            SqlCompareResult::is_less_than,
+        ),
+        _ => unreachable!("ANY must follow a comparison operator"),
+    };
+
+    ScalarFunction::new(
+        name,
+        FunctionSignature::exact(vec![ParameterType::Any, ParameterType::Array]),
+        ReturnType::Fixed(SqlType::boolean()),
+        false,
+        move |args| {
+            let array = match &args[1] {
+                SqlValue::Null => return Ok(SqlValue::Null),
+                SqlValue::NotNull(nn) => nn.unpack::<ArrayValue>()?,
+            };
+
+            let mut unknown = false;
+            for element in array.elements() {
+                let sql_compare_result = args[0].sql_compare(element)?;
+                if holds(&sql_compare_result) {
+                    return Ok(SqlValue::NotNull(NnSqlValue::Boolean(true)));
+                } else if !negation_holds(&sql_compare_result) {
+                    unknown = true;
+                }
+            }
+            Ok(if unknown {
+                SqlValue::Null
+            } else {
+                SqlValue::NotNull(NnSqlValue::Boolean(false))
+            })
+        },
+    )
+}
+
+/// # Failures
+///
+/// - [DataExceptionIllegalOperation](apllodb_shared_components::SqlState::DataExceptionIllegalOperation) when:
+///   - `key_or_index` is neither a string nor an integer
+fn json_member_or_element(json: &Json, key_or_index: &NnSqlValue) -> ApllodbResult<Option<Json>> {
+    match key_or_index.sql_type() {
+        SqlType::StringComparableLoose(_) => {
+            Ok(json.member(key_or_index.unpack::<String>()?.trim_end_matches(' ')))
+        }
+        SqlType::NumericComparable(_) if key_or_index.unpack::<i64>().is_ok() => {
+            Ok(json.element(key_or_index.unpack::<i64>()?))
+        }
+        _ => Err(ApllodbError::data_exception_illegal_operation(format!(
+            "JSON key must be a string or an integer but got {:?}",
+            key_or_index
+        ))),
+    }
+}
+
+/// Argument of a strict function.
+fn nn_arg(args: &[SqlValue], pos: usize) -> &NnSqlValue {
+    match &args[pos] {
+        SqlValue::NotNull(nn) => nn,
+        SqlValue::Null => unreachable!("strict function is not called with NULL"),
+    }
+}
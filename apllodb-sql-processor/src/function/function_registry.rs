@@ -0,0 +1,572 @@
+use std::collections::HashMap;
+
+use apllodb_shared_components::{
+    ApllodbError, ApllodbResult, Expression, FunctionCall, SchemaIndex, SqlType,
+};
+
+use super::{builtin, scalar_function::ScalarFunction};
+
+/// Scalar functions callable from SQL.
+///
+/// [Default](std::default::Default) instance has built-in functions.
+/// Embedders can register their own functions by [register()](Self::register).
+#[derive(Debug)]
+pub struct FunctionRegistry {
+    functions: HashMap<String, ScalarFunction>,
+}
+
+impl Default for FunctionRegistry {
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        for function in builtin::functions() {
+            registry
+                .register(function)
+                .expect("built-in function names must be unique");
+        }
+        registry
+    }
+}
+
+impl FunctionRegistry {
+    /// Registry without any function (even built-in ones).
+    pub fn empty() -> Self {
+        Self {
+            functions: HashMap::new(),
+        }
+    }
+
+    /// Registers a scalar function.
+    ///
+    /// # Failures
+    ///
+    /// - [NameErrorDuplicate](apllodb_shared_components::SqlState::NameErrorDuplicate) when:
+    ///   - a function with the same name is already registered
+    pub fn register(&mut self, function: ScalarFunction) -> ApllodbResult<()> {
+        if self.functions.contains_key(function.name()) {
+            return Err(ApllodbError::name_error_duplicate(format!(
+                "function `{}` is already registered",
+                function.name()
+            )));
+        }
+        self.functions.insert(function.name().to_string(), function);
+        Ok(())
+    }
+
+    /// Whether a function is registered as `function_name`.
+    pub(crate) fn contains(&self, function_name: &str) -> bool {
+        self.functions.contains_key(&function_name.to_lowercase())
+    }
+
+    /// Resolves a function call by function name and the number of arguments.
+    /// The result type is chosen from the arguments' types, which are inferred by `type_from_index` for columns.
+    ///
+    /// # Failures
+    ///
+    /// - [NameErrorNotFound](apllodb_shared_components::SqlState::NameErrorNotFound) when:
+    ///   - no function is registered as `function_name`
+    ///   - the function does not take as many arguments as `arguments`
+    /// - [DataExceptionIllegalOperation](apllodb_shared_components::SqlState::DataExceptionIllegalOperation) when:
+    ///   - an argument's type does not match the signature
+    ///   - the result type cannot be resolved from the arguments' types (e.g. `COALESCE(1, 'a')`)
+    pub(crate) fn resolve(
+        &self,
+        function_name: &str,
+        arguments: Vec<Expression>,
+        type_from_index: &dyn Fn(&SchemaIndex) -> Option<SqlType>,
+    ) -> ApllodbResult<FunctionCall> {
+        let function = self
+            .functions
+            .get(&function_name.to_lowercase())
+            .ok_or_else(|| {
+                ApllodbError::name_error_not_found(format!(
+                    "function `{}` does not exist",
+                    function_name
+                ))
+            })?;
+
+        if !function.signature().accepts_arity(arguments.len()) {
+            return Err(ApllodbError::name_error_not_found(format!(
+                "function `{}` does not take {} argument(s): {:?}",
+                function_name,
+                arguments.len(),
+                function.signature()
+            )));
+        }
+
+        function.resolve_call(arguments, type_from_index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use apllodb_shared_components::{
+        ApllodbResult, ArrayValue, BinaryOperator, Expression, FunctionCall, NnSqlValue,
+        SchemaIndex, SqlState, SqlType, SqlValue,
+    };
+
+    use crate::function::{
+        builtin,
+        scalar_function::{FunctionSignature, ParameterType, ReturnType, ScalarFunction},
+    };
+
+    use super::FunctionRegistry;
+
+    fn text(s: &str) -> Expression {
+        Expression::ConstantVariant(SqlValue::NotNull(NnSqlValue::Text(s.to_string())))
+    }
+    fn integer(i: i32) -> Expression {
+        Expression::ConstantVariant(SqlValue::NotNull(NnSqlValue::Integer(i)))
+    }
+    fn eval(
+        registry: &FunctionRegistry,
+        name: &str,
+        args: Vec<Expression>,
+    ) -> ApllodbResult<SqlValue> {
+        let function_call = registry.resolve(name, args, &|_| None)?;
+        Expression::FunctionCallVariant(function_call).to_sql_value_for_expr_without_index()
+    }
+
+    #[test]
+    fn test_builtin() -> ApllodbResult<()> {
+        let registry = FunctionRegistry::default();
+
+        assert_eq!(
+            eval(&registry, "LENGTH", vec![text("あいう")])?,
+            SqlValue::NotNull(NnSqlValue::Integer(3))
+        );
+        assert_eq!(
+            eval(&registry, "upper", vec![text("abc")])?,
+            SqlValue::NotNull(NnSqlValue::Text("ABC".to_string()))
+        );
+        assert_eq!(
+            eval(&registry, "lower", vec![text("ABC")])?,
+            SqlValue::NotNull(NnSqlValue::Text("abc".to_string()))
+        );
+        assert_eq!(
+            eval(&registry, "abs", vec![integer(-3)])?,
+            SqlValue::NotNull(NnSqlValue::Integer(3))
+        );
+        assert_eq!(
+            eval(
+                &registry,
+                "abs",
+                vec![Expression::ConstantVariant(SqlValue::NotNull(
+                    NnSqlValue::SmallInt(i16::MIN)
+                ))]
+            )
+            .unwrap_err()
+            .kind(),
+            &SqlState::DataExceptionNumericValueOutOfRange
+        );
+        assert_eq!(
+            eval(
+                &registry,
+                "coalesce",
+                vec![
+                    Expression::ConstantVariant(SqlValue::Null),
+                    integer(1),
+                    integer(2)
+                ]
+            )?,
+            SqlValue::NotNull(NnSqlValue::Integer(1))
+        );
+
+        // strict functions return NULL for NULL input
+        assert!(matches!(
+            eval(
+                &registry,
+                "length",
+                vec![Expression::ConstantVariant(SqlValue::Null)]
+            )?,
+            SqlValue::Null
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_builtin_json() -> ApllodbResult<()> {
+        let registry = FunctionRegistry::default();
+        let json = |s: &str| {
+            Expression::ConstantVariant(SqlValue::NotNull(NnSqlValue::Json(s.parse().unwrap())))
+        };
+        let json_value = |s: &str| SqlValue::NotNull(NnSqlValue::Json(s.parse().unwrap()));
+
+        let doc = r#"{"a": {"b": [1, "x", null]}}"#;
+        assert_eq!(
+            eval(&registry, "json_extract", vec![json(doc), text("$.a.b[1]")])?,
+            json_value(r#""x""#)
+        );
+        assert!(matches!(
+            eval(&registry, "json_extract", vec![json(doc), text("$.c")])?,
+            SqlValue::Null
+        ));
+        assert_eq!(
+            eval(&registry, "json_extract", vec![json(doc), text("a")])
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionInvalidParameterValue
+        );
+        assert_eq!(
+            eval(&registry, "json_extract", vec![text(doc), text("$.a")])
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+
+        assert_eq!(
+            eval(&registry, "json_array_length", vec![json("[1, [2, 3]]")])?,
+            SqlValue::NotNull(NnSqlValue::Integer(2))
+        );
+        assert_eq!(
+            eval(&registry, "json_array_length", vec![json("{}")])
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionInvalidParameterValue
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_operator() -> ApllodbResult<()> {
+        let json = |s: &str| {
+            Expression::ConstantVariant(SqlValue::NotNull(NnSqlValue::Json(s.parse().unwrap())))
+        };
+        let apply = |as_text: bool, left: Expression, right: Expression| {
+            let function = builtin::json_operator(as_text);
+            Expression::FunctionCallVariant(FunctionCall::new(
+                function.name(),
+                vec![left, right],
+                function.checked_body(),
+            ))
+            .to_sql_value_for_expr_without_index()
+        };
+
+        let doc = r#"{"a": ["x", 2, null]}"#;
+        assert_eq!(
+            apply(false, json(doc), text("a"))?,
+            SqlValue::NotNull(NnSqlValue::Json(r#"["x",2,null]"#.parse()?))
+        );
+        assert_eq!(
+            apply(true, json(doc), text("a"))?,
+            SqlValue::NotNull(NnSqlValue::Text(r#"["x",2,null]"#.to_string()))
+        );
+        assert!(matches!(
+            apply(false, json(doc), text("z"))?,
+            SqlValue::Null
+        ));
+        assert!(matches!(
+            apply(false, json(doc), integer(0))?,
+            SqlValue::Null
+        ));
+
+        let array = r#"["x", 2, null]"#;
+        assert_eq!(
+            apply(true, json(array), integer(0))?,
+            SqlValue::NotNull(NnSqlValue::Text("x".to_string()))
+        );
+        assert_eq!(
+            apply(false, json(array), integer(0))?,
+            SqlValue::NotNull(NnSqlValue::Json(r#""x""#.parse()?))
+        );
+        assert_eq!(
+            apply(true, json(array), integer(-2))?,
+            SqlValue::NotNull(NnSqlValue::Text("2".to_string()))
+        );
+        // JSON null is NULL only as TEXT
+        assert_eq!(
+            apply(false, json(array), integer(2))?,
+            SqlValue::NotNull(NnSqlValue::Json("null".parse()?))
+        );
+        assert!(matches!(
+            apply(true, json(array), integer(2))?,
+            SqlValue::Null
+        ));
+        assert!(matches!(
+            apply(
+                true,
+                json(array),
+                Expression::ConstantVariant(SqlValue::Null)
+            )?,
+            SqlValue::Null
+        ));
+
+        assert_eq!(
+            apply(
+                false,
+                json(array),
+                Expression::ConstantVariant(SqlValue::NotNull(NnSqlValue::Boolean(true)))
+            )
+            .unwrap_err()
+            .kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_builtin_array() -> ApllodbResult<()> {
+        let registry = FunctionRegistry::default();
+        let apply = |function: ScalarFunction, args: Vec<Expression>| {
+            Expression::FunctionCallVariant(FunctionCall::new(
+                function.name(),
+                args,
+                function.checked_body(),
+            ))
+            .to_sql_value_for_expr_without_index()
+        };
+        let null = || Expression::ConstantVariant(SqlValue::Null);
+        let array = |elements: Vec<Expression>| {
+            Expression::ConstantVariant(apply(builtin::array_constructor(), elements).unwrap())
+        };
+        let boolean = |b: bool| SqlValue::NotNull(NnSqlValue::Boolean(b));
+
+        // ARRAY[...]
+        let tags = array(vec![text("a"), null(), text("c")]);
+        assert_eq!(
+            tags.to_sql_value_for_expr_without_index()?,
+            SqlValue::NotNull(NnSqlValue::Array(ArrayValue::new(
+                SqlType::text(),
+                vec![
+                    SqlValue::NotNull(NnSqlValue::Text("a".to_string())),
+                    SqlValue::Null,
+                    SqlValue::NotNull(NnSqlValue::Text("c".to_string())),
+                ]
+            )?))
+        );
+        assert_eq!(
+            apply(builtin::array_constructor(), vec![])
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+        assert_eq!(
+            apply(builtin::array_constructor(), vec![integer(1), text("a")])
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+
+        // array[index]
+        assert_eq!(
+            apply(builtin::array_subscript(), vec![tags.clone(), integer(3)])?,
+            SqlValue::NotNull(NnSqlValue::Text("c".to_string()))
+        );
+        for index in [integer(2), integer(4), integer(0), null()] {
+            assert!(matches!(
+                apply(builtin::array_subscript(), vec![tags.clone(), index])?,
+                SqlValue::Null
+            ));
+        }
+        assert_eq!(
+            apply(builtin::array_subscript(), vec![tags.clone(), text("1")])
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+        assert_eq!(
+            apply(builtin::array_subscript(), vec![text("a"), integer(1)])
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+
+        // value op ANY(array)
+        let any = |op: BinaryOperator, left: Expression, array: Expression| {
+            apply(builtin::any_comparison(&op), vec![left, array])
+        };
+        let integers = array(vec![integer(1), integer(2)]);
+        assert_eq!(
+            any(BinaryOperator::Equal, integer(2), integers.clone())?,
+            boolean(true)
+        );
+        assert_eq!(
+            any(BinaryOperator::Equal, integer(3), integers.clone())?,
+            boolean(false)
+        );
+        assert_eq!(
+            any(BinaryOperator::GreaterThan, integer(2), integers.clone())?,
+            boolean(true)
+        );
+        assert_eq!(
+            any(BinaryOperator::LessThan, integer(2), integers.clone())?,
+            boolean(false)
+        );
+        assert_eq!(
+            any(BinaryOperator::NotEqual, integer(1), integers.clone())?,
+            boolean(true)
+        );
+        // UNKNOWN unless any element matches
+        assert_eq!(
+            any(BinaryOperator::Equal, text("a"), tags.clone())?,
+            boolean(true)
+        );
+        assert!(matches!(
+            any(BinaryOperator::Equal, text("b"), tags.clone())?,
+            SqlValue::Null
+        ));
+        assert!(matches!(
+            any(BinaryOperator::Equal, null(), integers.clone())?,
+            SqlValue::Null
+        ));
+        assert!(matches!(
+            any(BinaryOperator::Equal, integer(1), null())?,
+            SqlValue::Null
+        ));
+        assert_eq!(
+            any(BinaryOperator::Equal, integer(1), tags.clone())
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalComparison
+        );
+        assert_eq!(
+            any(BinaryOperator::Equal, integer(1), integer(1))
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+
+        // array_length(array)
+        assert_eq!(
+            eval(&registry, "array_length", vec![tags])?,
+            SqlValue::NotNull(NnSqlValue::Integer(3))
+        );
+        assert_eq!(
+            eval(&registry, "array_length", vec![text("abc")])
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_failures() {
+        let registry = FunctionRegistry::default();
+
+        assert_eq!(
+            registry
+                .resolve("no_such_function", vec![], &|_| None)
+                .unwrap_err()
+                .kind(),
+            &SqlState::NameErrorNotFound
+        );
+        assert_eq!(
+            registry
+                .resolve("length", vec![text("a"), text("b")], &|_| None)
+                .unwrap_err()
+                .kind(),
+            &SqlState::NameErrorNotFound
+        );
+        assert_eq!(
+            registry
+                .resolve("coalesce", vec![], &|_| None)
+                .unwrap_err()
+                .kind(),
+            &SqlState::NameErrorNotFound
+        );
+        assert_eq!(
+            eval(&registry, "length", vec![integer(1)])
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+    }
+
+    #[test]
+    fn test_resolve_types() -> ApllodbResult<()> {
+        let registry = FunctionRegistry::default();
+        let column = || Expression::SchemaIndexVariant(SchemaIndex::from("c"));
+        let integer_column = |_: &SchemaIndex| Some(SqlType::integer());
+        let small_int =
+            |i: i16| Expression::ConstantVariant(SqlValue::NotNull(NnSqlValue::SmallInt(i)));
+
+        // COALESCE(INTEGER column, SMALLINT) is INTEGER even if the column is NULL
+        let function_call =
+            registry.resolve("coalesce", vec![column(), small_int(0)], &integer_column)?;
+        assert_eq!(function_call.result_type(), Some(&SqlType::integer()));
+        assert_eq!(
+            Expression::FunctionCallVariant(function_call)
+                .to_sql_value_for_expr_with_index(&|_| Ok(SqlValue::Null))?,
+            SqlValue::NotNull(NnSqlValue::Integer(0))
+        );
+
+        assert_eq!(
+            registry
+                .resolve("length", vec![column()], &|_| None)?
+                .result_type(),
+            Some(&SqlType::integer())
+        );
+        assert_eq!(
+            registry
+                .resolve("length", vec![column()], &integer_column)
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+        assert_eq!(
+            registry
+                .resolve("coalesce", vec![column(), text("a")], &integer_column)
+                .unwrap_err()
+                .kind(),
+            &SqlState::DataExceptionIllegalOperation
+        );
+
+        // ARRAY[INTEGER column, SMALLINT] is INTEGER[]
+        assert_eq!(
+            builtin::array_constructor()
+                .resolve_call(vec![column(), small_int(1)], &integer_column)?
+                .result_type(),
+            Some(&SqlType::array(SqlType::integer()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_register() -> ApllodbResult<()> {
+        let mut registry = FunctionRegistry::empty();
+        registry.register(ScalarFunction::new(
+            "Twice",
+            FunctionSignature::exact(vec![ParameterType::Numeric]),
+            ReturnType::Fixed(SqlType::big_int()),
+            true,
+            |args| match &args[0] {
+                SqlValue::NotNull(nn) => Ok(SqlValue::NotNull(NnSqlValue::BigInt(
+                    nn.unpack::<i64>()? * 2,
+                ))),
+                SqlValue::Null => unreachable!("strict function"),
+            },
+        ))?;
+
+        assert_eq!(
+            eval(&registry, "twice", vec![integer(21)])?,
+            SqlValue::NotNull(NnSqlValue::BigInt(42))
+        );
+        assert_eq!(
+            registry
+                .register(ScalarFunction::new(
+                    "TWICE",
+                    FunctionSignature::exact(vec![]),
+                    ReturnType::Unknown,
+                    true,
+                    |_| Ok(SqlValue::Null),
+                ))
+                .unwrap_err()
+                .kind(),
+            &SqlState::NameErrorDuplicate
+        );
+        assert_eq!(
+            registry
+                .resolve("length", vec![text("a")], &|_| None)
+                .unwrap_err()
+                .kind(),
+            &SqlState::NameErrorNotFound
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,266 @@
+use std::{fmt::Debug, sync::Arc};
+
+use apllodb_shared_components::{
+    ApllodbError, ApllodbResult, Expression, FunctionCall, ScalarFunctionBody, SchemaIndex,
+    SqlType, SqlValue,
+};
+
+/// Type of a scalar function's parameter.
+///
+/// Matched against the [SqlType](apllodb_shared_components::SqlType) of each non-NULL argument.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ParameterType {
+    /// Any type
+    Any,
+    /// Numeric types
+    Numeric,
+    /// String types
+    String,
+    /// Boolean types
+    Boolean,
+    /// JSON
+    Json,
+    /// Array types
+    Array,
+}
+
+impl ParameterType {
+    fn accepts(&self, sql_type: &SqlType) -> bool {
+        matches!(
+            (self, sql_type),
+            (ParameterType::Any, _)
+                | (ParameterType::Numeric, SqlType::NumericComparable(_))
+                | (ParameterType::String, SqlType::StringComparableLoose(_))
+                | (ParameterType::Boolean, SqlType::BooleanComparable)
+                | (ParameterType::Json, SqlType::JsonComparable)
+                | (ParameterType::Array, SqlType::ArrayComparable(_))
+        )
+    }
+}
+
+/// Type of a scalar function's result.
+///
+/// Non-NULL results are widened into the type resolved for each call (see [FunctionCall](apllodb_shared_components::FunctionCall)).
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ReturnType {
+    /// Always in this type
+    Fixed(SqlType),
+    /// Type unified from the arguments' types (see [SqlType::unified()](apllodb_shared_components::SqlType::unified))
+    UnifiedArguments,
+    /// Array of the type unified from the arguments' types
+    ArrayOfUnifiedArguments,
+    /// Not known before evaluation
+    Unknown,
+}
+
+impl ReturnType {
+    /// Resolves the result type from the arguments' types (None for unknown ones).
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalOperation](apllodb_shared_components::SqlState::DataExceptionIllegalOperation) when:
+    ///   - the arguments' types cannot be unified for [UnifiedArguments](Self::UnifiedArguments) or [ArrayOfUnifiedArguments](Self::ArrayOfUnifiedArguments)
+    pub(crate) fn resolve(
+        &self,
+        argument_types: &[Option<SqlType>],
+    ) -> ApllodbResult<Option<SqlType>> {
+        let unified_arguments = || {
+            argument_types
+                .iter()
+                .flatten()
+                .try_fold(None, |unified: Option<SqlType>, sql_type| {
+                    Ok(Some(match unified {
+                        Some(t) => t.unified(sql_type)?,
+                        None => sql_type.clone(),
+                    }))
+                })
+        };
+        match self {
+            ReturnType::Fixed(sql_type) => Ok(Some(sql_type.clone())),
+            ReturnType::UnifiedArguments => unified_arguments(),
+            ReturnType::ArrayOfUnifiedArguments => Ok(unified_arguments()?.map(SqlType::array)),
+            ReturnType::Unknown => Ok(None),
+        }
+    }
+}
+
+/// Parameters of a scalar function.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct FunctionSignature {
+    parameters: Vec<ParameterType>,
+    variadic: bool,
+}
+
+impl FunctionSignature {
+    /// Takes exactly as many arguments as `parameters`.
+    pub fn exact(parameters: Vec<ParameterType>) -> Self {
+        Self {
+            parameters,
+            variadic: false,
+        }
+    }
+
+    /// Takes as many or more arguments as `parameters`.
+    /// Extra arguments are typed as the last parameter.
+    ///
+    /// # Panics
+    ///
+    /// If `parameters` is empty.
+    pub fn variadic(parameters: Vec<ParameterType>) -> Self {
+        assert!(
+            !parameters.is_empty(),
+            "variadic function must have at least 1 parameter"
+        );
+        Self {
+            parameters,
+            variadic: true,
+        }
+    }
+
+    pub(crate) fn accepts_arity(&self, n_args: usize) -> bool {
+        if self.variadic {
+            n_args >= self.parameters.len()
+        } else {
+            n_args == self.parameters.len()
+        }
+    }
+
+    fn parameter_type(&self, pos: usize) -> &ParameterType {
+        self.parameters
+            .get(pos)
+            .or_else(|| self.parameters.last())
+            .expect("arity must be checked beforehand")
+    }
+}
+
+/// Scalar function to be registered in [FunctionRegistry](crate::FunctionRegistry).
+#[derive(Clone)]
+pub struct ScalarFunction {
+    name: String,
+    signature: FunctionSignature,
+    return_type: ReturnType,
+    strict: bool,
+    body: ScalarFunctionBody,
+}
+
+impl ScalarFunction {
+    /// Constructor.
+    ///
+    /// `name` is case-insensitive.
+    ///
+    /// A `strict` function returns NULL without calling `body` when any of its arguments is NULL.
+    ///
+    /// `body` is called only with arguments whose number and types match `signature`.
+    pub fn new(
+        name: &str,
+        signature: FunctionSignature,
+        return_type: ReturnType,
+        strict: bool,
+        body: impl Fn(&[SqlValue]) -> ApllodbResult<SqlValue> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name: name.to_lowercase(),
+            signature,
+            return_type,
+            strict,
+            body: Arc::new(body),
+        }
+    }
+
+    /// Function name (lowercase)
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Signature
+    pub fn signature(&self) -> &FunctionSignature {
+        &self.signature
+    }
+
+    /// Return type
+    pub fn return_type(&self) -> &ReturnType {
+        &self.return_type
+    }
+
+    /// Implementation which checks arguments' types before calling `body`.
+    pub(crate) fn checked_body(&self) -> ScalarFunctionBody {
+        let f = self.clone();
+        Arc::new(move |args| f.call(args))
+    }
+
+    /// Resolves a call with `arguments`, whose types are inferred by `type_from_index` for columns
+    /// (see [Expression::sql_type_with_index()](apllodb_shared_components::Expression::sql_type_with_index)).
+    ///
+    /// The number of `arguments` must be checked beforehand.
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalOperation](apllodb_shared_components::SqlState::DataExceptionIllegalOperation) when:
+    ///   - an argument's type does not match the signature
+    ///   - the result type cannot be resolved from the arguments' types
+    pub(crate) fn resolve_call(
+        &self,
+        arguments: Vec<Expression>,
+        type_from_index: &dyn Fn(&SchemaIndex) -> Option<SqlType>,
+    ) -> ApllodbResult<FunctionCall> {
+        let argument_types: Vec<Option<SqlType>> = arguments
+            .iter()
+            .map(|arg| arg.sql_type_with_index(type_from_index))
+            .collect();
+        for (pos, sql_type) in argument_types.iter().enumerate() {
+            if let Some(sql_type) = sql_type {
+                self.check_argument_type(pos, sql_type)?;
+            }
+        }
+        let result_type = self.return_type.resolve(&argument_types)?;
+
+        Ok(
+            FunctionCall::new(self.name(), arguments, self.checked_body())
+                .with_result_type(result_type),
+        )
+    }
+
+    /// # Failures
+    ///
+    /// - [DataExceptionIllegalOperation](apllodb_shared_components::SqlState::DataExceptionIllegalOperation) when:
+    ///   - an argument's type does not match the signature
+    fn call(&self, args: &[SqlValue]) -> ApllodbResult<SqlValue> {
+        for (pos, arg) in args.iter().enumerate() {
+            if let SqlValue::NotNull(nn) = arg {
+                self.check_argument_type(pos, &nn.sql_type())?;
+            }
+        }
+
+        if self.strict && args.iter().any(|arg| matches!(arg, SqlValue::Null)) {
+            Ok(SqlValue::Null)
+        } else {
+            (self.body)(args)
+        }
+    }
+
+    fn check_argument_type(&self, pos: usize, sql_type: &SqlType) -> ApllodbResult<()> {
+        let parameter_type = self.signature.parameter_type(pos);
+        if parameter_type.accepts(sql_type) {
+            Ok(())
+        } else {
+            Err(ApllodbError::data_exception_illegal_operation(format!(
+                "argument #{} of function `{}` must be {:?} but got {:?}",
+                pos + 1,
+                self.name,
+                parameter_type,
+                sql_type
+            )))
+        }
+    }
+}
+
+impl Debug for ScalarFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScalarFunction")
+            .field("name", &self.name)
+            .field("signature", &self.signature)
+            .field("return_type", &self.return_type)
+            .field("strict", &self.strict)
+            .finish()
+    }
+}
@@ -14,13 +14,21 @@ pub(crate) mod attribute;
 pub(crate) mod condition;
 pub(crate) mod correlation;
 pub(crate) mod field;
+pub(crate) mod function;
 pub(crate) mod records;
 pub(crate) mod select;
 pub(crate) mod sql_processor;
+pub(crate) mod type_catalog;
 
+pub use function::{
+    function_registry::FunctionRegistry,
+    scalar_function::{FunctionSignature, ParameterType, ReturnType, ScalarFunction},
+};
 pub use records::{record::Record, record_index::RecordIndex, Records};
 pub use sql_processor::{
-    sql_processor_context::SqlProcessorContext, success::SqlProcessorSuccess, SqlProcessor,
+    sql_processor_context::SqlProcessorContext,
+    success::{SqlProcessorScriptSuccess, SqlProcessorSuccess},
+    SqlProcessor,
 };
 
 #[cfg(any(test, feature = "test-support"))]
@@ -1,6 +1,8 @@
+pub(crate) mod distinct_key;
 pub(crate) mod record;
 pub(crate) mod record_index;
 pub(crate) mod record_schema;
+pub(crate) mod row_counter;
 
 use std::{
     collections::{HashMap, HashSet},
@@ -8,13 +10,22 @@ use std::{
 };
 
 use apllodb_shared_components::{
-    ApllodbResult, RPos, Schema, SchemaIndex, SqlCompareResult, SqlValue, SqlValueHashKey,
+    ApllodbResult, Expression, FuzzyDate, RPos, Schema, SchemaIndex, SqlCompareResult, SqlType,
+    SqlValue, SqlValueHashKey,
 };
 use apllodb_storage_engine_interface::{Row, Rows};
 
-use crate::{aliaser::Aliaser, condition::Condition, select::ordering::Ordering};
+use crate::{
+    aliaser::Aliaser,
+    condition::Condition,
+    field::field_alias::FieldAlias,
+    function::aggregate_function::{Accumulator, AggregateCall},
+    select::{join_type::JoinType, ordering::Ordering},
+};
 
-use self::{record::Record, record_schema::RecordSchema};
+use self::{
+    distinct_key::DistinctKey, record::Record, record_schema::RecordSchema, row_counter::RowCounter,
+};
 
 /// Seq of [Record](crate::Record)s.
 #[derive(Clone, PartialEq, Debug)]
@@ -112,83 +123,380 @@ impl Records {
         Ok(Self::new(new_schema, new_inner))
     }
 
+    /// Horizontally shrink records, and then append computed fields (constants, operations, ...) evaluated for each input record.
+    /// Computed fields are appended as unnamed fields (with their aliases if any) after the shrunk fields.
+    ///
+    /// # Failures
+    ///
+    /// - [NameErrorNotFound](apllodb_shared_components::SqlState::NameErrorNotFound) when:
+    ///   - Specified field does not exist in this record.
+    /// - Errors from [Expression::to_sql_value_for_expr_with_index()](apllodb_shared_components::Expression::to_sql_value_for_expr_with_index)
+    ///   when evaluating computed fields.
+    pub(crate) fn projection_with_computed_fields(
+        self,
+        indexes: &HashSet<SchemaIndex>,
+        computed_fields: &[(Expression, Option<FieldAlias>)],
+    ) -> ApllodbResult<Self> {
+        let computed_values = self
+            .inner
+            .iter()
+            .map(|record| {
+                computed_fields
+                    .iter()
+                    .map(|(expr, _)| {
+                        expr.to_sql_value_for_expr_with_index(&|index| {
+                            record.get_sql_value(index).cloned()
+                        })
+                    })
+                    .collect::<ApllodbResult<Vec<SqlValue>>>()
+            })
+            .collect::<ApllodbResult<Vec<_>>>()?;
+
+        let projected = self.projection(indexes)?;
+
+        let new_schema = Arc::new(
+            projected.as_schema().clone().with_unnamed_fields(
+                computed_fields
+                    .iter()
+                    .map(|(_, alias)| alias.clone())
+                    .collect(),
+            ),
+        );
+        let new_inner: Vec<Record> = projected
+            .inner
+            .into_iter()
+            .zip(computed_values)
+            .map(|(record, values)| {
+                let mut row = record.row;
+                for value in values {
+                    row.append(value);
+                }
+                Record::new(new_schema.clone(), row)
+            })
+            .collect();
+
+        Ok(Self::new(new_schema, new_inner))
+    }
+
     /// ORDER BY
     pub(crate) fn sort(mut self, field_orderings: &[(SchemaIndex, Ordering)]) -> Self {
         assert!(!field_orderings.is_empty(), "parser should avoid this case");
 
         // TODO check if type in FieldIndex is PartialOrd
 
-        self.inner.sort_by(|a_record, b_record| {
-            let mut res = std::cmp::Ordering::Equal;
+        self.inner
+            .sort_by(|a_record, b_record| Self::compare(a_record, b_record, field_orderings));
+        self
+    }
 
-            for (index, ord) in field_orderings {
-                let a_val = a_record
-                    .get_sql_value(index)
-                    .unwrap_or_else(|_| panic!("must be valid field: `{}`", index));
-                let b_val = b_record
-                    .get_sql_value(index)
-                    .unwrap_or_else(|_| panic!("must be valid field: `{}`", index));
+    /// ORDER BY with LIMIT: only the first `n` records in the order are kept.
+    ///
+    /// Unlike [sort()](Self::sort), records after the `n`-th are not sorted.
+    pub(crate) fn sort_top_n(
+        mut self,
+        field_orderings: &[(SchemaIndex, Ordering)],
+        n: usize,
+    ) -> Self {
+        assert!(!field_orderings.is_empty(), "parser should avoid this case");
 
-                match a_val.sql_compare(&b_val).unwrap_or_else(|_| {
-                    panic!(
+        if n == 0 {
+            self.inner.clear();
+        } else if n < self.inner.len() {
+            // partitions the first `n` records in O(len)
+            self.inner
+                .select_nth_unstable_by(n - 1, |a_record, b_record| {
+                    Self::compare(a_record, b_record, field_orderings)
+                });
+            self.inner.truncate(n);
+        }
+        self.inner
+            .sort_by(|a_record, b_record| Self::compare(a_record, b_record, field_orderings));
+        self
+    }
+
+    /// LIMIT and OFFSET: skips `offset` records and then takes `count` records (or all the rest if `None`).
+    pub(crate) fn limit(mut self, count: Option<usize>, offset: usize) -> Self {
+        let end = count.map_or(self.inner.len(), |count| {
+            offset.saturating_add(count).min(self.inner.len())
+        });
+        let start = offset.min(end);
+        self.inner.truncate(end);
+        self.inner.drain(..start);
+        self
+    }
+
+    fn compare(
+        a_record: &Record,
+        b_record: &Record,
+        field_orderings: &[(SchemaIndex, Ordering)],
+    ) -> std::cmp::Ordering {
+        let mut res = std::cmp::Ordering::Equal;
+
+        for (index, ord) in field_orderings {
+            let a_val = a_record
+                .get_sql_value(index)
+                .unwrap_or_else(|_| panic!("must be valid field: `{}`", index));
+            let b_val = b_record
+                .get_sql_value(index)
+                .unwrap_or_else(|_| panic!("must be valid field: `{}`", index));
+
+            match a_val.sql_compare(b_val).unwrap_or_else(|_| {
+                panic!(
                     "two records in the same RecordIterator must have the same type for field `{}`",
                     index
                 )
-                }) {
-                    SqlCompareResult::Eq => res = std::cmp::Ordering::Equal,
-                    SqlCompareResult::LessThan => {
-                        match ord {
-                            Ordering::Asc => {
-                                res = std::cmp::Ordering::Less;
-                            }
-                            Ordering::Desc => {
-                                res = std::cmp::Ordering::Greater;
-                            }
+            }) {
+                SqlCompareResult::Eq => res = std::cmp::Ordering::Equal,
+                SqlCompareResult::LessThan => {
+                    match ord {
+                        Ordering::Asc => {
+                            res = std::cmp::Ordering::Less;
+                        }
+                        Ordering::Desc => {
+                            res = std::cmp::Ordering::Greater;
                         }
-                        break;
                     }
-                    SqlCompareResult::GreaterThan => {
-                        match ord {
-                            Ordering::Asc => {
-                                res = std::cmp::Ordering::Greater;
-                            }
-                            Ordering::Desc => {
-                                res = std::cmp::Ordering::Less;
-                            }
+                    break;
+                }
+                SqlCompareResult::GreaterThan => {
+                    match ord {
+                        Ordering::Asc => {
+                            res = std::cmp::Ordering::Greater;
+                        }
+                        Ordering::Desc => {
+                            res = std::cmp::Ordering::Less;
                         }
-                        break;
                     }
-                    SqlCompareResult::Null => {
-                        // NULL comes last, regardless of ASC/DESC
-                        match (a_val, b_val) {
-                            (SqlValue::Null, SqlValue::Null) => res = std::cmp::Ordering::Equal,
-                            (SqlValue::Null, SqlValue::NotNull(_)) => {
-                                res = std::cmp::Ordering::Greater;
-                                break;
-                            }
-                            (SqlValue::NotNull(_), SqlValue::Null) => {
-                                res = std::cmp::Ordering::Less;
-                                break;
-                            }
-                            (SqlValue::NotNull(_), SqlValue::NotNull(_)) => unreachable!(
-                                "at least 1 should be NULL to get SqlCompareResult::Null"
-                            ),
+                    break;
+                }
+                SqlCompareResult::Null => {
+                    // NULL comes last, regardless of ASC/DESC
+                    match (a_val, b_val) {
+                        (SqlValue::Null, SqlValue::Null) => res = std::cmp::Ordering::Equal,
+                        (SqlValue::Null, SqlValue::NotNull(_)) => {
+                            res = std::cmp::Ordering::Greater;
+                            break;
+                        }
+                        (SqlValue::NotNull(_), SqlValue::Null) => {
+                            res = std::cmp::Ordering::Less;
+                            break;
+                        }
+                        (SqlValue::NotNull(_), SqlValue::NotNull(_)) => {
+                            unreachable!("at least 1 should be NULL to get SqlCompareResult::Null")
                         }
                     }
-                    SqlCompareResult::NotEq => {
-                        unreachable!("sort key `{}` must be at least PartialOrd", index)
+                }
+                SqlCompareResult::Indefinite { .. } => {
+                    // ambiguous dates are sorted by their ranges: earliest date first, and then latest date
+                    let range = |v: &SqlValue| -> FuzzyDate {
+                        match v {
+                            SqlValue::NotNull(nn) => nn
+                                .clone()
+                                .cast(&SqlType::fuzzy_date())
+                                .and_then(|nn| nn.unpack::<FuzzyDate>())
+                                .unwrap_or_else(|_| {
+                                    panic!("only FUZZY DATE is indefinitely compared: `{}`", index)
+                                }),
+                            SqlValue::Null => unreachable!("NULL is not indefinite"),
+                        }
+                    };
+                    match (range(a_val).cmp(&range(b_val)), ord) {
+                        (std::cmp::Ordering::Equal, _) => res = std::cmp::Ordering::Equal,
+                        (range_ord, Ordering::Asc) => {
+                            res = range_ord;
+                            break;
+                        }
+                        (range_ord, Ordering::Desc) => {
+                            res = range_ord.reverse();
+                            break;
+                        }
                     }
                 }
+                SqlCompareResult::NotEq => {
+                    unreachable!("sort key `{}` must be at least PartialOrd", index)
+                }
             }
-            res
-        });
-        self
+        }
+        res
+    }
+
+    /// GROUP BY algorithm using hash table.
+    /// Records having the same values (NULLs are not distinct from each other) for `group_by` fields make a group.
+    /// Without `group_by` fields, all records make a single group (even if there is no record).
+    ///
+    /// Each output record has `group_by` fields and then the results of `aggregate_calls` as unnamed fields with their aliases.
+    ///
+    /// # Failures
+    ///
+    /// - [NameErrorNotFound](apllodb_shared_components::SqlState::NameErrorNotFound) when:
+    ///   - Specified field does not exist in this record.
+    /// - Errors from [Accumulator](crate::function::aggregate_function::Accumulator) when aggregating values.
+    pub(crate) fn hash_aggregate(
+        self,
+        group_by: &[SchemaIndex],
+        aggregate_calls: &[(AggregateCall, FieldAlias)],
+    ) -> ApllodbResult<Self> {
+        let group_by_positions = group_by
+            .iter()
+            .map(|idx| self.schema.pos(idx))
+            .collect::<ApllodbResult<HashSet<RPos>>>()?;
+        let new_schema = Arc::new(
+            self.schema
+                .projection(&group_by.iter().cloned().collect())?
+                .with_unnamed_fields(
+                    aggregate_calls
+                        .iter()
+                        .map(|(_, alias)| Some(alias.clone()))
+                        .collect(),
+                ),
+        );
+        let new_accumulators = || -> Vec<Accumulator> {
+            aggregate_calls
+                .iter()
+                .map(|(call, _)| call.accumulator())
+                .collect()
+        };
+
+        // (group_by fields, accumulators) for each group
+        let mut groups = Vec::<(Row, Vec<Accumulator>)>::new();
+        let mut hash_table = HashMap::<DistinctKey, usize>::new();
+
+        for record in self.inner {
+            let key = DistinctKey::new(
+                group_by
+                    .iter()
+                    .map(|idx| record.get_sql_value(idx).cloned())
+                    .collect::<ApllodbResult<_>>()?,
+            );
+            let group_id = *hash_table.entry(key).or_insert_with(|| {
+                groups.push((
+                    record.row.clone().projection(&group_by_positions),
+                    new_accumulators(),
+                ));
+                groups.len() - 1
+            });
+
+            let (_, accumulators) = &mut groups[group_id];
+            for ((call, _), accumulator) in aggregate_calls.iter().zip(accumulators.iter_mut()) {
+                accumulator.accumulate(call.argument_value(&record)?)?;
+            }
+        }
+        if group_by.is_empty() && groups.is_empty() {
+            groups.push((Row::new(vec![]), new_accumulators()));
+        }
+
+        let new_inner = groups
+            .into_iter()
+            .map(|(mut row, accumulators)| {
+                for accumulator in accumulators {
+                    row.append(accumulator.finish()?);
+                }
+                Ok(Record::new(new_schema.clone(), row))
+            })
+            .collect::<ApllodbResult<Vec<Record>>>()?;
+
+        Ok(Self::new(new_schema, new_inner))
+    }
+
+    /// SELECT DISTINCT: eliminates duplicate records (NULLs are not distinct from each other).
+    /// The first one of duplicate records is kept and the order of records is preserved.
+    pub(crate) fn distinct(self) -> Self {
+        let mut seen = RowCounter::default();
+        let schema = self.schema.clone();
+        let new_inner: Vec<Record> = self
+            .inner
+            .into_iter()
+            .filter(|record| seen.add(record.row.clone().into_values()) == 1)
+            .collect();
+        Self::new(schema, new_inner)
+    }
+
+    /// UNION (ALL). Records from `right_records` follow records from self.
+    ///
+    /// Two inputs are supposed to have the same number of fields with unified types, which are checked in query planning.
+    pub(crate) fn union(self, right_records: Records, all: bool) -> ApllodbResult<Self> {
+        let right_records = self.conform_set_operation_input(right_records);
+
+        let mut union = self;
+        union.inner.extend(right_records.inner);
+
+        Ok(if all { union } else { union.distinct() })
+    }
+
+    /// INTERSECT (ALL). Records from self which also appear in `right_records` are output.
+    /// With `all`, a record appearing `m` times in self and `n` times in `right_records` is output `min(m, n)` times.
+    pub(crate) fn intersect(self, right_records: Records, all: bool) -> ApllodbResult<Self> {
+        let right_records = self.conform_set_operation_input(right_records);
+        let mut right_counts = RowCounter::default();
+        for record in right_records.inner {
+            let _ = right_counts.add(record.row.into_values());
+        }
+
+        let schema = self.schema.clone();
+        let records = if all { self } else { self.distinct() };
+        let new_inner: Vec<Record> = records
+            .inner
+            .into_iter()
+            .filter(
+                |record| match right_counts.count_mut(&record.row.clone().into_values()) {
+                    Some(cnt) if *cnt > 0 => {
+                        *cnt -= 1;
+                        true
+                    }
+                    _ => false,
+                },
+            )
+            .collect();
+        Ok(Self::new(schema, new_inner))
+    }
+
+    /// EXCEPT (ALL). Records from self which do not appear in `right_records` are output.
+    /// With `all`, a record appearing `m` times in self and `n` times in `right_records` is output `max(m - n, 0)` times.
+    pub(crate) fn except(self, right_records: Records, all: bool) -> ApllodbResult<Self> {
+        let right_records = self.conform_set_operation_input(right_records);
+        let mut right_counts = RowCounter::default();
+        for record in right_records.inner {
+            let _ = right_counts.add(record.row.into_values());
+        }
+
+        let schema = self.schema.clone();
+        let records = if all { self } else { self.distinct() };
+        let new_inner: Vec<Record> = records
+            .inner
+            .into_iter()
+            .filter(
+                |record| match right_counts.count_mut(&record.row.clone().into_values()) {
+                    Some(cnt) if all && *cnt > 0 => {
+                        *cnt -= 1;
+                        false
+                    }
+                    Some(cnt) => *cnt == 0,
+                    None => true,
+                },
+            )
+            .collect();
+        Ok(Self::new(schema, new_inner))
+    }
+
+    /// Makes `right_records` have the same schema as self, so that set operations (UNION, INTERSECT, EXCEPT) can mix them.
+    /// Fields are matched by their positions.
+    fn conform_set_operation_input(&self, right_records: Records) -> Records {
+        let schema = self.schema.clone();
+        Self::new(
+            schema.clone(),
+            right_records
+                .inner
+                .into_iter()
+                .map(|record| Record::new(schema.clone(), record.row)),
+        )
     }
 
     /// Join algorithm using hash table.
     /// It can be used with join keys' equality (like `ON t.id = s.t_id`).
     /// This algorithm's time-complexity is `max[O(len(self)), O(len(right_records))]` but uses relatively large memory.
     ///
+    /// In outer joins, records without any matching record (including ones with NULL join keys) are output with NULLs for the other side's fields.
+    ///
     /// # Failures
     ///
     /// - [NameErrorNotFound](apllodb_shared_components::SqlState::NameErrorNotFound) when:
@@ -199,61 +507,131 @@ impl Records {
         right_records: Records,
         self_join_field: &SchemaIndex,
         right_join_field: &SchemaIndex,
+        join_type: &JoinType,
     ) -> ApllodbResult<Self> {
         joined_schema.assert_all_named();
 
-        fn helper_join_records(
-            joined_schema: Arc<RecordSchema>,
-            left_record: Record,
-            right_record: Record,
-        ) -> ApllodbResult<Record> {
-            let sql_values: Vec<SqlValue> = joined_schema
-                .to_aliased_field_names()
-                .iter()
-                .map(|joined_name| {
-                    left_record
-                        .helper_get_sql_value(joined_name)
-                        .or_else(|| right_record.helper_get_sql_value(joined_name))
-                        .expect("left or right must have AliasedFieldName in joined_schema")
-                })
-                .collect::<ApllodbResult<_>>()?;
-
-            Ok(Record::new(joined_schema, Row::new(sql_values)))
-        }
+        let left_outer = matches!(join_type, JoinType::LeftOuter | JoinType::FullOuter);
+        let right_outer = matches!(join_type, JoinType::RightOuter | JoinType::FullOuter);
 
         // TODO Create hash table from smaller input.
-        let mut hash_table = HashMap::<SqlValueHashKey, Vec<Record>>::new();
+        let left_records = self.inner;
+        let mut left_matched = vec![false; left_records.len()];
+        let mut hash_table = HashMap::<SqlValueHashKey, Vec<usize>>::new();
 
-        for left_record in self {
+        for (left_i, left_record) in left_records.iter().enumerate() {
             let left_sql_value = left_record.get_sql_value(self_join_field)?;
             hash_table
                 .entry(SqlValueHashKey::from(left_sql_value))
-                // FIXME Clone less. If join keys are unique, no need for clone.
-                .and_modify(|records| records.push(left_record.clone()))
-                .or_insert_with(|| vec![left_record]);
+                .or_default()
+                .push(left_i);
         }
 
         let mut records = Vec::<Record>::new();
-        for right_record in right_records {
+        for right_record in right_records.inner {
             let right_sql_value = right_record.get_sql_value(right_join_field)?;
-            if let Some(left_records) = hash_table.get(&SqlValueHashKey::from(right_sql_value)) {
-                records.append(
-                    &mut left_records
-                        .iter()
-                        .map(|left_record| {
-                            helper_join_records(
-                                joined_schema.clone(),
-                                left_record.clone(),
-                                right_record.clone(),
-                            )
-                        })
-                        .collect::<ApllodbResult<Vec<Record>>>()?,
-                );
+            let mut right_matched = false;
+
+            if let Some(left_is) = hash_table.get(&SqlValueHashKey::from(right_sql_value)) {
+                for left_i in left_is {
+                    let left_record = &left_records[*left_i];
+                    // Same hash does not imply same value (e.g. hash collision between INTEGER and TEXT).
+                    // Comparing join keys here applies implicit type coercion and rejects incomparable types.
+                    let left_sql_value = left_record.get_sql_value(self_join_field)?;
+                    if left_sql_value.sql_compare(right_sql_value)?.is_equal() {
+                        records.push(Self::helper_join_records(
+                            joined_schema.clone(),
+                            Some(left_record),
+                            Some(&right_record),
+                        )?);
+                        left_matched[*left_i] = true;
+                        right_matched = true;
+                    }
+                }
+            }
+
+            if right_outer && !right_matched {
+                records.push(Self::helper_join_records(
+                    joined_schema.clone(),
+                    None,
+                    Some(&right_record),
+                )?);
+            }
+        }
+
+        if left_outer {
+            for (left_record, matched) in left_records.iter().zip(left_matched) {
+                if !matched {
+                    records.push(Self::helper_join_records(
+                        joined_schema.clone(),
+                        Some(left_record),
+                        None,
+                    )?);
+                }
+            }
+        }
+
+        Ok(Records::new(joined_schema, records))
+    }
+
+    /// Cartesian product of records (CROSS JOIN) using nested loop.
+    ///
+    /// # Failures
+    ///
+    /// - [NameErrorNotFound](apllodb_shared_components::SqlState::NameErrorNotFound) when:
+    ///   - Specified field does not exist in any record.
+    pub(crate) fn cross_join(
+        self,
+        joined_schema: Arc<RecordSchema>,
+        right_records: Records,
+    ) -> ApllodbResult<Self> {
+        joined_schema.assert_all_named();
+
+        let mut records =
+            Vec::<Record>::with_capacity(self.inner.len() * right_records.inner.len());
+        for left_record in &self.inner {
+            for right_record in &right_records.inner {
+                records.push(Self::helper_join_records(
+                    joined_schema.clone(),
+                    Some(left_record),
+                    Some(right_record),
+                )?);
             }
         }
 
         Ok(Records::new(joined_schema, records))
     }
+
+    /// Fields of a missing side (in outer joins) are NULL.
+    fn helper_join_records(
+        joined_schema: Arc<RecordSchema>,
+        left_record: Option<&Record>,
+        right_record: Option<&Record>,
+    ) -> ApllodbResult<Record> {
+        let sql_values: Vec<SqlValue> = joined_schema
+            .to_aliased_field_names()
+            .iter()
+            .map(|joined_name| {
+                let sql_value = left_record
+                    .and_then(|record| record.helper_get_sql_value(joined_name))
+                    .or_else(|| {
+                        right_record.and_then(|record| record.helper_get_sql_value(joined_name))
+                    });
+                match sql_value {
+                    Some(sql_value) => sql_value,
+                    None => {
+                        assert!(
+                            left_record.is_none() || right_record.is_none(),
+                            "left or right must have AliasedFieldName in joined_schema"
+                        );
+                        Ok(SqlValue::Null)
+                    }
+                }
+            })
+            .collect::<ApllodbResult<_>>()?;
+
+        Ok(Record::new(joined_schema, Row::new(sql_values)))
+    }
 }
 
 impl Iterator for Records {
@@ -0,0 +1,42 @@
+use std::hash::{Hash, Hasher};
+
+use apllodb_shared_components::{SqlValue, SqlValueHashKey};
+
+/// `Eq + Hash` key of a sequence of values, used to find duplicates (GROUP BY, DISTINCT, ...).
+///
+/// Two keys are equal if all of their values are not distinct from each other
+/// (see [SqlValue::is_not_distinct_from()](apllodb_shared_components::SqlValue::is_not_distinct_from)):
+/// unlike `=` (and [SqlValueHashKey](apllodb_shared_components::SqlValueHashKey) for NULL), NULL is not distinct from NULL.
+#[derive(Clone, Debug)]
+pub(crate) struct DistinctKey(Vec<SqlValue>);
+
+impl DistinctKey {
+    pub(crate) fn new(values: Vec<SqlValue>) -> Self {
+        Self(values)
+    }
+}
+
+impl PartialEq for DistinctKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(other.0.iter())
+                .all(|(a, b)| a.is_not_distinct_from(b))
+    }
+}
+
+impl Eq for DistinctKey {}
+
+impl Hash for DistinctKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for value in &self.0 {
+            match value {
+                SqlValue::Null => None,
+                SqlValue::NotNull(_) => Some(SqlValueHashKey::from(value)),
+            }
+            .hash(state)
+        }
+    }
+}
@@ -4,7 +4,7 @@ use crate::field::aliased_field_name::AliasedFieldName;
 
 use super::{record_index::RecordIndex, record_schema::RecordSchema};
 use apllodb_shared_components::{
-    ApllodbResult, RPos, Schema, SchemaIndex, SqlConvertible, SqlState, SqlValue,
+    ApllodbResult, RPos, SchemaIndex, SqlConvertible, SqlState, SqlValue,
 };
 use apllodb_storage_engine_interface::Row;
 
@@ -35,9 +35,8 @@ impl Record {
     /// Get sequence of field name vs SqlValue.
     pub fn into_name_values(self) -> Vec<(String, SqlValue)> {
         self.schema
-            .to_aliased_field_names()
+            .to_display_names()
             .into_iter()
-            .map(|afn| format!("{}", SchemaIndex::from(&afn)))
             .zip(self.row.into_values())
             .collect()
     }
@@ -50,10 +49,7 @@ impl Record {
     fn pos(&self, index: &RecordIndex) -> ApllodbResult<RPos> {
         match index {
             RecordIndex::Pos(pos) => Ok(*pos),
-            RecordIndex::Name(index) => {
-                let (pos, _) = self.schema.index(index)?;
-                Ok(pos)
-            }
+            RecordIndex::Name(index) => self.schema.pos(index),
         }
     }
 
@@ -1,12 +1,13 @@
 use std::collections::HashSet;
 
-use apllodb_shared_components::{RPos, Schema};
+use apllodb_shared_components::{ApllodbResult, RPos, Schema, SchemaIndex, SqlState};
 use apllodb_storage_engine_interface::RowSchema;
 use sorted_vec::SortedSet;
 
 use crate::{
-    aliaser::Aliaser, correlation::aliased_correlation_name::AliasedCorrelationName,
-    field::aliased_field_name::AliasedFieldName,
+    aliaser::Aliaser,
+    correlation::aliased_correlation_name::AliasedCorrelationName,
+    field::{aliased_field_name::AliasedFieldName, field_alias::FieldAlias},
 };
 
 /// Schema of records.
@@ -32,7 +33,7 @@ use crate::{
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
 pub(crate) struct RecordSchema {
     named: SortedSet<AliasedFieldName>,
-    unnamed_len: usize,
+    unnamed: Vec<Option<FieldAlias>>,
 }
 
 impl Schema for RecordSchema {
@@ -44,7 +45,7 @@ impl Schema for RecordSchema {
     {
         Self {
             named: SortedSet::from_unsorted(names.into_iter().collect()),
-            unnamed_len: unnamed_fields_len,
+            unnamed: vec![None; unnamed_fields_len],
         }
     }
 
@@ -56,7 +57,7 @@ impl Schema for RecordSchema {
             .map(|(raw_pos, afn)| (RPos::new(raw_pos), Some(afn.clone())))
             .collect();
 
-        for _ in 0..self.unnamed_len {
+        for _ in 0..self.unnamed.len() {
             let pos = v.len();
             v.push((RPos::new(pos), None))
         }
@@ -64,13 +65,54 @@ impl Schema for RecordSchema {
     }
 
     fn len(&self) -> usize {
-        self.named.len() + self.unnamed_len
+        self.named.len() + self.unnamed.len()
     }
 }
 
 impl RecordSchema {
     pub(crate) fn assert_all_named(&self) {
-        assert!(self.unnamed_len == 0);
+        assert!(self.unnamed.is_empty());
+    }
+
+    /// Appends unnamed fields (constants, operations, ...) with their optional aliases.
+    /// They are positioned after the existing fields.
+    pub(crate) fn with_unnamed_fields(mut self, aliases: Vec<Option<FieldAlias>>) -> Self {
+        self.unnamed.extend(aliases);
+        self
+    }
+
+    /// Finds position of a field.
+    /// Unlike [Schema::index()](apllodb_shared_components::Schema::index), unnamed fields can also be found by their aliases.
+    ///
+    /// # Failures
+    ///
+    /// Same as [Schema::index()](apllodb_shared_components::Schema::index).
+    pub(crate) fn pos(&self, idx: &SchemaIndex) -> ApllodbResult<RPos> {
+        match self.index(idx) {
+            Ok((pos, _)) => Ok(pos),
+            Err(e) if matches!(e.kind(), SqlState::NameErrorNotFound) && idx.prefix().is_none() => {
+                self.unnamed
+                    .iter()
+                    .position(|alias| alias.as_ref().map(FieldAlias::as_str) == Some(idx.attr()))
+                    .map(|unnamed_pos| RPos::new(self.named.len() + unnamed_pos))
+                    .ok_or(e)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Names to display for each field in position order.
+    /// Unnamed fields are displayed by their aliases or `?column?` (as PostgreSQL does).
+    pub(crate) fn to_display_names(&self) -> Vec<String> {
+        self.named
+            .iter()
+            .map(|afn| format!("{}", SchemaIndex::from(afn)))
+            .chain(self.unnamed.iter().map(|alias| {
+                alias
+                    .as_ref()
+                    .map_or_else(|| "?column?".to_string(), |a| a.as_str().to_string())
+            }))
+            .collect()
     }
 
     pub(crate) fn from_row_schema(row_schema: &RowSchema, aliaser: Aliaser) -> Self {
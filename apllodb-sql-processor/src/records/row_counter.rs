@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+
+use apllodb_shared_components::{SqlValue, SqlValueHashKey};
+
+/// Counts of rows, used to find duplicate records in DISTINCT and set operations (UNION, INTERSECT, EXCEPT).
+///
+/// Rows are put into buckets by [SqlValueHashKey](apllodb_shared_components::SqlValueHashKey)s of their values
+/// and then compared, since the same hash keys do not imply the same values (e.g. hash collision between INTEGER and TEXT).
+///
+/// Rows are compared by [SqlValue::is_not_distinct_from()](apllodb_shared_components::SqlValue::is_not_distinct_from):
+/// unlike `=`, NULL is not distinct from NULL here.
+/// NULL is put into a bucket by None because SqlValueHashKey of NULL differs every time (to avoid matching NULLs in hash joins).
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RowCounter(HashMap<Vec<Option<SqlValueHashKey>>, Vec<RowCount>>);
+
+type RowCount = (Vec<SqlValue>, usize);
+
+impl RowCounter {
+    /// Adds a row and returns the count of the row (including the added one).
+    pub(crate) fn add(&mut self, values: Vec<SqlValue>) -> usize {
+        let bucket = self.0.entry(Self::hash_keys(&values)).or_default();
+        match bucket
+            .iter_mut()
+            .find(|(bucket_values, _)| Self::is_not_distinct(bucket_values, &values))
+        {
+            Some((_, count)) => {
+                *count += 1;
+                *count
+            }
+            None => {
+                bucket.push((values, 1));
+                1
+            }
+        }
+    }
+
+    /// Count of the row, or None if the row has never been added.
+    pub(crate) fn count_mut(&mut self, values: &[SqlValue]) -> Option<&mut usize> {
+        self.0
+            .get_mut(&Self::hash_keys(values))?
+            .iter_mut()
+            .find(|(bucket_values, _)| Self::is_not_distinct(bucket_values, values))
+            .map(|(_, count)| count)
+    }
+
+    fn hash_keys(values: &[SqlValue]) -> Vec<Option<SqlValueHashKey>> {
+        values
+            .iter()
+            .map(|value| match value {
+                SqlValue::Null => None,
+                SqlValue::NotNull(_) => Some(SqlValueHashKey::from(value)),
+            })
+            .collect()
+    }
+
+    fn is_not_distinct(a: &[SqlValue], b: &[SqlValue]) -> bool {
+        a.len() == b.len()
+            && a.iter()
+                .zip(b.iter())
+                .all(|(a, b)| a.is_not_distinct_from(b))
+    }
+}
@@ -1 +1,2 @@
+pub(crate) mod join_type;
 pub(crate) mod ordering;
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// Join types which can be evaluated with join keys (`ON a = b`).
+///
+/// Records without any matching record are output with NULLs for the other side's fields in outer joins.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize, Deserialize)]
+pub enum JoinType {
+    /// INNER JOIN
+    Inner,
+    /// LEFT OUTER JOIN
+    LeftOuter,
+    /// RIGHT OUTER JOIN
+    RightOuter,
+    /// FULL OUTER JOIN
+    FullOuter,
+}
@@ -4,7 +4,7 @@ pub(crate) mod query;
 pub(crate) mod sql_processor_context;
 pub(crate) mod success;
 
-use std::sync::Arc;
+use std::rc::Rc;
 
 use apllodb_shared_components::{ApllodbError, ApllodbSessionError, ApllodbSessionResult, Session};
 use apllodb_sql_parser::{apllodb_ast, ApllodbAst, ApllodbSqlParser};
@@ -13,8 +13,11 @@ use apllodb_storage_engine_interface::{
 };
 
 use self::{
-    ddl::DdlProcessor, modification::ModificationProcessor, query::QueryProcessor,
-    sql_processor_context::SqlProcessorContext, success::SqlProcessorSuccess,
+    ddl::DdlProcessor,
+    modification::ModificationProcessor,
+    query::QueryProcessor,
+    sql_processor_context::SqlProcessorContext,
+    success::{SqlProcessorScriptSuccess, SqlProcessorSuccess},
 };
 
 use crate::ast_translator::AstTranslator;
@@ -22,12 +25,14 @@ use crate::ast_translator::AstTranslator;
 /// Processes SQL.
 #[derive(Debug, new)]
 pub struct SqlProcessor<Engine: StorageEngine> {
-    context: Arc<SqlProcessorContext<Engine>>,
+    context: Rc<SqlProcessorContext<Engine>>,
 }
 
 impl<Engine: StorageEngine> SqlProcessor<Engine> {
     /// # Failures
     ///
+    /// - [SyntaxError](apllodb-shared-components::SqlState::SyntaxError) when:
+    ///   - the sql fails to parse.
     /// - [ConnectionExceptionDatabaseNotOpen](apllodb-shared-components::SqlState::ConnectionExceptionDatabaseNotOpen) when:
     ///   - requesting an operation that uses an open database with [SessionWithoutDb](apllodb-shared-components::SessionWithoutDb).
     /// - [FeatureNotSupported](apllodb-shared-components::SqlState::FeatureNotSupported) when:
@@ -40,16 +45,74 @@ impl<Engine: StorageEngine> SqlProcessor<Engine> {
         let parser = ApllodbSqlParser::default();
 
         match parser.parse(sql) {
-            Err(e) => Err(
-                ApllodbSessionError::new(
+            Err(e) => Err(ApllodbSessionError::new(
+                ApllodbError::syntax_error(format!("failed to parse SQL: {}", sql), Box::new(e)),
+                session,
+            )),
+            Ok(ApllodbAst(command)) => self.run_command(session, command, sql).await,
+        }
+    }
+
+    /// Runs semicolon-separated commands in `sql` (e.g. a migration file) one by one.
+    /// Each command is run with the session returned from the previous one.
+    ///
+    /// # Failures
+    ///
+    /// - [SyntaxError](apllodb-shared-components::SqlState::SyntaxError) when:
+    ///   - any command fails to parse. No command is run.
+    /// - Same as [run()](Self::run) for each command.
+    ///   Commands before the failed one have been run, and the returned error has the session at the failure.
+    pub async fn run_script(
+        &self,
+        session: Session,
+        sql: &str,
+    ) -> ApllodbSessionResult<SqlProcessorScriptSuccess> {
+        let parser = ApllodbSqlParser::default();
+
+        let asts = match parser.parse_script(sql) {
+            Ok(asts) => asts,
+            Err(e) => {
+                return Err(ApllodbSessionError::new(
                     ApllodbError::syntax_error(
-                        format!("failed to parse SQL: {}", sql),
+                        format!("failed to parse SQL script: {}", sql),
                         Box::new(e),
                     ),
-                    session
-                )
-            ),
-            Ok(ApllodbAst(command)) => match session {
+                    session,
+                ))
+            }
+        };
+
+        let mut session = session;
+        let mut records_seq = Vec::new();
+        for ApllodbAst(command) in asts {
+            session = match self.run_command(session, command, sql).await? {
+                SqlProcessorSuccess::QueryRes { session, records } => {
+                    records_seq.push(records);
+                    Session::from(session)
+                }
+                SqlProcessorSuccess::ModificationRes { session }
+                | SqlProcessorSuccess::DdlRes { session }
+                | SqlProcessorSuccess::BeginTransactionRes { session } => Session::from(session),
+                SqlProcessorSuccess::CreateDatabaseRes { session } => session,
+                SqlProcessorSuccess::UseDatabaseRes { session }
+                | SqlProcessorSuccess::TransactionEndRes { session } => Session::from(session),
+            };
+        }
+
+        Ok(SqlProcessorScriptSuccess {
+            session,
+            records: records_seq,
+        })
+    }
+
+    /// `sql` is only used in error messages.
+    async fn run_command(
+        &self,
+        session: Session,
+        command: apllodb_ast::Command,
+        sql: &str,
+    ) -> ApllodbSessionResult<SqlProcessorSuccess> {
+        match session {
                 Session::WithTx(sess) => match command {
                     apllodb_ast::Command::CommitTransactionCommandVariant => {
                         let session = self.context.engine.with_tx().commit_transaction(sess).await?;
@@ -62,6 +125,7 @@ impl<Engine: StorageEngine> SqlProcessor<Engine> {
 
                     apllodb_ast::Command::AlterTableCommandVariant(_)
                     | apllodb_ast::Command::CreateTableCommandVariant(_)
+                    | apllodb_ast::Command::CreateTypeCommandVariant(_)
                     | apllodb_ast::Command::DropTableCommandVariant(_) => {
                         let processor = self.ddl();
                         let sess = processor.run(sess, command).await?;
@@ -106,6 +170,7 @@ impl<Engine: StorageEngine> SqlProcessor<Engine> {
 
                     apllodb_ast::Command::AlterTableCommandVariant(_)
                     | apllodb_ast::Command::CreateTableCommandVariant(_)
+                    | apllodb_ast::Command::CreateTypeCommandVariant(_)
                     | apllodb_ast::Command::DropTableCommandVariant(_)
                     | apllodb_ast::Command::DeleteCommandVariant(_)
                     | apllodb_ast::Command::InsertCommandVariant(_)
@@ -164,6 +229,7 @@ impl<Engine: StorageEngine> SqlProcessor<Engine> {
                     | apllodb_ast::Command::CommitTransactionCommandVariant
                     | apllodb_ast::Command::AlterTableCommandVariant(_)
                     | apllodb_ast::Command::CreateTableCommandVariant(_)
+                    | apllodb_ast::Command::CreateTypeCommandVariant(_)
                     | apllodb_ast::Command::DropTableCommandVariant(_)
                     | apllodb_ast::Command::DeleteCommandVariant(_)
                     | apllodb_ast::Command::InsertCommandVariant(_)
@@ -174,7 +240,6 @@ impl<Engine: StorageEngine> SqlProcessor<Engine> {
                         format!("this command requires open database: {}", sql),
                     ), Session::from(sess))),
                 },
-            },
         }
     }
 
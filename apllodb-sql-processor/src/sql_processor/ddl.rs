@@ -1,24 +1,25 @@
-use std::sync::Arc;
+use std::rc::Rc;
 
 use apllodb_shared_components::{
-    ApllodbError, ApllodbResult, ApllodbSessionError, ApllodbSessionResult, Session, SessionWithTx,
+    AdtField, AdtType, AdtVariant, ApllodbError, ApllodbResult, ApllodbSessionError,
+    ApllodbSessionResult, Session, SessionWithTx,
 };
 use apllodb_sql_parser::apllodb_ast::{
-    AlterTableCommand, Command, CreateTableCommand, TableElement,
+    AlterTableCommand, Command, CreateTableCommand, CreateTypeCommand, TableElement,
 };
 use apllodb_storage_engine_interface::{
     AlterTableAction, ColumnDefinition, StorageEngine, TableConstraintKind, TableConstraints,
     TableName, WithTxMethods,
 };
 
-use crate::ast_translator::AstTranslator;
+use crate::{ast_translator::AstTranslator, type_catalog::TypeCatalog};
 
 use super::sql_processor_context::SqlProcessorContext;
 
 /// Processes DDL command.
 #[derive(Clone, Debug, new)]
 pub(crate) struct DdlProcessor<Engine: StorageEngine> {
-    context: Arc<SqlProcessorContext<Engine>>,
+    context: Rc<SqlProcessorContext<Engine>>,
 }
 
 impl<Engine: StorageEngine> DdlProcessor<Engine> {
@@ -28,8 +29,12 @@ impl<Engine: StorageEngine> DdlProcessor<Engine> {
         session: SessionWithTx,
         command: Command,
     ) -> ApllodbSessionResult<SessionWithTx> {
+        let (type_catalog, session) = self.context.type_catalog(session, vec![]).await?;
+
         match command {
-            Command::CreateTableCommandVariant(cc) => match self.run_helper_create_table(cc) {
+            Command::CreateTableCommandVariant(cc) => match self
+                .run_helper_create_table(cc, &type_catalog)
+            {
                 Ok((table_name, table_constraints, column_definitions)) => {
                     self.context
                         .engine
@@ -39,19 +44,33 @@ impl<Engine: StorageEngine> DdlProcessor<Engine> {
                 }
                 Err(e) => Err(ApllodbSessionError::new(e, Session::from(session))),
             },
-            Command::AlterTableCommandVariant(ac) => match self.run_helper_alter_table(ac) {
-                Ok((table_name, action)) => {
-                    self.context
-                        .engine
-                        .with_tx()
-                        .alter_table(session, table_name, action)
-                        .await
+            Command::AlterTableCommandVariant(ac) => {
+                match self.run_helper_alter_table(ac, &type_catalog) {
+                    Ok((table_name, action)) => {
+                        self.context
+                            .engine
+                            .with_tx()
+                            .alter_table(session, table_name, action)
+                            .await
+                    }
+                    Err(e) => Err(ApllodbSessionError::new(e, Session::from(session))),
                 }
-                Err(e) => Err(ApllodbSessionError::new(e, Session::from(session))),
-            },
+            }
+            Command::CreateTypeCommandVariant(ct) => {
+                match self.run_helper_create_type(ct, &type_catalog) {
+                    Ok(adt_type) => {
+                        self.context
+                            .engine
+                            .with_tx()
+                            .create_type(session, adt_type)
+                            .await
+                    }
+                    Err(e) => Err(ApllodbSessionError::new(e, Session::from(session))),
+                }
+            }
             _ => Err(ApllodbSessionError::new(
                 ApllodbError::feature_not_supported(
-                    "only CREATE TABLE / ALTER TABLE are supported for DDL currently",
+                    "only CREATE TABLE / ALTER TABLE / CREATE TYPE are supported for DDL currently",
                 ),
                 Session::from(session),
             )),
@@ -61,6 +80,7 @@ impl<Engine: StorageEngine> DdlProcessor<Engine> {
     fn run_helper_create_table(
         &self,
         command: CreateTableCommand,
+        type_catalog: &TypeCatalog,
     ) -> ApllodbResult<(TableName, TableConstraints, Vec<ColumnDefinition>)> {
         let table_name = AstTranslator::table_name(command.table_name)?;
 
@@ -75,7 +95,7 @@ impl<Engine: StorageEngine> DdlProcessor<Engine> {
                     None
                 }
             })
-            .map(|cd| AstTranslator::column_definition(cd.clone()))
+            .map(|cd| AstTranslator::column_definition(cd.clone(), type_catalog))
             .collect::<ApllodbResult<_>>()?;
 
         let table_constraints: Vec<TableConstraintKind> = command
@@ -102,6 +122,7 @@ impl<Engine: StorageEngine> DdlProcessor<Engine> {
     fn run_helper_alter_table(
         &self,
         command: AlterTableCommand,
+        type_catalog: &TypeCatalog,
     ) -> ApllodbResult<(TableName, AlterTableAction)> {
         let table_name = AstTranslator::table_name(command.table_name)?;
 
@@ -117,15 +138,40 @@ impl<Engine: StorageEngine> DdlProcessor<Engine> {
                 .clone())
         }?;
 
-        let action = AstTranslator::alter_table_action(ast_action)?;
+        let action = AstTranslator::alter_table_action(ast_action, type_catalog)?;
 
         Ok((table_name, action))
     }
+
+    fn run_helper_create_type(
+        &self,
+        command: CreateTypeCommand,
+        type_catalog: &TypeCatalog,
+    ) -> ApllodbResult<AdtType> {
+        let variants = command
+            .variant_definitions
+            .into_vec()
+            .into_iter()
+            .map(|vd| {
+                let fields = vd
+                    .field_definitions
+                    .into_iter()
+                    .map(|fd| {
+                        let sql_type = AstTranslator::data_type(fd.data_type, type_catalog)?;
+                        Ok(AdtField::new(fd.field_name.0 .0, sql_type))
+                    })
+                    .collect::<ApllodbResult<Vec<AdtField>>>()?;
+                Ok(AdtVariant::new(vd.variant_name.0 .0, fields))
+            })
+            .collect::<ApllodbResult<Vec<AdtVariant>>>()?;
+
+        AdtType::new(&command.type_name.0 .0, variants)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
+    use std::rc::Rc;
 
     use super::DdlProcessor;
     use crate::sql_processor::sql_processor_context::SqlProcessorContext;
@@ -197,6 +243,9 @@ mod tests {
                 let test_datum = test_datum.clone();
 
                 let mut with_tx = MockWithTxMethods::new();
+                with_tx
+                    .expect_list_types()
+                    .returning(|session| async { Ok((vec![], session)) }.boxed_local());
                 with_tx
                     .expect_create_table()
                     .with(
@@ -209,7 +258,7 @@ mod tests {
                 with_tx
             });
 
-            let context = Arc::new(SqlProcessorContext::new(engine));
+            let context = Rc::new(SqlProcessorContext::new(engine));
 
             let ast = parser.parse(&sql).unwrap();
             DdlProcessor::run_directly(context.clone(), ast.0).await?;
@@ -1,7 +1,8 @@
+mod delete_planner;
 mod insert_planner;
 mod update_planner;
 
-use std::sync::Arc;
+use std::rc::Rc;
 
 use apllodb_shared_components::{
     ApllodbError, ApllodbResult, ApllodbSessionError, ApllodbSessionResult, Session, SessionWithTx,
@@ -9,7 +10,10 @@ use apllodb_shared_components::{
 use apllodb_sql_parser::apllodb_ast::Command;
 use apllodb_storage_engine_interface::StorageEngine;
 
+use crate::ast_translator::AstTranslator;
+
 use self::{
+    delete_planner::DeletePlanner,
     insert_planner::InsertPlanner,
     modification_executor::ModificationExecutor,
     modification_plan::{modification_plan_tree::ModificationPlanTree, ModificationPlan},
@@ -24,7 +28,7 @@ pub(crate) mod modification_plan;
 /// Processes ÎNSERT/UPDATE/DELETE command.
 #[derive(Debug, new)]
 pub(crate) struct ModificationProcessor<Engine: StorageEngine> {
-    context: Arc<SqlProcessorContext<Engine>>,
+    context: Rc<SqlProcessorContext<Engine>>,
 }
 
 impl<Engine: StorageEngine> ModificationProcessor<Engine> {}
@@ -36,19 +40,46 @@ impl<Engine: StorageEngine> ModificationProcessor<Engine> {
         session: SessionWithTx,
         command: Command,
     ) -> ApllodbSessionResult<SessionWithTx> {
+        let table_name = match &command {
+            Command::InsertCommandVariant(ic) => Some(ic.table_name.clone()),
+            Command::UpdateCommandVariant(uc) => Some(uc.table_name.clone()),
+            Command::DeleteCommandVariant(dc) => Some(dc.table_name.clone()),
+            _ => None,
+        }
+        .map(AstTranslator::table_name)
+        .transpose();
+        let table_names = match table_name {
+            Ok(table_name) => table_name.into_iter().collect(),
+            Err(e) => return Err(ApllodbSessionError::new(e, Session::from(session))),
+        };
+        let (type_catalog, session) = self.context.type_catalog(session, table_names).await?;
         match command {
             Command::InsertCommandVariant(ic) => {
-                let planner = InsertPlanner::new(&self.context.node_repo, ic);
+                let planner = InsertPlanner::new(
+                    &self.context.node_repo,
+                    &self.context.function_registry,
+                    &type_catalog,
+                    ic,
+                );
                 let plan_tree_res = planner.run();
                 self.run_plan_tree(session, plan_tree_res).await
             }
             Command::UpdateCommandVariant(uc) => {
-                let planner = UpdatePlanner::new(uc);
+                let planner =
+                    UpdatePlanner::new(&self.context.function_registry, &type_catalog, uc);
+                let plan_tree_res = planner.run();
+                self.run_plan_tree(session, plan_tree_res).await
+            }
+            Command::DeleteCommandVariant(dc) => {
+                let planner =
+                    DeletePlanner::new(&self.context.function_registry, &type_catalog, dc);
                 let plan_tree_res = planner.run();
                 self.run_plan_tree(session, plan_tree_res).await
             }
             _ => Err(ApllodbSessionError::new(
-                ApllodbError::feature_not_supported("only INSERT is supported for DML currently"),
+                ApllodbError::feature_not_supported(
+                    "only INSERT / UPDATE / DELETE are supported for DML currently",
+                ),
                 Session::from(session),
             )),
         }
@@ -71,7 +102,7 @@ impl<Engine: StorageEngine> ModificationProcessor<Engine> {
 
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
+    use std::rc::Rc;
 
     use crate::sql_processor::sql_processor_context::SqlProcessorContext;
     use apllodb_shared_components::{ApllodbResult, NnSqlValue, SqlValue};
@@ -126,6 +157,12 @@ mod tests {
                 let test_datum = test_datum.clone();
 
                 let mut with_tx = MockWithTxMethods::new();
+                with_tx
+                    .expect_list_types()
+                    .returning(|session| async { Ok((vec![], session)) }.boxed_local());
+                with_tx
+                    .expect_list_columns()
+                    .returning(|session, _| async { Ok((vec![], session)) }.boxed_local());
                 with_tx
                     .expect_insert()
                     .with(
@@ -138,7 +175,7 @@ mod tests {
                 with_tx
             });
 
-            let context = Arc::new(SqlProcessorContext::new(engine));
+            let context = Rc::new(SqlProcessorContext::new(engine));
 
             let ast = parser.parse(&sql).unwrap();
             ModificationProcessor::run_directly(context.clone(), ast.0).await?;
@@ -0,0 +1,43 @@
+mod delete_command_analyzer;
+
+use apllodb_shared_components::ApllodbResult;
+use apllodb_sql_parser::apllodb_ast;
+
+use crate::{function::function_registry::FunctionRegistry, type_catalog::TypeCatalog};
+
+use self::delete_command_analyzer::DeleteCommandAnalyzer;
+
+use super::modification_plan::modification_plan_tree::{
+    modification_plan_node::{DeleteNode, ModificationPlanNode},
+    ModificationPlanTree,
+};
+
+/// Translates [DeleteCommand](apllodb_sql_parser::apllodb_ast::DeleteCommand) into [ModificationPlanTree](crate::sql_processor::modification::modification_plan::ModificationPlanTree).
+#[derive(Clone, Debug)]
+pub(crate) struct DeletePlanner<'r> {
+    analyzer: DeleteCommandAnalyzer<'r>,
+}
+
+impl<'r> DeletePlanner<'r> {
+    pub(crate) fn new(
+        function_registry: &'r FunctionRegistry,
+        type_catalog: &'r TypeCatalog,
+        delete_command: apllodb_ast::DeleteCommand,
+    ) -> Self {
+        Self {
+            analyzer: DeleteCommandAnalyzer::new(delete_command, function_registry, type_catalog),
+        }
+    }
+
+    pub(crate) fn run(&self) -> ApllodbResult<ModificationPlanTree> {
+        let table_name = self.analyzer.table_name_to_delete()?;
+        let where_condition = self.analyzer.where_condition()?;
+
+        let plan_node = ModificationPlanNode::Delete(DeleteNode {
+            table_name,
+            where_condition,
+        });
+
+        Ok(ModificationPlanTree::new(plan_node))
+    }
+}
@@ -0,0 +1,36 @@
+use apllodb_shared_components::ApllodbResult;
+use apllodb_sql_parser::apllodb_ast;
+use apllodb_storage_engine_interface::TableName;
+
+use crate::{
+    ast_translator::AstTranslator, condition::Condition,
+    function::function_registry::FunctionRegistry, type_catalog::TypeCatalog,
+};
+
+#[derive(Clone, Debug, new)]
+pub(crate) struct DeleteCommandAnalyzer<'r> {
+    command: apllodb_ast::DeleteCommand,
+    function_registry: &'r FunctionRegistry,
+    type_catalog: &'r TypeCatalog,
+}
+
+impl<'r> DeleteCommandAnalyzer<'r> {
+    pub(super) fn table_name_to_delete(&self) -> ApllodbResult<TableName> {
+        AstTranslator::table_name(self.command.table_name.clone())
+    }
+
+    pub(super) fn where_condition(&self) -> ApllodbResult<Option<Condition>> {
+        let opt_expression = if let Some(ast_condition) = &self.command.where_condition {
+            let expr = AstTranslator::expression_in_non_select(
+                ast_condition.clone().expression,
+                vec![self.table_name_to_delete()?],
+                self.function_registry,
+                self.type_catalog,
+            )?;
+            Some(Condition::new(expr))
+        } else {
+            None
+        };
+        Ok(opt_expression)
+    }
+}
@@ -6,6 +6,7 @@ use crate::sql_processor::query::query_plan::query_plan_tree::query_plan_node::{
     node_repo::QueryPlanNodeRepository,
     operation::LeafPlanOperation,
 };
+use crate::{function::function_registry::FunctionRegistry, type_catalog::TypeCatalog};
 
 use self::insert_command_analyzer::InsertCommandAnalyzer;
 
@@ -21,17 +22,19 @@ mod insert_command_analyzer;
 pub(crate) struct InsertPlanner<'r> {
     node_repo: &'r QueryPlanNodeRepository,
 
-    analyzer: InsertCommandAnalyzer,
+    analyzer: InsertCommandAnalyzer<'r>,
 }
 
 impl<'r> InsertPlanner<'r> {
     pub(crate) fn new(
         node_repo: &'r QueryPlanNodeRepository,
+        function_registry: &'r FunctionRegistry,
+        type_catalog: &'r TypeCatalog,
         insert_command: apllodb_ast::InsertCommand,
     ) -> Self {
         Self {
             node_repo,
-            analyzer: InsertCommandAnalyzer::new(insert_command),
+            analyzer: InsertCommandAnalyzer::new(insert_command, function_registry, type_catalog),
         }
     }
 
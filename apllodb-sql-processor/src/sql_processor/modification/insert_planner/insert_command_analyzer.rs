@@ -11,15 +11,19 @@ use crate::{
         aliased_correlation_name::AliasedCorrelationName, correlation_name::CorrelationName,
     },
     field::{aliased_field_name::AliasedFieldName, field_name::FieldName},
+    function::function_registry::FunctionRegistry,
     records::{record::Record, record_schema::RecordSchema, Records},
+    type_catalog::TypeCatalog,
 };
 
 #[derive(Clone, Debug, new)]
-pub(crate) struct InsertCommandAnalyzer {
+pub(crate) struct InsertCommandAnalyzer<'r> {
     command: apllodb_ast::InsertCommand,
+    function_registry: &'r FunctionRegistry,
+    type_catalog: &'r TypeCatalog,
 }
 
-impl InsertCommandAnalyzer {
+impl<'r> InsertCommandAnalyzer<'r> {
     pub(super) fn table_name_to_insert(&self) -> ApllodbResult<TableName> {
         AstTranslator::table_name(self.command.table_name.clone())
     }
@@ -86,10 +90,17 @@ impl InsertCommandAnalyzer {
                     let expr = AstTranslator::expression_in_non_select(
                         ast_expr.clone(),
                         vec![self.table_name_to_insert()?],
+                        self.function_registry,
+                        self.type_catalog,
+                    )?;
+                    let expr = self.type_catalog.column_value(
+                        &self.table_name_to_insert()?,
+                        &AstTranslator::column_name(cn.clone())?,
+                        expr,
                     )?;
                     let sql_value = expr.to_sql_value_for_expr_without_index()?;
 
-                    let (pos, _) = schema.index(&SchemaIndex::from(cn.0 .0.as_str()))?;
+                    let (pos, _) = schema.index(&SchemaIndex::new(None, cn.0 .0.clone()))?;
                     constant_values[pos.to_usize()] = sql_value;
                 }
 
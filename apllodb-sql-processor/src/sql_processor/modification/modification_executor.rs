@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::rc::Rc;
 
 use apllodb_shared_components::{ApllodbSessionResult, SessionWithTx};
 use apllodb_storage_engine_interface::{
@@ -19,7 +19,7 @@ use crate::{
 
 use super::modification_plan::{
     modification_plan_tree::modification_plan_node::{
-        InsertNode, ModificationPlanNode, UpdateNode,
+        DeleteNode, InsertNode, ModificationPlanNode, UpdateNode,
     },
     ModificationPlan,
 };
@@ -27,7 +27,7 @@ use super::modification_plan::{
 /// Modification (INSERT, UPDATE, and DELETE) executor which inputs a ModificationPlan requests to storage engine.
 #[derive(Clone, Debug, new)]
 pub(crate) struct ModificationExecutor<Engine: StorageEngine> {
-    context: Arc<SqlProcessorContext<Engine>>,
+    context: Rc<SqlProcessorContext<Engine>>,
 }
 
 impl<Engine: StorageEngine> ModificationExecutor<Engine> {
@@ -45,6 +45,9 @@ impl<Engine: StorageEngine> ModificationExecutor<Engine> {
             ModificationPlanNode::Update(update_node) => {
                 self.run_update(session, update_node).await
             }
+            ModificationPlanNode::Delete(delete_node) => {
+                self.run_delete(session, delete_node).await
+            }
         }
     }
 
@@ -109,6 +112,24 @@ impl<Engine: StorageEngine> ModificationExecutor<Engine> {
         Ok(session)
     }
 
+    async fn run_delete(
+        &self,
+        session: SessionWithTx,
+        delete_node: DeleteNode,
+    ) -> ApllodbSessionResult<SessionWithTx> {
+        let selection =
+            Self::condition_into_selection(&delete_node.table_name, delete_node.where_condition);
+
+        let session = self
+            .context
+            .engine
+            .with_tx()
+            .delete(session, delete_node.table_name, selection)
+            .await?;
+
+        Ok(session)
+    }
+
     fn condition_into_selection(
         table_name: &TableName,
         condition: Option<Condition>,
@@ -13,6 +13,7 @@ use crate::{
 pub(crate) enum ModificationPlanNode {
     Insert(InsertNode),
     Update(UpdateNode),
+    Delete(DeleteNode),
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -39,3 +40,9 @@ pub(crate) struct UpdateNode {
     pub(crate) column_values: HashMap<ColumnName, Expression>,
     pub(crate) where_condition: Option<Condition>,
 }
+
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct DeleteNode {
+    pub(crate) table_name: TableName,
+    pub(crate) where_condition: Option<Condition>,
+}
@@ -3,6 +3,8 @@ mod update_command_analyzer;
 use apllodb_shared_components::ApllodbResult;
 use apllodb_sql_parser::apllodb_ast;
 
+use crate::{function::function_registry::FunctionRegistry, type_catalog::TypeCatalog};
+
 use self::update_command_analyzer::UpdateCommandAnalyzer;
 
 use super::modification_plan::modification_plan_tree::{
@@ -12,14 +14,18 @@ use super::modification_plan::modification_plan_tree::{
 
 /// Translates [UpdateCommand](apllodb_sql_parser::apllodb_ast::UpdateCommandCommand) into [ModificationPlanTree](crate::sql_processor::modification::modification_plan::ModificationPlanTree).
 #[derive(Clone, Debug)]
-pub(crate) struct UpdatePlanner {
-    analyzer: UpdateCommandAnalyzer,
+pub(crate) struct UpdatePlanner<'r> {
+    analyzer: UpdateCommandAnalyzer<'r>,
 }
 
-impl UpdatePlanner {
-    pub(crate) fn new(update_command: apllodb_ast::UpdateCommand) -> Self {
+impl<'r> UpdatePlanner<'r> {
+    pub(crate) fn new(
+        function_registry: &'r FunctionRegistry,
+        type_catalog: &'r TypeCatalog,
+        update_command: apllodb_ast::UpdateCommand,
+    ) -> Self {
         Self {
-            analyzer: UpdateCommandAnalyzer::new(update_command),
+            analyzer: UpdateCommandAnalyzer::new(update_command, function_registry, type_catalog),
         }
     }
 
@@ -4,14 +4,19 @@ use apllodb_shared_components::{ApllodbResult, Expression};
 use apllodb_sql_parser::apllodb_ast;
 use apllodb_storage_engine_interface::{ColumnName, TableName};
 
-use crate::{ast_translator::AstTranslator, condition::Condition};
+use crate::{
+    ast_translator::AstTranslator, condition::Condition,
+    function::function_registry::FunctionRegistry, type_catalog::TypeCatalog,
+};
 
 #[derive(Clone, Debug, new)]
-pub(crate) struct UpdateCommandAnalyzer {
+pub(crate) struct UpdateCommandAnalyzer<'r> {
     command: apllodb_ast::UpdateCommand,
+    function_registry: &'r FunctionRegistry,
+    type_catalog: &'r TypeCatalog,
 }
 
-impl UpdateCommandAnalyzer {
+impl<'r> UpdateCommandAnalyzer<'r> {
     pub(super) fn table_name_to_update(&self) -> ApllodbResult<TableName> {
         AstTranslator::table_name(self.command.table_name.clone())
     }
@@ -21,6 +26,13 @@ impl UpdateCommandAnalyzer {
         let expression = AstTranslator::expression_in_non_select(
             self.command.expression.clone(),
             vec![self.table_name_to_update()?],
+            self.function_registry,
+            self.type_catalog,
+        )?;
+        let expression = self.type_catalog.column_value(
+            &self.table_name_to_update()?,
+            &column_name,
+            expression,
         )?;
 
         let mut r = HashMap::<ColumnName, Expression>::new();
@@ -33,6 +45,8 @@ impl UpdateCommandAnalyzer {
             let expr = AstTranslator::expression_in_non_select(
                 ast_condition.clone().expression,
                 vec![self.table_name_to_update()?],
+                self.function_registry,
+                self.type_catalog,
             )?;
             Some(Condition::new(expr))
         } else {
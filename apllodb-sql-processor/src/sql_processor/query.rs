@@ -3,25 +3,25 @@ pub(crate) mod query_executor;
 pub(crate) mod query_plan;
 
 use apllodb_shared_components::{
-    ApllodbSessionError, ApllodbSessionResult, Session, SessionWithTx,
+    ApllodbResult, ApllodbSessionError, ApllodbSessionResult, Session, SessionWithTx,
 };
-use apllodb_sql_parser::apllodb_ast::SelectCommand;
-use apllodb_storage_engine_interface::StorageEngine;
+use apllodb_sql_parser::apllodb_ast::{FromItem, SelectCommand};
+use apllodb_storage_engine_interface::{StorageEngine, TableName};
 
-use crate::records::Records;
+use crate::{ast_translator::AstTranslator, records::Records};
 
 use self::{
     naive_query_planner::NaiveQueryPlanner, query_executor::QueryExecutor, query_plan::QueryPlan,
 };
 
-use std::sync::Arc;
+use std::rc::Rc;
 
 use super::sql_processor_context::SqlProcessorContext;
 
 /// Processes SELECT command.
 #[derive(Debug, new)]
 pub(crate) struct QueryProcessor<Engine: StorageEngine> {
-    context: Arc<SqlProcessorContext<Engine>>,
+    context: Rc<SqlProcessorContext<Engine>>,
 }
 
 impl<Engine: StorageEngine> QueryProcessor<Engine> {
@@ -33,7 +33,17 @@ impl<Engine: StorageEngine> QueryProcessor<Engine> {
     ) -> ApllodbSessionResult<(Records, SessionWithTx)> {
         // TODO query rewrite -> SelectCommand
 
-        let planner = NaiveQueryPlanner::new(&self.context.node_repo, select_command);
+        let table_names = match Self::table_names(&select_command) {
+            Ok(table_names) => table_names,
+            Err(e) => return Err(ApllodbSessionError::new(e, Session::from(session))),
+        };
+        let (type_catalog, session) = self.context.type_catalog(session, table_names).await?;
+        let planner = NaiveQueryPlanner::new(
+            &self.context.node_repo,
+            &self.context.function_registry,
+            &type_catalog,
+            select_command,
+        );
 
         match planner.run() {
             Ok(plan) => {
@@ -44,6 +54,39 @@ impl<Engine: StorageEngine> QueryProcessor<Engine> {
             Err(e) => Err(ApllodbSessionError::new(e, Session::from(session))),
         }
     }
+
+    /// Tables in FROM clauses, including those of set operations' operands.
+    fn table_names(select_command: &SelectCommand) -> ApllodbResult<Vec<TableName>> {
+        fn from_item_table_names(
+            from_item: &FromItem,
+            table_names: &mut Vec<TableName>,
+        ) -> ApllodbResult<()> {
+            match from_item {
+                FromItem::TableNameVariant { table_name, .. } => {
+                    table_names.push(AstTranslator::table_name(table_name.clone())?);
+                }
+                FromItem::JoinVariant { left, right, .. } => {
+                    from_item_table_names(left, table_names)?;
+                    from_item_table_names(right, table_names)?;
+                }
+            }
+            Ok(())
+        }
+
+        let mut table_names = Vec::<TableName>::new();
+        let select_commands = std::iter::once(select_command).chain(
+            select_command
+                .set_operations
+                .iter()
+                .map(|set_operation| &set_operation.select_command),
+        );
+        for select_command in select_commands {
+            if let Some(from_item) = &select_command.from_item {
+                from_item_table_names(from_item, &mut table_names)?;
+            }
+        }
+        Ok(table_names)
+    }
 }
 
 #[cfg(test)]
@@ -59,8 +102,9 @@ mod tests {
         test_models::{ModelsMock, People},
         MockWithTxMethods,
     };
+    use futures::FutureExt;
     use pretty_assertions::assert_eq;
-    use std::sync::Arc;
+    use std::rc::Rc;
 
     #[derive(Clone, PartialEq, Debug)]
     struct TestDatum {
@@ -84,13 +128,19 @@ mod tests {
         let mut engine = default_mock_engine();
         engine.expect_with_tx().returning(|| {
             let mut with_tx = MockWithTxMethods::new();
+            with_tx
+                .expect_list_types()
+                .returning(|session| async { Ok((vec![], session)) }.boxed_local());
+            with_tx
+                .expect_list_columns()
+                .returning(|session, _| async { Ok((vec![], session)) }.boxed_local());
 
             // mocking select()
             mock_select(&mut with_tx, ModelsMock::fx_full());
 
             with_tx
         });
-        let context = Arc::new(SqlProcessorContext::new(engine));
+        let context = Rc::new(SqlProcessorContext::new(engine));
 
         let test_data: Vec<TestDatum> = vec![
             // full scan
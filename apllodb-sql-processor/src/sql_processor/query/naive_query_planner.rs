@@ -1,21 +1,27 @@
 mod select_command_analyzer;
 
-use std::collections::HashSet;
+use std::{collections::HashSet, sync::Arc};
 
-use apllodb_shared_components::{ApllodbResult, SchemaIndex};
+use apllodb_shared_components::{ApllodbError, ApllodbResult, SchemaIndex, SqlType};
 use apllodb_sql_parser::apllodb_ast::{self};
-use apllodb_storage_engine_interface::RowProjectionQuery;
+use apllodb_storage_engine_interface::{Row, RowProjectionQuery};
 
 use super::query_plan::query_plan_tree::query_plan_node::node_repo::QueryPlanNodeRepository;
 use crate::{
     correlation::correlation_name::CorrelationName,
+    function::function_registry::FunctionRegistry,
+    records::{record::Record, record_schema::RecordSchema, Records},
     sql_processor::query::query_plan::query_plan_tree::{
         query_plan_node::{
-            node_kind::{QueryPlanNodeKind, QueryPlanNodeLeaf, QueryPlanNodeUnary},
-            operation::{LeafPlanOperation, UnaryPlanOperation},
+            node_id::QueryPlanNodeId,
+            node_kind::{
+                QueryPlanNodeBinary, QueryPlanNodeKind, QueryPlanNodeLeaf, QueryPlanNodeUnary,
+            },
+            operation::{BinaryPlanOperation, LeafPlanOperation, UnaryPlanOperation},
         },
         QueryPlanTree,
     },
+    type_catalog::TypeCatalog,
 };
 use select_command_analyzer::SelectCommandAnalyzer;
 
@@ -24,12 +30,18 @@ use select_command_analyzer::SelectCommandAnalyzer;
 /// Output tree has the following form:
 ///
 /// ```text
-/// proj
+/// limit
 ///  |
-/// aggregation
+/// distinct
+///  |
+/// proj
 ///  |
 /// sort
 ///  |
+/// having
+///  |
+/// aggregation
+///  |
 /// selection
 ///  |
 /// join
@@ -37,40 +49,195 @@ use select_command_analyzer::SelectCommandAnalyzer;
 /// CORR   CORR
 /// ```
 ///
+/// With set operations (UNION, INTERSECT, EXCEPT), each operand is planned without ORDER BY and LIMIT,
+/// and they are applied to the result of set operations:
+///
+/// ```text
+/// limit
+///  |
+/// sort
+///  |
+/// set operation
+///  |----------+
+/// distinct   distinct
+///  |          |
+/// proj       proj
+///  |          |
+/// ...        ...
+/// ```
+///
 /// Nodes are created from bottom to top.
 #[derive(Clone, Debug)]
 pub(crate) struct NaiveQueryPlanner<'r> {
     node_repo: &'r QueryPlanNodeRepository,
+    function_registry: &'r FunctionRegistry,
+    type_catalog: &'r TypeCatalog,
 
-    analyzer: SelectCommandAnalyzer,
+    analyzer: SelectCommandAnalyzer<'r>,
 }
 
 impl<'r> NaiveQueryPlanner<'r> {
     pub(crate) fn new(
         node_repo: &'r QueryPlanNodeRepository,
+        function_registry: &'r FunctionRegistry,
+        type_catalog: &'r TypeCatalog,
         select_command: apllodb_ast::SelectCommand,
     ) -> Self {
         Self {
             node_repo,
-            analyzer: SelectCommandAnalyzer::new(select_command),
+            function_registry,
+            type_catalog,
+            analyzer: SelectCommandAnalyzer::new(select_command, function_registry, type_catalog),
         }
     }
 
     pub(crate) fn run(&self) -> ApllodbResult<QueryPlanTree> {
+        let set_operations = self.analyzer.set_operations();
+        if set_operations.is_empty() {
+            self.create_correlation_nodes()?;
+            self.create_join_nodes()?;
+            self.create_selection_node()?;
+            self.create_aggregation_nodes()?;
+            self.create_sort_node()?;
+            self.create_projection_node()?;
+            self.create_distinct_node()?;
+        } else {
+            self.create_set_operation_nodes(set_operations)?;
+            self.create_sort_node()?;
+        }
+        self.create_limit_node()?;
+
+        Ok(QueryPlanTree::new(self.node_repo.latest_node_id()))
+    }
+
+    /// Creates nodes for an operand of set operations, which does not have ORDER BY and LIMIT.
+    fn create_select_core_nodes(&self) -> ApllodbResult<QueryPlanNodeId> {
         self.create_correlation_nodes()?;
         self.create_join_nodes()?;
         self.create_selection_node()?;
-        self.create_sort_node()?;
-        // aggregation
+        self.create_aggregation_nodes()?;
         self.create_projection_node()?;
+        self.create_distinct_node()?;
 
-        Ok(QueryPlanTree::new(self.node_repo.latest_node_id()))
+        Ok(self.node_repo.latest_node_id())
+    }
+
+    /// INTERSECT binds tighter than UNION and EXCEPT. Otherwise, set operations are applied from left to right.
+    ///
+    /// # Failures
+    ///
+    /// - [NameErrorNotFound](apllodb_shared_components::SqlState::NameErrorNotFound) when:
+    ///   - ORDER BY refers to a field not in the result of set operations.
+    /// - [SyntaxErrorOrAccessRuleViolationDatatypeMismatch](apllodb_shared_components::SqlState::SyntaxErrorOrAccessRuleViolationDatatypeMismatch) when:
+    ///   - inputs have different number of fields.
+    ///   - inputs have types which cannot be unified for a field (e.g. INTEGER and TEXT).
+    fn create_set_operation_nodes(
+        &self,
+        set_operations: Vec<(BinaryPlanOperation, apllodb_ast::SelectCommand)>,
+    ) -> ApllodbResult<()> {
+        let projected_schema = self.analyzer.projected_schema()?;
+        for (index, _) in self.analyzer.sort_index_orderings()? {
+            let _ = projected_schema.pos(&index)?;
+        }
+
+        // (set operation with the previous term, term)
+        let mut terms: Vec<(Option<BinaryPlanOperation>, QueryPlanNodeId)> =
+            vec![(None, self.create_select_core_nodes()?)];
+        let mut field_types = self.analyzer.projected_types()?;
+        for (op, select_command) in set_operations {
+            let right_planner = Self::new(
+                self.node_repo,
+                self.function_registry,
+                self.type_catalog,
+                select_command,
+            );
+            field_types = Self::unify_set_operation_input_types(
+                field_types,
+                right_planner.analyzer.projected_types()?,
+            )?;
+            let right_id = right_planner.create_select_core_nodes()?;
+
+            if let BinaryPlanOperation::Intersect { .. } = op {
+                let (prev_op, left_id) = terms.pop().expect("at least 1 term");
+                let intersect_id = self.create_set_operation_node(op, left_id, right_id);
+                terms.push((prev_op, intersect_id));
+            } else {
+                terms.push((Some(op), right_id));
+            }
+        }
+
+        let mut terms = terms.into_iter();
+        let (_, mut left_id) = terms.next().expect("at least 1 term");
+        for (op, right_id) in terms {
+            let op = op.expect("terms other than the first one have set operations");
+            left_id = self.create_set_operation_node(op, left_id, right_id);
+        }
+        Ok(())
+    }
+
+    /// Fields of set operation inputs are matched by their positions.
+    /// Types unknown before execution (None) are not checked, and the unified type of such a field is also unknown.
+    fn unify_set_operation_input_types(
+        left_types: Vec<Option<SqlType>>,
+        right_types: Vec<Option<SqlType>>,
+    ) -> ApllodbResult<Vec<Option<SqlType>>> {
+        if left_types.len() != right_types.len() {
+            return Err(ApllodbError::datatype_mismatch(format!(
+                "each input of set operations must have the same number of columns: {} vs {}",
+                left_types.len(),
+                right_types.len()
+            )));
+        }
+
+        left_types
+            .into_iter()
+            .zip(right_types)
+            .enumerate()
+            .map(|(raw_pos, types)| match types {
+                (Some(left_type), Some(right_type)) => left_type
+                    .unified(&right_type)
+                    .map(Some)
+                    .map_err(|_| {
+                        ApllodbError::datatype_mismatch(format!(
+                            "column #{} of set operation inputs have types which cannot be unified: `{:?}` vs `{:?}`",
+                            raw_pos + 1,
+                            left_type,
+                            right_type
+                        ))
+                    }),
+                _ => Ok(None),
+            })
+            .collect()
+    }
+
+    fn create_set_operation_node(
+        &self,
+        op: BinaryPlanOperation,
+        left: QueryPlanNodeId,
+        right: QueryPlanNodeId,
+    ) -> QueryPlanNodeId {
+        self.node_repo
+            .create(QueryPlanNodeKind::Binary(QueryPlanNodeBinary {
+                op,
+                left,
+                right,
+            }))
     }
 
     fn create_correlation_nodes(&self) -> ApllodbResult<()> {
         let from_item_correlations = self.analyzer.from_item_correlations()?;
         let widest_schema = self.analyzer.widest_schema()?;
 
+        if from_item_correlations.is_empty() {
+            // SELECT without FROM evaluates select fields for a single record without fields.
+            let schema = Arc::new(RecordSchema::default());
+            let records = Records::new(schema.clone(), vec![Record::new(schema, Row::new(vec![]))]);
+            self.node_repo
+                .create(QueryPlanNodeKind::Leaf(QueryPlanNodeLeaf {
+                    op: LeafPlanOperation::Values { records },
+                }));
+        }
+
         for aliased_correlation_name in &from_item_correlations {
             match &aliased_correlation_name.correlation_name {
                 CorrelationName::TableNameVariant(table_name) => {
@@ -118,12 +285,71 @@ impl<'r> NaiveQueryPlanner<'r> {
         Ok(())
     }
 
+    /// Creates hash aggregation node and selection node for HAVING (if any).
+    fn create_aggregation_nodes(&self) -> ApllodbResult<()> {
+        if !self.analyzer.is_aggregated() {
+            return Ok(());
+        }
+        self.analyzer.validate_grouping()?;
+
+        let aggregate_op = UnaryPlanOperation::HashAggregate {
+            group_by: self.analyzer.grouping_indexes()?,
+            aggregate_calls: self.analyzer.aggregate_calls()?,
+        };
+        let child_id = self.node_repo.latest_node_id();
+        let _ = self
+            .node_repo
+            .create(QueryPlanNodeKind::Unary(QueryPlanNodeUnary {
+                op: aggregate_op,
+                left: child_id,
+            }));
+
+        if let Some(condition) = self.analyzer.having_condition()? {
+            let selection_op = UnaryPlanOperation::Selection { condition };
+            let child_id = self.node_repo.latest_node_id();
+            let _ = self
+                .node_repo
+                .create(QueryPlanNodeKind::Unary(QueryPlanNodeUnary {
+                    op: selection_op,
+                    left: child_id,
+                }));
+        }
+        Ok(())
+    }
+
+    /// Only records up to LIMIT (+ OFFSET) are sorted if LIMIT is given (top-N sort),
+    /// unless DISTINCT may eliminate some of them after sort.
+    ///
+    /// # Failures
+    ///
+    /// - [SyntaxErrorOrAccessRuleViolationInvalidColumnReference](apllodb_shared_components::SqlState::SyntaxErrorOrAccessRuleViolationInvalidColumnReference) when:
+    ///   - ORDER BY of SELECT DISTINCT refers to a field not in select fields,
+    ///     since records eliminated as duplicates may have different values for it.
     fn create_sort_node(&self) -> ApllodbResult<()> {
         let index_orderings = self.analyzer.sort_index_orderings()?;
         if index_orderings.is_empty() {
             Ok(())
         } else {
-            let sort_op = UnaryPlanOperation::Sort { index_orderings };
+            let top_n = if self.analyzer.is_distinct() {
+                let projected_schema = self.analyzer.projected_schema()?;
+                for (index, _) in &index_orderings {
+                    if projected_schema.pos(index).is_err() {
+                        return Err(ApllodbError::invalid_column_reference(format!(
+                            "for SELECT DISTINCT, ORDER BY `{}` must appear in select fields",
+                            index
+                        )));
+                    }
+                }
+                None
+            } else {
+                self.analyzer
+                    .limit()?
+                    .and_then(|(count, offset)| count.map(|count| count.saturating_add(offset)))
+            };
+            let sort_op = UnaryPlanOperation::Sort {
+                index_orderings,
+                top_n,
+            };
             let child_id = self.node_repo.latest_node_id();
 
             let _ = self
@@ -137,11 +363,42 @@ impl<'r> NaiveQueryPlanner<'r> {
         }
     }
 
+    fn create_limit_node(&self) -> ApllodbResult<()> {
+        if let Some((count, offset)) = self.analyzer.limit()? {
+            let limit_op = UnaryPlanOperation::Limit { count, offset };
+            let child_id = self.node_repo.latest_node_id();
+
+            let _ = self
+                .node_repo
+                .create(QueryPlanNodeKind::Unary(QueryPlanNodeUnary {
+                    op: limit_op,
+                    left: child_id,
+                }));
+        }
+        Ok(())
+    }
+
+    fn create_distinct_node(&self) -> ApllodbResult<()> {
+        if self.analyzer.is_distinct() {
+            let child_id = self.node_repo.latest_node_id();
+
+            let _ = self
+                .node_repo
+                .create(QueryPlanNodeKind::Unary(QueryPlanNodeUnary {
+                    op: UnaryPlanOperation::Distinct,
+                    left: child_id,
+                }));
+        }
+        Ok(())
+    }
+
     fn create_projection_node(&self) -> ApllodbResult<()> {
         let afns = self.analyzer.aliased_field_names_in_projection()?;
+        let computed_fields = self.analyzer.computed_fields_in_projection()?;
 
         let projection_op = UnaryPlanOperation::Projection {
             fields: afns.iter().map(SchemaIndex::from).collect(),
+            computed_fields,
         };
         let child_id = self.node_repo.latest_node_id();
 
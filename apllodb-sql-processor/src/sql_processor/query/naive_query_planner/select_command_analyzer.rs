@@ -1,3 +1,4 @@
+mod aggregation;
 mod from_item;
 mod schema;
 
@@ -6,15 +7,37 @@ use apllodb_sql_parser::apllodb_ast;
 
 use crate::{
     aliaser::Aliaser, ast_translator::AstTranslator, condition::Condition,
-    select::ordering::Ordering,
+    function::function_registry::FunctionRegistry, select::ordering::Ordering,
+    sql_processor::query::query_plan::query_plan_tree::query_plan_node::operation::BinaryPlanOperation,
+    type_catalog::TypeCatalog,
 };
 
-#[derive(Clone, Debug, new)]
-pub(crate) struct SelectCommandAnalyzer {
+#[derive(Clone, Debug)]
+pub(crate) struct SelectCommandAnalyzer<'r> {
+    /// Aliases in HAVING and ORDER BY are replaced with the aliased expressions,
+    /// and then aggregate function calls are replaced with references to their results.
     select_command: apllodb_ast::SelectCommand,
+    ast_aggregate_calls: Vec<apllodb_ast::FunctionCall>,
+    function_registry: &'r FunctionRegistry,
+    type_catalog: &'r TypeCatalog,
 }
 
-impl SelectCommandAnalyzer {
+impl<'r> SelectCommandAnalyzer<'r> {
+    pub(super) fn new(
+        mut select_command: apllodb_ast::SelectCommand,
+        function_registry: &'r FunctionRegistry,
+        type_catalog: &'r TypeCatalog,
+    ) -> Self {
+        Self::resolve_aliases(&mut select_command);
+        let ast_aggregate_calls = Self::extract_aggregate_calls(&mut select_command);
+        Self {
+            select_command,
+            ast_aggregate_calls,
+            function_registry,
+            type_catalog,
+        }
+    }
+
     pub(super) fn aliaser(&self) -> ApllodbResult<Aliaser> {
         let afns = self.aliased_field_names_in_projection()?;
         Ok(Aliaser::from(afns))
@@ -23,14 +46,24 @@ impl SelectCommandAnalyzer {
     pub(super) fn selection_condition(&self) -> ApllodbResult<Option<Condition>> {
         if let Some(ast_condition) = &self.select_command.where_condition {
             let from_correlations = self.from_item_correlations()?;
-            let expr =
-                AstTranslator::condition_in_select(ast_condition.clone(), &from_correlations)?;
+            let expr = AstTranslator::condition_in_select(
+                ast_condition.clone(),
+                &from_correlations,
+                self.function_registry,
+                self.type_catalog,
+            )?;
             Ok(Some(Condition::new(expr)))
         } else {
             Ok(None)
         }
     }
 
+    /// # Failures
+    ///
+    /// - [FeatureNotSupported](apllodb_shared_components::SqlState::FeatureNotSupported) when:
+    ///   - ORDER BY has an expression other than a column reference.
+    /// - [DataExceptionIllegalOperation](apllodb_shared_components::SqlState::DataExceptionIllegalOperation) when:
+    ///   - ORDER BY refers to a field whose type is not ordered (e.g. JSON).
     pub(super) fn sort_index_orderings(&self) -> ApllodbResult<Vec<(SchemaIndex, Ordering)>> {
         if let Some(ast_order_bys) = &self.select_command.order_bys {
             let from_correlations = self.from_item_correlations()?;
@@ -42,6 +75,8 @@ impl SelectCommandAnalyzer {
                     let expression = AstTranslator::expression_in_select(
                         ast_order_by.expression,
                         &from_correlations,
+                        self.function_registry,
+                        self.type_catalog,
                     )?;
                     let index = if let Expression::SchemaIndexVariant(idx) = expression {
                         Ok(idx)
@@ -50,6 +85,14 @@ impl SelectCommandAnalyzer {
                             "ORDER BY's expression is supposed to be a SchemaIndex currently",
                         ))
                     }?;
+                    if let Some(sql_type) = self.field_type(&index)? {
+                        if !sql_type.is_ordered() {
+                            return Err(ApllodbError::data_exception_illegal_operation(format!(
+                                "cannot ORDER BY `{}` of type {:?}, which is not ordered",
+                                index, sql_type
+                            )));
+                        }
+                    }
                     let ordering = AstTranslator::ordering(ast_order_by.ordering);
                     Ok((index, ordering))
                 })
@@ -60,4 +103,59 @@ impl SelectCommandAnalyzer {
             Ok(vec![])
         }
     }
+
+    /// SELECT DISTINCT
+    pub(super) fn is_distinct(&self) -> bool {
+        matches!(
+            self.select_command.set_quantifier,
+            Some(apllodb_ast::SetQuantifier::DistinctVariant)
+        )
+    }
+
+    /// Set operations (UNION, INTERSECT, EXCEPT) and their right operands in written order.
+    /// Duplicate records are eliminated unless ALL is specified.
+    pub(super) fn set_operations(&self) -> Vec<(BinaryPlanOperation, apllodb_ast::SelectCommand)> {
+        self.select_command
+            .set_operations
+            .iter()
+            .map(|ast_set_operation| {
+                let all = matches!(
+                    ast_set_operation.set_quantifier,
+                    Some(apllodb_ast::SetQuantifier::AllVariant)
+                );
+                let op = match ast_set_operation.set_operator {
+                    apllodb_ast::SetOperator::UnionVariant => BinaryPlanOperation::Union { all },
+                    apllodb_ast::SetOperator::IntersectVariant => {
+                        BinaryPlanOperation::Intersect { all }
+                    }
+                    apllodb_ast::SetOperator::ExceptVariant => BinaryPlanOperation::Except { all },
+                };
+                (op, ast_set_operation.select_command.clone())
+            })
+            .collect()
+    }
+
+    /// LIMIT count and OFFSET (0 if omitted).
+    ///
+    /// # Failures
+    ///
+    /// - [DataExceptionNumericValueOutOfRange](apllodb_shared_components::SqlState::DataExceptionNumericValueOutOfRange) when:
+    ///   - count or offset is too large.
+    pub(super) fn limit(&self) -> ApllodbResult<Option<(Option<usize>, usize)>> {
+        if let Some(ast_limit) = &self.select_command.limit {
+            let to_usize = |ast_integer: &apllodb_ast::IntegerConstant| {
+                ast_integer.0.parse::<usize>().map_err(|_| {
+                    ApllodbError::data_exception_numeric_value_out_of_range(format!(
+                        "LIMIT or OFFSET is too large: {}",
+                        ast_integer.0
+                    ))
+                })
+            };
+            let count = ast_limit.count.as_ref().map(to_usize).transpose()?;
+            let offset = ast_limit.offset.as_ref().map(to_usize).transpose()?;
+            Ok(Some((count, offset.unwrap_or(0))))
+        } else {
+            Ok(None)
+        }
+    }
 }
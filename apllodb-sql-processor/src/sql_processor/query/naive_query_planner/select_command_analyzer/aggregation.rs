@@ -0,0 +1,407 @@
+use apllodb_shared_components::{ApllodbError, ApllodbResult, Expression, SchemaIndex};
+use apllodb_sql_parser::apllodb_ast;
+
+use crate::{
+    ast_translator::AstTranslator,
+    condition::Condition,
+    field::field_alias::FieldAlias,
+    function::aggregate_function::{AggregateCall, AggregateFunction},
+};
+
+use super::SelectCommandAnalyzer;
+
+/// Prefix of the field names of aggregate function call results.
+/// `?` cannot be a part of identifiers so that they never conflict with column names.
+const AGGREGATE_RESULT_PREFIX: &str = "?aggregate";
+
+impl<'r> SelectCommandAnalyzer<'r> {
+    /// Replaces references to select field aliases in HAVING conditions and ORDER BY with the aliased expressions,
+    /// so that they are checked and computed as if the expressions were written there
+    /// (e.g. `SELECT g, SUM(v) AS total ... ORDER BY total` is `... ORDER BY SUM(v)`).
+    ///
+    /// In ORDER BY, only aliases of column references and aggregate function calls are replaced,
+    /// since ORDER BY only accepts them currently.
+    pub(super) fn resolve_aliases(select_command: &mut apllodb_ast::SelectCommand) {
+        let aliased_expressions: Vec<(String, apllodb_ast::Expression)> = select_command
+            .select_fields
+            .as_vec()
+            .iter()
+            .filter_map(|select_field| {
+                select_field
+                    .alias
+                    .as_ref()
+                    .map(|alias| (alias.0 .0.clone(), select_field.expression.clone()))
+            })
+            .collect();
+        if aliased_expressions.is_empty() {
+            return;
+        }
+
+        let aliased_expression = |ast_expression: &apllodb_ast::Expression| match ast_expression {
+            apllodb_ast::Expression::ColumnReferenceVariant(apllodb_ast::ColumnReference {
+                correlation: None,
+                column_name,
+            }) => aliased_expressions
+                .iter()
+                .find(|(alias, _)| alias == &column_name.0 .0)
+                .map(|(_, expression)| expression.clone()),
+            _ => None,
+        };
+
+        if let Some(having_conditions) = &mut select_command.having_conditions {
+            for condition in having_conditions.iter_mut() {
+                Self::rewrite_expression(&mut condition.expression, &mut |e| {
+                    aliased_expression(e).map(|aliased| *e = aliased).is_some()
+                });
+            }
+        }
+        if let Some(order_bys) = &mut select_command.order_bys {
+            for order_by in order_bys.iter_mut() {
+                match aliased_expression(&order_by.expression) {
+                    Some(aliased @ apllodb_ast::Expression::ColumnReferenceVariant(_)) => {
+                        order_by.expression = aliased
+                    }
+                    Some(aliased @ apllodb_ast::Expression::FunctionCallVariant(_))
+                        if Self::is_aggregate_call(&aliased) =>
+                    {
+                        order_by.expression = aliased
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Replaces aggregate function calls in select fields, HAVING conditions and ORDER BY with column references to their results.
+    /// Returns the replaced (deduplicated) function calls, whose results are named by their positions.
+    pub(super) fn extract_aggregate_calls(
+        select_command: &mut apllodb_ast::SelectCommand,
+    ) -> Vec<apllodb_ast::FunctionCall> {
+        let mut ast_aggregate_calls = Vec::new();
+
+        for select_field in select_command.select_fields.iter_mut() {
+            Self::extract_aggregate_calls_in(
+                &mut select_field.expression,
+                &mut ast_aggregate_calls,
+            );
+        }
+        if let Some(having_conditions) = &mut select_command.having_conditions {
+            for condition in having_conditions.iter_mut() {
+                Self::extract_aggregate_calls_in(
+                    &mut condition.expression,
+                    &mut ast_aggregate_calls,
+                );
+            }
+        }
+        if let Some(order_bys) = &mut select_command.order_bys {
+            for order_by in order_bys.iter_mut() {
+                Self::extract_aggregate_calls_in(
+                    &mut order_by.expression,
+                    &mut ast_aggregate_calls,
+                );
+            }
+        }
+
+        ast_aggregate_calls
+    }
+
+    fn is_aggregate_call(ast_expression: &apllodb_ast::Expression) -> bool {
+        matches!(
+            ast_expression,
+            apllodb_ast::Expression::FunctionCallVariant(ast_function_call)
+                if AggregateFunction::from_name(&ast_function_call.function_name.0 .0).is_some()
+        )
+    }
+
+    /// Aggregate function calls inside arguments of aggregate function calls are left as they are
+    /// (and rejected on translation).
+    fn extract_aggregate_calls_in(
+        ast_expression: &mut apllodb_ast::Expression,
+        ast_aggregate_calls: &mut Vec<apllodb_ast::FunctionCall>,
+    ) {
+        Self::rewrite_expression(ast_expression, &mut |e| {
+            if !Self::is_aggregate_call(e) {
+                return false;
+            }
+            if let apllodb_ast::Expression::FunctionCallVariant(ast_function_call) = e {
+                let pos = ast_aggregate_calls
+                    .iter()
+                    .position(|call| call == ast_function_call)
+                    .unwrap_or_else(|| {
+                        ast_aggregate_calls.push(ast_function_call.clone());
+                        ast_aggregate_calls.len() - 1
+                    });
+                *e =
+                    apllodb_ast::Expression::ColumnReferenceVariant(apllodb_ast::ColumnReference {
+                        correlation: None,
+                        column_name: apllodb_ast::ColumnName(apllodb_ast::Identifier(
+                            aggregate_result_name(pos),
+                        )),
+                    });
+            }
+            true
+        })
+    }
+
+    /// Applies `rewrite` to `ast_expression` and its sub-expressions, from the outermost one.
+    /// Sub-expressions of an expression are not visited if `rewrite` returns true for it.
+    fn rewrite_expression(
+        ast_expression: &mut apllodb_ast::Expression,
+        rewrite: &mut dyn FnMut(&mut apllodb_ast::Expression) -> bool,
+    ) {
+        if rewrite(ast_expression) {
+            return;
+        }
+
+        let mut recurse = |e: &mut apllodb_ast::Expression| Self::rewrite_expression(e, rewrite);
+
+        match ast_expression {
+            apllodb_ast::Expression::FunctionCallVariant(ast_function_call) => {
+                if let apllodb_ast::FunctionArguments::ExpressionsVariant { expressions, .. } =
+                    &mut ast_function_call.arguments
+                {
+                    expressions.iter_mut().for_each(recurse);
+                }
+            }
+            apllodb_ast::Expression::ConstantVariant(_)
+            | apllodb_ast::Expression::ColumnReferenceVariant(_)
+            | apllodb_ast::Expression::CurrentDatetimeFunctionVariant(_) => {}
+            apllodb_ast::Expression::UnaryOperatorVariant(_, e)
+            | apllodb_ast::Expression::CastVariant(e, _) => recurse(e),
+            apllodb_ast::Expression::BinaryOperatorVariant(_, left, right)
+            | apllodb_ast::Expression::SubscriptVariant(left, right)
+            | apllodb_ast::Expression::AnyComparisonVariant(_, left, right) => {
+                recurse(left);
+                recurse(right);
+            }
+            apllodb_ast::Expression::InPredicateVariant(in_predicate) => {
+                recurse(&mut in_predicate.expression);
+                in_predicate.list.iter_mut().for_each(recurse);
+            }
+            apllodb_ast::Expression::BetweenPredicateVariant(between_predicate) => {
+                recurse(&mut between_predicate.expression);
+                recurse(&mut between_predicate.low);
+                recurse(&mut between_predicate.high);
+            }
+            apllodb_ast::Expression::LikePredicateVariant(like_predicate) => {
+                recurse(&mut like_predicate.expression);
+                recurse(&mut like_predicate.pattern);
+            }
+            apllodb_ast::Expression::CaseExpressionVariant(case_expression) => {
+                if let Some(operand) = &mut case_expression.operand {
+                    recurse(operand);
+                }
+                for when_clause in case_expression.when_clauses.iter_mut() {
+                    recurse(&mut when_clause.expression);
+                    recurse(&mut when_clause.result);
+                }
+                if let Some(else_result) = &mut case_expression.else_result {
+                    recurse(else_result);
+                }
+            }
+            apllodb_ast::Expression::MatchExpressionVariant(match_expression) => {
+                recurse(&mut match_expression.operand);
+                for match_arm in match_expression.match_arms.iter_mut() {
+                    recurse(&mut match_arm.result);
+                }
+            }
+            apllodb_ast::Expression::ArrayConstructorVariant(elements) => {
+                elements.iter_mut().for_each(recurse);
+            }
+        }
+    }
+
+    /// Whether records are grouped: GROUP BY, HAVING or any aggregate function call is used.
+    pub(in super::super) fn is_aggregated(&self) -> bool {
+        self.select_command.grouping_elements.is_some()
+            || self.select_command.having_conditions.is_some()
+            || !self.ast_aggregate_calls.is_empty()
+    }
+
+    /// Whether `index` refers to a result of an aggregate function call.
+    pub(super) fn is_aggregate_result(index: &SchemaIndex) -> bool {
+        index.prefix().is_none() && index.attr().starts_with(AGGREGATE_RESULT_PREFIX)
+    }
+
+    /// # Failures
+    ///
+    /// - [FeatureNotSupported](apllodb_shared_components::SqlState::FeatureNotSupported) when:
+    ///   - a GROUP BY element is not a column reference.
+    /// - [SyntaxErrorOrAccessRuleViolationGroupingError](apllodb_shared_components::SqlState::SyntaxErrorOrAccessRuleViolationGroupingError) when:
+    ///   - a GROUP BY element contains an aggregate function call.
+    pub(in super::super) fn grouping_indexes(&self) -> ApllodbResult<Vec<SchemaIndex>> {
+        if let Some(ast_grouping_elements) = &self.select_command.grouping_elements {
+            let from_correlations = self.from_item_correlations()?;
+
+            ast_grouping_elements
+                .as_vec()
+                .iter()
+                .map(|ast_grouping_element| {
+                    let apllodb_ast::GroupingElement::ExpressionVariant(ast_expression) =
+                        ast_grouping_element;
+                    let expression = AstTranslator::expression_in_select(
+                        ast_expression.clone(),
+                        &from_correlations,
+                        self.function_registry,
+                        self.type_catalog,
+                    )?;
+                    if let Expression::SchemaIndexVariant(idx) = expression {
+                        Ok(idx)
+                    } else {
+                        Err(ApllodbError::feature_not_supported(
+                            "GROUP BY's expression is supposed to be a SchemaIndex currently",
+                        ))
+                    }
+                })
+                .collect()
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    /// Aggregate function calls with the aliases of their results.
+    ///
+    /// # Failures
+    ///
+    /// - [SyntaxErrorOrAccessRuleViolationGroupingError](apllodb_shared_components::SqlState::SyntaxErrorOrAccessRuleViolationGroupingError) when:
+    ///   - an argument contains another aggregate function call.
+    /// - [NameErrorNotFound](apllodb_shared_components::SqlState::NameErrorNotFound) when:
+    ///   - `*` is given to a function other than COUNT.
+    ///   - a function does not take exactly 1 argument.
+    pub(in super::super) fn aggregate_calls(
+        &self,
+    ) -> ApllodbResult<Vec<(AggregateCall, FieldAlias)>> {
+        let from_correlations = self.from_item_correlations()?;
+
+        self.ast_aggregate_calls
+            .iter()
+            .enumerate()
+            .map(|(pos, ast_function_call)| {
+                let function_name = &ast_function_call.function_name.0 .0;
+                let function = AggregateFunction::from_name(function_name)
+                    .expect("only aggregate function calls are extracted");
+
+                let (argument, distinct) = match &ast_function_call.arguments {
+                    apllodb_ast::FunctionArguments::AsteriskVariant => {
+                        if function != AggregateFunction::Count {
+                            return Err(ApllodbError::name_error_not_found(format!(
+                                "function `{}` does not take `*`",
+                                function_name
+                            )));
+                        }
+                        (None, false)
+                    }
+                    apllodb_ast::FunctionArguments::ExpressionsVariant {
+                        set_quantifier,
+                        expressions,
+                    } => {
+                        if expressions.len() != 1 {
+                            return Err(ApllodbError::name_error_not_found(format!(
+                                "aggregate function `{}` does not take {} argument(s)",
+                                function_name,
+                                expressions.len()
+                            )));
+                        }
+                        let argument = AstTranslator::expression_in_select(
+                            expressions[0].clone(),
+                            &from_correlations,
+                            self.function_registry,
+                            self.type_catalog,
+                        )?;
+                        let distinct = matches!(
+                            set_quantifier,
+                            Some(apllodb_ast::SetQuantifier::DistinctVariant)
+                        );
+                        (Some(argument), distinct)
+                    }
+                };
+
+                let alias = FieldAlias::new(aggregate_result_name(pos))?;
+                Ok((AggregateCall::new(function, argument, distinct), alias))
+            })
+            .collect()
+    }
+
+    /// HAVING conditions joined with AND.
+    pub(in super::super) fn having_condition(&self) -> ApllodbResult<Option<Condition>> {
+        if let Some(ast_conditions) = &self.select_command.having_conditions {
+            let ast_expression = ast_conditions
+                .clone()
+                .into_vec()
+                .into_iter()
+                .map(|ast_condition| ast_condition.expression)
+                .reduce(|left, right| {
+                    apllodb_ast::Expression::BinaryOperatorVariant(
+                        apllodb_ast::BinaryOperator::And,
+                        Box::new(left),
+                        Box::new(right),
+                    )
+                })
+                .expect("NonEmptyVec");
+
+            let from_correlations = self.from_item_correlations()?;
+            let expr = AstTranslator::expression_in_select(
+                ast_expression,
+                &from_correlations,
+                self.function_registry,
+                self.type_catalog,
+            )?;
+            Ok(Some(Condition::new(expr)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Fields read by GROUP BY and arguments of aggregate function calls.
+    pub(super) fn aggregation_indexes(&self) -> ApllodbResult<Vec<SchemaIndex>> {
+        let mut indexes = self.grouping_indexes()?;
+        for (aggregate_call, _) in self.aggregate_calls()? {
+            indexes.extend(aggregate_call.argument_indexes());
+        }
+        Ok(indexes)
+    }
+
+    /// Checks if fields referred outside aggregate function calls (in select fields, HAVING and ORDER BY) are grouped.
+    ///
+    /// # Failures
+    ///
+    /// - [SyntaxErrorOrAccessRuleViolationGroupingError](apllodb_shared_components::SqlState::SyntaxErrorOrAccessRuleViolationGroupingError) when:
+    ///   - a field is neither in GROUP BY nor used in an aggregate function.
+    pub(in super::super) fn validate_grouping(&self) -> ApllodbResult<()> {
+        let from_correlations = self.from_item_correlations()?;
+        let grouping_field_names = self
+            .grouping_indexes()?
+            .iter()
+            .map(|idx| Self::field_name(idx, &from_correlations))
+            .collect::<ApllodbResult<Vec<_>>>()?;
+
+        let mut referred_indexes = Vec::<SchemaIndex>::new();
+        for (expression, _) in self.select_field_expressions()? {
+            referred_indexes.extend(expression.to_schema_indexes());
+        }
+        if let Some(condition) = self.having_condition()? {
+            referred_indexes.extend(condition.as_expression().to_schema_indexes());
+        }
+        for (idx, _) in self.sort_index_orderings()? {
+            referred_indexes.push(idx);
+        }
+
+        for idx in referred_indexes
+            .iter()
+            .filter(|idx| !Self::is_aggregate_result(idx))
+        {
+            let field_name = Self::field_name(idx, &from_correlations)?;
+            if !grouping_field_names.contains(&field_name) {
+                return Err(ApllodbError::grouping_error(format!(
+                    "column `{}` must appear in the GROUP BY clause or be used in an aggregate function",
+                    idx
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn aggregate_result_name(pos: usize) -> String {
+    format!("{}{}?", AGGREGATE_RESULT_PREFIX, pos)
+}
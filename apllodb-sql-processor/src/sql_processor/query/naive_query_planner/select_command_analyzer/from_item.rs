@@ -4,18 +4,21 @@ use crate::{
     correlation::{
         aliased_correlation_name::AliasedCorrelationName, correlation_name::CorrelationName,
     },
+    function::function_registry::FunctionRegistry,
     records::record_schema::RecordSchema,
+    select::join_type::JoinType,
     sql_processor::query::query_plan::query_plan_tree::query_plan_node::{
         node_id::QueryPlanNodeId,
         node_kind::{QueryPlanNodeBinary, QueryPlanNodeKind},
         node_repo::QueryPlanNodeRepository,
         operation::BinaryPlanOperation,
     },
+    type_catalog::TypeCatalog,
 };
 use apllodb_shared_components::{ApllodbError, ApllodbResult, Expression, SchemaIndex};
 use apllodb_sql_parser::apllodb_ast;
 
-impl SelectCommandAnalyzer {
+impl<'r> SelectCommandAnalyzer<'r> {
     pub(in super::super) fn from_item_correlations(
         &self,
     ) -> ApllodbResult<Vec<AliasedCorrelationName>> {
@@ -29,7 +32,11 @@ impl SelectCommandAnalyzer {
     /// Indexes appear in JOIN ... *ON ...* condition.
     pub(in super::super) fn from_item_indexes(&self) -> ApllodbResult<Vec<SchemaIndex>> {
         if let Some(ast_from_item) = self.ast_from_item() {
-            Self::ast_from_item_into_indexes(ast_from_item)
+            Self::ast_from_item_into_indexes(
+                ast_from_item,
+                self.function_registry,
+                self.type_catalog,
+            )
         } else {
             Ok(vec![])
         }
@@ -49,6 +56,8 @@ impl SelectCommandAnalyzer {
             cur_from_item: &apllodb_ast::FromItem,
             widest_schema: &RecordSchema,
             node_repo: &QueryPlanNodeRepository,
+            function_registry: &FunctionRegistry,
+            type_catalog: &TypeCatalog,
         ) -> ApllodbResult<QueryPlanNodeId> {
             let from_item_correlations =
                 SelectCommandAnalyzer::ast_from_item_into_correlation_references(cur_from_item)?;
@@ -68,8 +77,20 @@ impl SelectCommandAnalyzer {
                     right,
                     on,
                 } => {
-                    let left_node_id = rec_create(&*left, widest_schema, node_repo)?;
-                    let right_node_id = rec_create(&*right, widest_schema, node_repo)?;
+                    let left_node_id = rec_create(
+                        left,
+                        widest_schema,
+                        node_repo,
+                        function_registry,
+                        type_catalog,
+                    )?;
+                    let right_node_id = rec_create(
+                        right,
+                        widest_schema,
+                        node_repo,
+                        function_registry,
+                        type_catalog,
+                    )?;
                     let mid_node_id =
                         node_repo.create(QueryPlanNodeKind::Binary(QueryPlanNodeBinary {
                             left: left_node_id,
@@ -78,7 +99,14 @@ impl SelectCommandAnalyzer {
                                 join_type,
                                 on,
                                 joined_schema,
-                                &from_item_correlations,
+                                &SelectCommandAnalyzer::ast_from_item_into_correlation_references(
+                                    left,
+                                )?,
+                                &SelectCommandAnalyzer::ast_from_item_into_correlation_references(
+                                    right,
+                                )?,
+                                function_registry,
+                                type_catalog,
                             )?,
                         }));
                     Ok(mid_node_id)
@@ -87,7 +115,14 @@ impl SelectCommandAnalyzer {
         }
 
         if let Some(ast_from_item) = self.ast_from_item() {
-            rec_create(ast_from_item, &self.widest_schema()?, node_repo).map(|_| ())
+            rec_create(
+                ast_from_item,
+                &self.widest_schema()?,
+                node_repo,
+                self.function_registry,
+                self.type_catalog,
+            )
+            .map(|_| ())
         } else {
             Ok(())
         }
@@ -123,53 +158,123 @@ impl SelectCommandAnalyzer {
 
     fn ast_from_item_into_indexes(
         ast_from_item: &apllodb_ast::FromItem,
+        function_registry: &FunctionRegistry,
+        type_catalog: &TypeCatalog,
     ) -> ApllodbResult<Vec<SchemaIndex>> {
         match ast_from_item {
             apllodb_ast::FromItem::TableNameVariant { .. } => Ok(vec![]),
             apllodb_ast::FromItem::JoinVariant {
                 left, right, on, ..
             } => {
-                let expression = AstTranslator::expression_in_select(
-                    on.expression.clone(),
-                    &Self::ast_from_item_into_correlation_references(ast_from_item)?,
-                )?;
-
-                let mut idxs = expression.to_schema_indexes();
-                idxs.append(&mut Self::ast_from_item_into_indexes(left.as_ref())?);
-                idxs.append(&mut Self::ast_from_item_into_indexes(right.as_ref())?);
+                let mut idxs = if let Some(on) = on {
+                    let expression = AstTranslator::expression_in_select(
+                        on.expression.clone(),
+                        &Self::ast_from_item_into_correlation_references(ast_from_item)?,
+                        function_registry,
+                        type_catalog,
+                    )?;
+                    expression.to_schema_indexes()
+                } else {
+                    vec![]
+                };
+                idxs.append(&mut Self::ast_from_item_into_indexes(
+                    left.as_ref(),
+                    function_registry,
+                    type_catalog,
+                )?);
+                idxs.append(&mut Self::ast_from_item_into_indexes(
+                    right.as_ref(),
+                    function_registry,
+                    type_catalog,
+                )?);
                 Ok(idxs)
             }
         }
     }
 
+    /// Operands of `ON a = b` are matched to the left and right inputs by their correlations,
+    /// so `FROM a JOIN b ON b.a_id = a.id` is the same as `FROM a JOIN b ON a.id = b.a_id`.
+    ///
+    /// # Failures
+    ///
+    /// - [FeatureNotSupported](apllodb_shared_components::SqlState::FeatureNotSupported) when:
+    ///   - ON condition is other than `a = b` of fields.
+    ///   - fields in ON condition are not from each of the left and right inputs.
     fn join_variant_into_join_op(
         join_type: &apllodb_ast::JoinType,
-        on: &apllodb_ast::Condition,
+        on: &Option<apllodb_ast::Condition>,
         joined_schema: RecordSchema,
-        from_item_correlations: &[AliasedCorrelationName],
+        left_correlations: &[AliasedCorrelationName],
+        right_correlations: &[AliasedCorrelationName],
+        function_registry: &FunctionRegistry,
+        type_catalog: &TypeCatalog,
     ) -> ApllodbResult<BinaryPlanOperation> {
-        assert!(
-            matches!(join_type, apllodb_ast::JoinType::InnerJoin,),
-            "only InnerJoin is supported currently"
-        );
+        let join_type = match join_type {
+            apllodb_ast::JoinType::InnerJoin => JoinType::Inner,
+            apllodb_ast::JoinType::LeftOuterJoin => JoinType::LeftOuter,
+            apllodb_ast::JoinType::RightOuterJoin => JoinType::RightOuter,
+            apllodb_ast::JoinType::FullOuterJoin => JoinType::FullOuter,
+            apllodb_ast::JoinType::CrossJoin => {
+                return Ok(BinaryPlanOperation::CrossJoin { joined_schema });
+            }
+        };
+        let on = on
+            .as_ref()
+            .expect("parser must give ON condition to joins other than CROSS JOIN");
+
+        let from_item_correlations: Vec<AliasedCorrelationName> = left_correlations
+            .iter()
+            .chain(right_correlations)
+            .cloned()
+            .collect();
+        let from_item_correlations = from_item_correlations.as_slice();
+        let is_field_of = |index: &SchemaIndex, correlations: &[AliasedCorrelationName]| {
+            Self::field_name(index, correlations).is_ok()
+        };
 
         match &on.expression {
             apllodb_ast::Expression::BinaryOperatorVariant(bin_op, left, right) => {
-                let left =
-                    AstTranslator::expression_in_select(*left.clone(), from_item_correlations)?;
-                let right =
-                    AstTranslator::expression_in_select(*right.clone(), from_item_correlations)?;
+                let left = AstTranslator::expression_in_select(
+                    *left.clone(),
+                    from_item_correlations,
+                    function_registry,
+                    type_catalog,
+                )?;
+                let right = AstTranslator::expression_in_select(
+                    *right.clone(),
+                    from_item_correlations,
+                    function_registry,
+                    type_catalog,
+                )?;
 
                 match (bin_op, left, right) {
                     (
                         apllodb_ast::BinaryOperator::Equal,
                         Expression::SchemaIndexVariant(left_field),
                         Expression::SchemaIndexVariant(right_field),
-                    ) => Ok(BinaryPlanOperation::HashJoin {
-                        left_field,
-                        right_field,
-                        joined_schema,
-                    }),
+                    ) => {
+                        let (left_field, right_field) =
+                            if is_field_of(&left_field, left_correlations)
+                                && is_field_of(&right_field, right_correlations)
+                            {
+                                (left_field, right_field)
+                            } else if is_field_of(&right_field, left_correlations)
+                                && is_field_of(&left_field, right_correlations)
+                            {
+                                (right_field, left_field)
+                            } else {
+                                return Err(ApllodbError::feature_not_supported(format!(
+                                    "JOIN condition must compare a field of each side: `{}` = `{}`",
+                                    left_field, right_field
+                                )));
+                            };
+                        Ok(BinaryPlanOperation::HashJoin {
+                            left_field,
+                            right_field,
+                            joined_schema,
+                            join_type,
+                        })
+                    }
                     _ => Err(ApllodbError::feature_not_supported(
                         "only `ON a = b` JOIN condition is supported currently",
                     )),
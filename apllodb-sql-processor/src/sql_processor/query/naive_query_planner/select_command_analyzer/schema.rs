@@ -1,13 +1,12 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
-use apllodb_shared_components::{ApllodbError, ApllodbResult, Expression, SchemaIndex};
-use apllodb_sql_parser::apllodb_ast;
+use apllodb_shared_components::{ApllodbError, ApllodbResult, Expression, SchemaIndex, SqlType};
 
 use crate::attribute::attribute_name::AttributeName;
 use crate::{
     ast_translator::AstTranslator,
     correlation::aliased_correlation_name::AliasedCorrelationName,
-    field::{aliased_field_name::AliasedFieldName, field_name::FieldName},
+    field::{aliased_field_name::AliasedFieldName, field_alias::FieldAlias, field_name::FieldName},
     records::record_schema::RecordSchema,
 };
 use apllodb_shared_components::SchemaName;
@@ -15,7 +14,7 @@ use apllodb_storage_engine_interface::ColumnName;
 
 use super::SelectCommandAnalyzer;
 
-impl SelectCommandAnalyzer {
+impl<'r> SelectCommandAnalyzer<'r> {
     /// including all fields used during a SELECT execution
     pub(in super::super) fn widest_schema(&self) -> ApllodbResult<RecordSchema> {
         let mut indexes = HashSet::<SchemaIndex>::new();
@@ -29,6 +28,16 @@ impl SelectCommandAnalyzer {
         for idx in self.sort_indexes()? {
             indexes.insert(idx);
         }
+        for (expression, _) in self.computed_fields_in_projection()? {
+            for idx in expression.to_schema_indexes() {
+                indexes.insert(idx);
+            }
+        }
+        for idx in self.aggregation_indexes()? {
+            indexes.insert(idx);
+        }
+        // results of aggregate function calls are computed after scans.
+        indexes.retain(|idx| !Self::is_aggregate_result(idx));
 
         let mut widest_afns = Vec::<AliasedFieldName>::new();
         // insert AliasedFieldNames first.
@@ -50,48 +59,120 @@ impl SelectCommandAnalyzer {
         ))
     }
 
+    /// Column references in select fields.
     pub(in super::super) fn aliased_field_names_in_projection(
         &self,
     ) -> ApllodbResult<Vec<AliasedFieldName>> {
         let from_item_correlations = self.from_item_correlations()?;
-        let ast_select_fields = self.select_command.select_fields.as_vec().clone();
 
-        ast_select_fields
-            .iter()
-            .map(|select_field| {
-                Self::select_field_into_aliased_field_name(select_field, &from_item_correlations)
+        self.select_field_expressions()?
+            .into_iter()
+            .filter_map(|(expression, field_alias)| match expression {
+                Expression::SchemaIndexVariant(index) if !Self::is_aggregate_result(&index) => {
+                    Some(
+                        Self::field_name(&index, &from_item_correlations)
+                            .map(|field_name| AliasedFieldName::new(field_name, field_alias)),
+                    )
+                }
+                _ => None,
             })
-            .collect::<ApllodbResult<_>>()
+            .collect()
     }
-    fn select_field_into_aliased_field_name(
-        ast_select_field: &apllodb_ast::SelectField,
-        from_item_correlations: &[AliasedCorrelationName],
-    ) -> ApllodbResult<AliasedFieldName> {
-        let expression = AstTranslator::expression_in_select(
-            ast_select_field.expression.clone(),
-            from_item_correlations,
-        )?;
 
-        match expression {
-            Expression::ConstantVariant(_) => Err(ApllodbError::feature_not_supported(
-                "constant in select field is not supported currently",
-            )),
-            Expression::UnaryOperatorVariant(_, _) | Expression::BooleanExpressionVariant(_) => {
-                // TODO Better to shrink expression in this layer.
-                Err(ApllodbError::feature_not_supported(
-                    "unary/binary operation in select field is not supported currently",
-                ))
-            }
-            Expression::SchemaIndexVariant(index) => {
-                let field_name = Self::field_name(&index, from_item_correlations)?;
-                let afn = if let Some(a) = &ast_select_field.alias {
-                    AliasedFieldName::new(field_name, Some(AstTranslator::field_alias(a.clone())?))
-                } else {
-                    AliasedFieldName::new(field_name, None)
-                };
-                Ok(afn)
+    /// Select fields other than column references: constants, operations, aggregate function calls, ...
+    pub(in super::super) fn computed_fields_in_projection(
+        &self,
+    ) -> ApllodbResult<Vec<(Expression, Option<FieldAlias>)>> {
+        Ok(self
+            .select_field_expressions()?
+            .into_iter()
+            .filter(|(expression, _)| match expression {
+                Expression::SchemaIndexVariant(index) => Self::is_aggregate_result(index),
+                _ => true,
+            })
+            .collect())
+    }
+
+    /// Schema of records output from projection.
+    pub(in super::super) fn projected_schema(&self) -> ApllodbResult<RecordSchema> {
+        let afns = self.aliased_field_names_in_projection()?;
+        let computed_fields = self.computed_fields_in_projection()?;
+        Ok(
+            RecordSchema::from(afns.into_iter().collect::<HashSet<_>>()).with_unnamed_fields(
+                computed_fields
+                    .into_iter()
+                    .map(|(_, alias)| alias)
+                    .collect(),
+            ),
+        )
+    }
+
+    /// Types of fields output from projection, in the same order as [projected_schema()](Self::projected_schema).
+    /// None for a field whose type is not known before execution (e.g. results of aggregate function calls).
+    pub(in super::super) fn projected_types(&self) -> ApllodbResult<Vec<Option<SqlType>>> {
+        let from_item_correlations = self.from_item_correlations()?;
+        let type_from_index =
+            |idx: &SchemaIndex| self.type_catalog.column_type(&from_item_correlations, idx);
+
+        let mut named = BTreeMap::<AliasedFieldName, Option<SqlType>>::new();
+        let mut unnamed = Vec::<Option<SqlType>>::new();
+        for (expression, field_alias) in self.select_field_expressions()? {
+            let sql_type = expression.sql_type_with_index(&type_from_index);
+            match &expression {
+                Expression::SchemaIndexVariant(index) if !Self::is_aggregate_result(index) => {
+                    let field_name = Self::field_name(index, &from_item_correlations)?;
+                    named.insert(AliasedFieldName::new(field_name, field_alias), sql_type);
+                }
+                _ => unnamed.push(sql_type),
             }
         }
+        Ok(named.into_values().chain(unnamed).collect())
+    }
+
+    /// Type of the field `index` refers to (a select field alias or a column in FROM items).
+    /// None if the type is not known before execution (e.g. results of aggregate function calls).
+    pub(super) fn field_type(&self, index: &SchemaIndex) -> ApllodbResult<Option<SqlType>> {
+        let from_item_correlations = self.from_item_correlations()?;
+        let type_from_index =
+            |idx: &SchemaIndex| self.type_catalog.column_type(&from_item_correlations, idx);
+
+        let aliased_expression =
+            self.select_field_expressions()?
+                .into_iter()
+                .find(|(_, field_alias)| {
+                    index.prefix().is_none()
+                        && field_alias
+                            .as_ref()
+                            .is_some_and(|alias| alias.as_str() == index.attr())
+                });
+        Ok(match aliased_expression {
+            Some((expression, _)) => expression.sql_type_with_index(&type_from_index),
+            None => type_from_index(index),
+        })
+    }
+
+    pub(super) fn select_field_expressions(
+        &self,
+    ) -> ApllodbResult<Vec<(Expression, Option<FieldAlias>)>> {
+        let from_item_correlations = self.from_item_correlations()?;
+        let ast_select_fields = self.select_command.select_fields.as_vec().clone();
+
+        ast_select_fields
+            .into_iter()
+            .map(|ast_select_field| {
+                let expression = AstTranslator::expression_in_select(
+                    ast_select_field.expression,
+                    &from_item_correlations,
+                    self.function_registry,
+                    self.type_catalog,
+                )?;
+                let field_alias = ast_select_field
+                    .alias
+                    .map(AstTranslator::field_alias)
+                    .transpose()?;
+                Ok((expression, field_alias))
+            })
+            .collect()
     }
 
     fn join_indexes(&self) -> ApllodbResult<Vec<SchemaIndex>> {
@@ -101,8 +182,12 @@ impl SelectCommandAnalyzer {
     fn selection_indexes(&self) -> ApllodbResult<Vec<SchemaIndex>> {
         if let Some(ast_condition) = &self.select_command.where_condition {
             let from_correlations = self.from_item_correlations()?;
-            let expression =
-                AstTranslator::condition_in_select(ast_condition.clone(), &from_correlations)?;
+            let expression = AstTranslator::condition_in_select(
+                ast_condition.clone(),
+                &from_correlations,
+                self.function_registry,
+                self.type_catalog,
+            )?;
             let indexes = expression.to_schema_indexes();
             Ok(indexes)
         } else {
@@ -124,12 +209,16 @@ impl SelectCommandAnalyzer {
     /// - [NameErrorNotFound](apllodb_shared_components::SqlState::NameErrorNotFound) when:
     ///   - none of `from_item_correlations` has field named `ast_column_reference.column_name`
     ///   - `ast_column_reference` has a correlation but it is not any of `from_item_correlations`.
+    ///   - `from_item_correlations` is empty (SELECT without FROM).
     pub(super) fn field_name(
         index: &SchemaIndex,
         from_item_correlations: &[AliasedCorrelationName],
     ) -> ApllodbResult<FieldName> {
         if from_item_correlations.is_empty() {
-            unreachable!("SQL parser must handle this case")
+            Err(ApllodbError::name_error_not_found(format!(
+                "column `{}` does not exist without FROM clause",
+                index
+            )))
         } else if let Some(corr) = index.prefix() {
             Self::field_name_with_prefix(corr, index.attr(), from_item_correlations)
         } else {
@@ -149,7 +238,7 @@ impl SelectCommandAnalyzer {
         assert!(!from_item_correlations.is_empty());
 
         let attr = AttributeName::ColumnNameVariant(ColumnName::new(attr)?);
-        let index = SchemaIndex::from(format!("{}.{}", prefix, attr).as_str());
+        let index = SchemaIndex::new(Some(prefix.to_string()), attr.to_string());
 
         // SELECT T.C FROM ...;
         from_item_correlations
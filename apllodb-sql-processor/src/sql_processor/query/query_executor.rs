@@ -1,6 +1,6 @@
 mod plan_node_executor;
 
-use std::sync::Arc;
+use std::rc::Rc;
 
 use apllodb_shared_components::{
     ApllodbSessionError, ApllodbSessionResult, Session, SessionWithTx,
@@ -24,7 +24,7 @@ use super::query_plan::query_plan_tree::query_plan_node::node_id::QueryPlanNodeI
 /// Query executor which inputs a QueryPlan and outputs [RecordIterator](apllodb-shared-components::RecordIterator).
 #[derive(Clone, Debug, new)]
 pub(crate) struct QueryExecutor<Engine: StorageEngine> {
-    context: Arc<SqlProcessorContext<Engine>>,
+    context: Rc<SqlProcessorContext<Engine>>,
 }
 
 impl<Engine: StorageEngine> QueryExecutor<Engine> {
@@ -79,7 +79,7 @@ impl<Engine: StorageEngine> QueryExecutor<Engine> {
 #[allow(unused_imports)]
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
+    use std::rc::Rc;
 
     use apllodb_shared_components::ApllodbResult;
     use apllodb_storage_engine_interface::{
@@ -99,6 +99,7 @@ mod tests {
     use crate::{
         aliaser::Aliaser,
         records::{record::Record, record_schema::RecordSchema},
+        select::join_type::JoinType,
         sql_processor::query::query_plan::{
             query_plan_tree::{
                 query_plan_node::{
@@ -133,7 +134,7 @@ mod tests {
 
         #[derive(Clone)]
         struct TestRunner {
-            context: Arc<SqlProcessorContext<MockStorageEngine>>,
+            context: Rc<SqlProcessorContext<MockStorageEngine>>,
 
             in_plan_tree: Option<QueryPlanTree>,
             expected_select_records: Option<Vec<Record>>,
@@ -141,7 +142,7 @@ mod tests {
         impl TestRunner {
             fn new() -> Self {
                 Self {
-                    context: Arc::new(SqlProcessorContext::new(engine())),
+                    context: Rc::new(SqlProcessorContext::new(engine())),
                     in_plan_tree: None,
                     expected_select_records: None,
                 }
@@ -246,6 +247,7 @@ mod tests {
                 repo.create(QueryPlanNodeKind::Unary(QueryPlanNodeUnary {
                     op: UnaryPlanOperation::Projection {
                         fields: vec![People::tc_id().into()].into_iter().collect(),
+                        computed_fields: vec![],
                     },
                     left: repo.create(QueryPlanNodeKind::Leaf(QueryPlanNodeLeaf {
                         op: LeafPlanOperation::SeqScan {
@@ -271,6 +273,7 @@ mod tests {
                 repo.create(QueryPlanNodeKind::Unary(QueryPlanNodeUnary {
                     op: UnaryPlanOperation::Projection {
                         fields: vec![People::tc_age().into()].into_iter().collect(),
+                        computed_fields: vec![],
                     },
                     left: repo.create(QueryPlanNodeKind::Leaf(QueryPlanNodeLeaf {
                         op: LeafPlanOperation::SeqScan {
@@ -306,6 +309,7 @@ mod tests {
                         )),
                         left_field: People::tc_id().into(),
                         right_field: Body::tc_people_id().into(),
+                        join_type: JoinType::Inner,
                     },
                     left: repo.create(QueryPlanNodeKind::Leaf(QueryPlanNodeLeaf {
                         op: LeafPlanOperation::SeqScan {
@@ -344,6 +348,7 @@ mod tests {
                         )),
                         left_field: People::tc_id().into(),
                         right_field: Pet::tc_people_id().into(),
+                        join_type: JoinType::Inner,
                     },
                     left: repo.create(QueryPlanNodeKind::Leaf(QueryPlanNodeLeaf {
                         op: LeafPlanOperation::SeqScan {
@@ -383,6 +388,7 @@ mod tests {
                         )),
                         left_field: Pet::tc_people_id().into(),
                         right_field: People::tc_id().into(),
+                        join_type: JoinType::Inner,
                     },
                     left: repo.create(QueryPlanNodeKind::Leaf(QueryPlanNodeLeaf {
                         op: LeafPlanOperation::SeqScan {
@@ -422,6 +428,7 @@ mod tests {
                         )),
                         left_field: People::tc_age().into(),
                         right_field: Pet::tc_age().into(),
+                        join_type: JoinType::Inner,
                     },
                     left: repo.create(QueryPlanNodeKind::Leaf(QueryPlanNodeLeaf {
                         op: LeafPlanOperation::SeqScan {
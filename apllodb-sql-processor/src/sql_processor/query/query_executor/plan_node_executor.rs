@@ -1,6 +1,8 @@
-use std::{collections::HashSet, sync::Arc};
+use std::{collections::HashSet, rc::Rc, sync::Arc};
 
-use apllodb_shared_components::{ApllodbResult, ApllodbSessionResult, SchemaIndex, SessionWithTx};
+use apllodb_shared_components::{
+    ApllodbResult, ApllodbSessionResult, Expression, SchemaIndex, SessionWithTx,
+};
 use apllodb_storage_engine_interface::{
     RowProjectionQuery, RowSelectionQuery, StorageEngine, TableName, WithTxMethods,
 };
@@ -8,6 +10,8 @@ use apllodb_storage_engine_interface::{
 use crate::{
     aliaser::Aliaser,
     condition::Condition,
+    field::field_alias::FieldAlias,
+    function::aggregate_function::AggregateCall,
     records::Records,
     select::ordering::Ordering,
     sql_processor::{
@@ -20,7 +24,7 @@ use crate::{
 
 #[derive(Clone, Debug, new)]
 pub(super) struct PlanNodeExecutor<Engine: StorageEngine> {
-    context: Arc<SqlProcessorContext<Engine>>,
+    context: Rc<SqlProcessorContext<Engine>>,
 }
 
 impl<Engine: StorageEngine> PlanNodeExecutor<Engine> {
@@ -48,11 +52,23 @@ impl<Engine: StorageEngine> PlanNodeExecutor<Engine> {
         input_left: Records,
     ) -> ApllodbResult<Records> {
         match op_unary {
-            UnaryPlanOperation::Projection { fields } => self.projection(input_left, &fields),
+            UnaryPlanOperation::Projection {
+                fields,
+                computed_fields,
+            } => self.projection(input_left, &fields, &computed_fields),
             UnaryPlanOperation::Selection { condition } => self.selection(input_left, condition),
             UnaryPlanOperation::Sort {
                 index_orderings: field_orderings,
-            } => Ok(self.sort(input_left, field_orderings)),
+                top_n,
+            } => Ok(self.sort(input_left, field_orderings, top_n)),
+            UnaryPlanOperation::HashAggregate {
+                group_by,
+                aggregate_calls,
+            } => self.hash_aggregate(input_left, &group_by, &aggregate_calls),
+            UnaryPlanOperation::Limit { count, offset } => {
+                Ok(self.limit(input_left, count, offset))
+            }
+            UnaryPlanOperation::Distinct => Ok(self.distinct(input_left)),
         }
     }
 
@@ -63,17 +79,24 @@ impl<Engine: StorageEngine> PlanNodeExecutor<Engine> {
         input_right: Records,
     ) -> ApllodbResult<Records> {
         match op_binary {
-            // TODO type cast
             BinaryPlanOperation::HashJoin {
                 joined_schema,
                 left_field,
                 right_field,
+                join_type,
             } => input_left.hash_join(
                 Arc::new(joined_schema),
                 input_right,
                 &left_field,
                 &right_field,
+                &join_type,
             ),
+            BinaryPlanOperation::CrossJoin { joined_schema } => {
+                input_left.cross_join(Arc::new(joined_schema), input_right)
+            }
+            BinaryPlanOperation::Union { all } => input_left.union(input_right, all),
+            BinaryPlanOperation::Intersect { all } => input_left.intersect(input_right, all),
+            BinaryPlanOperation::Except { all } => input_left.except(input_right, all),
         }
     }
 
@@ -97,20 +120,49 @@ impl<Engine: StorageEngine> PlanNodeExecutor<Engine> {
 
     /// # Failures
     ///
-    /// Failures from [Record::projection()](apllodb_shared_components::Record::projection).
+    /// Failures from [Records::projection_with_computed_fields()](crate::Records::projection_with_computed_fields).
     fn projection(
         &self,
         input_left: Records,
         indexes: &HashSet<SchemaIndex>,
+        computed_fields: &[(Expression, Option<FieldAlias>)],
     ) -> ApllodbResult<Records> {
-        input_left.projection(indexes)
+        input_left.projection_with_computed_fields(indexes, computed_fields)
     }
 
     fn selection(&self, input_left: Records, condition: Condition) -> ApllodbResult<Records> {
         input_left.selection(&condition)
     }
 
-    fn sort(&self, input_left: Records, field_orderings: Vec<(SchemaIndex, Ordering)>) -> Records {
-        input_left.sort(&field_orderings)
+    fn sort(
+        &self,
+        input_left: Records,
+        field_orderings: Vec<(SchemaIndex, Ordering)>,
+        top_n: Option<usize>,
+    ) -> Records {
+        match top_n {
+            Some(n) => input_left.sort_top_n(&field_orderings, n),
+            None => input_left.sort(&field_orderings),
+        }
+    }
+
+    fn limit(&self, input_left: Records, count: Option<usize>, offset: usize) -> Records {
+        input_left.limit(count, offset)
+    }
+
+    fn distinct(&self, input_left: Records) -> Records {
+        input_left.distinct()
+    }
+
+    /// # Failures
+    ///
+    /// Failures from [Records::hash_aggregate()](crate::Records::hash_aggregate).
+    fn hash_aggregate(
+        &self,
+        input_left: Records,
+        group_by: &[SchemaIndex],
+        aggregate_calls: &[(AggregateCall, FieldAlias)],
+    ) -> ApllodbResult<Records> {
+        input_left.hash_aggregate(group_by, aggregate_calls)
     }
 }
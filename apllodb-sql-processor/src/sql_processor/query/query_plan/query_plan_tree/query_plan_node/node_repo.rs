@@ -52,11 +52,13 @@ impl QueryPlanNodeRepository {
             .read()
             .unwrap()
             .iter()
-            .find_map(|(id, node)| {
+            .filter_map(|(id, node)| {
                 node.source_correlation_name()
                     .map(|corr_name| (correlation_name == &corr_name).then(|| *id))
                     .flatten()
             })
+            // the latest one, in case the same correlation appears in each operand of set operations
+            .max()
             .ok_or_else(|| {
                 ApllodbError::name_error_not_found("no QueryPlanNode exists (already removed?)")
             })
@@ -1,14 +1,16 @@
 use std::collections::HashSet;
 
-use apllodb_shared_components::SchemaIndex;
+use apllodb_shared_components::{Expression, SchemaIndex};
 use apllodb_storage_engine_interface::{RowProjectionQuery, TableName};
 use serde::{Deserialize, Serialize};
 
 use crate::{
     aliaser::Aliaser,
     condition::Condition,
+    field::field_alias::FieldAlias,
+    function::aggregate_function::AggregateCall,
     records::{record_schema::RecordSchema, Records},
-    select::ordering::Ordering,
+    select::{join_type::JoinType, ordering::Ordering},
 };
 
 /// Leaf operations, which generates [RecordIterator](apllodb-shared-components::RecordIterator).
@@ -31,14 +33,32 @@ pub(crate) enum LeafPlanOperation {
 pub(crate) enum UnaryPlanOperation {
     Projection {
         fields: HashSet<SchemaIndex>,
+        /// Constants, operations, ... evaluated for each record and appended as unnamed fields.
+        computed_fields: Vec<(Expression, Option<FieldAlias>)>,
     },
     Selection {
         condition: Condition,
     },
     Sort {
         index_orderings: Vec<(SchemaIndex, Ordering)>,
-    }, // TODO extend.
-       // See PostgreSQL's plan nodes: <https://github.com/postgres/postgres/blob/master/src/include/nodes/nodes.h#L42-L95>
+        /// Only the first `n` records are sorted and output if `Some(n)` (ORDER BY with LIMIT).
+        top_n: Option<usize>,
+    },
+    HashAggregate {
+        /// GROUP BY fields. Empty to aggregate all records into one.
+        group_by: Vec<SchemaIndex>,
+        /// Evaluated for each group and appended as unnamed fields with the aliases.
+        aggregate_calls: Vec<(AggregateCall, FieldAlias)>,
+    },
+    Limit {
+        /// All the rest records after `offset` if `None`.
+        count: Option<usize>,
+        offset: usize,
+    },
+    /// SELECT DISTINCT. The first one of duplicate records is output.
+    Distinct,
+    // TODO extend.
+    // See PostgreSQL's plan nodes: <https://github.com/postgres/postgres/blob/master/src/include/nodes/nodes.h#L42-L95>
 }
 
 /// Binary operations, which inputs two [RecordIterator](apllodb-shared-components::RecordIterator) and outputs one [RecordIterator](apllodb-shared-components::RecordIterator).
@@ -48,6 +68,23 @@ pub(crate) enum BinaryPlanOperation {
         joined_schema: RecordSchema,
         left_field: SchemaIndex,
         right_field: SchemaIndex,
+        join_type: JoinType,
+    },
+    CrossJoin {
+        joined_schema: RecordSchema,
+    },
+    /// Fields of two inputs are matched by their positions. Output records have the left input's schema.
+    Union {
+        /// UNION ALL if true. Duplicate records are eliminated otherwise.
+        all: bool,
+    },
+    Intersect {
+        /// INTERSECT ALL if true. Duplicate records are eliminated otherwise.
+        all: bool,
+    },
+    Except {
+        /// EXCEPT ALL if true. Duplicate records are eliminated otherwise.
+        all: bool,
     },
     // TODO extend.
     // See PostgreSQL's plan nodes: <https://github.com/postgres/postgres/blob/master/src/include/nodes/nodes.h#L42-L95>
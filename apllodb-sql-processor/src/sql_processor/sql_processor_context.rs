@@ -1,4 +1,7 @@
-use apllodb_storage_engine_interface::StorageEngine;
+use apllodb_shared_components::{ApllodbSessionResult, SessionWithTx};
+use apllodb_storage_engine_interface::{ColumnDataType, StorageEngine, TableName, WithTxMethods};
+
+use crate::{function::function_registry::FunctionRegistry, type_catalog::TypeCatalog};
 
 use super::query::query_plan::query_plan_tree::query_plan_node::node_repo::QueryPlanNodeRepository;
 
@@ -8,14 +11,45 @@ use super::query::query_plan::query_plan_tree::query_plan_node::node_repo::Query
 pub struct SqlProcessorContext<Engine: StorageEngine> {
     pub(crate) engine: Engine,
     pub(crate) node_repo: QueryPlanNodeRepository,
+    pub(crate) function_registry: FunctionRegistry,
 }
 
 impl<Engine: StorageEngine> SqlProcessorContext<Engine> {
-    /// Constructor
+    /// Constructor with built-in functions.
     pub fn new(engine: Engine) -> Self {
+        Self::with_function_registry(engine, FunctionRegistry::default())
+    }
+
+    /// Constructor with user-defined functions.
+    pub fn with_function_registry(engine: Engine, function_registry: FunctionRegistry) -> Self {
         Self {
             engine,
             node_repo: QueryPlanNodeRepository::default(),
+            function_registry,
         }
     }
+    /// Fetches user-defined types visible from `session`'s transaction, and columns of `table_names`.
+    pub(crate) async fn type_catalog(
+        &self,
+        session: SessionWithTx,
+        table_names: Vec<TableName>,
+    ) -> ApllodbSessionResult<(TypeCatalog, SessionWithTx)> {
+        let (types, mut session) = self.engine.with_tx().list_types(session).await?;
+
+        let mut table_columns = Vec::<(TableName, Vec<ColumnDataType>)>::new();
+        for table_name in table_names {
+            if table_columns.iter().any(|(tn, _)| tn == &table_name) {
+                continue;
+            }
+            let (columns, s) = self
+                .engine
+                .with_tx()
+                .list_columns(session, table_name.clone())
+                .await?;
+            session = s;
+            table_columns.push((table_name, columns));
+        }
+
+        Ok((TypeCatalog::new(types, table_columns), session))
+    }
 }
@@ -49,3 +49,12 @@ pub enum SqlProcessorSuccess {
         session: SessionWithDb,
     },
 }
+
+/// Successful result from [SqlProcessor::run_script()](crate::SqlProcessor::run_script).
+#[derive(Debug)]
+pub struct SqlProcessorScriptSuccess {
+    /// Session returned from the last command (input session if no command is in the script)
+    pub session: Session,
+    /// Result records of SELECT commands in order
+    pub records: Vec<Records>,
+}
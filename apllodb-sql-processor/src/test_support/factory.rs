@@ -17,11 +17,11 @@ use apllodb_immutable_schema_engine_infra::test_support::session_with_tx;
 use apllodb_shared_components::{ApllodbError, ApllodbResult, SchemaIndex, SqlValue};
 use apllodb_sql_parser::apllodb_ast;
 use apllodb_storage_engine_interface::{ColumnName, MockStorageEngine, Row, TableName};
-use std::{collections::HashSet, sync::Arc};
+use std::{collections::HashSet, rc::Rc, sync::Arc};
 
 impl QueryProcessor<MockStorageEngine> {
     pub(crate) async fn run_directly(
-        context: Arc<SqlProcessorContext<MockStorageEngine>>,
+        context: Rc<SqlProcessorContext<MockStorageEngine>>,
         select_command: apllodb_ast::SelectCommand,
     ) -> ApllodbResult<Records> {
         let session = session_with_tx(&context.engine).await?;
@@ -37,7 +37,7 @@ impl QueryProcessor<MockStorageEngine> {
 
 impl ModificationProcessor<MockStorageEngine> {
     pub(crate) async fn run_directly(
-        context: Arc<SqlProcessorContext<MockStorageEngine>>,
+        context: Rc<SqlProcessorContext<MockStorageEngine>>,
         command: apllodb_ast::Command,
     ) -> ApllodbResult<()> {
         let session = session_with_tx(&context.engine).await?;
@@ -53,7 +53,7 @@ impl ModificationProcessor<MockStorageEngine> {
 
 impl DdlProcessor<MockStorageEngine> {
     pub(crate) async fn run_directly(
-        context: Arc<SqlProcessorContext<MockStorageEngine>>,
+        context: Rc<SqlProcessorContext<MockStorageEngine>>,
         command: apllodb_ast::Command,
     ) -> ApllodbResult<()> {
         let session = session_with_tx(&context.engine).await?;
@@ -69,7 +69,7 @@ impl DdlProcessor<MockStorageEngine> {
 
 impl QueryExecutor<MockStorageEngine> {
     pub(crate) async fn run_directly(
-        context: Arc<SqlProcessorContext<MockStorageEngine>>,
+        context: Rc<SqlProcessorContext<MockStorageEngine>>,
         plan: QueryPlan,
     ) -> ApllodbResult<Records> {
         let session = session_with_tx(&context.engine).await?;
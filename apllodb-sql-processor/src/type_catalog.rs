@@ -0,0 +1,295 @@
+use std::sync::Arc;
+
+use apllodb_shared_components::{
+    AdtType, AdtValue, ApllodbError, ApllodbResult, Expression, FunctionCall, NnSqlValue,
+    SchemaIndex, SchemaName, SqlType, SqlValue,
+};
+use apllodb_storage_engine_interface::{ColumnDataType, ColumnName, TableName};
+
+use crate::{
+    attribute::attribute_name::AttributeName,
+    correlation::{
+        aliased_correlation_name::AliasedCorrelationName, correlation_name::CorrelationName,
+    },
+    field::{aliased_field_name::AliasedFieldName, field_name::FieldName},
+};
+
+/// User-defined types (see [AdtType](apllodb_shared_components::AdtType)) visible from a statement,
+/// and columns of the tables the statement refers to.
+///
+/// Fetched from the storage engine before translating each statement, since translation itself is synchronous.
+#[derive(Clone, PartialEq, Debug, Default, new)]
+pub(crate) struct TypeCatalog {
+    types: Vec<AdtType>,
+    table_columns: Vec<(TableName, Vec<ColumnDataType>)>,
+
+    /// Names bound by a MATCH pattern, shadowing unqualified columns (see [MatchExpression](apllodb_shared_components::MatchExpression)).
+    #[new(default)]
+    bindings: Vec<(String, SqlType)>,
+}
+
+impl TypeCatalog {
+    /// Catalog for the result of a MATCH arm, where `bindings` shadow unqualified columns
+    /// (and names bound by outer MATCH patterns) of the same name.
+    pub(crate) fn with_bindings(&self, bindings: Vec<(String, SqlType)>) -> Self {
+        let mut catalog = self.clone();
+        catalog.bindings = bindings
+            .into_iter()
+            .chain(self.bindings.iter().cloned())
+            .collect();
+        catalog
+    }
+
+    /// Type of the column (or the name bound by MATCH pattern) `index` refers to in `correlations`.
+    /// None if no column or more than 1 columns match `index`.
+    pub(crate) fn column_type(
+        &self,
+        correlations: &[AliasedCorrelationName],
+        index: &SchemaIndex,
+    ) -> Option<SqlType> {
+        if index.prefix().is_none() {
+            if let Some((_, sql_type)) = self.bindings.iter().find(|(name, _)| name == index.attr())
+            {
+                return Some(sql_type.clone());
+            }
+        }
+
+        let mut candidates = correlations.iter().flat_map(|corr| {
+            let CorrelationName::TableNameVariant(table_name) = &corr.correlation_name;
+            self.table_columns
+                .iter()
+                .filter(move |(tn, _)| tn == table_name)
+                .flat_map(|(_, columns)| columns.iter())
+                .filter(move |cdt| {
+                    let field_name = AliasedFieldName::new(
+                        FieldName::new(
+                            corr.clone(),
+                            AttributeName::ColumnNameVariant(cdt.column_name().clone()),
+                        ),
+                        None,
+                    );
+                    field_name.matches(index)
+                })
+        });
+        match (candidates.next(), candidates.next()) {
+            (Some(cdt), None) => Some(cdt.sql_type().clone()),
+            _ => None,
+        }
+    }
+
+    /// Checks if `expression` can be written into `column_name` of `table_name` (on INSERT or UPDATE),
+    /// and returns the expression to write.
+    ///
+    /// A bare name which is not a column of `table_name` (e.g. `red`) is resolved into the nullary variant
+    /// of that name if the column is of user-defined type having the variant (same as `red()`).
+    ///
+    /// Only a value of the same user-defined type is written into a column of user-defined type,
+    /// and only an array implicitly coercible to the column's array type (see [SqlType::is_implicitly_coercible_to()](apllodb_shared_components::SqlType::is_implicitly_coercible_to))
+    /// is written into a column of array type.
+    /// Expressions whose type is not known before evaluation (e.g. NULL) are not checked here.
+    ///
+    /// # Failures
+    ///
+    /// - [SyntaxErrorOrAccessRuleViolationDatatypeMismatch](apllodb_shared_components::SqlState::SyntaxErrorOrAccessRuleViolationDatatypeMismatch) when:
+    ///   - `expression` is not of the column's user-defined type
+    ///   - `expression` is not implicitly coercible to the column's array type
+    pub(crate) fn column_value(
+        &self,
+        table_name: &TableName,
+        column_name: &ColumnName,
+        expression: Expression,
+    ) -> ApllodbResult<Expression> {
+        let column_type = self
+            .table_columns
+            .iter()
+            .filter(|(tn, _)| tn == table_name)
+            .flat_map(|(_, columns)| columns.iter())
+            .find(|cdt| cdt.column_name() == column_name)
+            .map(|cdt| cdt.sql_type());
+
+        let correlations = vec![AliasedCorrelationName::new(
+            CorrelationName::TableNameVariant(table_name.clone()),
+            None,
+        )];
+
+        let expression = match (column_type, expression) {
+            (Some(SqlType::AdtComparable(adt_type)), Expression::SchemaIndexVariant(index))
+                if index.prefix().is_none()
+                    && self.column_type(&correlations, &index).is_none() =>
+            {
+                match adt_type.variant(index.attr()) {
+                    Some((_, variant)) if variant.fields().is_empty() => {
+                        let adt_value = AdtValue::new(adt_type.clone(), index.attr(), vec![])?;
+                        Expression::ConstantVariant(SqlValue::NotNull(NnSqlValue::Adt(adt_value)))
+                    }
+                    _ => Expression::SchemaIndexVariant(index),
+                }
+            }
+            (_, expression) => expression,
+        };
+
+        let expression_type =
+            expression.sql_type_with_index(&|index| self.column_type(&correlations, index));
+
+        let mismatch = match (column_type, &expression_type) {
+            (Some(column_type @ SqlType::AdtComparable(_)), Some(expression_type)) => {
+                expression_type != column_type
+            }
+            (Some(column_type @ SqlType::ArrayComparable(_)), Some(expression_type)) => {
+                !expression_type.is_implicitly_coercible_to(column_type)
+            }
+            _ => false,
+        };
+        if mismatch {
+            Err(ApllodbError::datatype_mismatch(format!(
+                "column `{:?}` is of type {:?} but expression is of type {:?}",
+                column_name, column_type, expression_type
+            )))
+        } else {
+            Ok(expression)
+        }
+    }
+
+    /// # Failures
+    ///
+    /// - [NameErrorNotFound](apllodb_shared_components::SqlState::NameErrorNotFound) when:
+    ///   - no type is named `type_name`
+    pub(crate) fn type_by_name(&self, type_name: &str) -> ApllodbResult<&AdtType> {
+        self.types
+            .iter()
+            .find(|t| t.name() == type_name)
+            .ok_or_else(|| {
+                ApllodbError::name_error_not_found(format!("type `{}` does not exist", type_name))
+            })
+    }
+
+    /// The type having all of `variant_names` (None if no type has them).
+    ///
+    /// # Failures
+    ///
+    /// - [NameErrorAmbiguous](apllodb_shared_components::SqlState::NameErrorAmbiguous) when:
+    ///   - more than 1 type have all of `variant_names`
+    pub(crate) fn type_by_variants(
+        &self,
+        variant_names: &[&str],
+    ) -> ApllodbResult<Option<&AdtType>> {
+        let mut candidates = self.types.iter().filter(|t| {
+            variant_names
+                .iter()
+                .all(|variant_name| t.variant(variant_name).is_some())
+        });
+        match (candidates.next(), candidates.next()) {
+            (Some(t), None) => Ok(Some(t)),
+            (None, _) => Ok(None),
+            (Some(t1), Some(t2)) => Err(ApllodbError::name_error_ambiguous(format!(
+                "variant(s) {:?} belong to both type `{}` and `{}`",
+                variant_names,
+                t1.name(),
+                t2.name()
+            ))),
+        }
+    }
+
+    /// Resolves `variant_name(arguments...)` into a constructor of a user-defined type (None if no type has `variant_name`).
+    ///
+    /// # Failures
+    ///
+    /// - [NameErrorAmbiguous](apllodb_shared_components::SqlState::NameErrorAmbiguous) when:
+    ///   - more than 1 type have `variant_name`
+    /// - [DataExceptionIllegalOperation](apllodb_shared_components::SqlState::DataExceptionIllegalOperation) when:
+    ///   - the variant does not have as many fields as `arguments`
+    pub(crate) fn constructor(
+        &self,
+        variant_name: &str,
+        arguments: Vec<Expression>,
+    ) -> ApllodbResult<Option<FunctionCall>> {
+        let adt_type = match self.type_by_variants(&[variant_name])? {
+            Some(adt_type) => adt_type.clone(),
+            None => return Ok(None),
+        };
+        let (_, variant) = adt_type
+            .variant(variant_name)
+            .expect("type_by_variants() assures the variant");
+        if variant.fields().len() != arguments.len() {
+            return Err(ApllodbError::data_exception_illegal_operation(format!(
+                "variant `{}` of type `{}` takes {} field(s) but {} given",
+                variant_name,
+                adt_type.name(),
+                variant.fields().len(),
+                arguments.len()
+            )));
+        }
+
+        let result_type = SqlType::adt(adt_type.clone());
+        let name = variant_name.to_string();
+        let body = Arc::new(move |args: &[SqlValue]| {
+            let fields = args
+                .iter()
+                .map(|arg| match arg {
+                    SqlValue::NotNull(nn) => Ok(nn.clone()),
+                    SqlValue::Null => Err(ApllodbError::integrity_constraint_not_null_violation(
+                        format!("field of variant `{}` cannot be NULL", name),
+                    )),
+                })
+                .collect::<ApllodbResult<Vec<NnSqlValue>>>()?;
+            let adt_value = AdtValue::new(adt_type.clone(), &name, fields)?;
+            Ok(SqlValue::NotNull(NnSqlValue::Adt(adt_value)))
+        });
+        Ok(Some(
+            FunctionCall::new(variant_name, arguments, body).with_result_type(Some(result_type)),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use apllodb_shared_components::{AdtType, AdtVariant, ApllodbResult, SqlState};
+    use pretty_assertions::assert_eq;
+
+    use super::TypeCatalog;
+
+    fn enum_type(name: &str, variant_names: &[&str]) -> AdtType {
+        AdtType::new(
+            name,
+            variant_names
+                .iter()
+                .map(|v| AdtVariant::new(v.to_string(), vec![]))
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_type_by_variants() -> ApllodbResult<()> {
+        let catalog = TypeCatalog::new(
+            vec![
+                enum_type("color", &["red", "green"]),
+                enum_type("signal", &["red", "yellow"]),
+            ],
+            vec![],
+        );
+
+        assert_eq!(
+            catalog.type_by_variants(&["green"])?.map(|t| t.name()),
+            Some("color")
+        );
+        assert_eq!(
+            catalog
+                .type_by_variants(&["red", "yellow"])?
+                .map(|t| t.name()),
+            Some("signal")
+        );
+        assert_eq!(catalog.type_by_variants(&["blue"])?, None);
+        assert_eq!(
+            catalog.type_by_variants(&["red"]).unwrap_err().kind(),
+            &SqlState::NameErrorAmbiguous
+        );
+
+        assert_eq!(catalog.type_by_name("color")?.name(), "color");
+        assert_eq!(
+            catalog.type_by_name("shape").unwrap_err().kind(),
+            &SqlState::NameErrorNotFound
+        );
+        Ok(())
+    }
+}
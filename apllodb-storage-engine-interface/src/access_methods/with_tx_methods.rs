@@ -1,14 +1,17 @@
 use std::collections::HashMap;
 
 use apllodb_shared_components::{
-    ApllodbResult, ApllodbSessionError, ApllodbSessionResult, Expression, Session, SessionId,
-    SessionWithDb, SessionWithTx,
+    AdtType, ApllodbResult, ApllodbSessionError, ApllodbSessionResult, Expression, Session,
+    SessionId, SessionWithDb, SessionWithTx,
 };
 use futures::FutureExt;
 
 use crate::{
     alter_table_action::AlterTableAction,
-    column::{column_definition::ColumnDefinition, column_name::ColumnName},
+    column::{
+        column_data_type::ColumnDataType, column_definition::ColumnDefinition,
+        column_name::ColumnName,
+    },
     row_selection_query::RowSelectionQuery,
     rows::row::Row,
     table::{table_constraints::TableConstraints, table_name::TableName},
@@ -130,6 +133,63 @@ pub trait WithTxMethods: Sized + 'static {
     #[doc(hidden)]
     fn drop_table_core(self, sid: SessionId, table_name: TableName) -> BoxFut<ApllodbResult<()>>;
 
+    fn create_type(
+        self,
+        session: SessionWithTx,
+        adt_type: AdtType,
+    ) -> BoxFut<ApllodbSessionResult<SessionWithTx>> {
+        let sid = *session.get_id();
+        async move {
+            match self.create_type_core(sid, adt_type).await {
+                Ok(_) => Ok(session),
+                Err(e) => Err(ApllodbSessionError::new(e, Session::from(session))),
+            }
+        }
+        .boxed_local()
+    }
+
+    #[doc(hidden)]
+    fn create_type_core(self, sid: SessionId, adt_type: AdtType) -> BoxFut<ApllodbResult<()>>;
+
+    fn list_types(
+        self,
+        session: SessionWithTx,
+    ) -> BoxFut<ApllodbSessionResult<(Vec<AdtType>, SessionWithTx)>> {
+        let sid = *session.get_id();
+        async move {
+            match self.list_types_core(sid).await {
+                Ok(types) => Ok((types, session)),
+                Err(e) => Err(ApllodbSessionError::new(e, Session::from(session))),
+            }
+        }
+        .boxed_local()
+    }
+
+    #[doc(hidden)]
+    fn list_types_core(self, sid: SessionId) -> BoxFut<ApllodbResult<Vec<AdtType>>>;
+
+    fn list_columns(
+        self,
+        session: SessionWithTx,
+        table_name: TableName,
+    ) -> BoxFut<ApllodbSessionResult<(Vec<ColumnDataType>, SessionWithTx)>> {
+        let sid = *session.get_id();
+        async move {
+            match self.list_columns_core(sid, table_name).await {
+                Ok(columns) => Ok((columns, session)),
+                Err(e) => Err(ApllodbSessionError::new(e, Session::from(session))),
+            }
+        }
+        .boxed_local()
+    }
+
+    #[doc(hidden)]
+    fn list_columns_core(
+        self,
+        sid: SessionId,
+        table_name: TableName,
+    ) -> BoxFut<ApllodbResult<Vec<ColumnDataType>>>;
+
     // ========================================================================
     // DML
     // ========================================================================
@@ -2,6 +2,9 @@ use apllodb_shared_components::{ApllodbResult, SchemaIndex, ShortName};
 use serde::{Deserialize, Serialize};
 
 /// Column name.
+///
+/// Preserves case and may contain any characters (as quoted identifiers do), so two names are equal only if they are exactly the same.
+/// Storage engines are responsible for escaping it when embedding it into their own query languages.
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize, Deserialize)]
 pub struct ColumnName(ShortName);
 
@@ -29,6 +32,6 @@ impl ColumnName {
 
 impl From<&ColumnName> for SchemaIndex {
     fn from(cn: &ColumnName) -> Self {
-        SchemaIndex::from(cn.as_str())
+        SchemaIndex::new(None, cn.as_str().to_string())
     }
 }
@@ -1,6 +1,4 @@
-use apllodb_shared_components::{
-    BooleanExpression, ComparisonFunction, Expression, LogicalFunction,
-};
+use apllodb_shared_components::{BooleanExpression, Expression, LogicalFunction};
 use serde::{Deserialize, Serialize};
 
 use crate::TableName;
@@ -29,25 +27,74 @@ impl SingleTableCondition {
                 Expression::UnaryOperatorVariant(_, un_expr) => {
                     validate_unknown_table_in_defendants(tbl, un_expr);
                 }
+                Expression::CastVariant(child, _) => {
+                    validate_unknown_table_in_defendants(tbl, child);
+                }
+                Expression::ArithmeticFunctionVariant(af) => {
+                    let (left, right) = af.operands();
+                    validate_unknown_table_in_defendants(tbl, left);
+                    validate_unknown_table_in_defendants(tbl, right);
+                }
+                Expression::FunctionCallVariant(fc) => {
+                    for arg in fc.arguments() {
+                        validate_unknown_table_in_defendants(tbl, arg);
+                    }
+                }
+                Expression::CaseExpressionVariant(ce) => {
+                    if let Some(operand) = ce.operand() {
+                        validate_unknown_table_in_defendants(tbl, operand);
+                    }
+                    for (when, then) in ce.when_clauses() {
+                        validate_unknown_table_in_defendants(tbl, when);
+                        validate_unknown_table_in_defendants(tbl, then);
+                    }
+                    if let Some(else_result) = ce.else_result() {
+                        validate_unknown_table_in_defendants(tbl, else_result);
+                    }
+                }
+                Expression::MatchExpressionVariant(me) => {
+                    validate_unknown_table_in_defendants(tbl, me.operand());
+                    for arm in me.arms() {
+                        validate_unknown_table_in_defendants(tbl, arm.result());
+                    }
+                }
                 Expression::BooleanExpressionVariant(bin_expr) => match bin_expr {
                     BooleanExpression::LogicalFunctionVariant(lf) => match lf {
-                        LogicalFunction::AndVariant { left, right } => {
-                            validate_unknown_table_in_defendants(
-                                tbl,
-                                &Expression::BooleanExpressionVariant(*left.clone()),
-                            );
-                            validate_unknown_table_in_defendants(
-                                tbl,
-                                &Expression::BooleanExpressionVariant(*right.clone()),
-                            );
-                        }
-                    },
-                    BooleanExpression::ComparisonFunctionVariant(cf) => match cf {
-                        ComparisonFunction::EqualVariant { left, right } => {
+                        LogicalFunction::AndVariant { left, right }
+                        | LogicalFunction::OrVariant { left, right } => {
                             validate_unknown_table_in_defendants(tbl, left.as_ref());
                             validate_unknown_table_in_defendants(tbl, right.as_ref());
                         }
+                        LogicalFunction::NotVariant { child }
+                        | LogicalFunction::PossiblyVariant { child } => {
+                            validate_unknown_table_in_defendants(tbl, child.as_ref());
+                        }
                     },
+                    BooleanExpression::ComparisonFunctionVariant(cf) => {
+                        let (left, right) = cf.operands();
+                        validate_unknown_table_in_defendants(tbl, left);
+                        validate_unknown_table_in_defendants(tbl, right);
+                    }
+                    BooleanExpression::NullPredicateVariant(np) => {
+                        validate_unknown_table_in_defendants(tbl, np.child());
+                    }
+                    BooleanExpression::InPredicateVariant(ip) => {
+                        validate_unknown_table_in_defendants(tbl, ip.child());
+                        for expr in ip.list() {
+                            validate_unknown_table_in_defendants(tbl, expr);
+                        }
+                    }
+                    BooleanExpression::BetweenPredicateVariant(bp) => {
+                        let (child, low, high) = bp.operands();
+                        validate_unknown_table_in_defendants(tbl, child);
+                        validate_unknown_table_in_defendants(tbl, low);
+                        validate_unknown_table_in_defendants(tbl, high);
+                    }
+                    BooleanExpression::LikePredicateVariant(lp) => {
+                        let (child, pattern) = lp.operands();
+                        validate_unknown_table_in_defendants(tbl, child);
+                        validate_unknown_table_in_defendants(tbl, pattern);
+                    }
                 },
             }
         }
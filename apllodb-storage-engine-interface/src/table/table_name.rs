@@ -2,6 +2,9 @@ use apllodb_shared_components::{ApllodbResult, ShortName};
 use serde::{Deserialize, Serialize};
 
 /// Table name.
+///
+/// Preserves case and may contain any characters (as quoted identifiers do), so two names are equal only if they are exactly the same.
+/// Storage engines are responsible for escaping it when embedding it into their own query languages.
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize, Deserialize)]
 pub struct TableName(ShortName);
 
@@ -34,12 +34,10 @@ impl TableColumnName {
 
 impl From<TableColumnName> for SchemaIndex {
     fn from(tc: TableColumnName) -> Self {
-        let s = format!(
-            "{}.{}",
-            tc.as_table_name().as_str(),
-            tc.as_column_name().as_str()
-        );
-        SchemaIndex::from(s.as_str())
+        SchemaIndex::new(
+            Some(tc.as_table_name().as_str().to_string()),
+            tc.as_column_name().as_str().to_string(),
+        )
     }
 }
 